@@ -0,0 +1,138 @@
+//! Baseline storage and regression comparison.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Default allowed growth over the baseline, in percent.
+pub const DEFAULT_THRESHOLD_PCT: u64 = 5;
+
+/// Measured compute units keyed by `program::instruction`.
+pub type Measurements = BTreeMap<String, u64>;
+
+/// Serializes baseline rewrites from benchmark tests running in parallel.
+static BASELINE_LOCK: Mutex<()> = Mutex::new(());
+
+pub fn baseline_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("baseline.json")
+}
+
+/// Where every run records what it measured, so CI can publish a baseline
+/// candidate built by the real SBF toolchain.
+pub fn measured_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("cu-bench")
+        .join("measured.json")
+}
+
+fn load_map(path: &Path) -> Measurements {
+    match std::fs::read_to_string(path) {
+        Ok(raw) => serde_json::from_str(&raw)
+            .unwrap_or_else(|_| panic!("{} is not a valid CU map", path.display())),
+        Err(_) => Measurements::new(),
+    }
+}
+
+/// Merge `measured` into the map stored at `path` and write it back.
+fn merge_into(path: &Path, measured: &Measurements) {
+    let _guard = BASELINE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut merged = load_map(path);
+    merged.extend(measured.iter().map(|(k, v)| (k.clone(), *v)));
+    let json = serde_json::to_string_pretty(&merged).expect("serialize CU map");
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).expect("create CU map directory");
+    }
+    std::fs::write(path, json + "\n").unwrap_or_else(|e| panic!("write {}: {e}", path.display()));
+}
+
+pub fn load_baseline() -> Measurements {
+    load_map(&baseline_path())
+}
+
+/// Merge `measured` into the stored baseline and write it back.
+pub fn write_baseline(measured: &Measurements) {
+    merge_into(&baseline_path(), measured);
+}
+
+pub fn threshold_pct() -> u64 {
+    std::env::var("CU_REGRESSION_THRESHOLD_PCT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_THRESHOLD_PCT)
+}
+
+pub fn update_requested() -> bool {
+    std::env::var("UPDATE_CU_BASELINE").is_ok_and(|v| v == "1")
+}
+
+/// Returns one message per instruction whose measurement exceeds
+/// `baseline * (100 + threshold_pct) / 100`, or that has no baseline at all:
+/// an unrecorded instruction would otherwise never be checked.
+pub fn regressions(
+    baseline: &Measurements,
+    measured: &Measurements,
+    threshold_pct: u64,
+) -> Vec<String> {
+    measured
+        .iter()
+        .filter_map(|(name, &cu)| {
+            let Some(&base) = baseline.get(name) else {
+                return Some(format!(
+                    "{name}: {cu} CU has no baseline; record it with UPDATE_CU_BASELINE=1"
+                ));
+            };
+            let limit = base.saturating_mul(100 + threshold_pct) / 100;
+            (cu > limit).then(|| {
+                format!("{name}: {cu} CU exceeds baseline {base} CU by more than {threshold_pct}%")
+            })
+        })
+        .collect()
+}
+
+/// Report measurements, then either rewrite the baseline or assert that every
+/// instruction has a baseline and none regressed.
+pub fn check(measured: &Measurements) {
+    let baseline = load_baseline();
+    for (name, cu) in measured {
+        match baseline.get(name) {
+            Some(base) => println!("{name:<48} {cu:>8} CU (baseline {base})"),
+            None => println!("{name:<48} {cu:>8} CU (no baseline)"),
+        }
+    }
+    merge_into(&measured_path(), measured);
+
+    if update_requested() {
+        write_baseline(measured);
+        return;
+    }
+
+    let failures = regressions(&baseline, measured, threshold_pct());
+    assert!(
+        failures.is_empty(),
+        "Compute-unit regressions:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regressions_threshold() {
+        let baseline: Measurements = [("a".to_string(), 1_000), ("b".to_string(), 1_000)].into();
+        let measured: Measurements = [
+            ("a".to_string(), 1_050), // exactly at 5%
+            ("b".to_string(), 1_051), // over
+            ("c".to_string(), 9_999), // not in baseline
+        ]
+        .into();
+
+        let failures = regressions(&baseline, &measured, 5);
+        assert_eq!(failures.len(), 2);
+        assert!(failures[0].starts_with("b:"));
+        assert!(failures[1].starts_with("c:") && failures[1].contains("no baseline"));
+    }
+}
@@ -0,0 +1,2752 @@
+//! CU benchmarks for every `sss-core` instruction, run in lifecycle order on
+//! an SSS-1 mint.
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+use anchor_spl::token_2022::spl_token_2022;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use sss_core::pda::{
+    find_claim_status_address, find_collateral_position_address, find_collateral_vault_address,
+    find_config_address, find_consumed_nonce_address, find_credit_line_address,
+    find_deployment_counter_address, find_destination_throttle_address, find_distributor_address,
+    find_event_queue_address, find_fee_schedule_address, find_fee_tier_address,
+    find_freeze_exemption_address, find_kyc_entry_address, find_mint_approval_address,
+    find_mint_receipt_address, find_mint_request_address, find_pending_action_address,
+    find_pending_role_address, find_period_summary_address, find_receivership_address,
+    find_redemption_request_address, find_regulator_access_address, find_report_address,
+    find_reserve_attestation_address, find_reserve_journal_address, find_reserve_movement_address,
+    find_role_address, find_role_directory_address, find_seize_approval_address,
+    find_seizure_record_address, find_snapshot_address, find_snapshot_balance_address,
+    find_standby_config_address, find_sub_minter_address, find_swap_route_address,
+    find_sweep_route_address, find_wipe_record_address,
+};
+use sss_core::state::{
+    DistributionFunding, FeeRates, OracleKind, OraclePriceSource, ReserveMovementKind, Role,
+    RoleAccount, ThawRamp, TimelockedAction,
+};
+use sss_transfer_hook::pda::find_blacklist_address;
+
+use crate::baseline;
+use crate::fixture::Bench;
+
+fn core_ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: sss_core::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+#[test]
+fn core_compute_units() {
+    let Some(mut bench) = Bench::load() else {
+        return;
+    };
+
+    let mint = bench.create_mint(false);
+    let (config, _) = find_config_address(&mint);
+    let admin = bench.authority.pubkey();
+    let admin_role = find_role_address(&config, &admin, Role::Admin).0;
+
+    let deployment_counter = find_deployment_counter_address().0;
+    bench.measure(
+        "sss_core::initialize_deployment_counter",
+        core_ix(
+            sss_core::accounts::InitializeDeploymentCounter {
+                payer: admin,
+                deployment_counter,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::InitializeDeploymentCounter {},
+        ),
+        &[],
+    );
+
+    // Take a `mint_index`: the counter is the last, optional account.
+    let mut ix = bench.initialize_ix(&mint, 1);
+    *ix.accounts.last_mut().unwrap() = AccountMeta::new(deployment_counter, false);
+    bench.measure("sss_core::initialize", ix, &[]);
+
+    let fresh_mint = Keypair::new();
+    let ix = bench.initialize_with_mint_ix(&fresh_mint.pubkey(), 1);
+    bench.measure("sss_core::initialize_with_mint", ix, &[&fresh_mint]);
+
+    let ix = bench.grant_role_ix(&config, &admin, Role::Minter);
+    bench.measure("sss_core::grant_role", ix, &[]);
+    let ix = bench.accept_role_ix(&config, &admin, Role::Minter);
+    bench.measure("sss_core::accept_role", ix, &[]);
+    for role in [
+        Role::Burner,
+        Role::Freezer,
+        Role::Pauser,
+        Role::Seizer,
+        Role::Blacklister,
+        Role::Bridge,
+        Role::MetadataManager,
+        Role::QuotaManager,
+        Role::Rescuer,
+    ] {
+        let ixs = bench.grant_role_ixs(&config, &admin, role);
+        bench.send(&ixs, &[]);
+    }
+
+    let minter_role = find_role_address(&config, &admin, Role::Minter).0;
+    bench.measure(
+        "sss_core::update_minter",
+        core_ix(
+            sss_core::accounts::UpdateMinter {
+                admin,
+                config,
+                admin_role,
+                minter_role,
+            },
+            sss_core::instruction::UpdateMinter {
+                new_quota: Some(1_000_000_000_000),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::set_minter_hours",
+        core_ix(
+            sss_core::accounts::UpdateMinter {
+                admin,
+                config,
+                admin_role,
+                minter_role,
+            },
+            sss_core::instruction::SetMinterHours {
+                hours: Some(sss_core::state::OperatingHours {
+                    start_minute: 9 * 60,
+                    end_minute: 17 * 60,
+                    weekdays: sss_core::state::OperatingHours::ALL_DAYS,
+                }),
+            },
+        ),
+        &[],
+    );
+    // Lift the window again so the mints below do not depend on the clock.
+    let ix = core_ix(
+        sss_core::accounts::UpdateMinter {
+            admin,
+            config,
+            admin_role,
+            minter_role,
+        },
+        sss_core::instruction::SetMinterHours { hours: None },
+    );
+    bench.send(&[ix], &[]);
+    // Left on, so the mints below include the quota period rollover.
+    bench.measure(
+        "sss_core::set_minter_quota_period",
+        core_ix(
+            sss_core::accounts::UpdateMinter {
+                admin,
+                config,
+                admin_role,
+                minter_role,
+            },
+            sss_core::instruction::SetMinterQuotaPeriod {
+                period_secs: Some(86_400),
+            },
+        ),
+        &[],
+    );
+    // Left on too, with a window far above what the bench mints.
+    bench.measure(
+        "sss_core::set_minter_rate_limit",
+        core_ix(
+            sss_core::accounts::UpdateMinter {
+                admin,
+                config,
+                admin_role,
+                minter_role,
+            },
+            sss_core::instruction::SetMinterRateLimit {
+                max_per_window: Some(1_000_000_000_000),
+                window_secs: 3_600,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::reset_minter_usage",
+        core_ix(
+            sss_core::accounts::ResetMinterUsage {
+                admin,
+                config,
+                admin_role,
+                minter_role,
+            },
+            sss_core::instruction::ResetMinterUsage {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_metadata_uri",
+        core_ix(
+            sss_core::accounts::UpdateMetadataUri {
+                authority: admin,
+                config,
+                authority_role: find_role_address(&config, &admin, Role::MetadataManager).0,
+                mint,
+                token_program: spl_token_2022::ID,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::UpdateMetadataUri {
+                uri: "https://example.com/sss-v2.json".to_string(),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_metadata",
+        core_ix(
+            sss_core::accounts::UpdateMetadata {
+                authority: admin,
+                config,
+                authority_role: find_role_address(&config, &admin, Role::MetadataManager).0,
+                mint,
+                token_program: spl_token_2022::ID,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::UpdateMetadata {
+                name: Some("SSS Dollar".to_string()),
+                symbol: Some("SSSD".to_string()),
+                uri: None,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::migrate_config",
+        core_ix(
+            sss_core::accounts::MigrateConfig {
+                admin,
+                config,
+                mint,
+                admin_role,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::MigrateConfig {},
+        ),
+        &[],
+    );
+    // Cut the Admin role back to the layout from before `allowed_hours` so
+    // `migrate_role` has something to grow.
+    let mut legacy_admin_role = bench.svm.get_account(&admin_role).expect("admin role");
+    legacy_admin_role
+        .data
+        .truncate(RoleAccount::LEGACY_SPACES[0]);
+    bench
+        .svm
+        .set_account(admin_role, legacy_admin_role)
+        .expect("set legacy admin role");
+    bench.measure(
+        "sss_core::migrate_role",
+        core_ix(
+            sss_core::accounts::MigrateRole {
+                payer: admin,
+                role_account: admin_role,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::MigrateRole {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_capabilities",
+        core_ix(
+            sss_core::accounts::UpdateCapabilities {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateCapabilities {
+                disabled: 0,
+                lock: 0,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_supply_cap",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCap {
+                admin,
+                config,
+                admin_role,
+                pending_action: None,
+            },
+            sss_core::instruction::UpdateSupplyCap {
+                new_supply_cap: Some(2_000_000_000_000),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_oracle_feed",
+        core_ix(
+            sss_core::accounts::UpdateOracleFeed {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateOracleFeed {
+                oracle_feed_id: Some([7u8; 32]),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_oracle_confidence",
+        core_ix(
+            sss_core::accounts::UpdateOracleFeed {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateOracleConfidence {
+                max_confidence_bps: Some(50),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_oracle_price_source",
+        core_ix(
+            sss_core::accounts::UpdateOracleFeed {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateOraclePriceSource {
+                source: OraclePriceSource::Ema,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_oracle_kind",
+        core_ix(
+            sss_core::accounts::UpdateOracleFeed {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateOracleKind {
+                kind: OracleKind::Switchboard,
+                oracle_feed_id: Some([8u8; 32]),
+            },
+        ),
+        &[],
+    );
+
+    let holder = bench.new_funded_keypair();
+    let holder_ata = bench.create_token_account(&mint, &holder.pubkey(), false);
+    let treasury_ata = bench.create_token_account(&mint, &admin, false);
+
+    let ix = bench.mint_tokens_ix(&mint, &holder_ata, 1_000_000);
+    bench.measure("sss_core::mint_tokens", ix, &[]);
+
+    let mut ix = core_ix(
+        sss_core::accounts::MintTokensBatch {
+            minter: admin,
+            config,
+            minter_role: find_role_address(&config, &admin, Role::Minter).0,
+            mint,
+            token_program: spl_token_2022::ID,
+            price_update: None,
+            reserve_attestation: None,
+        },
+        sss_core::instruction::MintTokensBatch {
+            amounts: vec![1_000, 1_000],
+        },
+    );
+    ix.accounts.push(AccountMeta::new(holder_ata, false));
+    ix.accounts.push(AccountMeta::new(treasury_ata, false));
+    bench.measure("sss_core::mint_tokens_batch", ix, &[]);
+
+    // Large mints: one co-signer approves a request, which the mint then
+    // consumes. A second request is cancelled, and the policy lifted again.
+    bench.measure(
+        "sss_core::update_large_mint_policy",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateLargeMintPolicy {
+                threshold: Some(100_000),
+                approvals: 1,
+            },
+        ),
+        &[],
+    );
+    let cosigner = bench.new_funded_keypair();
+    let ixs = bench.grant_role_ixs(&config, &cosigner.pubkey(), Role::Minter);
+    bench.send(&ixs, &[&cosigner]);
+    let mint_approval = find_mint_approval_address(&config, &admin, 0).0;
+    bench.measure(
+        "sss_core::request_mint_approval",
+        core_ix(
+            sss_core::accounts::RequestMintApproval {
+                minter: admin,
+                config,
+                minter_role,
+                to: holder_ata,
+                mint_approval,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::RequestMintApproval {
+                id: 0,
+                amount: 200_000,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::approve_mint",
+        core_ix(
+            sss_core::accounts::ApproveMint {
+                approver: cosigner.pubkey(),
+                config,
+                approver_role: find_role_address(&config, &cosigner.pubkey(), Role::Minter).0,
+                mint_approval,
+            },
+            sss_core::instruction::ApproveMint {},
+        ),
+        &[&cosigner],
+    );
+    let mut accounts = bench.mint_tokens_accounts(&mint, &holder_ata);
+    accounts.mint_approval = Some(mint_approval);
+    let ix = core_ix(
+        accounts,
+        sss_core::instruction::MintTokens { amount: 200_000 },
+    );
+    bench.send(&[ix], &[]);
+    let cancelled_approval = find_mint_approval_address(&config, &admin, 1).0;
+    let ix = core_ix(
+        sss_core::accounts::RequestMintApproval {
+            minter: admin,
+            config,
+            minter_role,
+            to: holder_ata,
+            mint_approval: cancelled_approval,
+            system_program: solana_sdk::system_program::ID,
+        },
+        sss_core::instruction::RequestMintApproval {
+            id: 1,
+            amount: 200_000,
+        },
+    );
+    bench.send(&[ix], &[]);
+    bench.measure(
+        "sss_core::cancel_mint_approval",
+        core_ix(
+            sss_core::accounts::CancelMintApproval {
+                minter: admin,
+                config,
+                mint_approval: cancelled_approval,
+            },
+            sss_core::instruction::CancelMintApproval {},
+        ),
+        &[],
+    );
+    let ix = core_ix(
+        sss_core::accounts::UpdateSupplyCheck {
+            admin,
+            config,
+            admin_role,
+        },
+        sss_core::instruction::UpdateLargeMintPolicy {
+            threshold: None,
+            approvals: 0,
+        },
+    );
+    bench.send(&[ix], &[]);
+
+    bench.measure(
+        "sss_core::update_receipt_policy",
+        core_ix(
+            sss_core::accounts::UpdateReceiptPolicy {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateReceiptPolicy {
+                threshold: Some(1_000_000),
+                retention_secs: 0,
+            },
+        ),
+        &[],
+    );
+    let reference = [9u8; 32];
+    let receipt = find_mint_receipt_address(&config, &reference).0;
+    bench.measure(
+        "sss_core::mint_tokens_with_receipt",
+        core_ix(
+            sss_core::accounts::MintTokensWithReceipt {
+                issuance: bench.mint_tokens_accounts(&mint, &holder_ata),
+                receipt,
+                payer: admin,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::MintTokensWithReceipt {
+                amount: 1_000_000,
+                reference,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::close_mint_receipt",
+        core_ix(
+            sss_core::accounts::CloseMintReceipt {
+                admin,
+                config,
+                admin_role,
+                receipt,
+            },
+            sss_core::instruction::CloseMintReceipt {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::mint_tokens_idempotent",
+        core_ix(
+            sss_core::accounts::MintTokensIdempotent {
+                issuance: bench.mint_tokens_accounts(&mint, &holder_ata),
+                consumed_nonce: find_consumed_nonce_address(&config, &admin, 1).0,
+                payer: admin,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::MintTokensIdempotent {
+                amount: 500_000,
+                nonce: 1,
+            },
+        ),
+        &[],
+    );
+
+    let requester = bench.new_funded_keypair();
+    let ixs = bench.grant_role_ixs(&config, &requester.pubkey(), Role::MintRequester);
+    bench.send(&ixs, &[&requester]);
+    let requester_role = find_role_address(&config, &requester.pubkey(), Role::MintRequester).0;
+    let request_mint_ix = |id: u64| {
+        core_ix(
+            sss_core::accounts::RequestMint {
+                requester: requester.pubkey(),
+                config,
+                requester_role,
+                to: holder_ata,
+                mint_request: find_mint_request_address(&config, &requester.pubkey(), id).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::RequestMint {
+                id,
+                amount: 500_000,
+                reference: [7; 32],
+            },
+        )
+    };
+    bench.measure("sss_core::request_mint", request_mint_ix(0), &[&requester]);
+    bench.send(&[request_mint_ix(1)], &[&requester]);
+    bench.measure(
+        "sss_core::fulfill_mint_request",
+        core_ix(
+            sss_core::accounts::FulfillMintRequest {
+                issuance: bench.mint_tokens_accounts(&mint, &holder_ata),
+                mint_request: find_mint_request_address(&config, &requester.pubkey(), 0).0,
+                requester: requester.pubkey(),
+            },
+            sss_core::instruction::FulfillMintRequest {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::cancel_mint_request",
+        core_ix(
+            sss_core::accounts::CancelMintRequest {
+                requester: requester.pubkey(),
+                config,
+                mint_request: find_mint_request_address(&config, &requester.pubkey(), 1).0,
+            },
+            sss_core::instruction::CancelMintRequest {},
+        ),
+        &[&requester],
+    );
+
+    let sub_minter = bench.new_funded_keypair();
+    let sub_minter_account = find_sub_minter_address(&config, &admin, &sub_minter.pubkey()).0;
+    bench.measure(
+        "sss_core::delegate_quota",
+        core_ix(
+            sss_core::accounts::DelegateQuota {
+                parent: admin,
+                config,
+                parent_role: minter_role,
+                sub_minter: sub_minter.pubkey(),
+                sub_minter_account,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::DelegateQuota { quota: 500_000 },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::mint_tokens_as_sub_minter",
+        core_ix(
+            sss_core::accounts::MintTokensAsSubMinter {
+                sub_minter: sub_minter.pubkey(),
+                config,
+                sub_minter_account,
+                parent_role: minter_role,
+                mint,
+                to: holder_ata,
+                token_program: spl_token_2022::ID,
+                price_update: None,
+                kyc_entry: None,
+                destination_throttle: None,
+                reserve_attestation: None,
+                fee_schedule: None,
+                fee_tier: None,
+                fee_treasury: None,
+            },
+            sss_core::instruction::MintTokensAsSubMinter { amount: 250_000 },
+        ),
+        &[&sub_minter],
+    );
+    bench.measure(
+        "sss_core::revoke_sub_minter",
+        core_ix(
+            sss_core::accounts::RevokeSubMinter {
+                parent: admin,
+                config,
+                sub_minter_account,
+            },
+            sss_core::instruction::RevokeSubMinter {},
+        ),
+        &[],
+    );
+
+    let hot = bench.new_funded_keypair();
+    let minter_keys = find_minter_keys_address(&minter_role).0;
+    bench.measure(
+        "sss_core::configure_minter_keys",
+        core_ix(
+            sss_core::accounts::ConfigureMinterKeys {
+                admin,
+                config,
+                admin_role,
+                minter_role,
+                minter_keys,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::ConfigureMinterKeys {
+                hot_key: hot.pubkey(),
+                hot_window_quota: 500_000,
+                window_secs: 3_600,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::mint_tokens_hot",
+        core_ix(
+            sss_core::accounts::MintTokensHot {
+                hot: hot.pubkey(),
+                cold: None,
+                config,
+                minter_keys,
+                minter_role,
+                mint,
+                to: holder_ata,
+                token_program: spl_token_2022::ID,
+                price_update: None,
+                kyc_entry: None,
+                destination_throttle: None,
+                reserve_attestation: None,
+                fee_schedule: None,
+                fee_tier: None,
+                fee_treasury: None,
+            },
+            sss_core::instruction::MintTokensHot { amount: 250_000 },
+        ),
+        &[&hot],
+    );
+    bench.measure(
+        "sss_core::remove_minter_keys",
+        core_ix(
+            sss_core::accounts::RemoveMinterKeys {
+                admin,
+                config,
+                admin_role,
+                minter_keys,
+            },
+            sss_core::instruction::RemoveMinterKeys {},
+        ),
+        &[],
+    );
+
+    let blacklister_role = find_role_address(&config, &admin, Role::Blacklister).0;
+    let kyc_entry = find_kyc_entry_address(&config, &holder.pubkey()).0;
+    bench.measure(
+        "sss_core::approve_kyc",
+        core_ix(
+            sss_core::accounts::ApproveKyc {
+                blacklister: admin,
+                config,
+                blacklister_role,
+                owner: holder.pubkey(),
+                kyc_entry,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::ApproveKyc { expires_at: None },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::revoke_kyc",
+        core_ix(
+            sss_core::accounts::RevokeKyc {
+                blacklister: admin,
+                config,
+                blacklister_role,
+                kyc_entry,
+            },
+            sss_core::instruction::RevokeKyc {},
+        ),
+        &[],
+    );
+
+    bench.measure(
+        "sss_core::update_burner",
+        core_ix(
+            sss_core::accounts::UpdateBurner {
+                admin,
+                config,
+                admin_role,
+                burner_role: find_role_address(&config, &admin, Role::Burner).0,
+            },
+            sss_core::instruction::UpdateBurner {
+                new_quota: Some(1_000_000_000_000),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::burn_tokens",
+        core_ix(
+            sss_core::accounts::BurnTokens {
+                burner: admin,
+                config,
+                burner_role: find_role_address(&config, &admin, Role::Burner).0,
+                mint,
+                from: holder_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::BurnTokens { amount: 100_000 },
+        ),
+        &[],
+    );
+    let mut ix = core_ix(
+        sss_core::accounts::BurnTokensBatch {
+            burner: admin,
+            config,
+            burner_role: find_role_address(&config, &admin, Role::Burner).0,
+            mint,
+            token_program: spl_token_2022::ID,
+        },
+        sss_core::instruction::BurnTokensBatch {
+            amounts: vec![1_000, 1_000],
+        },
+    );
+    ix.accounts.push(AccountMeta::new(holder_ata, false));
+    ix.accounts.push(AccountMeta::new(treasury_ata, false));
+    bench.measure("sss_core::burn_tokens_batch", ix, &[]);
+    bench.measure(
+        "sss_core::burn_tokens_idempotent",
+        core_ix(
+            sss_core::accounts::BurnTokensIdempotent {
+                burn: sss_core::accounts::BurnTokens {
+                    burner: admin,
+                    config,
+                    burner_role: find_role_address(&config, &admin, Role::Burner).0,
+                    mint,
+                    from: holder_ata,
+                    token_program: spl_token_2022::ID,
+                },
+                consumed_nonce: find_consumed_nonce_address(&config, &admin, 2).0,
+                payer: admin,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::BurnTokensIdempotent {
+                amount: 100_000,
+                nonce: 2,
+            },
+        ),
+        &[],
+    );
+
+    bench.measure(
+        "sss_core::redeem",
+        core_ix(
+            sss_core::accounts::Redeem {
+                holder: holder.pubkey(),
+                config,
+                mint,
+                from: holder_ata,
+                token_program: spl_token_2022::ID,
+                fee_schedule: None,
+                fee_tier: None,
+                fee_treasury: None,
+            },
+            sss_core::instruction::Redeem { amount: 10_000 },
+        ),
+        &[&holder],
+    );
+
+    // One redemption settled, one cancelled by the holder.
+    let request_redemption_ix = |id: u64| {
+        let redemption_request = find_redemption_request_address(&config, &holder.pubkey(), id).0;
+        let escrow = get_associated_token_address_with_program_id(
+            &redemption_request,
+            &mint,
+            &spl_token_2022::ID,
+        );
+        let ix = core_ix(
+            sss_core::accounts::RequestRedemption {
+                holder: holder.pubkey(),
+                config,
+                mint,
+                from: holder_ata,
+                redemption_request,
+                escrow,
+                token_program: spl_token_2022::ID,
+                associated_token_program: associated_token::ID,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::RequestRedemption {
+                id,
+                amount: 10_000,
+                bank_reference: [7; 32],
+            },
+        );
+        (ix, redemption_request, escrow)
+    };
+    let (ix, settled_request, settled_escrow) = request_redemption_ix(0);
+    bench.measure("sss_core::request_redemption", ix, &[&holder]);
+    bench.measure(
+        "sss_core::settle_redemption",
+        core_ix(
+            sss_core::accounts::SettleRedemption {
+                burner: admin,
+                config,
+                burner_role: find_role_address(&config, &admin, Role::Burner).0,
+                mint,
+                redemption_request: settled_request,
+                holder: holder.pubkey(),
+                escrow: settled_escrow,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::SettleRedemption {},
+        ),
+        &[],
+    );
+    let (ix, cancelled_request, cancelled_escrow) = request_redemption_ix(1);
+    bench.send(&[ix], &[&holder]);
+    bench.measure(
+        "sss_core::cancel_redemption",
+        core_ix(
+            sss_core::accounts::CancelRedemption {
+                authority: holder.pubkey(),
+                config,
+                burner_role: None,
+                mint,
+                redemption_request: cancelled_request,
+                holder: holder.pubkey(),
+                escrow: cancelled_escrow,
+                to: holder_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::CancelRedemption {},
+        ),
+        &[&holder],
+    );
+
+    // Bridge out from the admin's own account, then back in to the holder.
+    let bridge_role = find_role_address(&config, &admin, Role::Bridge).0;
+    let ix = bench.mint_tokens_ix(&mint, &treasury_ata, 100_000);
+    bench.send(&[ix], &[]);
+    bench.measure(
+        "sss_core::bridge_burn",
+        core_ix(
+            sss_core::accounts::BridgeBurn {
+                bridge: admin,
+                config,
+                bridge_role,
+                mint,
+                from: treasury_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::BridgeBurn { amount: 50_000 },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::bridge_mint",
+        core_ix(
+            sss_core::accounts::BridgeMint {
+                bridge: admin,
+                config,
+                bridge_role,
+                mint,
+                to: holder_ata,
+                token_program: spl_token_2022::ID,
+                kyc_entry: None,
+            },
+            sss_core::instruction::BridgeMint { amount: 50_000 },
+        ),
+        &[],
+    );
+
+    let freezer_role = find_role_address(&config, &admin, Role::Freezer).0;
+    bench.measure(
+        "sss_core::set_action_quota",
+        core_ix(
+            sss_core::accounts::SetActionQuota {
+                admin,
+                config,
+                admin_role,
+                role_account: freezer_role,
+            },
+            sss_core::instruction::SetActionQuota { quota: Some(50) },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::set_role_expiry",
+        core_ix(
+            sss_core::accounts::SetRoleExpiry {
+                admin,
+                config,
+                admin_role,
+                role_account: freezer_role,
+            },
+            sss_core::instruction::SetRoleExpiry {
+                expires_at: Some(i64::MAX),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::freeze_account",
+        core_ix(
+            sss_core::accounts::FreezeTokenAccount {
+                freezer: admin,
+                config,
+                freezer_role,
+                mint,
+                token_account: holder_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::FreezeAccount {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::thaw_account",
+        core_ix(
+            sss_core::accounts::ThawTokenAccount {
+                freezer: admin,
+                config,
+                freezer_role,
+                mint,
+                token_account: holder_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::ThawAccount {},
+        ),
+        &[],
+    );
+
+    // Two accounts per batch; remaining accounts are (token account,
+    // exemption PDA) pairs for the freeze and bare token accounts for the thaw.
+    let batch_accounts = [(holder_ata, holder.pubkey()), (treasury_ata, admin)];
+    let mut ix = core_ix(
+        sss_core::accounts::FreezeTokenAccountsBatch {
+            freezer: admin,
+            config,
+            freezer_role,
+            mint,
+            token_program: spl_token_2022::ID,
+        },
+        sss_core::instruction::FreezeAccountsBatch {},
+    );
+    for (token_account, owner) in batch_accounts {
+        ix.accounts.push(AccountMeta::new(token_account, false));
+        ix.accounts.push(AccountMeta::new_readonly(
+            find_freeze_exemption_address(&config, &owner).0,
+            false,
+        ));
+    }
+    bench.measure("sss_core::freeze_accounts_batch", ix, &[]);
+    let mut ix = core_ix(
+        sss_core::accounts::ThawTokenAccountsBatch {
+            freezer: admin,
+            config,
+            freezer_role,
+            mint,
+            token_program: spl_token_2022::ID,
+        },
+        sss_core::instruction::ThawAccountsBatch {},
+    );
+    for (token_account, _) in batch_accounts {
+        ix.accounts.push(AccountMeta::new(token_account, false));
+    }
+    bench.measure("sss_core::thaw_accounts_batch", ix, &[]);
+
+    let freeze_exemption = find_freeze_exemption_address(&config, &holder.pubkey()).0;
+    bench.measure(
+        "sss_core::add_freeze_exemption",
+        core_ix(
+            sss_core::accounts::AddFreezeExemption {
+                admin,
+                config,
+                admin_role,
+                owner: holder.pubkey(),
+                credit_line: find_credit_line_address(&config, &holder.pubkey()).0,
+                freeze_exemption,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::AddFreezeExemption {},
+        ),
+        &[],
+    );
+    let freeze_ix = core_ix(
+        sss_core::accounts::FreezeTokenAccount {
+            freezer: admin,
+            config,
+            freezer_role,
+            mint,
+            token_account: holder_ata,
+            token_program: spl_token_2022::ID,
+        },
+        sss_core::instruction::FreezeAccount {},
+    );
+    bench.send(&[freeze_ix], &[]);
+    bench.measure(
+        "sss_core::thaw_exempt_account",
+        core_ix(
+            sss_core::accounts::ThawExemptAccount {
+                config,
+                mint,
+                token_account: holder_ata,
+                freeze_exemption,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::ThawExemptAccount {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::remove_freeze_exemption",
+        core_ix(
+            sss_core::accounts::RemoveFreezeExemption {
+                admin,
+                config,
+                admin_role,
+                freeze_exemption,
+            },
+            sss_core::instruction::RemoveFreezeExemption {},
+        ),
+        &[],
+    );
+
+    bench.measure(
+        "sss_core::seize",
+        core_ix(
+            sss_core::accounts::Seize {
+                seizer: admin,
+                config,
+                seizer_role: find_role_address(&config, &admin, Role::Seizer).0,
+                mint,
+                from: holder_ata,
+                to: treasury_ata,
+                token_program: spl_token_2022::ID,
+                event_queue: None,
+                seize_approval: None,
+                seizure_record: find_seizure_record_address(&config, 0).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::Seize {
+                amount: 100_000,
+                reason: "Court order 2026-CV-1142".to_string(),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::seize_idempotent",
+        core_ix(
+            sss_core::accounts::SeizeIdempotent {
+                seizure: sss_core::accounts::Seize {
+                    seizer: admin,
+                    config,
+                    seizer_role: find_role_address(&config, &admin, Role::Seizer).0,
+                    mint,
+                    from: holder_ata,
+                    to: treasury_ata,
+                    token_program: spl_token_2022::ID,
+                    event_queue: None,
+                    seize_approval: None,
+                    seizure_record: find_seizure_record_address(&config, 1).0,
+                    system_program: solana_sdk::system_program::ID,
+                },
+                consumed_nonce: find_consumed_nonce_address(&config, &admin, 3).0,
+                payer: admin,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::SeizeIdempotent {
+                amount: 100_000,
+                nonce: 3,
+                reason: "Court order 2026-CV-1142".to_string(),
+            },
+        ),
+        &[],
+    );
+
+    // A frozen account wiped by the Admin.
+    let wiped_ata = bench.create_token_account(&mint, &Pubkey::new_unique(), false);
+    let ixs = [
+        bench.mint_tokens_ix(&mint, &wiped_ata, 100_000),
+        core_ix(
+            sss_core::accounts::FreezeTokenAccount {
+                freezer: admin,
+                config,
+                freezer_role,
+                mint,
+                token_account: wiped_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::FreezeAccount {},
+        ),
+    ];
+    bench.send(&ixs, &[]);
+    bench.measure(
+        "sss_core::wipe_account",
+        core_ix(
+            sss_core::accounts::WipeAccount {
+                wiper: admin,
+                config,
+                wiper_role: admin_role,
+                mint,
+                token_account: wiped_ata,
+                token_program: spl_token_2022::ID,
+                event_queue: None,
+                wipe_record: find_wipe_record_address(&config, 0).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::WipeAccount {
+                reason: "OFAC SDN designation".to_string(),
+            },
+        ),
+        &[],
+    );
+
+    // Large seizures: a second Seizer approves one, which the seizure then
+    // consumes. A second approval is cancelled, and the policy lifted again.
+    bench.measure(
+        "sss_core::update_seize_policy",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateSeizePolicy {
+                threshold: Some(50_000),
+            },
+        ),
+        &[],
+    );
+    let ixs = bench.grant_role_ixs(&config, &cosigner.pubkey(), Role::Seizer);
+    bench.send(&ixs, &[&cosigner]);
+    let approve_seize_ix = |id: u64| {
+        core_ix(
+            sss_core::accounts::ApproveSeize {
+                approver: cosigner.pubkey(),
+                config,
+                approver_role: find_role_address(&config, &cosigner.pubkey(), Role::Seizer).0,
+                from: holder_ata,
+                to: treasury_ata,
+                seize_approval: find_seize_approval_address(&config, &cosigner.pubkey(), id).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::ApproveSeize {
+                id,
+                seizer: admin,
+                amount: 100_000,
+            },
+        )
+    };
+    bench.measure("sss_core::approve_seize", approve_seize_ix(0), &[&cosigner]);
+    bench.measure(
+        "sss_core::seize_approved",
+        core_ix(
+            sss_core::accounts::Seize {
+                seizer: admin,
+                config,
+                seizer_role: find_role_address(&config, &admin, Role::Seizer).0,
+                mint,
+                from: holder_ata,
+                to: treasury_ata,
+                token_program: spl_token_2022::ID,
+                event_queue: None,
+                seize_approval: Some(find_seize_approval_address(&config, &cosigner.pubkey(), 0).0),
+                seizure_record: find_seizure_record_address(&config, 2).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::Seize {
+                amount: 100_000,
+                reason: "Court order 2026-CV-1142".to_string(),
+            },
+        ),
+        &[],
+    );
+    bench.send(&[approve_seize_ix(1)], &[&cosigner]);
+    bench.measure(
+        "sss_core::cancel_seize_approval",
+        core_ix(
+            sss_core::accounts::CancelSeizeApproval {
+                approver: cosigner.pubkey(),
+                config,
+                seize_approval: find_seize_approval_address(&config, &cosigner.pubkey(), 1).0,
+            },
+            sss_core::instruction::CancelSeizeApproval {},
+        ),
+        &[&cosigner],
+    );
+    let ix = core_ix(
+        sss_core::accounts::UpdateSupplyCheck {
+            admin,
+            config,
+            admin_role,
+        },
+        sss_core::instruction::UpdateSeizePolicy { threshold: None },
+    );
+    bench.send(&[ix], &[]);
+
+    // The bench's token accounts are not ATAs, so the treasury is cleared
+    // again rather than seizing into it.
+    bench.measure(
+        "sss_core::update_treasury",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateTreasury {
+                treasury: Some(admin),
+            },
+        ),
+        &[],
+    );
+    let ix = core_ix(
+        sss_core::accounts::UpdateSupplyCheck {
+            admin,
+            config,
+            admin_role,
+        },
+        sss_core::instruction::UpdateTreasury { treasury: None },
+    );
+    bench.send(&[ix], &[]);
+
+    // Seizing a frozen account thaws and refreezes it around the transfer.
+    bench.measure(
+        "sss_core::update_seize_freeze_policy",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateSeizeFreezePolicy {
+                requires_frozen: true,
+            },
+        ),
+        &[],
+    );
+    let ix = core_ix(
+        sss_core::accounts::FreezeTokenAccount {
+            freezer: admin,
+            config,
+            freezer_role,
+            mint,
+            token_account: holder_ata,
+            token_program: spl_token_2022::ID,
+        },
+        sss_core::instruction::FreezeAccount {},
+    );
+    bench.send(&[ix], &[]);
+    bench.measure(
+        "sss_core::seize_frozen",
+        core_ix(
+            sss_core::accounts::Seize {
+                seizer: admin,
+                config,
+                seizer_role: find_role_address(&config, &admin, Role::Seizer).0,
+                mint,
+                from: holder_ata,
+                to: treasury_ata,
+                token_program: spl_token_2022::ID,
+                event_queue: None,
+                seize_approval: None,
+                seizure_record: find_seizure_record_address(&config, 3).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::Seize {
+                amount: 100_000,
+                reason: "Court order 2026-CV-1142".to_string(),
+            },
+        ),
+        &[],
+    );
+    let ixs = [
+        core_ix(
+            sss_core::accounts::ThawTokenAccount {
+                freezer: admin,
+                config,
+                freezer_role,
+                mint,
+                token_account: holder_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::ThawAccount {},
+        ),
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateSeizeFreezePolicy {
+                requires_frozen: false,
+            },
+        ),
+    ];
+    bench.send(&ixs, &[]);
+
+    // Tokens sent to the config address by mistake.
+    let config_ata = bench.create_token_account(&mint, &config, false);
+    let ix = bench.mint_tokens_ix(&mint, &config_ata, 50_000);
+    bench.send(&[ix], &[]);
+    bench.measure(
+        "sss_core::rescue_tokens",
+        core_ix(
+            sss_core::accounts::RescueTokens {
+                rescuer: admin,
+                config,
+                rescuer_role: find_role_address(&config, &admin, Role::Rescuer).0,
+                mint,
+                from: config_ata,
+                to: treasury_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::RescueTokens { amount: 50_000 },
+        ),
+        &[],
+    );
+
+    let event_queue = find_event_queue_address(&config).0;
+    bench.measure(
+        "sss_core::configure_event_queue",
+        core_ix(
+            sss_core::accounts::ConfigureEventQueue {
+                admin,
+                config,
+                admin_role,
+                event_queue,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::ConfigureEventQueue { enabled: true },
+        ),
+        &[],
+    );
+
+    // Any pause starts the ramp, so `unpause` below measures that path.
+    bench.measure(
+        "sss_core::update_thaw_ramp",
+        core_ix(
+            sss_core::accounts::UpdateThawRamp {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateThawRamp {
+                thaw_ramp: Some(ThawRamp {
+                    min_pause_slots: 0,
+                    ramp_slots: 1_000,
+                    start_bps: 5_000,
+                }),
+            },
+        ),
+        &[],
+    );
+
+    let pauser_role = find_role_address(&config, &admin, Role::Pauser).0;
+    bench.measure(
+        "sss_core::update_max_pause",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateMaxPause {
+                max_pause_secs: 3_600,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::pause",
+        core_ix(
+            sss_core::accounts::Pause {
+                pauser: admin,
+                config,
+                pauser_role,
+                event_queue: Some(event_queue),
+            },
+            sss_core::instruction::Pause {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::set_incident_status",
+        core_ix(
+            sss_core::accounts::SetIncidentStatus {
+                authority: admin,
+                config,
+                authority_role: pauser_role,
+            },
+            sss_core::instruction::SetIncidentStatus {
+                active: true,
+                uri: "https://status.example.com/incident.json".to_string(),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::renew_pause",
+        core_ix(
+            sss_core::accounts::RenewPause {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::RenewPause {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::unpause",
+        core_ix(
+            sss_core::accounts::Unpause {
+                pauser: admin,
+                config,
+                pauser_role,
+                event_queue: Some(event_queue),
+            },
+            sss_core::instruction::Unpause {},
+        ),
+        &[],
+    );
+    for (name, flags) in [
+        (
+            "sss_core::set_pause_flags",
+            sss_core::constants::pause::MINT,
+        ),
+        ("sss_core::set_pause_flags_clear", 0),
+    ] {
+        bench.measure(
+            name,
+            core_ix(
+                sss_core::accounts::SetPauseFlags {
+                    pauser: admin,
+                    config,
+                    pauser_role,
+                    event_queue: Some(event_queue),
+                },
+                sss_core::instruction::SetPauseFlags { flags },
+            ),
+            &[],
+        );
+    }
+
+    let ixs = bench.grant_role_ixs(&config, &holder.pubkey(), Role::Freezer);
+    bench.send(&ixs, &[&holder]);
+    bench.measure(
+        "sss_core::sync_role_directory",
+        core_ix(
+            sss_core::accounts::SyncRoleDirectory {
+                admin,
+                config,
+                admin_role,
+                role_account: find_role_address(&config, &holder.pubkey(), Role::Freezer).0,
+                role_directory: find_role_directory_address(&config, Role::Freezer).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::SyncRoleDirectory {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::revoke_role",
+        core_ix(
+            sss_core::accounts::RevokeRole {
+                admin,
+                config,
+                admin_role,
+                role_account: find_role_address(&config, &holder.pubkey(), Role::Freezer).0,
+                role_directory: find_role_directory_address(&config, Role::Freezer).0,
+            },
+            sss_core::instruction::RevokeRole {},
+        ),
+        &[],
+    );
+
+    let ixs = bench.grant_role_ixs(&config, &holder.pubkey(), Role::Burner);
+    bench.send(&ixs, &[&holder]);
+    let ix = bench.grant_role_ix(&config, &holder.pubkey(), Role::Seizer);
+    bench.send(&[ix], &[]);
+    bench.measure(
+        "sss_core::cancel_role_grant",
+        core_ix(
+            sss_core::accounts::CancelRoleGrant {
+                admin,
+                config,
+                admin_role,
+                pending_role: find_pending_role_address(&config, &holder.pubkey(), Role::Seizer).0,
+            },
+            sss_core::instruction::CancelRoleGrant {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::renounce_role",
+        core_ix(
+            sss_core::accounts::RenounceRole {
+                holder: holder.pubkey(),
+                config,
+                role_account: find_role_address(&config, &holder.pubkey(), Role::Burner).0,
+                role_directory: find_role_directory_address(&config, Role::Burner).0,
+            },
+            sss_core::instruction::RenounceRole {},
+        ),
+        &[&holder],
+    );
+
+    let regulator_access = find_regulator_access_address(&config, &holder.pubkey()).0;
+    bench.measure(
+        "sss_core::grant_regulator_access",
+        core_ix(
+            sss_core::accounts::GrantRegulatorAccess {
+                admin,
+                config,
+                admin_role,
+                regulator: holder.pubkey(),
+                regulator_access,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::GrantRegulatorAccess {
+                name: "Bench Regulator".to_string(),
+            },
+        ),
+        &[],
+    );
+    let report = find_report_address(&config, &holder.pubkey(), 202610).0;
+    bench.measure(
+        "sss_core::request_report",
+        core_ix(
+            sss_core::accounts::RequestReport {
+                regulator: holder.pubkey(),
+                config,
+                regulator_access,
+                report,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::RequestReport { period: 202610 },
+        ),
+        &[&holder],
+    );
+    bench.measure(
+        "sss_core::publish_report",
+        core_ix(
+            sss_core::accounts::PublishReport {
+                admin,
+                config,
+                admin_role,
+                report,
+            },
+            sss_core::instruction::PublishReport {
+                report_hash: [3u8; 32],
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::acknowledge_report",
+        core_ix(
+            sss_core::accounts::AcknowledgeReport {
+                regulator: holder.pubkey(),
+                config,
+                report,
+            },
+            sss_core::instruction::AcknowledgeReport {},
+        ),
+        &[&holder],
+    );
+    bench.measure(
+        "sss_core::revoke_regulator_access",
+        core_ix(
+            sss_core::accounts::RevokeRegulatorAccess {
+                admin,
+                config,
+                admin_role,
+                regulator_access,
+            },
+            sss_core::instruction::RevokeRegulatorAccess {},
+        ),
+        &[],
+    );
+
+    let slot = bench.svm.get_sysvar::<solana_sdk::clock::Clock>().slot;
+    let snapshot = find_snapshot_address(&config, slot).0;
+    bench.measure(
+        "sss_core::declare_snapshot",
+        core_ix(
+            sss_core::accounts::DeclareSnapshot {
+                admin,
+                config,
+                admin_role,
+                snapshot,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::DeclareSnapshot {
+                slot,
+                record_window_slots: 100,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::record_snapshot_balance",
+        core_ix(
+            sss_core::accounts::RecordSnapshotBalance {
+                payer: admin,
+                config,
+                snapshot,
+                mint,
+                token_account: holder_ata,
+                snapshot_balance: find_snapshot_balance_address(&snapshot, &holder_ata).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::RecordSnapshotBalance {},
+        ),
+        &[],
+    );
+
+    // Single-leaf tree: the root is the leaf and the proof is empty.
+    let distributor = find_distributor_address(&config, 1).0;
+    let merkle_root = sss_core::merkle::claim_leaf(0, &holder.pubkey(), 50_000);
+    bench.measure(
+        "sss_core::create_distributor",
+        core_ix(
+            sss_core::accounts::CreateDistributor {
+                admin,
+                config,
+                admin_role,
+                distributor,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::CreateDistributor {
+                id: 1,
+                merkle_root,
+                max_total: 50_000,
+                funding: DistributionFunding::Mint,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::claim_distribution",
+        core_ix(
+            sss_core::accounts::ClaimDistribution {
+                claimant: holder.pubkey(),
+                config,
+                distributor,
+                claim_status: find_claim_status_address(&distributor, 0).0,
+                mint,
+                destination: holder_ata,
+                claimant_blacklist: find_blacklist_address(&mint, &holder.pubkey()).0,
+                treasury: None,
+                token_program: spl_token_2022::ID,
+                system_program: solana_sdk::system_program::ID,
+                reserve_attestation: None,
+            },
+            sss_core::instruction::ClaimDistribution {
+                index: 0,
+                amount: 50_000,
+                proof: vec![],
+            },
+        ),
+        &[&holder],
+    );
+
+    // Par swap into a second stablecoin from the same issuer.
+    let target_mint = bench.create_mint(false);
+    let ix = bench.initialize_ix(&target_mint, 1);
+    bench.send(&[ix], &[]);
+    let target_config = find_config_address(&target_mint).0;
+    let target_ata = bench.create_token_account(&target_mint, &holder.pubkey(), false);
+    let swap_route = find_swap_route_address(&config, &target_config).0;
+    bench.measure(
+        "sss_core::create_swap_route",
+        core_ix(
+            sss_core::accounts::CreateSwapRoute {
+                admin,
+                from_config: config,
+                from_admin_role: admin_role,
+                to_config: target_config,
+                to_admin_role: find_role_address(&target_config, &admin, Role::Admin).0,
+                swap_route,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::CreateSwapRoute {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::par_swap",
+        core_ix(
+            sss_core::accounts::ParSwap {
+                holder: holder.pubkey(),
+                swap_route,
+                from_config: config,
+                from_mint: mint,
+                from_account: holder_ata,
+                from_blacklist: find_blacklist_address(&mint, &holder.pubkey()).0,
+                to_config: target_config,
+                to_mint: target_mint,
+                to_account: target_ata,
+                to_blacklist: find_blacklist_address(&target_mint, &holder.pubkey()).0,
+                token_program: spl_token_2022::ID,
+                reserve_attestation: None,
+            },
+            sss_core::instruction::ParSwap { amount: 10_000 },
+        ),
+        &[&holder],
+    );
+    bench.measure(
+        "sss_core::close_swap_route",
+        core_ix(
+            sss_core::accounts::CloseSwapRoute {
+                admin,
+                config,
+                admin_role,
+                swap_route,
+            },
+            sss_core::instruction::CloseSwapRoute {},
+        ),
+        &[],
+    );
+
+    // Collateral vault backed at par by the holder's second stablecoin.
+    let collateral_vault = find_collateral_vault_address(&config).0;
+    let vault_account = get_associated_token_address_with_program_id(
+        &collateral_vault,
+        &target_mint,
+        &spl_token_2022::ID,
+    );
+    bench.measure(
+        "sss_core::create_collateral_vault",
+        core_ix(
+            sss_core::accounts::CreateCollateralVault {
+                admin,
+                config,
+                admin_role,
+                collateral_mint: target_mint,
+                collateral_vault,
+                vault_account,
+                collateral_token_program: spl_token_2022::ID,
+                associated_token_program: associated_token::ID,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::CreateCollateralVault {
+                collateral_ratio_bps: 10_000,
+                collateral_feed_id: None,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::deposit_and_mint",
+        core_ix(
+            sss_core::accounts::DepositAndMint {
+                depositor: holder.pubkey(),
+                config,
+                mint,
+                to: holder_ata,
+                blacklist: find_blacklist_address(&mint, &holder.pubkey()).0,
+                collateral_vault,
+                position: find_collateral_position_address(&collateral_vault, &holder.pubkey()).0,
+                collateral_mint: target_mint,
+                depositor_collateral: target_ata,
+                vault_account,
+                price_update: None,
+                kyc_entry: None,
+                token_program: spl_token_2022::ID,
+                collateral_token_program: spl_token_2022::ID,
+                system_program: solana_sdk::system_program::ID,
+                fee_schedule: None,
+                fee_tier: None,
+                fee_treasury: None,
+            },
+            sss_core::instruction::DepositAndMint {
+                collateral_amount: 5_000,
+                min_mint_amount: 5_000,
+            },
+        ),
+        &[&holder],
+    );
+    bench.measure(
+        "sss_core::burn_and_withdraw",
+        core_ix(
+            sss_core::accounts::BurnAndWithdraw {
+                holder: holder.pubkey(),
+                config,
+                mint,
+                from: holder_ata,
+                blacklist: find_blacklist_address(&mint, &holder.pubkey()).0,
+                collateral_vault,
+                position: find_collateral_position_address(&collateral_vault, &holder.pubkey()).0,
+                collateral_mint: target_mint,
+                vault_account,
+                holder_collateral: target_ata,
+                token_program: spl_token_2022::ID,
+                collateral_token_program: spl_token_2022::ID,
+                fee_schedule: None,
+                fee_tier: None,
+                fee_treasury: None,
+            },
+            sss_core::instruction::BurnAndWithdraw { amount: 5_000 },
+        ),
+        &[&holder],
+    );
+
+    // Mint and burn fees on the second stablecoin, paid into a treasury
+    // account of the admin's.
+    let ixs = bench.grant_role_ixs(&target_config, &admin, Role::Minter);
+    bench.send(&ixs, &[]);
+    let target_admin_role = find_role_address(&target_config, &admin, Role::Admin).0;
+    let target_fee_schedule = find_fee_schedule_address(&target_config).0;
+    let fee_treasury = bench.create_token_account(&target_mint, &admin, false);
+    let ix = core_ix(
+        sss_core::accounts::SetFeeSchedule {
+            admin,
+            config: target_config,
+            admin_role: target_admin_role,
+            fee_schedule: target_fee_schedule,
+            system_program: solana_sdk::system_program::ID,
+        },
+        sss_core::instruction::SetFeeSchedule {
+            tiers: vec![FeeRates {
+                mint_bps: 30,
+                burn_bps: 30,
+                swap_bps: 0,
+                transfer_bps: 0,
+            }],
+        },
+    );
+    bench.send(&[ix], &[]);
+    bench.measure(
+        "sss_core::update_fee_treasury",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config: target_config,
+                admin_role: target_admin_role,
+            },
+            sss_core::instruction::UpdateFeeTreasury {
+                fee_treasury: Some(fee_treasury),
+            },
+        ),
+        &[],
+    );
+    let mut fee_mint = bench.mint_tokens_accounts(&target_mint, &target_ata);
+    let target_fee_tier = find_fee_tier_address(&target_config, &holder.pubkey()).0;
+    fee_mint.fee_schedule = Some(target_fee_schedule);
+    fee_mint.fee_tier = Some(target_fee_tier);
+    fee_mint.fee_treasury = Some(fee_treasury);
+    bench.measure(
+        "sss_core::mint_tokens_with_fee",
+        core_ix(
+            fee_mint,
+            sss_core::instruction::MintTokens { amount: 100_000 },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::redeem_with_fee",
+        core_ix(
+            sss_core::accounts::Redeem {
+                holder: holder.pubkey(),
+                config: target_config,
+                mint: target_mint,
+                from: target_ata,
+                token_program: spl_token_2022::ID,
+                fee_schedule: Some(target_fee_schedule),
+                fee_tier: Some(target_fee_tier),
+                fee_treasury: Some(fee_treasury),
+            },
+            sss_core::instruction::Redeem { amount: 10_000 },
+        ),
+        &[&holder],
+    );
+
+    bench.measure(
+        "sss_core::emit_issuer_event",
+        core_ix(
+            sss_core::accounts::EmitIssuerEvent {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::EmitIssuerEvent {
+                tag: "settlement-batch".to_string(),
+                data: vec![0u8; 64],
+            },
+        ),
+        &[],
+    );
+
+    bench.measure(
+        "sss_core::record_reserve_movement",
+        core_ix(
+            sss_core::accounts::RecordReserveMovement {
+                admin,
+                config,
+                admin_role,
+                journal: find_reserve_journal_address(&config).0,
+                movement: find_reserve_movement_address(&config, 0).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::RecordReserveMovement {
+                kind: ReserveMovementKind::CustodyDeposit,
+                amount: 1_000_000,
+                ref_hash: [7u8; 32],
+            },
+        ),
+        &[],
+    );
+
+    // Proof of reserves: an Attestor publishes, then the gate is measured on
+    // a mint and switched off again.
+    let attestor = bench.new_funded_keypair();
+    let ixs = bench.grant_role_ixs(&config, &attestor.pubkey(), Role::Attestor);
+    bench.send(&ixs, &[&attestor]);
+    let reserve_attestation = find_reserve_attestation_address(&config).0;
+    let now = bench
+        .svm
+        .get_sysvar::<solana_sdk::clock::Clock>()
+        .unix_timestamp;
+    bench.measure(
+        "sss_core::publish_reserve_attestation",
+        core_ix(
+            sss_core::accounts::PublishReserveAttestation {
+                attestor: attestor.pubkey(),
+                config,
+                attestor_role: find_role_address(&config, &attestor.pubkey(), Role::Attestor).0,
+                reserve_attestation,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::PublishReserveAttestation {
+                reserve_amount: u64::MAX,
+                attested_at: now,
+                report_hash: [7u8; 32],
+            },
+        ),
+        &[&attestor],
+    );
+    // The Attestor co-signs the first period close.
+    bench.measure(
+        "sss_core::export_period_summary",
+        core_ix(
+            sss_core::accounts::ExportPeriodSummary {
+                admin,
+                attestor: attestor.pubkey(),
+                config,
+                admin_role,
+                attestor_role: find_role_address(&config, &attestor.pubkey(), Role::Attestor).0,
+                period_summary: find_period_summary_address(&config, 0).0,
+                previous_summary: None,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::ExportPeriodSummary { period_id: 0 },
+        ),
+        &[&attestor],
+    );
+    let reserve_gate_ix = |max_attestation_age_secs| {
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateReserveGate {
+                max_attestation_age_secs,
+            },
+        )
+    };
+    bench.measure(
+        "sss_core::update_reserve_gate",
+        reserve_gate_ix(Some(86_400)),
+        &[],
+    );
+    let mut gated = bench.mint_tokens_accounts(&mint, &holder_ata);
+    gated.reserve_attestation = Some(reserve_attestation);
+    bench.measure(
+        "sss_core::mint_tokens_reserve_gated",
+        core_ix(gated, sss_core::instruction::MintTokens { amount: 1_000 }),
+        &[],
+    );
+    bench.send(&[reserve_gate_ix(None)], &[]);
+
+    // Treasury sweep of a fresh intake account, before the receivership
+    // below pauses the config.
+    let intake_ata = bench.create_token_account(&mint, &holder.pubkey(), false);
+    let sweep_route = find_sweep_route_address(&config, &intake_ata).0;
+    let ix = bench.mint_tokens_ix(&mint, &intake_ata, 5_000);
+    bench.send(&[ix], &[]);
+    bench.measure(
+        "sss_core::register_sweep_route",
+        core_ix(
+            sss_core::accounts::RegisterSweepRoute {
+                admin,
+                config,
+                admin_role,
+                intake_owner: holder.pubkey(),
+                intake: intake_ata,
+                treasury: treasury_ata,
+                sweep_route,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::RegisterSweepRoute {
+                threshold: 1_000,
+                min_interval: 3_600,
+            },
+        ),
+        &[&holder],
+    );
+    bench.measure(
+        "sss_core::sweep_intake",
+        core_ix(
+            sss_core::accounts::SweepIntake {
+                cranker: admin,
+                config,
+                sweep_route,
+                mint,
+                intake: intake_ata,
+                treasury: treasury_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::SweepIntake {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::close_sweep_route",
+        core_ix(
+            sss_core::accounts::CloseSweepRoute {
+                admin,
+                config,
+                admin_role,
+                sweep_route,
+            },
+            sss_core::instruction::CloseSweepRoute {},
+        ),
+        &[],
+    );
+
+    // Intraday credit: draw, partly repay, then default a day later.
+    let market_maker = bench.new_funded_keypair();
+    let market_maker_ata = bench.create_token_account(&mint, &market_maker.pubkey(), false);
+    let credit_line = find_credit_line_address(&config, &market_maker.pubkey()).0;
+    bench.measure(
+        "sss_core::set_credit_line",
+        core_ix(
+            sss_core::accounts::SetCreditLine {
+                admin,
+                config,
+                admin_role,
+                market_maker: market_maker.pubkey(),
+                freeze_exemption: find_freeze_exemption_address(&config, &market_maker.pubkey()).0,
+                credit_line,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::SetCreditLine {
+                limit: 100_000,
+                repay_cutoff: 86_400,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::draw_credit",
+        core_ix(
+            sss_core::accounts::DrawCredit {
+                market_maker: market_maker.pubkey(),
+                config,
+                credit_line,
+                mint,
+                to: market_maker_ata,
+                token_program: spl_token_2022::ID,
+                price_update: None,
+                kyc_entry: None,
+                destination_throttle: None,
+                reserve_attestation: None,
+                fee_schedule: None,
+                fee_tier: None,
+                fee_treasury: None,
+            },
+            sss_core::instruction::DrawCredit { amount: 60_000 },
+        ),
+        &[&market_maker],
+    );
+    bench.measure(
+        "sss_core::repay_credit",
+        core_ix(
+            sss_core::accounts::RepayCredit {
+                market_maker: market_maker.pubkey(),
+                config,
+                credit_line,
+                mint,
+                from: market_maker_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::RepayCredit { amount: 10_000 },
+        ),
+        &[&market_maker],
+    );
+    let mut clock = bench.svm.get_sysvar::<solana_sdk::clock::Clock>();
+    clock.unix_timestamp += 86_400;
+    bench.svm.set_sysvar(&clock);
+    bench.measure(
+        "sss_core::freeze_defaulted_credit",
+        core_ix(
+            sss_core::accounts::FreezeDefaultedCredit {
+                cranker: admin,
+                config,
+                credit_line,
+                mint,
+                token_account: market_maker_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::FreezeDefaultedCredit {},
+        ),
+        &[],
+    );
+
+    // Receivership: a single Admin meets a quorum of one. The receiver acts
+    // on a fresh account so earlier freezes and seizures don't interfere.
+    let receiver = bench.new_funded_keypair();
+    let receivership = find_receivership_address(&config).0;
+    let receivable_ata = bench.create_token_account(&mint, &holder.pubkey(), false);
+    let escrow_ata = bench.create_token_account(&mint, &receivership, false);
+    let ix = bench.mint_tokens_ix(&mint, &receivable_ata, 10_000);
+    bench.send(&[ix], &[]);
+    bench.measure(
+        "sss_core::register_receiver",
+        core_ix(
+            sss_core::accounts::RegisterReceiver {
+                admin,
+                config,
+                admin_role,
+                receiver: receiver.pubkey(),
+                receivership,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::RegisterReceiver { quorum: 1 },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::approve_receivership",
+        core_ix(
+            sss_core::accounts::ApproveReceivership {
+                admin,
+                config,
+                admin_role,
+                receivership,
+            },
+            sss_core::instruction::ApproveReceivership {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::receivership_seize",
+        core_ix(
+            sss_core::accounts::ReceivershipSeize {
+                receiver: receiver.pubkey(),
+                config,
+                receivership,
+                mint,
+                from: receivable_ata,
+                escrow: escrow_ata,
+                token_program: spl_token_2022::ID,
+                event_queue: Some(event_queue),
+                seizure_record: find_seizure_record_address(&config, 4).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::ReceivershipSeize {
+                amount: 10_000,
+                reason: "Receivership order 2026-BK-0310".to_string(),
+            },
+        ),
+        &[&receiver],
+    );
+    bench.measure(
+        "sss_core::receivership_freeze",
+        core_ix(
+            sss_core::accounts::ReceivershipFreeze {
+                receiver: receiver.pubkey(),
+                config,
+                receivership,
+                mint,
+                token_account: receivable_ata,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::ReceivershipFreeze {},
+        ),
+        &[&receiver],
+    );
+    bench.measure(
+        "sss_core::receivership_pause",
+        core_ix(
+            sss_core::accounts::ReceivershipPause {
+                receiver: receiver.pubkey(),
+                config,
+                receivership,
+                event_queue: Some(event_queue),
+            },
+            sss_core::instruction::ReceivershipPause {},
+        ),
+        &[&receiver],
+    );
+    bench.measure(
+        "sss_core::discharge_receivership",
+        core_ix(
+            sss_core::accounts::DischargeReceivership {
+                receiver: receiver.pubkey(),
+                config,
+                receivership,
+                registered_by: admin,
+            },
+            sss_core::instruction::DischargeReceivership {},
+        ),
+        &[&receiver],
+    );
+    let register_ix = core_ix(
+        sss_core::accounts::RegisterReceiver {
+            admin,
+            config,
+            admin_role,
+            receiver: receiver.pubkey(),
+            receivership,
+            system_program: solana_sdk::system_program::ID,
+        },
+        sss_core::instruction::RegisterReceiver { quorum: 1 },
+    );
+    bench.send(&[register_ix], &[]);
+    bench.measure(
+        "sss_core::deregister_receiver",
+        core_ix(
+            sss_core::accounts::DeregisterReceiver {
+                admin,
+                config,
+                admin_role,
+                receivership,
+            },
+            sss_core::instruction::DeregisterReceiver {},
+        ),
+        &[],
+    );
+
+    bench.measure(
+        "sss_core::open_destination_throttle",
+        core_ix(
+            sss_core::accounts::OpenDestinationThrottle {
+                payer: admin,
+                config,
+                token_account: holder_ata,
+                destination_throttle: find_destination_throttle_address(&config, &holder_ata).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::OpenDestinationThrottle {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_destination_mint_limit",
+        core_ix(
+            sss_core::accounts::UpdateDestinationMintLimit {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateDestinationMintLimit {
+                limit: Some(1_000_000),
+            },
+        ),
+        &[],
+    );
+
+    bench.measure(
+        "sss_core::update_supply_check",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateSupplyCheck {
+                halt_mint_on_mismatch: true,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::verify_supply",
+        core_ix(
+            sss_core::accounts::VerifySupply { config, mint },
+            sss_core::instruction::VerifySupply {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::reconcile_supply",
+        core_ix(
+            sss_core::accounts::ReconcileSupply {
+                reconciler: admin,
+                config,
+                mint,
+            },
+            sss_core::instruction::ReconcileSupply { adjust: true },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_authority_check",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateAuthorityCheck {
+                pause_on_anomaly: false,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::update_unpause_policy",
+        core_ix(
+            sss_core::accounts::UpdateSupplyCheck {
+                admin,
+                config,
+                admin_role,
+            },
+            sss_core::instruction::UpdateUnpausePolicy {
+                requires_admin: false,
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::verify_authorities",
+        core_ix(
+            sss_core::accounts::VerifyAuthorities {
+                config,
+                mint,
+                event_queue: Some(event_queue),
+            },
+            sss_core::instruction::VerifyAuthorities {},
+        ),
+        &[],
+    );
+
+    let fee_schedule = find_fee_schedule_address(&config).0;
+    let fee_tier = find_fee_tier_address(&config, &holder.pubkey()).0;
+    let retail = FeeRates {
+        mint_bps: 30,
+        burn_bps: 30,
+        swap_bps: 10,
+        transfer_bps: 0,
+    };
+    bench.measure(
+        "sss_core::set_fee_schedule",
+        core_ix(
+            sss_core::accounts::SetFeeSchedule {
+                admin,
+                config,
+                admin_role,
+                fee_schedule,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::SetFeeSchedule {
+                tiers: vec![retail, FeeRates::default()],
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::assign_fee_tier",
+        core_ix(
+            sss_core::accounts::AssignFeeTier {
+                admin,
+                config,
+                admin_role,
+                fee_schedule,
+                wallet: holder.pubkey(),
+                fee_tier,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::AssignFeeTier { tier: 1 },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::clear_fee_tier",
+        core_ix(
+            sss_core::accounts::ClearFeeTier {
+                admin,
+                config,
+                admin_role,
+                fee_tier,
+            },
+            sss_core::instruction::ClearFeeTier {},
+        ),
+        &[],
+    );
+
+    // Standby config: sync, then fail over once the delay has passed. A
+    // seizure on either side of the failover checks the restored config
+    // keeps the live seizure count.
+    let standby_config = find_standby_config_address(&config).0;
+    let failover_seize_ix = |index: u64| {
+        core_ix(
+            sss_core::accounts::Seize {
+                seizer: admin,
+                config,
+                seizer_role: find_role_address(&config, &admin, Role::Seizer).0,
+                mint,
+                from: holder_ata,
+                to: treasury_ata,
+                token_program: spl_token_2022::ID,
+                event_queue: Some(event_queue),
+                seize_approval: None,
+                seizure_record: find_seizure_record_address(&config, index).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::Seize {
+                amount: 1_000,
+                reason: "Court order 2026-CV-1142".to_string(),
+            },
+        )
+    };
+    bench.measure(
+        "sss_core::create_standby_config",
+        core_ix(
+            sss_core::accounts::CreateStandbyConfig {
+                admin,
+                config,
+                admin_role,
+                standby_config,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::CreateStandbyConfig {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::migrate_standby_config",
+        core_ix(
+            sss_core::accounts::MigrateStandbyConfig {
+                admin,
+                config,
+                admin_role,
+                standby_config,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::MigrateStandbyConfig {},
+        ),
+        &[],
+    );
+    bench.send(&[failover_seize_ix(5)], &[]);
+    bench.measure(
+        "sss_core::request_failover",
+        core_ix(
+            sss_core::accounts::ManageFailover {
+                admin,
+                config,
+                admin_role,
+                standby_config,
+            },
+            sss_core::instruction::RequestFailover {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::cancel_failover",
+        core_ix(
+            sss_core::accounts::ManageFailover {
+                admin,
+                config,
+                admin_role,
+                standby_config,
+            },
+            sss_core::instruction::CancelFailover {},
+        ),
+        &[],
+    );
+    let ix = core_ix(
+        sss_core::accounts::ManageFailover {
+            admin,
+            config,
+            admin_role,
+            standby_config,
+        },
+        sss_core::instruction::RequestFailover {},
+    );
+    bench.send(&[ix], &[]);
+    let mut clock = bench.svm.get_sysvar::<solana_sdk::clock::Clock>();
+    clock.unix_timestamp += sss_core::state::StandbyConfig::FAILOVER_DELAY_SECS;
+    bench.svm.set_sysvar(&clock);
+    bench.measure(
+        "sss_core::failover",
+        core_ix(
+            sss_core::accounts::Failover {
+                admin,
+                config,
+                admin_role,
+                standby_config,
+                mint,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::Failover {},
+        ),
+        &[],
+    );
+    bench.send(&[failover_seize_ix(6)], &[]);
+
+    // Admin timelock: enable it, queue and cancel a change, then queue one
+    // and execute it after the delay. Disabling it again also waits.
+    let delay_secs = 3_600;
+    bench.measure(
+        "sss_core::set_admin_timelock",
+        core_ix(
+            sss_core::accounts::SetAdminTimelock {
+                admin,
+                config,
+                admin_role,
+                pending_action: None,
+            },
+            sss_core::instruction::SetAdminTimelock { delay_secs },
+        ),
+        &[],
+    );
+    let cap_change = TimelockedAction::UpdateSupplyCap {
+        new_supply_cap: Some(3_000_000_000_000),
+    };
+    let cancelled_action = find_pending_action_address(&config, 0).0;
+    bench.measure(
+        "sss_core::queue_action",
+        core_ix(
+            sss_core::accounts::QueueAction {
+                admin,
+                config,
+                admin_role,
+                pending_action: cancelled_action,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::QueueAction {
+                id: 0,
+                action: cap_change.clone(),
+            },
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::cancel_action",
+        core_ix(
+            sss_core::accounts::CancelAction {
+                admin,
+                config,
+                admin_role,
+                pending_action: cancelled_action,
+            },
+            sss_core::instruction::CancelAction {},
+        ),
+        &[],
+    );
+    let cap_action = find_pending_action_address(&config, 1).0;
+    let disable_action = find_pending_action_address(&config, 2).0;
+    let queue_cap = core_ix(
+        sss_core::accounts::QueueAction {
+            admin,
+            config,
+            admin_role,
+            pending_action: cap_action,
+            system_program: solana_sdk::system_program::ID,
+        },
+        sss_core::instruction::QueueAction {
+            id: 1,
+            action: cap_change,
+        },
+    );
+    let queue_disable = core_ix(
+        sss_core::accounts::QueueAction {
+            admin,
+            config,
+            admin_role,
+            pending_action: disable_action,
+            system_program: solana_sdk::system_program::ID,
+        },
+        sss_core::instruction::QueueAction {
+            id: 2,
+            action: TimelockedAction::SetAdminTimelock { delay_secs: 0 },
+        },
+    );
+    bench.send(&[queue_cap, queue_disable], &[]);
+    let mut clock = bench.svm.get_sysvar::<solana_sdk::clock::Clock>();
+    clock.unix_timestamp += i64::from(delay_secs);
+    bench.svm.set_sysvar(&clock);
+    let execute_cap = core_ix(
+        sss_core::accounts::UpdateSupplyCap {
+            admin,
+            config,
+            admin_role,
+            pending_action: Some(cap_action),
+        },
+        sss_core::instruction::UpdateSupplyCap {
+            new_supply_cap: Some(3_000_000_000_000),
+        },
+    );
+    let execute_disable = core_ix(
+        sss_core::accounts::SetAdminTimelock {
+            admin,
+            config,
+            admin_role,
+            pending_action: Some(disable_action),
+        },
+        sss_core::instruction::SetAdminTimelock { delay_secs: 0 },
+    );
+    bench.send(&[execute_cap, execute_disable], &[]);
+
+    bench.measure(
+        "sss_core::view_config",
+        core_ix(
+            sss_core::accounts::ViewConfig { config },
+            sss_core::instruction::ViewConfig {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::view_role",
+        core_ix(
+            sss_core::accounts::ViewRole {
+                role_account: admin_role,
+            },
+            sss_core::instruction::ViewRole {},
+        ),
+        &[],
+    );
+
+    let new_authority = holder.pubkey();
+    bench.measure(
+        "sss_core::propose_authority",
+        core_ix(
+            sss_core::accounts::ProposeAuthority {
+                admin,
+                config,
+                admin_role,
+                new_authority,
+                pending_action: None,
+            },
+            sss_core::instruction::ProposeAuthority {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::accept_authority",
+        core_ix(
+            sss_core::accounts::AcceptAuthority {
+                new_authority,
+                config,
+                old_authority: admin,
+                old_admin_role: admin_role,
+                new_admin_role: find_role_address(&config, &new_authority, Role::Admin).0,
+                admin_directory: find_role_directory_address(&config, Role::Admin).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_core::instruction::AcceptAuthority {},
+        ),
+        &[&holder],
+    );
+
+    // Wind down a fresh stablecoin that never issued.
+    let retired_mint = bench.create_mint(false);
+    let ix = bench.initialize_ix(&retired_mint, 1);
+    bench.send(&[ix], &[]);
+    let retired_config = find_config_address(&retired_mint).0;
+    let retired_admin_role = find_role_address(&retired_config, &admin, Role::Admin).0;
+    bench.measure(
+        "sss_core::sunset",
+        core_ix(
+            sss_core::accounts::Sunset {
+                admin,
+                config: retired_config,
+                admin_role: retired_admin_role,
+                pending_action: None,
+            },
+            sss_core::instruction::Sunset {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_core::close_config",
+        core_ix(
+            sss_core::accounts::CloseConfig {
+                admin,
+                config: retired_config,
+                admin_role: retired_admin_role,
+                admin_directory: find_role_directory_address(&retired_config, Role::Admin).0,
+                mint: retired_mint,
+                token_program: spl_token_2022::ID,
+            },
+            sss_core::instruction::CloseConfig {},
+        ),
+        &[],
+    );
+
+    baseline::check(&bench.measured);
+}
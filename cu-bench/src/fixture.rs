@@ -0,0 +1,427 @@
+//! LiteSVM fixture: loads both programs and provides the account setup shared
+//! by the per-program benchmark sequences.
+
+use std::path::PathBuf;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_hook, ExtensionType},
+    state::{Account as TokenAccountState, Mint as MintState},
+};
+use litesvm::{types::FailedTransactionMetadata, LiteSVM};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use sss_core::address_book::AddressBook;
+use sss_core::pda::{
+    find_config_address, find_kyc_entry_address, find_pending_role_address, find_role_address,
+    find_role_directory_address,
+};
+use sss_core::state::Role;
+use sss_core::InitializeArgs;
+
+use crate::baseline::Measurements;
+
+pub const DECIMALS: u8 = 6;
+
+fn deploy_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("deploy")
+}
+
+pub struct Bench {
+    pub svm: LiteSVM,
+    /// Initial authority and fee payer; holds every role it grants itself.
+    pub authority: Keypair,
+    pub measured: Measurements,
+}
+
+impl Bench {
+    /// Returns `None` when the compiled programs are not present, unless the
+    /// `CI` environment variable is set: CI runs `anchor build` first, so a
+    /// missing artifact there is a broken build, not a reason to skip.
+    pub fn load() -> Option<Self> {
+        let core_so = deploy_dir().join("sss_core.so");
+        let hook_so = deploy_dir().join("sss_transfer_hook.so");
+        if !core_so.exists() || !hook_so.exists() {
+            assert!(
+                std::env::var_os("CI").is_none(),
+                "CU benchmarks need {} and {}; `anchor build` did not produce them",
+                core_so.display(),
+                hook_so.display()
+            );
+            println!(
+                "skipping LiteSVM run: `anchor build` must produce {}",
+                deploy_dir().display()
+            );
+            return None;
+        }
+
+        let mut svm = LiteSVM::new();
+        svm.add_program_from_file(sss_core::ID, core_so)
+            .expect("load sss_core.so");
+        svm.add_program_from_file(sss_transfer_hook::ID, hook_so)
+            .expect("load sss_transfer_hook.so");
+
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 100_000_000_000)
+            .expect("airdrop");
+
+        Some(Self {
+            svm,
+            authority,
+            measured: Measurements::new(),
+        })
+    }
+
+    /// Send `ixs` paid by the authority, returning the compute units consumed
+    /// or the failed transaction for callers that expect rejections.
+    pub fn try_send(
+        &mut self,
+        ixs: &[Instruction],
+        extra_signers: &[&Keypair],
+    ) -> Result<u64, FailedTransactionMetadata> {
+        let mut signers: Vec<&Keypair> = vec![&self.authority];
+        signers.extend_from_slice(extra_signers);
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&self.authority.pubkey()),
+            &signers,
+            self.svm.latest_blockhash(),
+        );
+        let result = self.svm.send_transaction(tx);
+        self.svm.expire_blockhash();
+        result.map(|meta| meta.compute_units_consumed)
+    }
+
+    /// Send `ixs` paid by the authority and return the compute units consumed.
+    pub fn send(&mut self, ixs: &[Instruction], extra_signers: &[&Keypair]) -> u64 {
+        self.try_send(ixs, extra_signers)
+            .unwrap_or_else(|e| panic!("transaction failed: {:?}\n{}", e.err, e.meta.pretty_logs()))
+    }
+
+    /// Send a single instruction and record its CU usage under `name`.
+    pub fn measure(&mut self, name: &str, ix: Instruction, extra_signers: &[&Keypair]) {
+        let cu = self.send(&[ix], extra_signers);
+        self.measured.insert(name.to_string(), cu);
+    }
+
+    pub fn new_funded_keypair(&mut self) -> Keypair {
+        let kp = Keypair::new();
+        self.svm
+            .airdrop(&kp.pubkey(), 10_000_000_000)
+            .expect("airdrop");
+        kp
+    }
+
+    /// Create a Token-2022 mint whose mint/freeze authority and permanent
+    /// delegate are the config PDA, optionally attaching the transfer hook.
+    pub fn create_mint(&mut self, with_hook: bool) -> Pubkey {
+        let mint = Keypair::new();
+        let (config, _) = find_config_address(&mint.pubkey());
+
+        let mut extensions = vec![ExtensionType::PermanentDelegate];
+        if with_hook {
+            extensions.push(ExtensionType::TransferHook);
+        }
+        let space =
+            ExtensionType::try_calculate_account_len::<MintState>(&extensions).expect("mint size");
+        let lamports = self.svm.minimum_balance_for_rent_exemption(space);
+
+        let mut ixs = vec![
+            system_instruction::create_account(
+                &self.authority.pubkey(),
+                &mint.pubkey(),
+                lamports,
+                space as u64,
+                &spl_token_2022::ID,
+            ),
+            spl_token_2022::instruction::initialize_permanent_delegate(
+                &spl_token_2022::ID,
+                &mint.pubkey(),
+                &config,
+            )
+            .unwrap(),
+        ];
+        if with_hook {
+            ixs.push(
+                transfer_hook::instruction::initialize(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                    Some(config),
+                    Some(sss_transfer_hook::ID),
+                )
+                .unwrap(),
+            );
+        }
+        ixs.push(
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::ID,
+                &mint.pubkey(),
+                &config,
+                Some(&config),
+                DECIMALS,
+            )
+            .unwrap(),
+        );
+
+        self.send(&ixs, &[&mint]);
+        mint.pubkey()
+    }
+
+    /// Create a plain Token-2022 account for `owner`. Mints with a transfer
+    /// hook need the `TransferHookAccount` extension on every token account.
+    pub fn create_token_account(
+        &mut self,
+        mint: &Pubkey,
+        owner: &Pubkey,
+        with_hook: bool,
+    ) -> Pubkey {
+        let account = Keypair::new();
+        let extensions = if with_hook {
+            vec![ExtensionType::TransferHookAccount]
+        } else {
+            vec![]
+        };
+        let space = ExtensionType::try_calculate_account_len::<TokenAccountState>(&extensions)
+            .expect("account size");
+        let lamports = self.svm.minimum_balance_for_rent_exemption(space);
+
+        let ixs = [
+            system_instruction::create_account(
+                &self.authority.pubkey(),
+                &account.pubkey(),
+                lamports,
+                space as u64,
+                &spl_token_2022::ID,
+            ),
+            spl_token_2022::instruction::initialize_account3(
+                &spl_token_2022::ID,
+                &account.pubkey(),
+                mint,
+                owner,
+            )
+            .unwrap(),
+        ];
+        self.send(&ixs, &[&account]);
+        account.pubkey()
+    }
+
+    pub fn initialize_ix(&self, mint: &Pubkey, preset: u8) -> Instruction {
+        let (config, _) = find_config_address(mint);
+        let (admin_role, _) = find_role_address(&config, &self.authority.pubkey(), Role::Admin);
+        Instruction {
+            program_id: sss_core::ID,
+            accounts: sss_core::accounts::Initialize {
+                authority: self.authority.pubkey(),
+                config,
+                mint: *mint,
+                admin_role,
+                admin_directory: find_role_directory_address(&config, Role::Admin).0,
+                token_program: spl_token_2022::ID,
+                system_program: solana_sdk::system_program::ID,
+                deployment_counter: None,
+            }
+            .to_account_metas(None),
+            data: sss_core::instruction::Initialize {
+                args: Self::initialize_args(preset),
+            }
+            .data(),
+        }
+    }
+
+    /// `initialize_with_mint` for a fresh `mint` keypair, which must sign.
+    pub fn initialize_with_mint_ix(&self, mint: &Pubkey, preset: u8) -> Instruction {
+        let (config, _) = find_config_address(mint);
+        let (admin_role, _) = find_role_address(&config, &self.authority.pubkey(), Role::Admin);
+        Instruction {
+            program_id: sss_core::ID,
+            accounts: sss_core::accounts::InitializeWithMint {
+                authority: self.authority.pubkey(),
+                config,
+                mint: *mint,
+                admin_role,
+                admin_directory: find_role_directory_address(&config, Role::Admin).0,
+                token_program: spl_token_2022::ID,
+                system_program: solana_sdk::system_program::ID,
+                deployment_counter: None,
+            }
+            .to_account_metas(None),
+            data: sss_core::instruction::InitializeWithMint {
+                args: Self::initialize_args(preset),
+            }
+            .data(),
+        }
+    }
+
+    fn initialize_args(preset: u8) -> InitializeArgs {
+        InitializeArgs {
+            preset,
+            name: "Bench USD".to_string(),
+            symbol: "BUSD".to_string(),
+            uri: String::new(),
+            decimals: DECIMALS,
+            supply_cap: Some(1_000_000_000_000),
+            enable_permanent_delegate: None,
+            enable_transfer_hook: None,
+            default_account_frozen: None,
+            oracle_feed_id: None,
+        }
+    }
+
+    pub fn grant_role_ix(&self, config: &Pubkey, grantee: &Pubkey, role: Role) -> Instruction {
+        let admin = self.authority.pubkey();
+        Instruction {
+            program_id: sss_core::ID,
+            accounts: sss_core::accounts::GrantRole {
+                admin,
+                config: *config,
+                admin_role: find_role_address(config, &admin, Role::Admin).0,
+                grantee: *grantee,
+                pending_role: find_pending_role_address(config, grantee, role).0,
+                system_program: solana_sdk::system_program::ID,
+                pending_action: None,
+            }
+            .to_account_metas(None),
+            data: sss_core::instruction::GrantRole { role: role.as_u8() }.data(),
+        }
+    }
+
+    /// `grantee` taking up a role offered by `grant_role_ix`. The grantee
+    /// signs.
+    pub fn accept_role_ix(&self, config: &Pubkey, grantee: &Pubkey, role: Role) -> Instruction {
+        Instruction {
+            program_id: sss_core::ID,
+            accounts: sss_core::accounts::AcceptRole {
+                grantee: *grantee,
+                config: *config,
+                pending_role: find_pending_role_address(config, grantee, role).0,
+                role_account: find_role_address(config, grantee, role).0,
+                role_directory: find_role_directory_address(config, role).0,
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sss_core::instruction::AcceptRole {}.data(),
+        }
+    }
+
+    /// Grant `role` to `grantee` and accept it, in one transaction the
+    /// grantee must sign.
+    pub fn grant_role_ixs(
+        &self,
+        config: &Pubkey,
+        grantee: &Pubkey,
+        role: Role,
+    ) -> [Instruction; 2] {
+        [
+            self.grant_role_ix(config, grantee, role),
+            self.accept_role_ix(config, grantee, role),
+        ]
+    }
+
+    /// `mint_tokens` accounts for the authority minting into `to`.
+    pub fn mint_tokens_accounts(
+        &self,
+        mint: &Pubkey,
+        to: &Pubkey,
+    ) -> sss_core::accounts::MintTokens {
+        let (config, _) = find_config_address(mint);
+        let minter = self.authority.pubkey();
+        sss_core::accounts::MintTokens {
+            minter,
+            config,
+            minter_role: find_role_address(&config, &minter, Role::Minter).0,
+            mint: *mint,
+            to: *to,
+            token_program: spl_token_2022::ID,
+            price_update: None,
+            kyc_entry: None,
+            destination_throttle: None,
+            mint_approval: None,
+            reserve_attestation: None,
+            fee_schedule: None,
+            fee_tier: None,
+            fee_treasury: None,
+        }
+    }
+
+    pub fn mint_tokens_ix(&self, mint: &Pubkey, to: &Pubkey, amount: u64) -> Instruction {
+        Instruction {
+            program_id: sss_core::ID,
+            accounts: self.mint_tokens_accounts(mint, to).to_account_metas(None),
+            data: sss_core::instruction::MintTokens { amount }.data(),
+        }
+    }
+
+    /// `approve_kyc` by the authority (which must hold Blacklister) for `owner`.
+    pub fn approve_kyc_ix(&self, config: &Pubkey, owner: &Pubkey) -> Instruction {
+        let blacklister = self.authority.pubkey();
+        Instruction {
+            program_id: sss_core::ID,
+            accounts: sss_core::accounts::ApproveKyc {
+                blacklister,
+                config: *config,
+                blacklister_role: find_role_address(config, &blacklister, Role::Blacklister).0,
+                owner: *owner,
+                kyc_entry: find_kyc_entry_address(config, owner).0,
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sss_core::instruction::ApproveKyc { expires_at: None }.data(),
+        }
+    }
+
+    /// `mint_tokens` on an SSS-2 mint, passing the `KycEntry` of `owner`
+    /// (the owner of `to`).
+    pub fn mint_tokens_kyc_ix(
+        &self,
+        mint: &Pubkey,
+        to: &Pubkey,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let mut accounts = self.mint_tokens_accounts(mint, to);
+        accounts.kyc_entry = Some(find_kyc_entry_address(&accounts.config, owner).0);
+        Instruction {
+            program_id: sss_core::ID,
+            accounts: accounts.to_account_metas(None),
+            data: sss_core::instruction::MintTokens { amount }.data(),
+        }
+    }
+
+    /// `transfer_checked` on a hooked mint with the extra accounts Token-2022
+    /// resolves from the ExtraAccountMetaList appended, in list order, as
+    /// derived by `AddressBook::transfer_hook_accounts`.
+    pub fn hook_transfer_ix(
+        &self,
+        mint: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+        sender: &Pubkey,
+        receiver: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let mut transfer = spl_token_2022::instruction::transfer_checked(
+            &spl_token_2022::ID,
+            source,
+            mint,
+            destination,
+            sender,
+            &[],
+            amount,
+            DECIMALS,
+        )
+        .unwrap();
+        transfer
+            .accounts
+            .extend(AddressBook::for_mint(*mint).transfer_hook_accounts(source, sender, receiver));
+        transfer
+    }
+}
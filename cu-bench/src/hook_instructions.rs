@@ -0,0 +1,400 @@
+//! CU benchmarks for every `sss-transfer-hook` instruction on an SSS-2 mint,
+//! including a full Token-2022 `transfer_checked` routed through `fallback`.
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Signer};
+use sss_core::pda::{find_config_address, find_role_address};
+use sss_core::state::Role;
+use sss_transfer_hook::pda::{
+    find_account_hold_address, find_action_counter_address, find_blacklist_address,
+    find_enforcement_queue_address, find_extra_account_metas_address, find_hook_config_address,
+    find_hook_event_queue_address, find_risk_score_address,
+};
+use sss_transfer_hook::state::enforcement_action;
+
+use crate::baseline;
+use crate::fixture::Bench;
+
+fn hook_ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: sss_transfer_hook::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+#[test]
+fn hook_compute_units() {
+    let Some(mut bench) = Bench::load() else {
+        return;
+    };
+
+    let mint = bench.create_mint(true);
+    let (config, _) = find_config_address(&mint);
+    let admin = bench.authority.pubkey();
+
+    let ix = bench.initialize_ix(&mint, 2);
+    bench.send(&[ix], &[]);
+    for role in [Role::Minter, Role::Blacklister] {
+        let ixs = bench.grant_role_ixs(&config, &admin, role);
+        bench.send(&ixs, &[]);
+    }
+
+    let (extra_account_metas, _) = find_extra_account_metas_address(&mint);
+    bench.measure(
+        "sss_transfer_hook::initialize_extra_account_metas",
+        hook_ix(
+            sss_transfer_hook::accounts::InitializeExtraAccountMetas {
+                payer: admin,
+                extra_account_metas,
+                mint,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_transfer_hook::instruction::InitializeExtraAccountMetas {},
+        ),
+        &[],
+    );
+
+    let admin_role = find_role_address(&config, &admin, Role::Admin).0;
+    bench.measure(
+        "sss_transfer_hook::update_extra_account_metas",
+        hook_ix(
+            sss_transfer_hook::accounts::UpdateExtraAccountMetas {
+                admin,
+                admin_role,
+                extra_account_metas,
+                mint,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_transfer_hook::instruction::UpdateExtraAccountMetas {},
+        ),
+        &[],
+    );
+
+    // Screen in flag-only mode so the transfers below still succeed.
+    let hook_config = find_hook_config_address(&mint).0;
+    bench.measure(
+        "sss_transfer_hook::configure_screening",
+        hook_ix(
+            sss_transfer_hook::accounts::ConfigureScreening {
+                admin,
+                admin_role,
+                mint,
+                config,
+                hook_config,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_transfer_hook::instruction::ConfigureScreening {
+                screening_provider: Some(admin),
+                max_risk_score: 50,
+                block_high_risk: false,
+            },
+        ),
+        &[],
+    );
+
+    // The US money transmitter pack is flag-only too, so the scored
+    // transfers below still succeed.
+    bench.measure(
+        "sss_transfer_hook::apply_rule_pack",
+        hook_ix(
+            sss_transfer_hook::accounts::ConfigureScreening {
+                admin,
+                admin_role,
+                mint,
+                config,
+                hook_config,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_transfer_hook::instruction::ApplyRulePack {
+                pack: sss_transfer_hook::state::RulePack::UsMoneyTransmitter,
+                screening_provider: Some(admin),
+            },
+        ),
+        &[],
+    );
+
+    let event_queue = find_hook_event_queue_address(&mint).0;
+    bench.measure(
+        "sss_transfer_hook::initialize_event_queue",
+        hook_ix(
+            sss_transfer_hook::accounts::InitializeEventQueue {
+                admin,
+                admin_role,
+                mint,
+                config,
+                event_queue,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_transfer_hook::instruction::InitializeEventQueue {},
+        ),
+        &[],
+    );
+
+    let enforcement_queue = find_enforcement_queue_address(&mint).0;
+    bench.measure(
+        "sss_transfer_hook::initialize_enforcement_queue",
+        hook_ix(
+            sss_transfer_hook::accounts::InitializeEnforcementQueue {
+                admin,
+                admin_role,
+                mint,
+                config,
+                enforcement_queue,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_transfer_hook::instruction::InitializeEnforcementQueue {
+                actions: enforcement_action::ALL,
+                notifier: admin,
+            },
+        ),
+        &[],
+    );
+
+    let blacklister_role = find_role_address(&config, &admin, Role::Blacklister).0;
+    let flagged = Pubkey::new_unique();
+    let flagged_entry = find_blacklist_address(&mint, &flagged).0;
+    bench.measure(
+        "sss_transfer_hook::open_action_counter",
+        hook_ix(
+            sss_transfer_hook::accounts::OpenActionCounter {
+                payer: admin,
+                mint,
+                blacklister: admin,
+                action_counter: find_action_counter_address(&mint, &admin).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_transfer_hook::instruction::OpenActionCounter {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_transfer_hook::add_to_blacklist",
+        hook_ix(
+            sss_transfer_hook::accounts::AddToBlacklist {
+                blacklister: admin,
+                blacklister_role,
+                mint,
+                config,
+                address: flagged,
+                blacklist_entry: flagged_entry,
+                system_program: solana_sdk::system_program::ID,
+                event_queue: Some(event_queue),
+                action_counter: None,
+                enforcement_queue: Some(enforcement_queue),
+            },
+            sss_transfer_hook::instruction::AddToBlacklist {
+                reason: "OFAC-REF-0001".to_string(),
+            },
+        ),
+        &[],
+    );
+
+    // Keeper follow-up on the queued entry (seq 1): freeze, then attest both.
+    let flagged_account = bench.create_token_account(&mint, &flagged, true);
+    bench.measure(
+        "sss_core::freeze_blacklisted",
+        Instruction {
+            program_id: sss_core::ID,
+            accounts: sss_core::accounts::FreezeBlacklisted {
+                cranker: admin,
+                config,
+                mint,
+                token_account: flagged_account,
+                blacklist_entry: flagged_entry,
+                token_program: spl_token_2022::ID,
+            }
+            .to_account_metas(None),
+            data: sss_core::instruction::FreezeBlacklisted {}.data(),
+        },
+        &[],
+    );
+    for (name, action, token_account) in [
+        (
+            "sss_transfer_hook::complete_enforcement_action (freeze)",
+            enforcement_action::FREEZE_ACCOUNTS,
+            Some(flagged_account),
+        ),
+        (
+            "sss_transfer_hook::complete_enforcement_action (notify)",
+            enforcement_action::NOTIFY_PARTNERS,
+            None,
+        ),
+    ] {
+        bench.measure(
+            name,
+            hook_ix(
+                sss_transfer_hook::accounts::CompleteEnforcementAction {
+                    keeper: admin,
+                    mint,
+                    config,
+                    enforcement_queue,
+                    token_account,
+                },
+                sss_transfer_hook::instruction::CompleteEnforcementAction { seq: 1, action },
+            ),
+            &[],
+        );
+    }
+    bench.measure(
+        "sss_transfer_hook::view_blacklist_entry",
+        hook_ix(
+            sss_transfer_hook::accounts::ViewBlacklistEntry {
+                blacklist_entry: flagged_entry,
+            },
+            sss_transfer_hook::instruction::ViewBlacklistEntry {},
+        ),
+        &[],
+    );
+    bench.measure(
+        "sss_transfer_hook::remove_from_blacklist",
+        hook_ix(
+            sss_transfer_hook::accounts::RemoveFromBlacklist {
+                blacklister: admin,
+                blacklister_role,
+                mint,
+                config,
+                blacklist_entry: flagged_entry,
+            },
+            sss_transfer_hook::instruction::RemoveFromBlacklist {},
+        ),
+        &[],
+    );
+    let now = bench
+        .svm
+        .get_sysvar::<solana_sdk::clock::Clock>()
+        .unix_timestamp;
+    bench.measure(
+        "sss_transfer_hook::schedule_blacklist",
+        hook_ix(
+            sss_transfer_hook::accounts::AddToBlacklist {
+                blacklister: admin,
+                blacklister_role,
+                mint,
+                config,
+                address: flagged,
+                blacklist_entry: flagged_entry,
+                system_program: solana_sdk::system_program::ID,
+                event_queue: Some(event_queue),
+                action_counter: None,
+                enforcement_queue: None,
+            },
+            sss_transfer_hook::instruction::ScheduleBlacklist {
+                reason: "OFAC-REF-0001".to_string(),
+                effective_at: now + 86_400,
+            },
+        ),
+        &[],
+    );
+    let ix = hook_ix(
+        sss_transfer_hook::accounts::RemoveFromBlacklist {
+            blacklister: admin,
+            blacklister_role,
+            mint,
+            config,
+            blacklist_entry: flagged_entry,
+        },
+        sss_transfer_hook::instruction::RemoveFromBlacklist {},
+    );
+    bench.send(&[ix], &[]);
+
+    let sender = bench.new_funded_keypair();
+    let receiver = Pubkey::new_unique();
+    let source = bench.create_token_account(&mint, &sender.pubkey(), true);
+    let destination = bench.create_token_account(&mint, &receiver, true);
+    let ix = bench.approve_kyc_ix(&config, &sender.pubkey());
+    bench.send(&[ix], &[]);
+    let ix = bench.mint_tokens_kyc_ix(&mint, &source, &sender.pubkey(), 1_000_000);
+    bench.send(&[ix], &[]);
+
+    bench.measure(
+        "sss_transfer_hook::set_risk_score",
+        hook_ix(
+            sss_transfer_hook::accounts::SetRiskScore {
+                provider: admin,
+                mint,
+                config,
+                hook_config,
+                wallet: receiver,
+                risk_score: find_risk_score_address(&mint, &receiver).0,
+                system_program: solana_sdk::system_program::ID,
+            },
+            sss_transfer_hook::instruction::SetRiskScore { score: 90 },
+        ),
+        &[],
+    );
+
+    // A hold on the receiving account does not stop the transfers below.
+    let destination_hold = find_account_hold_address(&mint, &destination).0;
+    bench.measure(
+        "sss_transfer_hook::place_account_hold",
+        hook_ix(
+            sss_transfer_hook::accounts::PlaceAccountHold {
+                blacklister: admin,
+                blacklister_role,
+                mint,
+                config,
+                token_account: destination,
+                account_hold: destination_hold,
+                system_program: solana_sdk::system_program::ID,
+                action_counter: None,
+            },
+            sss_transfer_hook::instruction::PlaceAccountHold {
+                expires_at: now + 86_400,
+                reason_code: 1,
+            },
+        ),
+        &[],
+    );
+
+    let sender_blacklist = find_blacklist_address(&mint, &sender.pubkey()).0;
+    let receiver_blacklist = find_blacklist_address(&mint, &receiver).0;
+
+    // Direct invocation: the hook's own cost without Token-2022 overhead.
+    bench.measure(
+        "sss_transfer_hook::transfer_hook",
+        hook_ix(
+            sss_transfer_hook::accounts::TransferHook {
+                source,
+                mint,
+                destination,
+                authority: sender.pubkey(),
+                extra_account_metas,
+                sender_blacklist,
+                receiver_blacklist,
+                config,
+            },
+            sss_transfer_hook::instruction::TransferHook { amount: 1 },
+        ),
+        &[],
+    );
+
+    // Full path: Token-2022 resolves the extra metas and enters `fallback`.
+    // The receiver is over the screening threshold, so this includes a flag.
+    let transfer = bench.hook_transfer_ix(
+        &mint,
+        &source,
+        &destination,
+        &sender.pubkey(),
+        &receiver,
+        1_000,
+    );
+    bench.measure("token_2022::transfer_checked (hook)", transfer, &[&sender]);
+
+    bench.measure(
+        "sss_transfer_hook::lift_account_hold",
+        hook_ix(
+            sss_transfer_hook::accounts::LiftAccountHold {
+                blacklister: admin,
+                blacklister_role,
+                config,
+                account_hold: destination_hold,
+            },
+            sss_transfer_hook::instruction::LiftAccountHold {},
+        ),
+        &[],
+    );
+
+    baseline::check(&bench.measured);
+}
@@ -0,0 +1,32 @@
+//! Compute-unit regression benchmarks for the SSS programs.
+//!
+//! Every instruction of `sss-core` and `sss-transfer-hook` is executed once in
+//! LiteSVM against the compiled programs in `target/deploy/`, and the compute
+//! units consumed are compared against `cu-bench/baseline.json`:
+//!
+//! - An instruction that uses more than the baseline plus the threshold
+//!   (default 5%, override with `CU_REGRESSION_THRESHOLD_PCT`) fails the run.
+//! - Instructions missing from the baseline are reported but do not fail.
+//! - `UPDATE_CU_BASELINE=1` rewrites the baseline with the measured values.
+//!
+//! Run after `anchor build`:
+//!
+//! ```text
+//! cargo test -p sss-cu-bench -- --nocapture
+//! UPDATE_CU_BASELINE=1 cargo test -p sss-cu-bench
+//! ```
+//!
+//! Every run also merges its measurements into `target/cu-bench/measured.json`,
+//! which CI uploads as a baseline candidate.
+//!
+//! When the `.so` artifacts are missing the benchmarks are skipped, so
+//! `cargo test --workspace` stays usable without the SBF toolchain. With `CI`
+//! set a missing artifact fails the run instead.
+
+pub mod baseline;
+pub mod fixture;
+
+#[cfg(test)]
+mod core_instructions;
+#[cfg(test)]
+mod hook_instructions;
@@ -0,0 +1,68 @@
+//! Table-driven transfer-hook scenarios.
+//!
+//! Each file in `hook-corpus/scenarios/` describes one Token-2022
+//! `transfer_checked` on a fresh SSS-2 mint: the policy state to set up
+//! (blacklisted sender or receiver, paused protocol, sender balance) and the
+//! expected outcome. The corpus runs through the real hook in LiteSVM, so new
+//! regression cases only need a JSON file:
+//!
+//! ```json
+//! {
+//!   "name": "blacklisted sender is rejected",
+//!   "sender_blacklisted": true,
+//!   "amount": 1000,
+//!   "expect": "sender_blacklisted"
+//! }
+//! ```
+//!
+//! `expect` is one of `allow`, `sender_blacklisted`, `receiver_blacklisted`,
+//! `protocol_paused` or `insufficient_funds`. Fields the hook does not enforce
+//! are rejected at load time.
+//!
+//! Run after `anchor build`:
+//!
+//! ```text
+//! cargo test -p sss-hook-corpus -- --nocapture
+//! ```
+//!
+//! Like the CU benchmarks, the run is skipped when the `.so` artifacts are
+//! missing.
+
+pub mod runner;
+pub mod scenario;
+
+#[cfg(test)]
+mod corpus {
+    use sss_cu_bench::fixture::Bench;
+
+    use crate::runner::run;
+    use crate::scenario::{corpus_dir, load_corpus};
+
+    #[test]
+    fn hook_scenarios() {
+        let corpus = load_corpus(&corpus_dir());
+        let mut failures = Vec::new();
+
+        for (file, scenario) in &corpus {
+            let Some(bench) = Bench::load() else {
+                return;
+            };
+            match run(bench, scenario) {
+                Ok(outcome) if outcome == scenario.expect => {
+                    println!("ok    {file}: {}", scenario.name);
+                }
+                Ok(outcome) => failures.push(format!(
+                    "{file} ({}): expected {:?}, got {outcome:?}",
+                    scenario.name, scenario.expect
+                )),
+                Err(err) => failures.push(format!("{file} ({}): {err}", scenario.name)),
+            }
+        }
+
+        assert!(
+            failures.is_empty(),
+            "Hook scenario failures:\n{}",
+            failures.join("\n")
+        );
+    }
+}
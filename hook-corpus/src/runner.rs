@@ -0,0 +1,306 @@
+//! Executes a [`Scenario`] against the compiled programs in LiteSVM and
+//! classifies the transfer result.
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022::error::TokenError;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::TransactionError,
+};
+use sss_core::pda::{find_config_address, find_role_address};
+use sss_core::state::Role;
+use sss_cu_bench::fixture::Bench;
+use sss_transfer_hook::error::TransferHookError;
+use sss_transfer_hook::pda::{
+    find_account_hold_address, find_blacklist_address, find_extra_account_metas_address,
+    find_hook_config_address, find_risk_score_address,
+};
+
+use crate::scenario::{Expect, Scenario};
+
+/// Add (`Some(effective_at)` schedules) or remove `address` on the blacklist.
+fn blacklist_ix(
+    bench: &Bench,
+    mint: &Pubkey,
+    address: &Pubkey,
+    add: bool,
+    effective_at: Option<i64>,
+) -> Instruction {
+    let blacklister = bench.authority.pubkey();
+    let (config, _) = find_config_address(mint);
+    let blacklister_role = find_role_address(&config, &blacklister, Role::Blacklister).0;
+    let blacklist_entry = find_blacklist_address(mint, address).0;
+
+    let (accounts, data) = if add {
+        (
+            sss_transfer_hook::accounts::AddToBlacklist {
+                blacklister,
+                blacklister_role,
+                mint: *mint,
+                config,
+                address: *address,
+                blacklist_entry,
+                system_program: solana_sdk::system_program::ID,
+                event_queue: None,
+                action_counter: None,
+                enforcement_queue: None,
+            }
+            .to_account_metas(None),
+            match effective_at {
+                Some(effective_at) => sss_transfer_hook::instruction::ScheduleBlacklist {
+                    reason: "corpus".to_string(),
+                    effective_at,
+                }
+                .data(),
+                None => sss_transfer_hook::instruction::AddToBlacklist {
+                    reason: "corpus".to_string(),
+                }
+                .data(),
+            },
+        )
+    } else {
+        (
+            sss_transfer_hook::accounts::RemoveFromBlacklist {
+                blacklister,
+                blacklister_role,
+                mint: *mint,
+                config,
+                blacklist_entry,
+            }
+            .to_account_metas(None),
+            sss_transfer_hook::instruction::RemoveFromBlacklist {}.data(),
+        )
+    };
+    Instruction {
+        program_id: sss_transfer_hook::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Register the bench authority as screening provider in blocking mode.
+fn configure_screening_ix(bench: &Bench, mint: &Pubkey, max_risk_score: u8) -> Instruction {
+    let admin = bench.authority.pubkey();
+    let (config, _) = find_config_address(mint);
+    Instruction {
+        program_id: sss_transfer_hook::ID,
+        accounts: sss_transfer_hook::accounts::ConfigureScreening {
+            admin,
+            admin_role: find_role_address(&config, &admin, Role::Admin).0,
+            mint: *mint,
+            config,
+            hook_config: find_hook_config_address(mint).0,
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: sss_transfer_hook::instruction::ConfigureScreening {
+            screening_provider: Some(admin),
+            max_risk_score,
+            block_high_risk: true,
+        }
+        .data(),
+    }
+}
+
+fn risk_score_ix(bench: &Bench, mint: &Pubkey, wallet: &Pubkey, score: u8) -> Instruction {
+    Instruction {
+        program_id: sss_transfer_hook::ID,
+        accounts: sss_transfer_hook::accounts::SetRiskScore {
+            provider: bench.authority.pubkey(),
+            mint: *mint,
+            config: find_config_address(mint).0,
+            hook_config: find_hook_config_address(mint).0,
+            wallet: *wallet,
+            risk_score: find_risk_score_address(mint, wallet).0,
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: sss_transfer_hook::instruction::SetRiskScore { score }.data(),
+    }
+}
+
+/// Place a compliance hold on `token_account` until `expires_at`.
+fn account_hold_ix(
+    bench: &Bench,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    expires_at: i64,
+) -> Instruction {
+    let blacklister = bench.authority.pubkey();
+    let (config, _) = find_config_address(mint);
+    Instruction {
+        program_id: sss_transfer_hook::ID,
+        accounts: sss_transfer_hook::accounts::PlaceAccountHold {
+            blacklister,
+            blacklister_role: find_role_address(&config, &blacklister, Role::Blacklister).0,
+            mint: *mint,
+            config,
+            token_account: *token_account,
+            account_hold: find_account_hold_address(mint, token_account).0,
+            system_program: solana_sdk::system_program::ID,
+            action_counter: None,
+        }
+        .to_account_metas(None),
+        data: sss_transfer_hook::instruction::PlaceAccountHold {
+            expires_at,
+            reason_code: 0,
+        }
+        .data(),
+    }
+}
+
+fn pause_ix(bench: &Bench, mint: &Pubkey) -> Instruction {
+    let pauser = bench.authority.pubkey();
+    let (config, _) = find_config_address(mint);
+    Instruction {
+        program_id: sss_core::ID,
+        accounts: sss_core::accounts::Pause {
+            pauser,
+            config,
+            pauser_role: find_role_address(&config, &pauser, Role::Pauser).0,
+            event_queue: None,
+        }
+        .to_account_metas(None),
+        data: sss_core::instruction::Pause {}.data(),
+    }
+}
+
+/// Map a failed transfer to the outcome it represents, or `None` when the
+/// error is not one the corpus knows how to express.
+fn classify(err: &TransactionError) -> Option<Expect> {
+    let TransactionError::InstructionError(_, InstructionError::Custom(code)) = err else {
+        return None;
+    };
+    let hook_code = |e: TransferHookError| u32::from(e);
+    match *code {
+        c if c == hook_code(TransferHookError::SenderBlacklisted) => {
+            Some(Expect::SenderBlacklisted)
+        }
+        c if c == hook_code(TransferHookError::ReceiverBlacklisted) => {
+            Some(Expect::ReceiverBlacklisted)
+        }
+        c if c == hook_code(TransferHookError::ProtocolPaused) => Some(Expect::ProtocolPaused),
+        c if c == hook_code(TransferHookError::SenderRiskTooHigh) => {
+            Some(Expect::SenderRiskTooHigh)
+        }
+        c if c == hook_code(TransferHookError::ReceiverRiskTooHigh) => {
+            Some(Expect::ReceiverRiskTooHigh)
+        }
+        c if c == hook_code(TransferHookError::SourceOnHold) => Some(Expect::SourceOnHold),
+        c if c == TokenError::InsufficientFunds as u32 => Some(Expect::InsufficientFunds),
+        _ => None,
+    }
+}
+
+/// Run `scenario` on a fresh SSS-2 mint. Returns the observed outcome, or a
+/// description of the unrecognised failure.
+pub fn run(mut bench: Bench, scenario: &Scenario) -> Result<Expect, String> {
+    let mint = bench.create_mint(true);
+    let (config, _) = find_config_address(&mint);
+    let admin = bench.authority.pubkey();
+
+    let ix = bench.initialize_ix(&mint, 2);
+    bench.send(&[ix], &[]);
+    for role in [Role::Minter, Role::Blacklister, Role::Pauser] {
+        let ixs = bench.grant_role_ixs(&config, &admin, role);
+        bench.send(&ixs, &[]);
+    }
+
+    let (extra_account_metas, _) = find_extra_account_metas_address(&mint);
+    let ix = Instruction {
+        program_id: sss_transfer_hook::ID,
+        accounts: sss_transfer_hook::accounts::InitializeExtraAccountMetas {
+            payer: admin,
+            extra_account_metas,
+            mint,
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: sss_transfer_hook::instruction::InitializeExtraAccountMetas {}.data(),
+    };
+    bench.send(&[ix], &[]);
+
+    let sender = bench.new_funded_keypair();
+    let receiver = Pubkey::new_unique();
+    let source = bench.create_token_account(&mint, &sender.pubkey(), true);
+    let destination = bench.create_token_account(&mint, &receiver, true);
+    if scenario.sender_balance > 0 {
+        let ix = bench.approve_kyc_ix(&config, &sender.pubkey());
+        bench.send(&[ix], &[]);
+        let ix =
+            bench.mint_tokens_kyc_ix(&mint, &source, &sender.pubkey(), scenario.sender_balance);
+        bench.send(&[ix], &[]);
+    }
+
+    if scenario.sender_blacklisted {
+        let ix = blacklist_ix(&bench, &mint, &sender.pubkey(), true, None);
+        bench.send(&[ix], &[]);
+    }
+    if scenario.sender_delisted {
+        let ix = blacklist_ix(&bench, &mint, &sender.pubkey(), false, None);
+        bench.send(&[ix], &[]);
+    }
+    if scenario.receiver_blacklisted {
+        let effective_at = scenario
+            .receiver_blacklist_delay_secs
+            .map(|delay| bench.svm.get_sysvar::<Clock>().unix_timestamp + delay);
+        let ix = blacklist_ix(&bench, &mint, &receiver, true, effective_at);
+        bench.send(&[ix], &[]);
+    }
+    if let Some(max_risk_score) = scenario.max_risk_score {
+        let ix = configure_screening_ix(&bench, &mint, max_risk_score);
+        bench.send(&[ix], &[]);
+        for (wallet, score) in [
+            (sender.pubkey(), scenario.sender_risk_score),
+            (receiver, scenario.receiver_risk_score),
+        ] {
+            if let Some(score) = score {
+                let ix = risk_score_ix(&bench, &mint, &wallet, score);
+                bench.send(&[ix], &[]);
+            }
+        }
+    }
+    for (token_account, hold_secs) in [
+        (source, scenario.sender_hold_secs),
+        (destination, scenario.receiver_hold_secs),
+    ] {
+        if let Some(secs) = hold_secs {
+            let expires_at = bench.svm.get_sysvar::<Clock>().unix_timestamp + secs;
+            let ix = account_hold_ix(&bench, &mint, &token_account, expires_at);
+            bench.send(&[ix], &[]);
+        }
+    }
+    if scenario.paused {
+        let ix = pause_ix(&bench, &mint);
+        bench.send(&[ix], &[]);
+    }
+
+    if scenario.elapsed_secs > 0 {
+        let mut clock = bench.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += scenario.elapsed_secs;
+        bench.svm.set_sysvar(&clock);
+    }
+
+    let transfer = bench.hook_transfer_ix(
+        &mint,
+        &source,
+        &destination,
+        &sender.pubkey(),
+        &receiver,
+        scenario.amount,
+    );
+
+    match bench.try_send(&[transfer], &[&sender]) {
+        Ok(_) => Ok(Expect::Allow),
+        Err(failed) => classify(&failed.err).ok_or_else(|| {
+            format!(
+                "unrecognised error {:?}\n{}",
+                failed.err,
+                failed.meta.pretty_logs()
+            )
+        }),
+    }
+}
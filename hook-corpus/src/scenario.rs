@@ -0,0 +1,145 @@
+//! Scenario fixtures: one JSON file per transfer in `hook-corpus/scenarios/`.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Balance minted to the sender when a scenario does not set one.
+pub const DEFAULT_SENDER_BALANCE: u64 = 1_000_000;
+
+/// A single transfer and the policy state it runs against.
+///
+/// Unknown fields are rejected so that a fixture describing a policy the hook
+/// does not enforce fails loudly instead of silently passing.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    /// Human-readable description, shown in failure reports.
+    pub name: String,
+    /// Token balance minted to the sender before the transfer.
+    #[serde(default = "default_sender_balance")]
+    pub sender_balance: u64,
+    /// Amount passed to `transfer_checked`.
+    pub amount: u64,
+    /// Add the sender's wallet to the blacklist.
+    #[serde(default)]
+    pub sender_blacklisted: bool,
+    /// Remove the sender again after blacklisting (requires `sender_blacklisted`).
+    #[serde(default)]
+    pub sender_delisted: bool,
+    /// Add the receiver's wallet to the blacklist.
+    #[serde(default)]
+    pub receiver_blacklisted: bool,
+    /// Schedule the receiver's blacklist entry this many seconds in the
+    /// future instead of adding it immediately (requires `receiver_blacklisted`).
+    #[serde(default)]
+    pub receiver_blacklist_delay_secs: Option<i64>,
+    /// Place a compliance hold on the sender's token account that expires
+    /// this many seconds from now.
+    #[serde(default)]
+    pub sender_hold_secs: Option<i64>,
+    /// Place a compliance hold on the receiver's token account that expires
+    /// this many seconds from now.
+    #[serde(default)]
+    pub receiver_hold_secs: Option<i64>,
+    /// Pause the stablecoin through `sss-core` before the transfer.
+    #[serde(default)]
+    pub paused: bool,
+    /// Enable blocking wallet screening with this threshold.
+    #[serde(default)]
+    pub max_risk_score: Option<u8>,
+    /// Sender's provider risk score (requires `max_risk_score`).
+    #[serde(default)]
+    pub sender_risk_score: Option<u8>,
+    /// Receiver's provider risk score (requires `max_risk_score`).
+    #[serde(default)]
+    pub receiver_risk_score: Option<u8>,
+    /// Advance the clock by this many seconds just before the transfer.
+    #[serde(default)]
+    pub elapsed_secs: i64,
+    pub expect: Expect,
+}
+
+fn default_sender_balance() -> u64 {
+    DEFAULT_SENDER_BALANCE
+}
+
+/// Expected outcome of the transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Expect {
+    Allow,
+    SenderBlacklisted,
+    ReceiverBlacklisted,
+    ProtocolPaused,
+    SenderRiskTooHigh,
+    ReceiverRiskTooHigh,
+    SourceOnHold,
+    /// Rejected by Token-2022 itself before the hook runs.
+    InsufficientFunds,
+}
+
+pub fn corpus_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("scenarios")
+}
+
+/// Load every `*.json` fixture in `dir`, sorted by file name.
+pub fn load_corpus(dir: &Path) -> Vec<(String, Scenario)> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("read {}: {e}", dir.display()))
+        .map(|entry| entry.expect("read scenario entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let file = path.file_name().unwrap().to_string_lossy().into_owned();
+            let raw = std::fs::read_to_string(&path).expect("read scenario");
+            let scenario = serde_json::from_str(&raw)
+                .unwrap_or_else(|e| panic!("{file}: invalid scenario: {e}"));
+            (file, scenario)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corpus_parses() {
+        let corpus = load_corpus(&corpus_dir());
+        assert!(!corpus.is_empty());
+        for (file, scenario) in &corpus {
+            assert!(
+                !scenario.sender_delisted || scenario.sender_blacklisted,
+                "{file}: sender_delisted requires sender_blacklisted"
+            );
+            assert!(
+                scenario.receiver_blacklist_delay_secs.is_none() || scenario.receiver_blacklisted,
+                "{file}: receiver_blacklist_delay_secs requires receiver_blacklisted"
+            );
+            assert!(
+                scenario.max_risk_score.is_some()
+                    || (scenario.sender_risk_score.is_none()
+                        && scenario.receiver_risk_score.is_none()),
+                "{file}: risk scores require max_risk_score"
+            );
+            assert!(
+                [scenario.sender_hold_secs, scenario.receiver_hold_secs]
+                    .iter()
+                    .flatten()
+                    .all(|secs| *secs > 0),
+                "{file}: holds must expire in the future"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_policy_is_rejected() {
+        let raw = r#"{ "name": "kyc", "amount": 1, "kyc_expired": true, "expect": "allow" }"#;
+        assert!(serde_json::from_str::<Scenario>(raw).is_err());
+    }
+}
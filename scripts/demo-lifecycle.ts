@@ -352,7 +352,12 @@ async function main() {
 
     // Seize
     await sss1.roles.grant(payer.publicKey, asRole('seizer'));
-    const seizeSig = await sss1.seize(payer.publicKey, payer.publicKey, BigInt(1_000_000));
+    const seizeSig = await sss1.seize(
+      payer.publicKey,
+      payer.publicKey,
+      BigInt(1_000_000),
+      'Demo: lifecycle walkthrough',
+    );
     logEntry('Seize (self-test)', `${seizeSig.slice(0, 20)}...`, icons.skull);
 
     const info = await sss1.info();
@@ -496,7 +501,12 @@ async function main() {
     const mintToRecipSig2 = await sss2.mintTokens(recipient2.publicKey, BigInt(50_000_000));
     logEntry('Mint 50 to recipient', `${mintToRecipSig2.slice(0, 20)}...`, icons.rocket);
     await sss2.roles.grant(payer.publicKey, asRole('seizer'));
-    const seizeSig2 = await sss2.seize(recipient2.publicKey, payer.publicKey, BigInt(25_000_000));
+    const seizeSig2 = await sss2.seize(
+      recipient2.publicKey,
+      payer.publicKey,
+      BigInt(25_000_000),
+      'Demo: sanctioned holder',
+    );
     logEntry('Seize 25 from recipient', `${seizeSig2.slice(0, 20)}...`, icons.skull);
 
     // 12. Pause/unpause cycle
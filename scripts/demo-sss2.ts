@@ -192,7 +192,12 @@ async function main() {
   txSigs.grantSeizer = await sss.roles.grant(payer.publicKey, asRole('seizer'));
 
   try {
-    txSigs.seize = await sss.seize(recipient.publicKey, payer.publicKey, BigInt(25_000_000)); // Seize 25
+    txSigs.seize = await sss.seize(
+      recipient.publicKey,
+      payer.publicKey,
+      BigInt(25_000_000), // Seize 25
+      'Demo: sanctioned holder',
+    );
     logSuccess(`Seized 25 tokens. Tx: ${txSigs.seize}`);
   } catch (err: any) {
     logError('Seize failed with error: ' + err.message);
@@ -199,7 +199,12 @@ async function main() {
   logSection('7. Seizing tokens via permanent delegate...');
   await sss.roles.grant(payer.publicKey, asRole('seizer'));
   // Seize from ourselves back to treasury (self-test)
-  txSigs.seize = await sss.seize(payer.publicKey, payer.publicKey, BigInt(10_000_000));
+  txSigs.seize = await sss.seize(
+    payer.publicKey,
+    payer.publicKey,
+    BigInt(10_000_000),
+    'Demo: public balance recovery',
+  );
   logSuccess(`Seized 10 tokens. Tx: ${txSigs.seize}`);
 
   // 8. Burn some tokens
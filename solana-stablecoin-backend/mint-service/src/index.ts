@@ -46,6 +46,11 @@ const seizeSchema = z.object({
     .string()
     .regex(/^\d+$/, 'Amount must be a numeric string')
     .refine((v) => BigInt(v) > 0n, 'Amount must be positive'),
+  reason: z
+    .string()
+    .trim()
+    .min(1, 'Reason is required')
+    .refine((v) => Buffer.byteLength(v) <= 200, 'Reason must be 200 bytes or fewer'),
 });
 
 // Helper for error handling
@@ -185,10 +190,15 @@ app.post('/api/seize', async (req, res) => {
   const parsed = seizeSchema.safeParse(req.body);
   if (!parsed.success) return res.status(422).json({ error: parsed.error.flatten().fieldErrors });
   try {
-    const { mint, from, to, amount } = parsed.data;
+    const { mint, from, to, amount, reason } = parsed.data;
     const connector = getChainConnector();
     const sss = await connector.getStablecoinHandle(new PublicKey(mint));
-    const signature = await sss.seize(new PublicKey(from), new PublicKey(to), BigInt(amount));
+    const signature = await sss.seize(
+      new PublicKey(from),
+      new PublicKey(to),
+      BigInt(amount),
+      reason,
+    );
     res.json({ success: true, signature });
   } catch (err) {
     handleRouteError(res, err, 'Seize');
@@ -298,6 +298,10 @@ program
   .option('-f, --from <address>', 'Wallet to seize from')
   .option('-t, --to <address>', 'Treasury wallet to seize to')
   .option('-a, --amount <amount>', 'Amount to seize')
+  .option(
+    '-r, --reason <text>',
+    'Compliance reason recorded on-chain (e.g. a court order reference)',
+  )
   .action((from, to, amount, opts) => {
     const mint = opts.mint || getMintFromConfig() || process.env.SSS_MINT;
     if (!mint) {
@@ -324,7 +328,15 @@ program
       r(<Text color="red">Error: Amount is required.</Text>);
       return;
     }
-    r(<Seize options={{ mint, from: finalFrom, to: finalTo, amount: finalAmount }} />);
+    if (!opts.reason) {
+      r(<Text color="red">Error: --reason is required.</Text>);
+      return;
+    }
+    r(
+      <Seize
+        options={{ mint, from: finalFrom, to: finalTo, amount: finalAmount, reason: opts.reason }}
+      />,
+    );
   });
 
 // ─── Roles ────────────────────────────────────────────────────────────────────
@@ -332,7 +344,7 @@ const rolesCmd = program.command('roles').description('Role management');
 
 rolesCmd
   .command('list')
-  .description('List active roles for an address')
+  .description('List active roles for an address, or every role holder when no address is given')
   .argument('[address]', 'Wallet to check')
   .option(
     '-m, --mint <address>',
@@ -351,16 +363,12 @@ rolesCmd
       return;
     }
     const finalAddress = address || opts.address;
-    if (!finalAddress) {
-      r(<Text color="red">Error: Address is required.</Text>);
-      return;
-    }
     r(<Roles options={{ mint, action: 'list', address: finalAddress }} />);
   });
 
 rolesCmd
   .command('grant')
-  .description('Grant a role to an address (admin only)')
+  .description('Grant a role to an address (admin only); the grantee must accept it')
   .argument('[address]', 'Recipient wallet')
   .argument('[role]', 'admin | minter | freezer | pauser | burner | blacklister | seizer')
   .option(
@@ -393,6 +401,34 @@ rolesCmd
     r(<Roles options={{ mint, action: 'grant', address: finalAddress, role: finalRole }} />);
   });
 
+rolesCmd
+  .command('accept')
+  .description('Accept a role granted to your wallet')
+  .argument('[role]', 'admin | minter | freezer | pauser | burner | blacklister | seizer')
+  .option(
+    '-m, --mint <address>',
+    'Mint address (falls back to SSS_CONFIG, .sss-config.json or SSS_MINT env var)',
+  )
+  .option('-r, --role <role>', 'admin | minter | freezer | pauser | burner | blacklister | seizer')
+  .action((role, opts) => {
+    const mint = opts.mint || getMintFromConfig() || process.env.SSS_MINT;
+    if (!mint) {
+      r(
+        <Text color="red">
+          Error: --mint option, SSS_CONFIG, .sss-config.json, or SSS_MINT environment variable must
+          be set.
+        </Text>,
+      );
+      return;
+    }
+    const finalRole = role || opts.role;
+    if (!finalRole) {
+      r(<Text color="red">Error: Role is required.</Text>);
+      return;
+    }
+    r(<Roles options={{ mint, action: 'accept', role: finalRole }} />);
+  });
+
 rolesCmd
   .command('revoke')
   .description('Revoke a role from an address (admin only)')
@@ -5,8 +5,22 @@ import { PublicKey } from '@solana/web3.js';
 import { Header, Spinner, Success, Err, Card } from '../components/ui.js';
 import { loadProvider } from '../utils/config.js';
 
-type RoleAction = 'grant' | 'revoke' | 'check';
-type ValidRole = 'admin' | 'minter' | 'freezer' | 'pauser' | 'burner' | 'blacklister' | 'seizer';
+type RoleAction = 'grant' | 'accept' | 'revoke' | 'check';
+type ValidRole =
+  | 'admin'
+  | 'minter'
+  | 'freezer'
+  | 'pauser'
+  | 'burner'
+  | 'blacklister'
+  | 'seizer'
+  | 'bridge'
+  | 'metadata-manager'
+  | 'quota-manager'
+  | 'rescuer'
+  | 'guardian'
+  | 'mint-requester'
+  | 'attestor';
 const ALL_ROLES: ValidRole[] = [
   'admin',
   'minter',
@@ -15,6 +29,13 @@ const ALL_ROLES: ValidRole[] = [
   'burner',
   'blacklister',
   'seizer',
+  'bridge',
+  'metadata-manager',
+  'quota-manager',
+  'rescuer',
+  'guardian',
+  'mint-requester',
+  'attestor',
 ];
 
 interface RolesOptions {
@@ -38,19 +59,29 @@ export default function Roles({ options }: { options: RolesOptions }) {
         const sss = await SSS.load(provider, mint as any);
 
         if (options.action === 'list') {
-          // SDK has no .list(), so we check all roles for the wallet address as a convenience
-          if (!options.address)
-            throw new Error(
-              '--address is required for roles list (shows roles for a specific wallet)',
-            );
-          const addr = new PublicKey(options.address);
           const results: { address: string; role: string }[] = [];
-          for (const r of ALL_ROLES) {
-            const has = await sss.accessControl.check(addr, r as any);
-            if (has) results.push({ address: addr.toBase58(), role: r });
+          if (options.address) {
+            const addr = new PublicKey(options.address);
+            for (const r of ALL_ROLES) {
+              const has = await sss.accessControl.check(addr, r as any);
+              if (has) results.push({ address: addr.toBase58(), role: r });
+            }
+          } else {
+            // Without an address, read every role's on-chain directory
+            for (const r of ALL_ROLES) {
+              const holders = await sss.accessControl.holders(roleType(r) as any);
+              for (const h of holders) results.push({ address: h.toBase58(), role: r });
+            }
           }
           setRoleInfos(results);
           setPhase('done');
+        } else if (options.action === 'accept') {
+          if (!options.role) throw new Error('--role is required');
+          const roleStrs = options.role.includes(',') ? options.role.split(',') : [options.role];
+          const roles = roleStrs.map((r) => roleType(r.trim() as ValidRole));
+          const txSig = await sss.accessControl.accept(roles as any);
+          setSig(txSig);
+          setPhase('done');
         } else {
           if (!options.address || !options.role) {
             throw new Error('--address and --role are required');
@@ -99,7 +130,13 @@ export default function Roles({ options }: { options: RolesOptions }) {
       {phase === 'running' && <Spinner label={`Roles: ${options.action}...`} />}
       {phase === 'confirming' && <Spinner label="Confirming transaction..." />}
       {phase === 'done' && options.action === 'list' && (
-        <Card title={`Active roles for ${options.address?.slice(0, 8)}...`}>
+        <Card
+          title={
+            options.address
+              ? `Active roles for ${options.address.slice(0, 8)}...`
+              : 'All role holders'
+          }
+        >
           {roleInfos.length === 0 ? (
             <Text color="gray">No active roles found.</Text>
           ) : (
@@ -11,6 +11,7 @@ interface SeizeOptions {
   from: string;
   to: string;
   amount: string;
+  reason: string;
 }
 
 export default function Seize({ options }: { options: SeizeOptions }) {
@@ -30,7 +31,7 @@ export default function Seize({ options }: { options: SeizeOptions }) {
         const toAta = getAssociatedTokenAddressSync(mint, to, false, TOKEN_2022_PROGRAM_ID);
         const amount = parseAmount(options.amount);
 
-        const txSig = await sss.seize(fromAta, toAta, amount);
+        const txSig = await sss.seize(fromAta, toAta, amount, options.reason);
         setSig(txSig);
         setPhase('confirming');
 
@@ -50,6 +50,7 @@ export function OperationsPanel({
   const [address, setAddress] = useState('');
   const [fromAddress, setFromAddress] = useState('');
   const [toAddress, setToAddress] = useState('');
+  const [reason, setReason] = useState('');
 
   // Process State
   const [phase, setPhase] = useState<'idle' | 'confirming' | 'executing' | 'done'>('idle');
@@ -61,7 +62,7 @@ export function OperationsPanel({
       case 'mint':
         return 2;
       case 'seize':
-        return 3;
+        return 4;
       case 'burn':
       case 'freeze':
       case 'thaw':
@@ -113,6 +114,7 @@ export function OperationsPanel({
         setAddress('');
         setFromAddress('');
         setToAddress('');
+        setReason('');
       }
     },
     { isActive: !isPaused && phase === 'idle' },
@@ -148,7 +150,7 @@ export function OperationsPanel({
         const from = new PublicKey(fromAddress);
         const to = new PublicKey(toAddress);
         const amt = parseAmount(amount);
-        txSig = await sss.seize(from, to, amt);
+        txSig = await sss.seize(from, to, amt, reason);
       }
 
       const latestBlockHash = await provider.connection.getLatestBlockhash();
@@ -295,8 +297,15 @@ export function OperationsPanel({
                 label="Amount"
                 value={amount}
                 onChange={setAmount}
-                onSubmit={attemptOp}
                 isFocused={focusedField === 2}
+                onSubmit={() => setFocusedField(3)}
+              />
+              <TextInput
+                label="Reason (e.g. court order reference)"
+                value={reason}
+                onChange={setReason}
+                onSubmit={attemptOp}
+                isFocused={focusedField === 3}
               />
             </>
           )}
@@ -23,7 +23,21 @@ import { type AccessRole, asRole, SssCore } from '@stbr/sss-token';
 import { AccountNamespace } from '@coral-xyz/anchor';
 import { cn } from '@/lib/utils';
 
-type RoleName = 'Admin' | 'Minter' | 'Freezer' | 'Pauser' | 'Burner' | 'Blacklister' | 'Seizer';
+type RoleName =
+  | 'Admin'
+  | 'Minter'
+  | 'Freezer'
+  | 'Pauser'
+  | 'Burner'
+  | 'Blacklister'
+  | 'Seizer'
+  | 'Bridge'
+  | 'Metadata Manager'
+  | 'Quota Manager'
+  | 'Rescuer'
+  | 'Guardian'
+  | 'Mint Requester'
+  | 'Attestor';
 
 type CheckedRole = {
   name: RoleName;
@@ -40,6 +54,13 @@ const ROLE_DISPLAY_MAP: Record<RoleName, AccessRole> = {
   Burner: asRole('burner'),
   Blacklister: asRole('blacklister'),
   Seizer: asRole('seizer'),
+  Bridge: asRole('bridge'),
+  'Metadata Manager': asRole('metadata-manager'),
+  'Quota Manager': asRole('quota-manager'),
+  Rescuer: asRole('rescuer'),
+  Guardian: asRole('guardian'),
+  'Mint Requester': asRole('mint-requester'),
+  Attestor: asRole('attestor'),
 };
 
 const ROLE_DESCRIPTIONS: Record<RoleName, string> = {
@@ -57,6 +78,20 @@ const ROLE_DESCRIPTIONS: Record<RoleName, string> = {
     "Secondary compliance role specific to SSS-2. Blacklisters manage a list of addresses that are strictly prohibited from holding or transferring the stablecoin. Powered by Solana's Transfer Hook extension.",
   Seizer:
     'The most powerful compliance tool. Seizers can unilaterally move tokens from any account to a target destination using permanent delegate authority. This role functions even when the protocol is paused.',
+  Bridge:
+    'Moves supply between chains. A bridge burns its own tokens when they leave this chain and can mint back at most what it has burned, so bridging never changes the issuer supply or consumes the supply cap.',
+  'Metadata Manager':
+    "Keeps the token's metadata current. Metadata managers can point the configuration at a new metadata URI without holding any other admin power.",
+  'Quota Manager':
+    "Controls minter limits. Quota managers set each minter's mint quota and operating hours, but cannot grant the Minter role or mint themselves.",
+  Rescuer:
+    'Recovers misdirected funds. Rescuers can move stablecoins that were sent to the configuration address by mistake back out to a chosen account.',
+  Guardian:
+    'Emergency kill switch only. Guardians can pause the stablecoin but never unpause it, mint or seize, so the role is safe to hand to monitoring bots and partners.',
+  'Mint Requester':
+    'Asks for issuance. Mint requesters open mint requests naming an amount and destination that a minter then fulfils, so who asked for tokens and who minted them are recorded separately.',
+  Attestor:
+    'Publishes proof of reserves. Attestors record the latest audited reserve amount and report hash, which can be required to back every mint.',
 };
 
 type OperationType = 'grant' | 'revoke' | 'check' | 'info';
@@ -75,7 +110,8 @@ const OPERATIONS: Record<
   grant: {
     id: 'grant',
     title: 'Grant Role',
-    description: 'Assign a role to an address. Only the admin can grant roles.',
+    description:
+      'Assign a role to an address. Only the admin can grant roles, and the grantee must accept before the role takes effect.',
     icon: ShieldPlus,
     color: 'text-success bg-success/10 border-success/20',
     buttonVariant: 'primary',
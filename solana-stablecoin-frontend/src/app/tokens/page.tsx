@@ -89,7 +89,7 @@ export default function TokensPage() {
           symbol: string;
           preset: number;
           authority: PublicKey;
-          paused: boolean;
+          pauseFlags: number;
         };
       }
       const [accounts, roleAccounts] = await Promise.all([
@@ -115,7 +115,7 @@ export default function TokensPage() {
           symbol: acc.account.symbol.replace(/\0/g, '').trim(),
           preset: acc.account.preset,
           authority: acc.account.authority.toBase58(),
-          paused: acc.account.paused,
+          paused: acc.account.pauseFlags !== 0,
           isAuthority: acc.account.authority.equals(program.provider.publicKey!),
           roles: rolesByMint[mintStr] || [],
           raw: acc,
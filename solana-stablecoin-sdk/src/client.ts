@@ -12,7 +12,9 @@ import { SssCoreIdl, SssTransferHookIdl } from './idl';
 import {
   deriveConfigPda,
   deriveRolePda,
+  deriveRoleDirectoryPda,
   deriveBlacklistPda,
+  deriveSeizureRecordPda,
   STBL_CORE_PROGRAM_ID,
   STBL_HOOK_PROGRAM_ID,
 } from './pda';
@@ -34,6 +36,20 @@ import { createSss2MintTx } from './presets/sss2';
 import { createSss3MintTx } from './presets/sss3';
 import { PrivacyOpsBuilder } from './confidential';
 
+// RoleDirectory layout: discriminator(8) + config(32) + role(1) + bump(1) + holders(4 + 32×n).
+// The bundled IDL predates the directory account, so holders are read by offset.
+const ROLE_DIRECTORY_HOLDERS_OFFSET = 42;
+
+function decodeRoleDirectoryHolders(data: Buffer): PublicKey[] {
+  const count = data.readUInt32LE(ROLE_DIRECTORY_HOLDERS_OFFSET);
+  const holders: PublicKey[] = [];
+  for (let i = 0; i < count; i++) {
+    const start = ROLE_DIRECTORY_HOLDERS_OFFSET + 4 + i * 32;
+    holders.push(new PublicKey(data.subarray(start, start + 32)));
+  }
+  return holders;
+}
+
 export class StablecoinClient {
   public readonly mintAddress: TokenMintKey;
   public readonly configPda: ConfigAccountKey;
@@ -245,6 +261,13 @@ export class StablecoinClient {
           case 'burner':
           case 'blacklister':
           case 'seizer':
+          case 'bridge':
+          case 'metadata-manager':
+          case 'quota-manager':
+          case 'rescuer':
+          case 'guardian':
+          case 'mint-requester':
+          case 'attestor':
             validRole = asRole(roleStr.toLowerCase() as any);
             break;
         }
@@ -256,7 +279,13 @@ export class StablecoinClient {
             payer,
             validRole,
           );
-          roleIxs.push(grantIx);
+          const acceptIx = await coreix.createRoleAcceptInstruction(
+            ledgerProgram,
+            configPda,
+            payer,
+            validRole,
+          );
+          roleIxs.push(grantIx, acceptIx);
         }
       }
     }
@@ -571,30 +600,50 @@ export class StablecoinClient {
     return new Transaction().add(ix);
   }
 
-  async pause(): Promise<string> {
-    const tx = await this.composePause();
+  async pause(role: AccessRole = asRole('pauser')): Promise<string> {
+    const tx = await this.composePause(role);
     return this.dispatchInstruction(tx.instructions);
   }
 
-  async composePause(): Promise<Transaction> {
+  async composePause(role: AccessRole = asRole('pauser')): Promise<Transaction> {
     const pauser = this.anchorProvider.publicKey;
-    const ix = await coreix.createPauseInstruction(this.ledgerProgram, this.configPda, pauser);
+    const ix = await coreix.createPauseInstruction(
+      this.ledgerProgram,
+      this.configPda,
+      pauser,
+      role,
+    );
     return new Transaction().add(ix);
   }
 
-  async unpause(): Promise<string> {
-    const tx = await this.composeResume();
+  async unpause(role: AccessRole = asRole('pauser')): Promise<string> {
+    const tx = await this.composeResume(role);
     return this.dispatchInstruction(tx.instructions);
   }
 
-  async composeResume(): Promise<Transaction> {
+  async composeResume(role: AccessRole = asRole('pauser')): Promise<Transaction> {
     const pauser = this.anchorProvider.publicKey;
-    const ix = await coreix.createResumeInstruction(this.ledgerProgram, this.configPda, pauser);
+    const ix = await coreix.createResumeInstruction(
+      this.ledgerProgram,
+      this.configPda,
+      pauser,
+      role,
+    );
     return new Transaction().add(ix);
   }
 
-  async seize(fromWallet: PublicKey, toWallet: PublicKey, amount: bigint): Promise<string> {
-    const tx = await this.composeSeize(fromWallet, toWallet, amount);
+  /**
+   * Move `amount` from `fromWallet` to `toWallet` as permanent delegate.
+   * `reason` (e.g. a court order reference, at most 200 bytes) is stored
+   * on-chain in a `SeizureRecord` and emitted with `TokensSeized`.
+   */
+  async seize(
+    fromWallet: PublicKey,
+    toWallet: PublicKey,
+    amount: bigint,
+    reason: string,
+  ): Promise<string> {
+    const tx = await this.composeSeize(fromWallet, toWallet, amount, reason);
     return this.dispatchInstruction(tx.instructions);
   }
 
@@ -602,6 +651,7 @@ export class StablecoinClient {
     fromWallet: PublicKey,
     toWallet: PublicKey,
     amount: bigint,
+    reason: string,
   ): Promise<Transaction> {
     const seizer = this.anchorProvider.publicKey;
     const tx = new Transaction();
@@ -658,6 +708,8 @@ export class StablecoinClient {
       fromAta,
       toAta,
       new BN(amount.toString()),
+      reason,
+      await this.nextSeizureIndex(),
     );
 
     // Resolve extra hook accounts using canonical spl-token logic if needed
@@ -682,6 +734,34 @@ export class StablecoinClient {
     return tx;
   }
 
+  /**
+   * The config's `seizure_count`, found as the first index without a
+   * `SeizureRecord`: records are never closed, so a doubling search and a
+   * binary search need only a logarithmic number of lookups.
+   */
+  private async nextSeizureIndex(): Promise<bigint> {
+    const connection = this.anchorProvider.connection;
+    const recorded = async (index: bigint) => {
+      const [pda] = deriveSeizureRecordPda(this.configPda, index, this.ledgerProgram.programId);
+      return (await connection.getAccountInfo(pda)) !== null;
+    };
+
+    if (!(await recorded(0n))) return 0n;
+    let low = 0n;
+    let high = 1n;
+    while (await recorded(high)) {
+      low = high;
+      high *= 2n;
+    }
+    // recorded(low) holds and recorded(high) does not.
+    while (high - low > 1n) {
+      const mid = (low + high) / 2n;
+      if (await recorded(mid)) low = mid;
+      else high = mid;
+    }
+    return high;
+  }
+
   async updateSupplyCap(newSupplyCap: bigint | null): Promise<string> {
     const tx = await this.composeUpdateSupplyCap(newSupplyCap);
     return this.dispatchInstruction(tx.instructions);
@@ -699,14 +779,14 @@ export class StablecoinClient {
     return new Transaction().add(ix);
   }
 
-  async transferAuthority(newAuthority: PublicKey): Promise<string> {
-    const tx = await this.composeTransferAuthority(newAuthority);
+  async proposeAuthority(newAuthority: PublicKey): Promise<string> {
+    const tx = await this.composeProposeAuthority(newAuthority);
     return this.dispatchInstruction(tx.instructions);
   }
 
-  async composeTransferAuthority(newAuthority: PublicKey): Promise<Transaction> {
+  async composeProposeAuthority(newAuthority: PublicKey): Promise<Transaction> {
     const admin = this.anchorProvider.publicKey;
-    const ix = await coreix.createAuthorityTransferInstruction(
+    const ix = await coreix.createAuthorityProposalInstruction(
       this.ledgerProgram,
       this.configPda,
       admin,
@@ -715,6 +795,24 @@ export class StablecoinClient {
     return new Transaction().add(ix);
   }
 
+  /** Accept a pending authority transfer. The provider must be the proposed authority. */
+  async acceptAuthority(): Promise<string> {
+    const tx = await this.composeAcceptAuthority();
+    return this.dispatchInstruction(tx.instructions);
+  }
+
+  async composeAcceptAuthority(): Promise<Transaction> {
+    const newAuthority = this.anchorProvider.publicKey;
+    const config = await this.ledgerProgram.account.stablecoinConfig.fetch(this.configPda);
+    const ix = await coreix.createAuthorityAcceptInstruction(
+      this.ledgerProgram,
+      this.configPda,
+      config.authority,
+      newAuthority,
+    );
+    return new Transaction().add(ix);
+  }
+
   async fetchConfig(): Promise<TokenStateSnapshot> {
     const onChainState = await this.ledgerProgram.account.stablecoinConfig.fetch(this.configPda);
 
@@ -726,7 +824,8 @@ export class StablecoinClient {
       mint: onChainState.mint as TokenMintKey,
       authority: onChainState.authority,
       preset: ORDINAL_TO_TIER_MAP[onChainState.preset] ?? asTier('sss-1'),
-      paused: onChainState.paused,
+      paused: onChainState.pauseFlags !== 0,
+      pauseFlags: onChainState.pauseFlags,
       supplyCap,
       totalMinted,
       totalBurned,
@@ -770,6 +869,12 @@ export class StablecoinClient {
         return this.dispatchInstruction(tx.instructions);
       },
 
+      /** Take up roles granted to the provider. */
+      accept: async (roles: AccessRole | AccessRole[]): Promise<string> => {
+        const tx = await this.composeAcceptRole(roles);
+        return this.dispatchInstruction(tx.instructions);
+      },
+
       revoke: async (address: PublicKey, roles: AccessRole | AccessRole[]): Promise<string> => {
         const tx = await this.composeRevokeRole(address, roles);
         return this.dispatchInstruction(tx.instructions);
@@ -785,6 +890,17 @@ export class StablecoinClient {
         const account = await this.ledgerProgram.account.roleAccount.fetchNullable(rolePda);
         return account !== null;
       },
+
+      /** List every current holder of a role from its on-chain RoleDirectory. */
+      holders: async (role: AccessRole): Promise<PublicKey[]> => {
+        const [directoryPda] = deriveRoleDirectoryPda(
+          this.configPda,
+          role,
+          this.ledgerProgram.programId,
+        );
+        const info = await this.anchorProvider.connection.getAccountInfo(directoryPda);
+        return info ? decodeRoleDirectoryHolders(info.data) : [];
+      },
     };
   }
 
@@ -805,6 +921,29 @@ export class StablecoinClient {
         r,
       );
       tx.add(ix);
+      // A grant to the signer itself is accepted in the same transaction.
+      if (address.equals(admin)) {
+        tx.add(
+          await coreix.createRoleAcceptInstruction(this.ledgerProgram, this.configPda, admin, r),
+        );
+      }
+    }
+    return tx;
+  }
+
+  async composeAcceptRole(role: AccessRole | AccessRole[]): Promise<Transaction> {
+    const grantee = this.anchorProvider.publicKey;
+    const roles = Array.isArray(role) ? role : [role];
+    const tx = new Transaction();
+
+    for (const r of roles) {
+      const ix = await coreix.createRoleAcceptInstruction(
+        this.ledgerProgram,
+        this.configPda,
+        grantee,
+        r,
+      );
+      tx.add(ix);
     }
     return tx;
   }
@@ -889,8 +1028,8 @@ export class StablecoinClient {
       blacklistAdd: (address: PublicKey, reason: string) => this.denyList.add(address, reason),
       blacklistRemove: (address: PublicKey) => this.denyList.remove(address),
       blacklistCheck: (address: PublicKey) => this.denyList.check(address),
-      seize: (fromWallet: PublicKey, toWallet: PublicKey, amount: bigint) =>
-        this.seize(fromWallet, toWallet, amount),
+      seize: (fromWallet: PublicKey, toWallet: PublicKey, amount: bigint, reason: string) =>
+        this.seize(fromWallet, toWallet, amount, reason),
     };
   }
 
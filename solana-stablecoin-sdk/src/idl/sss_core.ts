@@ -13,6 +13,110 @@ export type SssCore = {
     description: 'Solana Stablecoin Standard - Core Program';
   };
   instructions: [
+    {
+      name: 'acceptAuthority';
+      discriminator: [107, 86, 198, 91, 33, 12, 107, 160];
+      accounts: [
+        {
+          name: 'newAuthority';
+          docs: ['The pending authority. Pays for its admin role PDA.'];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [115, 115, 115, 45, 99, 111, 110, 102, 105, 103];
+              },
+              {
+                kind: 'account';
+                path: 'config.mint';
+                account: 'stablecoinConfig';
+              },
+            ];
+          };
+        },
+        {
+          name: 'oldAuthority';
+          writable: true;
+        },
+        {
+          name: 'oldAdminRole';
+          docs: ["The current authority's admin role PDA — will be closed."];
+          writable: true;
+        },
+        {
+          name: 'newAdminRole';
+          docs: ["The new authority's admin role PDA — will be created."];
+          writable: true;
+        },
+        {
+          name: 'adminDirectory';
+          docs: ['Admin holder list — the old authority is swapped for the new one.'];
+          writable: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'acceptRole';
+      discriminator: [52, 25, 7, 231, 196, 235, 119, 253];
+      accounts: [
+        {
+          name: 'grantee';
+          docs: ['The grantee. Pays for its role PDA and directory entry.'];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [115, 115, 115, 45, 99, 111, 110, 102, 105, 103];
+              },
+              {
+                kind: 'account';
+                path: 'config.mint';
+                account: 'stablecoinConfig';
+              },
+            ];
+          };
+        },
+        {
+          name: 'pendingRole';
+          docs: ['The grant being accepted. Closed and rent returned to the grantee.'];
+          writable: true;
+        },
+        {
+          name: 'roleAccount';
+          writable: true;
+        },
+        {
+          name: 'roleDirectory';
+          docs: [
+            'Holder list for the role. Created on the first grant of a role that',
+            'was not set up at `initialize`.',
+          ];
+          writable: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [];
+    },
     {
       name: 'burnTokens';
       discriminator: [76, 15, 51, 254, 229, 215, 121, 66];
@@ -124,7 +228,6 @@ export type SssCore = {
         },
         {
           name: 'config';
-          writable: true;
           pda: {
             seeds: [
               {
@@ -148,7 +251,8 @@ export type SssCore = {
           docs: ['The address receiving the role.'];
         },
         {
-          name: 'roleAccount';
+          name: 'pendingRole';
+          docs: ['The grant, until the grantee accepts it.'];
           writable: true;
         },
         {
@@ -312,6 +416,47 @@ export type SssCore = {
       ];
       args: [];
     },
+    {
+      name: 'proposeAuthority';
+      discriminator: [20, 148, 236, 198, 76, 119, 99, 142];
+      accounts: [
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [115, 115, 115, 45, 99, 111, 110, 102, 105, 103];
+              },
+              {
+                kind: 'account';
+                path: 'config.mint';
+                account: 'stablecoinConfig';
+              },
+            ];
+          };
+        },
+        {
+          name: 'adminRole';
+        },
+        {
+          name: 'newAuthority';
+        },
+        {
+          name: 'pendingAction';
+          docs: ['Required while the admin timelock is enabled. Closed on success.'];
+          writable: true;
+          optional: true;
+        },
+      ];
+      args: [];
+    },
     {
       name: 'revokeRole';
       discriminator: [179, 232, 2, 180, 48, 227, 82, 7];
@@ -356,11 +501,16 @@ export type SssCore = {
       accounts: [
         {
           name: 'seizer';
+          writable: true;
           signer: true;
         },
         {
           name: 'config';
-          docs: ['NO pause check — seizure works during emergencies.'];
+          docs: [
+            'NO pause check — seizure works during emergencies. Mutable to',
+            'advance `seizure_count`.',
+          ];
+          writable: true;
           pda: {
             seeds: [
               {
@@ -377,6 +527,7 @@ export type SssCore = {
         {
           name: 'seizerRole';
           docs: ['Seizer role PDA — its existence proves seizure authorization.'];
+          writable: true;
         },
         {
           name: 'mint';
@@ -392,12 +543,39 @@ export type SssCore = {
         {
           name: 'tokenProgram';
         },
+        {
+          name: 'eventQueue';
+          docs: ['Required while `config.event_queue_enabled`; see `EventQueue`.'];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'seizeApproval';
+          docs: [
+            "A second key's approval of exactly this seizure. Required above",
+            '`config.large_seize_threshold`; closed to the seizer on success.',
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'seizureRecord';
+          writable: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
       ];
       args: [
         {
           name: 'amount';
           type: 'u64';
         },
+        {
+          name: 'reason';
+          type: 'string';
+        },
       ];
     },
     {
@@ -439,52 +617,6 @@ export type SssCore = {
       ];
       args: [];
     },
-    {
-      name: 'transferAuthority';
-      discriminator: [48, 169, 76, 72, 229, 180, 55, 161];
-      accounts: [
-        {
-          name: 'admin';
-          writable: true;
-          signer: true;
-        },
-        {
-          name: 'config';
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: 'const';
-                value: [115, 115, 115, 45, 99, 111, 110, 102, 105, 103];
-              },
-              {
-                kind: 'account';
-                path: 'config.mint';
-                account: 'stablecoinConfig';
-              },
-            ];
-          };
-        },
-        {
-          name: 'adminRole';
-          docs: ["The caller's admin role PDA — will be closed."];
-          writable: true;
-        },
-        {
-          name: 'newAuthority';
-        },
-        {
-          name: 'newAdminRole';
-          docs: ["The new authority's admin role PDA — will be created."];
-          writable: true;
-        },
-        {
-          name: 'systemProgram';
-          address: '11111111111111111111111111111111';
-        },
-      ];
-      args: [];
-    },
     {
       name: 'unpause';
       discriminator: [169, 144, 4, 38, 10, 141, 188, 255];
@@ -1236,8 +1368,8 @@ export type SssCore = {
             type: 'u8';
           },
           {
-            name: 'paused';
-            type: 'bool';
+            name: 'pauseFlags';
+            type: 'u8';
           },
           {
             name: 'supplyCap';
@@ -37,6 +37,7 @@ import {
   ROLE_ID_MAP,
   TIER_ORDINAL_MAP,
   ORDINAL_TO_TIER_MAP,
+  PAUSE_FLAGS,
   StablecoinTiers,
   asTier,
   asRole,
@@ -48,6 +49,7 @@ import {
 export { ROLE_ID_MAP };
 export { TIER_ORDINAL_MAP };
 export { ORDINAL_TO_TIER_MAP };
+export { PAUSE_FLAGS };
 export { StablecoinTiers };
 /** @deprecated Use asTier instead */
 export { asTier as present };
@@ -75,16 +77,28 @@ export const REVERSE_PRESET_MAP = ORDINAL_TO_TIER_MAP;
 import {
   deriveConfigPda,
   deriveRolePda,
+  deriveRoleDirectoryPda,
+  derivePendingRolePda,
+  deriveSeizureRecordPda,
   deriveBlacklistPda,
   deriveExtraAccountMetasPda,
+  deriveHookConfigPda,
+  deriveRiskScorePda,
+  deriveAccountHoldPda,
   STBL_CORE_PROGRAM_ID,
   STBL_HOOK_PROGRAM_ID,
 } from './pda';
 
 export { deriveConfigPda };
 export { deriveRolePda };
+export { deriveRoleDirectoryPda };
+export { derivePendingRolePda };
+export { deriveSeizureRecordPda };
 export { deriveBlacklistPda };
 export { deriveExtraAccountMetasPda };
+export { deriveHookConfigPda };
+export { deriveRiskScorePda };
+export { deriveAccountHoldPda };
 export { STBL_CORE_PROGRAM_ID };
 export { STBL_HOOK_PROGRAM_ID };
 
@@ -203,13 +217,16 @@ import {
   createResumeInstruction,
   createSeizeInstruction,
   createGrantInstruction,
+  createRoleAcceptInstruction,
   createRevokeInstruction,
-  createAuthorityTransferInstruction,
+  createAuthorityProposalInstruction,
+  createAuthorityAcceptInstruction,
   createMinterUpdateInstruction,
   createCapUpdateInstruction,
   createHookMetaInitInstruction,
   createDenyListAddInstruction,
   createDenyListRemoveInstruction,
+  resolveTransferHookAccounts,
 } from './instructions';
 
 export { createInitInstruction };
@@ -221,13 +238,16 @@ export { createPauseInstruction };
 export { createResumeInstruction };
 export { createSeizeInstruction };
 export { createGrantInstruction };
+export { createRoleAcceptInstruction };
 export { createRevokeInstruction };
-export { createAuthorityTransferInstruction };
+export { createAuthorityProposalInstruction };
+export { createAuthorityAcceptInstruction };
 export { createMinterUpdateInstruction };
 export { createCapUpdateInstruction };
 export { createHookMetaInitInstruction };
 export { createDenyListAddInstruction };
 export { createDenyListRemoveInstruction };
+export { resolveTransferHookAccounts };
 
 export type { SssCore } from './idl/sss_core';
 export type { SssTransferHook } from './idl/sss_transfer_hook';
@@ -250,3 +270,21 @@ export { convertRawAmountToUsd };
 export { packOracleMeta };
 export { PRICE_FEED_REGISTRY };
 export type { PriceFeedData };
+
+import {
+  encodeSignedMessage,
+  createSignedMessageInstruction,
+  SignedMessageKind,
+  SigningCluster,
+  SIGNED_MESSAGE_PREFIX,
+  SIGNED_MESSAGE_VERSION,
+} from './signing';
+import type { SigningDomain } from './signing';
+
+export { encodeSignedMessage };
+export { createSignedMessageInstruction };
+export { SignedMessageKind };
+export { SigningCluster };
+export { SIGNED_MESSAGE_PREFIX };
+export { SIGNED_MESSAGE_VERSION };
+export type { SigningDomain };
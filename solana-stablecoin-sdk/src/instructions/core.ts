@@ -2,7 +2,13 @@ import { Program, BN } from '@coral-xyz/anchor';
 import { PublicKey } from '@solana/web3.js';
 import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
 import type { SssCore } from '../idl/sss_core';
-import { deriveConfigPda, deriveRolePda } from '../pda';
+import {
+  deriveConfigPda,
+  derivePendingRolePda,
+  deriveRoleDirectoryPda,
+  deriveRolePda,
+  deriveSeizureRecordPda,
+} from '../pda';
 import type { AccessRole, TokenMintKey, ConfigAccountKey, RoleAccountKey } from '../types';
 import { ROLE_ID_MAP, asRole } from '../types';
 
@@ -154,14 +160,16 @@ export function createThawInstruction(
 }
 
 /**
- * Build the `pause` instruction.
+ * Build the `pause` instruction. Pass `asRole('guardian')` when `pauser`
+ * holds the Guardian role.
  */
 export function createPauseInstruction(
   program: Program<SssCore>,
   configPda: ConfigAccountKey,
   pauser: PublicKey,
+  role: AccessRole = asRole('pauser'),
 ) {
-  const [pauserRolePda] = deriveRolePda(configPda, pauser, asRole('pauser'), program.programId);
+  const [pauserRolePda] = deriveRolePda(configPda, pauser, role, program.programId);
 
   return program.methods
     .pause()
@@ -174,14 +182,16 @@ export function createPauseInstruction(
 }
 
 /**
- * Build the `unpause` instruction.
+ * Build the `unpause` instruction. Pass `asRole('admin')` while the config
+ * has `unpause_requires_admin` set.
  */
 export function createResumeInstruction(
   program: Program<SssCore>,
   configPda: ConfigAccountKey,
   pauser: PublicKey,
+  role: AccessRole = asRole('pauser'),
 ) {
-  const [pauserRolePda] = deriveRolePda(configPda, pauser, asRole('pauser'), program.programId);
+  const [pauserRolePda] = deriveRolePda(configPda, pauser, role, program.programId);
 
   return program.methods
     .unpause()
@@ -194,7 +204,8 @@ export function createResumeInstruction(
 }
 
 /**
- * Build the `seize` instruction.
+ * Build the `seize` instruction. `reason` is stored in the `SeizureRecord`
+ * at `seizureIndex`, which must be the config's current `seizure_count`.
  */
 export function createSeizeInstruction(
   program: Program<SssCore>,
@@ -203,12 +214,15 @@ export function createSeizeInstruction(
   fromAta: PublicKey,
   toAta: PublicKey,
   amount: BN,
+  reason: string,
+  seizureIndex: bigint,
 ) {
   const [configPda] = deriveConfigPda(mint, program.programId);
   const [seizerRolePda] = deriveRolePda(configPda, seizer, asRole('seizer'), program.programId);
+  const [seizureRecordPda] = deriveSeizureRecordPda(configPda, seizureIndex, program.programId);
 
   return (
-    program.methods.seize(amount).accounts({
+    program.methods.seize(amount, reason).accounts({
       seizer,
       mint,
       config: configPda,
@@ -216,12 +230,16 @@ export function createSeizeInstruction(
       from: fromAta,
       to: toAta,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
+      eventQueue: null,
+      seizeApproval: null,
+      seizureRecord: seizureRecordPda,
     } as any) as any
   ).instruction();
 }
 
 /**
- * Build the `grantRole` instruction.
+ * Build the `grantRole` instruction. The role only takes effect once the
+ * grantee signs `acceptRole`.
  */
 export function createGrantInstruction(
   program: Program<SssCore>,
@@ -231,7 +249,7 @@ export function createGrantInstruction(
   role: AccessRole,
 ) {
   const [adminRolePda] = deriveRolePda(configPda, admin, asRole('admin'), program.programId);
-  const [roleAccountPda] = deriveRolePda(configPda, grantee, role, program.programId);
+  const [pendingRolePda] = derivePendingRolePda(configPda, grantee, role, program.programId);
 
   return program.methods
     .grantRole((ROLE_ID_MAP as any)[role] as number)
@@ -240,7 +258,32 @@ export function createGrantInstruction(
       config: configPda,
       adminRole: adminRolePda,
       grantee,
+      pendingRole: pendingRolePda,
+    })
+    .instruction();
+}
+
+/**
+ * Build the `acceptRole` instruction. Signed by the grantee.
+ */
+export function createRoleAcceptInstruction(
+  program: Program<SssCore>,
+  configPda: ConfigAccountKey,
+  grantee: PublicKey,
+  role: AccessRole,
+) {
+  const [pendingRolePda] = derivePendingRolePda(configPda, grantee, role, program.programId);
+  const [roleAccountPda] = deriveRolePda(configPda, grantee, role, program.programId);
+  const [roleDirectoryPda] = deriveRoleDirectoryPda(configPda, role, program.programId);
+
+  return program.methods
+    .acceptRole()
+    .accountsPartial({
+      grantee,
+      config: configPda,
+      pendingRole: pendingRolePda,
       roleAccount: roleAccountPda,
+      roleDirectory: roleDirectoryPda,
     })
     .instruction();
 }
@@ -268,30 +311,57 @@ export function createRevokeInstruction(
 }
 
 /**
- * Build the `transferAuthority` instruction.
+ * Build the `proposeAuthority` instruction. Signed by the current authority.
  */
-export function createAuthorityTransferInstruction(
+export function createAuthorityProposalInstruction(
   program: Program<SssCore>,
   configPda: ConfigAccountKey,
   admin: PublicKey,
   newAuthority: PublicKey,
 ) {
   const [adminRolePda] = deriveRolePda(configPda, admin, asRole('admin'), program.programId);
-  // derive new admin role PDA
+  return program.methods
+    .proposeAuthority()
+    .accountsPartial({
+      admin,
+      config: configPda,
+      adminRole: adminRolePda,
+      newAuthority,
+    })
+    .instruction();
+}
+
+/**
+ * Build the `acceptAuthority` instruction. Signed by the proposed authority.
+ */
+export function createAuthorityAcceptInstruction(
+  program: Program<SssCore>,
+  configPda: ConfigAccountKey,
+  oldAuthority: PublicKey,
+  newAuthority: PublicKey,
+) {
+  const [oldAdminRolePda] = deriveRolePda(
+    configPda,
+    oldAuthority,
+    asRole('admin'),
+    program.programId,
+  );
   const [newAdminRolePda] = deriveRolePda(
     configPda,
     newAuthority,
     asRole('admin'),
     program.programId,
   );
+  const [adminDirectoryPda] = deriveRoleDirectoryPda(configPda, asRole('admin'), program.programId);
   return program.methods
-    .transferAuthority()
+    .acceptAuthority()
     .accountsPartial({
-      admin,
-      config: configPda,
-      adminRole: adminRolePda,
       newAuthority,
+      config: configPda,
+      oldAuthority,
+      oldAdminRole: oldAdminRolePda,
       newAdminRole: newAdminRolePda,
+      adminDirectory: adminDirectoryPda,
     })
     .instruction();
 }
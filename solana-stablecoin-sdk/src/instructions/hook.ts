@@ -1,8 +1,19 @@
 import { Program } from '@coral-xyz/anchor';
 import { PublicKey } from '@solana/web3.js';
+import type { AccountMeta } from '@solana/web3.js';
 import type { SssTransferHook } from '../idl/sss_transfer_hook';
 import type { TokenMintKey } from '../types';
-import { deriveBlacklistPda, deriveConfigPda, deriveRolePda, STBL_CORE_PROGRAM_ID } from '../pda';
+import {
+  deriveAccountHoldPda,
+  deriveBlacklistPda,
+  deriveConfigPda,
+  deriveExtraAccountMetasPda,
+  deriveHookConfigPda,
+  deriveRiskScorePda,
+  deriveRolePda,
+  STBL_CORE_PROGRAM_ID,
+  STBL_HOOK_PROGRAM_ID,
+} from '../pda';
 import { asRole } from '../types';
 
 /**
@@ -84,3 +95,35 @@ export function createDenyListRemoveInstruction(
     })
     .instruction();
 }
+
+/**
+ * The accounts to append to a Token-2022 `transferChecked` from `source`
+ * (owned by `sender`) to a token account owned by `receiver`: the hook's
+ * extra accounts in ExtraAccountMetaList order, then the hook program and the
+ * list itself.
+ *
+ * Derived without RPC, so composed transactions and programs that CPI into
+ * Token-2022 can pass them as remaining accounts up front. None of the policy
+ * accounts has to exist; the hook treats a missing one as passing.
+ */
+export function resolveTransferHookAccounts(
+  mint: TokenMintKey,
+  source: PublicKey,
+  sender: PublicKey,
+  receiver: PublicKey,
+  hookProgramId: PublicKey = STBL_HOOK_PROGRAM_ID,
+  coreProgramId: PublicKey = STBL_CORE_PROGRAM_ID,
+): AccountMeta[] {
+  const keys = [
+    deriveBlacklistPda(mint, sender, hookProgramId)[0],
+    deriveBlacklistPda(mint, receiver, hookProgramId)[0],
+    deriveConfigPda(mint, coreProgramId)[0],
+    deriveHookConfigPda(mint, hookProgramId)[0],
+    deriveRiskScorePda(mint, sender, hookProgramId)[0],
+    deriveRiskScorePda(mint, receiver, hookProgramId)[0],
+    deriveAccountHoldPda(mint, source, hookProgramId)[0],
+    hookProgramId,
+    deriveExtraAccountMetasPda(mint, hookProgramId)[0],
+  ];
+  return keys.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }));
+}
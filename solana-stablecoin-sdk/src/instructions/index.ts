@@ -8,8 +8,10 @@ export {
   createResumeInstruction,
   createSeizeInstruction,
   createGrantInstruction,
+  createRoleAcceptInstruction,
   createRevokeInstruction,
-  createAuthorityTransferInstruction,
+  createAuthorityProposalInstruction,
+  createAuthorityAcceptInstruction,
   createMinterUpdateInstruction,
   createCapUpdateInstruction,
 } from './core';
@@ -18,4 +20,5 @@ export {
   createHookMetaInitInstruction,
   createDenyListAddInstruction,
   createDenyListRemoveInstruction,
+  resolveTransferHookAccounts,
 } from './hook';
@@ -16,8 +16,14 @@ export const STBL_HOOK_PROGRAM_ID = new PublicKey('HookFvKFaoF9KL8TUXUnQK5r2mJoM
 // Static Seeds
 const STBL_CONFIG_SEED = Buffer.from('sss-config');
 const STBL_ROLE_SEED = Buffer.from('sss-role');
+const STBL_ROLE_DIRECTORY_SEED = Buffer.from('sss-role-dir');
+const STBL_PENDING_ROLE_SEED = Buffer.from('sss-pending-role');
 const DENY_LIST_SEED = Buffer.from('blacklist');
 const HOOK_EXTRA_METAS_SEED = Buffer.from('extra-account-metas');
+const HOOK_CONFIG_SEED = Buffer.from('hook-config');
+const RISK_SCORE_SEED = Buffer.from('risk-score');
+const ACCOUNT_HOLD_SEED = Buffer.from('account-hold');
+const STBL_SEIZURE_RECORD_SEED = Buffer.from('sss-seizure');
 
 export function deriveConfigPda(
   mint: TokenMintKey,
@@ -48,6 +54,55 @@ export function deriveRolePda(
   return [pda as RoleAccountKey, bump];
 }
 
+export function deriveRoleDirectoryPda(
+  config: ConfigAccountKey,
+  role: AccessRole,
+  programId: PublicKey = STBL_CORE_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      STBL_ROLE_DIRECTORY_SEED,
+      config.toBuffer(),
+      Buffer.from([(ROLE_ID_MAP as any)[role] as number]),
+    ],
+    programId,
+  );
+}
+
+export function derivePendingRolePda(
+  config: ConfigAccountKey,
+  address: PublicKey,
+  role: AccessRole,
+  programId: PublicKey = STBL_CORE_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      STBL_PENDING_ROLE_SEED,
+      config.toBuffer(),
+      address.toBuffer(),
+      Buffer.from([(ROLE_ID_MAP as any)[role] as number]),
+    ],
+    programId,
+  );
+}
+
+/**
+ * Derive the `SeizureRecord` for a config's `index`th seizure. Records are
+ * never closed, so the next seizure uses the first index without one.
+ */
+export function deriveSeizureRecordPda(
+  config: ConfigAccountKey,
+  index: bigint,
+  programId: PublicKey = STBL_CORE_PROGRAM_ID,
+): [PublicKey, number] {
+  const indexBytes = Buffer.alloc(8);
+  indexBytes.writeBigUInt64LE(index);
+  return PublicKey.findProgramAddressSync(
+    [STBL_SEIZURE_RECORD_SEED, config.toBuffer(), indexBytes],
+    programId,
+  );
+}
+
 export function deriveBlacklistPda(
   mint: TokenMintKey,
   address: PublicKey,
@@ -66,3 +121,32 @@ export function deriveExtraAccountMetasPda(
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([HOOK_EXTRA_METAS_SEED, mint.toBuffer()], programId);
 }
+
+export function deriveHookConfigPda(
+  mint: TokenMintKey,
+  programId: PublicKey = STBL_HOOK_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([HOOK_CONFIG_SEED, mint.toBuffer()], programId);
+}
+
+export function deriveRiskScorePda(
+  mint: TokenMintKey,
+  wallet: PublicKey,
+  programId: PublicKey = STBL_HOOK_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [RISK_SCORE_SEED, mint.toBuffer(), wallet.toBuffer()],
+    programId,
+  );
+}
+
+export function deriveAccountHoldPda(
+  mint: TokenMintKey,
+  tokenAccount: PublicKey,
+  programId: PublicKey = STBL_HOOK_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [ACCOUNT_HOLD_SEED, mint.toBuffer(), tokenAccount.toBuffer()],
+    programId,
+  );
+}
@@ -0,0 +1,73 @@
+import { Ed25519Program, Keypair, PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { Buffer } from 'buffer';
+
+// Off-chain message format shared with `sss_core::signing`. The layout is:
+//
+//   0xff "sss-offchain" | version u8 | kind u8 | cluster u8
+//     | program_id (32) | mint (32) | nonce u64 LE | payload_len u32 LE | payload
+//
+// Sign these bytes for permits, attestations and guardian approvals; never
+// invent a per-feature format.
+
+export const SIGNED_MESSAGE_PREFIX = Buffer.concat([
+  Buffer.from([0xff]),
+  Buffer.from('sss-offchain'),
+]);
+export const SIGNED_MESSAGE_VERSION = 1;
+
+export const SignedMessageKind = {
+  Permit: 0,
+  Attestation: 1,
+  GuardianApproval: 2,
+} as const;
+export type SignedMessageKind = (typeof SignedMessageKind)[keyof typeof SignedMessageKind];
+
+export const SigningCluster = {
+  Mainnet: 0,
+  Devnet: 1,
+  Testnet: 2,
+  Localnet: 3,
+} as const;
+export type SigningCluster = (typeof SigningCluster)[keyof typeof SigningCluster];
+
+export interface SigningDomain {
+  programId: PublicKey;
+  mint: PublicKey;
+  cluster: SigningCluster;
+}
+
+/** The exact bytes to sign for `kind` with `nonce` and `payload`. */
+export function encodeSignedMessage(
+  domain: SigningDomain,
+  kind: SignedMessageKind,
+  nonce: bigint,
+  payload: Uint8Array,
+): Buffer {
+  const nonceBytes = Buffer.alloc(8);
+  nonceBytes.writeBigUInt64LE(nonce);
+  const lengthBytes = Buffer.alloc(4);
+  lengthBytes.writeUInt32LE(payload.length);
+  return Buffer.concat([
+    SIGNED_MESSAGE_PREFIX,
+    Buffer.from([SIGNED_MESSAGE_VERSION, kind, domain.cluster]),
+    domain.programId.toBuffer(),
+    domain.mint.toBuffer(),
+    nonceBytes,
+    lengthBytes,
+    Buffer.from(payload),
+  ]);
+}
+
+/**
+ * Ed25519 precompile instruction proving `signer` signed `message`. Place it
+ * before the SSS instruction that consumes the signature.
+ */
+export function createSignedMessageInstruction(
+  signer: Keypair,
+  message: Uint8Array,
+): TransactionInstruction {
+  return Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: signer.secretKey,
+    message,
+  });
+}
@@ -4,17 +4,32 @@ declare const __brand: unique symbol;
 export type Brand<T, B extends string> = T & { readonly [__brand]: B };
 
 export type TierLabel = Brand<'sss-1' | 'sss-2' | 'sss-3', 'TierLabel'>;
-export type AccessRole = Brand<
-  'admin' | 'minter' | 'freezer' | 'pauser' | 'burner' | 'blacklister' | 'seizer',
-  'AccessRole'
+type RoleLabel =
+  | 'admin'
+  | 'minter'
+  | 'freezer'
+  | 'pauser'
+  | 'burner'
+  | 'blacklister'
+  | 'seizer'
+  | 'bridge'
+  | 'metadata-manager'
+  | 'quota-manager'
+  | 'rescuer'
+  | 'guardian'
+  | 'mint-requester'
+  | 'attestor';
+export type AccessRole = Brand<RoleLabel, 'AccessRole'>;
+export type AccessRoleId = Brand<
+  0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13,
+  'AccessRoleId'
 >;
-export type AccessRoleId = Brand<0 | 1 | 2 | 3 | 4 | 5 | 6, 'AccessRoleId'>;
 
 export const asTier = (v: 'sss-1' | 'sss-2' | 'sss-3'): TierLabel => v as TierLabel;
-export const asRole = (
-  v: 'admin' | 'minter' | 'freezer' | 'pauser' | 'burner' | 'blacklister' | 'seizer',
-): AccessRole => v as AccessRole;
-export const asRoleId = (v: 0 | 1 | 2 | 3 | 4 | 5 | 6): AccessRoleId => v as AccessRoleId;
+export const asRole = (v: RoleLabel): AccessRole => v as AccessRole;
+export const asRoleId = (
+  v: 0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13,
+): AccessRoleId => v as AccessRoleId;
 
 // Branded key types for compile-time distinction of PublicKey uses
 export type TokenMintKey = Brand<PublicKey, 'TokenMintKey'>;
@@ -43,7 +58,10 @@ export interface TokenStateSnapshot {
   mint: TokenMintKey;
   authority: PublicKey;
   preset: TierLabel;
+  /** Whether any operation class is paused. */
   paused: boolean;
+  /** Paused operation classes; see `PAUSE_FLAGS`. */
+  pauseFlags: number;
   supplyCap: bigint | null;
   totalMinted: bigint;
   totalBurned: bigint;
@@ -75,6 +93,13 @@ export const ROLE_ID_MAP: Record<string, AccessRoleId> = {
   burner: asRoleId(4),
   blacklister: asRoleId(5),
   seizer: asRoleId(6),
+  bridge: asRoleId(7),
+  'metadata-manager': asRoleId(8),
+  'quota-manager': asRoleId(9),
+  rescuer: asRoleId(10),
+  guardian: asRoleId(11),
+  'mint-requester': asRoleId(12),
+  attestor: asRoleId(13),
 };
 
 export const TIER_ORDINAL_MAP: Record<string, number> = {
@@ -89,6 +114,15 @@ export const ORDINAL_TO_TIER_MAP: Record<number, TierLabel> = {
   3: asTier('sss-3'),
 };
 
+/** Operation class bits of `StablecoinConfig.pause_flags`. */
+export const PAUSE_FLAGS = {
+  MINT: 1 << 0,
+  BURN: 1 << 1,
+  FREEZE_THAW: 1 << 2,
+  TRANSFERS: 1 << 3,
+  ALL: 0x0f,
+} as const;
+
 export const StablecoinTiers = {
   SSS_1: asTier('sss-1'),
   SSS_2: asTier('sss-2'),
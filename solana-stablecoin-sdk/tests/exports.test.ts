@@ -26,8 +26,10 @@ describe('SDK barrel exports', () => {
       'createResumeInstruction',
       'createSeizeInstruction',
       'createGrantInstruction',
+      'createRoleAcceptInstruction',
       'createRevokeInstruction',
-      'createAuthorityTransferInstruction',
+      'createAuthorityProposalInstruction',
+      'createAuthorityAcceptInstruction',
       'createMinterUpdateInstruction',
       'createCapUpdateInstruction',
       'createHookMetaInitInstruction',
@@ -39,8 +41,8 @@ describe('SDK barrel exports', () => {
       expect(typeof (SDK as Record<string, unknown>)[name]).toBe('function');
     });
 
-    it('exports exactly 16 instruction builders', () => {
-      expect(builders).toHaveLength(16);
+    it('exports exactly 18 instruction builders', () => {
+      expect(builders).toHaveLength(18);
     });
   });
 
@@ -51,9 +53,26 @@ describe('SDK barrel exports', () => {
     it('exports deriveRolePda', () => {
       expect(typeof SDK.deriveRolePda).toBe('function');
     });
+    it('exports deriveRoleDirectoryPda', () => {
+      expect(typeof SDK.deriveRoleDirectoryPda).toBe('function');
+    });
+    it('exports derivePendingRolePda', () => {
+      expect(typeof SDK.derivePendingRolePda).toBe('function');
+    });
+    it('exports deriveSeizureRecordPda', () => {
+      expect(typeof SDK.deriveSeizureRecordPda).toBe('function');
+    });
     it('exports deriveBlacklistPda', () => {
       expect(typeof SDK.deriveBlacklistPda).toBe('function');
     });
+    it('exports the hook policy derivers', () => {
+      expect(typeof SDK.deriveHookConfigPda).toBe('function');
+      expect(typeof SDK.deriveRiskScorePda).toBe('function');
+      expect(typeof SDK.deriveAccountHoldPda).toBe('function');
+    });
+    it('exports resolveTransferHookAccounts', () => {
+      expect(typeof SDK.resolveTransferHookAccounts).toBe('function');
+    });
   });
 
   describe('preset creators', () => {
@@ -120,8 +139,8 @@ describe('SDK barrel exports', () => {
   });
 
   describe('type maps', () => {
-    it('exports ROLE_MAP with 7 roles', () => {
-      expect(Object.keys(SDK.ROLE_MAP)).toHaveLength(7);
+    it('exports ROLE_MAP with 14 roles', () => {
+      expect(Object.keys(SDK.ROLE_MAP)).toHaveLength(14);
     });
     it('exports PRESET_MAP with 3 presets', () => {
       expect(Object.keys(SDK.PRESET_MAP)).toHaveLength(3);
@@ -129,5 +148,9 @@ describe('SDK barrel exports', () => {
     it('exports REVERSE_PRESET_MAP', () => {
       expect(SDK.REVERSE_PRESET_MAP).toBeDefined();
     });
+    it('exports PAUSE_FLAGS covering every operation class', () => {
+      const { MINT, BURN, FREEZE_THAW, TRANSFERS, ALL } = SDK.PAUSE_FLAGS;
+      expect(MINT | BURN | FREEZE_THAW | TRANSFERS).toBe(ALL);
+    });
   });
 });
@@ -3,11 +3,17 @@ import { PublicKey } from '@solana/web3.js';
 import {
   deriveConfigPda,
   deriveRolePda,
+  derivePendingRolePda,
+  deriveSeizureRecordPda,
   deriveBlacklistPda,
   deriveExtraAccountMetasPda,
+  deriveHookConfigPda,
+  deriveRiskScorePda,
+  deriveAccountHoldPda,
   STBL_CORE_PROGRAM_ID,
   STBL_HOOK_PROGRAM_ID,
 } from '../src/pda';
+import { resolveTransferHookAccounts } from '../src/instructions';
 import { asMint, asConfig, asRole } from '../src/types';
 
 describe('PDA derivation', () => {
@@ -68,6 +74,26 @@ describe('PDA derivation', () => {
     expect(pdaSet.size).toBe(4);
   });
 
+  it('derives a pending role PDA distinct from the role PDA', () => {
+    const config = asConfig(PublicKey.unique());
+    const address = PublicKey.unique();
+    const [pending] = derivePendingRolePda(config, address, asRole('seizer'), STBL_CORE_PROGRAM_ID);
+    const [role] = deriveRolePda(config, address, asRole('seizer'), STBL_CORE_PROGRAM_ID);
+    expect(pending.equals(role)).toBe(false);
+  });
+
+  it('derives seizure record PDAs from the little-endian index', () => {
+    const config = asConfig(PublicKey.unique());
+    const [first] = deriveSeizureRecordPda(config, 0n, STBL_CORE_PROGRAM_ID);
+    const [second] = deriveSeizureRecordPda(config, 1n, STBL_CORE_PROGRAM_ID);
+    const [expected] = PublicKey.findProgramAddressSync(
+      [Buffer.from('sss-seizure'), config.toBuffer(), Buffer.from([1, 0, 0, 0, 0, 0, 0, 0])],
+      STBL_CORE_PROGRAM_ID,
+    );
+    expect(first.equals(second)).toBe(false);
+    expect(second.equals(expected)).toBe(true);
+  });
+
   it('derives blacklist PDA deterministically', () => {
     const mint = asMint(PublicKey.unique());
     const address = PublicKey.unique();
@@ -118,4 +144,33 @@ describe('PDA derivation', () => {
     const [blExplicit] = deriveBlacklistPda(mint, address, STBL_HOOK_PROGRAM_ID);
     expect(blDefault.equals(blExplicit)).toBe(true);
   });
+
+  it('derives distinct risk score PDAs per wallet', () => {
+    const mint = asMint(PublicKey.unique());
+    const [sender] = deriveRiskScorePda(mint, PublicKey.unique());
+    const [receiver] = deriveRiskScorePda(mint, PublicKey.unique());
+    expect(sender.equals(receiver)).toBe(false);
+  });
+
+  it('resolves transfer hook accounts in ExtraAccountMetaList order', () => {
+    const mint = asMint(PublicKey.unique());
+    const source = PublicKey.unique();
+    const sender = PublicKey.unique();
+    const receiver = PublicKey.unique();
+    const metas = resolveTransferHookAccounts(mint, source, sender, receiver);
+
+    const expected = [
+      deriveBlacklistPda(mint, sender)[0],
+      deriveBlacklistPda(mint, receiver)[0],
+      deriveConfigPda(mint)[0],
+      deriveHookConfigPda(mint)[0],
+      deriveRiskScorePda(mint, sender)[0],
+      deriveRiskScorePda(mint, receiver)[0],
+      deriveAccountHoldPda(mint, source)[0],
+      STBL_HOOK_PROGRAM_ID,
+      deriveExtraAccountMetasPda(mint)[0],
+    ];
+    expect(metas.map((m) => m.pubkey.toBase58())).toEqual(expected.map((k) => k.toBase58()));
+    expect(metas.every((m) => !m.isSigner && !m.isWritable)).toBe(true);
+  });
 });
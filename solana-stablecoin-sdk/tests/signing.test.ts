@@ -0,0 +1,76 @@
+import { describe, it, expect } from 'vitest';
+import { Ed25519Program, Keypair, PublicKey } from '@solana/web3.js';
+import {
+  createSignedMessageInstruction,
+  encodeSignedMessage,
+  SignedMessageKind,
+  SigningCluster,
+} from '../src/signing';
+
+// Same vector as `sss_core::signing` tests; both encoders must agree.
+const PERMIT_VECTOR =
+  'ff7373732d6f6666636861696e010001' +
+  '01'.repeat(32) +
+  '02'.repeat(32) +
+  '0700000000000000' +
+  '05000000' +
+  '68656c6c6f';
+
+const domain = {
+  programId: new PublicKey(new Uint8Array(32).fill(1)),
+  mint: new PublicKey(new Uint8Array(32).fill(2)),
+  cluster: SigningCluster.Devnet,
+};
+
+describe('off-chain message signing', () => {
+  it('matches the shared permit vector', () => {
+    const message = encodeSignedMessage(
+      domain,
+      SignedMessageKind.Permit,
+      7n,
+      Buffer.from('hello'),
+    );
+    expect(message.toString('hex')).toBe(PERMIT_VECTOR);
+  });
+
+  it('binds the message to the mint, cluster and kind', () => {
+    const payload = Buffer.from('hello');
+    const permit = encodeSignedMessage(domain, SignedMessageKind.Permit, 7n, payload);
+    const variants = [
+      encodeSignedMessage(
+        { ...domain, mint: PublicKey.unique() },
+        SignedMessageKind.Permit,
+        7n,
+        payload,
+      ),
+      encodeSignedMessage(
+        { ...domain, cluster: SigningCluster.Mainnet },
+        SignedMessageKind.Permit,
+        7n,
+        payload,
+      ),
+      encodeSignedMessage(domain, SignedMessageKind.GuardianApproval, 7n, payload),
+      encodeSignedMessage(domain, SignedMessageKind.Permit, 8n, payload),
+    ];
+    for (const variant of variants) {
+      expect(variant.equals(permit)).toBe(false);
+    }
+  });
+
+  it('builds an Ed25519 instruction in the layout the program parses', () => {
+    const signer = Keypair.generate();
+    const message = encodeSignedMessage(
+      domain,
+      SignedMessageKind.Attestation,
+      1n,
+      Buffer.from('reserve'),
+    );
+    const ix = createSignedMessageInstruction(signer, message);
+    expect(ix.programId.equals(Ed25519Program.programId)).toBe(true);
+    expect(ix.data[0]).toBe(1);
+    expect(ix.data.readUInt16LE(6)).toBe(16);
+    expect(ix.data.readUInt16LE(10)).toBe(112);
+    expect(new PublicKey(ix.data.subarray(16, 48)).equals(signer.publicKey)).toBe(true);
+    expect(ix.data.subarray(112).equals(message)).toBe(true);
+  });
+});
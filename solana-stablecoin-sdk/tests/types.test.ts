@@ -17,10 +17,17 @@ describe('Type mappings', () => {
       expect(ROLE_ID_MAP['burner']).toBe(4);
       expect(ROLE_ID_MAP['blacklister']).toBe(5);
       expect(ROLE_ID_MAP['seizer']).toBe(6);
+      expect(ROLE_ID_MAP['bridge']).toBe(7);
+      expect(ROLE_ID_MAP['metadata-manager']).toBe(8);
+      expect(ROLE_ID_MAP['quota-manager']).toBe(9);
+      expect(ROLE_ID_MAP['rescuer']).toBe(10);
+      expect(ROLE_ID_MAP['guardian']).toBe(11);
+      expect(ROLE_ID_MAP['mint-requester']).toBe(12);
+      expect(ROLE_ID_MAP['attestor']).toBe(13);
     });
 
-    it('has exactly seven roles', () => {
-      expect(Object.keys(ROLE_ID_MAP)).toHaveLength(7);
+    it('has exactly fourteen roles', () => {
+      expect(Object.keys(ROLE_ID_MAP)).toHaveLength(14);
     });
   });
 
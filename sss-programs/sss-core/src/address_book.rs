@@ -0,0 +1,298 @@
+//! Canonical addresses of every SSS account for one mint.
+//!
+//! Integration tests, the CLI and third-party tools should take addresses
+//! from here (or the `pda` helpers it is built on) rather than deriving them
+//! with their own seed constants. `entries()` lists the per-mint accounts
+//! with stable names, e.g. for a devnet address dump, and
+//! `transfer_hook_accounts` the extra accounts of a hooked transfer.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::TRANSFER_HOOK_PROGRAM_ID;
+use crate::pda::{
+    find_account_hold_address, find_action_counter_address, find_blacklist_address,
+    find_collateral_position_address, find_collateral_vault_address, find_config_address,
+    find_credit_line_address, find_destination_throttle_address, find_enforcement_queue_address,
+    find_event_queue_address, find_extra_account_metas_address, find_fee_schedule_address,
+    find_fee_tier_address, find_freeze_exemption_address, find_hook_config_address,
+    find_hook_event_queue_address, find_kyc_entry_address, find_mint_approval_address,
+    find_mint_request_address, find_pending_action_address, find_pending_role_address,
+    find_period_summary_address, find_receivership_address, find_redemption_request_address,
+    find_regulator_access_address, find_reserve_attestation_address, find_reserve_journal_address,
+    find_risk_score_address, find_role_address, find_role_directory_address,
+    find_seize_approval_address, find_seizure_record_address, find_standby_config_address,
+    find_sweep_route_address, find_wipe_record_address,
+};
+use crate::state::Role;
+
+/// The per-mint accounts of both programs. Accounts keyed by a wallet, role
+/// or token account are derived on demand by the methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddressBook {
+    pub mint: Pubkey,
+    /// sss-core `StablecoinConfig`.
+    pub config: Pubkey,
+    /// sss-core `EventQueue`.
+    pub event_queue: Pubkey,
+    /// sss-core `ReserveJournal`.
+    pub reserve_journal: Pubkey,
+    /// sss-core `ReserveAttestation`.
+    pub reserve_attestation: Pubkey,
+    /// sss-core `CollateralVault`.
+    pub collateral_vault: Pubkey,
+    /// sss-core `Receivership`.
+    pub receivership: Pubkey,
+    /// sss-core `FeeSchedule`.
+    pub fee_schedule: Pubkey,
+    /// sss-core `StandbyConfig`.
+    pub standby_config: Pubkey,
+    /// Transfer hook `ExtraAccountMetaList`.
+    pub extra_account_metas: Pubkey,
+    /// Transfer hook `HookConfig`.
+    pub hook_config: Pubkey,
+    /// Transfer hook `HookEventQueue`.
+    pub hook_event_queue: Pubkey,
+    /// Transfer hook `EnforcementQueue`.
+    pub enforcement_queue: Pubkey,
+}
+
+impl AddressBook {
+    pub fn for_mint(mint: Pubkey) -> Self {
+        let config = find_config_address(&mint).0;
+        Self {
+            mint,
+            config,
+            event_queue: find_event_queue_address(&config).0,
+            reserve_journal: find_reserve_journal_address(&config).0,
+            reserve_attestation: find_reserve_attestation_address(&config).0,
+            collateral_vault: find_collateral_vault_address(&config).0,
+            receivership: find_receivership_address(&config).0,
+            fee_schedule: find_fee_schedule_address(&config).0,
+            standby_config: find_standby_config_address(&config).0,
+            extra_account_metas: find_extra_account_metas_address(&mint).0,
+            hook_config: find_hook_config_address(&mint).0,
+            hook_event_queue: find_hook_event_queue_address(&mint).0,
+            enforcement_queue: find_enforcement_queue_address(&mint).0,
+        }
+    }
+
+    /// The per-mint accounts with stable names, in declaration order.
+    pub fn entries(&self) -> [(&'static str, Pubkey); 13] {
+        [
+            ("mint", self.mint),
+            ("config", self.config),
+            ("event_queue", self.event_queue),
+            ("reserve_journal", self.reserve_journal),
+            ("reserve_attestation", self.reserve_attestation),
+            ("collateral_vault", self.collateral_vault),
+            ("receivership", self.receivership),
+            ("fee_schedule", self.fee_schedule),
+            ("standby_config", self.standby_config),
+            ("extra_account_metas", self.extra_account_metas),
+            ("hook_config", self.hook_config),
+            ("hook_event_queue", self.hook_event_queue),
+            ("enforcement_queue", self.enforcement_queue),
+        ]
+    }
+
+    pub fn role(&self, holder: &Pubkey, role: Role) -> Pubkey {
+        find_role_address(&self.config, holder, role).0
+    }
+
+    pub fn role_directory(&self, role: Role) -> Pubkey {
+        find_role_directory_address(&self.config, role).0
+    }
+
+    pub fn pending_role(&self, holder: &Pubkey, role: Role) -> Pubkey {
+        find_pending_role_address(&self.config, holder, role).0
+    }
+
+    pub fn kyc_entry(&self, owner: &Pubkey) -> Pubkey {
+        find_kyc_entry_address(&self.config, owner).0
+    }
+
+    pub fn freeze_exemption(&self, owner: &Pubkey) -> Pubkey {
+        find_freeze_exemption_address(&self.config, owner).0
+    }
+
+    pub fn regulator_access(&self, regulator: &Pubkey) -> Pubkey {
+        find_regulator_access_address(&self.config, regulator).0
+    }
+
+    pub fn destination_throttle(&self, token_account: &Pubkey) -> Pubkey {
+        find_destination_throttle_address(&self.config, token_account).0
+    }
+
+    pub fn fee_tier(&self, wallet: &Pubkey) -> Pubkey {
+        find_fee_tier_address(&self.config, wallet).0
+    }
+
+    pub fn sweep_route(&self, intake: &Pubkey) -> Pubkey {
+        find_sweep_route_address(&self.config, intake).0
+    }
+
+    pub fn credit_line(&self, market_maker: &Pubkey) -> Pubkey {
+        find_credit_line_address(&self.config, market_maker).0
+    }
+
+    pub fn collateral_position(&self, owner: &Pubkey) -> Pubkey {
+        find_collateral_position_address(&self.collateral_vault, owner).0
+    }
+
+    pub fn mint_approval(&self, minter: &Pubkey, id: u64) -> Pubkey {
+        find_mint_approval_address(&self.config, minter, id).0
+    }
+
+    pub fn mint_request(&self, requester: &Pubkey, id: u64) -> Pubkey {
+        find_mint_request_address(&self.config, requester, id).0
+    }
+
+    pub fn redemption_request(&self, holder: &Pubkey, id: u64) -> Pubkey {
+        find_redemption_request_address(&self.config, holder, id).0
+    }
+
+    pub fn seize_approval(&self, approver: &Pubkey, id: u64) -> Pubkey {
+        find_seize_approval_address(&self.config, approver, id).0
+    }
+
+    pub fn seizure_record(&self, index: u64) -> Pubkey {
+        find_seizure_record_address(&self.config, index).0
+    }
+
+    pub fn wipe_record(&self, index: u64) -> Pubkey {
+        find_wipe_record_address(&self.config, index).0
+    }
+
+    pub fn period_summary(&self, period_id: u64) -> Pubkey {
+        find_period_summary_address(&self.config, period_id).0
+    }
+
+    pub fn pending_action(&self, id: u64) -> Pubkey {
+        find_pending_action_address(&self.config, id).0
+    }
+
+    pub fn blacklist_entry(&self, wallet: &Pubkey) -> Pubkey {
+        find_blacklist_address(&self.mint, wallet).0
+    }
+
+    pub fn risk_score(&self, wallet: &Pubkey) -> Pubkey {
+        find_risk_score_address(&self.mint, wallet).0
+    }
+
+    pub fn account_hold(&self, token_account: &Pubkey) -> Pubkey {
+        find_account_hold_address(&self.mint, token_account).0
+    }
+
+    pub fn action_counter(&self, blacklister: &Pubkey) -> Pubkey {
+        find_action_counter_address(&self.mint, blacklister).0
+    }
+
+    /// The accounts Token-2022 needs appended to a `transfer_checked` from
+    /// `source` (owned by `sender`) to a token account owned by `receiver`:
+    /// the hook's extra accounts in `ExtraAccountMetaList` order, then the
+    /// hook program and the list itself.
+    ///
+    /// Derived without RPC, so composed transactions and programs that CPI
+    /// into Token-2022 can pass them as remaining accounts up front. None of
+    /// the policy accounts has to exist: the hook treats a missing blacklist
+    /// entry, hook config, risk score or hold as passing, so nothing needs to
+    /// be created before a first transfer.
+    pub fn transfer_hook_accounts(
+        &self,
+        source: &Pubkey,
+        sender: &Pubkey,
+        receiver: &Pubkey,
+    ) -> Vec<AccountMeta> {
+        [
+            self.blacklist_entry(sender),
+            self.blacklist_entry(receiver),
+            self.config,
+            self.hook_config,
+            self.risk_score(sender),
+            self.risk_score(receiver),
+            self.account_hold(source),
+            TRANSFER_HOOK_PROGRAM_ID,
+            self.extra_account_metas,
+        ]
+        .into_iter()
+        .map(|key| AccountMeta::new_readonly(key, false))
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seeds;
+
+    #[test]
+    fn test_address_book_is_deterministic() {
+        let mint = Pubkey::new_from_array([7; 32]);
+        let book = AddressBook::for_mint(mint);
+        assert_eq!(book, AddressBook::for_mint(mint));
+        assert_ne!(book, AddressBook::for_mint(Pubkey::new_from_array([8; 32])));
+
+        let names: Vec<_> = book.entries().iter().map(|(name, _)| *name).collect();
+        let mut unique = names.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(names.len(), unique.len());
+    }
+
+    #[test]
+    fn test_address_book_matches_seeds() {
+        let mint = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let book = AddressBook::for_mint(mint);
+
+        let (config, _) = Pubkey::find_program_address(&[seeds::CONFIG, mint.as_ref()], &crate::ID);
+        assert_eq!(book.config, config);
+        let role = Pubkey::find_program_address(
+            &[
+                seeds::ROLE,
+                config.as_ref(),
+                wallet.as_ref(),
+                &seeds::role_seed(Role::Blacklister),
+            ],
+            &crate::ID,
+        )
+        .0;
+        assert_eq!(book.role(&wallet, Role::Blacklister), role);
+        let blacklist = Pubkey::find_program_address(
+            &[seeds::BLACKLIST, mint.as_ref(), wallet.as_ref()],
+            &TRANSFER_HOOK_PROGRAM_ID,
+        )
+        .0;
+        assert_eq!(book.blacklist_entry(&wallet), blacklist);
+    }
+
+    #[test]
+    fn test_transfer_hook_accounts() {
+        let book = AddressBook::for_mint(Pubkey::new_unique());
+        let (source, sender, receiver) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let metas = book.transfer_hook_accounts(&source, &sender, &receiver);
+
+        // Indices 5.. of the hook's Execute accounts, then the program and
+        // the validation account Token-2022 looks up by key.
+        assert_eq!(metas.len(), 9);
+        assert!(metas.iter().all(|m| !m.is_signer && !m.is_writable));
+        assert_eq!(metas[0].pubkey, book.blacklist_entry(&sender));
+        assert_eq!(metas[2].pubkey, book.config);
+        assert_eq!(metas[5].pubkey, book.risk_score(&receiver));
+        assert_eq!(metas[6].pubkey, book.account_hold(&source));
+        assert_eq!(metas[7].pubkey, TRANSFER_HOOK_PROGRAM_ID);
+        assert_eq!(metas[8].pubkey, book.extra_account_metas);
+    }
+
+    #[test]
+    fn test_seed_helpers() {
+        assert_eq!(seeds::role_seed(Role::Bridge), [7]);
+        assert_eq!(seeds::u64_seed(1), [1, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(seeds::same_address(&crate::ID, &crate::ID));
+        assert!(!seeds::same_address(&crate::ID, &TRANSFER_HOOK_PROGRAM_ID));
+    }
+}
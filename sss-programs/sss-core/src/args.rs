@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Arguments for `initialize`. Lives outside `instructions` so off-chain
+/// clients built with the `client` feature can serialize it.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeArgs {
+    pub preset: u8,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub decimals: u8,
+    pub supply_cap: Option<u64>,
+    /// Override preset default for permanent delegate. If None, derived from preset.
+    pub enable_permanent_delegate: Option<bool>,
+    /// Override preset default for transfer hook. If None, derived from preset.
+    pub enable_transfer_hook: Option<bool>,
+    /// Override preset default for default-frozen accounts. If None, derived from preset.
+    pub default_account_frozen: Option<bool>,
+    /// Optional Pyth oracle feed ID (32-byte array) for oracle-gated supply caps.
+    /// If None, oracle-adjusted minting is disabled for this stablecoin.
+    /// Can be set later via `update_oracle_feed`.
+    pub oracle_feed_id: Option<[u8; 32]>,
+}
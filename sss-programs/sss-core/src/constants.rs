@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+/// The SSS transfer hook program. sss-core cannot depend on the hook crate
+/// (the hook depends on sss-core), so its ID is mirrored here for the hook
+/// PDA helpers in `pda` and the paths that check the blacklist without going
+/// through the hook. The hook asserts at compile time that the two agree.
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey =
+    pubkey!("HookFvKFaoF9KL8TUXUnQK5r2mJoMYdBENu549seRyXW");
+
+/// Operation classes in `StablecoinConfig::pause_flags`. `pause` and
+/// `unpause` set and clear all of them; `set_pause_flags` sets any subset.
+pub mod pause {
+    /// Every path that mints, including credit draws, bridge mints,
+    /// distributions and the mint leg of `par_swap`. Setting it records
+    /// `paused_at_slot` and clearing it starts the thaw ramp.
+    pub const MINT: u8 = 1 << 0;
+    /// `burn_tokens*`, `bridge_burn` and the burn leg of `par_swap`.
+    pub const BURN: u8 = 1 << 1;
+    /// Freezing and thawing token accounts.
+    pub const FREEZE_THAW: u8 = 1 << 2;
+    /// Hooked transfers, `sweep_intake` and `rescue_tokens`.
+    pub const TRANSFERS: u8 = 1 << 3;
+
+    pub const ALL: u8 = MINT | BURN | FREEZE_THAW | TRANSFERS;
+}
+
+/// Most token accounts `freeze_accounts_batch` and `thaw_accounts_batch`
+/// take in one call, keeping a full batch inside the compute budget.
+pub const MAX_FREEZE_BATCH: usize = 16;
+
+/// Most recipients `mint_tokens_batch` takes in one call.
+pub const MAX_MINT_BATCH: usize = 16;
+
+/// Most source accounts `burn_tokens_batch` takes in one call.
+pub const MAX_BURN_BATCH: usize = 16;
+
+/// Capability bits for `StablecoinConfig::disabled_instructions` and
+/// `locked_instructions`.
+pub mod capability {
+    /// Every path that mints: `mint_tokens*`, mint-funded distributions and
+    /// the mint leg of `par_swap`.
+    pub const MINT: u32 = 1 << 0;
+    /// `burn_tokens*` and the burn leg of `par_swap`.
+    pub const BURN: u32 = 1 << 1;
+    pub const FREEZE: u32 = 1 << 2;
+    pub const THAW: u32 = 1 << 3;
+    /// `pause` only; `unpause` always works so a disabled pause cannot
+    /// strand a paused config.
+    pub const PAUSE: u32 = 1 << 4;
+    pub const SEIZE: u32 = 1 << 5;
+    /// `add_to_blacklist` in the transfer hook. Removal always works.
+    pub const BLACKLIST: u32 = 1 << 6;
+
+    pub const ALL: u32 = MINT | BURN | FREEZE | THAW | PAUSE | SEIZE | BLACKLIST;
+}
@@ -40,4 +40,240 @@ pub enum SssError {
     OraclePriceStale,
     #[msg("Oracle feed ID not configured — call update_oracle_feed before using a price update")]
     OracleFeedNotConfigured,
+    #[msg("Role directory is full — revoke an unused holder first")]
+    RoleDirectoryFull,
+    #[msg("Amount is at or above the receipt threshold — use mint_tokens_with_receipt")]
+    MintReceiptRequired,
+    #[msg("Mint receipt retention period has not elapsed")]
+    ReceiptRetentionActive,
+    #[msg("Receipt retention period must not be negative")]
+    InvalidReceiptRetention,
+    #[msg("Oracle confidence interval exceeds the configured maximum")]
+    OracleConfidenceTooWide,
+    #[msg("Confidence bound must be at most 10000 basis points")]
+    InvalidConfidenceBps,
+    #[msg("Report has already been published")]
+    ReportAlreadyPublished,
+    #[msg("Report has not been published yet")]
+    ReportNotPublished,
+    #[msg("Report has already been acknowledged")]
+    ReportAlreadyAcknowledged,
+    #[msg("Config was written by an incompatible program version; migrate it first")]
+    UnsupportedConfigVersion,
+    #[msg("Issuer event tag must be 1-32 bytes")]
+    InvalidIssuerEventTag,
+    #[msg("Issuer event data exceeds maximum length of 512 bytes")]
+    IssuerEventDataTooLong,
+    #[msg("Snapshot slot is in the past")]
+    SnapshotSlotInPast,
+    #[msg("Snapshot record window overflows")]
+    InvalidSnapshotWindow,
+    #[msg("Snapshot is not open for recording")]
+    SnapshotNotOpen,
+    #[msg("Merkle proof does not match the distributor root")]
+    InvalidMerkleProof,
+    #[msg("Claim would exceed the distributor's maximum total")]
+    DistributionExhausted,
+    #[msg("Blacklist account does not match the claimant")]
+    InvalidBlacklistAccount,
+    #[msg("Address is blacklisted")]
+    AddressBlacklisted,
+    #[msg("Treasury account missing or does not match the distributor")]
+    InvalidTreasuryAccount,
+    #[msg("Swap route must join two different stablecoins with equal decimals")]
+    InvalidSwapRoute,
+    #[msg("Mint would exceed the sub-minter's delegated quota")]
+    SubMinterQuotaExceeded,
+    #[msg("Destination owner has no valid KYC approval")]
+    KycRequired,
+    #[msg("KYC expiry must be in the future")]
+    KycExpired,
+    #[msg("Event queue recording is enabled but the queue account is missing")]
+    EventQueueRequired,
+    #[msg("Hot and cold keys must differ and the window must be positive")]
+    InvalidMinterKeys,
+    #[msg("Mint exceeds the hot key's window quota; the cold key must co-sign")]
+    HotQuotaExceeded,
+    #[msg("Capability mask has unknown bits or would re-enable a locked capability")]
+    CapabilityLocked,
+    #[msg("This instruction has been disabled by the issuer")]
+    InstructionDisabled,
+    #[msg("Operating hours must be two different minutes of the day and at least one weekday")]
+    InvalidOperatingHours,
+    #[msg("Minter is outside its allowed operating hours")]
+    OutsideOperatingHours,
+    #[msg("Bridge can only mint back what it has burned")]
+    BridgeInflowExceedsOutflow,
+    #[msg("Thaw ramp needs a positive length and a start below 10000 bps")]
+    InvalidThawRamp,
+    #[msg("Receivership quorum must be between 1 and the number of admins (max 8)")]
+    InvalidReceivershipQuorum,
+    #[msg("Admin has already approved this receivership")]
+    ReceivershipAlreadyApproved,
+    #[msg("Receivership is active")]
+    ReceivershipActive,
+    #[msg("Receivership has not been activated")]
+    ReceivershipInactive,
+    #[msg("Destination mint limit is set but the destination's throttle account is missing")]
+    DestinationThrottleRequired,
+    #[msg("Destination token account has reached its daily mint limit")]
+    DestinationMintLimitExceeded,
+    #[msg("Fee schedule needs 1 to 8 tiers with rates of at most 10000 bps")]
+    InvalidFeeSchedule,
+    #[msg("Fee tier is not in the schedule")]
+    UnknownFeeTier,
+    #[msg("Sweep route needs a treasury other than the intake, a non-negative interval and an unchanged intake owner")]
+    InvalidSweepRoute,
+    #[msg("Intake balance is below the sweep threshold or the sweep interval has not passed")]
+    SweepNotDue,
+    #[msg("Action quotas only apply to Freezer, Blacklister and Seizer roles")]
+    ActionQuotaNotSupported,
+    #[msg("Role has used its action quota for the current window")]
+    ActionQuotaExceeded,
+    #[msg("Token account owner has no blacklist entry in effect")]
+    NotBlacklisted,
+    #[msg("Credit limit must be non-zero and the repayment cutoff a time of day in seconds")]
+    InvalidCreditLine,
+    #[msg("Freeze-exempt wallets cannot hold a credit line")]
+    CreditLineExemptWallet,
+    #[msg("Credit line is in default")]
+    CreditLineDefaulted,
+    #[msg("Today's repayment cutoff has passed")]
+    CreditWindowClosed,
+    #[msg("Draw would exceed the credit limit")]
+    CreditLimitExceeded,
+    #[msg("Repayment exceeds the outstanding credit")]
+    RepaymentExceedsOutstanding,
+    #[msg("Credit line is not past due")]
+    CreditNotPastDue,
+    #[msg("Credit line still has an outstanding balance")]
+    CreditOutstanding,
+    #[msg("A failover is pending")]
+    FailoverPending,
+    #[msg("No failover is pending")]
+    NoFailoverPending,
+    #[msg("The failover delay has not elapsed")]
+    FailoverTimelockActive,
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+    #[msg("This action must be queued while the admin timelock is enabled")]
+    TimelockRequired,
+    #[msg("Pending action does not match this instruction")]
+    PendingActionMismatch,
+    #[msg("Timelock delay has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Admin timelock is not enabled")]
+    TimelockDisabled,
+    #[msg("Timelock delay exceeds the maximum")]
+    InvalidTimelockDelay,
+    #[msg("Mint amount exceeds the large mint threshold and needs an approval")]
+    LargeMintApprovalRequired,
+    #[msg("Mint approval does not match this mint")]
+    MintApprovalMismatch,
+    #[msg("Approver is the requesting minter, already approved, or the approval is full")]
+    MintApprovalRejected,
+    #[msg("Required approvals must be between 1 and 8")]
+    InvalidApprovalCount,
+    #[msg("Role has expired")]
+    RoleExpired,
+    #[msg("Role expiry must be in the future")]
+    InvalidRoleExpiry,
+    #[msg("Admin roles cannot expire")]
+    RoleExpiryNotSupported,
+    #[msg("The config authority must transfer authority before renouncing Admin")]
+    AuthorityCannotRenounce,
+    #[msg("Rescue source must be a token account owned by the config")]
+    RescueSourceNotConfigOwned,
+    #[msg("Pause flags outside the known operation classes")]
+    InvalidPauseFlags,
+    #[msg("Pauses do not expire on this config")]
+    PauseExpiryDisabled,
+    #[msg("Seizures above the large seize threshold need a seize approval")]
+    LargeSeizeApprovalRequired,
+    #[msg("Seize approval does not match this seizure")]
+    SeizeApprovalMismatch,
+    #[msg("A seizer cannot approve its own seizure")]
+    SeizeApprovalRejected,
+    #[msg("Seizure reason must be non-empty and at most 200 bytes")]
+    InvalidSeizureReason,
+    #[msg("Seized funds must go to the treasury's associated token account")]
+    SeizeDestinationNotTreasury,
+    #[msg("Source account must be frozen before it can be seized")]
+    SeizeRequiresFrozenAccount,
+    #[msg("Batch must hold between 1 and 16 token accounts")]
+    InvalidFreezeBatch,
+    #[msg("Freeze exemption account is not the token account owner's exemption PDA")]
+    FreezeExemptionMismatch,
+    #[msg("Batch must pair 1 to 16 amounts with their recipient accounts")]
+    InvalidMintBatch,
+    #[msg("Batch must pair 1 to 16 amounts with their source token accounts")]
+    InvalidBurnBatch,
+    #[msg("Quota period must be at least one second")]
+    InvalidQuotaPeriod,
+    #[msg("Burner quota exceeded")]
+    BurnQuotaExceeded,
+    #[msg("Mint would exceed the minter's rate limit for the current window")]
+    MintRateLimitExceeded,
+    #[msg("Rate limit window must be at least one second")]
+    InvalidMintRateLimit,
+    #[msg("Mint request does not match this config, destination or requester")]
+    MintRequestMismatch,
+    #[msg("A mint requester cannot fulfil its own request")]
+    MintRequestSelfFulfillment,
+    #[msg("Redemption exceeds the supply held on this chain")]
+    RedemptionExceedsSupply,
+    #[msg("Redemption request does not match this config, holder or escrow")]
+    RedemptionMismatch,
+    #[msg("Token account must be frozen before it can be wiped")]
+    WipeRequiresFrozenAccount,
+    #[msg("Wipe reason must be non-empty and at most 200 bytes")]
+    InvalidWipeReason,
+    #[msg("SSS-3 mints need confidential transfer setup and must be created externally")]
+    PresetNeedsExternalMint,
+    #[msg("Mint authorities, permanent delegate or transfer hook do not name this config")]
+    MintAuthorityMismatch,
+    #[msg("Mint decimals do not match the initialize arguments")]
+    DecimalsMismatch,
+    #[msg("The stablecoin has already been sunset")]
+    AlreadySunset,
+    #[msg("The stablecoin must be sunset first")]
+    NotSunset,
+    #[msg("Outstanding supply must be zero")]
+    SupplyNotZero,
+    #[msg("Revoke the other Admin roles before closing the config")]
+    AdminsRemain,
+    #[msg("Oracle feed ID must not be all zeros")]
+    InvalidOracleFeedId,
+    #[msg("Reserve attestation account required while the reserve gate is enabled")]
+    ReserveAttestationRequired,
+    #[msg("Reserve attestation is older than the configured maximum age")]
+    ReserveAttestationStale,
+    #[msg("Mint would push supply above the latest attested reserves")]
+    ReservesExceeded,
+    #[msg("Attestation time must not be in the future or before the previous attestation")]
+    InvalidAttestationTime,
+    #[msg("Collateral ratio must be at least 100% (10000 bps)")]
+    InvalidCollateralRatio,
+    #[msg("Collateral mint must differ from the stablecoin mint")]
+    InvalidCollateralMint,
+    #[msg("Deposit would mint less than the requested minimum")]
+    CollateralMintBelowMinimum,
+    #[msg("Burn exceeds the supply issued against the holder's collateral position")]
+    CollateralWithdrawalExceedsIssued,
+    #[msg("Collateral vault has a price feed; pass its price update")]
+    CollateralPriceRequired,
+    #[msg("Fees are on; pass the fee schedule")]
+    FeeScheduleRequired,
+    #[msg("Fee treasury account missing or not the configured fee treasury")]
+    InvalidFeeTreasury,
+    #[msg("Withdrawal would leave the collateral position less backed than before")]
+    CollateralRatioNotKept,
+    #[msg("Fees are on; pass the payer's fee tier PDA, even if empty")]
+    FeeTierRequired,
+    #[msg("Fees are on; batches cannot charge them, mint through mint_tokens")]
+    FeesRequireSingleMint,
+    #[msg("Account is not a role account in an earlier layout")]
+    NotLegacyRoleAccount,
+    #[msg("Period summaries must be exported in order, each on top of the previous one")]
+    PeriodSummaryOutOfOrder,
 }
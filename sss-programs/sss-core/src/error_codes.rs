@@ -0,0 +1,628 @@
+//! Stable numeric error codes for integrators.
+//!
+//! `SssError` is numbered by Anchor from its declaration order, starting at
+//! `ERROR_CODE_OFFSET` (6000, `0x1770`). Services that see only the number in
+//! a failed transaction's logs or `InstructionError::Custom` convert it with
+//! `SssErrorCode::from(number)` and match on the result. Every number below is
+//! fixed: new errors are only ever appended to `SssError`, and a code this
+//! build does not know converts to `Unknown` rather than to a wrong variant.
+
+use crate::error::SssError;
+
+/// Anchor's offset for program-defined error numbers.
+pub const ERROR_CODE_OFFSET: u32 = anchor_lang::error::ERROR_CODE_OFFSET;
+
+/// A sss-core error number as a typed value. Matches against it need a
+/// wildcard arm, so errors added in later versions do not break callers.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SssErrorCode {
+    Paused,
+    NotPaused,
+    SupplyCapExceeded,
+    Unauthorized,
+    InvalidPreset,
+    LastAdmin,
+    ArithmeticOverflow,
+    MintMismatch,
+    InvalidSupplyCap,
+    ZeroAmount,
+    InvalidRole,
+    InvalidOracleData,
+    InvalidOraclePrice,
+    QuotaExceeded,
+    NameTooLong,
+    SymbolTooLong,
+    UriTooLong,
+    OraclePriceStale,
+    OracleFeedNotConfigured,
+    RoleDirectoryFull,
+    MintReceiptRequired,
+    ReceiptRetentionActive,
+    InvalidReceiptRetention,
+    OracleConfidenceTooWide,
+    InvalidConfidenceBps,
+    ReportAlreadyPublished,
+    ReportNotPublished,
+    ReportAlreadyAcknowledged,
+    UnsupportedConfigVersion,
+    InvalidIssuerEventTag,
+    IssuerEventDataTooLong,
+    SnapshotSlotInPast,
+    InvalidSnapshotWindow,
+    SnapshotNotOpen,
+    InvalidMerkleProof,
+    DistributionExhausted,
+    InvalidBlacklistAccount,
+    AddressBlacklisted,
+    InvalidTreasuryAccount,
+    InvalidSwapRoute,
+    SubMinterQuotaExceeded,
+    KycRequired,
+    KycExpired,
+    EventQueueRequired,
+    InvalidMinterKeys,
+    HotQuotaExceeded,
+    CapabilityLocked,
+    InstructionDisabled,
+    InvalidOperatingHours,
+    OutsideOperatingHours,
+    BridgeInflowExceedsOutflow,
+    InvalidThawRamp,
+    InvalidReceivershipQuorum,
+    ReceivershipAlreadyApproved,
+    ReceivershipActive,
+    ReceivershipInactive,
+    DestinationThrottleRequired,
+    DestinationMintLimitExceeded,
+    InvalidFeeSchedule,
+    UnknownFeeTier,
+    InvalidSweepRoute,
+    SweepNotDue,
+    ActionQuotaNotSupported,
+    ActionQuotaExceeded,
+    NotBlacklisted,
+    InvalidCreditLine,
+    CreditLineExemptWallet,
+    CreditLineDefaulted,
+    CreditWindowClosed,
+    CreditLimitExceeded,
+    RepaymentExceedsOutstanding,
+    CreditNotPastDue,
+    CreditOutstanding,
+    FailoverPending,
+    NoFailoverPending,
+    FailoverTimelockActive,
+    NotPendingAuthority,
+    TimelockRequired,
+    PendingActionMismatch,
+    TimelockNotElapsed,
+    TimelockDisabled,
+    InvalidTimelockDelay,
+    LargeMintApprovalRequired,
+    MintApprovalMismatch,
+    MintApprovalRejected,
+    InvalidApprovalCount,
+    RoleExpired,
+    InvalidRoleExpiry,
+    RoleExpiryNotSupported,
+    AuthorityCannotRenounce,
+    RescueSourceNotConfigOwned,
+    InvalidPauseFlags,
+    PauseExpiryDisabled,
+    LargeSeizeApprovalRequired,
+    SeizeApprovalMismatch,
+    SeizeApprovalRejected,
+    InvalidSeizureReason,
+    SeizeDestinationNotTreasury,
+    SeizeRequiresFrozenAccount,
+    InvalidFreezeBatch,
+    FreezeExemptionMismatch,
+    InvalidMintBatch,
+    InvalidBurnBatch,
+    InvalidQuotaPeriod,
+    BurnQuotaExceeded,
+    MintRateLimitExceeded,
+    InvalidMintRateLimit,
+    MintRequestMismatch,
+    MintRequestSelfFulfillment,
+    RedemptionExceedsSupply,
+    RedemptionMismatch,
+    WipeRequiresFrozenAccount,
+    InvalidWipeReason,
+    PresetNeedsExternalMint,
+    MintAuthorityMismatch,
+    DecimalsMismatch,
+    AlreadySunset,
+    NotSunset,
+    SupplyNotZero,
+    AdminsRemain,
+    InvalidOracleFeedId,
+    ReserveAttestationRequired,
+    ReserveAttestationStale,
+    ReservesExceeded,
+    InvalidAttestationTime,
+    InvalidCollateralRatio,
+    InvalidCollateralMint,
+    CollateralMintBelowMinimum,
+    CollateralWithdrawalExceedsIssued,
+    CollateralPriceRequired,
+    FeeScheduleRequired,
+    InvalidFeeTreasury,
+    CollateralRatioNotKept,
+    FeeTierRequired,
+    FeesRequireSingleMint,
+    NotLegacyRoleAccount,
+    PeriodSummaryOutOfOrder,
+    /// A number outside sss-core's errors, e.g. an Anchor framework error
+    /// or one added after this build.
+    Unknown(u32),
+}
+
+impl SssErrorCode {
+    /// The on-chain error number.
+    pub fn code(self) -> u32 {
+        match self {
+            Self::Paused => 6000,
+            Self::NotPaused => 6001,
+            Self::SupplyCapExceeded => 6002,
+            Self::Unauthorized => 6003,
+            Self::InvalidPreset => 6004,
+            Self::LastAdmin => 6005,
+            Self::ArithmeticOverflow => 6006,
+            Self::MintMismatch => 6007,
+            Self::InvalidSupplyCap => 6008,
+            Self::ZeroAmount => 6009,
+            Self::InvalidRole => 6010,
+            Self::InvalidOracleData => 6011,
+            Self::InvalidOraclePrice => 6012,
+            Self::QuotaExceeded => 6013,
+            Self::NameTooLong => 6014,
+            Self::SymbolTooLong => 6015,
+            Self::UriTooLong => 6016,
+            Self::OraclePriceStale => 6017,
+            Self::OracleFeedNotConfigured => 6018,
+            Self::RoleDirectoryFull => 6019,
+            Self::MintReceiptRequired => 6020,
+            Self::ReceiptRetentionActive => 6021,
+            Self::InvalidReceiptRetention => 6022,
+            Self::OracleConfidenceTooWide => 6023,
+            Self::InvalidConfidenceBps => 6024,
+            Self::ReportAlreadyPublished => 6025,
+            Self::ReportNotPublished => 6026,
+            Self::ReportAlreadyAcknowledged => 6027,
+            Self::UnsupportedConfigVersion => 6028,
+            Self::InvalidIssuerEventTag => 6029,
+            Self::IssuerEventDataTooLong => 6030,
+            Self::SnapshotSlotInPast => 6031,
+            Self::InvalidSnapshotWindow => 6032,
+            Self::SnapshotNotOpen => 6033,
+            Self::InvalidMerkleProof => 6034,
+            Self::DistributionExhausted => 6035,
+            Self::InvalidBlacklistAccount => 6036,
+            Self::AddressBlacklisted => 6037,
+            Self::InvalidTreasuryAccount => 6038,
+            Self::InvalidSwapRoute => 6039,
+            Self::SubMinterQuotaExceeded => 6040,
+            Self::KycRequired => 6041,
+            Self::KycExpired => 6042,
+            Self::EventQueueRequired => 6043,
+            Self::InvalidMinterKeys => 6044,
+            Self::HotQuotaExceeded => 6045,
+            Self::CapabilityLocked => 6046,
+            Self::InstructionDisabled => 6047,
+            Self::InvalidOperatingHours => 6048,
+            Self::OutsideOperatingHours => 6049,
+            Self::BridgeInflowExceedsOutflow => 6050,
+            Self::InvalidThawRamp => 6051,
+            Self::InvalidReceivershipQuorum => 6052,
+            Self::ReceivershipAlreadyApproved => 6053,
+            Self::ReceivershipActive => 6054,
+            Self::ReceivershipInactive => 6055,
+            Self::DestinationThrottleRequired => 6056,
+            Self::DestinationMintLimitExceeded => 6057,
+            Self::InvalidFeeSchedule => 6058,
+            Self::UnknownFeeTier => 6059,
+            Self::InvalidSweepRoute => 6060,
+            Self::SweepNotDue => 6061,
+            Self::ActionQuotaNotSupported => 6062,
+            Self::ActionQuotaExceeded => 6063,
+            Self::NotBlacklisted => 6064,
+            Self::InvalidCreditLine => 6065,
+            Self::CreditLineExemptWallet => 6066,
+            Self::CreditLineDefaulted => 6067,
+            Self::CreditWindowClosed => 6068,
+            Self::CreditLimitExceeded => 6069,
+            Self::RepaymentExceedsOutstanding => 6070,
+            Self::CreditNotPastDue => 6071,
+            Self::CreditOutstanding => 6072,
+            Self::FailoverPending => 6073,
+            Self::NoFailoverPending => 6074,
+            Self::FailoverTimelockActive => 6075,
+            Self::NotPendingAuthority => 6076,
+            Self::TimelockRequired => 6077,
+            Self::PendingActionMismatch => 6078,
+            Self::TimelockNotElapsed => 6079,
+            Self::TimelockDisabled => 6080,
+            Self::InvalidTimelockDelay => 6081,
+            Self::LargeMintApprovalRequired => 6082,
+            Self::MintApprovalMismatch => 6083,
+            Self::MintApprovalRejected => 6084,
+            Self::InvalidApprovalCount => 6085,
+            Self::RoleExpired => 6086,
+            Self::InvalidRoleExpiry => 6087,
+            Self::RoleExpiryNotSupported => 6088,
+            Self::AuthorityCannotRenounce => 6089,
+            Self::RescueSourceNotConfigOwned => 6090,
+            Self::InvalidPauseFlags => 6091,
+            Self::PauseExpiryDisabled => 6092,
+            Self::LargeSeizeApprovalRequired => 6093,
+            Self::SeizeApprovalMismatch => 6094,
+            Self::SeizeApprovalRejected => 6095,
+            Self::InvalidSeizureReason => 6096,
+            Self::SeizeDestinationNotTreasury => 6097,
+            Self::SeizeRequiresFrozenAccount => 6098,
+            Self::InvalidFreezeBatch => 6099,
+            Self::FreezeExemptionMismatch => 6100,
+            Self::InvalidMintBatch => 6101,
+            Self::InvalidBurnBatch => 6102,
+            Self::InvalidQuotaPeriod => 6103,
+            Self::BurnQuotaExceeded => 6104,
+            Self::MintRateLimitExceeded => 6105,
+            Self::InvalidMintRateLimit => 6106,
+            Self::MintRequestMismatch => 6107,
+            Self::MintRequestSelfFulfillment => 6108,
+            Self::RedemptionExceedsSupply => 6109,
+            Self::RedemptionMismatch => 6110,
+            Self::WipeRequiresFrozenAccount => 6111,
+            Self::InvalidWipeReason => 6112,
+            Self::PresetNeedsExternalMint => 6113,
+            Self::MintAuthorityMismatch => 6114,
+            Self::DecimalsMismatch => 6115,
+            Self::AlreadySunset => 6116,
+            Self::NotSunset => 6117,
+            Self::SupplyNotZero => 6118,
+            Self::AdminsRemain => 6119,
+            Self::InvalidOracleFeedId => 6120,
+            Self::ReserveAttestationRequired => 6121,
+            Self::ReserveAttestationStale => 6122,
+            Self::ReservesExceeded => 6123,
+            Self::InvalidAttestationTime => 6124,
+            Self::InvalidCollateralRatio => 6125,
+            Self::InvalidCollateralMint => 6126,
+            Self::CollateralMintBelowMinimum => 6127,
+            Self::CollateralWithdrawalExceedsIssued => 6128,
+            Self::CollateralPriceRequired => 6129,
+            Self::FeeScheduleRequired => 6130,
+            Self::InvalidFeeTreasury => 6131,
+            Self::CollateralRatioNotKept => 6132,
+            Self::FeeTierRequired => 6133,
+            Self::FeesRequireSingleMint => 6134,
+            Self::NotLegacyRoleAccount => 6135,
+            Self::PeriodSummaryOutOfOrder => 6136,
+            Self::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<u32> for SssErrorCode {
+    fn from(code: u32) -> Self {
+        match code {
+            6000 => Self::Paused,
+            6001 => Self::NotPaused,
+            6002 => Self::SupplyCapExceeded,
+            6003 => Self::Unauthorized,
+            6004 => Self::InvalidPreset,
+            6005 => Self::LastAdmin,
+            6006 => Self::ArithmeticOverflow,
+            6007 => Self::MintMismatch,
+            6008 => Self::InvalidSupplyCap,
+            6009 => Self::ZeroAmount,
+            6010 => Self::InvalidRole,
+            6011 => Self::InvalidOracleData,
+            6012 => Self::InvalidOraclePrice,
+            6013 => Self::QuotaExceeded,
+            6014 => Self::NameTooLong,
+            6015 => Self::SymbolTooLong,
+            6016 => Self::UriTooLong,
+            6017 => Self::OraclePriceStale,
+            6018 => Self::OracleFeedNotConfigured,
+            6019 => Self::RoleDirectoryFull,
+            6020 => Self::MintReceiptRequired,
+            6021 => Self::ReceiptRetentionActive,
+            6022 => Self::InvalidReceiptRetention,
+            6023 => Self::OracleConfidenceTooWide,
+            6024 => Self::InvalidConfidenceBps,
+            6025 => Self::ReportAlreadyPublished,
+            6026 => Self::ReportNotPublished,
+            6027 => Self::ReportAlreadyAcknowledged,
+            6028 => Self::UnsupportedConfigVersion,
+            6029 => Self::InvalidIssuerEventTag,
+            6030 => Self::IssuerEventDataTooLong,
+            6031 => Self::SnapshotSlotInPast,
+            6032 => Self::InvalidSnapshotWindow,
+            6033 => Self::SnapshotNotOpen,
+            6034 => Self::InvalidMerkleProof,
+            6035 => Self::DistributionExhausted,
+            6036 => Self::InvalidBlacklistAccount,
+            6037 => Self::AddressBlacklisted,
+            6038 => Self::InvalidTreasuryAccount,
+            6039 => Self::InvalidSwapRoute,
+            6040 => Self::SubMinterQuotaExceeded,
+            6041 => Self::KycRequired,
+            6042 => Self::KycExpired,
+            6043 => Self::EventQueueRequired,
+            6044 => Self::InvalidMinterKeys,
+            6045 => Self::HotQuotaExceeded,
+            6046 => Self::CapabilityLocked,
+            6047 => Self::InstructionDisabled,
+            6048 => Self::InvalidOperatingHours,
+            6049 => Self::OutsideOperatingHours,
+            6050 => Self::BridgeInflowExceedsOutflow,
+            6051 => Self::InvalidThawRamp,
+            6052 => Self::InvalidReceivershipQuorum,
+            6053 => Self::ReceivershipAlreadyApproved,
+            6054 => Self::ReceivershipActive,
+            6055 => Self::ReceivershipInactive,
+            6056 => Self::DestinationThrottleRequired,
+            6057 => Self::DestinationMintLimitExceeded,
+            6058 => Self::InvalidFeeSchedule,
+            6059 => Self::UnknownFeeTier,
+            6060 => Self::InvalidSweepRoute,
+            6061 => Self::SweepNotDue,
+            6062 => Self::ActionQuotaNotSupported,
+            6063 => Self::ActionQuotaExceeded,
+            6064 => Self::NotBlacklisted,
+            6065 => Self::InvalidCreditLine,
+            6066 => Self::CreditLineExemptWallet,
+            6067 => Self::CreditLineDefaulted,
+            6068 => Self::CreditWindowClosed,
+            6069 => Self::CreditLimitExceeded,
+            6070 => Self::RepaymentExceedsOutstanding,
+            6071 => Self::CreditNotPastDue,
+            6072 => Self::CreditOutstanding,
+            6073 => Self::FailoverPending,
+            6074 => Self::NoFailoverPending,
+            6075 => Self::FailoverTimelockActive,
+            6076 => Self::NotPendingAuthority,
+            6077 => Self::TimelockRequired,
+            6078 => Self::PendingActionMismatch,
+            6079 => Self::TimelockNotElapsed,
+            6080 => Self::TimelockDisabled,
+            6081 => Self::InvalidTimelockDelay,
+            6082 => Self::LargeMintApprovalRequired,
+            6083 => Self::MintApprovalMismatch,
+            6084 => Self::MintApprovalRejected,
+            6085 => Self::InvalidApprovalCount,
+            6086 => Self::RoleExpired,
+            6087 => Self::InvalidRoleExpiry,
+            6088 => Self::RoleExpiryNotSupported,
+            6089 => Self::AuthorityCannotRenounce,
+            6090 => Self::RescueSourceNotConfigOwned,
+            6091 => Self::InvalidPauseFlags,
+            6092 => Self::PauseExpiryDisabled,
+            6093 => Self::LargeSeizeApprovalRequired,
+            6094 => Self::SeizeApprovalMismatch,
+            6095 => Self::SeizeApprovalRejected,
+            6096 => Self::InvalidSeizureReason,
+            6097 => Self::SeizeDestinationNotTreasury,
+            6098 => Self::SeizeRequiresFrozenAccount,
+            6099 => Self::InvalidFreezeBatch,
+            6100 => Self::FreezeExemptionMismatch,
+            6101 => Self::InvalidMintBatch,
+            6102 => Self::InvalidBurnBatch,
+            6103 => Self::InvalidQuotaPeriod,
+            6104 => Self::BurnQuotaExceeded,
+            6105 => Self::MintRateLimitExceeded,
+            6106 => Self::InvalidMintRateLimit,
+            6107 => Self::MintRequestMismatch,
+            6108 => Self::MintRequestSelfFulfillment,
+            6109 => Self::RedemptionExceedsSupply,
+            6110 => Self::RedemptionMismatch,
+            6111 => Self::WipeRequiresFrozenAccount,
+            6112 => Self::InvalidWipeReason,
+            6113 => Self::PresetNeedsExternalMint,
+            6114 => Self::MintAuthorityMismatch,
+            6115 => Self::DecimalsMismatch,
+            6116 => Self::AlreadySunset,
+            6117 => Self::NotSunset,
+            6118 => Self::SupplyNotZero,
+            6119 => Self::AdminsRemain,
+            6120 => Self::InvalidOracleFeedId,
+            6121 => Self::ReserveAttestationRequired,
+            6122 => Self::ReserveAttestationStale,
+            6123 => Self::ReservesExceeded,
+            6124 => Self::InvalidAttestationTime,
+            6125 => Self::InvalidCollateralRatio,
+            6126 => Self::InvalidCollateralMint,
+            6127 => Self::CollateralMintBelowMinimum,
+            6128 => Self::CollateralWithdrawalExceedsIssued,
+            6129 => Self::CollateralPriceRequired,
+            6130 => Self::FeeScheduleRequired,
+            6131 => Self::InvalidFeeTreasury,
+            6132 => Self::CollateralRatioNotKept,
+            6133 => Self::FeeTierRequired,
+            6134 => Self::FeesRequireSingleMint,
+            6135 => Self::NotLegacyRoleAccount,
+            6136 => Self::PeriodSummaryOutOfOrder,
+            _ => Self::Unknown(code),
+        }
+    }
+}
+
+impl From<SssError> for SssErrorCode {
+    fn from(error: SssError) -> Self {
+        match error {
+            SssError::Paused => Self::Paused,
+            SssError::NotPaused => Self::NotPaused,
+            SssError::SupplyCapExceeded => Self::SupplyCapExceeded,
+            SssError::Unauthorized => Self::Unauthorized,
+            SssError::InvalidPreset => Self::InvalidPreset,
+            SssError::LastAdmin => Self::LastAdmin,
+            SssError::ArithmeticOverflow => Self::ArithmeticOverflow,
+            SssError::MintMismatch => Self::MintMismatch,
+            SssError::InvalidSupplyCap => Self::InvalidSupplyCap,
+            SssError::ZeroAmount => Self::ZeroAmount,
+            SssError::InvalidRole => Self::InvalidRole,
+            SssError::InvalidOracleData => Self::InvalidOracleData,
+            SssError::InvalidOraclePrice => Self::InvalidOraclePrice,
+            SssError::QuotaExceeded => Self::QuotaExceeded,
+            SssError::NameTooLong => Self::NameTooLong,
+            SssError::SymbolTooLong => Self::SymbolTooLong,
+            SssError::UriTooLong => Self::UriTooLong,
+            SssError::OraclePriceStale => Self::OraclePriceStale,
+            SssError::OracleFeedNotConfigured => Self::OracleFeedNotConfigured,
+            SssError::RoleDirectoryFull => Self::RoleDirectoryFull,
+            SssError::MintReceiptRequired => Self::MintReceiptRequired,
+            SssError::ReceiptRetentionActive => Self::ReceiptRetentionActive,
+            SssError::InvalidReceiptRetention => Self::InvalidReceiptRetention,
+            SssError::OracleConfidenceTooWide => Self::OracleConfidenceTooWide,
+            SssError::InvalidConfidenceBps => Self::InvalidConfidenceBps,
+            SssError::ReportAlreadyPublished => Self::ReportAlreadyPublished,
+            SssError::ReportNotPublished => Self::ReportNotPublished,
+            SssError::ReportAlreadyAcknowledged => Self::ReportAlreadyAcknowledged,
+            SssError::UnsupportedConfigVersion => Self::UnsupportedConfigVersion,
+            SssError::InvalidIssuerEventTag => Self::InvalidIssuerEventTag,
+            SssError::IssuerEventDataTooLong => Self::IssuerEventDataTooLong,
+            SssError::SnapshotSlotInPast => Self::SnapshotSlotInPast,
+            SssError::InvalidSnapshotWindow => Self::InvalidSnapshotWindow,
+            SssError::SnapshotNotOpen => Self::SnapshotNotOpen,
+            SssError::InvalidMerkleProof => Self::InvalidMerkleProof,
+            SssError::DistributionExhausted => Self::DistributionExhausted,
+            SssError::InvalidBlacklistAccount => Self::InvalidBlacklistAccount,
+            SssError::AddressBlacklisted => Self::AddressBlacklisted,
+            SssError::InvalidTreasuryAccount => Self::InvalidTreasuryAccount,
+            SssError::InvalidSwapRoute => Self::InvalidSwapRoute,
+            SssError::SubMinterQuotaExceeded => Self::SubMinterQuotaExceeded,
+            SssError::KycRequired => Self::KycRequired,
+            SssError::KycExpired => Self::KycExpired,
+            SssError::EventQueueRequired => Self::EventQueueRequired,
+            SssError::InvalidMinterKeys => Self::InvalidMinterKeys,
+            SssError::HotQuotaExceeded => Self::HotQuotaExceeded,
+            SssError::CapabilityLocked => Self::CapabilityLocked,
+            SssError::InstructionDisabled => Self::InstructionDisabled,
+            SssError::InvalidOperatingHours => Self::InvalidOperatingHours,
+            SssError::OutsideOperatingHours => Self::OutsideOperatingHours,
+            SssError::BridgeInflowExceedsOutflow => Self::BridgeInflowExceedsOutflow,
+            SssError::InvalidThawRamp => Self::InvalidThawRamp,
+            SssError::InvalidReceivershipQuorum => Self::InvalidReceivershipQuorum,
+            SssError::ReceivershipAlreadyApproved => Self::ReceivershipAlreadyApproved,
+            SssError::ReceivershipActive => Self::ReceivershipActive,
+            SssError::ReceivershipInactive => Self::ReceivershipInactive,
+            SssError::DestinationThrottleRequired => Self::DestinationThrottleRequired,
+            SssError::DestinationMintLimitExceeded => Self::DestinationMintLimitExceeded,
+            SssError::InvalidFeeSchedule => Self::InvalidFeeSchedule,
+            SssError::UnknownFeeTier => Self::UnknownFeeTier,
+            SssError::InvalidSweepRoute => Self::InvalidSweepRoute,
+            SssError::SweepNotDue => Self::SweepNotDue,
+            SssError::ActionQuotaNotSupported => Self::ActionQuotaNotSupported,
+            SssError::ActionQuotaExceeded => Self::ActionQuotaExceeded,
+            SssError::NotBlacklisted => Self::NotBlacklisted,
+            SssError::InvalidCreditLine => Self::InvalidCreditLine,
+            SssError::CreditLineExemptWallet => Self::CreditLineExemptWallet,
+            SssError::CreditLineDefaulted => Self::CreditLineDefaulted,
+            SssError::CreditWindowClosed => Self::CreditWindowClosed,
+            SssError::CreditLimitExceeded => Self::CreditLimitExceeded,
+            SssError::RepaymentExceedsOutstanding => Self::RepaymentExceedsOutstanding,
+            SssError::CreditNotPastDue => Self::CreditNotPastDue,
+            SssError::CreditOutstanding => Self::CreditOutstanding,
+            SssError::FailoverPending => Self::FailoverPending,
+            SssError::NoFailoverPending => Self::NoFailoverPending,
+            SssError::FailoverTimelockActive => Self::FailoverTimelockActive,
+            SssError::NotPendingAuthority => Self::NotPendingAuthority,
+            SssError::TimelockRequired => Self::TimelockRequired,
+            SssError::PendingActionMismatch => Self::PendingActionMismatch,
+            SssError::TimelockNotElapsed => Self::TimelockNotElapsed,
+            SssError::TimelockDisabled => Self::TimelockDisabled,
+            SssError::InvalidTimelockDelay => Self::InvalidTimelockDelay,
+            SssError::LargeMintApprovalRequired => Self::LargeMintApprovalRequired,
+            SssError::MintApprovalMismatch => Self::MintApprovalMismatch,
+            SssError::MintApprovalRejected => Self::MintApprovalRejected,
+            SssError::InvalidApprovalCount => Self::InvalidApprovalCount,
+            SssError::RoleExpired => Self::RoleExpired,
+            SssError::InvalidRoleExpiry => Self::InvalidRoleExpiry,
+            SssError::RoleExpiryNotSupported => Self::RoleExpiryNotSupported,
+            SssError::AuthorityCannotRenounce => Self::AuthorityCannotRenounce,
+            SssError::RescueSourceNotConfigOwned => Self::RescueSourceNotConfigOwned,
+            SssError::InvalidPauseFlags => Self::InvalidPauseFlags,
+            SssError::PauseExpiryDisabled => Self::PauseExpiryDisabled,
+            SssError::LargeSeizeApprovalRequired => Self::LargeSeizeApprovalRequired,
+            SssError::SeizeApprovalMismatch => Self::SeizeApprovalMismatch,
+            SssError::SeizeApprovalRejected => Self::SeizeApprovalRejected,
+            SssError::InvalidSeizureReason => Self::InvalidSeizureReason,
+            SssError::SeizeDestinationNotTreasury => Self::SeizeDestinationNotTreasury,
+            SssError::SeizeRequiresFrozenAccount => Self::SeizeRequiresFrozenAccount,
+            SssError::InvalidFreezeBatch => Self::InvalidFreezeBatch,
+            SssError::FreezeExemptionMismatch => Self::FreezeExemptionMismatch,
+            SssError::InvalidMintBatch => Self::InvalidMintBatch,
+            SssError::InvalidBurnBatch => Self::InvalidBurnBatch,
+            SssError::InvalidQuotaPeriod => Self::InvalidQuotaPeriod,
+            SssError::BurnQuotaExceeded => Self::BurnQuotaExceeded,
+            SssError::MintRateLimitExceeded => Self::MintRateLimitExceeded,
+            SssError::InvalidMintRateLimit => Self::InvalidMintRateLimit,
+            SssError::MintRequestMismatch => Self::MintRequestMismatch,
+            SssError::MintRequestSelfFulfillment => Self::MintRequestSelfFulfillment,
+            SssError::RedemptionExceedsSupply => Self::RedemptionExceedsSupply,
+            SssError::RedemptionMismatch => Self::RedemptionMismatch,
+            SssError::WipeRequiresFrozenAccount => Self::WipeRequiresFrozenAccount,
+            SssError::InvalidWipeReason => Self::InvalidWipeReason,
+            SssError::PresetNeedsExternalMint => Self::PresetNeedsExternalMint,
+            SssError::MintAuthorityMismatch => Self::MintAuthorityMismatch,
+            SssError::DecimalsMismatch => Self::DecimalsMismatch,
+            SssError::AlreadySunset => Self::AlreadySunset,
+            SssError::NotSunset => Self::NotSunset,
+            SssError::SupplyNotZero => Self::SupplyNotZero,
+            SssError::AdminsRemain => Self::AdminsRemain,
+            SssError::InvalidOracleFeedId => Self::InvalidOracleFeedId,
+            SssError::ReserveAttestationRequired => Self::ReserveAttestationRequired,
+            SssError::ReserveAttestationStale => Self::ReserveAttestationStale,
+            SssError::ReservesExceeded => Self::ReservesExceeded,
+            SssError::InvalidAttestationTime => Self::InvalidAttestationTime,
+            SssError::InvalidCollateralRatio => Self::InvalidCollateralRatio,
+            SssError::InvalidCollateralMint => Self::InvalidCollateralMint,
+            SssError::CollateralMintBelowMinimum => Self::CollateralMintBelowMinimum,
+            SssError::CollateralWithdrawalExceedsIssued => Self::CollateralWithdrawalExceedsIssued,
+            SssError::CollateralPriceRequired => Self::CollateralPriceRequired,
+            SssError::FeeScheduleRequired => Self::FeeScheduleRequired,
+            SssError::InvalidFeeTreasury => Self::InvalidFeeTreasury,
+            SssError::CollateralRatioNotKept => Self::CollateralRatioNotKept,
+            SssError::FeeTierRequired => Self::FeeTierRequired,
+            SssError::FeesRequireSingleMint => Self::FeesRequireSingleMint,
+            SssError::NotLegacyRoleAccount => Self::NotLegacyRoleAccount,
+            SssError::PeriodSummaryOutOfOrder => Self::PeriodSummaryOutOfOrder,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() {
+        for code in ERROR_CODE_OFFSET..ERROR_CODE_OFFSET + 137 {
+            let typed = SssErrorCode::from(code);
+            assert_ne!(typed, SssErrorCode::Unknown(code));
+            assert_eq!(typed.code(), code);
+        }
+        assert_eq!(
+            SssErrorCode::from(ERROR_CODE_OFFSET + 137),
+            SssErrorCode::Unknown(ERROR_CODE_OFFSET + 137)
+        );
+    }
+
+    #[test]
+    fn test_codes_match_anchor_numbering() {
+        for error in [
+            SssError::Paused,
+            SssError::InvalidBlacklistAccount,
+            SssError::PeriodSummaryOutOfOrder,
+        ] {
+            assert_eq!(SssErrorCode::from(error).code(), u32::from(error));
+            assert_eq!(
+                SssErrorCode::from(u32::from(error)),
+                SssErrorCode::from(error)
+            );
+        }
+    }
+}
@@ -1,5 +1,18 @@
+//! Events emitted by sss-core.
+//!
+//! Every event carries the config PDA and the config's `mint_index`, so an
+//! indexer serving many SSS deployments can partition the log stream
+//! without resolving mint to config for each line. Cross-mint events carry
+//! both sides. `mint_index` is zero for configs initialized without the
+//! `DeploymentCounter`.
+
 use anchor_lang::prelude::*;
 
+use crate::state::{
+    DistributionFunding, FeeKind, FeeRates, MintAuthorities, OracleKind, OraclePriceSource,
+    ReceivershipActionKind, ReserveMovementKind, ThawRamp, TimelockedAction,
+};
+
 #[event]
 pub struct StablecoinInitialized {
     pub mint: Pubkey,
@@ -9,6 +22,8 @@ pub struct StablecoinInitialized {
     pub name: String,
     pub symbol: String,
     pub decimals: u8,
+    pub config: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
@@ -18,6 +33,18 @@ pub struct TokensMinted {
     pub amount: u64,
     pub minter: Pubkey,
     pub new_supply: u64,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct MintReceiptCreated {
+    pub config: Pubkey,
+    pub receipt: Pubkey,
+    pub minter: Pubkey,
+    pub amount: u64,
+    pub reference: [u8; 32],
+    pub mint_index: u64,
 }
 
 #[event]
@@ -32,6 +59,8 @@ pub struct TokensBurned {
     /// acted on a third-party account). Compliance systems should flag and
     /// independently verify all burns where `from_owner != burner`.
     pub from_owner: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
@@ -39,6 +68,8 @@ pub struct AccountFrozen {
     pub mint: Pubkey,
     pub account: Pubkey,
     pub freezer: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
@@ -46,18 +77,58 @@ pub struct AccountThawed {
     pub mint: Pubkey,
     pub account: Pubkey,
     pub freezer: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
 pub struct OperationsPaused {
     pub mint: Pubkey,
     pub pauser: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
 pub struct OperationsUnpaused {
     pub mint: Pubkey,
     pub pauser: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by `set_pause_flags`; `pause` and `unpause` emit
+/// `OperationsPaused` and `OperationsUnpaused`.
+#[event]
+pub struct PauseFlagsChanged {
+    pub mint: Pubkey,
+    pub old_flags: u8,
+    pub new_flags: u8,
+    pub updated_by: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by `renew_pause`.
+#[event]
+pub struct PauseRenewed {
+    pub mint: Pubkey,
+    pub expires_at: i64,
+    pub renewed_by: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted when minting resumes after a pause long enough to start the
+/// config's thaw ramp. The full supply cap is available from `end_slot`.
+#[event]
+pub struct ThawRampStarted {
+    pub mint: Pubkey,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub base_supply: u64,
+    pub config: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
@@ -67,6 +138,55 @@ pub struct TokensSeized {
     pub to: Pubkey,
     pub amount: u64,
     pub seizer: Pubkey,
+    /// Compliance reason, also stored in the seizure's `SeizureRecord`.
+    pub reason: String,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// A frozen account's whole balance burned by `wipe_account`, alongside
+/// `TokensBurned`.
+#[event]
+pub struct AccountWiped {
+    pub config: Pubkey,
+    /// Index of the `WipeRecord`.
+    pub index: u64,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub wiper: Pubkey,
+    /// Compliance reason, also stored in the `WipeRecord`.
+    pub reason: String,
+    pub mint_index: u64,
+}
+
+/// Tokens moved out of an account the config PDA owns by `rescue_tokens`.
+#[event]
+pub struct TokensRescued {
+    pub config: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub rescuer: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct RoleGrantPending {
+    pub config: Pubkey,
+    pub address: Pubkey,
+    pub role: u8,
+    pub granted_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct RoleGrantCancelled {
+    pub config: Pubkey,
+    pub address: Pubkey,
+    pub role: u8,
+    pub cancelled_by: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
@@ -75,6 +195,7 @@ pub struct RoleGranted {
     pub address: Pubkey,
     pub role: u8,
     pub granted_by: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
@@ -83,6 +204,17 @@ pub struct RoleRevoked {
     pub address: Pubkey,
     pub role: u8,
     pub revoked_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by `propose_authority`. Nothing changes hands until `to` signs
+/// `accept_authority`.
+#[event]
+pub struct AuthorityProposed {
+    pub config: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
@@ -90,6 +222,20 @@ pub struct AuthorityTransferred {
     pub config: Pubkey,
     pub from: Pubkey,
     pub to: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by `update_metadata` with the name, symbol and URI now in force
+/// on the config (and on the mint's Token-2022 metadata, where present).
+#[event]
+pub struct MetadataUpdated {
+    pub config: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub updater: Pubkey,
+    pub mint_index: u64,
 }
 
 #[event]
@@ -97,4 +243,910 @@ pub struct ConfigUpdated {
     pub config: Pubkey,
     pub field: String,
     pub updater: Pubkey,
+    pub mint_index: u64,
+}
+
+/// A config brought up to the current account layout by `migrate_config`.
+#[event]
+pub struct ConfigMigrated {
+    pub config: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub from_program_version: [u8; 3],
+    pub to_program_version: [u8; 3],
+    pub migrated_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// A standby's image brought up to the current config layout by
+/// `migrate_standby_config`.
+#[event]
+pub struct StandbyConfigMigrated {
+    pub config: Pubkey,
+    pub standby: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub migrated_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Minting permanently disabled by `sunset`; burns and redemptions stay
+/// open so holders can wind down. `supply` is what remains outstanding.
+#[event]
+pub struct StablecoinSunset {
+    pub mint: Pubkey,
+    pub sunset_by: Pubkey,
+    pub supply: u64,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// The config closed by `close_config` after its mint's authorities were
+/// revoked. Last event for the stablecoin.
+#[event]
+pub struct StablecoinClosed {
+    pub mint: Pubkey,
+    pub closed_by: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// A `StablecoinConfig` field written by an admin instruction, identified in
+/// `ConfigFieldChanged`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigField {
+    Authority,
+    AdminCount,
+    SupplyCap,
+    DisabledInstructions,
+    LockedInstructions,
+    ThawRamp,
+    RampStartedAt,
+    DestinationMintLimit,
+    HaltMintOnSupplyMismatch,
+    PauseOnAuthorityAnomaly,
+    OracleFeedId,
+    MaxConfidenceBps,
+    OraclePriceSource,
+    EventQueueEnabled,
+    ReceiptThreshold,
+    ReceiptRetentionSecs,
+    IncidentFlag,
+    IncidentUri,
+    PendingAuthority,
+    AdminTimelockSecs,
+    LargeMintThreshold,
+    LargeMintApprovals,
+    Uri,
+    UnpauseRequiresAdmin,
+    MaxPauseSecs,
+    LargeSeizeThreshold,
+    Treasury,
+    SeizeRequiresFrozen,
+    Name,
+    Symbol,
+    SunsetAt,
+    OracleKind,
+    MaxAttestationAgeSecs,
+    FeeTreasury,
+}
+
+/// A config value in `ConfigFieldChanged` and the hook's
+/// `HookConfigFieldChanged`. Optional fields that are unset are `None`, e.g.
+/// `cap.map_or(ConfigValue::None, ConfigValue::U64)`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ConfigValue {
+    None,
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I64(i64),
+    Pubkey(Pubkey),
+    Bytes32([u8; 32]),
+    Text(String),
+    ThawRamp(ThawRamp),
+    OraclePriceSource(OraclePriceSource),
+    OracleKind(OracleKind),
+}
+
+/// Emitted by admin instructions, next to their own event, for every config
+/// field they actually change, with its value before and after, so the full
+/// configuration history can be rebuilt from logs. Writes that leave a field
+/// as it was emit nothing.
+#[event]
+pub struct ConfigFieldChanged {
+    pub config: Pubkey,
+    pub field: ConfigField,
+    pub old: ConfigValue,
+    pub new: ConfigValue,
+    pub updater: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct RegulatorAccessGranted {
+    pub config: Pubkey,
+    pub regulator: Pubkey,
+    pub name: String,
+    pub granted_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct RegulatorAccessRevoked {
+    pub config: Pubkey,
+    pub regulator: Pubkey,
+    pub revoked_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct ReportRequested {
+    pub config: Pubkey,
+    pub regulator: Pubkey,
+    pub period: u64,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct ReportPublished {
+    pub config: Pubkey,
+    pub regulator: Pubkey,
+    pub period: u64,
+    pub report_hash: [u8; 32],
+    pub published_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct ReportAcknowledged {
+    pub config: Pubkey,
+    pub regulator: Pubkey,
+    pub period: u64,
+    pub report_hash: [u8; 32],
+    pub mint_index: u64,
+}
+
+/// Issuer-defined operational event, emitted by `emit_issuer_event`.
+#[event]
+pub struct IssuerEvent {
+    pub config: Pubkey,
+    /// Issuer-chosen label, e.g. `"settlement-batch"`.
+    pub tag: String,
+    /// Opaque payload; its encoding is up to the issuer.
+    pub data: Vec<u8>,
+    pub emitted_by: Pubkey,
+    pub timestamp: i64,
+    pub mint_index: u64,
+}
+
+impl IssuerEvent {
+    pub const MAX_TAG_LEN: usize = 32;
+    pub const MAX_DATA_LEN: usize = 512;
+}
+
+#[event]
+pub struct SnapshotDeclared {
+    pub config: Pubkey,
+    pub snapshot: Pubkey,
+    pub slot: u64,
+    pub record_until_slot: u64,
+    pub declared_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct SnapshotBalanceRecorded {
+    pub snapshot: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct DistributorCreated {
+    pub config: Pubkey,
+    pub distributor: Pubkey,
+    pub id: u64,
+    pub merkle_root: [u8; 32],
+    pub max_total: u64,
+    pub funding: DistributionFunding,
+    pub created_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct DistributionClaimed {
+    pub distributor: Pubkey,
+    pub index: u64,
+    pub claimant: Pubkey,
+    pub amount: u64,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct SwapRouteCreated {
+    pub from_config: Pubkey,
+    pub to_config: Pubkey,
+    pub created_by: Pubkey,
+    pub from_mint_index: u64,
+    pub to_mint_index: u64,
+}
+
+#[event]
+pub struct SwapRouteClosed {
+    pub from_config: Pubkey,
+    pub to_config: Pubkey,
+    pub closed_by: Pubkey,
+    /// The side whose Admin closed the route.
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct ParSwapped {
+    pub from_mint: Pubkey,
+    pub to_mint: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub from_config: Pubkey,
+    pub to_config: Pubkey,
+    pub from_mint_index: u64,
+    pub to_mint_index: u64,
+}
+
+#[event]
+pub struct QuotaDelegated {
+    pub config: Pubkey,
+    pub parent: Pubkey,
+    pub sub_minter: Pubkey,
+    pub quota: u64,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct SubMinterRevoked {
+    pub config: Pubkey,
+    pub parent: Pubkey,
+    pub sub_minter: Pubkey,
+    pub amount_minted: u64,
+    pub mint_index: u64,
+}
+
+/// An Admin cleared a minter's consumed quota with `reset_minter_usage`.
+#[event]
+pub struct MinterUsageReset {
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    pub previous_amount_minted: u64,
+    pub admin: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct KycApproved {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub approved_by: Pubkey,
+    pub expires_at: Option<i64>,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct KycRevoked {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub revoked_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct MinterKeysConfigured {
+    pub config: Pubkey,
+    pub minter_role: Pubkey,
+    pub cold_key: Pubkey,
+    pub hot_key: Pubkey,
+    pub hot_window_quota: u64,
+    pub window_secs: i64,
+    pub updated_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct MinterKeysRemoved {
+    pub config: Pubkey,
+    pub minter_role: Pubkey,
+    pub hot_key: Pubkey,
+    pub removed_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct CapabilitiesUpdated {
+    pub config: Pubkey,
+    pub disabled: u32,
+    pub locked: u32,
+    pub updated_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct ReserveMovementRecorded {
+    pub config: Pubkey,
+    pub index: u64,
+    pub kind: ReserveMovementKind,
+    pub amount: u64,
+    pub ref_hash: [u8; 32],
+    pub recorded_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by `publish_reserve_attestation`, with the global supply at the
+/// time so the reserve ratio can be charted from logs.
+#[event]
+pub struct ReservesAttested {
+    pub config: Pubkey,
+    pub reserve_amount: u64,
+    pub attested_at: i64,
+    pub report_hash: [u8; 32],
+    pub attestor: Pubkey,
+    pub global_supply: u64,
+    pub mint_index: u64,
+}
+
+/// Emitted by `export_period_summary` with the period's bounds and its
+/// headline totals; the full figures are on the `PeriodSummary` account.
+#[event]
+pub struct PeriodSummaryExported {
+    pub config: Pubkey,
+    pub period_id: u64,
+    pub opened_at: i64,
+    pub closed_at: i64,
+    pub first_nonce: u64,
+    pub last_nonce: u64,
+    pub minted: u64,
+    pub burned: u64,
+    pub exported_by: Pubkey,
+    pub auditor: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct CollateralVaultConfigured {
+    pub config: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub collateral_ratio_bps: u16,
+    pub collateral_feed_id: Option<[u8; 32]>,
+    pub updated_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct CollateralDeposited {
+    pub config: Pubkey,
+    pub depositor: Pubkey,
+    pub to: Pubkey,
+    pub collateral_amount: u64,
+    pub minted_amount: u64,
+    pub total_collateral: u64,
+    pub total_issued: u64,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct CollateralWithdrawn {
+    pub config: Pubkey,
+    pub holder: Pubkey,
+    pub burned_amount: u64,
+    pub collateral_amount: u64,
+    pub total_collateral: u64,
+    pub total_issued: u64,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct FreezeExemptionAdded {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub added_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct FreezeExemptionRemoved {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub removed_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by the permissionless `thaw_exempt_account`.
+#[event]
+pub struct ExemptAccountThawed {
+    pub mint: Pubkey,
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by the permissionless `freeze_blacklisted`. `cranker` is the
+/// keeper that paid for it.
+#[event]
+pub struct BlacklistedAccountFrozen {
+    pub mint: Pubkey,
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub cranker: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by `bridge_mint`. `local_supply` is the supply on this chain
+/// after the mint; issuer supply is unchanged.
+#[event]
+pub struct BridgeMinted {
+    pub mint: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub bridge: Pubkey,
+    pub local_supply: u64,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by `bridge_burn`. `local_supply` is the supply on this chain
+/// after the burn; issuer supply is unchanged.
+#[event]
+pub struct BridgeBurned {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub amount: u64,
+    pub bridge: Pubkey,
+    pub local_supply: u64,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by `set_incident_status`. Wallets can also read the current
+/// status from the config's `incident_flag` and `incident_uri`.
+#[event]
+pub struct IncidentStatusChanged {
+    pub mint: Pubkey,
+    pub active: bool,
+    pub uri: String,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct ReceiverRegistered {
+    pub config: Pubkey,
+    pub receiver: Pubkey,
+    pub quorum: u8,
+    pub registered_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted for each Admin approval. `approvals` counts distinct Admins so far.
+#[event]
+pub struct ReceivershipApproved {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub approvals: u8,
+    pub quorum: u8,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct ReceivershipActivated {
+    pub config: Pubkey,
+    pub receiver: Pubkey,
+    pub approved_by: Vec<Pubkey>,
+    pub mint_index: u64,
+}
+
+/// Emitted alongside the usual pause, freeze or seize event whenever the
+/// receiver acts, so receivership actions are tagged apart from the issuer's.
+/// `subject` is the mint for a pause, otherwise the frozen or seized token
+/// account; `amount` is zero except for a seize.
+#[event]
+pub struct ReceivershipAction {
+    pub mint: Pubkey,
+    pub receiver: Pubkey,
+    pub action: ReceivershipActionKind,
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct ReceivershipDischarged {
+    pub config: Pubkey,
+    pub receiver: Pubkey,
+    pub was_active: bool,
+    pub mint_index: u64,
+}
+
+/// `verify_supply` found the mint's `supply` differing from the config's
+/// `local_supply()`. `minting_disabled` is set when this call switched off
+/// the mint capability.
+#[event]
+pub struct SupplyMismatch {
+    pub mint: Pubkey,
+    pub expected: u64,
+    pub actual: u64,
+    pub minting_disabled: bool,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// `reconcile_supply` compared the mint's `supply` with the config's
+/// `local_supply()`; `delta` is `actual - expected`. `adjusted` is set when
+/// the shortfall was added to `total_burned`.
+#[event]
+pub struct SupplyReconciled {
+    pub mint: Pubkey,
+    pub expected: u64,
+    pub actual: u64,
+    pub delta: i128,
+    pub adjusted: bool,
+    pub reconciler: Pubkey,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+/// `verify_authorities` found the mint's authorities or transfer hook
+/// changed out-of-band. `observed` is what the mint names now; `paused` is
+/// set when this call paused the config.
+#[event]
+pub struct AuthorityAnomaly {
+    pub mint: Pubkey,
+    pub observed: MintAuthorities,
+    pub paused: bool,
+    pub config: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct FeeScheduleUpdated {
+    pub config: Pubkey,
+    pub tiers: Vec<FeeRates>,
+    pub updated_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct FeeTierAssigned {
+    pub config: Pubkey,
+    pub wallet: Pubkey,
+    pub tier: u8,
+    pub assigned_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct FeeTierCleared {
+    pub config: Pubkey,
+    pub wallet: Pubkey,
+    pub cleared_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// A `FeeSchedule` mint or burn fee paid into `config.fee_treasury`.
+/// `payer` is the wallet whose tier set the rate; `amount` is the gross
+/// amount the fee was taken from.
+#[event]
+pub struct FeeCollected {
+    pub config: Pubkey,
+    pub kind: FeeKind,
+    pub payer: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub treasury: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct SweepRouteRegistered {
+    pub config: Pubkey,
+    pub intake: Pubkey,
+    pub treasury: Pubkey,
+    pub threshold: u64,
+    pub min_interval: i64,
+    pub registered_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct SweepRouteClosed {
+    pub config: Pubkey,
+    pub intake: Pubkey,
+    pub closed_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// `sweep_intake` moved an intake account's whole balance to its treasury.
+#[event]
+pub struct IntakeSwept {
+    pub config: Pubkey,
+    pub intake: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub cranker: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted when an Admin opens or re-sizes a market maker's `CreditLine`.
+#[event]
+pub struct CreditLineSet {
+    pub config: Pubkey,
+    pub market_maker: Pubkey,
+    pub limit: u64,
+    pub repay_cutoff: u32,
+    pub set_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted by `draw_credit`, alongside `TokensMinted`.
+#[event]
+pub struct CreditDrawn {
+    pub config: Pubkey,
+    pub market_maker: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub outstanding: u64,
+    pub repay_by: i64,
+    pub mint_index: u64,
+}
+
+/// Emitted by `repay_credit`, alongside `TokensBurned`.
+#[event]
+pub struct CreditRepaid {
+    pub config: Pubkey,
+    pub market_maker: Pubkey,
+    pub from: Pubkey,
+    pub amount: u64,
+    pub outstanding: u64,
+    pub mint_index: u64,
+}
+
+/// Emitted by the permissionless `freeze_defaulted_credit` for each market
+/// maker token account it freezes.
+#[event]
+pub struct CreditDefaultFrozen {
+    pub config: Pubkey,
+    pub market_maker: Pubkey,
+    pub account: Pubkey,
+    pub outstanding: u64,
+    pub repay_by: i64,
+    pub cranker: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct CreditLineClosed {
+    pub config: Pubkey,
+    pub market_maker: Pubkey,
+    pub closed_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct StandbyConfigSynced {
+    pub config: Pubkey,
+    pub standby: Pubkey,
+    /// The primary's `state_nonce` at the sync, to match against its digest.
+    pub state_nonce: u64,
+    pub synced_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct FailoverRequested {
+    pub config: Pubkey,
+    pub standby: Pubkey,
+    pub requested_by: Pubkey,
+    /// Earliest time `failover` may run.
+    pub executable_at: i64,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct FailoverCancelled {
+    pub config: Pubkey,
+    pub standby: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub mint_index: u64,
+}
+
+/// Emitted when `failover` overwrites the primary config with the standby
+/// image. The config comes back paused; `synced_at` says how old the
+/// restored parameters are.
+#[event]
+pub struct FailoverExecuted {
+    pub config: Pubkey,
+    pub standby: Pubkey,
+    pub synced_at: i64,
+    pub new_supply: u64,
+    pub executed_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct PendingActionQueued {
+    pub config: Pubkey,
+    pub id: u64,
+    pub action: TimelockedAction,
+    pub queued_by: Pubkey,
+    pub executable_at: i64,
+    pub mint_index: u64,
+}
+
+/// Emitted by the timelocked instruction that consumed the pending action,
+/// alongside its own events.
+#[event]
+pub struct PendingActionExecuted {
+    pub config: Pubkey,
+    pub id: u64,
+    pub action: TimelockedAction,
+    pub executed_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct PendingActionCancelled {
+    pub config: Pubkey,
+    pub id: u64,
+    pub action: TimelockedAction,
+    pub cancelled_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct MintApprovalRequested {
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    pub id: u64,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct MintRequested {
+    pub config: Pubkey,
+    pub requester: Pubkey,
+    pub id: u64,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub reference: [u8; 32],
+    pub mint_index: u64,
+}
+
+/// Emitted alongside `TokensMinted` when a Minter fulfils a request, so the
+/// requester and the minter are on record together.
+#[event]
+pub struct MintRequestFulfilled {
+    pub config: Pubkey,
+    pub requester: Pubkey,
+    pub id: u64,
+    pub minter: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub reference: [u8; 32],
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct MintRequestCancelled {
+    pub config: Pubkey,
+    pub requester: Pubkey,
+    pub id: u64,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct RedemptionRequested {
+    pub config: Pubkey,
+    pub holder: Pubkey,
+    pub id: u64,
+    pub amount: u64,
+    pub bank_reference: [u8; 32],
+    pub mint_index: u64,
+}
+
+/// Emitted alongside `TokensBurned` when a Burner settles a redemption.
+#[event]
+pub struct RedemptionSettled {
+    pub config: Pubkey,
+    pub holder: Pubkey,
+    pub id: u64,
+    pub amount: u64,
+    pub bank_reference: [u8; 32],
+    pub burner: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct RedemptionCancelled {
+    pub config: Pubkey,
+    pub holder: Pubkey,
+    pub id: u64,
+    /// Tokens returned to the holder.
+    pub amount: u64,
+    /// The holder, or the Burner that rejected the request.
+    pub cancelled_by: Pubkey,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct MintApproved {
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    pub id: u64,
+    pub approver: Pubkey,
+    /// Approvals recorded so far, including this one.
+    pub approvals: u8,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct MintApprovalCancelled {
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    pub id: u64,
+    pub mint_index: u64,
+}
+
+/// Emitted alongside `TokensMinted` when a mint consumes its approval, so
+/// the co-signers are on record next to the mint.
+#[event]
+pub struct MintApprovalExecuted {
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    pub id: u64,
+    pub amount: u64,
+    pub approvers: Vec<Pubkey>,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct SeizeApproved {
+    pub config: Pubkey,
+    pub approver: Pubkey,
+    pub id: u64,
+    pub seizer: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub mint_index: u64,
+}
+
+#[event]
+pub struct SeizeApprovalCancelled {
+    pub config: Pubkey,
+    pub approver: Pubkey,
+    pub id: u64,
+    pub mint_index: u64,
+}
+
+/// Emitted alongside `TokensSeized` when a seizure consumes its approval,
+/// so both keys are on record next to the seizure.
+#[event]
+pub struct SeizeApprovalExecuted {
+    pub config: Pubkey,
+    pub seizer: Pubkey,
+    pub approver: Pubkey,
+    pub id: u64,
+    pub amount: u64,
+    pub mint_index: u64,
 }
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::SssError;
+use crate::events::CollateralVaultConfigured;
+use crate::state::{CollateralVault, Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+pub struct CreateCollateralVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mint::token_program = collateral_token_program,
+        constraint = collateral_mint.key() != config.mint @ SssError::InvalidCollateralMint,
+    )]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = CollateralVault::SPACE,
+        seeds = [CollateralVault::SSS_COLLATERAL_VAULT_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub collateral_vault: Account<'info, CollateralVault>,
+
+    /// Owned by the vault PDA rather than the config, so `rescue_tokens`
+    /// cannot reach it.
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = collateral_vault,
+        associated_token::token_program = collateral_token_program,
+    )]
+    pub vault_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub collateral_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Approve `collateral_mint` as the config's on-chain collateral. Deposits
+/// mint at `collateral_ratio_bps` of the collateral's value, priced by the
+/// Pyth feed `collateral_feed_id` or at par when `None`. The collateral
+/// mint cannot be changed later.
+pub fn handler_create_collateral_vault(
+    ctx: Context<CreateCollateralVault>,
+    collateral_ratio_bps: u16,
+    collateral_feed_id: Option<[u8; 32]>,
+) -> Result<()> {
+    validate_vault_terms(collateral_ratio_bps, collateral_feed_id)?;
+
+    let vault = &mut ctx.accounts.collateral_vault;
+    vault.config = ctx.accounts.config.key();
+    vault.collateral_mint = ctx.accounts.collateral_mint.key();
+    vault.vault_account = ctx.accounts.vault_account.key();
+    vault.collateral_ratio_bps = collateral_ratio_bps;
+    vault.collateral_feed_id = collateral_feed_id;
+    vault.total_collateral = 0;
+    vault.total_issued = 0;
+    vault.bump = ctx.bumps.collateral_vault;
+
+    emit!(CollateralVaultConfigured {
+        config: vault.config,
+        collateral_mint: vault.collateral_mint,
+        collateral_ratio_bps,
+        collateral_feed_id,
+        updated_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCollateralVault<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        seeds = [CollateralVault::SSS_COLLATERAL_VAULT_SEED, config.key().as_ref()],
+        bump = collateral_vault.bump,
+    )]
+    pub collateral_vault: Account<'info, CollateralVault>,
+}
+
+/// Change the ratio and price feed future deposits are minted at. Collateral
+/// already deposited stays in the vault and is withdrawn pro rata as before.
+pub fn handler_update_collateral_vault(
+    ctx: Context<UpdateCollateralVault>,
+    collateral_ratio_bps: u16,
+    collateral_feed_id: Option<[u8; 32]>,
+) -> Result<()> {
+    validate_vault_terms(collateral_ratio_bps, collateral_feed_id)?;
+
+    let vault = &mut ctx.accounts.collateral_vault;
+    vault.collateral_ratio_bps = collateral_ratio_bps;
+    vault.collateral_feed_id = collateral_feed_id;
+
+    emit!(CollateralVaultConfigured {
+        config: vault.config,
+        collateral_mint: vault.collateral_mint,
+        collateral_ratio_bps,
+        collateral_feed_id,
+        updated_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+fn validate_vault_terms(
+    collateral_ratio_bps: u16,
+    collateral_feed_id: Option<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        collateral_ratio_bps >= CollateralVault::MIN_RATIO_BPS,
+        SssError::InvalidCollateralRatio
+    );
+    require!(
+        collateral_feed_id != Some([0u8; 32]),
+        SssError::InvalidOracleFeedId
+    );
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{ConfigField, ConfigUpdated, ConfigValue};
+use crate::instructions::admin::update_config::emit_config_diff;
+use crate::state::{EventQueue, Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureEventQueue<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// Created on first use and kept when the queue is disabled, so
+    /// consumers do not lose their position across a toggle.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = EventQueue::SPACE,
+        seeds = [EventQueue::SSS_EVENT_QUEUE_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub event_queue: Account<'info, EventQueue>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Turn recording of seize, pause and unpause into the config's
+/// `EventQueue` on or off.
+pub fn handler_configure_event_queue(
+    ctx: Context<ConfigureEventQueue>,
+    enabled: bool,
+) -> Result<()> {
+    let queue = &mut ctx.accounts.event_queue;
+    queue.config = ctx.accounts.config.key();
+    queue.bump = ctx.bumps.event_queue;
+
+    let was_enabled = ctx.accounts.config.event_queue_enabled;
+    ctx.accounts.config.event_queue_enabled = enabled;
+    ctx.accounts.config.refresh_digest();
+
+    emit_config_diff(
+        &ctx.accounts.config,
+        ctx.accounts.admin.key(),
+        ConfigField::EventQueueEnabled,
+        ConfigValue::Bool(was_enabled),
+        ConfigValue::Bool(enabled),
+    );
+
+    emit!(ConfigUpdated {
+        config: ctx.accounts.config.key(),
+        field: "event_queue_enabled".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
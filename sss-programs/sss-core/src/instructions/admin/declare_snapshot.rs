@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::SnapshotDeclared;
+use crate::state::{Role, RoleAccount, Snapshot, StablecoinConfig};
+
+#[derive(Accounts)]
+#[instruction(slot: u64)]
+pub struct DeclareSnapshot<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Snapshot::SPACE,
+        seeds = [
+            Snapshot::SSS_SNAPSHOT_SEED,
+            config.key().as_ref(),
+            &slot.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_declare_snapshot(
+    ctx: Context<DeclareSnapshot>,
+    slot: u64,
+    record_window_slots: u64,
+) -> Result<()> {
+    require!(slot >= Clock::get()?.slot, SssError::SnapshotSlotInPast);
+    let record_until_slot = slot
+        .checked_add(record_window_slots)
+        .ok_or(SssError::InvalidSnapshotWindow)?;
+
+    let config = &ctx.accounts.config;
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.config = config.key();
+    snapshot.slot = slot;
+    snapshot.record_until_slot = record_until_slot;
+    snapshot.supply_at_declaration = config.current_supply();
+    snapshot.total_recorded = 0;
+    snapshot.record_count = 0;
+    snapshot.declared_by = ctx.accounts.admin.key();
+    snapshot.bump = ctx.bumps.snapshot;
+
+    emit!(SnapshotDeclared {
+        config: snapshot.config,
+        snapshot: snapshot.key(),
+        slot,
+        record_until_slot,
+        declared_by: snapshot.declared_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
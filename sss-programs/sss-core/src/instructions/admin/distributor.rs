@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::DistributorCreated;
+use crate::state::{DistributionFunding, Distributor, Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateDistributor<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Distributor::SPACE,
+        seeds = [
+            Distributor::SSS_DISTRIBUTOR_SEED,
+            config.key().as_ref(),
+            &id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub distributor: Account<'info, Distributor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Publish a merkle root of `(index, claimant, amount)` leaves that holders
+/// can claim against. The root is immutable; a corrected list gets a new `id`.
+pub fn handler_create_distributor(
+    ctx: Context<CreateDistributor>,
+    id: u64,
+    merkle_root: [u8; 32],
+    max_total: u64,
+    funding: DistributionFunding,
+) -> Result<()> {
+    require!(max_total > 0, SssError::ZeroAmount);
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.config = ctx.accounts.config.key();
+    distributor.id = id;
+    distributor.merkle_root = merkle_root;
+    distributor.funding = funding;
+    distributor.max_total = max_total;
+    distributor.total_claimed = 0;
+    distributor.num_claimed = 0;
+    distributor.created_by = ctx.accounts.admin.key();
+    distributor.created_at = Clock::get()?.unix_timestamp;
+    distributor.bump = ctx.bumps.distributor;
+
+    emit!(DistributorCreated {
+        config: distributor.config,
+        distributor: distributor.key(),
+        id,
+        merkle_root,
+        max_total,
+        funding,
+        created_by: distributor.created_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
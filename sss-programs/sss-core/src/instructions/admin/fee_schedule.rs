@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{FeeScheduleUpdated, FeeTierAssigned, FeeTierCleared};
+use crate::state::{FeeRates, FeeSchedule, FeeTierAssignment, Role, RoleAccount, StablecoinConfig};
+
+// Set Fee Schedule
+
+#[derive(Accounts)]
+pub struct SetFeeSchedule<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FeeSchedule::SPACE,
+        seeds = [FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create or replace the config's fee schedule. Assignments to tiers past
+/// the end of the new schedule fall back to tier 0.
+pub fn handler_set_fee_schedule(ctx: Context<SetFeeSchedule>, tiers: Vec<FeeRates>) -> Result<()> {
+    require!(
+        !tiers.is_empty()
+            && tiers.len() <= FeeSchedule::MAX_TIERS
+            && tiers.iter().all(FeeRates::is_valid),
+        SssError::InvalidFeeSchedule
+    );
+
+    let schedule = &mut ctx.accounts.fee_schedule;
+    schedule.config = ctx.accounts.config.key();
+    schedule.tiers = tiers;
+    schedule.updated_by = ctx.accounts.admin.key();
+    schedule.updated_at = Clock::get()?.unix_timestamp;
+    schedule.bump = ctx.bumps.fee_schedule;
+
+    emit!(FeeScheduleUpdated {
+        config: schedule.config,
+        tiers: schedule.tiers.clone(),
+        updated_by: schedule.updated_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Assign Fee Tier
+
+#[derive(Accounts)]
+pub struct AssignFeeTier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        seeds = [FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.key().as_ref()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// CHECK: The wallet being placed in a tier; it does not sign.
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FeeTierAssignment::SPACE,
+        seeds = [
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.key().as_ref(),
+            wallet.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub fee_tier: Account<'info, FeeTierAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Place `wallet` in `tier`, or move it to another tier.
+pub fn handler_assign_fee_tier(ctx: Context<AssignFeeTier>, tier: u8) -> Result<()> {
+    require!(
+        (tier as usize) < ctx.accounts.fee_schedule.tiers.len(),
+        SssError::UnknownFeeTier
+    );
+
+    let assignment = &mut ctx.accounts.fee_tier;
+    assignment.config = ctx.accounts.config.key();
+    assignment.wallet = ctx.accounts.wallet.key();
+    assignment.tier = tier;
+    assignment.assigned_by = ctx.accounts.admin.key();
+    assignment.assigned_at = Clock::get()?.unix_timestamp;
+    assignment.bump = ctx.bumps.fee_tier;
+
+    emit!(FeeTierAssigned {
+        config: assignment.config,
+        wallet: assignment.wallet,
+        tier,
+        assigned_by: assignment.assigned_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Clear Fee Tier
+
+#[derive(Accounts)]
+pub struct ClearFeeTier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.key().as_ref(),
+            fee_tier.wallet.as_ref(),
+        ],
+        bump = fee_tier.bump,
+    )]
+    pub fee_tier: Account<'info, FeeTierAssignment>,
+}
+
+/// Return a wallet to tier 0.
+pub fn handler_clear_fee_tier(ctx: Context<ClearFeeTier>) -> Result<()> {
+    emit!(FeeTierCleared {
+        config: ctx.accounts.config.key(),
+        wallet: ctx.accounts.fee_tier.wallet,
+        cleared_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{FreezeExemptionAdded, FreezeExemptionRemoved};
+use crate::state::{CreditLine, FreezeExemption, Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+pub struct AddFreezeExemption<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// CHECK: The exempted wallet; it does not sign.
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: The wallet's `CreditLine` PDA. Must not exist: anyone may thaw
+    /// an exempt wallet's accounts, which would undo a default freeze.
+    #[account(
+        seeds = [
+            CreditLine::SSS_CREDIT_LINE_SEED,
+            config.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub credit_line: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = FreezeExemption::SPACE,
+        seeds = [
+            FreezeExemption::SSS_FREEZE_EXEMPTION_SEED,
+            config.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub freeze_exemption: Account<'info, FreezeExemption>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_add_freeze_exemption(ctx: Context<AddFreezeExemption>) -> Result<()> {
+    require!(
+        ctx.accounts.credit_line.data_is_empty(),
+        SssError::CreditLineExemptWallet
+    );
+
+    let exemption = &mut ctx.accounts.freeze_exemption;
+    exemption.config = ctx.accounts.config.key();
+    exemption.owner = ctx.accounts.owner.key();
+    exemption.added_by = ctx.accounts.admin.key();
+    exemption.added_at = Clock::get()?.unix_timestamp;
+    exemption.bump = ctx.bumps.freeze_exemption;
+
+    emit!(FreezeExemptionAdded {
+        config: exemption.config,
+        owner: exemption.owner,
+        added_by: exemption.added_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveFreezeExemption<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            FreezeExemption::SSS_FREEZE_EXEMPTION_SEED,
+            config.key().as_ref(),
+            freeze_exemption.owner.as_ref(),
+        ],
+        bump = freeze_exemption.bump,
+    )]
+    pub freeze_exemption: Account<'info, FreezeExemption>,
+}
+
+pub fn handler_remove_freeze_exemption(ctx: Context<RemoveFreezeExemption>) -> Result<()> {
+    emit!(FreezeExemptionRemoved {
+        config: ctx.accounts.config.key(),
+        owner: ctx.accounts.freeze_exemption.owner,
+        removed_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
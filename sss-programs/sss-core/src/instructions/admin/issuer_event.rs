@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::IssuerEvent;
+use crate::state::{Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+pub struct EmitIssuerEvent<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+}
+
+/// Anchor an issuer-defined event (settlement batch, reserve rebalance, ...)
+/// in the program's event stream. The payload is opaque to the program.
+pub fn handler_emit_issuer_event(
+    ctx: Context<EmitIssuerEvent>,
+    tag: String,
+    data: Vec<u8>,
+) -> Result<()> {
+    require!(
+        !tag.is_empty() && tag.len() <= IssuerEvent::MAX_TAG_LEN,
+        SssError::InvalidIssuerEventTag
+    );
+    require!(
+        data.len() <= IssuerEvent::MAX_DATA_LEN,
+        SssError::IssuerEventDataTooLong
+    );
+
+    emit!(IssuerEvent {
+        config: ctx.accounts.config.key(),
+        tag,
+        data,
+        emitted_by: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
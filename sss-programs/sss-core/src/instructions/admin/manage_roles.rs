@@ -1,7 +1,15 @@
 use anchor_lang::prelude::*;
 
-use crate::events::{RoleGranted, RoleRevoked};
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::events::{
+    ConfigField, ConfigUpdated, ConfigValue, RoleGrantCancelled, RoleGrantPending, RoleGranted,
+    RoleRevoked,
+};
+use crate::instructions::admin::timelock::consume_pending_action;
+use crate::instructions::admin::update_config::emit_config_diff;
+use crate::state::{
+    ActionWindow, PendingAction, PendingRole, Role, RoleAccount, RoleDirectory, StablecoinConfig,
+    TimelockedAction,
+};
 
 // Grant Role
 #[derive(Accounts)]
@@ -11,9 +19,9 @@ pub struct GrantRole<'info> {
     pub admin: Signer<'info>,
 
     #[account(
-        mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
         bump = config.bump,
+        constraint = config.is_supported_version() @ crate::error::SssError::UnsupportedConfigVersion,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
@@ -33,66 +41,256 @@ pub struct GrantRole<'info> {
     /// CHECK: Any valid public key can be granted a role.
     pub grantee: UncheckedAccount<'info>,
 
+    /// The grant, until the grantee accepts it.
     #[account(
         init,
         payer = admin,
-        space = RoleAccount::ROLE_SPACE,
+        space = PendingRole::SPACE,
         seeds = [
-            RoleAccount::SSS_ROLE_SEED,
+            PendingRole::SSS_PENDING_ROLE_SEED,
             config.key().as_ref(),
             grantee.key().as_ref(),
             &[role],
         ],
         bump,
     )]
-    pub role_account: Account<'info, RoleAccount>,
+    pub pending_role: Account<'info, PendingRole>,
 
     pub system_program: Program<'info, System>,
+
+    /// Required for Admin grants while the admin timelock is enabled.
+    /// Closed on success.
+    #[account(mut, close = admin)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
 }
 
+/// Offer `role` to `grantee`. Nothing is authorized until the grantee signs
+/// `accept_role`.
 pub fn handler_grant(ctx: Context<GrantRole>, role: u8) -> Result<()> {
-    let role_enum = match role {
-        0 => Role::Admin,
-        1 => Role::Minter,
-        2 => Role::Freezer,
-        3 => Role::Pauser,
-        4 => Role::Burner,
-        5 => Role::Blacklister,
-        6 => Role::Seizer,
-        _ => return Err(error!(crate::error::SssError::InvalidRole)),
-    };
+    let role_enum = Role::from_u8(role).ok_or(error!(crate::error::SssError::InvalidRole))?;
 
     // Admin role grants/revokes are exempt from pause so admins can always
     // perform incident-response actions (revoke compromised keys, add new admins)
     // even while operations are paused. All other role changes are blocked.
     if role_enum != Role::Admin {
-        require!(!ctx.accounts.config.paused, crate::error::SssError::Paused);
+        require!(
+            !ctx.accounts.config.is_paused(Clock::get()?.unix_timestamp),
+            crate::error::SssError::Paused
+        );
+    }
+
+    if role_enum != Role::Admin {
+        require!(
+            ctx.accounts.pending_action.is_none(),
+            crate::error::SssError::PendingActionMismatch
+        );
     }
 
     if role_enum == Role::Admin {
-        ctx.accounts.config.admin_count = ctx
-            .accounts
-            .config
-            .admin_count
+        consume_pending_action(
+            &ctx.accounts.config,
+            ctx.accounts.pending_action.as_ref(),
+            &TimelockedAction::GrantAdmin {
+                grantee: ctx.accounts.grantee.key(),
+            },
+            ctx.accounts.admin.key(),
+        )?;
+    }
+
+    let pending_role = &mut ctx.accounts.pending_role;
+    pending_role.config = ctx.accounts.config.key();
+    pending_role.address = ctx.accounts.grantee.key();
+    pending_role.role = role_enum;
+    pending_role.granted_by = ctx.accounts.admin.key();
+    pending_role.granted_at = Clock::get()?.unix_timestamp;
+    pending_role.bump = ctx.bumps.pending_role;
+
+    emit!(RoleGrantPending {
+        config: ctx.accounts.config.key(),
+        address: ctx.accounts.grantee.key(),
+        role,
+        granted_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Accept Role
+#[derive(Accounts)]
+pub struct AcceptRole<'info> {
+    /// The grantee. Pays for its role PDA and directory entry.
+    #[account(mut)]
+    pub grantee: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ crate::error::SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// The grant being accepted. Closed and rent returned to the grantee.
+    #[account(
+        mut,
+        close = grantee,
+        seeds = [
+            PendingRole::SSS_PENDING_ROLE_SEED,
+            config.key().as_ref(),
+            grantee.key().as_ref(),
+            &[pending_role.role.as_u8()],
+        ],
+        bump = pending_role.bump,
+    )]
+    pub pending_role: Account<'info, PendingRole>,
+
+    #[account(
+        init,
+        payer = grantee,
+        space = RoleAccount::ROLE_SPACE,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            grantee.key().as_ref(),
+            &[pending_role.role.as_u8()],
+        ],
+        bump,
+    )]
+    pub role_account: Account<'info, RoleAccount>,
+
+    /// Holder list for the role. Created on the first grant of a role that
+    /// was not set up at `initialize`.
+    #[account(
+        init_if_needed,
+        payer = grantee,
+        space = RoleDirectory::SPACE,
+        seeds = [
+            RoleDirectory::SSS_ROLE_DIRECTORY_SEED,
+            config.key().as_ref(),
+            &[pending_role.role.as_u8()],
+        ],
+        bump,
+    )]
+    pub role_directory: Account<'info, RoleDirectory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Take up a role granted to the signer, creating its `RoleAccount`. Same
+/// pause rule as `grant_role`.
+pub fn handler_accept(ctx: Context<AcceptRole>) -> Result<()> {
+    let pending_role = &ctx.accounts.pending_role;
+    let grantee = ctx.accounts.grantee.key();
+
+    if pending_role.role != Role::Admin {
+        require!(
+            !ctx.accounts.config.is_paused(Clock::get()?.unix_timestamp),
+            crate::error::SssError::Paused
+        );
+    } else {
+        let old_count = ctx.accounts.config.admin_count;
+        ctx.accounts.config.admin_count = old_count
             .checked_add(1)
             .ok_or(error!(crate::error::SssError::ArithmeticOverflow))?;
+        ctx.accounts.config.refresh_digest();
+        emit_config_diff(
+            &ctx.accounts.config,
+            grantee,
+            ConfigField::AdminCount,
+            ConfigValue::U32(old_count),
+            ConfigValue::U32(ctx.accounts.config.admin_count),
+        );
     }
 
     let role_account = &mut ctx.accounts.role_account;
     role_account.config = ctx.accounts.config.key();
-    role_account.address = ctx.accounts.grantee.key();
-    role_account.role = role_enum;
-    role_account.granted_by = ctx.accounts.admin.key();
+    role_account.address = grantee;
+    role_account.role = pending_role.role;
+    role_account.granted_by = pending_role.granted_by;
     role_account.granted_at = Clock::get()?.unix_timestamp;
     role_account.bump = ctx.bumps.role_account;
     role_account.mint_quota = None;
     role_account.amount_minted = 0;
+    role_account.allowed_hours = None;
+    role_account.action_quota = None;
+    role_account.action_window = ActionWindow::default();
+    role_account.expires_at = None;
+    role_account.quota_period_secs = None;
+    role_account.period_start = 0;
+    role_account.burn_quota = None;
+    role_account.amount_burned = 0;
+    role_account.max_per_window = None;
+    role_account.window_secs = 0;
+    role_account.window_start = 0;
+    role_account.window_minted = 0;
+    role_account.prev_window_minted = 0;
+
+    let role_directory = &mut ctx.accounts.role_directory;
+    if role_directory.config == Pubkey::default() {
+        role_directory.config = ctx.accounts.config.key();
+        role_directory.role = pending_role.role;
+        role_directory.bump = ctx.bumps.role_directory;
+    }
+    require!(
+        role_directory.add(grantee),
+        crate::error::SssError::RoleDirectoryFull
+    );
 
     emit!(RoleGranted {
         config: ctx.accounts.config.key(),
-        address: ctx.accounts.grantee.key(),
-        role,
-        granted_by: ctx.accounts.admin.key(),
+        address: grantee,
+        role: pending_role.role.as_u8(),
+        granted_by: pending_role.granted_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Cancel Role Grant
+#[derive(Accounts)]
+pub struct CancelRoleGrant<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Admin's own role PDA — proves admin authorization.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// The unaccepted grant. Closed and rent returned to admin.
+    #[account(
+        mut,
+        close = admin,
+        constraint = pending_role.config == config.key(),
+    )]
+    pub pending_role: Account<'info, PendingRole>,
+}
+
+/// Withdraw a grant the grantee has not accepted.
+pub fn handler_cancel_grant(ctx: Context<CancelRoleGrant>) -> Result<()> {
+    let pending_role = &ctx.accounts.pending_role;
+
+    emit!(RoleGrantCancelled {
+        config: ctx.accounts.config.key(),
+        address: pending_role.address,
+        role: pending_role.role.as_u8(),
+        cancelled_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
     });
 
     Ok(())
@@ -108,6 +306,7 @@ pub struct RevokeRole<'info> {
         mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
         bump = config.bump,
+        constraint = config.is_supported_version() @ crate::error::SssError::UnsupportedConfigVersion,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
@@ -130,6 +329,18 @@ pub struct RevokeRole<'info> {
         constraint = role_account.config == config.key(),
     )]
     pub role_account: Account<'info, RoleAccount>,
+
+    /// Holder list for the revoked role.
+    #[account(
+        mut,
+        seeds = [
+            RoleDirectory::SSS_ROLE_DIRECTORY_SEED,
+            config.key().as_ref(),
+            &[role_account.role.as_u8()],
+        ],
+        bump = role_directory.bump,
+    )]
+    pub role_directory: Account<'info, RoleDirectory>,
 }
 
 pub fn handler_revoke(ctx: Context<RevokeRole>) -> Result<()> {
@@ -138,7 +349,10 @@ pub fn handler_revoke(ctx: Context<RevokeRole>) -> Result<()> {
     // Admin role revocations are exempt from pause for incident response.
     // All other role revocations are blocked while paused.
     if role_account.role != Role::Admin {
-        require!(!ctx.accounts.config.paused, crate::error::SssError::Paused);
+        require!(
+            !ctx.accounts.config.is_paused(Clock::get()?.unix_timestamp),
+            crate::error::SssError::Paused
+        );
     }
 
     // Prevent revoking the last admin — would brick the config permanently.
@@ -153,19 +367,299 @@ pub fn handler_revoke(ctx: Context<RevokeRole>) -> Result<()> {
             ctx.accounts.config.admin_count > 1,
             crate::error::SssError::LastAdmin
         );
-        ctx.accounts.config.admin_count = ctx
-            .accounts
-            .config
-            .admin_count
+        let old_count = ctx.accounts.config.admin_count;
+        ctx.accounts.config.admin_count = old_count
             .checked_sub(1)
             .ok_or(error!(crate::error::SssError::ArithmeticOverflow))?;
+        ctx.accounts.config.refresh_digest();
+        emit_config_diff(
+            &ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            ConfigField::AdminCount,
+            ConfigValue::U32(old_count),
+            ConfigValue::U32(ctx.accounts.config.admin_count),
+        );
     }
 
+    ctx.accounts.role_directory.remove(&role_account.address);
+
     emit!(RoleRevoked {
         config: ctx.accounts.config.key(),
         address: role_account.address,
         role: role_account.role.as_u8(),
         revoked_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Renounce Role
+#[derive(Accounts)]
+pub struct RenounceRole<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ crate::error::SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// The signer's own role PDA. Closed and rent returned to the holder.
+    #[account(
+        mut,
+        close = holder,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            holder.key().as_ref(),
+            &[role_account.role.as_u8()],
+        ],
+        bump = role_account.bump,
+    )]
+    pub role_account: Account<'info, RoleAccount>,
+
+    /// Holder list for the renounced role.
+    #[account(
+        mut,
+        seeds = [
+            RoleDirectory::SSS_ROLE_DIRECTORY_SEED,
+            config.key().as_ref(),
+            &[role_account.role.as_u8()],
+        ],
+        bump = role_directory.bump,
+    )]
+    pub role_directory: Account<'info, RoleDirectory>,
+}
+
+/// Give up the signer's own role, e.g. when an operator leaves. Same pause
+/// and last-admin rules as `revoke_role`; the authority must hand over
+/// authority first, since `accept_authority` closes its Admin role. Emits
+/// `RoleRevoked` with the holder as `revoked_by`.
+pub fn handler_renounce(ctx: Context<RenounceRole>) -> Result<()> {
+    let role_account = &ctx.accounts.role_account;
+    let holder = ctx.accounts.holder.key();
+
+    if role_account.role != Role::Admin {
+        require!(
+            !ctx.accounts.config.is_paused(Clock::get()?.unix_timestamp),
+            crate::error::SssError::Paused
+        );
+    } else {
+        require_keys_neq!(
+            ctx.accounts.config.authority,
+            holder,
+            crate::error::SssError::AuthorityCannotRenounce
+        );
+        require!(
+            ctx.accounts.config.admin_count > 1,
+            crate::error::SssError::LastAdmin
+        );
+        let old_count = ctx.accounts.config.admin_count;
+        ctx.accounts.config.admin_count = old_count
+            .checked_sub(1)
+            .ok_or(error!(crate::error::SssError::ArithmeticOverflow))?;
+        ctx.accounts.config.refresh_digest();
+        emit_config_diff(
+            &ctx.accounts.config,
+            holder,
+            ConfigField::AdminCount,
+            ConfigValue::U32(old_count),
+            ConfigValue::U32(ctx.accounts.config.admin_count),
+        );
+    }
+
+    ctx.accounts.role_directory.remove(&holder);
+
+    emit!(RoleRevoked {
+        config: ctx.accounts.config.key(),
+        address: holder,
+        role: role_account.role.as_u8(),
+        revoked_by: holder,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Sync Role Directory
+#[derive(Accounts)]
+pub struct SyncRoleDirectory<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ crate::error::SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Admin's own role PDA — proves admin authorization.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// An existing role PDA whose holder should be listed.
+    #[account(constraint = role_account.config == config.key())]
+    pub role_account: Account<'info, RoleAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = RoleDirectory::SPACE,
+        seeds = [
+            RoleDirectory::SSS_ROLE_DIRECTORY_SEED,
+            config.key().as_ref(),
+            &[role_account.role.as_u8()],
+        ],
+        bump,
+    )]
+    pub role_directory: Account<'info, RoleDirectory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Backfill a directory entry for a role granted before directories existed.
+/// Idempotent, so it can be run over every `RoleAccount` of a config.
+pub fn handler_sync_directory(ctx: Context<SyncRoleDirectory>) -> Result<()> {
+    let role_account = &ctx.accounts.role_account;
+    let role_directory = &mut ctx.accounts.role_directory;
+    if role_directory.config == Pubkey::default() {
+        role_directory.config = ctx.accounts.config.key();
+        role_directory.role = role_account.role;
+        role_directory.bump = ctx.bumps.role_directory;
+    }
+    require!(
+        role_directory.add(role_account.address),
+        crate::error::SssError::RoleDirectoryFull
+    );
+    Ok(())
+}
+
+// Set Action Quota
+#[derive(Accounts)]
+pub struct SetActionQuota<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ crate::error::SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Admin's own role PDA — proves admin authorization.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// The Freezer, Blacklister or Seizer grant to limit.
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            role_account.address.as_ref(),
+            &[role_account.role.as_u8()],
+        ],
+        bump = role_account.bump,
+        constraint = role_account.role.supports_action_quota()
+            @ crate::error::SssError::ActionQuotaNotSupported,
+    )]
+    pub role_account: Account<'info, RoleAccount>,
+}
+
+/// Cap the key's freezes, seizures or blacklist additions and holds per
+/// rolling day, or lift the cap with `None`. Bounds the damage a stolen
+/// compliance key can do before it is revoked.
+pub fn handler_set_action_quota(ctx: Context<SetActionQuota>, quota: Option<u32>) -> Result<()> {
+    require!(quota != Some(0), crate::error::SssError::ZeroAmount);
+    ctx.accounts.role_account.action_quota = quota;
+
+    emit!(ConfigUpdated {
+        config: ctx.accounts.config.key(),
+        field: "action_quota".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Set Role Expiry
+#[derive(Accounts)]
+pub struct SetRoleExpiry<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ crate::error::SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Admin's own role PDA — proves admin authorization.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// The operator grant to time-box. Admin grants cannot expire, since
+    /// `admin_count` would go on counting an expired admin.
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            role_account.address.as_ref(),
+            &[role_account.role.as_u8()],
+        ],
+        bump = role_account.bump,
+        constraint = role_account.role != Role::Admin
+            @ crate::error::SssError::RoleExpiryNotSupported,
+    )]
+    pub role_account: Account<'info, RoleAccount>,
+}
+
+/// Make the grant lapse at `expires_at`, or remove its expiry with `None`.
+/// An expired grant stays in place, and can be extended, until revoked.
+pub fn handler_set_role_expiry(ctx: Context<SetRoleExpiry>, expires_at: Option<i64>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        expires_at.is_none_or(|expires_at| expires_at > now),
+        crate::error::SssError::InvalidRoleExpiry
+    );
+    ctx.accounts.role_account.expires_at = expires_at;
+
+    emit!(ConfigUpdated {
+        config: ctx.accounts.config.key(),
+        field: "role_expiry".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
     });
 
     Ok(())
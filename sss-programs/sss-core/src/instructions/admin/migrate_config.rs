@@ -0,0 +1,259 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::Mint;
+
+use crate::error::SssError;
+use crate::events::{ConfigMigrated, StandbyConfigMigrated};
+use crate::state::{Role, RoleAccount, StablecoinConfig, StandbyConfig};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// Pays for the bytes the current layout adds.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: A `StablecoinConfig` possibly in an older layout, which no
+    /// longer deserializes as `Account<StablecoinConfig>`. Address and
+    /// ownership are checked here and the discriminator in the handler.
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow the config to the current account layout and stamp it with
+/// `CURRENT_VERSION` and this build's program version, so instructions
+/// accept it again after an upgrade that appended fields. Fields the old
+/// layout lacked start zeroed (`None`, `false`, `0`); see
+/// `StablecoinConfig::reset_fields_after`. Configs from before `version`
+/// existed are migrated from version 0. Rejected for configs written by a
+/// newer or different-major build, whose layout this build cannot know.
+pub fn handler_migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let info = ctx.accounts.config.to_account_info();
+
+    // Appended fields are read past the old end of the account, so decode a
+    // zero-padded copy. `version` and later fields may still decode from
+    // stale bytes the old layout left in its slack; `stored_version` and
+    // `reset_fields_after` discard them.
+    let mut config = {
+        let data = info.try_borrow_data()?;
+        let mut padded = data.to_vec();
+        padded.resize(data.len() + StablecoinConfig::BASE_SIZE, 0);
+        StablecoinConfig::try_deserialize(&mut padded.as_slice())?
+    };
+    require!(
+        crate::version::is_compatible(config.program_version),
+        SssError::UnsupportedConfigVersion
+    );
+
+    let from_version = config.stored_version(info.data_len());
+    let from_program_version = config.program_version;
+    config.reset_fields_after(from_version);
+    config.version = StablecoinConfig::CURRENT_VERSION;
+    config.program_version = crate::version::program_version();
+    config.refresh_digest();
+
+    let new_len = StablecoinConfig::compute_space(&config.name, &config.symbol, &config.uri)
+        .max(info.data_len());
+    grow_account(
+        &ctx.accounts.system_program,
+        &ctx.accounts.admin,
+        &info,
+        new_len,
+    )?;
+
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        config.try_serialize(&mut writer)?;
+        // Clear the slack so the next migration reads zeros past the end.
+        writer.fill(0);
+    }
+
+    emit!(ConfigMigrated {
+        config: info.key(),
+        from_version,
+        to_version: config.version,
+        from_program_version,
+        to_program_version: config.program_version,
+        migrated_by: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Migrate Standby Config
+
+#[derive(Accounts)]
+pub struct MigrateStandbyConfig<'info> {
+    /// Pays for the bytes the current layout adds.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: The primary config. Bound to `standby_config` by its seeds and
+    /// only used as a key, so the standby can be migrated whatever state the
+    /// primary is in.
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// CHECK: A `StandbyConfig` whose image is possibly in an older layout,
+    /// which no longer deserializes as `Account<StandbyConfig>`. Address and
+    /// ownership are checked here and the discriminator in the handler.
+    #[account(
+        mut,
+        seeds = [StandbyConfig::SSS_STANDBY_CONFIG_SEED, config.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub standby_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Bring a standby's image up to the current config layout, as
+/// `migrate_config` does for the primary, so it can be re-synced and failed
+/// over to after an upgrade. The image keeps the `program_version` it was
+/// synced with.
+pub fn handler_migrate_standby_config(ctx: Context<MigrateStandbyConfig>) -> Result<()> {
+    let info = ctx.accounts.standby_config.to_account_info();
+
+    let mut standby = {
+        let data = info.try_borrow_data()?;
+        let mut padded = data.to_vec();
+        padded.resize(data.len() + StablecoinConfig::BASE_SIZE, 0);
+        StandbyConfig::try_deserialize(&mut padded.as_slice())?
+    };
+    let image = &mut standby.image;
+    require!(
+        crate::version::is_compatible(image.program_version),
+        SssError::UnsupportedConfigVersion
+    );
+
+    let from_version = image.stored_version(StandbyConfig::image_space(info.data_len()));
+    image.reset_fields_after(from_version);
+    image.version = StablecoinConfig::CURRENT_VERSION;
+
+    let new_len = StandbyConfig::space(image).max(info.data_len());
+    grow_account(
+        &ctx.accounts.system_program,
+        &ctx.accounts.admin,
+        &info,
+        new_len,
+    )?;
+
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        standby.try_serialize(&mut writer)?;
+        writer.fill(0);
+    }
+
+    emit!(StandbyConfigMigrated {
+        config: standby.primary,
+        standby: info.key(),
+        from_version,
+        to_version: StablecoinConfig::CURRENT_VERSION,
+        migrated_by: ctx.accounts.admin.key(),
+        mint_index: standby.image.mint_index,
+    });
+
+    Ok(())
+}
+
+// Migrate Role
+
+#[derive(Accounts)]
+pub struct MigrateRole<'info> {
+    /// Pays the rent for the extra bytes. Anyone may migrate a role account:
+    /// the grant is unchanged and the fields its layout lacked are written
+    /// as `None` or zero. No role is required, since the Admin's own role
+    /// account does not load until it has been migrated.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: A `RoleAccount` in an earlier layout, which no longer
+    /// deserializes as `Account<RoleAccount>`. Ownership is checked here and
+    /// the discriminator and length in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub role_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a role account created before an upgrade appended `RoleAccount`
+/// fields to `ROLE_SPACE`, so `migrate_config` and every instruction gated
+/// on the role can load it again. Admins migrate their own role first.
+pub fn handler_migrate_role(ctx: Context<MigrateRole>) -> Result<()> {
+    let info = ctx.accounts.role_account.to_account_info();
+
+    let role = RoleAccount::decode_legacy(&info.try_borrow_data()?)
+        .ok_or(SssError::NotLegacyRoleAccount)?;
+    grow_account(
+        &ctx.accounts.system_program,
+        &ctx.accounts.payer,
+        &info,
+        RoleAccount::ROLE_SPACE,
+    )?;
+
+    let mut data = info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    role.try_serialize(&mut writer)?;
+    writer.fill(0);
+
+    Ok(())
+}
+
+/// Resize `info` to `new_len`, with `payer` topping up its rent.
+pub(crate) fn grow_account<'info>(
+    system_program: &Program<'info, System>,
+    payer: &Signer<'info>,
+    info: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    info.resize(new_len)?;
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{MinterKeysConfigured, MinterKeysRemoved};
+use crate::state::{MinterKeys, Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureMinterKeys<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// The Minter role held by the cold key.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            minter_role.address.as_ref(),
+            &[Role::Minter.as_u8()],
+        ],
+        bump = minter_role.bump,
+        constraint = minter_role.role == Role::Minter,
+    )]
+    pub minter_role: Account<'info, RoleAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MinterKeys::SPACE,
+        seeds = [MinterKeys::SSS_MINTER_KEYS_SEED, minter_role.key().as_ref()],
+        bump,
+    )]
+    pub minter_keys: Account<'info, MinterKeys>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Attach (or update) a hot key to a Minter role. Changing the hot key or
+/// window length resets the current window.
+pub fn handler_configure_minter_keys(
+    ctx: Context<ConfigureMinterKeys>,
+    hot_key: Pubkey,
+    hot_window_quota: u64,
+    window_secs: i64,
+) -> Result<()> {
+    let cold_key = ctx.accounts.minter_role.address;
+    require!(
+        hot_key != cold_key && window_secs > 0,
+        SssError::InvalidMinterKeys
+    );
+
+    let keys = &mut ctx.accounts.minter_keys;
+    if keys.hot_key != hot_key || keys.window_secs != window_secs {
+        keys.window_start = 0;
+        keys.window_minted = 0;
+    }
+    keys.config = ctx.accounts.config.key();
+    keys.minter_role = ctx.accounts.minter_role.key();
+    keys.cold_key = cold_key;
+    keys.hot_key = hot_key;
+    keys.hot_window_quota = hot_window_quota;
+    keys.window_secs = window_secs;
+    keys.bump = ctx.bumps.minter_keys;
+
+    emit!(MinterKeysConfigured {
+        config: keys.config,
+        minter_role: keys.minter_role,
+        cold_key,
+        hot_key,
+        hot_window_quota,
+        window_secs,
+        updated_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveMinterKeys<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [MinterKeys::SSS_MINTER_KEYS_SEED, minter_keys.minter_role.as_ref()],
+        bump = minter_keys.bump,
+        constraint = minter_keys.config == config.key() @ SssError::InvalidMinterKeys,
+    )]
+    pub minter_keys: Account<'info, MinterKeys>,
+}
+
+/// Detach the hot key. The cold key keeps its Minter role.
+pub fn handler_remove_minter_keys(ctx: Context<RemoveMinterKeys>) -> Result<()> {
+    emit!(MinterKeysRemoved {
+        config: ctx.accounts.config.key(),
+        minter_role: ctx.accounts.minter_keys.minter_role,
+        hot_key: ctx.accounts.minter_keys.hot_key,
+        removed_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
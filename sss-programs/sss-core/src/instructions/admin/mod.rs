@@ -1,11 +1,47 @@
+pub mod configure_collateral_vault;
+pub mod configure_event_queue;
+pub mod declare_snapshot;
+pub mod distributor;
+pub mod fee_schedule;
+pub mod freeze_exemption;
+pub mod issuer_event;
 pub mod manage_roles;
+pub mod migrate_config;
+pub mod minter_keys;
+pub mod receipts;
+pub mod regulator_access;
+pub mod reserve_journal;
+pub mod standby_config;
+pub mod sunset;
+pub mod swap_route;
+pub mod timelock;
 pub mod transfer_authority;
+pub mod update_burner;
 pub mod update_config;
+pub mod update_metadata;
 pub mod update_minter;
 pub mod update_oracle;
 
+pub use configure_collateral_vault::*;
+pub use configure_event_queue::*;
+pub use declare_snapshot::*;
+pub use distributor::*;
+pub use fee_schedule::*;
+pub use freeze_exemption::*;
+pub use issuer_event::*;
 pub use manage_roles::*;
+pub use migrate_config::*;
+pub use minter_keys::*;
+pub use receipts::*;
+pub use regulator_access::*;
+pub use reserve_journal::*;
+pub use standby_config::*;
+pub use sunset::*;
+pub use swap_route::*;
+pub use timelock::*;
 pub use transfer_authority::*;
+pub use update_burner::*;
 pub use update_config::*;
+pub use update_metadata::*;
 pub use update_minter::*;
 pub use update_oracle::*;
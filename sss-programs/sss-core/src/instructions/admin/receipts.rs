@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{ConfigField, ConfigUpdated, ConfigValue};
+use crate::instructions::admin::update_config::emit_config_diff;
+use crate::state::{MintReceipt, Role, RoleAccount, StablecoinConfig};
+
+// Update Receipt Policy
+#[derive(Accounts)]
+pub struct UpdateReceiptPolicy<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+}
+
+/// Set the minimum amount that requires a receipt (`None` disables the
+/// requirement) and how long receipts must be kept before they can be closed.
+pub fn handler_update_receipt_policy(
+    ctx: Context<UpdateReceiptPolicy>,
+    threshold: Option<u64>,
+    retention_secs: i64,
+) -> Result<()> {
+    require!(retention_secs >= 0, SssError::InvalidReceiptRetention);
+    if let Some(threshold) = threshold {
+        require!(threshold > 0, SssError::ZeroAmount);
+    }
+
+    let config = &mut ctx.accounts.config;
+    let (old_threshold, old_retention) = (config.receipt_threshold, config.receipt_retention_secs);
+    config.receipt_threshold = threshold;
+    config.receipt_retention_secs = retention_secs;
+    config.refresh_digest();
+
+    let admin = ctx.accounts.admin.key();
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::ReceiptThreshold,
+        old_threshold.map_or(ConfigValue::None, ConfigValue::U64),
+        threshold.map_or(ConfigValue::None, ConfigValue::U64),
+    );
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::ReceiptRetentionSecs,
+        ConfigValue::I64(old_retention),
+        ConfigValue::I64(retention_secs),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "receipt_policy".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Close Mint Receipt
+#[derive(Accounts)]
+pub struct CloseMintReceipt<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// Receipt being closed. Rent is returned to the admin.
+    #[account(
+        mut,
+        close = admin,
+        constraint = receipt.config == config.key(),
+    )]
+    pub receipt: Account<'info, MintReceipt>,
+}
+
+pub fn handler_close_mint_receipt(ctx: Context<CloseMintReceipt>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts
+            .receipt
+            .is_expired(ctx.accounts.config.receipt_retention_secs, now),
+        SssError::ReceiptRetentionActive
+    );
+    Ok(())
+}
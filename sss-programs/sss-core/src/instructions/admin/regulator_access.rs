@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{RegulatorAccessGranted, RegulatorAccessRevoked};
+use crate::state::{RegulatorAccess, Role, RoleAccount, StablecoinConfig};
+
+// Grant Regulator Access
+#[derive(Accounts)]
+pub struct GrantRegulatorAccess<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// CHECK: Any key can be registered as a regulator.
+    pub regulator: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = RegulatorAccess::SPACE,
+        seeds = [
+            RegulatorAccess::SSS_REGULATOR_SEED,
+            config.key().as_ref(),
+            regulator.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub regulator_access: Account<'info, RegulatorAccess>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_grant_regulator_access(
+    ctx: Context<GrantRegulatorAccess>,
+    name: String,
+) -> Result<()> {
+    require!(
+        name.len() <= RegulatorAccess::MAX_NAME_LEN,
+        SssError::NameTooLong
+    );
+
+    let access = &mut ctx.accounts.regulator_access;
+    access.config = ctx.accounts.config.key();
+    access.regulator = ctx.accounts.regulator.key();
+    access.name = name;
+    access.granted_by = ctx.accounts.admin.key();
+    access.granted_at = Clock::get()?.unix_timestamp;
+    access.bump = ctx.bumps.regulator_access;
+
+    emit!(RegulatorAccessGranted {
+        config: access.config,
+        regulator: access.regulator,
+        name: access.name.clone(),
+        granted_by: access.granted_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Revoke Regulator Access
+#[derive(Accounts)]
+pub struct RevokeRegulatorAccess<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// Access record being revoked. Existing reports are kept.
+    #[account(
+        mut,
+        close = admin,
+        constraint = regulator_access.config == config.key(),
+    )]
+    pub regulator_access: Account<'info, RegulatorAccess>,
+}
+
+pub fn handler_revoke_regulator_access(ctx: Context<RevokeRegulatorAccess>) -> Result<()> {
+    emit!(RegulatorAccessRevoked {
+        config: ctx.accounts.config.key(),
+        regulator: ctx.accounts.regulator_access.regulator,
+        revoked_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::ReserveMovementRecorded;
+use crate::state::{
+    ReserveJournal, ReserveMovement, ReserveMovementKind, Role, RoleAccount, StablecoinConfig,
+};
+
+#[derive(Accounts)]
+pub struct RecordReserveMovement<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// Created with the first entry.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ReserveJournal::SPACE,
+        seeds = [ReserveJournal::SSS_RESERVE_JOURNAL_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub journal: Account<'info, ReserveJournal>,
+
+    /// The next entry. Keyed by `journal.entry_count`, so entries can only be
+    /// appended in order and an index is never reused.
+    #[account(
+        init,
+        payer = admin,
+        space = ReserveMovement::SPACE,
+        seeds = [
+            ReserveMovement::SSS_RESERVE_MOVEMENT_SEED,
+            config.key().as_ref(),
+            &journal.entry_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub movement: Account<'info, ReserveMovement>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Append an off-chain reserve flow to the config's journal. `ref_hash`
+/// identifies the custodian record an auditor reconciles it against.
+pub fn handler_record_reserve_movement(
+    ctx: Context<RecordReserveMovement>,
+    kind: ReserveMovementKind,
+    amount: u64,
+    ref_hash: [u8; 32],
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+
+    let config = ctx.accounts.config.key();
+    let journal = &mut ctx.accounts.journal;
+    journal.config = config;
+    journal.bump = ctx.bumps.journal;
+    let index = journal
+        .record(kind, amount)
+        .ok_or(error!(SssError::ArithmeticOverflow))?;
+
+    let movement = &mut ctx.accounts.movement;
+    movement.config = config;
+    movement.index = index;
+    movement.kind = kind;
+    movement.amount = amount;
+    movement.ref_hash = ref_hash;
+    movement.recorded_by = ctx.accounts.admin.key();
+    movement.recorded_at = Clock::get()?.unix_timestamp;
+    movement.bump = ctx.bumps.movement;
+
+    emit!(ReserveMovementRecorded {
+        config,
+        index,
+        kind,
+        amount,
+        ref_hash,
+        recorded_by: movement.recorded_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::pause;
+use crate::error::SssError;
+use crate::events::{FailoverCancelled, FailoverExecuted, FailoverRequested, StandbyConfigSynced};
+use crate::instructions::migrate_config::grow_account;
+use crate::state::{Role, RoleAccount, StablecoinConfig, StandbyConfig};
+
+// Create Standby Config
+
+#[derive(Accounts)]
+pub struct CreateStandbyConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = StandbyConfig::space(&config),
+        seeds = [StandbyConfig::SSS_STANDBY_CONFIG_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub standby_config: Account<'info, StandbyConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Copy the live config into the standby, creating it on first use. Call
+/// again after parameter changes to keep the standby warm. Refused while a
+/// failover is pending, so the image Admins review during the delay is the
+/// one restored. The standby grows if the config's strings have since grown.
+pub fn handler_create_standby_config(ctx: Context<CreateStandbyConfig>) -> Result<()> {
+    require!(
+        !ctx.accounts.standby_config.failover_pending(),
+        SssError::FailoverPending
+    );
+
+    let config = &ctx.accounts.config;
+    let space = StandbyConfig::space(config);
+    let info = ctx.accounts.standby_config.to_account_info();
+    if info.data_len() < space {
+        grow_account(
+            &ctx.accounts.system_program,
+            &ctx.accounts.admin,
+            &info,
+            space,
+        )?;
+    }
+
+    let standby = &mut ctx.accounts.standby_config;
+    standby.primary = config.key();
+    standby.synced_at = Clock::get()?.unix_timestamp;
+    standby.synced_by = ctx.accounts.admin.key();
+    standby.bump = ctx.bumps.standby_config;
+    standby.image = (**config).clone();
+    standby.image.pause_flags = pause::ALL;
+
+    emit!(StandbyConfigSynced {
+        config: standby.primary,
+        standby: standby.key(),
+        state_nonce: config.state_nonce,
+        synced_by: standby.synced_by,
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Request / Cancel Failover
+
+/// Accounts for `request_failover` and `cancel_failover`. The primary is not
+/// deserialized, so both work when it no longer loads.
+#[derive(Accounts)]
+pub struct ManageFailover<'info> {
+    pub admin: Signer<'info>,
+
+    /// CHECK: The primary config. Bound to `standby_config` by its seeds and
+    /// only used as a key.
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        seeds = [StandbyConfig::SSS_STANDBY_CONFIG_SEED, config.key().as_ref()],
+        bump = standby_config.bump,
+    )]
+    pub standby_config: Account<'info, StandbyConfig>,
+}
+
+/// Start the failover delay. Any Admin can cancel until it elapses.
+pub fn handler_request_failover(ctx: Context<ManageFailover>) -> Result<()> {
+    let live = load_primary(&ctx.accounts.config)?;
+    let standby = &mut ctx.accounts.standby_config;
+    require!(!standby.failover_pending(), SssError::FailoverPending);
+
+    standby.failover_requested_at = Clock::get()?.unix_timestamp;
+    standby.failover_requested_by = ctx.accounts.admin.key();
+
+    emit!(FailoverRequested {
+        config: standby.primary,
+        standby: standby.key(),
+        requested_by: standby.failover_requested_by,
+        executable_at: standby.failover_requested_at + standby.failover_delay_secs(live.as_ref()),
+        mint_index: standby.image.mint_index,
+    });
+
+    Ok(())
+}
+
+pub fn handler_cancel_failover(ctx: Context<ManageFailover>) -> Result<()> {
+    let standby = &mut ctx.accounts.standby_config;
+    require!(standby.failover_pending(), SssError::NoFailoverPending);
+
+    standby.failover_requested_at = 0;
+    standby.failover_requested_by = Pubkey::default();
+
+    emit!(FailoverCancelled {
+        config: standby.primary,
+        standby: standby.key(),
+        cancelled_by: ctx.accounts.admin.key(),
+        mint_index: standby.image.mint_index,
+    });
+
+    Ok(())
+}
+
+// Failover
+
+#[derive(Accounts)]
+pub struct Failover<'info> {
+    /// Pays for growing the primary if the image no longer fits it.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: The primary config, overwritten with the standby image. Bound
+    /// to `standby_config` by its seeds; deliberately not deserialized, since
+    /// a corrupted or mis-migrated config is what failover recovers from.
+    #[account(mut, owner = crate::ID)]
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        seeds = [StandbyConfig::SSS_STANDBY_CONFIG_SEED, config.key().as_ref()],
+        bump = standby_config.bump,
+        constraint = standby_config.image.is_supported_version()
+            @ SssError::UnsupportedConfigVersion,
+    )]
+    pub standby_config: Account<'info, StandbyConfig>,
+
+    #[account(address = standby_config.image.mint @ SssError::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the primary config with the standby image once the delay has
+/// elapsed. The restored config is paused, with its supply counters
+/// reconciled to the mint; Admins check it and unpause. If the primary still
+/// loads, its digest chain is continued rather than rewound, and its record
+/// counters, locks, sunset and timelocked settings are kept (see
+/// `StandbyConfig::restored_config`). A standby synced before a layout
+/// upgrade must first go through `migrate_standby_config`.
+pub fn handler_failover(ctx: Context<Failover>) -> Result<()> {
+    let clock = Clock::get()?;
+    let live = load_primary(&ctx.accounts.config)?;
+    let standby = &mut ctx.accounts.standby_config;
+    require!(standby.failover_pending(), SssError::NoFailoverPending);
+    require!(
+        standby.failover_ready(clock.unix_timestamp, live.as_ref()),
+        SssError::FailoverTimelockActive
+    );
+
+    let config_info = ctx.accounts.config.to_account_info();
+    // The primary may have shrunk since the sync, e.g. a shorter name.
+    let space = StablecoinConfig::compute_space(
+        &standby.image.name,
+        &standby.image.symbol,
+        &standby.image.uri,
+    );
+    if config_info.data_len() < space {
+        grow_account(
+            &ctx.accounts.system_program,
+            &ctx.accounts.admin,
+            &config_info,
+            space,
+        )?;
+    }
+
+    let mut restored = standby.restored_config(ctx.accounts.mint.supply, live.as_ref());
+    restored.paused_at_slot = clock.slot;
+    if let Some(current) = live {
+        restored.state_digest = current.state_digest;
+        restored.state_nonce = current.state_nonce;
+    }
+    restored.refresh_digest();
+    {
+        let mut data = config_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        restored.try_serialize(&mut writer)?;
+    }
+
+    standby.failover_requested_at = 0;
+    standby.failover_requested_by = Pubkey::default();
+
+    emit!(FailoverExecuted {
+        config: standby.primary,
+        standby: standby.key(),
+        synced_at: standby.synced_at,
+        new_supply: restored.current_supply(),
+        executed_by: ctx.accounts.admin.key(),
+        mint_index: restored.mint_index,
+    });
+
+    Ok(())
+}
+
+/// The primary config, or `None` if it no longer loads.
+fn load_primary(config: &UncheckedAccount) -> Result<Option<StablecoinConfig>> {
+    let data = config.try_borrow_data()?;
+    Ok(StablecoinConfig::try_deserialize(&mut &data[..]).ok())
+}
@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    metadata_pointer::MetadataPointer, permanent_delegate::PermanentDelegate,
+    transfer_hook::TransferHook,
+};
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{
+    get_mint_extension_data, set_authority, Mint, SetAuthority, TokenInterface,
+};
+
+use crate::constants::capability;
+use crate::error::SssError;
+use crate::events::{ConfigField, ConfigValue, StablecoinClosed, StablecoinSunset};
+use crate::instructions::admin::timelock::consume_pending_action;
+use crate::instructions::admin::update_config::emit_config_diff;
+use crate::state::{
+    PendingAction, Role, RoleAccount, RoleDirectory, StablecoinConfig, TimelockedAction,
+};
+
+// Sunset
+
+#[derive(Accounts)]
+pub struct Sunset<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = config.sunset_at.is_none() @ SssError::AlreadySunset,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// Required while the admin timelock is enabled. Closed on success.
+    #[account(mut, close = admin)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+/// Begin winding the stablecoin down: disable and lock the mint capability,
+/// so no path can issue again, and record `sunset_at`. Burns, redemptions,
+/// freezes and seizures keep working until `close_config`.
+pub fn handler_sunset(ctx: Context<Sunset>) -> Result<()> {
+    consume_pending_action(
+        &ctx.accounts.config,
+        ctx.accounts.pending_action.as_ref(),
+        &TimelockedAction::Sunset,
+        ctx.accounts.admin.key(),
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    let (old_disabled, old_locked) = (config.disabled_instructions, config.locked_instructions);
+    let (disabled, locked) = config
+        .updated_capabilities(old_disabled | capability::MINT, capability::MINT)
+        .ok_or(SssError::CapabilityLocked)?;
+    let now = Clock::get()?.unix_timestamp;
+    config.disabled_instructions = disabled;
+    config.locked_instructions = locked;
+    config.sunset_at = Some(now);
+    config.refresh_digest();
+
+    let admin = ctx.accounts.admin.key();
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::DisabledInstructions,
+        ConfigValue::U32(old_disabled),
+        ConfigValue::U32(disabled),
+    );
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::LockedInstructions,
+        ConfigValue::U32(old_locked),
+        ConfigValue::U32(locked),
+    );
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::SunsetAt,
+        ConfigValue::None,
+        ConfigValue::I64(now),
+    );
+    emit!(StablecoinSunset {
+        mint: config.mint,
+        sunset_by: admin,
+        supply: config.current_supply(),
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Close Config
+
+#[derive(Accounts)]
+pub struct CloseConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = config.sunset_at.is_some() @ SssError::NotSunset,
+        constraint = config.admin_count == 1 @ SssError::AdminsRemain,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            RoleDirectory::SSS_ROLE_DIRECTORY_SEED,
+            config.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_directory.bump,
+    )]
+    pub admin_directory: Account<'info, RoleDirectory>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// End of life for a sunset stablecoin with nothing left outstanding. Every
+/// mint authority the config PDA still holds (mint, freeze, permanent
+/// delegate, transfer hook and metadata pointer) is set to none, so a config
+/// re-created for the mint could never control it, and the config, the last
+/// Admin's role and the Admin directory are closed to that Admin. Other
+/// Admins must be revoked first; other roles' accounts are left behind.
+pub fn handler_close_config(ctx: Context<CloseConfig>) -> Result<()> {
+    let mint = &ctx.accounts.mint;
+    require!(mint.supply == 0, SssError::SupplyNotZero);
+
+    let config_key = ctx.accounts.config.key();
+    let mint_info = mint.to_account_info();
+    let held = |authority: Option<Pubkey>| authority == Some(config_key);
+    let mut revoke = Vec::with_capacity(5);
+    if held(mint.mint_authority.into()) {
+        revoke.push(AuthorityType::MintTokens);
+    }
+    if held(mint.freeze_authority.into()) {
+        revoke.push(AuthorityType::FreezeAccount);
+    }
+    if let Ok(ext) = get_mint_extension_data::<PermanentDelegate>(&mint_info) {
+        if held(ext.delegate.into()) {
+            revoke.push(AuthorityType::PermanentDelegate);
+        }
+    }
+    if let Ok(ext) = get_mint_extension_data::<TransferHook>(&mint_info) {
+        if held(ext.authority.into()) {
+            revoke.push(AuthorityType::TransferHookProgramId);
+        }
+    }
+    if let Ok(ext) = get_mint_extension_data::<MetadataPointer>(&mint_info) {
+        if held(ext.authority.into()) {
+            revoke.push(AuthorityType::MetadataPointer);
+        }
+    }
+
+    let mint_key = mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        StablecoinConfig::SSS_CONFIG_SEED,
+        mint_key.as_ref(),
+        &[ctx.accounts.config.bump],
+    ]];
+    for authority_type in revoke {
+        set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: ctx.accounts.config.to_account_info(),
+                    account_or_mint: mint_info.clone(),
+                },
+                signer_seeds,
+            ),
+            authority_type,
+            None,
+        )?;
+    }
+
+    emit!(StablecoinClosed {
+        mint: mint_key,
+        closed_by: ctx.accounts.admin.key(),
+        config: config_key,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
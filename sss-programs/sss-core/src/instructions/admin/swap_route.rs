@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{SwapRouteClosed, SwapRouteCreated};
+use crate::state::{Role, RoleAccount, StablecoinConfig, SwapRoute};
+
+#[derive(Accounts)]
+pub struct CreateSwapRoute<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, from_config.mint.as_ref()],
+        bump = from_config.bump,
+        constraint = from_config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub from_config: Box<Account<'info, StablecoinConfig>>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            from_config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = from_admin_role.bump,
+    )]
+    pub from_admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, to_config.mint.as_ref()],
+        bump = to_config.bump,
+        constraint = to_config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub to_config: Box<Account<'info, StablecoinConfig>>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            to_config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = to_admin_role.bump,
+    )]
+    pub to_admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = SwapRoute::SPACE,
+        seeds = [
+            SwapRoute::SSS_SWAP_ROUTE_SEED,
+            from_config.key().as_ref(),
+            to_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub swap_route: Account<'info, SwapRoute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a one-directional par swap route. Both stablecoins must use the same
+/// decimals so that 1 base unit swaps for exactly 1 base unit.
+pub fn handler_create_swap_route(ctx: Context<CreateSwapRoute>) -> Result<()> {
+    let from_config = &ctx.accounts.from_config;
+    let to_config = &ctx.accounts.to_config;
+    require_keys_neq!(
+        from_config.key(),
+        to_config.key(),
+        SssError::InvalidSwapRoute
+    );
+    require!(
+        from_config.decimals == to_config.decimals,
+        SssError::InvalidSwapRoute
+    );
+
+    let route = &mut ctx.accounts.swap_route;
+    route.from_config = from_config.key();
+    route.to_config = to_config.key();
+    route.from_mint = from_config.mint;
+    route.to_mint = to_config.mint;
+    route.created_by = ctx.accounts.admin.key();
+    route.created_at = Clock::get()?.unix_timestamp;
+    route.bump = ctx.bumps.swap_route;
+
+    emit!(SwapRouteCreated {
+        from_config: route.from_config,
+        to_config: route.to_config,
+        created_by: route.created_by,
+        from_mint_index: from_config.mint_index,
+        to_mint_index: to_config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseSwapRoute<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Either side of the route; its Admin may close it.
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = admin,
+        constraint = swap_route.from_config == config.key()
+            || swap_route.to_config == config.key() @ SssError::InvalidSwapRoute,
+    )]
+    pub swap_route: Account<'info, SwapRoute>,
+}
+
+pub fn handler_close_swap_route(ctx: Context<CloseSwapRoute>) -> Result<()> {
+    emit!(SwapRouteClosed {
+        from_config: ctx.accounts.swap_route.from_config,
+        to_config: ctx.accounts.swap_route.to_config,
+        closed_by: ctx.accounts.admin.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+    Ok(())
+}
@@ -0,0 +1,256 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{
+    ConfigField, ConfigUpdated, ConfigValue, PendingActionCancelled, PendingActionExecuted,
+    PendingActionQueued,
+};
+use crate::instructions::admin::update_config::emit_config_diff;
+use crate::state::{PendingAction, Role, RoleAccount, StablecoinConfig, TimelockedAction};
+
+/// Gate for the timelocked admin instructions, which take `pending_action`
+/// as an optional account closed on success. While the config's timelock is
+/// enabled the account must be present, queued for this config and
+/// `action`, and ready; it is checked whenever it is passed, so a pending
+/// action cannot be closed by an instruction it was not queued for.
+pub(crate) fn consume_pending_action(
+    config: &Account<StablecoinConfig>,
+    pending_action: Option<&Account<PendingAction>>,
+    action: &TimelockedAction,
+    executed_by: Pubkey,
+) -> Result<()> {
+    let Some(pending) = pending_action else {
+        require!(config.admin_timelock_secs == 0, SssError::TimelockRequired);
+        return Ok(());
+    };
+    require!(
+        pending.config == config.key() && pending.action == *action,
+        SssError::PendingActionMismatch
+    );
+    require!(
+        pending.is_ready(Clock::get()?.unix_timestamp, config.admin_timelock_secs),
+        SssError::TimelockNotElapsed
+    );
+
+    emit!(PendingActionExecuted {
+        config: config.key(),
+        id: pending.id,
+        action: action.clone(),
+        executed_by,
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Queue Action
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct QueueAction<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = PendingAction::SPACE,
+        seeds = [
+            PendingAction::SSS_PENDING_ACTION_SEED,
+            config.key().as_ref(),
+            &id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue `action` to become executable after the config's admin timelock.
+/// `propose_authority` may only be queued by the current authority, which
+/// is also the only key that can execute it.
+pub fn handler_queue_action(
+    ctx: Context<QueueAction>,
+    id: u64,
+    action: TimelockedAction,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(config.admin_timelock_secs > 0, SssError::TimelockDisabled);
+    match action {
+        TimelockedAction::ProposeAuthority { .. } => {
+            require_keys_eq!(
+                config.authority,
+                ctx.accounts.admin.key(),
+                SssError::Unauthorized
+            );
+        }
+        TimelockedAction::SetAdminTimelock { delay_secs } => {
+            require!(
+                delay_secs <= PendingAction::MAX_DELAY_SECS,
+                SssError::InvalidTimelockDelay
+            );
+        }
+        TimelockedAction::UpdateSupplyCap { .. }
+        | TimelockedAction::GrantAdmin { .. }
+        | TimelockedAction::Sunset => {}
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let pending = &mut ctx.accounts.pending_action;
+    pending.config = config.key();
+    pending.id = id;
+    pending.action = action;
+    pending.queued_by = ctx.accounts.admin.key();
+    pending.queued_at = now;
+    pending.executable_at = now + i64::from(config.admin_timelock_secs);
+    pending.bump = ctx.bumps.pending_action;
+
+    emit!(PendingActionQueued {
+        config: pending.config,
+        id,
+        action: pending.action.clone(),
+        queued_by: pending.queued_by,
+        executable_at: pending.executable_at,
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Cancel Action
+
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = admin,
+        has_one = config @ SssError::PendingActionMismatch,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+}
+
+/// Drop a queued action. Any Admin can cancel, so one that disagrees with
+/// a queued change can stop it during the delay.
+pub fn handler_cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+    let pending = &ctx.accounts.pending_action;
+
+    emit!(PendingActionCancelled {
+        config: pending.config,
+        id: pending.id,
+        action: pending.action.clone(),
+        cancelled_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Set Admin Timelock
+
+#[derive(Accounts)]
+pub struct SetAdminTimelock<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// Required while the timelock is enabled: a ready `SetAdminTimelock`
+    /// with the same `delay_secs`. Closed on success.
+    #[account(mut, close = admin)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+/// Set `admin_timelock_secs`; zero disables the timelock. Enabling it takes
+/// effect at once, but once enabled, every change to it (including turning
+/// it off) waits out the current delay.
+pub fn handler_set_admin_timelock(ctx: Context<SetAdminTimelock>, delay_secs: u32) -> Result<()> {
+    require!(
+        delay_secs <= PendingAction::MAX_DELAY_SECS,
+        SssError::InvalidTimelockDelay
+    );
+    let admin = ctx.accounts.admin.key();
+    consume_pending_action(
+        &ctx.accounts.config,
+        ctx.accounts.pending_action.as_ref(),
+        &TimelockedAction::SetAdminTimelock { delay_secs },
+        admin,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    let old_delay = config.admin_timelock_secs;
+    config.admin_timelock_secs = delay_secs;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::AdminTimelockSecs,
+        ConfigValue::U32(old_delay),
+        ConfigValue::U32(delay_secs),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "admin_timelock_secs".to_string(),
+        updater: admin,
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
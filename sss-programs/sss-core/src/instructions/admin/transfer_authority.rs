@@ -1,12 +1,18 @@
 use anchor_lang::prelude::*;
 
-use crate::events::AuthorityTransferred;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::error::SssError;
+use crate::events::{AuthorityProposed, AuthorityTransferred, ConfigField, ConfigValue};
+use crate::instructions::admin::timelock::consume_pending_action;
+use crate::instructions::admin::update_config::emit_config_diff;
+use crate::state::{
+    ActionWindow, PendingAction, Role, RoleAccount, RoleDirectory, StablecoinConfig,
+    TimelockedAction,
+};
 
-// Transfer Authority / Update Admin
+// Propose Authority
 
 #[derive(Accounts)]
-pub struct TransferAuthority<'info> {
+pub struct ProposeAuthority<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
@@ -14,13 +20,12 @@ pub struct TransferAuthority<'info> {
         mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
         bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = config.authority == admin.key() @ SssError::Unauthorized,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
-    /// The caller's admin role PDA — will be closed.
     #[account(
-        mut,
-        close = admin,
         seeds = [
             RoleAccount::SSS_ROLE_SEED,
             config.key().as_ref(),
@@ -31,13 +36,88 @@ pub struct TransferAuthority<'info> {
     )]
     pub admin_role: Account<'info, RoleAccount>,
 
-    /// CHECK: The new authority address receiving admin role.
+    /// CHECK: The proposed authority; it signs `accept_authority`.
     pub new_authority: UncheckedAccount<'info>,
 
+    /// Required while the admin timelock is enabled. Closed on success.
+    #[account(mut, close = admin)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+/// Record `new_authority` as the pending authority. A later proposal
+/// replaces an earlier one, so a mistyped key is fixed by proposing again.
+pub fn handler_propose_authority(ctx: Context<ProposeAuthority>) -> Result<()> {
+    let new_authority = ctx.accounts.new_authority.key();
+    consume_pending_action(
+        &ctx.accounts.config,
+        ctx.accounts.pending_action.as_ref(),
+        &TimelockedAction::ProposeAuthority { new_authority },
+        ctx.accounts.admin.key(),
+    )?;
+
+    let old_pending = ctx.accounts.config.pending_authority;
+    ctx.accounts.config.pending_authority = Some(new_authority);
+    ctx.accounts.config.refresh_digest();
+
+    emit_config_diff(
+        &ctx.accounts.config,
+        ctx.accounts.admin.key(),
+        ConfigField::PendingAuthority,
+        old_pending.map_or(ConfigValue::None, ConfigValue::Pubkey),
+        ConfigValue::Pubkey(new_authority),
+    );
+
+    emit!(AuthorityProposed {
+        config: ctx.accounts.config.key(),
+        from: ctx.accounts.admin.key(),
+        to: new_authority,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Accept Authority
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// The pending authority. Pays for its admin role PDA.
+    #[account(mut)]
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = config.pending_authority == Some(new_authority.key())
+            @ SssError::NotPendingAuthority,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// CHECK: The current `config.authority`; receives the rent of its
+    /// closed admin role.
+    #[account(mut, address = config.authority)]
+    pub old_authority: UncheckedAccount<'info>,
+
+    /// The current authority's admin role PDA — will be closed.
+    #[account(
+        mut,
+        close = old_authority,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            old_authority.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = old_admin_role.bump,
+    )]
+    pub old_admin_role: Account<'info, RoleAccount>,
+
     /// The new authority's admin role PDA — will be created.
     #[account(
         init,
-        payer = admin,
+        payer = new_authority,
         space = RoleAccount::ROLE_SPACE,
         seeds = [
             RoleAccount::SSS_ROLE_SEED,
@@ -49,27 +129,83 @@ pub struct TransferAuthority<'info> {
     )]
     pub new_admin_role: Account<'info, RoleAccount>,
 
+    /// Admin holder list — the old authority is swapped for the new one.
+    #[account(
+        mut,
+        seeds = [
+            RoleDirectory::SSS_ROLE_DIRECTORY_SEED,
+            config.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_directory.bump,
+    )]
+    pub admin_directory: Account<'info, RoleDirectory>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler_transfer_authority(ctx: Context<TransferAuthority>) -> Result<()> {
+/// Complete the transfer: the old authority's admin role is closed and one
+/// is created for the pending authority, which signs, so admin rights can
+/// only move to a key somebody controls.
+pub fn handler_accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let old_authority = ctx.accounts.old_authority.key();
+    let new_authority = ctx.accounts.new_authority.key();
+
     let new_role = &mut ctx.accounts.new_admin_role;
     new_role.config = ctx.accounts.config.key();
-    new_role.address = ctx.accounts.new_authority.key();
+    new_role.address = new_authority;
     new_role.role = Role::Admin;
-    new_role.granted_by = ctx.accounts.admin.key();
+    new_role.granted_by = old_authority;
     new_role.granted_at = Clock::get()?.unix_timestamp;
     new_role.bump = ctx.bumps.new_admin_role;
     new_role.mint_quota = None;
     new_role.amount_minted = 0;
+    new_role.allowed_hours = None;
+    new_role.action_quota = None;
+    new_role.action_window = ActionWindow::default();
+    new_role.expires_at = None;
+    new_role.quota_period_secs = None;
+    new_role.period_start = 0;
+    new_role.burn_quota = None;
+    new_role.amount_burned = 0;
+    new_role.max_per_window = None;
+    new_role.window_secs = 0;
+    new_role.window_start = 0;
+    new_role.window_minted = 0;
+    new_role.prev_window_minted = 0;
+
+    let admin_directory = &mut ctx.accounts.admin_directory;
+    admin_directory.remove(&old_authority);
+    require!(
+        admin_directory.add(new_authority),
+        SssError::RoleDirectoryFull
+    );
 
     // Update config.authority so on-chain queries reflect the new admin
-    ctx.accounts.config.authority = ctx.accounts.new_authority.key();
+    ctx.accounts.config.authority = new_authority;
+    ctx.accounts.config.pending_authority = None;
+    ctx.accounts.config.refresh_digest();
+
+    emit_config_diff(
+        &ctx.accounts.config,
+        new_authority,
+        ConfigField::Authority,
+        ConfigValue::Pubkey(old_authority),
+        ConfigValue::Pubkey(new_authority),
+    );
+    emit_config_diff(
+        &ctx.accounts.config,
+        new_authority,
+        ConfigField::PendingAuthority,
+        ConfigValue::Pubkey(new_authority),
+        ConfigValue::None,
+    );
 
     emit!(AuthorityTransferred {
         config: ctx.accounts.config.key(),
-        from: ctx.accounts.admin.key(),
-        to: ctx.accounts.new_authority.key(),
+        from: old_authority,
+        to: new_authority,
+        mint_index: ctx.accounts.config.mint_index,
     });
 
     Ok(())
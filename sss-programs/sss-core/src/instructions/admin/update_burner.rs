@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::ConfigUpdated;
+use crate::state::{Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+pub struct UpdateBurner<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Admin or QuotaManager role PDA of `admin`.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[admin_role.role.as_u8()],
+        ],
+        bump = admin_role.bump,
+        constraint = matches!(admin_role.role, Role::Admin | Role::QuotaManager) @ SssError::Unauthorized,
+        constraint = admin_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// The burner's role account to update. Must be a Burner role.
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            burner_role.address.as_ref(),
+            &[Role::Burner.as_u8()],
+        ],
+        bump = burner_role.bump,
+    )]
+    pub burner_role: Account<'info, RoleAccount>,
+}
+
+/// Cap what the burner may burn over the life of its grant, or lift the cap
+/// with `None`. Applies to `burn_tokens` and its idempotent and batch
+/// variants.
+pub fn handler_update_burner(ctx: Context<UpdateBurner>, new_quota: Option<u64>) -> Result<()> {
+    ctx.accounts.burner_role.burn_quota = new_quota;
+
+    emit!(ConfigUpdated {
+        config: ctx.accounts.config.key(),
+        field: "burner_quota".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
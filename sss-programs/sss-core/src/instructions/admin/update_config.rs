@@ -1,17 +1,24 @@
 use anchor_lang::prelude::*;
 
 use crate::error::SssError;
-use crate::events::ConfigUpdated;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::events::{
+    CapabilitiesUpdated, ConfigField, ConfigFieldChanged, ConfigUpdated, ConfigValue,
+};
+use crate::instructions::admin::timelock::consume_pending_action;
+use crate::state::{
+    MintApproval, PendingAction, Role, RoleAccount, StablecoinConfig, ThawRamp, TimelockedAction,
+};
 
 #[derive(Accounts)]
 pub struct UpdateSupplyCap<'info> {
+    #[account(mut)]
     pub admin: Signer<'info>,
 
     #[account(
         mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
         bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
@@ -25,24 +32,597 @@ pub struct UpdateSupplyCap<'info> {
         bump = admin_role.bump,
     )]
     pub admin_role: Account<'info, RoleAccount>,
+
+    /// Required while the admin timelock is enabled. Closed on success.
+    #[account(mut, close = admin)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+/// Emit `ConfigFieldChanged` for `field` unless `old == new`. Shared by
+/// every admin instruction that writes config fields.
+pub(crate) fn emit_config_diff(
+    config: &Account<StablecoinConfig>,
+    updater: Pubkey,
+    field: ConfigField,
+    old: ConfigValue,
+    new: ConfigValue,
+) {
+    if old != new {
+        emit!(ConfigFieldChanged {
+            config: config.key(),
+            field,
+            old,
+            new,
+            updater,
+            mint_index: config.mint_index,
+        });
+    }
 }
 
 pub fn handler_update_supply_cap(
     ctx: Context<UpdateSupplyCap>,
     new_supply_cap: Option<u64>,
 ) -> Result<()> {
+    consume_pending_action(
+        &ctx.accounts.config,
+        ctx.accounts.pending_action.as_ref(),
+        &TimelockedAction::UpdateSupplyCap { new_supply_cap },
+        ctx.accounts.admin.key(),
+    )?;
+
     let config = &mut ctx.accounts.config;
 
     if let Some(cap) = new_supply_cap {
         require!(cap >= config.current_supply(), SssError::InvalidSupplyCap);
     }
 
+    let old_cap = config.supply_cap;
     config.supply_cap = new_supply_cap;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::SupplyCap,
+        old_cap.map_or(ConfigValue::None, ConfigValue::U64),
+        new_supply_cap.map_or(ConfigValue::None, ConfigValue::U64),
+    );
 
     emit!(ConfigUpdated {
         config: config.key(),
         field: "supply_cap".to_string(),
         updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCapabilities<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+}
+
+/// Replace the disabled-capability mask and permanently lock some of the
+/// disabled bits. See `constants::capability`.
+pub fn handler_update_capabilities(
+    ctx: Context<UpdateCapabilities>,
+    disabled: u32,
+    lock: u32,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let (disabled, locked) = config
+        .updated_capabilities(disabled, lock)
+        .ok_or(SssError::CapabilityLocked)?;
+    let (old_disabled, old_locked) = (config.disabled_instructions, config.locked_instructions);
+    config.disabled_instructions = disabled;
+    config.locked_instructions = locked;
+    config.refresh_digest();
+
+    let admin = ctx.accounts.admin.key();
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::DisabledInstructions,
+        ConfigValue::U32(old_disabled),
+        ConfigValue::U32(disabled),
+    );
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::LockedInstructions,
+        ConfigValue::U32(old_locked),
+        ConfigValue::U32(locked),
+    );
+
+    emit!(CapabilitiesUpdated {
+        config: config.key(),
+        disabled,
+        locked,
+        updated_by: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateThawRamp<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+}
+
+/// Set or clear the post-pause thaw ramp. A running ramp picks up the new
+/// parameters; clearing the ramp ends it immediately.
+pub fn handler_update_thaw_ramp(
+    ctx: Context<UpdateThawRamp>,
+    thaw_ramp: Option<ThawRamp>,
+) -> Result<()> {
+    if let Some(ramp) = thaw_ramp {
+        require!(ramp.is_valid(), SssError::InvalidThawRamp);
+    }
+
+    let config = &mut ctx.accounts.config;
+    let (old_ramp, old_started_at) = (config.thaw_ramp, config.ramp_started_at);
+    config.thaw_ramp = thaw_ramp;
+    if thaw_ramp.is_none() {
+        config.ramp_started_at = None;
+    }
+    config.refresh_digest();
+
+    let admin = ctx.accounts.admin.key();
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::ThawRamp,
+        old_ramp.map_or(ConfigValue::None, ConfigValue::ThawRamp),
+        thaw_ramp.map_or(ConfigValue::None, ConfigValue::ThawRamp),
+    );
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::RampStartedAt,
+        old_started_at.map_or(ConfigValue::None, ConfigValue::U64),
+        config
+            .ramp_started_at
+            .map_or(ConfigValue::None, ConfigValue::U64),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "thaw_ramp".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateDestinationMintLimit<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+}
+
+/// Set or clear the per-destination daily mint limit. Windows already
+/// recorded on `DestinationThrottle`s are kept and checked against the new
+/// limit.
+pub fn handler_update_destination_mint_limit(
+    ctx: Context<UpdateDestinationMintLimit>,
+    limit: Option<u64>,
+) -> Result<()> {
+    require!(limit != Some(0), SssError::ZeroAmount);
+
+    let config = &mut ctx.accounts.config;
+    let old_limit = config.destination_mint_limit;
+    config.destination_mint_limit = limit;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::DestinationMintLimit,
+        old_limit.map_or(ConfigValue::None, ConfigValue::U64),
+        limit.map_or(ConfigValue::None, ConfigValue::U64),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "destination_mint_limit".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateSupplyCheck<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+}
+
+/// Choose whether `verify_supply` disables minting when it finds tokens
+/// the counters do not account for.
+pub fn handler_update_supply_check(
+    ctx: Context<UpdateSupplyCheck>,
+    halt_mint_on_mismatch: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_halt = config.halt_mint_on_supply_mismatch;
+    config.halt_mint_on_supply_mismatch = halt_mint_on_mismatch;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::HaltMintOnSupplyMismatch,
+        ConfigValue::Bool(old_halt),
+        ConfigValue::Bool(halt_mint_on_mismatch),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "halt_mint_on_supply_mismatch".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Choose whether `verify_authorities` pauses when it finds the mint's
+/// authorities changed. Reuses the `UpdateSupplyCheck` accounts.
+pub fn handler_update_authority_check(
+    ctx: Context<UpdateSupplyCheck>,
+    pause_on_anomaly: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_pause = config.pause_on_authority_anomaly;
+    config.pause_on_authority_anomaly = pause_on_anomaly;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::PauseOnAuthorityAnomaly,
+        ConfigValue::Bool(old_pause),
+        ConfigValue::Bool(pause_on_anomaly),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "pause_on_authority_anomaly".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Set or clear the amount above which mints need a co-signed
+/// `MintApproval`, and how many co-signers it needs. Reuses the
+/// `UpdateSupplyCheck` accounts.
+pub fn handler_update_large_mint_policy(
+    ctx: Context<UpdateSupplyCheck>,
+    threshold: Option<u64>,
+    approvals: u8,
+) -> Result<()> {
+    if threshold.is_some() {
+        require!(
+            (1..=MintApproval::MAX_APPROVERS).contains(&usize::from(approvals)),
+            SssError::InvalidApprovalCount
+        );
+    }
+
+    let config = &mut ctx.accounts.config;
+    let old_threshold = config.large_mint_threshold;
+    let old_approvals = config.large_mint_approvals;
+    config.large_mint_threshold = threshold;
+    config.large_mint_approvals = approvals;
+    config.refresh_digest();
+
+    let admin = ctx.accounts.admin.key();
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::LargeMintThreshold,
+        old_threshold.map_or(ConfigValue::None, ConfigValue::U64),
+        threshold.map_or(ConfigValue::None, ConfigValue::U64),
+    );
+    emit_config_diff(
+        config,
+        admin,
+        ConfigField::LargeMintApprovals,
+        ConfigValue::U8(old_approvals),
+        ConfigValue::U8(approvals),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "large_mint_policy".to_string(),
+        updater: admin,
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Choose whether `unpause` takes an Admin instead of a Pauser. Reuses the
+/// `UpdateSupplyCheck` accounts.
+pub fn handler_update_unpause_policy(
+    ctx: Context<UpdateSupplyCheck>,
+    requires_admin: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_requires_admin = config.unpause_requires_admin;
+    config.unpause_requires_admin = requires_admin;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::UnpauseRequiresAdmin,
+        ConfigValue::Bool(old_requires_admin),
+        ConfigValue::Bool(requires_admin),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "unpause_requires_admin".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Set how long a pause lasts before lifting on its own; zero disables
+/// expiry. A running pause keeps its deadline until renewed. Reuses the
+/// `UpdateSupplyCheck` accounts.
+pub fn handler_update_max_pause(
+    ctx: Context<UpdateSupplyCheck>,
+    max_pause_secs: u32,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_max_pause_secs = config.max_pause_secs;
+    config.max_pause_secs = max_pause_secs;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::MaxPauseSecs,
+        ConfigValue::U32(old_max_pause_secs),
+        ConfigValue::U32(max_pause_secs),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "max_pause_secs".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Set or clear the amount above which a seizure needs a `SeizeApproval`
+/// from a second Seizer or Admin. Reuses the `UpdateSupplyCheck` accounts.
+pub fn handler_update_seize_policy(
+    ctx: Context<UpdateSupplyCheck>,
+    threshold: Option<u64>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_threshold = config.large_seize_threshold;
+    config.large_seize_threshold = threshold;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::LargeSeizeThreshold,
+        old_threshold.map_or(ConfigValue::None, ConfigValue::U64),
+        threshold.map_or(ConfigValue::None, ConfigValue::U64),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "large_seize_threshold".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Set or clear the wallet whose associated token account must receive
+/// seized funds. Reuses the `UpdateSupplyCheck` accounts.
+pub fn handler_update_treasury(
+    ctx: Context<UpdateSupplyCheck>,
+    treasury: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_treasury = config.treasury;
+    config.treasury = treasury;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::Treasury,
+        old_treasury.map_or(ConfigValue::None, ConfigValue::Pubkey),
+        treasury.map_or(ConfigValue::None, ConfigValue::Pubkey),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "treasury".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Choose whether only frozen accounts may be seized. Reuses the
+/// `UpdateSupplyCheck` accounts.
+pub fn handler_update_seize_freeze_policy(
+    ctx: Context<UpdateSupplyCheck>,
+    requires_frozen: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_requires_frozen = config.seize_requires_frozen;
+    config.seize_requires_frozen = requires_frozen;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::SeizeRequiresFrozen,
+        ConfigValue::Bool(old_requires_frozen),
+        ConfigValue::Bool(requires_frozen),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "seize_requires_frozen".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Set or clear the maximum age of the `ReserveAttestation` that issuance
+/// is checked against. While set, minting needs a fresh attestation
+/// covering the new supply. Reuses the `UpdateSupplyCheck` accounts.
+pub fn handler_update_reserve_gate(
+    ctx: Context<UpdateSupplyCheck>,
+    max_attestation_age_secs: Option<u32>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_max_age = config.max_attestation_age_secs;
+    config.max_attestation_age_secs = max_attestation_age_secs;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::MaxAttestationAgeSecs,
+        old_max_age.map_or(ConfigValue::None, ConfigValue::U32),
+        max_attestation_age_secs.map_or(ConfigValue::None, ConfigValue::U32),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "max_attestation_age_secs".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Set or clear the token account `FeeSchedule` mint and burn fees are paid
+/// into; `None` stops charging them. Set up the schedule first, since every
+/// `mint_tokens` and `redeem` needs it while fees are on. Reuses the
+/// `UpdateSupplyCheck` accounts.
+pub fn handler_update_fee_treasury(
+    ctx: Context<UpdateSupplyCheck>,
+    fee_treasury: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_fee_treasury = config.fee_treasury;
+    config.fee_treasury = fee_treasury;
+    config.refresh_digest();
+
+    emit_config_diff(
+        config,
+        ctx.accounts.admin.key(),
+        ConfigField::FeeTreasury,
+        old_fee_treasury.map_or(ConfigValue::None, ConfigValue::Pubkey),
+        fee_treasury.map_or(ConfigValue::None, ConfigValue::Pubkey),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "fee_treasury".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: config.mint_index,
     });
 
     Ok(())
@@ -0,0 +1,298 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_interface::{
+    token_metadata_update_field, Mint, TokenInterface, TokenMetadataUpdateField,
+};
+
+use crate::error::SssError;
+use crate::events::{ConfigField, ConfigUpdated, ConfigValue, MetadataUpdated};
+use crate::instructions::admin::update_config::emit_config_diff;
+use crate::state::{Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+#[instruction(uri: String)]
+pub struct UpdateMetadataUri<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Resized to fit the new URI; `authority` pays for growth and receives
+    /// the rent freed when it shrinks.
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        realloc = StablecoinConfig::compute_space(&config.name, &config.symbol, &uri),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Admin or MetadataManager role PDA of `authority`.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            authority.key().as_ref(),
+            &[authority_role.role.as_u8()],
+        ],
+        bump = authority_role.bump,
+        constraint = matches!(authority_role.role, Role::Admin | Role::MetadataManager) @ SssError::Unauthorized,
+        constraint = authority_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Point the config at a new metadata URI. When the mint carries Token-2022
+/// metadata, its `uri` field is rewritten too, signed by the config PDA as
+/// update authority, and `authority` tops up the mint's rent if it grew.
+pub fn handler_update_metadata_uri(ctx: Context<UpdateMetadataUri>, uri: String) -> Result<()> {
+    require!(
+        uri.len() <= StablecoinConfig::MAX_URI_LEN,
+        SssError::UriTooLong
+    );
+
+    let mint_info = ctx.accounts.mint.to_account_info();
+    if has_token_metadata(&mint_info)? {
+        set_token_metadata_field(
+            &ctx.accounts.token_program,
+            &ctx.accounts.config,
+            &mint_info,
+            Field::Uri,
+            uri.clone(),
+        )?;
+        fund_mint_rent(
+            &ctx.accounts.system_program,
+            &ctx.accounts.authority,
+            mint_info,
+        )?;
+    }
+
+    let config = &mut ctx.accounts.config;
+    let old_uri = std::mem::replace(&mut config.uri, uri);
+    config.refresh_digest();
+
+    let updater = ctx.accounts.authority.key();
+    emit_config_diff(
+        config,
+        updater,
+        ConfigField::Uri,
+        ConfigValue::Text(old_uri),
+        ConfigValue::Text(config.uri.clone()),
+    );
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "uri".to_string(),
+        updater,
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(name: Option<String>, symbol: Option<String>, uri: Option<String>)]
+pub struct UpdateMetadata<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Resized to fit the new strings; `authority` pays for growth and
+    /// receives the rent freed when it shrinks.
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        realloc = StablecoinConfig::compute_space(
+            name.as_deref().unwrap_or(&config.name),
+            symbol.as_deref().unwrap_or(&config.symbol),
+            uri.as_deref().unwrap_or(&config.uri),
+        ),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Admin or MetadataManager role PDA of `authority`.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            authority.key().as_ref(),
+            &[authority_role.role.as_u8()],
+        ],
+        bump = authority_role.bump,
+        constraint = matches!(authority_role.role, Role::Admin | Role::MetadataManager) @ SssError::Unauthorized,
+        constraint = authority_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Rename the stablecoin: set whichever of `name`, `symbol` and `uri` are
+/// given, on the config and, when the mint carries Token-2022 metadata, on
+/// the mint so wallets show the change. Emits `MetadataUpdated` with the
+/// resulting values and a `ConfigFieldChanged` per field that changed.
+pub fn handler_update_metadata(
+    ctx: Context<UpdateMetadata>,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    let fits = |value: &Option<String>, max: usize| value.as_ref().is_none_or(|v| v.len() <= max);
+    require!(
+        fits(&name, StablecoinConfig::MAX_NAME_LEN),
+        SssError::NameTooLong
+    );
+    require!(
+        fits(&symbol, StablecoinConfig::MAX_SYMBOL_LEN),
+        SssError::SymbolTooLong
+    );
+    require!(
+        fits(&uri, StablecoinConfig::MAX_URI_LEN),
+        SssError::UriTooLong
+    );
+
+    let updates = [
+        (Field::Name, ConfigField::Name, name),
+        (Field::Symbol, ConfigField::Symbol, symbol),
+        (Field::Uri, ConfigField::Uri, uri),
+    ];
+
+    let mint_info = ctx.accounts.mint.to_account_info();
+    if has_token_metadata(&mint_info)? {
+        for (field, _, value) in &updates {
+            if let Some(value) = value {
+                set_token_metadata_field(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.config,
+                    &mint_info,
+                    field.clone(),
+                    value.clone(),
+                )?;
+            }
+        }
+        fund_mint_rent(
+            &ctx.accounts.system_program,
+            &ctx.accounts.authority,
+            mint_info,
+        )?;
+    }
+
+    let updater = ctx.accounts.authority.key();
+    let config = &mut ctx.accounts.config;
+    for (_, config_field, value) in updates {
+        let Some(value) = value else { continue };
+        let slot = match config_field {
+            ConfigField::Name => &mut config.name,
+            ConfigField::Symbol => &mut config.symbol,
+            _ => &mut config.uri,
+        };
+        let old = std::mem::replace(slot, value.clone());
+        emit_config_diff(
+            config,
+            updater,
+            config_field,
+            ConfigValue::Text(old),
+            ConfigValue::Text(value),
+        );
+    }
+    config.refresh_digest();
+
+    emit!(MetadataUpdated {
+        config: config.key(),
+        mint: config.mint,
+        name: config.name.clone(),
+        symbol: config.symbol.clone(),
+        uri: config.uri.clone(),
+        updater,
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Whether `mint` carries the Token-2022 metadata extension.
+fn has_token_metadata(mint: &AccountInfo) -> Result<bool> {
+    let data = mint.try_borrow_data()?;
+    Ok(StateWithExtensions::<MintState>::unpack(&data)?
+        .get_variable_len_extension::<TokenMetadata>()
+        .is_ok())
+}
+
+/// Rewrite one field of the mint's Token-2022 metadata, signed by the config
+/// PDA as update authority.
+fn set_token_metadata_field<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    config: &Account<'info, StablecoinConfig>,
+    mint: &AccountInfo<'info>,
+    field: Field,
+    value: String,
+) -> Result<()> {
+    let mint_key = mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        StablecoinConfig::SSS_CONFIG_SEED,
+        mint_key.as_ref(),
+        &[config.bump],
+    ]];
+    token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TokenMetadataUpdateField {
+                program_id: token_program.to_account_info(),
+                metadata: mint.clone(),
+                update_authority: config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        field,
+        value,
+    )
+}
+
+/// Token-2022 resizes the mint but leaves funding it to the caller, so
+/// `payer` covers any rent the metadata update left unpaid.
+fn fund_mint_rent<'info>(
+    system_program: &Program<'info, System>,
+    payer: &Signer<'info>,
+    mint: AccountInfo<'info>,
+) -> Result<()> {
+    let rent_due = Rent::get()?
+        .minimum_balance(mint.data_len())
+        .saturating_sub(mint.lamports());
+    if rent_due > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: mint,
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::events::ConfigUpdated;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::error::SssError;
+use crate::events::{ConfigUpdated, MinterUsageReset};
+use crate::state::{OperatingHours, Role, RoleAccount, StablecoinConfig};
 
 #[derive(Accounts)]
 pub struct UpdateMinter<'info> {
@@ -10,18 +11,21 @@ pub struct UpdateMinter<'info> {
     #[account(
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
         bump = config.bump,
+        constraint = config.is_supported_version() @ crate::error::SssError::UnsupportedConfigVersion,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
-    /// Admin role PDA — proves admin authorization.
+    /// Admin or QuotaManager role PDA of `admin`.
     #[account(
         seeds = [
             RoleAccount::SSS_ROLE_SEED,
             config.key().as_ref(),
             admin.key().as_ref(),
-            &[Role::Admin.as_u8()],
+            &[admin_role.role.as_u8()],
         ],
         bump = admin_role.bump,
+        constraint = matches!(admin_role.role, Role::Admin | Role::QuotaManager) @ SssError::Unauthorized,
+        constraint = admin_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
     )]
     pub admin_role: Account<'info, RoleAccount>,
 
@@ -50,6 +54,131 @@ pub fn handler_update_minter(ctx: Context<UpdateMinter>, new_quota: Option<u64>)
         config: ctx.accounts.config.key(),
         field: "minter_quota".to_string(),
         updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Restrict the minter to a recurring UTC window, or lift the restriction
+/// with `None`. Applies to every path that mints against the role: direct,
+/// hot-key and sub-minter mints.
+pub fn handler_set_minter_hours(
+    ctx: Context<UpdateMinter>,
+    hours: Option<OperatingHours>,
+) -> Result<()> {
+    if let Some(hours) = hours {
+        require!(hours.is_valid(), SssError::InvalidOperatingHours);
+    }
+    ctx.accounts.minter_role.allowed_hours = hours;
+
+    emit!(ConfigUpdated {
+        config: ctx.accounts.config.key(),
+        field: "minter_hours".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Make the minter's `mint_quota` apply per period of `period_secs` (e.g.
+/// 86_400 for a daily limit), or a lifetime cap again with `None`. The next
+/// mint after the running period elapses starts a new one and clears
+/// `amount_minted`, so a minter that had none starts afresh at its next
+/// mint.
+pub fn handler_set_minter_quota_period(
+    ctx: Context<UpdateMinter>,
+    period_secs: Option<u32>,
+) -> Result<()> {
+    require!(period_secs != Some(0), SssError::InvalidQuotaPeriod);
+    ctx.accounts.minter_role.quota_period_secs = period_secs;
+
+    emit!(ConfigUpdated {
+        config: ctx.accounts.config.key(),
+        field: "minter_quota_period".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Limit the minter to `max_per_window` per `window_secs` on top of its
+/// quota, or lift the limit with `None`. Applies to every path that mints
+/// against the role.
+pub fn handler_set_minter_rate_limit(
+    ctx: Context<UpdateMinter>,
+    max_per_window: Option<u64>,
+    window_secs: u32,
+) -> Result<()> {
+    if max_per_window.is_some() {
+        require!(window_secs > 0, SssError::InvalidMintRateLimit);
+    }
+    let minter_role = &mut ctx.accounts.minter_role;
+    minter_role.max_per_window = max_per_window;
+    minter_role.window_secs = window_secs;
+
+    emit!(ConfigUpdated {
+        config: ctx.accounts.config.key(),
+        field: "minter_rate_limit".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResetMinterUsage<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Admin's own role PDA — proves admin authorization.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            minter_role.address.as_ref(),
+            &[Role::Minter.as_u8()],
+        ],
+        bump = minter_role.bump,
+    )]
+    pub minter_role: Account<'info, RoleAccount>,
+}
+
+/// Zero the minter's `amount_minted`, leaving its quota and grant in place,
+/// so an exhausted minter can resume without being revoked and re-granted.
+/// `MinterUsageReset` records what was cleared.
+pub fn handler_reset_minter_usage(ctx: Context<ResetMinterUsage>) -> Result<()> {
+    let minter_role = &mut ctx.accounts.minter_role;
+    let previous_amount_minted = minter_role.amount_minted;
+    minter_role.amount_minted = 0;
+
+    emit!(MinterUsageReset {
+        config: ctx.accounts.config.key(),
+        minter: minter_role.address,
+        previous_amount_minted,
+        admin: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
     });
 
     Ok(())
@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
 
-use crate::events::ConfigUpdated;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::error::SssError;
+use crate::events::{ConfigField, ConfigUpdated, ConfigValue};
+use crate::instructions::admin::update_config::emit_config_diff;
+use crate::state::{OracleKind, OraclePriceSource, Role, RoleAccount, StablecoinConfig};
 
 /// Update (or clear) the Pyth oracle feed ID used for oracle-gated minting.
 ///
 /// Setting `oracle_feed_id` to `Some(feed_id)` enables oracle-adjusted supply
 /// caps for `mint_tokens` when a `price_update` account is provided.
 /// Setting it to `None` disables oracle-adjusted minting (raw cap only).
+/// The all-zero ID is rejected rather than treated as a wildcard.
 ///
 /// # Security
 /// Only an Admin can set this value. Operators must verify the Pyth feed ID
@@ -21,6 +24,7 @@ pub struct UpdateOracleFeed<'info> {
         mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
         bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
@@ -41,12 +45,131 @@ pub fn handler_update_oracle_feed(
     ctx: Context<UpdateOracleFeed>,
     oracle_feed_id: Option<[u8; 32]>,
 ) -> Result<()> {
+    require!(
+        oracle_feed_id != Some([0; 32]),
+        SssError::InvalidOracleFeedId
+    );
+    let old_feed_id = ctx.accounts.config.oracle_feed_id;
     ctx.accounts.config.oracle_feed_id = oracle_feed_id;
+    ctx.accounts.config.refresh_digest();
+
+    emit_config_diff(
+        &ctx.accounts.config,
+        ctx.accounts.admin.key(),
+        ConfigField::OracleFeedId,
+        old_feed_id.map_or(ConfigValue::None, ConfigValue::Bytes32),
+        oracle_feed_id.map_or(ConfigValue::None, ConfigValue::Bytes32),
+    );
 
     emit!(ConfigUpdated {
         config: ctx.accounts.config.key(),
         field: "oracle_feed_id".to_string(),
         updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Set (or clear) the maximum Pyth confidence interval, in basis points of
+/// the price, accepted for oracle-adjusted minting. Reuses the
+/// `UpdateOracleFeed` accounts: admin signer, config and admin role.
+pub fn handler_update_oracle_confidence(
+    ctx: Context<UpdateOracleFeed>,
+    max_confidence_bps: Option<u16>,
+) -> Result<()> {
+    if let Some(bps) = max_confidence_bps {
+        require!(bps <= 10_000, SssError::InvalidConfidenceBps);
+    }
+    let old_bps = ctx.accounts.config.max_confidence_bps;
+    ctx.accounts.config.max_confidence_bps = max_confidence_bps;
+    ctx.accounts.config.refresh_digest();
+
+    emit_config_diff(
+        &ctx.accounts.config,
+        ctx.accounts.admin.key(),
+        ConfigField::MaxConfidenceBps,
+        old_bps.map_or(ConfigValue::None, ConfigValue::U16),
+        max_confidence_bps.map_or(ConfigValue::None, ConfigValue::U16),
+    );
+
+    emit!(ConfigUpdated {
+        config: ctx.accounts.config.key(),
+        field: "max_confidence_bps".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Choose between Pyth's spot price and its EMA for oracle-adjusted caps.
+/// Reuses the `UpdateOracleFeed` accounts.
+pub fn handler_update_oracle_price_source(
+    ctx: Context<UpdateOracleFeed>,
+    source: OraclePriceSource,
+) -> Result<()> {
+    let old_source = ctx.accounts.config.oracle_price_source;
+    ctx.accounts.config.oracle_price_source = source;
+    ctx.accounts.config.refresh_digest();
+
+    emit_config_diff(
+        &ctx.accounts.config,
+        ctx.accounts.admin.key(),
+        ConfigField::OraclePriceSource,
+        ConfigValue::OraclePriceSource(old_source),
+        ConfigValue::OraclePriceSource(source),
+    );
+
+    emit!(ConfigUpdated {
+        config: ctx.accounts.config.key(),
+        field: "oracle_price_source".to_string(),
+        updater: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Switch the oracle network and pin its feed in one step, since a feed ID
+/// only means something for the network it was pinned for: a Pyth feed ID, or
+/// the Switchboard pull feed's account address. `None` leaves oracle-adjusted
+/// minting disabled. Reuses the `UpdateOracleFeed` accounts.
+pub fn handler_update_oracle_kind(
+    ctx: Context<UpdateOracleFeed>,
+    kind: OracleKind,
+    oracle_feed_id: Option<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        oracle_feed_id != Some([0; 32]),
+        SssError::InvalidOracleFeedId
+    );
+    let config = &mut ctx.accounts.config;
+    let old_kind = std::mem::replace(&mut config.oracle_kind, kind);
+    let old_feed_id = std::mem::replace(&mut config.oracle_feed_id, oracle_feed_id);
+    config.refresh_digest();
+
+    let updater = ctx.accounts.admin.key();
+    emit_config_diff(
+        config,
+        updater,
+        ConfigField::OracleKind,
+        ConfigValue::OracleKind(old_kind),
+        ConfigValue::OracleKind(kind),
+    );
+    emit_config_diff(
+        config,
+        updater,
+        ConfigField::OracleFeedId,
+        old_feed_id.map_or(ConfigValue::None, ConfigValue::Bytes32),
+        oracle_feed_id.map_or(ConfigValue::None, ConfigValue::Bytes32),
+    );
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        field: "oracle_kind".to_string(),
+        updater,
+        mint_index: config.mint_index,
     });
 
     Ok(())
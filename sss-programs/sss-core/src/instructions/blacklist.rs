@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::TRANSFER_HOOK_PROGRAM_ID;
+use crate::error::SssError;
+use crate::pda::find_blacklist_address;
+
+/// Offset of the reason's `u32` length prefix in the hook's `BlacklistEntry`:
+/// discriminator(8) + mint(32) + address(32) + added_by(32) + added_at(8).
+const BLACKLIST_REASON_OFFSET: usize = 8 + 32 + 32 + 32 + 8;
+
+/// Reject `owner` if the transfer hook has a blacklist entry for it under
+/// `mint` that is in effect. For paths that move tokens without going through
+/// the hook (mint, burn). `blacklist` must be the hook's
+/// `["blacklist", mint, owner]` PDA.
+pub(crate) fn require_not_blacklisted(
+    blacklist: &AccountInfo,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    require!(
+        !is_blacklisted(blacklist, mint, owner)?,
+        SssError::AddressBlacklisted
+    );
+    Ok(())
+}
+
+/// Whether the hook's `["blacklist", mint, owner]` PDA `blacklist` holds an
+/// entry that is in effect. Errors if `blacklist` is not that PDA.
+pub(crate) fn is_blacklisted(
+    blacklist: &AccountInfo,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<bool> {
+    let (expected, _) = find_blacklist_address(mint, owner);
+    require_keys_eq!(blacklist.key(), expected, SssError::InvalidBlacklistAccount);
+    if blacklist.data_is_empty() || blacklist.owner != &TRANSFER_HOOK_PROGRAM_ID {
+        return Ok(false);
+    }
+    match scheduled_effective_at(&blacklist.try_borrow_data()?) {
+        Some(effective_at) => Ok(Clock::get()?.unix_timestamp >= effective_at),
+        None => Ok(true),
+    }
+}
+
+/// The `effective_at` of a scheduled hook `BlacklistEntry`. The layout is read
+/// by offset because sss-core cannot depend on the hook crate. Entries
+/// without it (immediate, or written before scheduling existed) return
+/// `None`.
+fn scheduled_effective_at(data: &[u8]) -> Option<i64> {
+    let reason_len = data
+        .get(BLACKLIST_REASON_OFFSET..BLACKLIST_REASON_OFFSET + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_le_bytes)? as usize;
+    // reason bytes, then bump(1), then the `Option<i64>` tag.
+    let tag = BLACKLIST_REASON_OFFSET
+        .checked_add(4)?
+        .checked_add(reason_len)?
+        .checked_add(1)?;
+    if *data.get(tag)? != 1 {
+        return None;
+    }
+    data.get(tag + 1..tag + 9)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(i64::from_le_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(reason: &str, bump: u8, effective_at: Option<Option<i64>>) -> Vec<u8> {
+        let mut data = vec![0u8; BLACKLIST_REASON_OFFSET];
+        data.extend_from_slice(&(reason.len() as u32).to_le_bytes());
+        data.extend_from_slice(reason.as_bytes());
+        data.push(bump);
+        match effective_at {
+            // Legacy layout ends at `bump`.
+            None => {}
+            Some(None) => data.push(0),
+            Some(Some(at)) => {
+                data.push(1);
+                data.extend_from_slice(&at.to_le_bytes());
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_scheduled_effective_at() {
+        assert_eq!(scheduled_effective_at(&entry("ofac", 1, None)), None);
+        assert_eq!(scheduled_effective_at(&entry("ofac", 1, Some(None))), None);
+        assert_eq!(
+            scheduled_effective_at(&entry("ofac", 1, Some(Some(1_700_000_000)))),
+            Some(1_700_000_000)
+        );
+        assert_eq!(scheduled_effective_at(&[]), None);
+    }
+}
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::constants::{capability, pause};
+use crate::error::SssError;
+use crate::events::{BridgeBurned, BridgeMinted};
+use crate::instructions::kyc::require_kyc;
+use crate::state::{KycEntry, Role, RoleAccount, StablecoinConfig};
+
+/// Bridge inflow: re-mints tokens that were previously burned on this chain
+/// by `bridge_burn`. Counted in `bridge_minted`, not `total_minted`, so it
+/// neither consumes supply-cap headroom nor changes the issuer's supply.
+#[derive(Accounts)]
+pub struct BridgeMint<'info> {
+    pub bridge: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::MINT, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Bridge role PDA — its existence proves bridge authorization.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            bridge.key().as_ref(),
+            &[Role::Bridge.as_u8()],
+        ],
+        bump = bridge_role.bump,
+        constraint = bridge_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub bridge_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// KYC approval for the owner of `to`. Required on SSS-2 configs,
+    /// ignored otherwise.
+    pub kyc_entry: Option<Account<'info, KycEntry>>,
+}
+
+/// Bridge outflow: burns the bridge's own tokens before they are released on
+/// another chain. Counted in `bridge_burned`, not `total_burned`.
+#[derive(Accounts)]
+pub struct BridgeBurn<'info> {
+    pub bridge: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::BURN, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Bridge role PDA — its existence proves bridge authorization.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            bridge.key().as_ref(),
+            &[Role::Bridge.as_u8()],
+        ],
+        bump = bridge_role.bump,
+        constraint = bridge_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub bridge_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Unlike `burn_tokens`, the bridge can only burn from its own account.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = bridge,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_bridge_mint(ctx: Context<BridgeMint>, amount: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        config.is_enabled(capability::MINT),
+        SssError::InstructionDisabled
+    );
+    require!(
+        config.bridge_can_mint(amount),
+        SssError::BridgeInflowExceedsOutflow
+    );
+    require_kyc(
+        config,
+        ctx.accounts.kyc_entry.as_ref(),
+        &ctx.accounts.to.owner,
+    )?;
+
+    config.bridge_minted = config
+        .bridge_minted
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    config.refresh_digest();
+
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        StablecoinConfig::SSS_CONFIG_SEED,
+        mint_key.as_ref(),
+        &[config.bump],
+    ]];
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.to.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+        .with_signer(signer_seeds);
+    token_interface::mint_to(cpi_ctx, amount)?;
+
+    emit!(BridgeMinted {
+        mint: mint_key,
+        to: ctx.accounts.to.key(),
+        amount,
+        bridge: ctx.accounts.bridge.key(),
+        local_supply: config.local_supply(),
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+pub fn handler_bridge_burn(ctx: Context<BridgeBurn>, amount: u64) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    let config = &mut ctx.accounts.config;
+    require!(
+        config.is_enabled(capability::BURN),
+        SssError::InstructionDisabled
+    );
+
+    config.bridge_burned = config
+        .bridge_burned
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    config.refresh_digest();
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.from.to_account_info(),
+        authority: ctx.accounts.bridge.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token_interface::burn(cpi_ctx, amount)?;
+
+    emit!(BridgeBurned {
+        mint: ctx.accounts.mint.key(),
+        from: ctx.accounts.from.key(),
+        amount,
+        bridge: ctx.accounts.bridge.key(),
+        local_supply: ctx.accounts.config.local_supply(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{capability, pause, MAX_BURN_BATCH};
+use crate::error::SssError;
+use crate::events::TokensBurned;
+use crate::instructions::burn_tokens::burn_as_delegate;
+use crate::state::{Role, RoleAccount, StablecoinConfig};
+
+/// Burn from up to `MAX_BURN_BATCH` token accounts in one transaction, for
+/// settling a day's redemptions at once. `amounts[i]` is burned from the
+/// i-th remaining account under the config PDA's permanent delegate
+/// authority, like `burn_tokens`, and `total_burned` and the burner's quota
+/// are updated once with the batch total. Like `burn_tokens`, it charges no
+/// burn fee.
+#[derive(Accounts)]
+pub struct BurnTokensBatch<'info> {
+    pub burner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::BURN, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            burner.key().as_ref(),
+            &[Role::Burner.as_u8()],
+        ],
+        bump = burner_role.bump,
+        constraint = burner_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub burner_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_burn_tokens_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BurnTokensBatch<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    require!(
+        (1..=MAX_BURN_BATCH).contains(&amounts.len())
+            && ctx.remaining_accounts.len() == amounts.len(),
+        SssError::InvalidBurnBatch
+    );
+    require!(amounts.iter().all(|&a| a > 0), SssError::ZeroAmount);
+    require!(
+        ctx.accounts.config.is_enabled(capability::BURN),
+        SssError::InstructionDisabled
+    );
+    let total = amounts
+        .iter()
+        .try_fold(0u64, |sum, &amount| sum.checked_add(amount))
+        .ok_or(SssError::ArithmeticOverflow)?;
+    let within_quota = ctx
+        .accounts
+        .burner_role
+        .burn_quota_allows(total)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_quota, SssError::BurnQuotaExceeded);
+
+    let accounts = &mut *ctx.accounts;
+    let mut new_supply = accounts.config.current_supply();
+    accounts.config.total_burned = accounts
+        .config
+        .total_burned
+        .checked_add(total)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    accounts.config.refresh_digest();
+
+    for (info, &amount) in ctx.remaining_accounts.iter().zip(&amounts) {
+        let from = InterfaceAccount::<TokenAccount>::try_from(info)?;
+        require_keys_eq!(from.mint, accounts.config.mint, SssError::MintMismatch);
+
+        burn_as_delegate(
+            &accounts.config,
+            &accounts.mint,
+            &from,
+            &accounts.token_program,
+            amount,
+        )?;
+
+        new_supply = new_supply.saturating_sub(amount);
+        emit!(TokensBurned {
+            mint: accounts.mint.key(),
+            from: from.key(),
+            amount,
+            burner: accounts.burner.key(),
+            new_supply,
+            from_owner: from.owner,
+            config: accounts.config.key(),
+            mint_index: accounts.config.mint_index,
+        });
+    }
+
+    accounts.burner_role.amount_burned = accounts
+        .burner_role
+        .amount_burned
+        .checked_add(total)
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    Ok(())
+}
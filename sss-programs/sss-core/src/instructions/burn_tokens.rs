@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
 
+use crate::constants::{capability, pause};
 use crate::error::SssError;
 use crate::events::TokensBurned;
 use crate::state::{Role, RoleAccount, StablecoinConfig};
@@ -13,12 +14,15 @@ pub struct BurnTokens<'info> {
         mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
         bump = config.bump,
-        constraint = !config.paused @ SssError::Paused,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::BURN, Clock::get()?.unix_timestamp) @ SssError::Paused,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     /// Burner role PDA — its existence proves burn authorization.
+    /// Mutable for per-burner quota tracking (amount_burned).
     #[account(
+        mut,
         seeds = [
             RoleAccount::SSS_ROLE_SEED,
             config.key().as_ref(),
@@ -26,6 +30,7 @@ pub struct BurnTokens<'info> {
             &[Role::Burner.as_u8()],
         ],
         bump = burner_role.bump,
+        constraint = burner_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
     )]
     pub burner_role: Account<'info, RoleAccount>,
 
@@ -56,49 +61,86 @@ pub struct BurnTokens<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+/// Charges no burn fee, unlike `redeem`: a Burner retires supply for the
+/// issuer, which nets any fee from the off-chain payout.
 pub fn handler_burn_tokens(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
-    require!(amount > 0, SssError::ZeroAmount);
-
-    // Capture account infos before mutable borrow of config
-    let config_info = ctx.accounts.config.to_account_info();
-    let mint_info = ctx.accounts.mint.to_account_info();
-    let from_info = ctx.accounts.from.to_account_info();
-    let token_program_info = ctx.accounts.token_program.to_account_info();
-    let mint_key = ctx.accounts.mint.key();
-    let from_key = ctx.accounts.from.key();
-    let from_owner = ctx.accounts.from.owner; // captured for audit event
-    let burner_key = ctx.accounts.burner.key();
-
-    let config = &mut ctx.accounts.config;
-    config.total_burned = config
-        .total_burned
-        .checked_add(amount)
-        .ok_or(SssError::ArithmeticOverflow)?;
+    ctx.accounts.burn(amount)
+}
+
+impl<'info> BurnTokens<'info> {
+    /// Burn `amount` from `from` and update the supply counters. Shared with
+    /// `burn_tokens_idempotent`.
+    pub(crate) fn burn(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, SssError::ZeroAmount);
+        require!(
+            self.config.is_enabled(capability::BURN),
+            SssError::InstructionDisabled
+        );
+        let within_quota = self
+            .burner_role
+            .burn_quota_allows(amount)
+            .ok_or(SssError::ArithmeticOverflow)?;
+        require!(within_quota, SssError::BurnQuotaExceeded);
+
+        let config = &mut self.config;
+        config.total_burned = config
+            .total_burned
+            .checked_add(amount)
+            .ok_or(SssError::ArithmeticOverflow)?;
+        config.refresh_digest();
+
+        burn_as_delegate(
+            &self.config,
+            &self.mint,
+            &self.from,
+            &self.token_program,
+            amount,
+        )?;
+
+        emit!(TokensBurned {
+            mint: self.mint.key(),
+            from: self.from.key(),
+            amount,
+            burner: self.burner.key(),
+            new_supply: self.config.current_supply(),
+            from_owner: self.from.owner, // for the audit trail
+            config: self.config.key(),
+            mint_index: self.config.mint_index,
+        });
 
+        self.burner_role.amount_burned = self
+            .burner_role
+            .amount_burned
+            .checked_add(amount)
+            .ok_or(SssError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
+
+/// Burn `amount` from `from` via the config PDA's permanent delegate
+/// authority. Callers update `total_burned` and emit the event.
+pub(crate) fn burn_as_delegate<'info>(
+    config: &Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let mint_key = mint.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
         StablecoinConfig::SSS_CONFIG_SEED,
         mint_key.as_ref(),
         &[config.bump],
     ]];
 
-    // Burn via permanent delegate authority (config PDA)
     let cpi_accounts = Burn {
-        mint: mint_info,
-        from: from_info,
-        authority: config_info,
+        mint: mint.to_account_info(),
+        from: from.to_account_info(),
+        authority: config.to_account_info(),
     };
-    let cpi_ctx = CpiContext::new(token_program_info, cpi_accounts).with_signer(signer_seeds);
-
-    token_interface::burn(cpi_ctx, amount)?;
-
-    emit!(TokensBurned {
-        mint: mint_key,
-        from: from_key,
-        amount,
-        burner: burner_key,
-        new_supply: config.current_supply(),
-        from_owner,
-    });
+    let cpi_ctx =
+        CpiContext::new(token_program.to_account_info(), cpi_accounts).with_signer(signer_seeds);
 
-    Ok(())
+    token_interface::burn(cpi_ctx, amount)
 }
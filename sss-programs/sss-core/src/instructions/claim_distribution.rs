@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::constants::{capability, pause};
+use crate::error::SssError;
+use crate::events::DistributionClaimed;
+use crate::instructions::blacklist::require_not_blacklisted;
+use crate::instructions::reserve_attestation::require_attested_reserves;
+use crate::instructions::seize::transfer_as_delegate;
+use crate::merkle;
+use crate::state::{
+    ClaimStatus, DistributionFunding, Distributor, ReserveAttestation, StablecoinConfig,
+};
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct ClaimDistribution<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::MINT | pause::TRANSFERS, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            Distributor::SSS_DISTRIBUTOR_SEED,
+            config.key().as_ref(),
+            &distributor.id.to_le_bytes(),
+        ],
+        bump = distributor.bump,
+    )]
+    pub distributor: Account<'info, Distributor>,
+
+    /// Created once per leaf; a second claim of the same index fails here.
+    #[account(
+        init,
+        payer = claimant,
+        space = ClaimStatus::SPACE,
+        seeds = [
+            ClaimStatus::SSS_CLAIM_SEED,
+            distributor.key().as_ref(),
+            &index.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The claimant's own token account.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = claimant,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: The claimant's blacklist PDA in the transfer hook program,
+    /// verified by re-derivation. Checked explicitly because minted claims
+    /// never pass through the hook.
+    pub claimant_blacklist: UncheckedAccount<'info>,
+
+    /// Required for `DistributionFunding::Treasury`; must match the
+    /// distributor's treasury token account.
+    #[account(mut, token::mint = mint)]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// Required for `DistributionFunding::Mint` while
+    /// `config.max_attestation_age_secs` is set; see
+    /// `MintTokens::reserve_attestation`.
+    #[account(
+        seeds = [ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED, config.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+}
+
+/// Claim leaf `index` of a distributor. Remaining accounts are forwarded to
+/// the transfer hook for treasury-funded claims on SSS-2 mints.
+pub fn handler_claim_distribution<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimDistribution<'info>>,
+    index: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    let claimant = ctx.accounts.claimant.key();
+
+    let leaf = merkle::claim_leaf(index, &claimant, amount);
+    require!(
+        merkle::verify(&proof, &ctx.accounts.distributor.merkle_root, leaf),
+        SssError::InvalidMerkleProof
+    );
+
+    if ctx.accounts.config.enable_transfer_hook {
+        require_not_blacklisted(
+            &ctx.accounts.claimant_blacklist,
+            &ctx.accounts.mint.key(),
+            &claimant,
+        )?;
+    }
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.total_claimed = distributor
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(
+        distributor.total_claimed <= distributor.max_total,
+        SssError::DistributionExhausted
+    );
+    distributor.num_claimed = distributor
+        .num_claimed
+        .checked_add(1)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    let funding = distributor.funding;
+    let distributor_key = distributor.key();
+
+    match funding {
+        DistributionFunding::Mint => {
+            let config = &mut ctx.accounts.config;
+            require!(
+                config.is_enabled(capability::MINT),
+                SssError::InstructionDisabled
+            );
+            require!(config.can_mint(amount), SssError::SupplyCapExceeded);
+            require_attested_reserves(
+                config,
+                ctx.accounts.reserve_attestation.as_ref(),
+                amount,
+                Clock::get()?.unix_timestamp,
+            )?;
+            config.total_minted = config
+                .total_minted
+                .checked_add(amount)
+                .ok_or(SssError::ArithmeticOverflow)?;
+            config.refresh_digest();
+
+            let mint_key = ctx.accounts.mint.key();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                StablecoinConfig::SSS_CONFIG_SEED,
+                mint_key.as_ref(),
+                &[config.bump],
+            ]];
+            let cpi_accounts = MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: config.to_account_info(),
+            };
+            let cpi_ctx =
+                CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+                    .with_signer(signer_seeds);
+            token_interface::mint_to(cpi_ctx, amount)?;
+        }
+        DistributionFunding::Treasury { token_account } => {
+            let treasury = ctx
+                .accounts
+                .treasury
+                .as_ref()
+                .ok_or(SssError::InvalidTreasuryAccount)?;
+            require_keys_eq!(
+                treasury.key(),
+                token_account,
+                SssError::InvalidTreasuryAccount
+            );
+            transfer_as_delegate(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.config,
+                &ctx.accounts.mint,
+                &treasury.to_account_info(),
+                &ctx.accounts.destination.to_account_info(),
+                ctx.remaining_accounts,
+                amount,
+            )?;
+        }
+    }
+
+    let claim = &mut ctx.accounts.claim_status;
+    claim.distributor = distributor_key;
+    claim.index = index;
+    claim.claimant = claimant;
+    claim.amount = amount;
+    claim.claimed_at = Clock::get()?.unix_timestamp;
+    claim.bump = ctx.bumps.claim_status;
+
+    emit!(DistributionClaimed {
+        distributor: distributor_key,
+        index,
+        claimant,
+        amount,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
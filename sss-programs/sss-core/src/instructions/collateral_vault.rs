@@ -0,0 +1,503 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::{capability, pause};
+use crate::error::SssError;
+use crate::events::{CollateralDeposited, CollateralWithdrawn, FeeCollected, TokensBurned};
+use crate::instructions::blacklist::require_not_blacklisted;
+use crate::instructions::fees::fee_due;
+use crate::instructions::kyc::require_kyc;
+use crate::instructions::mint_tokens::{issue_less_fee, pyth_price};
+use crate::instructions::seize::transfer_with_hook;
+use crate::math::{collateral_mint_amount, confidence_within_bps};
+use crate::state::{
+    CollateralPosition, CollateralVault, FeeKind, FeeSchedule, FeeTierAssignment, KycEntry,
+    OraclePriceSource, StablecoinConfig,
+};
+
+#[derive(Accounts)]
+pub struct DepositAndMint<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::MINT, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Box<Account<'info, StablecoinConfig>>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Depositor's stablecoin account, credited with the minted amount.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+    )]
+    pub to: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Depositor's blacklist PDA under `mint` in the transfer hook
+    /// program, verified by re-derivation when the mint uses the hook.
+    pub blacklist: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CollateralVault::SSS_COLLATERAL_VAULT_SEED, config.key().as_ref()],
+        bump = collateral_vault.bump,
+        has_one = collateral_mint,
+        has_one = vault_account,
+    )]
+    pub collateral_vault: Box<Account<'info, CollateralVault>>,
+
+    /// The depositor's position, created on their first deposit.
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = CollateralPosition::SPACE,
+        seeds = [
+            CollateralPosition::SSS_COLLATERAL_POSITION_SEED,
+            collateral_vault.key().as_ref(),
+            depositor.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub position: Box<Account<'info, CollateralPosition>>,
+
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = depositor,
+    )]
+    pub depositor_collateral: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub vault_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pyth `PriceUpdateV2` for `collateral_vault.collateral_feed_id`.
+    /// Required when the vault has a feed, ignored when it values the
+    /// collateral at par.
+    /// CHECK: owner, feed and staleness are verified by `pyth_price`.
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// KYC approval for the depositor. Required on SSS-2 configs, ignored
+    /// otherwise.
+    pub kyc_entry: Option<Account<'info, KycEntry>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub collateral_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// Rates for the mint fee; see `MintTokens::fee_schedule`.
+    #[account(
+        seeds = [FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.key().as_ref()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
+
+    /// CHECK: The depositor's `FeeTierAssignment` address; see
+    /// `MintTokens::fee_tier`.
+    #[account(
+        seeds = [
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.key().as_ref(),
+            depositor.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub fee_tier: Option<UncheckedAccount<'info>>,
+
+    /// See `MintTokens::fee_treasury`.
+    #[account(mut, token::mint = mint)]
+    pub fee_treasury: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// Move `collateral_amount` of the vault's collateral from the depositor into
+/// the vault and mint them its value divided by the vault's collateral
+/// ratio. Fails with `CollateralMintBelowMinimum` if that is less than
+/// `min_mint_amount`, so a price move between signing and landing cannot
+/// short the depositor. Both amounts are added to the depositor's
+/// `CollateralPosition`.
+///
+/// While fees are on, the mint fee is split off into the fee treasury. The
+/// position records only what the depositor received, and the collateral
+/// share behind the fee stays in the vault, backing the treasury's tokens.
+///
+/// The mint counts against the supply cap like any other, but not against
+/// the proof-of-reserves gate: the vault itself backs it.
+pub fn handler_deposit_and_mint(
+    ctx: Context<DepositAndMint>,
+    collateral_amount: u64,
+    min_mint_amount: u64,
+) -> Result<()> {
+    require!(collateral_amount > 0, SssError::ZeroAmount);
+    let depositor = ctx.accounts.depositor.key();
+    if ctx.accounts.config.enable_transfer_hook {
+        require_not_blacklisted(
+            &ctx.accounts.blacklist,
+            &ctx.accounts.mint.key(),
+            &depositor,
+        )?;
+    }
+    require_kyc(
+        &ctx.accounts.config,
+        ctx.accounts.kyc_entry.as_ref(),
+        &depositor,
+    )?;
+
+    // Collateral with a transfer fee arrives short; value what the vault
+    // actually received.
+    let balance_before = ctx.accounts.vault_account.amount;
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.collateral_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.depositor_collateral.to_account_info(),
+                mint: ctx.accounts.collateral_mint.to_account_info(),
+                to: ctx.accounts.vault_account.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        collateral_amount,
+        ctx.accounts.collateral_mint.decimals,
+    )?;
+    ctx.accounts.vault_account.reload()?;
+    let received = ctx
+        .accounts
+        .vault_account
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    let vault = &ctx.accounts.collateral_vault;
+    let (price, exponent) = match vault.collateral_feed_id {
+        Some(feed_id) => {
+            let price_update = ctx
+                .accounts
+                .price_update
+                .as_ref()
+                .ok_or(SssError::CollateralPriceRequired)?;
+            let (price, conf) = pyth_price(
+                price_update,
+                &feed_id,
+                OraclePriceSource::Spot,
+                &Clock::get()?,
+            )?;
+            require!(price.price > 0, SssError::InvalidOraclePrice);
+            if let Some(max_bps) = ctx.accounts.config.max_confidence_bps {
+                require!(
+                    confidence_within_bps(price.price, conf, max_bps),
+                    SssError::OracleConfidenceTooWide
+                );
+            }
+            (price.price, price.exponent)
+        }
+        None => (1, 0),
+    };
+    let mint_amount = collateral_mint_amount(
+        received,
+        ctx.accounts.collateral_mint.decimals,
+        price,
+        exponent,
+        ctx.accounts.mint.decimals,
+        vault.collateral_ratio_bps,
+    )
+    .ok_or(SssError::ArithmeticOverflow)?;
+    require!(mint_amount > 0, SssError::ZeroAmount);
+    require!(
+        mint_amount >= min_mint_amount,
+        SssError::CollateralMintBelowMinimum
+    );
+
+    let accounts = &mut *ctx.accounts;
+    let fee = fee_due(
+        &accounts.config,
+        accounts.fee_schedule.as_deref(),
+        accounts.fee_tier.as_deref(),
+        accounts.fee_treasury.as_deref(),
+        FeeKind::Mint,
+        mint_amount,
+    )?;
+    let net_amount = mint_amount - fee.map_or(0, |(fee, _)| fee);
+    let position_collateral =
+        u64::try_from(u128::from(received) * u128::from(net_amount) / u128::from(mint_amount))
+            .map_err(|_| SssError::ArithmeticOverflow)?;
+
+    accounts
+        .collateral_vault
+        .record_deposit(received, mint_amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    let position = &mut accounts.position;
+    if position.owner == Pubkey::default() {
+        position.vault = accounts.collateral_vault.key();
+        position.owner = depositor;
+        position.bump = ctx.bumps.position;
+    }
+    position
+        .record_deposit(position_collateral, net_amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    issue_less_fee(
+        &mut accounts.config,
+        &accounts.mint,
+        &accounts.to,
+        &accounts.token_program,
+        None,
+        depositor,
+        mint_amount,
+        fee,
+    )?;
+
+    let vault = &ctx.accounts.collateral_vault;
+    emit!(CollateralDeposited {
+        config: vault.config,
+        depositor,
+        to: ctx.accounts.to.key(),
+        collateral_amount: received,
+        minted_amount: mint_amount,
+        total_collateral: vault.total_collateral,
+        total_issued: vault.total_issued,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BurnAndWithdraw<'info> {
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::BURN, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Box<Account<'info, StablecoinConfig>>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Only the holder's own account; the permanent delegate is not used.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+    )]
+    pub from: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Holder's blacklist PDA under `mint` in the transfer hook
+    /// program, verified by re-derivation when the mint uses the hook.
+    pub blacklist: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CollateralVault::SSS_COLLATERAL_VAULT_SEED, config.key().as_ref()],
+        bump = collateral_vault.bump,
+        has_one = collateral_mint,
+        has_one = vault_account,
+    )]
+    pub collateral_vault: Box<Account<'info, CollateralVault>>,
+
+    /// The holder's own position; only collateral they deposited can be
+    /// withdrawn.
+    #[account(
+        mut,
+        seeds = [
+            CollateralPosition::SSS_COLLATERAL_POSITION_SEED,
+            collateral_vault.key().as_ref(),
+            holder.key().as_ref(),
+        ],
+        bump = position.bump,
+    )]
+    pub position: Box<Account<'info, CollateralPosition>>,
+
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Holder's account receiving the collateral.
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = holder,
+    )]
+    pub holder_collateral: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub collateral_token_program: Interface<'info, TokenInterface>,
+
+    /// Rates for the burn fee; see `MintTokens::fee_schedule`.
+    #[account(
+        seeds = [FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.key().as_ref()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
+
+    /// CHECK: The holder's `FeeTierAssignment` address; see
+    /// `MintTokens::fee_tier`.
+    #[account(
+        seeds = [
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.key().as_ref(),
+            holder.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub fee_tier: Option<UncheckedAccount<'info>>,
+
+    /// See `MintTokens::fee_treasury`.
+    #[account(mut, token::mint = mint)]
+    pub fee_treasury: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// Burn `amount` of the holder's stablecoins and release the same share of
+/// their position's collateral as `amount` is of its issuance. Needs no
+/// price, and leaves the position at least as well backed as before, so
+/// holders who bought their stablecoins elsewhere cannot reach the
+/// collateral depositors keep above par.
+///
+/// While fees are on, the burn fee is moved to the fee treasury, as in
+/// `redeem`, with the hook's extra accounts in the remaining accounts. The
+/// whole `amount` leaves the position, but only the burned part's collateral
+/// is released; the fee's share stays in the vault, backing the treasury's
+/// tokens.
+pub fn handler_burn_and_withdraw<'info>(
+    ctx: Context<'_, '_, '_, 'info, BurnAndWithdraw<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    require!(
+        ctx.accounts.config.is_enabled(capability::BURN),
+        SssError::InstructionDisabled
+    );
+    let holder = ctx.accounts.holder.key();
+    if ctx.accounts.config.enable_transfer_hook {
+        require_not_blacklisted(&ctx.accounts.blacklist, &ctx.accounts.mint.key(), &holder)?;
+    }
+
+    let fee = fee_due(
+        &ctx.accounts.config,
+        ctx.accounts.fee_schedule.as_deref(),
+        ctx.accounts.fee_tier.as_deref(),
+        ctx.accounts.fee_treasury.as_deref(),
+        FeeKind::Burn,
+        amount,
+    )?;
+    let burned = amount - fee.map_or(0, |(fee, _)| fee);
+
+    let position = &mut ctx.accounts.position;
+    let (collateral_before, issued_before) = (position.collateral, position.issued);
+    let share = position
+        .withdrawal_for(amount)
+        .ok_or(SssError::CollateralWithdrawalExceedsIssued)?;
+    position
+        .record_withdrawal(share, amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(
+        position.ratio_kept(collateral_before, issued_before),
+        SssError::CollateralRatioNotKept
+    );
+    let collateral_amount =
+        u64::try_from(u128::from(share) * u128::from(burned) / u128::from(amount))
+            .map_err(|_| SssError::ArithmeticOverflow)?;
+    ctx.accounts
+        .collateral_vault
+        .record_withdrawal(collateral_amount, burned)
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    if let Some((fee, treasury)) = fee {
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint,
+            &ctx.accounts.from.to_account_info(),
+            &treasury.to_account_info(),
+            &ctx.accounts.holder.to_account_info(),
+            ctx.remaining_accounts,
+            fee,
+            &[],
+        )?;
+        emit!(FeeCollected {
+            config: ctx.accounts.config.key(),
+            kind: FeeKind::Burn,
+            payer: holder,
+            amount,
+            fee,
+            treasury: treasury.key(),
+            mint_index: ctx.accounts.config.mint_index,
+        });
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.total_burned = config
+        .total_burned
+        .checked_add(burned)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    config.refresh_digest();
+
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.from.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        burned,
+    )?;
+
+    if collateral_amount > 0 {
+        let config_key = ctx.accounts.config.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            CollateralVault::SSS_COLLATERAL_VAULT_SEED,
+            config_key.as_ref(),
+            &[ctx.accounts.collateral_vault.bump],
+        ]];
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.collateral_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_account.to_account_info(),
+                    mint: ctx.accounts.collateral_mint.to_account_info(),
+                    to: ctx.accounts.holder_collateral.to_account_info(),
+                    authority: ctx.accounts.collateral_vault.to_account_info(),
+                },
+            )
+            .with_signer(signer_seeds),
+            collateral_amount,
+            ctx.accounts.collateral_mint.decimals,
+        )?;
+    }
+
+    emit!(TokensBurned {
+        mint: ctx.accounts.mint.key(),
+        from: ctx.accounts.from.key(),
+        amount: burned,
+        burner: holder,
+        new_supply: ctx.accounts.config.current_supply(),
+        from_owner: holder,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+    let vault = &ctx.accounts.collateral_vault;
+    emit!(CollateralWithdrawn {
+        config: vault.config,
+        holder,
+        burned_amount: burned,
+        collateral_amount,
+        total_collateral: vault.total_collateral,
+        total_issued: vault.total_issued,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{ReportAcknowledged, ReportPublished, ReportRequested};
+use crate::state::{ComplianceReport, RegulatorAccess, Role, RoleAccount, StablecoinConfig};
+
+// Request Report
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct RequestReport<'info> {
+    #[account(mut)]
+    pub regulator: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Proves the signer is a registered regulator for this config.
+    #[account(
+        seeds = [
+            RegulatorAccess::SSS_REGULATOR_SEED,
+            config.key().as_ref(),
+            regulator.key().as_ref(),
+        ],
+        bump = regulator_access.bump,
+    )]
+    pub regulator_access: Account<'info, RegulatorAccess>,
+
+    #[account(
+        init,
+        payer = regulator,
+        space = ComplianceReport::SPACE,
+        seeds = [
+            ComplianceReport::SSS_REPORT_SEED,
+            config.key().as_ref(),
+            regulator.key().as_ref(),
+            &period.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub report: Account<'info, ComplianceReport>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_request_report(ctx: Context<RequestReport>, period: u64) -> Result<()> {
+    let report = &mut ctx.accounts.report;
+    report.config = ctx.accounts.config.key();
+    report.regulator = ctx.accounts.regulator.key();
+    report.period = period;
+    report.requested_at = Clock::get()?.unix_timestamp;
+    report.report_hash = None;
+    report.published_by = Pubkey::default();
+    report.published_at = 0;
+    report.acknowledged_at = 0;
+    report.bump = ctx.bumps.report;
+
+    emit!(ReportRequested {
+        config: report.config,
+        regulator: report.regulator,
+        period,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Publish Report
+#[derive(Accounts)]
+pub struct PublishReport<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = report.config == config.key(),
+    )]
+    pub report: Account<'info, ComplianceReport>,
+}
+
+/// Post the hash of the report answering a request. A published hash is
+/// final; a corrected report needs a new period.
+pub fn handler_publish_report(ctx: Context<PublishReport>, report_hash: [u8; 32]) -> Result<()> {
+    let report = &mut ctx.accounts.report;
+    require!(
+        report.report_hash.is_none(),
+        SssError::ReportAlreadyPublished
+    );
+
+    report.report_hash = Some(report_hash);
+    report.published_by = ctx.accounts.admin.key();
+    report.published_at = Clock::get()?.unix_timestamp;
+
+    emit!(ReportPublished {
+        config: report.config,
+        regulator: report.regulator,
+        period: report.period,
+        report_hash,
+        published_by: report.published_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Acknowledge Report
+#[derive(Accounts)]
+pub struct AcknowledgeReport<'info> {
+    pub regulator: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        constraint = report.regulator == regulator.key() @ SssError::Unauthorized,
+        constraint = report.config == config.key(),
+    )]
+    pub report: Account<'info, ComplianceReport>,
+}
+
+/// Regulator co-signature confirming the published hash was received.
+pub fn handler_acknowledge_report(ctx: Context<AcknowledgeReport>) -> Result<()> {
+    let report = &mut ctx.accounts.report;
+    let report_hash = report.report_hash.ok_or(SssError::ReportNotPublished)?;
+    require!(
+        report.acknowledged_at == 0,
+        SssError::ReportAlreadyAcknowledged
+    );
+
+    report.acknowledged_at = Clock::get()?.unix_timestamp;
+
+    emit!(ReportAcknowledged {
+        config: report.config,
+        regulator: report.regulator,
+        period: report.period,
+        report_hash,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
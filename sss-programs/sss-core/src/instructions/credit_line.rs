@@ -0,0 +1,499 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{capability, pause};
+use crate::error::SssError;
+use crate::events::{
+    CreditDefaultFrozen, CreditDrawn, CreditLineClosed, CreditLineSet, CreditRepaid, TokensBurned,
+};
+use crate::instructions::destination_throttle::record_destination_mint;
+use crate::instructions::fees::fee_due;
+use crate::instructions::freeze_account::freeze_as_authority;
+use crate::instructions::kyc::require_kyc;
+use crate::instructions::mint_tokens::issue_less_fee;
+use crate::instructions::reserve_attestation::require_attested_reserves;
+use crate::state::{
+    CreditLine, DestinationThrottle, FeeKind, FeeSchedule, FeeTierAssignment, FreezeExemption,
+    KycEntry, ReserveAttestation, Role, RoleAccount, StablecoinConfig,
+};
+
+// Set Credit Line
+
+/// An Admin opens (or re-sizes) a market maker's intraday credit line.
+/// Calling again replaces the limit and cutoff and keeps the outstanding
+/// balance and any default.
+#[derive(Accounts)]
+pub struct SetCreditLine<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// CHECK: The market maker's wallet; it only signs draws and repayments.
+    pub market_maker: UncheckedAccount<'info>,
+
+    /// CHECK: The market maker's `FreezeExemption` PDA. Must not exist:
+    /// anyone may thaw an exempt wallet's accounts, which would undo a
+    /// default freeze.
+    #[account(
+        seeds = [
+            FreezeExemption::SSS_FREEZE_EXEMPTION_SEED,
+            config.key().as_ref(),
+            market_maker.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub freeze_exemption: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CreditLine::SPACE,
+        seeds = [
+            CreditLine::SSS_CREDIT_LINE_SEED,
+            config.key().as_ref(),
+            market_maker.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub credit_line: Account<'info, CreditLine>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_set_credit_line(
+    ctx: Context<SetCreditLine>,
+    limit: u64,
+    repay_cutoff: u32,
+) -> Result<()> {
+    require!(
+        limit > 0 && CreditLine::cutoff_is_valid(repay_cutoff),
+        SssError::InvalidCreditLine
+    );
+    require!(
+        ctx.accounts.freeze_exemption.data_is_empty(),
+        SssError::CreditLineExemptWallet
+    );
+
+    let line = &mut ctx.accounts.credit_line;
+    line.config = ctx.accounts.config.key();
+    line.market_maker = ctx.accounts.market_maker.key();
+    line.limit = limit;
+    line.repay_cutoff = repay_cutoff;
+    line.set_by = ctx.accounts.admin.key();
+    line.bump = ctx.bumps.credit_line;
+
+    emit!(CreditLineSet {
+        config: line.config,
+        market_maker: line.market_maker,
+        limit,
+        repay_cutoff,
+        set_by: line.set_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Draw Credit
+
+/// The market maker mints against its credit line into one of its own token
+/// accounts. Subject to the supply cap, KYC and destination throttle like
+/// `mint_tokens`, but not to any Minter quota.
+#[derive(Accounts)]
+pub struct DrawCredit<'info> {
+    pub market_maker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::MINT, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            CreditLine::SSS_CREDIT_LINE_SEED,
+            config.key().as_ref(),
+            market_maker.key().as_ref(),
+        ],
+        bump = credit_line.bump,
+    )]
+    pub credit_line: Account<'info, CreditLine>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = market_maker,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: optional oracle account; see `MintTokens::price_update`.
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// KYC approval for the market maker; see `MintTokens::kyc_entry`.
+    pub kyc_entry: Option<Account<'info, KycEntry>>,
+
+    /// `to`'s throttle; see `MintTokens::destination_throttle`.
+    #[account(
+        mut,
+        seeds = [
+            DestinationThrottle::SSS_DESTINATION_THROTTLE_SEED,
+            config.key().as_ref(),
+            to.key().as_ref(),
+        ],
+        bump = destination_throttle.bump,
+    )]
+    pub destination_throttle: Option<Account<'info, DestinationThrottle>>,
+
+    /// See `MintTokens::reserve_attestation`.
+    #[account(
+        seeds = [ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED, config.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+
+    /// See `MintTokens::fee_schedule`.
+    #[account(
+        seeds = [FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.key().as_ref()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
+
+    /// CHECK: `FeeTierAssignment` address of the market maker, who pays the mint
+    /// fee; see `MintTokens::fee_tier`.
+    #[account(
+        seeds = [
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.key().as_ref(),
+            market_maker.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub fee_tier: Option<UncheckedAccount<'info>>,
+
+    /// See `MintTokens::fee_treasury`.
+    #[account(mut, token::mint = mint)]
+    pub fee_treasury: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+pub fn handler_draw_credit(ctx: Context<DrawCredit>, amount: u64) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    let now = Clock::get()?.unix_timestamp;
+
+    let line = &ctx.accounts.credit_line;
+    require!(!line.defaulted, SssError::CreditLineDefaulted);
+    let repay_by = line
+        .draw_deadline(now)
+        .ok_or(SssError::CreditWindowClosed)?;
+    let outstanding = line
+        .outstanding
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(outstanding <= line.limit, SssError::CreditLimitExceeded);
+    require_kyc(
+        &ctx.accounts.config,
+        ctx.accounts.kyc_entry.as_ref(),
+        &ctx.accounts.to.owner,
+    )?;
+
+    let accounts = &mut *ctx.accounts;
+    record_destination_mint(
+        &accounts.config,
+        accounts.destination_throttle.as_mut(),
+        amount,
+        now,
+    )?;
+    require_attested_reserves(
+        &accounts.config,
+        accounts.reserve_attestation.as_ref(),
+        amount,
+        now,
+    )?;
+    let fee = fee_due(
+        &accounts.config,
+        accounts.fee_schedule.as_deref(),
+        accounts.fee_tier.as_deref(),
+        accounts.fee_treasury.as_deref(),
+        FeeKind::Mint,
+        amount,
+    )?;
+    issue_less_fee(
+        &mut accounts.config,
+        &accounts.mint,
+        &accounts.to,
+        &accounts.token_program,
+        accounts.price_update.as_ref(),
+        accounts.market_maker.key(),
+        amount,
+        fee,
+    )?;
+
+    let line = &mut accounts.credit_line;
+    line.outstanding = outstanding;
+    line.repay_by = repay_by;
+
+    emit!(CreditDrawn {
+        config: line.config,
+        market_maker: line.market_maker,
+        to: accounts.to.key(),
+        amount,
+        outstanding,
+        repay_by,
+        mint_index: accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Repay Credit
+
+/// The market maker burns tokens from one of its own accounts against the
+/// outstanding balance. Allowed while paused and after a default, so a
+/// market maker can always settle.
+#[derive(Accounts)]
+pub struct RepayCredit<'info> {
+    pub market_maker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            CreditLine::SSS_CREDIT_LINE_SEED,
+            config.key().as_ref(),
+            market_maker.key().as_ref(),
+        ],
+        bump = credit_line.bump,
+    )]
+    pub credit_line: Account<'info, CreditLine>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = market_maker,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_repay_credit(ctx: Context<RepayCredit>, amount: u64) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    let outstanding = ctx
+        .accounts
+        .credit_line
+        .outstanding
+        .checked_sub(amount)
+        .ok_or(SssError::RepaymentExceedsOutstanding)?;
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.from.to_account_info(),
+        authority: ctx.accounts.market_maker.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token_interface::burn(cpi_ctx, amount)?;
+
+    let config = &mut ctx.accounts.config;
+    config.total_burned = config
+        .total_burned
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    config.refresh_digest();
+
+    let line = &mut ctx.accounts.credit_line;
+    line.outstanding = outstanding;
+    if outstanding == 0 {
+        line.repay_by = 0;
+    }
+
+    let market_maker = ctx.accounts.market_maker.key();
+    emit!(TokensBurned {
+        mint: ctx.accounts.mint.key(),
+        from: ctx.accounts.from.key(),
+        amount,
+        burner: market_maker,
+        new_supply: config.current_supply(),
+        from_owner: market_maker,
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+    emit!(CreditRepaid {
+        config: config.key(),
+        market_maker,
+        from: ctx.accounts.from.key(),
+        amount,
+        outstanding,
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Freeze Defaulted Credit
+
+/// Permissionless freeze of a market maker's token account once its credit
+/// line is past due. The first call marks the line defaulted; keepers call
+/// it once per token account.
+#[derive(Accounts)]
+pub struct FreezeDefaultedCredit<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::FREEZE_THAW, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            CreditLine::SSS_CREDIT_LINE_SEED,
+            config.key().as_ref(),
+            credit_line.market_maker.as_ref(),
+        ],
+        bump = credit_line.bump,
+    )]
+    pub credit_line: Account<'info, CreditLine>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = credit_line.market_maker,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_freeze_defaulted_credit(ctx: Context<FreezeDefaultedCredit>) -> Result<()> {
+    require!(
+        ctx.accounts.config.is_enabled(capability::FREEZE),
+        SssError::InstructionDisabled
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.credit_line.is_past_due(now),
+        SssError::CreditNotPastDue
+    );
+    ctx.accounts.credit_line.defaulted = true;
+
+    freeze_as_authority(
+        &ctx.accounts.token_program,
+        &ctx.accounts.config,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_account,
+    )?;
+
+    let line = &ctx.accounts.credit_line;
+    emit!(CreditDefaultFrozen {
+        config: line.config,
+        market_maker: line.market_maker,
+        account: ctx.accounts.token_account.key(),
+        outstanding: line.outstanding,
+        repay_by: line.repay_by,
+        cranker: ctx.accounts.cranker.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Close Credit Line
+
+/// An Admin closes a repaid credit line, clearing any default. Frozen
+/// accounts stay frozen until a Freezer thaws them.
+#[derive(Accounts)]
+pub struct CloseCreditLine<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            CreditLine::SSS_CREDIT_LINE_SEED,
+            config.key().as_ref(),
+            credit_line.market_maker.as_ref(),
+        ],
+        bump = credit_line.bump,
+        constraint = credit_line.outstanding == 0 @ SssError::CreditOutstanding,
+    )]
+    pub credit_line: Account<'info, CreditLine>,
+}
+
+pub fn handler_close_credit_line(ctx: Context<CloseCreditLine>) -> Result<()> {
+    emit!(CreditLineClosed {
+        config: ctx.accounts.config.key(),
+        market_maker: ctx.accounts.credit_line.market_maker,
+        closed_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
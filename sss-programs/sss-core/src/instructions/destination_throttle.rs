@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::error::SssError;
+use crate::state::{DestinationThrottle, StablecoinConfig};
+
+/// Create the throttle for a token account so minters can issue into it
+/// while `destination_mint_limit` is set. Permissionless: the throttle only
+/// ever restricts, so whoever wants the account funded pays the rent.
+#[derive(Accounts)]
+pub struct OpenDestinationThrottle<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(constraint = token_account.mint == config.mint @ SssError::MintMismatch)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = DestinationThrottle::SPACE,
+        seeds = [
+            DestinationThrottle::SSS_DESTINATION_THROTTLE_SEED,
+            config.key().as_ref(),
+            token_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub destination_throttle: Account<'info, DestinationThrottle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_open_destination_throttle(ctx: Context<OpenDestinationThrottle>) -> Result<()> {
+    let throttle = &mut ctx.accounts.destination_throttle;
+    throttle.config = ctx.accounts.config.key();
+    throttle.token_account = ctx.accounts.token_account.key();
+    throttle.window_start = 0;
+    throttle.window_minted = 0;
+    throttle.bump = ctx.bumps.destination_throttle;
+    Ok(())
+}
+
+/// While `config.destination_mint_limit` is set, fails unless `throttle`
+/// (already checked to be the destination's PDA) has room for `amount`,
+/// and records it. A no-op otherwise.
+pub(crate) fn record_destination_mint(
+    config: &StablecoinConfig,
+    throttle: Option<&mut Account<DestinationThrottle>>,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    let Some(limit) = config.destination_mint_limit else {
+        return Ok(());
+    };
+    let throttle = throttle.ok_or(SssError::DestinationThrottleRequired)?;
+    let within_limit = throttle
+        .allows(amount, limit, now)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_limit, SssError::DestinationMintLimitExceeded);
+    throttle
+        .record(amount, now)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// What `throttle`'s token account may still receive at `now` while
+/// `config.destination_mint_limit` is set.
+pub(crate) fn destination_headroom(
+    config: &StablecoinConfig,
+    throttle: Option<&DestinationThrottle>,
+    now: i64,
+) -> Option<u64> {
+    let limit = config.destination_mint_limit?;
+    throttle.map(|throttle| limit.saturating_sub(throttle.window_minted_at(now)))
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::state::{EventQueue, QueuedEventKind, StablecoinConfig};
+
+/// Push an event into the config's `EventQueue` when recording is enabled.
+/// A no-op otherwise; fails if recording is enabled and the queue was not
+/// passed, so callers cannot skip it.
+pub(crate) fn record_event(
+    config: &Account<StablecoinConfig>,
+    event_queue: Option<&mut Account<EventQueue>>,
+    kind: QueuedEventKind,
+    subject: Pubkey,
+    actor: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if !config.event_queue_enabled {
+        return Ok(());
+    }
+    let queue = event_queue.ok_or(SssError::EventQueueRequired)?;
+    queue
+        .ring
+        .push(kind, subject, actor, amount, Clock::get()?.slot);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::error::SssError;
+use crate::state::{FeeKind, FeeSchedule, FeeTierAssignment, StablecoinConfig};
+
+/// The `kind` fee on `amount` under the config's `FeeSchedule`, at the rate
+/// of the payer's `fee_tier`, and the treasury it is paid into. `None` while
+/// `config.fee_treasury` is unset or the fee rounds to zero.
+///
+/// The accounts are already checked to be the config's PDAs; `fee_treasury`
+/// must be the configured treasury. `fee_tier` is the payer's
+/// `FeeTierAssignment` address whether or not it exists, so a wallet cannot
+/// drop its tier by leaving the account out: tier 0 applies only when that
+/// address is empty.
+pub(crate) fn fee_due<'a, 'info>(
+    config: &StablecoinConfig,
+    fee_schedule: Option<&Account<FeeSchedule>>,
+    fee_tier: Option<&AccountInfo>,
+    fee_treasury: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    kind: FeeKind,
+    amount: u64,
+) -> Result<Option<(u64, &'a InterfaceAccount<'info, TokenAccount>)>> {
+    let Some(expected_treasury) = config.fee_treasury else {
+        return Ok(None);
+    };
+    let fee_schedule = fee_schedule.ok_or(SssError::FeeScheduleRequired)?;
+    let fee_tier = fee_tier.ok_or(SssError::FeeTierRequired)?;
+    let assignment = if fee_tier.data_is_empty() {
+        None
+    } else {
+        require_keys_eq!(
+            *fee_tier.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        Some(FeeTierAssignment::try_deserialize(
+            &mut &fee_tier.try_borrow_data()?[..],
+        )?)
+    };
+    let fee = fee_schedule
+        .fee(assignment.as_ref(), kind, amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    if fee == 0 {
+        return Ok(None);
+    }
+    let treasury = fee_treasury
+        .filter(|treasury| treasury.key() == expected_treasury)
+        .ok_or(SssError::InvalidFeeTreasury)?;
+    Ok(Some((fee, treasury)))
+}
@@ -3,8 +3,10 @@ use anchor_spl::token_interface::{
     self, FreezeAccount as FreezeAccountCpi, Mint, TokenAccount, TokenInterface,
 };
 
+use crate::constants::{capability, pause};
 use crate::error::SssError;
-use crate::events::AccountFrozen;
+use crate::events::{AccountFrozen, BlacklistedAccountFrozen};
+use crate::instructions::blacklist::is_blacklisted;
 use crate::state::{Role, RoleAccount, StablecoinConfig};
 
 #[derive(Accounts)]
@@ -14,11 +16,13 @@ pub struct FreezeTokenAccount<'info> {
     #[account(
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
         bump = config.bump,
-        constraint = !config.paused @ SssError::Paused,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::FREEZE_THAW, Clock::get()?.unix_timestamp) @ SssError::Paused,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     #[account(
+        mut,
         seeds = [
             RoleAccount::SSS_ROLE_SEED,
             config.key().as_ref(),
@@ -26,6 +30,7 @@ pub struct FreezeTokenAccount<'info> {
             &[Role::Freezer.as_u8()],
         ],
         bump = freezer_role.bump,
+        constraint = freezer_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
     )]
     pub freezer_role: Account<'info, RoleAccount>,
 
@@ -44,28 +49,121 @@ pub struct FreezeTokenAccount<'info> {
 }
 
 pub fn handler_freeze_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
-    let mint_key = ctx.accounts.mint.key();
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        StablecoinConfig::SSS_CONFIG_SEED,
-        mint_key.as_ref(),
-        &[ctx.accounts.config.bump],
-    ]];
-
-    let cpi_accounts = FreezeAccountCpi {
-        account: ctx.accounts.token_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        authority: ctx.accounts.config.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
-        .with_signer(signer_seeds);
-
-    token_interface::freeze_account(cpi_ctx)?;
+    require!(
+        ctx.accounts.config.is_enabled(capability::FREEZE),
+        SssError::InstructionDisabled
+    );
+    require!(
+        ctx.accounts
+            .freezer_role
+            .record_action(Clock::get()?.unix_timestamp),
+        SssError::ActionQuotaExceeded
+    );
+    freeze_as_authority(
+        &ctx.accounts.token_program,
+        &ctx.accounts.config,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_account,
+    )?;
 
     emit!(AccountFrozen {
         mint: ctx.accounts.mint.key(),
         account: ctx.accounts.token_account.key(),
         freezer: ctx.accounts.freezer.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Permissionless freeze of a token account whose owner the transfer hook
+/// has blacklisted, so keepers draining the hook's `EnforcementQueue` do not
+/// wait on a Freezer. `FreezeExemption` does not apply to the blacklist.
+#[derive(Accounts)]
+pub struct FreezeBlacklisted<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::FREEZE_THAW, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: The hook's blacklist PDA for `token_account.owner`; verified
+    /// in the handler.
+    pub blacklist_entry: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_freeze_blacklisted(ctx: Context<FreezeBlacklisted>) -> Result<()> {
+    require!(
+        ctx.accounts.config.is_enabled(capability::FREEZE),
+        SssError::InstructionDisabled
+    );
+    require!(
+        is_blacklisted(
+            &ctx.accounts.blacklist_entry,
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.token_account.owner,
+        )?,
+        SssError::NotBlacklisted
+    );
+    freeze_as_authority(
+        &ctx.accounts.token_program,
+        &ctx.accounts.config,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_account,
+    )?;
+
+    emit!(BlacklistedAccountFrozen {
+        mint: ctx.accounts.mint.key(),
+        account: ctx.accounts.token_account.key(),
+        owner: ctx.accounts.token_account.owner,
+        cranker: ctx.accounts.cranker.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
     });
 
     Ok(())
 }
+
+/// Freeze `token_account`, signed by the config PDA as the mint's freeze
+/// authority. Shared with `receivership_freeze` and `freeze_blacklisted`.
+pub(crate) fn freeze_as_authority<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    config: &Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<()> {
+    let mint_key = mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        StablecoinConfig::SSS_CONFIG_SEED,
+        mint_key.as_ref(),
+        &[config.bump],
+    ]];
+
+    let cpi_accounts = FreezeAccountCpi {
+        account: token_account.to_account_info(),
+        mint: mint.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx =
+        CpiContext::new(token_program.to_account_info(), cpi_accounts).with_signer(signer_seeds);
+
+    token_interface::freeze_account(cpi_ctx)
+}
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{capability, pause, MAX_FREEZE_BATCH};
+use crate::error::SssError;
+use crate::events::{AccountFrozen, AccountThawed};
+use crate::instructions::freeze_account::freeze_as_authority;
+use crate::instructions::thaw_account::thaw_as_authority;
+use crate::pda::find_freeze_exemption_address;
+use crate::state::{Role, RoleAccount, StablecoinConfig};
+
+/// Freeze many token accounts in one transaction. Remaining accounts are
+/// `(token_account, freeze_exemption)` pairs, where `freeze_exemption` is the
+/// owner's `FreezeExemption` PDA whether or not it exists. Exempt and
+/// already-frozen accounts are skipped, so a batch built from a stale
+/// holder list still lands.
+#[derive(Accounts)]
+pub struct FreezeTokenAccountsBatch<'info> {
+    pub freezer: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::FREEZE_THAW, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            freezer.key().as_ref(),
+            &[Role::Freezer.as_u8()],
+        ],
+        bump = freezer_role.bump,
+        constraint = freezer_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub freezer_role: Account<'info, RoleAccount>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_freeze_accounts_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FreezeTokenAccountsBatch<'info>>,
+) -> Result<()> {
+    let pairs = ctx.remaining_accounts.len() / 2;
+    require!(
+        ctx.remaining_accounts.len().is_multiple_of(2) && (1..=MAX_FREEZE_BATCH).contains(&pairs),
+        SssError::InvalidFreezeBatch
+    );
+    require!(
+        ctx.accounts.config.is_enabled(capability::FREEZE),
+        SssError::InstructionDisabled
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let config_key = ctx.accounts.config.key();
+
+    for pair in ctx.remaining_accounts.chunks_exact(2) {
+        let token_account = load_token_account(&pair[0], &ctx.accounts.config)?;
+        let (exemption, _) = find_freeze_exemption_address(&config_key, &token_account.owner);
+        require_keys_eq!(pair[1].key(), exemption, SssError::FreezeExemptionMismatch);
+        let exempt = pair[1].owner == &crate::ID && !pair[1].data_is_empty();
+        if exempt || token_account.is_frozen() {
+            continue;
+        }
+
+        require!(
+            ctx.accounts.freezer_role.record_action(now),
+            SssError::ActionQuotaExceeded
+        );
+        freeze_as_authority(
+            &ctx.accounts.token_program,
+            &ctx.accounts.config,
+            &ctx.accounts.mint,
+            &token_account,
+        )?;
+
+        emit!(AccountFrozen {
+            mint: ctx.accounts.mint.key(),
+            account: token_account.key(),
+            freezer: ctx.accounts.freezer.key(),
+            config: config_key,
+            mint_index: ctx.accounts.config.mint_index,
+        });
+    }
+
+    Ok(())
+}
+
+/// Thaw many token accounts in one transaction. Remaining accounts are the
+/// token accounts; ones that are not frozen are skipped.
+#[derive(Accounts)]
+pub struct ThawTokenAccountsBatch<'info> {
+    pub freezer: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::FREEZE_THAW, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            freezer.key().as_ref(),
+            &[Role::Freezer.as_u8()],
+        ],
+        bump = freezer_role.bump,
+        constraint = freezer_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub freezer_role: Account<'info, RoleAccount>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_thaw_accounts_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ThawTokenAccountsBatch<'info>>,
+) -> Result<()> {
+    require!(
+        (1..=MAX_FREEZE_BATCH).contains(&ctx.remaining_accounts.len()),
+        SssError::InvalidFreezeBatch
+    );
+    require!(
+        ctx.accounts.config.is_enabled(capability::THAW),
+        SssError::InstructionDisabled
+    );
+    let config_key = ctx.accounts.config.key();
+
+    for info in ctx.remaining_accounts {
+        let token_account = load_token_account(info, &ctx.accounts.config)?;
+        if !token_account.is_frozen() {
+            continue;
+        }
+
+        thaw_as_authority(
+            &ctx.accounts.token_program,
+            &ctx.accounts.config,
+            &ctx.accounts.mint,
+            &token_account,
+        )?;
+
+        emit!(AccountThawed {
+            mint: ctx.accounts.mint.key(),
+            account: token_account.key(),
+            freezer: ctx.accounts.freezer.key(),
+            config: config_key,
+            mint_index: ctx.accounts.config.mint_index,
+        });
+    }
+
+    Ok(())
+}
+
+/// Deserialize a remaining account as a token account of the config's mint.
+fn load_token_account<'info>(
+    info: &'info AccountInfo<'info>,
+    config: &StablecoinConfig,
+) -> Result<InterfaceAccount<'info, TokenAccount>> {
+    let token_account = InterfaceAccount::<TokenAccount>::try_from(info)?;
+    require_keys_eq!(token_account.mint, config.mint, SssError::MintMismatch);
+    Ok(token_account)
+}
@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::pause;
+use crate::error::SssError;
+use crate::instructions::destination_throttle::{destination_headroom, record_destination_mint};
+use crate::instructions::fees::fee_due;
+use crate::instructions::kyc::require_kyc;
+use crate::instructions::mint_tokens::issue_less_fee;
+use crate::instructions::reserve_attestation::require_attested_reserves;
+use crate::state::{
+    DestinationThrottle, FeeKind, FeeSchedule, FeeTierAssignment, KycEntry, MinterKeys,
+    ReserveAttestation, Role, RoleAccount, StablecoinConfig,
+};
+use crate::views::{MintOutcome, VIEW_LAYOUT_VERSION};
+
+/// `mint_tokens` signed by a Minter's hot key. Within the hot window quota
+/// the hot key signs alone; larger amounts need the cold key as co-signer
+/// and do not consume the hot window. The cold key can also mint on its own
+/// through `mint_tokens`.
+#[derive(Accounts)]
+pub struct MintTokensHot<'info> {
+    pub hot: Signer<'info>,
+
+    /// Required only when `amount` exceeds the hot key's remaining window.
+    pub cold: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::MINT, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [MinterKeys::SSS_MINTER_KEYS_SEED, minter_role.key().as_ref()],
+        bump = minter_keys.bump,
+        constraint = minter_keys.hot_key == hot.key() @ SssError::Unauthorized,
+    )]
+    pub minter_keys: Account<'info, MinterKeys>,
+
+    /// The cold key's Minter role; quota is tracked here.
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            minter_keys.cold_key.as_ref(),
+            &[Role::Minter.as_u8()],
+        ],
+        bump = minter_role.bump,
+        constraint = minter_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub minter_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: optional oracle account; see `MintTokens::price_update`.
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// KYC approval for the owner of `to`; see `MintTokens::kyc_entry`.
+    pub kyc_entry: Option<Account<'info, KycEntry>>,
+
+    /// `to`'s throttle; see `MintTokens::destination_throttle`.
+    #[account(
+        mut,
+        seeds = [
+            DestinationThrottle::SSS_DESTINATION_THROTTLE_SEED,
+            config.key().as_ref(),
+            to.key().as_ref(),
+        ],
+        bump = destination_throttle.bump,
+    )]
+    pub destination_throttle: Option<Account<'info, DestinationThrottle>>,
+
+    /// See `MintTokens::reserve_attestation`.
+    #[account(
+        seeds = [ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED, config.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+
+    /// See `MintTokens::fee_schedule`.
+    #[account(
+        seeds = [FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.key().as_ref()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
+
+    /// CHECK: `FeeTierAssignment` address of the owner of `to`, who pays the mint
+    /// fee; see `MintTokens::fee_tier`.
+    #[account(
+        seeds = [
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.key().as_ref(),
+            to.owner.as_ref(),
+        ],
+        bump,
+    )]
+    pub fee_tier: Option<UncheckedAccount<'info>>,
+
+    /// See `MintTokens::fee_treasury`.
+    #[account(mut, token::mint = mint)]
+    pub fee_treasury: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// Returns the remaining headroom, including the hot key's window, as a
+/// [`MintOutcome`].
+pub fn handler_mint_tokens_hot(ctx: Context<MintTokensHot>, amount: u64) -> Result<MintOutcome> {
+    require!(amount > 0, SssError::ZeroAmount);
+    // No receipt variant for hot keys; large issuances go through the cold key.
+    if let Some(threshold) = ctx.accounts.config.receipt_threshold {
+        require!(amount < threshold, SssError::MintReceiptRequired);
+    }
+    require!(
+        !ctx.accounts.config.requires_mint_approval(amount),
+        SssError::LargeMintApprovalRequired
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let within_window = ctx
+        .accounts
+        .minter_keys
+        .hot_allows(amount, now)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    let cold_signed = ctx
+        .accounts
+        .cold
+        .as_ref()
+        .is_some_and(|cold| cold.key() == ctx.accounts.minter_keys.cold_key);
+    require!(within_window || cold_signed, SssError::HotQuotaExceeded);
+
+    ctx.accounts.minter_role.roll_quota_period(now);
+    let within_quota = ctx
+        .accounts
+        .minter_role
+        .quota_allows(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_quota, SssError::QuotaExceeded);
+    let within_rate = ctx
+        .accounts
+        .minter_role
+        .rate_allows(amount, now)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_rate, SssError::MintRateLimitExceeded);
+    require!(
+        ctx.accounts.minter_role.hours_allow(now),
+        SssError::OutsideOperatingHours
+    );
+    require_kyc(
+        &ctx.accounts.config,
+        ctx.accounts.kyc_entry.as_ref(),
+        &ctx.accounts.to.owner,
+    )?;
+
+    let accounts = &mut *ctx.accounts;
+    record_destination_mint(
+        &accounts.config,
+        accounts.destination_throttle.as_mut(),
+        amount,
+        now,
+    )?;
+    require_attested_reserves(
+        &accounts.config,
+        accounts.reserve_attestation.as_ref(),
+        amount,
+        now,
+    )?;
+    let fee = fee_due(
+        &accounts.config,
+        accounts.fee_schedule.as_deref(),
+        accounts.fee_tier.as_deref(),
+        accounts.fee_treasury.as_deref(),
+        FeeKind::Mint,
+        amount,
+    )?;
+    let issued = issue_less_fee(
+        &mut accounts.config,
+        &accounts.mint,
+        &accounts.to,
+        &accounts.token_program,
+        accounts.price_update.as_ref(),
+        accounts.hot.key(),
+        amount,
+        fee,
+    )?;
+
+    if !cold_signed {
+        accounts
+            .minter_keys
+            .record_hot(amount, now)
+            .ok_or(SssError::ArithmeticOverflow)?;
+    }
+    accounts.minter_role.amount_minted = accounts
+        .minter_role
+        .amount_minted
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    accounts
+        .minter_role
+        .record_rate(amount, now)
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    let hot_remaining = accounts.minter_keys.hot_remaining_at(now);
+    Ok(MintOutcome {
+        layout_version: VIEW_LAYOUT_VERSION,
+        remaining_quota: accounts.minter_role.remaining_quota(),
+        remaining_window_quota: Some(
+            accounts
+                .minter_role
+                .remaining_window_quota(now)
+                .map_or(hot_remaining, |remaining| remaining.min(hot_remaining)),
+        ),
+        remaining_destination_limit: destination_headroom(
+            &accounts.config,
+            accounts.destination_throttle.as_deref(),
+            now,
+        ),
+        remaining_cap: issued.remaining_cap,
+    })
+}
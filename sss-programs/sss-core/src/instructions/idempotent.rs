@@ -0,0 +1,174 @@
+//! Idempotent variants of mint, burn and seize.
+//!
+//! Each takes a caller-chosen `nonce` and creates a `ConsumedNonce` PDA keyed
+//! by `[config, operator, nonce]`. A retried submission with the same nonce
+//! fails at account creation, so back-office retries under RPC uncertainty
+//! cannot double-execute.
+
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::instructions::burn_tokens::*;
+use crate::instructions::mint_tokens::*;
+use crate::instructions::seize::*;
+use crate::state::{ConsumedNonce, NonceOperation};
+
+#[derive(Accounts)]
+#[instruction(amount: u64, nonce: u64)]
+pub struct MintTokensIdempotent<'info> {
+    pub issuance: MintTokens<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ConsumedNonce::SPACE,
+        seeds = [
+            ConsumedNonce::SSS_NONCE_SEED,
+            issuance.config.key().as_ref(),
+            issuance.minter.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub consumed_nonce: Account<'info, ConsumedNonce>,
+
+    /// Pays nonce rent; usually the operator itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_mint_tokens_idempotent(
+    ctx: Context<MintTokensIdempotent>,
+    amount: u64,
+    nonce: u64,
+) -> Result<()> {
+    // Large issuances still need a receipt; this path does not create one.
+    if let Some(threshold) = ctx.accounts.issuance.config.receipt_threshold {
+        require!(amount < threshold, SssError::MintReceiptRequired);
+    }
+    ctx.accounts.issuance.issue(amount)?;
+
+    record_nonce(
+        &mut ctx.accounts.consumed_nonce,
+        ctx.accounts.issuance.config.key(),
+        ctx.accounts.issuance.minter.key(),
+        nonce,
+        NonceOperation::Mint,
+        amount,
+        ctx.bumps.consumed_nonce,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, nonce: u64)]
+pub struct BurnTokensIdempotent<'info> {
+    pub burn: BurnTokens<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ConsumedNonce::SPACE,
+        seeds = [
+            ConsumedNonce::SSS_NONCE_SEED,
+            burn.config.key().as_ref(),
+            burn.burner.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub consumed_nonce: Account<'info, ConsumedNonce>,
+
+    /// Pays nonce rent; usually the operator itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_burn_tokens_idempotent(
+    ctx: Context<BurnTokensIdempotent>,
+    amount: u64,
+    nonce: u64,
+) -> Result<()> {
+    ctx.accounts.burn.burn(amount)?;
+
+    record_nonce(
+        &mut ctx.accounts.consumed_nonce,
+        ctx.accounts.burn.config.key(),
+        ctx.accounts.burn.burner.key(),
+        nonce,
+        NonceOperation::Burn,
+        amount,
+        ctx.bumps.consumed_nonce,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, nonce: u64)]
+pub struct SeizeIdempotent<'info> {
+    pub seizure: Seize<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ConsumedNonce::SPACE,
+        seeds = [
+            ConsumedNonce::SSS_NONCE_SEED,
+            seizure.config.key().as_ref(),
+            seizure.seizer.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub consumed_nonce: Account<'info, ConsumedNonce>,
+
+    /// Pays nonce rent; usually the operator itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts are forwarded to the transfer hook exactly as in `seize`.
+pub fn handler_seize_idempotent<'info>(
+    ctx: Context<'_, '_, '_, 'info, SeizeIdempotent<'info>>,
+    amount: u64,
+    nonce: u64,
+    reason: String,
+) -> Result<()> {
+    let record_bump = ctx.bumps.seizure.seizure_record;
+    ctx.accounts
+        .seizure
+        .seize(amount, reason, record_bump, ctx.remaining_accounts)?;
+
+    record_nonce(
+        &mut ctx.accounts.consumed_nonce,
+        ctx.accounts.seizure.config.key(),
+        ctx.accounts.seizure.seizer.key(),
+        nonce,
+        NonceOperation::Seize,
+        amount,
+        ctx.bumps.consumed_nonce,
+    )
+}
+
+fn record_nonce(
+    consumed: &mut ConsumedNonce,
+    config: Pubkey,
+    operator: Pubkey,
+    nonce: u64,
+    operation: NonceOperation,
+    amount: u64,
+    bump: u8,
+) -> Result<()> {
+    consumed.config = config;
+    consumed.operator = operator;
+    consumed.nonce = nonce;
+    consumed.operation = operation;
+    consumed.amount = amount;
+    consumed.consumed_at = Clock::get()?.unix_timestamp;
+    consumed.bump = bump;
+    Ok(())
+}
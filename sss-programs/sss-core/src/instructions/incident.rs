@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{ConfigField, ConfigValue, IncidentStatusChanged};
+use crate::instructions::admin::update_config::emit_config_diff;
+use crate::state::{Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+pub struct SetIncidentStatus<'info> {
+    pub authority: Signer<'info>,
+
+    /// No pause check — incidents are usually declared while paused.
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Admin or Pauser role PDA of `authority`.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            authority.key().as_ref(),
+            &[authority_role.role.as_u8()],
+        ],
+        bump = authority_role.bump,
+        constraint = matches!(authority_role.role, Role::Admin | Role::Pauser) @ SssError::Unauthorized,
+        constraint = authority_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+}
+
+/// Raise or clear the incident flag and point wallets at a status document.
+/// Clearing can keep a URI, e.g. for the post-mortem; pass an empty string
+/// to drop it.
+pub fn handler_set_incident_status(
+    ctx: Context<SetIncidentStatus>,
+    active: bool,
+    uri: String,
+) -> Result<()> {
+    require!(
+        uri.len() <= StablecoinConfig::MAX_INCIDENT_URI_LEN,
+        SssError::UriTooLong
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.config;
+    let was_active = config.incident_flag;
+    let old_uri = std::mem::replace(&mut config.incident_uri, uri);
+    config.incident_flag = active;
+    config.incident_updated_at = now;
+    config.refresh_digest();
+
+    let authority = ctx.accounts.authority.key();
+    emit_config_diff(
+        config,
+        authority,
+        ConfigField::IncidentFlag,
+        ConfigValue::Bool(was_active),
+        ConfigValue::Bool(active),
+    );
+    emit_config_diff(
+        config,
+        authority,
+        ConfigField::IncidentUri,
+        ConfigValue::Text(old_uri),
+        ConfigValue::Text(config.incident_uri.clone()),
+    );
+
+    emit!(IncidentStatusChanged {
+        mint: config.mint,
+        active,
+        uri: config.incident_uri.clone(),
+        updated_by: ctx.accounts.authority.key(),
+        updated_at: now,
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
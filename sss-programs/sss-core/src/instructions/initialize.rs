@@ -1,29 +1,25 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenInterface};
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::state::{AccountState, Mint as MintState};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use anchor_spl::token_interface::{
+    default_account_state_initialize, initialize_mint2, metadata_pointer_initialize,
+    permanent_delegate_initialize, token_metadata_initialize, transfer_hook_initialize,
+    DefaultAccountStateInitialize, InitializeMint2, MetadataPointerInitialize, Mint,
+    PermanentDelegateInitialize, TokenInterface, TokenMetadataInitialize, TransferHookInitialize,
+};
 
+use crate::args::InitializeArgs;
+use crate::constants::TRANSFER_HOOK_PROGRAM_ID;
 use crate::error::SssError;
 use crate::events::StablecoinInitialized;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
-
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct InitializeArgs {
-    pub preset: u8,
-    pub name: String,
-    pub symbol: String,
-    pub uri: String,
-    pub decimals: u8,
-    pub supply_cap: Option<u64>,
-    /// Override preset default for permanent delegate. If None, derived from preset.
-    pub enable_permanent_delegate: Option<bool>,
-    /// Override preset default for transfer hook. If None, derived from preset.
-    pub enable_transfer_hook: Option<bool>,
-    /// Override preset default for default-frozen accounts. If None, derived from preset.
-    pub default_account_frozen: Option<bool>,
-    /// Optional Pyth oracle feed ID (32-byte array) for oracle-gated supply caps.
-    /// If None, oracle-adjusted minting is disabled for this stablecoin.
-    /// Can be set later via `update_oracle_feed`.
-    pub oracle_feed_id: Option<[u8; 32]>,
-}
+use crate::instructions::verify_authorities::observe_mint_authorities;
+use crate::state::{
+    ActionWindow, DeploymentCounter, OracleKind, OraclePriceSource, Role, RoleAccount,
+    RoleDirectory, StablecoinConfig,
+};
 
 #[derive(Accounts)]
 #[instruction(args: InitializeArgs)]
@@ -57,67 +53,492 @@ pub struct Initialize<'info> {
     )]
     pub admin_role: Account<'info, RoleAccount>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = RoleDirectory::SPACE,
+        seeds = [
+            RoleDirectory::SSS_ROLE_DIRECTORY_SEED,
+            config.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump,
+    )]
+    pub admin_directory: Account<'info, RoleDirectory>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// When passed, the config takes the next `mint_index` from it;
+    /// otherwise `mint_index` stays zero.
+    #[account(
+        mut,
+        seeds = [DeploymentCounter::SSS_DEPLOYMENT_COUNTER_SEED],
+        bump = deployment_counter.bump,
+    )]
+    pub deployment_counter: Option<Account<'info, DeploymentCounter>>,
 }
 
+/// Create the config for a mint the SDK set up beforehand. The mint must
+/// already name the config PDA as every authority `verify_authorities`
+/// checks, for the features `args` resolves to, and match its decimals;
+/// otherwise the stablecoin would be created unable to mint or enforce.
 pub fn handler_initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+    let features = MintFeatures::resolve(&args)?;
+    require!(
+        ctx.accounts.mint.decimals == args.decimals,
+        SssError::DecimalsMismatch
+    );
+    let observed = observe_mint_authorities(&ctx.accounts.mint);
+    let accounts = &mut *ctx.accounts;
+    NewStablecoin {
+        authority: accounts.authority.key(),
+        mint: accounts.mint.key(),
+        config: &mut accounts.config,
+        config_bump: ctx.bumps.config,
+        admin_role: &mut accounts.admin_role,
+        admin_role_bump: ctx.bumps.admin_role,
+        admin_directory: &mut accounts.admin_directory,
+        admin_directory_bump: ctx.bumps.admin_directory,
+        deployment_counter: accounts.deployment_counter.as_mut(),
+    }
+    .write(args, features)?;
+
+    let config = &ctx.accounts.config;
     require!(
-        args.preset >= 1 && args.preset <= 3,
-        SssError::InvalidPreset
+        config.authorities_intact(&config.key(), &observed),
+        SssError::MintAuthorityMismatch
     );
-    require!(args.name.len() <= 32, SssError::NameTooLong);
-    require!(args.symbol.len() <= 10, SssError::SymbolTooLong);
-    require!(args.uri.len() <= 200, SssError::UriTooLong);
-
-    // Derive feature flags from preset, allowing explicit overrides
-    let (default_perm_delegate, default_hook, default_frozen) = match args.preset {
-        1 => (true, false, false), // SSS-1: minimal
-        2 => (true, true, true),   // SSS-2: compliant (hook + frozen by default)
-        3 => (true, false, false), // SSS-3: private (confidential transfers, no hook)
-        _ => unreachable!(),       // already validated above
-    };
-
-    let config = &mut ctx.accounts.config;
-    config.authority = ctx.accounts.authority.key();
-    config.mint = ctx.accounts.mint.key();
-    config.preset = args.preset;
-    config.paused = false;
-    config.supply_cap = args.supply_cap;
-    config.total_minted = 0;
-    config.total_burned = 0;
-    config.bump = ctx.bumps.config;
-    config.name = args.name;
-    config.symbol = args.symbol;
-    config.uri = args.uri;
-    config.decimals = args.decimals;
-    config.enable_permanent_delegate = args
-        .enable_permanent_delegate
-        .unwrap_or(default_perm_delegate);
-    config.enable_transfer_hook = args.enable_transfer_hook.unwrap_or(default_hook);
-    config.default_account_frozen = args.default_account_frozen.unwrap_or(default_frozen);
-    config.admin_count = 1;
-    config.oracle_feed_id = args.oracle_feed_id;
-
-    let admin_role = &mut ctx.accounts.admin_role;
-    admin_role.config = config.key();
-    admin_role.address = ctx.accounts.authority.key();
-    admin_role.role = Role::Admin;
-    admin_role.granted_by = ctx.accounts.authority.key();
-    admin_role.granted_at = Clock::get()?.unix_timestamp;
-    admin_role.bump = ctx.bumps.admin_role;
-    admin_role.mint_quota = None;
-    admin_role.amount_minted = 0;
-
-    emit!(StablecoinInitialized {
-        mint: config.mint,
-        authority: config.authority,
-        preset: config.preset,
-        supply_cap: config.supply_cap,
-        name: config.name.clone(),
-        symbol: config.symbol.clone(),
-        decimals: config.decimals,
-    });
+    Ok(())
+}
+
+// Initialize With Mint
+
+#[derive(Accounts)]
+#[instruction(args: InitializeArgs)]
+pub struct InitializeWithMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = StablecoinConfig::compute_space(&args.name, &args.symbol, &args.uri),
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Fresh keypair for the mint; created and initialized by this
+    /// instruction, with `authority` paying its rent.
+    #[account(mut)]
+    pub mint: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RoleAccount::ROLE_SPACE,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            authority.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RoleDirectory::SPACE,
+        seeds = [
+            RoleDirectory::SSS_ROLE_DIRECTORY_SEED,
+            config.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump,
+    )]
+    pub admin_directory: Account<'info, RoleDirectory>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+
+    /// When passed, the config takes the next `mint_index` from it;
+    /// otherwise `mint_index` stays zero.
+    #[account(
+        mut,
+        seeds = [DeploymentCounter::SSS_DEPLOYMENT_COUNTER_SEED],
+        bump = deployment_counter.bump,
+    )]
+    pub deployment_counter: Option<Account<'info, DeploymentCounter>>,
+}
+
+/// `initialize`, but creating the Token-2022 mint in the same transaction
+/// instead of taking one the SDK set up beforehand, so there is no window
+/// in which the mint exists with a wallet as its authority. The preset's
+/// extensions are configured with the config PDA as every authority, and
+/// the mint carries Token-2022 metadata from `args`. SSS-3 mints still go
+/// through `initialize`, since their confidential transfer extension is set
+/// up client-side.
+pub fn handler_initialize_with_mint(
+    ctx: Context<InitializeWithMint>,
+    args: InitializeArgs,
+) -> Result<()> {
+    let features = MintFeatures::resolve(&args)?;
+    require!(args.preset != 3, SssError::PresetNeedsExternalMint);
+    ctx.accounts
+        .create_mint(ctx.bumps.config, &args, features)?;
+
+    let accounts = &mut *ctx.accounts;
+    NewStablecoin {
+        authority: accounts.authority.key(),
+        mint: accounts.mint.key(),
+        config: &mut accounts.config,
+        config_bump: ctx.bumps.config,
+        admin_role: &mut accounts.admin_role,
+        admin_role_bump: ctx.bumps.admin_role,
+        admin_directory: &mut accounts.admin_directory,
+        admin_directory_bump: ctx.bumps.admin_directory,
+        deployment_counter: accounts.deployment_counter.as_mut(),
+    }
+    .write(args, features)
+}
+
+impl InitializeWithMint<'_> {
+    /// Create the mint account, initialize its extensions and the mint
+    /// itself, then write its metadata signed by the config PDA as mint
+    /// authority. Funded up front for the metadata, which Token-2022 appends
+    /// without topping up rent.
+    fn create_mint(
+        &self,
+        config_bump: u8,
+        args: &InitializeArgs,
+        features: MintFeatures,
+    ) -> Result<()> {
+        let config = self.config.key();
+        let mint = self.mint.to_account_info();
+        let token_program = self.token_program.to_account_info();
+
+        let mut extensions = vec![ExtensionType::MetadataPointer];
+        if features.permanent_delegate {
+            extensions.push(ExtensionType::PermanentDelegate);
+        }
+        if features.transfer_hook {
+            extensions.push(ExtensionType::TransferHook);
+        }
+        if features.default_frozen {
+            extensions.push(ExtensionType::DefaultAccountState);
+        }
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+        let metadata = TokenMetadata {
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            uri: args.uri.clone(),
+            ..Default::default()
+        };
+        let lamports = Rent::get()?.minimum_balance(space + metadata.tlv_size_of()?);
+
+        create_account(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.authority.to_account_info(),
+                    to: mint.clone(),
+                },
+            ),
+            lamports,
+            space as u64,
+            &token_program.key(),
+        )?;
+
+        // Extensions must be initialized before InitializeMint2.
+        metadata_pointer_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                MetadataPointerInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            Some(config),
+            Some(mint.key()),
+        )?;
+        if features.permanent_delegate {
+            permanent_delegate_initialize(
+                CpiContext::new(
+                    token_program.clone(),
+                    PermanentDelegateInitialize {
+                        token_program_id: token_program.clone(),
+                        mint: mint.clone(),
+                    },
+                ),
+                &config,
+            )?;
+        }
+        if features.transfer_hook {
+            transfer_hook_initialize(
+                CpiContext::new(
+                    token_program.clone(),
+                    TransferHookInitialize {
+                        token_program_id: token_program.clone(),
+                        mint: mint.clone(),
+                    },
+                ),
+                Some(config),
+                Some(TRANSFER_HOOK_PROGRAM_ID),
+            )?;
+        }
+        if features.default_frozen {
+            default_account_state_initialize(
+                CpiContext::new(
+                    token_program.clone(),
+                    DefaultAccountStateInitialize {
+                        token_program_id: token_program.clone(),
+                        mint: mint.clone(),
+                    },
+                ),
+                &AccountState::Frozen,
+            )?;
+        }
+        initialize_mint2(
+            CpiContext::new(
+                token_program.clone(),
+                InitializeMint2 { mint: mint.clone() },
+            ),
+            args.decimals,
+            &config,
+            Some(&config),
+        )?;
+
+        let mint_key = mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            StablecoinConfig::SSS_CONFIG_SEED,
+            mint_key.as_ref(),
+            &[config_bump],
+        ]];
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataInitialize {
+                    program_id: token_program,
+                    metadata: mint.clone(),
+                    update_authority: self.config.to_account_info(),
+                    mint,
+                    mint_authority: self.config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        )
+    }
+}
+
+/// The Token-2022 features a config enables: the preset's defaults unless
+/// `InitializeArgs` overrides them.
+#[derive(Clone, Copy)]
+struct MintFeatures {
+    permanent_delegate: bool,
+    transfer_hook: bool,
+    default_frozen: bool,
+}
+
+impl MintFeatures {
+    /// Validate `args` and resolve its features.
+    fn resolve(args: &InitializeArgs) -> Result<Self> {
+        require!(
+            args.preset >= 1 && args.preset <= 3,
+            SssError::InvalidPreset
+        );
+        require!(
+            args.name.len() <= StablecoinConfig::MAX_NAME_LEN,
+            SssError::NameTooLong
+        );
+        require!(
+            args.symbol.len() <= StablecoinConfig::MAX_SYMBOL_LEN,
+            SssError::SymbolTooLong
+        );
+        require!(
+            args.uri.len() <= StablecoinConfig::MAX_URI_LEN,
+            SssError::UriTooLong
+        );
+        require!(
+            args.oracle_feed_id != Some([0; 32]),
+            SssError::InvalidOracleFeedId
+        );
+
+        // Derive feature flags from preset, allowing explicit overrides
+        let (default_perm_delegate, default_hook, default_frozen) = match args.preset {
+            1 => (true, false, false), // SSS-1: minimal
+            2 => (true, true, true),   // SSS-2: compliant (hook + frozen by default)
+            3 => (true, false, false), // SSS-3: private (confidential transfers, no hook)
+            _ => unreachable!(),       // already validated above
+        };
+
+        Ok(Self {
+            permanent_delegate: args
+                .enable_permanent_delegate
+                .unwrap_or(default_perm_delegate),
+            transfer_hook: args.enable_transfer_hook.unwrap_or(default_hook),
+            default_frozen: args.default_account_frozen.unwrap_or(default_frozen),
+        })
+    }
+}
+
+/// The accounts `initialize` and `initialize_with_mint` fill in.
+struct NewStablecoin<'a, 'info> {
+    authority: Pubkey,
+    mint: Pubkey,
+    config: &'a mut Account<'info, StablecoinConfig>,
+    config_bump: u8,
+    admin_role: &'a mut Account<'info, RoleAccount>,
+    admin_role_bump: u8,
+    admin_directory: &'a mut Account<'info, RoleDirectory>,
+    admin_directory_bump: u8,
+    deployment_counter: Option<&'a mut Account<'info, DeploymentCounter>>,
+}
+
+impl NewStablecoin<'_, '_> {
+    /// Write the new config, grant `authority` the Admin role and emit
+    /// `StablecoinInitialized`.
+    fn write(self, args: InitializeArgs, features: MintFeatures) -> Result<()> {
+        let config = self.config;
+        config.authority = self.authority;
+        config.mint = self.mint;
+        config.preset = args.preset;
+        config.pause_flags = 0;
+        config.supply_cap = args.supply_cap;
+        config.total_minted = 0;
+        config.total_burned = 0;
+        config.bump = self.config_bump;
+        config.name = args.name;
+        config.symbol = args.symbol;
+        config.uri = args.uri;
+        config.decimals = args.decimals;
+        config.enable_permanent_delegate = features.permanent_delegate;
+        config.enable_transfer_hook = features.transfer_hook;
+        config.default_account_frozen = features.default_frozen;
+        config.admin_count = 1;
+        config.oracle_feed_id = args.oracle_feed_id;
+        config.receipt_threshold = None;
+        config.receipt_retention_secs = 0;
+        config.max_confidence_bps = None;
+        config.program_version = crate::version::program_version();
+        config.event_queue_enabled = false;
+        config.disabled_instructions = 0;
+        config.locked_instructions = 0;
+        config.reserved_for_redemption = 0;
+        config.state_digest = [0; 32];
+        config.state_nonce = 0;
+        config.bridge_minted = 0;
+        config.bridge_burned = 0;
+        config.thaw_ramp = None;
+        config.paused_at_slot = 0;
+        config.ramp_started_at = None;
+        config.ramp_base_supply = 0;
+        config.incident_flag = false;
+        config.incident_updated_at = 0;
+        config.incident_uri = String::new();
+        config.destination_mint_limit = None;
+        config.halt_mint_on_supply_mismatch = false;
+        config.oracle_price_source = OraclePriceSource::Spot;
+        config.pause_on_authority_anomaly = false;
+        config.mint_index = match self.deployment_counter {
+            Some(counter) => counter.next_index().ok_or(SssError::ArithmeticOverflow)?,
+            None => 0,
+        };
+        config.pending_authority = None;
+        config.admin_timelock_secs = 0;
+        config.large_mint_threshold = None;
+        config.large_mint_approvals = 0;
+        config.unpause_requires_admin = false;
+        config.max_pause_secs = 0;
+        config.pause_expires_at = None;
+        config.large_seize_threshold = None;
+        config.seizure_count = 0;
+        config.treasury = None;
+        config.seize_requires_frozen = false;
+        config.wipe_count = 0;
+        config.version = StablecoinConfig::CURRENT_VERSION;
+        config.sunset_at = None;
+        config.oracle_kind = OracleKind::Pyth;
+        config.max_attestation_age_secs = None;
+        config.fee_treasury = None;
+        config.refresh_digest();
+
+        let admin_role = self.admin_role;
+        admin_role.config = config.key();
+        admin_role.address = self.authority;
+        admin_role.role = Role::Admin;
+        admin_role.granted_by = self.authority;
+        admin_role.granted_at = Clock::get()?.unix_timestamp;
+        admin_role.bump = self.admin_role_bump;
+        admin_role.mint_quota = None;
+        admin_role.amount_minted = 0;
+        admin_role.allowed_hours = None;
+        admin_role.action_quota = None;
+        admin_role.action_window = ActionWindow::default();
+        admin_role.expires_at = None;
+        admin_role.quota_period_secs = None;
+        admin_role.period_start = 0;
+        admin_role.burn_quota = None;
+        admin_role.amount_burned = 0;
+        admin_role.max_per_window = None;
+        admin_role.window_secs = 0;
+        admin_role.window_start = 0;
+        admin_role.window_minted = 0;
+        admin_role.prev_window_minted = 0;
+
+        let admin_directory = self.admin_directory;
+        admin_directory.config = config.key();
+        admin_directory.role = Role::Admin;
+        admin_directory.bump = self.admin_directory_bump;
+        admin_directory.holders = vec![self.authority];
+
+        emit!(StablecoinInitialized {
+            mint: config.mint,
+            authority: config.authority,
+            preset: config.preset,
+            supply_cap: config.supply_cap,
+            name: config.name.clone(),
+            symbol: config.symbol.clone(),
+            decimals: config.decimals,
+            config: config.key(),
+            mint_index: config.mint_index,
+        });
+
+        Ok(())
+    }
+}
+
+// Initialize Deployment Counter
+
+#[derive(Accounts)]
+pub struct InitializeDeploymentCounter<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = DeploymentCounter::SPACE,
+        seeds = [DeploymentCounter::SSS_DEPLOYMENT_COUNTER_SEED],
+        bump,
+    )]
+    pub deployment_counter: Account<'info, DeploymentCounter>,
+
+    pub system_program: Program<'info, System>,
+}
 
+/// Create the program-wide `DeploymentCounter`. Permissionless: it holds
+/// nothing but the count, and creation fails once it exists.
+pub fn handler_initialize_deployment_counter(
+    ctx: Context<InitializeDeploymentCounter>,
+) -> Result<()> {
+    let counter = &mut ctx.accounts.deployment_counter;
+    counter.count = 0;
+    counter.bump = ctx.bumps.deployment_counter;
     Ok(())
 }
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::{KycApproved, KycRevoked};
+use crate::state::{KycEntry, Role, RoleAccount, StablecoinConfig};
+
+/// A Blacklister (the compliance role) approves `owner` for minting, or
+/// updates the expiry of an existing approval.
+#[derive(Accounts)]
+pub struct ApproveKyc<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            blacklister.key().as_ref(),
+            &[Role::Blacklister.as_u8()],
+        ],
+        bump = blacklister_role.bump,
+        constraint = blacklister_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub blacklister_role: Account<'info, RoleAccount>,
+
+    /// CHECK: The wallet being approved; it does not sign.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = blacklister,
+        space = KycEntry::SPACE,
+        seeds = [
+            KycEntry::SSS_KYC_SEED,
+            config.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub kyc_entry: Account<'info, KycEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_approve_kyc(ctx: Context<ApproveKyc>, expires_at: Option<i64>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(expiry) = expires_at {
+        require!(expiry > now, SssError::KycExpired);
+    }
+
+    let entry = &mut ctx.accounts.kyc_entry;
+    entry.config = ctx.accounts.config.key();
+    entry.owner = ctx.accounts.owner.key();
+    entry.approved_by = ctx.accounts.blacklister.key();
+    entry.approved_at = now;
+    entry.expires_at = expires_at;
+    entry.bump = ctx.bumps.kyc_entry;
+
+    emit!(KycApproved {
+        config: entry.config,
+        owner: entry.owner,
+        approved_by: entry.approved_by,
+        expires_at,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeKyc<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            blacklister.key().as_ref(),
+            &[Role::Blacklister.as_u8()],
+        ],
+        bump = blacklister_role.bump,
+        constraint = blacklister_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub blacklister_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = blacklister,
+        seeds = [
+            KycEntry::SSS_KYC_SEED,
+            config.key().as_ref(),
+            kyc_entry.owner.as_ref(),
+        ],
+        bump = kyc_entry.bump,
+    )]
+    pub kyc_entry: Account<'info, KycEntry>,
+}
+
+pub fn handler_revoke_kyc(ctx: Context<RevokeKyc>) -> Result<()> {
+    emit!(KycRevoked {
+        config: ctx.accounts.config.key(),
+        owner: ctx.accounts.kyc_entry.owner,
+        revoked_by: ctx.accounts.blacklister.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// On configs that require KYC, fails unless `kyc_entry` is a live approval
+/// of `owner`. A no-op on other presets.
+pub(crate) fn require_kyc(
+    config: &Account<StablecoinConfig>,
+    kyc_entry: Option<&Account<KycEntry>>,
+    owner: &Pubkey,
+) -> Result<()> {
+    if !config.requires_kyc() {
+        return Ok(());
+    }
+    let entry = kyc_entry.ok_or(SssError::KycRequired)?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        entry.approves(&config.key(), owner, now),
+        SssError::KycRequired
+    );
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::error::SssError;
+use crate::events::{
+    MintApprovalCancelled, MintApprovalExecuted, MintApprovalRequested, MintApproved,
+};
+use crate::state::{MintApproval, Role, RoleAccount, StablecoinConfig};
+
+/// Gate for mints above `config.large_mint_threshold`, which take
+/// `mint_approval` as an optional account closed on success. A passed
+/// approval is checked even when the amount does not need one.
+pub(crate) fn consume_mint_approval(
+    config: &Account<StablecoinConfig>,
+    mint_approval: Option<&Account<MintApproval>>,
+    minter: &Pubkey,
+    to: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let Some(approval) = mint_approval else {
+        require!(
+            !config.requires_mint_approval(amount),
+            SssError::LargeMintApprovalRequired
+        );
+        return Ok(());
+    };
+    require!(
+        approval.config == config.key()
+            && approval.authorizes(minter, to, amount, config.large_mint_approvals),
+        SssError::MintApprovalMismatch
+    );
+
+    emit!(MintApprovalExecuted {
+        config: config.key(),
+        minter: approval.minter,
+        id: approval.id,
+        amount,
+        approvers: approval.approvers.clone(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Request Mint Approval
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct RequestMintApproval<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            minter.key().as_ref(),
+            &[Role::Minter.as_u8()],
+        ],
+        bump = minter_role.bump,
+        constraint = minter_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub minter_role: Account<'info, RoleAccount>,
+
+    #[account(constraint = to.mint == config.mint @ SssError::MintMismatch)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = minter,
+        space = MintApproval::SPACE,
+        seeds = [
+            MintApproval::SSS_MINT_APPROVAL_SEED,
+            config.key().as_ref(),
+            minter.key().as_ref(),
+            &id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub mint_approval: Account<'info, MintApproval>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a request to mint `amount` into `to`. Nothing is reserved: quota,
+/// cap and the other mint checks apply when the approved mint executes.
+pub fn handler_request_mint_approval(
+    ctx: Context<RequestMintApproval>,
+    id: u64,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+
+    let approval = &mut ctx.accounts.mint_approval;
+    approval.config = ctx.accounts.config.key();
+    approval.minter = ctx.accounts.minter.key();
+    approval.id = id;
+    approval.to = ctx.accounts.to.key();
+    approval.amount = amount;
+    approval.approvers = Vec::new();
+    approval.requested_at = Clock::get()?.unix_timestamp;
+    approval.bump = ctx.bumps.mint_approval;
+
+    emit!(MintApprovalRequested {
+        config: approval.config,
+        minter: approval.minter,
+        id,
+        to: approval.to,
+        amount,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Approve Mint
+
+#[derive(Accounts)]
+pub struct ApproveMint<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Minter or Admin role PDA of `approver`.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            approver.key().as_ref(),
+            &[approver_role.role.as_u8()],
+        ],
+        bump = approver_role.bump,
+        constraint = matches!(approver_role.role, Role::Minter | Role::Admin) @ SssError::Unauthorized,
+        constraint = approver_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub approver_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        has_one = config @ SssError::MintApprovalMismatch,
+    )]
+    pub mint_approval: Account<'info, MintApproval>,
+}
+
+/// Co-sign a mint request. Each Minter or Admin holder other than the
+/// requesting minter counts once.
+pub fn handler_approve_mint(ctx: Context<ApproveMint>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    let approval = &mut ctx.accounts.mint_approval;
+    require!(approval.approve(approver), SssError::MintApprovalRejected);
+
+    emit!(MintApproved {
+        config: approval.config,
+        minter: approval.minter,
+        id: approval.id,
+        approver,
+        approvals: approval.approvers.len() as u8,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Cancel Mint Approval
+
+#[derive(Accounts)]
+pub struct CancelMintApproval<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        close = minter,
+        has_one = config @ SssError::MintApprovalMismatch,
+        has_one = minter @ SssError::Unauthorized,
+    )]
+    pub mint_approval: Account<'info, MintApproval>,
+}
+
+/// Withdraw an unused request and reclaim its rent.
+pub fn handler_cancel_mint_approval(ctx: Context<CancelMintApproval>) -> Result<()> {
+    let approval = &ctx.accounts.mint_approval;
+
+    emit!(MintApprovalCancelled {
+        config: approval.config,
+        minter: approval.minter,
+        id: approval.id,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{pause, MAX_MINT_BATCH};
+use crate::error::SssError;
+use crate::events::TokensMinted;
+use crate::instructions::destination_throttle::record_destination_mint;
+use crate::instructions::kyc::require_kyc;
+use crate::instructions::mint_tokens::{mint_as_config, reserve_supply};
+use crate::instructions::reserve_attestation::require_attested_reserves;
+use crate::state::{
+    DestinationThrottle, KycEntry, ReserveAttestation, Role, RoleAccount, StablecoinConfig,
+};
+
+/// Mint to up to `MAX_MINT_BATCH` recipients in one transaction, for payroll
+/// and market-maker distributions. `amounts[i]` goes to the i-th recipient
+/// group in the remaining accounts: the token account, then its owner's
+/// `KycEntry` if the config requires KYC, then the token account's
+/// `DestinationThrottle` if `destination_mint_limit` is set.
+///
+/// The quota, receipt threshold, large-mint threshold, reserves and supply
+/// cap are checked once against the batch total, so splitting an issuance across
+/// recipients cannot slip under any of them. Refused while fees are on.
+#[derive(Accounts)]
+pub struct MintTokensBatch<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::MINT, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            minter.key().as_ref(),
+            &[Role::Minter.as_u8()],
+        ],
+        bump = minter_role.bump,
+        constraint = minter_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub minter_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: optional oracle account; see `MintTokens::price_update`.
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// See `MintTokens::reserve_attestation`.
+    #[account(
+        seeds = [ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED, config.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+}
+
+pub fn handler_mint_tokens_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintTokensBatch<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let stride = 1
+        + usize::from(config.requires_kyc())
+        + usize::from(config.destination_mint_limit.is_some());
+    require!(
+        (1..=MAX_MINT_BATCH).contains(&amounts.len())
+            && ctx.remaining_accounts.len() == amounts.len() * stride,
+        SssError::InvalidMintBatch
+    );
+    require!(amounts.iter().all(|&a| a > 0), SssError::ZeroAmount);
+    let total = amounts
+        .iter()
+        .try_fold(0u64, |sum, &amount| sum.checked_add(amount))
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    // Batches have no receipt variant or mint approvals, so large issuances
+    // go through `mint_tokens`.
+    if let Some(threshold) = config.receipt_threshold {
+        require!(total < threshold, SssError::MintReceiptRequired);
+    }
+    require!(
+        !config.requires_mint_approval(total),
+        SssError::LargeMintApprovalRequired
+    );
+    // Nor per-recipient fee tiers, so while fees are on every issuance goes
+    // through `mint_tokens`.
+    require!(
+        config.fee_treasury.is_none(),
+        SssError::FeesRequireSingleMint
+    );
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.minter_role.roll_quota_period(now);
+    let within_quota = ctx
+        .accounts
+        .minter_role
+        .quota_allows(total)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_quota, SssError::QuotaExceeded);
+    let within_rate = ctx
+        .accounts
+        .minter_role
+        .rate_allows(total, now)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_rate, SssError::MintRateLimitExceeded);
+    require!(
+        ctx.accounts.minter_role.hours_allow(now),
+        SssError::OutsideOperatingHours
+    );
+    require_attested_reserves(
+        config,
+        ctx.accounts.reserve_attestation.as_ref(),
+        total,
+        now,
+    )?;
+
+    let accounts = &mut *ctx.accounts;
+    reserve_supply(
+        &mut accounts.config,
+        &accounts.mint,
+        accounts.price_update.as_ref(),
+        total,
+    )?;
+    let mut new_supply = accounts.config.current_supply() - total;
+
+    for (group, &amount) in ctx.remaining_accounts.chunks_exact(stride).zip(&amounts) {
+        let to = InterfaceAccount::<TokenAccount>::try_from(&group[0])?;
+        require_keys_eq!(to.mint, accounts.config.mint, SssError::MintMismatch);
+        let mut rest = group[1..].iter();
+
+        let kyc_entry = if accounts.config.requires_kyc() {
+            rest.next().map(Account::<KycEntry>::try_from).transpose()?
+        } else {
+            None
+        };
+        require_kyc(&accounts.config, kyc_entry.as_ref(), &to.owner)?;
+
+        if let Some(info) = rest.next() {
+            let mut throttle = Account::<DestinationThrottle>::try_from(info)?;
+            require!(
+                throttle.config == accounts.config.key() && throttle.token_account == to.key(),
+                SssError::DestinationThrottleRequired
+            );
+            record_destination_mint(&accounts.config, Some(&mut throttle), amount, now)?;
+            throttle.exit(&crate::ID)?;
+        }
+
+        mint_as_config(
+            &accounts.config,
+            &accounts.mint,
+            &to,
+            &accounts.token_program,
+            amount,
+        )?;
+
+        new_supply += amount;
+        emit!(TokensMinted {
+            mint: accounts.mint.key(),
+            to: to.key(),
+            amount,
+            minter: accounts.minter.key(),
+            new_supply,
+            config: accounts.config.key(),
+            mint_index: accounts.config.mint_index,
+        });
+    }
+
+    accounts.minter_role.amount_minted = accounts
+        .minter_role
+        .amount_minted
+        .checked_add(total)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    accounts
+        .minter_role
+        .record_rate(total, now)
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::error::SssError;
+use crate::events::{MintRequestCancelled, MintRequestFulfilled, MintRequested};
+use crate::instructions::mint_tokens::*;
+use crate::state::{MintRequest, Role, RoleAccount, StablecoinConfig};
+
+// Request Mint
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct RequestMint<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            requester.key().as_ref(),
+            &[Role::MintRequester.as_u8()],
+        ],
+        bump = requester_role.bump,
+        constraint = requester_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub requester_role: Account<'info, RoleAccount>,
+
+    #[account(constraint = to.mint == config.mint @ SssError::MintMismatch)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = MintRequest::SPACE,
+        seeds = [
+            MintRequest::SSS_MINT_REQUEST_SEED,
+            config.key().as_ref(),
+            requester.key().as_ref(),
+            &id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub mint_request: Account<'info, MintRequest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Ask for `amount` to be minted into `to`. Nothing is reserved: quota, cap
+/// and the other mint checks apply to the Minter that fulfils it.
+pub fn handler_request_mint(
+    ctx: Context<RequestMint>,
+    id: u64,
+    amount: u64,
+    reference: [u8; 32],
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+
+    let request = &mut ctx.accounts.mint_request;
+    request.config = ctx.accounts.config.key();
+    request.requester = ctx.accounts.requester.key();
+    request.id = id;
+    request.to = ctx.accounts.to.key();
+    request.amount = amount;
+    request.reference = reference;
+    request.requested_at = Clock::get()?.unix_timestamp;
+    request.bump = ctx.bumps.mint_request;
+
+    emit!(MintRequested {
+        config: request.config,
+        requester: request.requester,
+        id,
+        to: request.to,
+        amount,
+        reference,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Fulfill Mint Request
+
+#[derive(Accounts)]
+pub struct FulfillMintRequest<'info> {
+    pub issuance: MintTokens<'info>,
+
+    #[account(
+        mut,
+        close = requester,
+        constraint = mint_request.config == issuance.config.key() @ SssError::MintRequestMismatch,
+        constraint = mint_request.to == issuance.to.key() @ SssError::MintRequestMismatch,
+        has_one = requester @ SssError::MintRequestMismatch,
+    )]
+    pub mint_request: Account<'info, MintRequest>,
+
+    /// CHECK: `mint_request.requester` (enforced by `has_one`); receives
+    /// the request's rent.
+    #[account(mut)]
+    pub requester: UncheckedAccount<'info>,
+}
+
+/// Mint the requested amount into the requested destination through the
+/// same checks as `mint_tokens`, then close the request. The requester
+/// cannot fulfil its own request.
+pub fn handler_fulfill_mint_request(ctx: Context<FulfillMintRequest>) -> Result<()> {
+    let request = &ctx.accounts.mint_request;
+    let minter = ctx.accounts.issuance.minter.key();
+    require_keys_neq!(
+        minter,
+        request.requester,
+        SssError::MintRequestSelfFulfillment
+    );
+    // Large issuances still need a receipt; this path does not create one.
+    if let Some(threshold) = ctx.accounts.issuance.config.receipt_threshold {
+        require!(request.amount < threshold, SssError::MintReceiptRequired);
+    }
+    ctx.accounts.issuance.issue(request.amount)?;
+
+    let request = &ctx.accounts.mint_request;
+    emit!(MintRequestFulfilled {
+        config: request.config,
+        requester: request.requester,
+        id: request.id,
+        minter,
+        to: request.to,
+        amount: request.amount,
+        reference: request.reference,
+        mint_index: ctx.accounts.issuance.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Cancel Mint Request
+
+#[derive(Accounts)]
+pub struct CancelMintRequest<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        close = requester,
+        has_one = config @ SssError::MintRequestMismatch,
+        has_one = requester @ SssError::Unauthorized,
+    )]
+    pub mint_request: Account<'info, MintRequest>,
+}
+
+/// Withdraw an unfulfilled request and reclaim its rent.
+pub fn handler_cancel_mint_request(ctx: Context<CancelMintRequest>) -> Result<()> {
+    let request = &ctx.accounts.mint_request;
+
+    emit!(MintRequestCancelled {
+        config: request.config,
+        requester: request.requester,
+        id: request.id,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
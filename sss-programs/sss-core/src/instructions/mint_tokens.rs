@@ -1,24 +1,47 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface};
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use switchboard_on_demand::{sb_pid, PullFeedAccountData, PRECISION};
 
+use crate::constants::{capability, pause};
 use crate::error::SssError;
-use crate::events::TokensMinted;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::events::{FeeCollected, TokensMinted};
+use crate::instructions::destination_throttle::{destination_headroom, record_destination_mint};
+use crate::instructions::fees::fee_due;
+use crate::instructions::kyc::require_kyc;
+use crate::instructions::mint_approval::consume_mint_approval;
+use crate::instructions::reserve_attestation::require_attested_reserves;
+use crate::math::{confidence_within_bps, oracle_token_cap};
+use crate::state::{
+    DestinationThrottle, FeeKind, FeeSchedule, FeeTierAssignment, KycEntry, MintApproval,
+    OracleKind, OraclePrice, OraclePriceSource, ReserveAttestation, Role, RoleAccount,
+    StablecoinConfig,
+};
+use crate::views::{MintOutcome, VIEW_LAYOUT_VERSION};
 
 /// Maximum age of a Pyth price update in seconds before it is considered stale.
 /// 120 seconds (2 minutes) — conservative threshold suited for stablecoin minting.
 const ORACLE_MAX_AGE_SECS: u64 = 120;
 
+/// Maximum age of a Switchboard pull feed result in slots, about
+/// `ORACLE_MAX_AGE_SECS` at 400 ms per slot.
+const SWITCHBOARD_MAX_AGE_SLOTS: u64 = 300;
+
+/// Decimal exponent Switchboard results are rescaled to. They carry 18
+/// decimals, which overflow `i64` above about $9.
+const SWITCHBOARD_EXPONENT: i32 = -8;
+
 #[derive(Accounts)]
 pub struct MintTokens<'info> {
+    #[account(mut)]
     pub minter: Signer<'info>,
 
     #[account(
         mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
         bump = config.bump,
-        constraint = !config.paused @ SssError::Paused,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::MINT, Clock::get()?.unix_timestamp) @ SssError::Paused,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
@@ -33,6 +56,7 @@ pub struct MintTokens<'info> {
             &[Role::Minter.as_u8()],
         ],
         bump = minter_role.bump,
+        constraint = minter_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
     )]
     pub minter_role: Account<'info, RoleAccount>,
 
@@ -50,61 +74,310 @@ pub struct MintTokens<'info> {
 
     pub token_program: Interface<'info, TokenInterface>,
 
-    /// Optional Pyth price update account.  Pass this account to have the
-    /// supply cap interpreted as a USD amount; omit it to use the raw
-    /// token-unit cap.
+    /// Optional oracle account.  Pass this account to have the supply cap
+    /// interpreted as a USD amount; omit it to use the raw token-unit cap.
+    ///
+    /// A Pyth `PriceUpdateV2` or, under `OracleKind::Switchboard`, the
+    /// Switchboard pull feed pinned in `config.oracle_feed_id`.
     ///
-    /// When provided, Anchor automatically verifies ownership by the Pyth
-    /// Solana Receiver program.  The instruction then calls
-    /// `get_price_no_older_than` which internally checks:
-    ///   1. The price is not older than `ORACLE_MAX_AGE_SECS`.
-    ///   2. The feed ID matches `config.oracle_feed_id` (if set).
-    pub price_update: Option<Account<'info, PriceUpdateV2>>,
+    /// CHECK: owner, discriminator, feed and staleness are verified by
+    /// `reserve_supply` according to `config.oracle_kind`.
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// KYC approval for the owner of `to`. Required on SSS-2 configs,
+    /// ignored otherwise.
+    pub kyc_entry: Option<Account<'info, KycEntry>>,
+
+    /// Issuance window of `to`. Required while
+    /// `config.destination_mint_limit` is set, ignored otherwise.
+    #[account(
+        mut,
+        seeds = [
+            DestinationThrottle::SSS_DESTINATION_THROTTLE_SEED,
+            config.key().as_ref(),
+            to.key().as_ref(),
+        ],
+        bump = destination_throttle.bump,
+    )]
+    pub destination_throttle: Option<Account<'info, DestinationThrottle>>,
+
+    /// Co-signed request for exactly this mint. Required above
+    /// `config.large_mint_threshold`; closed on success.
+    #[account(mut, close = minter)]
+    pub mint_approval: Option<Account<'info, MintApproval>>,
+
+    /// Latest proof of reserves. Required while
+    /// `config.max_attestation_age_secs` is set, ignored otherwise.
+    #[account(
+        seeds = [ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED, config.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+
+    /// Rates for the mint fee. Required while `config.fee_treasury` is set,
+    /// ignored otherwise.
+    #[account(
+        seeds = [FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.key().as_ref()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
+
+    /// CHECK: `FeeTierAssignment` address of the owner of `to`, who pays the
+    /// fee; read by `fee_due`, tier 0 if empty. Required while
+    /// `config.fee_treasury` is set, ignored otherwise.
+    #[account(
+        seeds = [
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.key().as_ref(),
+            to.owner.as_ref(),
+        ],
+        bump,
+    )]
+    pub fee_tier: Option<UncheckedAccount<'info>>,
+
+    /// `config.fee_treasury`, credited with the fee. Required while a fee
+    /// is due.
+    #[account(mut, token::mint = mint)]
+    pub fee_treasury: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
-pub fn handler_mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
-    require!(amount > 0, SssError::ZeroAmount);
+/// Returns the minter's remaining headroom as a [`MintOutcome`].
+pub fn handler_mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<MintOutcome> {
+    if let Some(threshold) = ctx.accounts.config.receipt_threshold {
+        require!(amount < threshold, SssError::MintReceiptRequired);
+    }
+    let issued = ctx.accounts.issue(amount)?;
+
+    let accounts = &ctx.accounts;
+    let now = Clock::get()?.unix_timestamp;
+    Ok(MintOutcome {
+        layout_version: VIEW_LAYOUT_VERSION,
+        remaining_quota: accounts.minter_role.remaining_quota(),
+        remaining_window_quota: accounts.minter_role.remaining_window_quota(now),
+        remaining_destination_limit: destination_headroom(
+            &accounts.config,
+            accounts.destination_throttle.as_deref(),
+            now,
+        ),
+        remaining_cap: issued.remaining_cap,
+    })
+}
+
+impl<'info> MintTokens<'info> {
+    /// Shared issuance path for `mint_tokens` and `mint_tokens_with_receipt`:
+    /// the large-mint approval, per-minter quota, operating-hours, KYC,
+    /// destination and reserve checks, then [`issue_within_cap`], then the
+    /// minter's running total. While fees are on, the mint fee is split off
+    /// `amount` into the fee treasury; quotas and the cap still count all of
+    /// `amount`.
+    pub(crate) fn issue(&mut self, amount: u64) -> Result<Issued> {
+        require!(amount > 0, SssError::ZeroAmount);
+        consume_mint_approval(
+            &self.config,
+            self.mint_approval.as_ref(),
+            &self.minter.key(),
+            &self.to.key(),
+            amount,
+        )?;
+
+        // Per-minter quota check
+        let now = Clock::get()?.unix_timestamp;
+        self.minter_role.roll_quota_period(now);
+        let within_quota = self
+            .minter_role
+            .quota_allows(amount)
+            .ok_or(SssError::ArithmeticOverflow)?;
+        require!(within_quota, SssError::QuotaExceeded);
+        let within_rate = self
+            .minter_role
+            .rate_allows(amount, now)
+            .ok_or(SssError::ArithmeticOverflow)?;
+        require!(within_rate, SssError::MintRateLimitExceeded);
+        require!(
+            self.minter_role.hours_allow(now),
+            SssError::OutsideOperatingHours
+        );
+        require_kyc(&self.config, self.kyc_entry.as_ref(), &self.to.owner)?;
+        record_destination_mint(
+            &self.config,
+            self.destination_throttle.as_mut(),
+            amount,
+            now,
+        )?;
+        require_attested_reserves(&self.config, self.reserve_attestation.as_ref(), amount, now)?;
+
+        let fee = fee_due(
+            &self.config,
+            self.fee_schedule.as_deref(),
+            self.fee_tier.as_deref(),
+            self.fee_treasury.as_deref(),
+            FeeKind::Mint,
+            amount,
+        )?;
+        let issued = issue_less_fee(
+            &mut self.config,
+            &self.mint,
+            &self.to,
+            &self.token_program,
+            self.price_update.as_ref(),
+            self.minter.key(),
+            amount,
+            fee,
+        )?;
 
-    // Per-minter quota check
-    let minter_role = &mut ctx.accounts.minter_role;
-    if let Some(quota) = minter_role.mint_quota {
-        let new_total = minter_role
+        // Update per-minter quota tracking
+        self.minter_role.amount_minted = self
+            .minter_role
             .amount_minted
             .checked_add(amount)
             .ok_or(SssError::ArithmeticOverflow)?;
-        require!(new_total <= quota, SssError::QuotaExceeded);
+        self.minter_role
+            .record_rate(amount, now)
+            .ok_or(SssError::ArithmeticOverflow)?;
+
+        Ok(issued)
     }
+}
+
+/// What [`issue_within_cap`] minted against.
+pub(crate) struct Issued {
+    /// The oracle price the cap was converted with, if any.
+    pub oracle_price: Option<OraclePrice>,
+    /// Room left under the effective cap after the mint; `None` when
+    /// uncapped.
+    pub remaining_cap: Option<u64>,
+}
 
-    // Capture keys before borrowing config mutably
-    let config_info = ctx.accounts.config.to_account_info();
-    let mint_info = ctx.accounts.mint.to_account_info();
-    let to_info = ctx.accounts.to.to_account_info();
-    let token_program_info = ctx.accounts.token_program.to_account_info();
-    let mint_key = ctx.accounts.mint.key();
-    let to_key = ctx.accounts.to.key();
-    let minter_key = ctx.accounts.minter.key();
-    let decimals = ctx.accounts.mint.decimals;
+/// Supply-cap check, `mint_to` CPI, `total_minted` update and the
+/// `TokensMinted` event. Callers are responsible for authorization and any
+/// quota accounting before calling this.
+pub(crate) fn issue_within_cap<'info>(
+    config: &mut Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    price_update: Option<&UncheckedAccount<'info>>,
+    minter: Pubkey,
+    amount: u64,
+) -> Result<Issued> {
+    let issued = reserve_supply(config, mint, price_update, amount)?;
+    mint_and_emit(config, mint, to, token_program, minter, amount)?;
+    Ok(issued)
+}
 
-    let config = &mut ctx.accounts.config;
+/// [`issue_within_cap`] less the mint `fee` from [`fee_due`], if any: the fee
+/// is minted to the fee treasury and the rest to `to`, each with its own
+/// `TokensMinted`, while the cap counts the whole `amount`. The owner of `to`
+/// is named as the payer in `FeeCollected`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn issue_less_fee<'info>(
+    config: &mut Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    price_update: Option<&UncheckedAccount<'info>>,
+    minter: Pubkey,
+    amount: u64,
+    fee: Option<(u64, &InterfaceAccount<'info, TokenAccount>)>,
+) -> Result<Issued> {
+    let Some((fee, treasury)) = fee else {
+        return issue_within_cap(
+            config,
+            mint,
+            to,
+            token_program,
+            price_update,
+            minter,
+            amount,
+        );
+    };
+    let issued = reserve_supply(config, mint, price_update, amount)?;
+    mint_and_emit(config, mint, to, token_program, minter, amount - fee)?;
+    mint_and_emit(config, mint, treasury, token_program, minter, fee)?;
+    emit!(FeeCollected {
+        config: config.key(),
+        kind: FeeKind::Mint,
+        payer: to.owner,
+        amount,
+        fee,
+        treasury: treasury.key(),
+        mint_index: config.mint_index,
+    });
+    Ok(issued)
+}
 
-    // Oracle-aware supply cap: if a Pyth PriceUpdateV2 account is provided,
-    // convert the USD-denominated cap to token units using the live price.
+/// The minting half of [`issue_within_cap`]: [`mint_as_config`] and the
+/// `TokensMinted` event, for supply already reserved.
+pub(crate) fn mint_and_emit<'info>(
+    config: &Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    minter: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    mint_as_config(config, mint, to, token_program, amount)?;
+
+    emit!(TokensMinted {
+        mint: mint.key(),
+        to: to.key(),
+        amount,
+        minter,
+        new_supply: config.current_supply(),
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// The supply-cap half of [`issue_within_cap`]: checks `amount` against the
+/// effective cap and adds it to `total_minted`, without minting. Lets
+/// `mint_tokens_batch` check a whole batch against the cap at once.
+pub(crate) fn reserve_supply<'info>(
+    config: &mut Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    price_update: Option<&UncheckedAccount<'info>>,
+    amount: u64,
+) -> Result<Issued> {
+    require!(
+        config.is_enabled(capability::MINT),
+        SssError::InstructionDisabled
+    );
+
+    // Oracle-aware supply cap: if a Pyth PriceUpdateV2 or Switchboard pull
+    // feed account is provided, convert the USD-denominated cap to token
+    // units using the live price.
     // This is backward-compatible — omitting the oracle uses the raw cap.
     //
     // SECURITY: A configured oracle_feed_id is REQUIRED before passing a
     // price_update. Using a wildcard (all-zeros) feed ID is no longer accepted —
     // this prevents an attacker from substituting a cheap-asset price feed to
     // inflate the effective cap. Call `update_oracle_feed` to pin the feed ID.
-    let effective_cap = if let Some(ref price_update) = ctx.accounts.price_update {
+    let (effective_cap, oracle_price) = if let Some(price_update) = price_update {
         let feed_id = config
             .oracle_feed_id
             .ok_or(error!(SssError::OracleFeedNotConfigured))?;
-        adjust_cap_with_oracle(config.supply_cap, price_update, decimals, &feed_id)?
+        adjust_cap_with_oracle(
+            config.supply_cap,
+            price_update,
+            mint.decimals,
+            &feed_id,
+            config.max_confidence_bps,
+            config.oracle_kind,
+            config.oracle_price_source,
+        )?
     } else {
-        config.supply_cap
+        (config.supply_cap, None)
     };
+    // After a long pause the thaw ramp holds the cap below its configured
+    // value for a while.
+    let slot = Clock::get()?.slot;
+    let effective_cap = effective_cap.map(|cap| config.ramped_cap(cap, slot));
 
-    // Check supply cap (oracle-adjusted or raw)
+    // Check supply cap (oracle-adjusted or raw). `current_supply` still
+    // counts tokens escrowed for redemption, so new mints cannot take their
+    // headroom.
     let can_mint = match effective_cap {
         Some(cap) => {
             let new_supply = config
@@ -121,7 +394,23 @@ pub fn handler_mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<()>
         .total_minted
         .checked_add(amount)
         .ok_or(SssError::ArithmeticOverflow)?;
+    config.refresh_digest();
 
+    Ok(Issued {
+        oracle_price,
+        remaining_cap: effective_cap.map(|cap| cap.saturating_sub(config.current_supply())),
+    })
+}
+
+/// `mint_to` CPI into `to`, signed by the config PDA as mint authority.
+pub(crate) fn mint_as_config<'info>(
+    config: &Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let mint_key = mint.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
         StablecoinConfig::SSS_CONFIG_SEED,
         mint_key.as_ref(),
@@ -129,97 +418,143 @@ pub fn handler_mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<()>
     ]];
 
     let cpi_accounts = MintTo {
-        mint: mint_info,
-        to: to_info,
-        authority: config_info,
+        mint: mint.to_account_info(),
+        to: to.to_account_info(),
+        authority: config.to_account_info(),
     };
-    let cpi_ctx = CpiContext::new(token_program_info, cpi_accounts).with_signer(signer_seeds);
-
-    token_interface::mint_to(cpi_ctx, amount)?;
-
-    // Update per-minter quota tracking
-    ctx.accounts.minter_role.amount_minted = ctx
-        .accounts
-        .minter_role
-        .amount_minted
-        .checked_add(amount)
-        .ok_or(SssError::ArithmeticOverflow)?;
-
-    emit!(TokensMinted {
-        mint: mint_key,
-        to: to_key,
-        amount,
-        minter: minter_key,
-        new_supply: config.current_supply(),
-    });
+    let cpi_ctx =
+        CpiContext::new(token_program.to_account_info(), cpi_accounts).with_signer(signer_seeds);
 
-    Ok(())
+    token_interface::mint_to(cpi_ctx, amount)
 }
 
-/// Adjust a USD-denominated supply cap to token units using a Pyth v2
-/// `PriceUpdateV2` account (pull-oracle model).
+/// Adjust a USD-denominated supply cap to token units using the price in
+/// `price_update`, read as a Pyth `PriceUpdateV2` or a Switchboard pull feed
+/// according to `kind` (pull-oracle model either way).
 ///
-/// Uses `get_price_no_older_than` which enforces:
+/// For Pyth, `get_price_no_older_than` enforces:
 ///   • Staleness — price must be ≤ `ORACLE_MAX_AGE_SECS` old.
 ///   • Positive price — prices ≤ 0 are rejected by the SDK.
 ///   • Feed ID match — the `feed_id` must match the on-chain price account,
 ///     preventing substitution of a different (cheaper) asset's price feed.
 ///
-/// Cap conversion:
+/// See [`switchboard_price`] for the Switchboard checks.
+///
+/// `source` selects the spot price or Pyth's EMA price (with its EMA
+/// confidence). Both come from the same verified, non-stale update.
+///
+/// When `max_confidence_bps` is set, prices whose confidence interval is wider
+/// than that fraction of the price are rejected with `OracleConfidenceTooWide`.
+///
+/// Cap conversion is delegated to [`oracle_token_cap`]:
 ///   token_cap = usd_cap × 10^mint_decimals / (price × 10^exponent)
 ///
-/// If no supply cap is set, returns `None` (unlimited minting).
+/// Returns the token-unit cap together with the price it was derived from.
+/// If no supply cap is set, returns `(None, None)` (unlimited minting) without
+/// reading the price.
 fn adjust_cap_with_oracle(
     usd_cap: Option<u64>,
-    price_update: &Account<PriceUpdateV2>,
+    price_update: &AccountInfo,
     mint_decimals: u8,
     feed_id: &[u8; 32],
-) -> Result<Option<u64>> {
+    max_confidence_bps: Option<u16>,
+    kind: OracleKind,
+    source: OraclePriceSource,
+) -> Result<(Option<u64>, Option<OraclePrice>)> {
     let Some(cap) = usd_cap else {
-        return Ok(None);
+        return Ok((None, None));
     };
 
+    let clock = Clock::get()?;
+    let (price, conf) = match kind {
+        OracleKind::Pyth => pyth_price(price_update, feed_id, source, &clock)?,
+        OracleKind::Switchboard => switchboard_price(price_update, feed_id, &clock)?,
+    };
+
+    require!(price.price > 0, SssError::InvalidOraclePrice);
+    if let Some(max_bps) = max_confidence_bps {
+        require!(
+            confidence_within_bps(price.price, conf, max_bps),
+            SssError::OracleConfidenceTooWide
+        );
+    }
+
+    let token_cap = oracle_token_cap(cap, price.price, price.exponent, mint_decimals)
+        .ok_or(error!(SssError::ArithmeticOverflow))?;
+    Ok((Some(token_cap), Some(price)))
+}
+
+/// The price `source` selects from a Pyth `PriceUpdateV2`, and its
+/// confidence interval.
+pub(crate) fn pyth_price(
+    price_update: &AccountInfo,
+    feed_id: &[u8; 32],
+    source: OraclePriceSource,
+    clock: &Clock,
+) -> Result<(OraclePrice, u64)> {
+    require_keys_eq!(
+        *price_update.owner,
+        PriceUpdateV2::owner(),
+        SssError::InvalidOracleData
+    );
+    let price_update = PriceUpdateV2::try_deserialize(&mut &price_update.try_borrow_data()?[..])?;
+
     // Retrieve price, enforcing staleness check and feed ID verification.
     // ORACLE_MAX_AGE_SECS = 120; the SDK rejects updates older than this.
     // `feed_id` is the caller-supplied feed ID pinned in StablecoinConfig,
     // preventing substitution of a cheap-asset feed to inflate the cap.
-    let clock = Clock::get()?;
     let price_data = price_update
-        .get_price_no_older_than(&clock, ORACLE_MAX_AGE_SECS, feed_id)
+        .get_price_no_older_than(clock, ORACLE_MAX_AGE_SECS, feed_id)
         .map_err(|_| error!(SssError::OraclePriceStale))?;
+    let (price, conf) = match source {
+        OraclePriceSource::Spot => (price_data.price, price_data.conf),
+        OraclePriceSource::Ema => (
+            price_update.price_message.ema_price,
+            price_update.price_message.ema_conf,
+        ),
+    };
+
+    let price = OraclePrice {
+        price,
+        exponent: price_data.exponent,
+    };
+    Ok((price, conf))
+}
 
-    let price_i64 = price_data.price;
-    let expo = price_data.exponent; // i32, typically -8
+/// The current result of a Switchboard On-Demand pull feed, and its standard
+/// deviation as the confidence interval, both rescaled to
+/// `SWITCHBOARD_EXPONENT`.
+///
+/// Switchboard feeds have no feed ID inside the update, so the feed account
+/// itself is pinned: its address must equal `feed_address`, it must be owned
+/// by the Switchboard On-Demand program, and its result must have been
+/// signed within `SWITCHBOARD_MAX_AGE_SLOTS`.
+fn switchboard_price(
+    feed: &AccountInfo,
+    feed_address: &[u8; 32],
+    clock: &Clock,
+) -> Result<(OraclePrice, u64)> {
+    require!(
+        feed.key().to_bytes() == *feed_address && feed.owner.to_bytes() == sb_pid().to_bytes(),
+        SssError::InvalidOracleData
+    );
+    let data = feed.try_borrow_data()?;
+    let result = PullFeedAccountData::parse(data)
+        .map_err(|_| error!(SssError::InvalidOracleData))?
+        .result;
 
-    require!(price_i64 > 0, SssError::InvalidOraclePrice);
+    require!(
+        result.slot != 0 && clock.slot.saturating_sub(result.slot) <= SWITCHBOARD_MAX_AGE_SLOTS,
+        SssError::OraclePriceStale
+    );
 
-    let price_u128 = price_i64 as u128;
-    let decimals_pow = 10u128.pow(mint_decimals as u32);
+    let scale = 10i128.pow(PRECISION - SWITCHBOARD_EXPONENT.unsigned_abs());
+    let price = i64::try_from(result.value / scale).map_err(|_| SssError::ArithmeticOverflow)?;
+    let conf = u64::try_from(result.std_dev / scale).map_err(|_| SssError::InvalidOracleData)?;
 
-    let token_cap = if expo < 0 {
-        // token_cap = cap * 10^decimals * 10^|expo| / price
-        let abs_expo = expo.unsigned_abs();
-        let numerator = (cap as u128)
-            .checked_mul(decimals_pow)
-            .and_then(|v| v.checked_mul(10u128.pow(abs_expo)))
-            .ok_or(error!(SssError::ArithmeticOverflow))?;
-        numerator
-            .checked_div(price_u128)
-            .ok_or(error!(SssError::ArithmeticOverflow))?
-    } else {
-        // token_cap = cap * 10^decimals / (price * 10^expo)
-        let expo_pow = 10u128.pow(expo as u32);
-        let numerator = (cap as u128)
-            .checked_mul(decimals_pow)
-            .ok_or(error!(SssError::ArithmeticOverflow))?;
-        let denominator = price_u128
-            .checked_mul(expo_pow)
-            .ok_or(error!(SssError::ArithmeticOverflow))?;
-        numerator
-            .checked_div(denominator)
-            .ok_or(error!(SssError::ArithmeticOverflow))?
+    let price = OraclePrice {
+        price,
+        exponent: SWITCHBOARD_EXPONENT,
     };
-
-    // Safe downcast — if it exceeds u64, cap at u64::MAX (effectively unlimited)
-    Ok(Some(token_cap.min(u64::MAX as u128) as u64))
+    Ok((price, conf))
 }
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::events::MintReceiptCreated;
+use crate::instructions::mint_tokens::*;
+use crate::state::MintReceipt;
+
+/// `mint_tokens` plus a `MintReceipt` PDA recording the issuance.
+///
+/// Required when `amount >= config.receipt_threshold`; allowed for any amount.
+/// The receipt is keyed by `reference`, so the same off-chain record cannot be
+/// used for two issuances.
+#[derive(Accounts)]
+#[instruction(amount: u64, reference: [u8; 32])]
+pub struct MintTokensWithReceipt<'info> {
+    pub issuance: MintTokens<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = MintReceipt::SPACE,
+        seeds = [
+            MintReceipt::SSS_MINT_RECEIPT_SEED,
+            issuance.config.key().as_ref(),
+            reference.as_ref(),
+        ],
+        bump,
+    )]
+    pub receipt: Account<'info, MintReceipt>,
+
+    /// Pays receipt rent; usually the minter itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_mint_tokens_with_receipt(
+    ctx: Context<MintTokensWithReceipt>,
+    amount: u64,
+    reference: [u8; 32],
+) -> Result<()> {
+    let oracle_price = ctx.accounts.issuance.issue(amount)?.oracle_price;
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.config = ctx.accounts.issuance.config.key();
+    receipt.minter = ctx.accounts.issuance.minter.key();
+    receipt.destination = ctx.accounts.issuance.to.key();
+    receipt.amount = amount;
+    receipt.oracle_price = oracle_price;
+    receipt.reference = reference;
+    receipt.minted_at = Clock::get()?.unix_timestamp;
+    receipt.bump = ctx.bumps.receipt;
+
+    emit!(MintReceiptCreated {
+        config: receipt.config,
+        receipt: receipt.key(),
+        minter: receipt.minter,
+        amount,
+        reference,
+        mint_index: ctx.accounts.issuance.config.mint_index,
+    });
+
+    Ok(())
+}
@@ -1,19 +1,86 @@
 pub mod admin;
+pub mod blacklist;
+pub mod bridge;
+pub mod burn_batch;
 pub mod burn_tokens;
+pub mod claim_distribution;
+pub mod collateral_vault;
+pub mod compliance_report;
+pub mod credit_line;
+pub mod destination_throttle;
+pub mod event_queue;
+pub mod fees;
 pub mod freeze_account;
+pub mod freeze_batch;
+pub mod hot_minter;
+pub mod idempotent;
+pub mod incident;
 pub mod initialize;
+pub mod kyc;
+pub mod mint_approval;
+pub mod mint_batch;
+pub mod mint_request;
 pub mod mint_tokens;
+pub mod mint_with_receipt;
+pub mod par_swap;
 pub mod pause;
+pub mod period_summary;
+pub mod receivership;
+pub mod reconcile_supply;
+pub mod redeem;
+pub mod redemption;
+pub mod rescue;
+pub mod reserve_attestation;
 pub mod seize;
+pub mod seize_approval;
+pub mod snapshot;
+pub mod sub_minter;
+pub mod sweep;
 pub mod thaw_account;
 pub mod unpause;
+pub mod verify_authorities;
+pub mod verify_supply;
+pub mod view;
+pub mod wipe;
 
 pub use admin::*;
+pub use bridge::*;
+pub use burn_batch::*;
 pub use burn_tokens::*;
+pub use claim_distribution::*;
+pub use collateral_vault::*;
+pub use compliance_report::*;
+pub use credit_line::*;
+pub use destination_throttle::*;
 pub use freeze_account::*;
+pub use freeze_batch::*;
+pub use hot_minter::*;
+pub use idempotent::*;
+pub use incident::*;
 pub use initialize::*;
+pub use kyc::*;
+pub use mint_approval::*;
+pub use mint_batch::*;
+pub use mint_request::*;
 pub use mint_tokens::*;
+pub use mint_with_receipt::*;
+pub use par_swap::*;
 pub use pause::*;
+pub use period_summary::*;
+pub use receivership::*;
+pub use reconcile_supply::*;
+pub use redeem::*;
+pub use redemption::*;
+pub use rescue::*;
+pub use reserve_attestation::*;
 pub use seize::*;
+pub use seize_approval::*;
+pub use snapshot::*;
+pub use sub_minter::*;
+pub use sweep::*;
 pub use thaw_account::*;
 pub use unpause::*;
+pub use verify_authorities::*;
+pub use verify_supply::*;
+pub use view::*;
+pub use wipe::*;
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::constants::{capability, pause};
+use crate::error::SssError;
+use crate::events::ParSwapped;
+use crate::instructions::blacklist::require_not_blacklisted;
+use crate::instructions::reserve_attestation::require_attested_reserves;
+use crate::state::{ReserveAttestation, StablecoinConfig, SwapRoute};
+
+#[derive(Accounts)]
+pub struct ParSwap<'info> {
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [
+            SwapRoute::SSS_SWAP_ROUTE_SEED,
+            from_config.key().as_ref(),
+            to_config.key().as_ref(),
+        ],
+        bump = swap_route.bump,
+    )]
+    pub swap_route: Account<'info, SwapRoute>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, from_mint.key().as_ref()],
+        bump = from_config.bump,
+        constraint = from_config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !from_config.pauses(pause::BURN, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub from_config: Box<Account<'info, StablecoinConfig>>,
+
+    #[account(mut)]
+    pub from_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Holder's token account of the stablecoin being retired.
+    #[account(
+        mut,
+        token::mint = from_mint,
+        token::authority = holder,
+    )]
+    pub from_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Holder's blacklist PDA under `from_mint` in the transfer hook
+    /// program, verified by re-derivation when the mint uses the hook.
+    pub from_blacklist: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, to_mint.key().as_ref()],
+        bump = to_config.bump,
+        constraint = to_config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !to_config.pauses(pause::MINT, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub to_config: Box<Account<'info, StablecoinConfig>>,
+
+    #[account(mut)]
+    pub to_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Holder's token account of the stablecoin being issued.
+    #[account(
+        mut,
+        token::mint = to_mint,
+        token::authority = holder,
+    )]
+    pub to_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Holder's blacklist PDA under `to_mint` in the transfer hook
+    /// program, verified by re-derivation when the mint uses the hook.
+    pub to_blacklist: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// `to_config`'s proof of reserves; see
+    /// `MintTokens::reserve_attestation`.
+    #[account(
+        seeds = [ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED, to_config.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Box<Account<'info, ReserveAttestation>>>,
+}
+
+/// Atomically burn `amount` of the route's source stablecoin from the
+/// holder and mint the same amount of the target stablecoin to them.
+///
+/// Neither leg goes through the transfer hook, so the holder is checked
+/// against each hook-enabled mint's blacklist here.
+pub fn handler_par_swap(ctx: Context<ParSwap>, amount: u64) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    require!(
+        ctx.accounts.from_config.is_enabled(capability::BURN)
+            && ctx.accounts.to_config.is_enabled(capability::MINT),
+        SssError::InstructionDisabled
+    );
+    let holder = ctx.accounts.holder.key();
+
+    if ctx.accounts.from_config.enable_transfer_hook {
+        require_not_blacklisted(
+            &ctx.accounts.from_blacklist,
+            &ctx.accounts.from_mint.key(),
+            &holder,
+        )?;
+    }
+    if ctx.accounts.to_config.enable_transfer_hook {
+        require_not_blacklisted(
+            &ctx.accounts.to_blacklist,
+            &ctx.accounts.to_mint.key(),
+            &holder,
+        )?;
+    }
+
+    // Burn leg: the holder signs as owner of the source account.
+    let from_config = &mut ctx.accounts.from_config;
+    from_config.total_burned = from_config
+        .total_burned
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    from_config.refresh_digest();
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.from_mint.to_account_info(),
+                from: ctx.accounts.from_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // Mint leg: the target config PDA signs as mint authority.
+    let to_config = &mut ctx.accounts.to_config;
+    require!(to_config.can_mint(amount), SssError::SupplyCapExceeded);
+    require_attested_reserves(
+        to_config,
+        ctx.accounts.reserve_attestation.as_deref(),
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+    to_config.total_minted = to_config
+        .total_minted
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    to_config.refresh_digest();
+    let to_mint_key = ctx.accounts.to_mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        StablecoinConfig::SSS_CONFIG_SEED,
+        to_mint_key.as_ref(),
+        &[to_config.bump],
+    ]];
+    token_interface::mint_to(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.to_mint.to_account_info(),
+                to: ctx.accounts.to_account.to_account_info(),
+                authority: to_config.to_account_info(),
+            },
+        )
+        .with_signer(signer_seeds),
+        amount,
+    )?;
+
+    emit!(ParSwapped {
+        from_mint: ctx.accounts.from_mint.key(),
+        to_mint: to_mint_key,
+        holder,
+        amount,
+        from_config: ctx.accounts.from_config.key(),
+        to_config: ctx.accounts.to_config.key(),
+        from_mint_index: ctx.accounts.from_config.mint_index,
+        to_mint_index: ctx.accounts.to_config.mint_index,
+    });
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{capability, pause};
 use crate::error::SssError;
-use crate::events::OperationsPaused;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::events::{OperationsPaused, PauseFlagsChanged, PauseRenewed, ThawRampStarted};
+use crate::instructions::event_queue::record_event;
+use crate::state::{EventQueue, QueuedEventKind, Role, RoleAccount, StablecoinConfig};
 
 #[derive(Accounts)]
 pub struct Pause<'info> {
@@ -12,29 +14,209 @@ pub struct Pause<'info> {
         mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
         bump = config.bump,
-        constraint = !config.paused @ SssError::Paused,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.is_fully_paused(Clock::get()?.unix_timestamp) @ SssError::Paused,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
+    /// Pauser or Guardian role PDA of `pauser`.
     #[account(
         seeds = [
             RoleAccount::SSS_ROLE_SEED,
             config.key().as_ref(),
             pauser.key().as_ref(),
-            &[Role::Pauser.as_u8()],
+            &[pauser_role.role.as_u8()],
         ],
         bump = pauser_role.bump,
+        constraint = matches!(pauser_role.role, Role::Pauser | Role::Guardian) @ SssError::Unauthorized,
+        constraint = pauser_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
     )]
     pub pauser_role: Account<'info, RoleAccount>,
+
+    /// Required while `config.event_queue_enabled`; see `EventQueue`.
+    #[account(
+        mut,
+        seeds = [EventQueue::SSS_EVENT_QUEUE_SEED, config.key().as_ref()],
+        bump = event_queue.bump,
+    )]
+    pub event_queue: Option<Account<'info, EventQueue>>,
 }
 
 pub fn handler_pause(ctx: Context<Pause>) -> Result<()> {
+    require!(
+        ctx.accounts.config.is_enabled(capability::PAUSE),
+        SssError::InstructionDisabled
+    );
+    let clock = Clock::get()?;
     let config = &mut ctx.accounts.config;
-    config.paused = true;
+    config.set_pause_flags(pause::ALL, clock.slot, clock.unix_timestamp);
+    config.refresh_digest();
 
     emit!(OperationsPaused {
         mint: config.mint,
         pauser: ctx.accounts.pauser.key(),
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+
+    record_event(
+        &ctx.accounts.config,
+        ctx.accounts.event_queue.as_mut(),
+        QueuedEventKind::Pause,
+        ctx.accounts.config.mint,
+        ctx.accounts.pauser.key(),
+        0,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Pauser, Guardian or Admin role PDA of `pauser`. Setting a flag takes
+    /// a Pauser or Guardian and clearing one takes the role `unpause`
+    /// requires, which is never a Guardian.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            pauser.key().as_ref(),
+            &[pauser_role.role.as_u8()],
+        ],
+        bump = pauser_role.bump,
+        constraint = matches!(pauser_role.role, Role::Pauser | Role::Admin | Role::Guardian) @ SssError::Unauthorized,
+        constraint = pauser_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub pauser_role: Account<'info, RoleAccount>,
+
+    /// Required while `config.event_queue_enabled`; see `EventQueue`.
+    #[account(
+        mut,
+        seeds = [EventQueue::SSS_EVENT_QUEUE_SEED, config.key().as_ref()],
+        bump = event_queue.bump,
+    )]
+    pub event_queue: Option<Account<'info, EventQueue>>,
+}
+
+/// Pause exactly the operation classes in `flags` (see `constants::pause`),
+/// e.g. halt minting while holders can still burn and redeem.
+pub fn handler_set_pause_flags(ctx: Context<SetPauseFlags>, flags: u8) -> Result<()> {
+    require!(flags & !pause::ALL == 0, SssError::InvalidPauseFlags);
+
+    let clock = Clock::get()?;
+    let old_flags = ctx.accounts.config.active_pause_flags(clock.unix_timestamp);
+    let added = flags & !old_flags;
+    let removed = old_flags & !flags;
+    let role = ctx.accounts.pauser_role.role;
+    if added != 0 {
+        require!(
+            matches!(role, Role::Pauser | Role::Guardian),
+            SssError::Unauthorized
+        );
+        require!(
+            ctx.accounts.config.is_enabled(capability::PAUSE),
+            SssError::InstructionDisabled
+        );
+    }
+    if removed != 0 {
+        require!(
+            role == ctx.accounts.config.unpause_role(),
+            SssError::Unauthorized
+        );
+    }
+
+    let pauser = ctx.accounts.pauser.key();
+    let config = &mut ctx.accounts.config;
+    let slot = clock.slot;
+    if config.set_pause_flags(flags, slot, clock.unix_timestamp) {
+        let ramp_slots = config.thaw_ramp.map_or(0, |ramp| ramp.ramp_slots);
+        emit!(ThawRampStarted {
+            mint: config.mint,
+            start_slot: slot,
+            end_slot: slot.saturating_add(ramp_slots),
+            base_supply: config.ramp_base_supply,
+            config: config.key(),
+            mint_index: config.mint_index,
+        });
+    }
+    config.refresh_digest();
+
+    emit!(PauseFlagsChanged {
+        mint: config.mint,
+        old_flags,
+        new_flags: flags,
+        updated_by: pauser,
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+
+    record_event(
+        &ctx.accounts.config,
+        ctx.accounts.event_queue.as_mut(),
+        if added != 0 {
+            QueuedEventKind::Pause
+        } else {
+            QueuedEventKind::Unpause
+        },
+        ctx.accounts.config.mint,
+        pauser,
+        u64::from(flags),
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RenewPause<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = config.is_paused(Clock::get()?.unix_timestamp) @ SssError::NotPaused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+}
+
+/// Push a running pause's `pause_expires_at` to `max_pause_secs` from now.
+pub fn handler_renew_pause(ctx: Context<RenewPause>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.config;
+    let expires_at = config
+        .pause_deadline(now)
+        .ok_or(SssError::PauseExpiryDisabled)?;
+    config.pause_expires_at = Some(expires_at);
+    config.refresh_digest();
+
+    emit!(PauseRenewed {
+        mint: config.mint,
+        expires_at,
+        renewed_by: ctx.accounts.admin.key(),
+        config: config.key(),
+        mint_index: config.mint_index,
     });
 
     Ok(())
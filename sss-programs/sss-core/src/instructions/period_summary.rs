@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::PeriodSummaryExported;
+use crate::state::{PeriodSummary, Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+#[instruction(period_id: u64)]
+pub struct ExportPeriodSummary<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Co-signs the close, typically held by the firm attesting reserves.
+    pub attestor: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            attestor.key().as_ref(),
+            &[Role::Attestor.as_u8()],
+        ],
+        bump = attestor_role.bump,
+        constraint = attestor_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub attestor_role: Account<'info, RoleAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = PeriodSummary::SPACE,
+        seeds = [
+            PeriodSummary::SSS_PERIOD_SUMMARY_SEED,
+            config.key().as_ref(),
+            &period_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub period_summary: Account<'info, PeriodSummary>,
+
+    /// Summary of `period_id - 1`; required for every period but the first.
+    pub previous_summary: Option<Account<'info, PeriodSummary>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Seal period `period_id` at the current time from the config's running
+/// counters: the growth since the previous summary, the cumulative totals,
+/// and the `state_nonce` range it covers. Periods are exported in order and
+/// each summary is final, so a correction belongs in the next period.
+pub fn handler_export_period_summary(
+    ctx: Context<ExportPeriodSummary>,
+    period_id: u64,
+) -> Result<()> {
+    let config_key = ctx.accounts.config.key();
+    let previous = match period_id.checked_sub(1) {
+        None => None,
+        Some(previous_id) => {
+            let previous = ctx
+                .accounts
+                .previous_summary
+                .as_deref()
+                .ok_or(SssError::PeriodSummaryOutOfOrder)?;
+            require!(
+                previous.config == config_key && previous.period_id == previous_id,
+                SssError::PeriodSummaryOutOfOrder
+            );
+            Some(previous)
+        }
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let summary = &mut ctx.accounts.period_summary;
+    summary.config = config_key;
+    summary.period_id = period_id;
+    summary.seal(&ctx.accounts.config, previous, now);
+    summary.exported_by = ctx.accounts.admin.key();
+    summary.auditor = ctx.accounts.attestor.key();
+    summary.bump = ctx.bumps.period_summary;
+
+    emit!(PeriodSummaryExported {
+        config: config_key,
+        period_id,
+        opened_at: summary.opened_at,
+        closed_at: summary.closed_at,
+        first_nonce: summary.first_nonce,
+        last_nonce: summary.last_nonce,
+        minted: summary.minted,
+        burned: summary.burned,
+        exported_by: summary.exported_by,
+        auditor: summary.auditor,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,520 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{capability, pause};
+use crate::error::SssError;
+use crate::events::{
+    AccountFrozen, OperationsPaused, ReceiverRegistered, ReceivershipAction, ReceivershipActivated,
+    ReceivershipApproved, ReceivershipDischarged, TokensSeized,
+};
+use crate::instructions::event_queue::record_event;
+use crate::instructions::freeze_account::freeze_as_authority;
+use crate::instructions::seize::transfer_as_delegate;
+use crate::state::{
+    EventQueue, QueuedEventKind, Receivership, ReceivershipActionKind, Role, RoleAccount,
+    SeizureRecord, StablecoinConfig,
+};
+
+// Register Receiver
+#[derive(Accounts)]
+pub struct RegisterReceiver<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// CHECK: The court-appointed receiver's key; any key can be registered.
+    pub receiver: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Receivership::SPACE,
+        seeds = [Receivership::SSS_RECEIVERSHIP_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub receivership: Account<'info, Receivership>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Register the receiver in advance. It gains nothing until `quorum`
+/// distinct Admins call `approve_receivership`.
+pub fn handler_register_receiver(ctx: Context<RegisterReceiver>, quorum: u8) -> Result<()> {
+    let admin_count = ctx.accounts.config.admin_count;
+    require!(
+        quorum > 0
+            && quorum as usize <= Receivership::MAX_APPROVALS
+            && quorum as u32 <= admin_count,
+        SssError::InvalidReceivershipQuorum
+    );
+
+    let receivership = &mut ctx.accounts.receivership;
+    receivership.config = ctx.accounts.config.key();
+    receivership.receiver = ctx.accounts.receiver.key();
+    receivership.quorum = quorum;
+    receivership.approvals = Vec::new();
+    receivership.activated_at = 0;
+    receivership.registered_by = ctx.accounts.admin.key();
+    receivership.bump = ctx.bumps.receivership;
+
+    emit!(ReceiverRegistered {
+        config: receivership.config,
+        receiver: receivership.receiver,
+        quorum,
+        registered_by: receivership.registered_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Approve Receivership
+#[derive(Accounts)]
+pub struct ApproveReceivership<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        seeds = [Receivership::SSS_RECEIVERSHIP_SEED, config.key().as_ref()],
+        bump = receivership.bump,
+        constraint = !receivership.is_active() @ SssError::ReceivershipActive,
+    )]
+    pub receivership: Account<'info, Receivership>,
+}
+
+/// Add the signing Admin's approval; the approval that reaches the quorum
+/// activates the receivership.
+pub fn handler_approve_receivership(ctx: Context<ApproveReceivership>) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    let receivership = &mut ctx.accounts.receivership;
+    require!(
+        receivership.approve(admin),
+        SssError::ReceivershipAlreadyApproved
+    );
+
+    emit!(ReceivershipApproved {
+        config: receivership.config,
+        admin,
+        approvals: receivership.approvals.len() as u8,
+        quorum: receivership.quorum,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    if receivership.has_quorum() {
+        receivership.activated_at = Clock::get()?.unix_timestamp;
+        emit!(ReceivershipActivated {
+            config: receivership.config,
+            receiver: receivership.receiver,
+            approved_by: receivership.approvals.clone(),
+            mint_index: ctx.accounts.config.mint_index,
+        });
+    }
+
+    Ok(())
+}
+
+// Deregister Receiver
+#[derive(Accounts)]
+pub struct DeregisterReceiver<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// Only before activation: once active, only the receiver can end it.
+    #[account(
+        mut,
+        close = admin,
+        seeds = [Receivership::SSS_RECEIVERSHIP_SEED, config.key().as_ref()],
+        bump = receivership.bump,
+        constraint = !receivership.is_active() @ SssError::ReceivershipActive,
+    )]
+    pub receivership: Account<'info, Receivership>,
+}
+
+pub fn handler_deregister_receiver(ctx: Context<DeregisterReceiver>) -> Result<()> {
+    emit!(ReceivershipDischarged {
+        config: ctx.accounts.config.key(),
+        receiver: ctx.accounts.receivership.receiver,
+        was_active: false,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    // Account closure handled by Anchor via `close = admin`.
+    Ok(())
+}
+
+// Discharge Receivership
+#[derive(Accounts)]
+pub struct DischargeReceivership<'info> {
+    pub receiver: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        close = registered_by,
+        seeds = [Receivership::SSS_RECEIVERSHIP_SEED, config.key().as_ref()],
+        bump = receivership.bump,
+        constraint = receivership.receiver == receiver.key() @ SssError::Unauthorized,
+    )]
+    pub receivership: Account<'info, Receivership>,
+
+    /// CHECK: Receives the rent back; must be the Admin that registered the receiver.
+    #[account(mut, address = receivership.registered_by)]
+    pub registered_by: UncheckedAccount<'info>,
+}
+
+/// End the receivership, e.g. on the court's discharge order. Anything
+/// seized into escrow stays there until moved with `seize`.
+pub fn handler_discharge_receivership(ctx: Context<DischargeReceivership>) -> Result<()> {
+    emit!(ReceivershipDischarged {
+        config: ctx.accounts.config.key(),
+        receiver: ctx.accounts.receiver.key(),
+        was_active: ctx.accounts.receivership.is_active(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    // Account closure handled by Anchor via `close = registered_by`.
+    Ok(())
+}
+
+// Receivership Pause
+#[derive(Accounts)]
+pub struct ReceivershipPause<'info> {
+    pub receiver: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.is_fully_paused(Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [Receivership::SSS_RECEIVERSHIP_SEED, config.key().as_ref()],
+        bump = receivership.bump,
+        constraint = receivership.receiver == receiver.key() @ SssError::Unauthorized,
+        constraint = receivership.is_active() @ SssError::ReceivershipInactive,
+    )]
+    pub receivership: Account<'info, Receivership>,
+
+    /// Required while `config.event_queue_enabled`; see `EventQueue`.
+    #[account(
+        mut,
+        seeds = [EventQueue::SSS_EVENT_QUEUE_SEED, config.key().as_ref()],
+        bump = event_queue.bump,
+    )]
+    pub event_queue: Option<Account<'info, EventQueue>>,
+}
+
+/// `pause` on the receiver's authority. The receiver cannot unpause; that
+/// stays with the Pauser role.
+pub fn handler_receivership_pause(ctx: Context<ReceivershipPause>) -> Result<()> {
+    require!(
+        ctx.accounts.config.is_enabled(capability::PAUSE),
+        SssError::InstructionDisabled
+    );
+    let receiver = ctx.accounts.receiver.key();
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.config;
+    config.set_pause_flags(pause::ALL, clock.slot, clock.unix_timestamp);
+    config.refresh_digest();
+
+    emit!(OperationsPaused {
+        mint: config.mint,
+        pauser: receiver,
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+    emit!(ReceivershipAction {
+        mint: config.mint,
+        receiver,
+        action: ReceivershipActionKind::Pause,
+        subject: config.mint,
+        amount: 0,
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+
+    record_event(
+        &ctx.accounts.config,
+        ctx.accounts.event_queue.as_mut(),
+        QueuedEventKind::Pause,
+        ctx.accounts.config.mint,
+        receiver,
+        0,
+    )?;
+
+    Ok(())
+}
+
+// Receivership Freeze
+#[derive(Accounts)]
+pub struct ReceivershipFreeze<'info> {
+    pub receiver: Signer<'info>,
+
+    /// NO pause check — the receiver typically pauses first.
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [Receivership::SSS_RECEIVERSHIP_SEED, config.key().as_ref()],
+        bump = receivership.bump,
+        constraint = receivership.receiver == receiver.key() @ SssError::Unauthorized,
+        constraint = receivership.is_active() @ SssError::ReceivershipInactive,
+    )]
+    pub receivership: Account<'info, Receivership>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_receivership_freeze(ctx: Context<ReceivershipFreeze>) -> Result<()> {
+    require!(
+        ctx.accounts.config.is_enabled(capability::FREEZE),
+        SssError::InstructionDisabled
+    );
+    freeze_as_authority(
+        &ctx.accounts.token_program,
+        &ctx.accounts.config,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_account,
+    )?;
+
+    let receiver = ctx.accounts.receiver.key();
+    emit!(AccountFrozen {
+        mint: ctx.accounts.mint.key(),
+        account: ctx.accounts.token_account.key(),
+        freezer: receiver,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+    emit!(ReceivershipAction {
+        mint: ctx.accounts.mint.key(),
+        receiver,
+        action: ReceivershipActionKind::Freeze,
+        subject: ctx.accounts.token_account.key(),
+        amount: 0,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Receivership Seize
+#[derive(Accounts)]
+pub struct ReceivershipSeize<'info> {
+    #[account(mut)]
+    pub receiver: Signer<'info>,
+
+    /// NO pause check, as for `seize`.
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [Receivership::SSS_RECEIVERSHIP_SEED, config.key().as_ref()],
+        bump = receivership.bump,
+        constraint = receivership.receiver == receiver.key() @ SssError::Unauthorized,
+        constraint = receivership.is_active() @ SssError::ReceivershipInactive,
+    )]
+    pub receivership: Account<'info, Receivership>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Unlike `seize`, the destination must be owned by the receivership
+    /// PDA, so the receiver can only move funds into escrow.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = receivership,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Required while `config.event_queue_enabled`; see `EventQueue`.
+    #[account(
+        mut,
+        seeds = [EventQueue::SSS_EVENT_QUEUE_SEED, config.key().as_ref()],
+        bump = event_queue.bump,
+    )]
+    pub event_queue: Option<Account<'info, EventQueue>>,
+
+    #[account(
+        init,
+        payer = receiver,
+        space = SeizureRecord::SPACE,
+        seeds = [
+            SeizureRecord::SSS_SEIZURE_RECORD_SEED,
+            config.key().as_ref(),
+            &config.seizure_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub seizure_record: Account<'info, SeizureRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_receivership_seize<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReceivershipSeize<'info>>,
+    amount: u64,
+    reason: String,
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    require!(
+        SeizureRecord::is_valid_reason(&reason),
+        SssError::InvalidSeizureReason
+    );
+    require!(
+        ctx.accounts.config.is_enabled(capability::SEIZE),
+        SssError::InstructionDisabled
+    );
+
+    transfer_as_delegate(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.config,
+        &ctx.accounts.mint,
+        &ctx.accounts.from.to_account_info(),
+        &ctx.accounts.escrow.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+    )?;
+
+    let receiver = ctx.accounts.receiver.key();
+    let index = ctx
+        .accounts
+        .config
+        .next_seizure_index()
+        .ok_or(SssError::ArithmeticOverflow)?;
+    ctx.accounts.seizure_record.set_inner(SeizureRecord {
+        config: ctx.accounts.config.key(),
+        index,
+        seizer: receiver,
+        from: ctx.accounts.from.key(),
+        to: ctx.accounts.escrow.key(),
+        amount,
+        approver: None,
+        reason: reason.clone(),
+        seized_at: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.seizure_record,
+    });
+
+    emit!(TokensSeized {
+        mint: ctx.accounts.mint.key(),
+        from: ctx.accounts.from.key(),
+        to: ctx.accounts.escrow.key(),
+        amount,
+        seizer: receiver,
+        reason,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+    emit!(ReceivershipAction {
+        mint: ctx.accounts.mint.key(),
+        receiver,
+        action: ReceivershipActionKind::Seize,
+        subject: ctx.accounts.from.key(),
+        amount,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    record_event(
+        &ctx.accounts.config,
+        ctx.accounts.event_queue.as_mut(),
+        QueuedEventKind::Seize,
+        ctx.accounts.from.key(),
+        receiver,
+        amount,
+    )?;
+
+    Ok(())
+}
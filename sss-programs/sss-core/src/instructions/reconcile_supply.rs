@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::error::SssError;
+use crate::events::SupplyReconciled;
+use crate::state::StablecoinConfig;
+
+/// Permissionless: bring the counters in line with tokens holders burned
+/// directly through Token-2022. Works while paused.
+#[derive(Accounts)]
+pub struct ReconcileSupply<'info> {
+    pub reconciler: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(constraint = config.mint == mint.key() @ SssError::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Compare the mint's `supply` with `local_supply()` and emit
+/// `SupplyReconciled` with the difference. With `adjust`, a mint holding
+/// fewer tokens than the counters expect has the shortfall added to
+/// `total_burned`. A surplus is never absorbed, since it means issuance
+/// outside sss-core; `verify_supply` is what raises that alarm.
+pub fn handler_reconcile_supply(ctx: Context<ReconcileSupply>, adjust: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let expected = config.local_supply();
+    let actual = ctx.accounts.mint.supply;
+
+    let adjusted = adjust && actual < expected;
+    if adjusted {
+        config.reconcile_supply(actual);
+        config.refresh_digest();
+    }
+
+    emit!(SupplyReconciled {
+        mint: config.mint,
+        expected,
+        actual,
+        delta: i128::from(actual) - i128::from(expected),
+        adjusted,
+        reconciler: ctx.accounts.reconciler.key(),
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{capability, pause};
+use crate::error::SssError;
+use crate::events::{FeeCollected, TokensBurned};
+use crate::instructions::fees::fee_due;
+use crate::instructions::seize::transfer_with_hook;
+use crate::state::{FeeKind, FeeSchedule, FeeTierAssignment, StablecoinConfig};
+
+/// Permissionless self-burn: any holder destroys part of their own balance,
+/// e.g. as the on-chain leg of a redemption claim. Counted in `total_burned`
+/// like a Burner's burn, with `burner == from_owner` in `TokensBurned`.
+///
+/// While `config.fee_treasury` is set, the burn fee is moved to the fee
+/// treasury instead of burned. On SSS-2 mints that transfer goes through the
+/// hook, whose extra accounts follow in the remaining accounts.
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::BURN, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Only the holder's own account; the permanent delegate is not used.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Rates for the burn fee. Required while `config.fee_treasury` is set,
+    /// ignored otherwise.
+    #[account(
+        seeds = [FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.key().as_ref()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Option<Account<'info, FeeSchedule>>,
+
+    /// CHECK: The holder's `FeeTierAssignment` address; read by `fee_due`,
+    /// tier 0 if empty. Required while `config.fee_treasury` is set, ignored
+    /// otherwise.
+    #[account(
+        seeds = [
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.key().as_ref(),
+            holder.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub fee_tier: Option<UncheckedAccount<'info>>,
+
+    /// `config.fee_treasury`, credited with the fee. Required while a fee
+    /// is due.
+    #[account(mut, token::mint = mint)]
+    pub fee_treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub fn handler_redeem<'info>(
+    ctx: Context<'_, '_, '_, 'info, Redeem<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    let config = &mut ctx.accounts.config;
+    require!(
+        config.is_enabled(capability::BURN),
+        SssError::InstructionDisabled
+    );
+
+    let fee = fee_due(
+        config,
+        ctx.accounts.fee_schedule.as_ref(),
+        ctx.accounts.fee_tier.as_deref(),
+        ctx.accounts.fee_treasury.as_ref(),
+        FeeKind::Burn,
+        amount,
+    )?;
+    if let Some((fee, treasury)) = fee {
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint,
+            &ctx.accounts.from.to_account_info(),
+            &treasury.to_account_info(),
+            &ctx.accounts.holder.to_account_info(),
+            ctx.remaining_accounts,
+            fee,
+            &[],
+        )?;
+        emit!(FeeCollected {
+            config: config.key(),
+            kind: FeeKind::Burn,
+            payer: ctx.accounts.holder.key(),
+            amount,
+            fee,
+            treasury: treasury.key(),
+            mint_index: config.mint_index,
+        });
+    }
+    let amount = amount - fee.map_or(0, |(fee, _)| fee);
+
+    config.total_burned = config
+        .total_burned
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    config.refresh_digest();
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.from.to_account_info(),
+        authority: ctx.accounts.holder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token_interface::burn(cpi_ctx, amount)?;
+
+    emit!(TokensBurned {
+        mint: ctx.accounts.mint.key(),
+        from: ctx.accounts.from.key(),
+        amount,
+        burner: ctx.accounts.holder.key(),
+        new_supply: ctx.accounts.config.current_supply(),
+        from_owner: ctx.accounts.holder.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
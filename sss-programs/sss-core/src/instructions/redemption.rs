@@ -0,0 +1,423 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{self, Burn, CloseAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{capability, pause};
+use crate::error::SssError;
+use crate::events::{RedemptionCancelled, RedemptionRequested, RedemptionSettled, TokensBurned};
+use crate::instructions::thaw_account::thaw_as_authority;
+use crate::state::{RedemptionRequest, Role, RoleAccount, StablecoinConfig};
+
+// Request Redemption
+
+/// Remaining accounts: the transfer hook's extra accounts for a transfer from
+/// `from` to `escrow` (empty for mints without a hook).
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct RequestRedemption<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::BURN, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = holder,
+        space = RedemptionRequest::SPACE,
+        seeds = [
+            RedemptionRequest::SSS_REDEMPTION_SEED,
+            config.key().as_ref(),
+            holder.key().as_ref(),
+            &id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// The request PDA's associated token account. Owned by the request
+    /// rather than the config, so `rescue_tokens` cannot reach it.
+    #[account(
+        init,
+        payer = holder,
+        associated_token::mint = mint,
+        associated_token::authority = redemption_request,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Escrow `amount` from the holder's account for redemption against the
+/// off-chain payout identified by `bank_reference`. The amount stays in
+/// `current_supply()` but leaves `circulating_supply()` until the request
+/// is settled or cancelled.
+pub fn handler_request_redemption<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestRedemption<'info>>,
+    id: u64,
+    amount: u64,
+    bank_reference: [u8; 32],
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    require!(
+        ctx.accounts.config.is_enabled(capability::BURN),
+        SssError::InstructionDisabled
+    );
+
+    let accounts = &mut *ctx.accounts;
+    accounts
+        .config
+        .reserve_for_redemption(amount)
+        .ok_or(SssError::RedemptionExceedsSupply)?;
+
+    // SSS-2 token accounts start frozen.
+    if accounts.escrow.is_frozen() {
+        thaw_as_authority(
+            &accounts.token_program,
+            &accounts.config,
+            &accounts.mint,
+            &accounts.escrow,
+        )?;
+    }
+    invoke_transfer_checked(
+        &accounts.token_program.key(),
+        accounts.from.to_account_info(),
+        accounts.mint.to_account_info(),
+        accounts.escrow.to_account_info(),
+        accounts.holder.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        accounts.mint.decimals,
+        &[],
+    )?;
+
+    let request = &mut accounts.redemption_request;
+    request.config = accounts.config.key();
+    request.holder = accounts.holder.key();
+    request.id = id;
+    request.escrow = accounts.escrow.key();
+    request.amount = amount;
+    request.bank_reference = bank_reference;
+    request.requested_at = Clock::get()?.unix_timestamp;
+    request.bump = ctx.bumps.redemption_request;
+
+    emit!(RedemptionRequested {
+        config: request.config,
+        holder: request.holder,
+        id,
+        amount,
+        bank_reference,
+        mint_index: accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Settle Redemption
+
+#[derive(Accounts)]
+pub struct SettleRedemption<'info> {
+    pub burner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::BURN, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            burner.key().as_ref(),
+            &[Role::Burner.as_u8()],
+        ],
+        bump = burner_role.bump,
+        constraint = burner_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub burner_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        close = holder,
+        has_one = config @ SssError::RedemptionMismatch,
+        has_one = holder @ SssError::RedemptionMismatch,
+        has_one = escrow @ SssError::RedemptionMismatch,
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// CHECK: `redemption_request.holder` (enforced by `has_one`); receives
+    /// the request and escrow rent.
+    #[account(mut)]
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Burn the escrow once the holder has been paid off-chain, release the
+/// reservation and close the request. Counts against the Burner's
+/// `burn_quota` like `burn_tokens`.
+pub fn handler_settle_redemption(ctx: Context<SettleRedemption>) -> Result<()> {
+    require!(
+        ctx.accounts.config.is_enabled(capability::BURN),
+        SssError::InstructionDisabled
+    );
+    // Anything sent to the escrow besides the request is burned with it.
+    let burned = ctx.accounts.escrow.amount;
+    let within_quota = ctx
+        .accounts
+        .burner_role
+        .burn_quota_allows(burned)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_quota, SssError::BurnQuotaExceeded);
+
+    let accounts = &mut *ctx.accounts;
+    let request = &accounts.redemption_request;
+    let id = request.id.to_le_bytes();
+    let config_key = accounts.config.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        RedemptionRequest::SSS_REDEMPTION_SEED,
+        config_key.as_ref(),
+        request.holder.as_ref(),
+        &id,
+        &[request.bump],
+    ]];
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Burn {
+                mint: accounts.mint.to_account_info(),
+                from: accounts.escrow.to_account_info(),
+                authority: request.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        burned,
+    )?;
+    close_escrow(
+        accounts.escrow.to_account_info(),
+        &accounts.token_program,
+        request,
+        &accounts.holder,
+        signer_seeds,
+    )?;
+
+    let config = &mut accounts.config;
+    config
+        .release_redemption(request.amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    config.total_burned = config
+        .total_burned
+        .checked_add(burned)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    config.refresh_digest();
+    accounts.burner_role.amount_burned = accounts
+        .burner_role
+        .amount_burned
+        .checked_add(burned)
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    emit!(TokensBurned {
+        mint: accounts.mint.key(),
+        from: accounts.escrow.key(),
+        amount: burned,
+        burner: accounts.burner.key(),
+        new_supply: config.current_supply(),
+        from_owner: request.holder, // for the audit trail
+        config: config_key,
+        mint_index: config.mint_index,
+    });
+    emit!(RedemptionSettled {
+        config: config_key,
+        holder: request.holder,
+        id: request.id,
+        amount: burned,
+        bank_reference: request.bank_reference,
+        burner: accounts.burner.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Cancel Redemption
+
+/// Remaining accounts: the transfer hook's extra accounts for a transfer from
+/// `escrow` to `to` (empty for mints without a hook).
+#[derive(Accounts)]
+pub struct CancelRedemption<'info> {
+    /// The holder, or a Burner rejecting the request.
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Burner role PDA of `authority`; required unless `authority` is the
+    /// holder.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            authority.key().as_ref(),
+            &[Role::Burner.as_u8()],
+        ],
+        bump = burner_role.bump,
+        constraint = burner_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub burner_role: Option<Account<'info, RoleAccount>>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        close = holder,
+        has_one = config @ SssError::RedemptionMismatch,
+        has_one = holder @ SssError::RedemptionMismatch,
+        has_one = escrow @ SssError::RedemptionMismatch,
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// CHECK: `redemption_request.holder` (enforced by `has_one`); receives
+    /// the request and escrow rent.
+    #[account(mut)]
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// Holder's token account the escrow is returned to.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Return the escrow to the holder, release the reservation and close the
+/// request. The holder can withdraw a request at any time before it is
+/// settled; a Burner can reject one, e.g. over an unusable bank reference.
+pub fn handler_cancel_redemption<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelRedemption<'info>>,
+) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    let request = &accounts.redemption_request;
+    let authority = accounts.authority.key();
+    require!(
+        authority == request.holder || accounts.burner_role.is_some(),
+        SssError::Unauthorized
+    );
+
+    let id = request.id.to_le_bytes();
+    let config_key = accounts.config.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        RedemptionRequest::SSS_REDEMPTION_SEED,
+        config_key.as_ref(),
+        request.holder.as_ref(),
+        &id,
+        &[request.bump],
+    ]];
+
+    let returned = accounts.escrow.amount;
+    invoke_transfer_checked(
+        &accounts.token_program.key(),
+        accounts.escrow.to_account_info(),
+        accounts.mint.to_account_info(),
+        accounts.to.to_account_info(),
+        request.to_account_info(),
+        ctx.remaining_accounts,
+        returned,
+        accounts.mint.decimals,
+        signer_seeds,
+    )?;
+    close_escrow(
+        accounts.escrow.to_account_info(),
+        &accounts.token_program,
+        request,
+        &accounts.holder,
+        signer_seeds,
+    )?;
+
+    accounts
+        .config
+        .release_redemption(request.amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    emit!(RedemptionCancelled {
+        config: config_key,
+        holder: request.holder,
+        id: request.id,
+        amount: returned,
+        cancelled_by: authority,
+        mint_index: accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Close an emptied escrow, signed by its request, returning the rent to the
+/// holder.
+fn close_escrow<'info>(
+    escrow: AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    request: &Account<'info, RedemptionRequest>,
+    holder: &UncheckedAccount<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: escrow,
+            destination: holder.to_account_info(),
+            authority: request.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::pause;
+use crate::error::SssError;
+use crate::events::TokensRescued;
+use crate::instructions::seize::transfer_as_delegate;
+use crate::state::{Role, RoleAccount, StablecoinConfig};
+
+/// Remaining accounts are forwarded to the transfer hook on SSS-2 mints.
+#[derive(Accounts)]
+pub struct RescueTokens<'info> {
+    pub rescuer: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::TRANSFERS, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            rescuer.key().as_ref(),
+            &[Role::Rescuer.as_u8()],
+        ],
+        bump = rescuer_role.bump,
+        constraint = rescuer_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub rescuer_role: Account<'info, RoleAccount>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Holds tokens sent to the config address by mistake. Nothing else
+    /// can move them, since the config PDA signs only for this program.
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = from.owner == config.key() @ SssError::RescueSourceNotConfigOwned,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Move `amount` of this mint out of a config-owned token account, signed by
+/// the config PDA as owner.
+pub fn handler_rescue_tokens<'info>(
+    ctx: Context<'_, '_, '_, 'info, RescueTokens<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+
+    transfer_as_delegate(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.config,
+        &ctx.accounts.mint,
+        &ctx.accounts.from.to_account_info(),
+        &ctx.accounts.to.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+    )?;
+
+    emit!(TokensRescued {
+        config: ctx.accounts.config.key(),
+        from: ctx.accounts.from.key(),
+        to: ctx.accounts.to.key(),
+        amount,
+        rescuer: ctx.accounts.rescuer.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::events::ReservesAttested;
+use crate::state::{ReserveAttestation, Role, RoleAccount, StablecoinConfig};
+
+#[derive(Accounts)]
+pub struct PublishReserveAttestation<'info> {
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            attestor.key().as_ref(),
+            &[Role::Attestor.as_u8()],
+        ],
+        bump = attestor_role.bump,
+        constraint = attestor_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub attestor_role: Account<'info, RoleAccount>,
+
+    /// Created with the first attestation.
+    #[account(
+        init_if_needed,
+        payer = attestor,
+        space = ReserveAttestation::SPACE,
+        seeds = [ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the config's proof of reserves with `reserve_amount`, measured at
+/// `attested_at` and documented by the report hashing to `report_hash`.
+/// `attested_at` may not be in the future nor go backwards, so a stale
+/// report cannot be replayed over a newer one.
+pub fn handler_publish_reserve_attestation(
+    ctx: Context<PublishReserveAttestation>,
+    reserve_amount: u64,
+    attested_at: i64,
+    report_hash: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let attestation = &mut ctx.accounts.reserve_attestation;
+    require!(attested_at <= now, SssError::InvalidAttestationTime);
+    require!(
+        attestation.attestation_count == 0 || attested_at > attestation.attested_at,
+        SssError::InvalidAttestationTime
+    );
+
+    attestation.config = ctx.accounts.config.key();
+    attestation.reserve_amount = reserve_amount;
+    attestation.attested_at = attested_at;
+    attestation.report_hash = report_hash;
+    attestation.attestor = ctx.accounts.attestor.key();
+    attestation.attestation_count = attestation
+        .attestation_count
+        .checked_add(1)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    attestation.bump = ctx.bumps.reserve_attestation;
+
+    emit!(ReservesAttested {
+        config: attestation.config,
+        reserve_amount,
+        attested_at,
+        report_hash,
+        attestor: attestation.attestor,
+        global_supply: ctx.accounts.config.global_supply(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// While `config.max_attestation_age_secs` is set, fails unless
+/// `attestation` (already checked to be the config's PDA) is fresh at `now`
+/// and covers the global supply after issuing `amount`. A no-op otherwise.
+pub(crate) fn require_attested_reserves(
+    config: &StablecoinConfig,
+    attestation: Option<&Account<ReserveAttestation>>,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    let Some(max_age_secs) = config.max_attestation_age_secs else {
+        return Ok(());
+    };
+    let attestation = attestation.ok_or(SssError::ReserveAttestationRequired)?;
+    require!(
+        attestation.is_fresh(now, max_age_secs),
+        SssError::ReserveAttestationStale
+    );
+    let new_supply = config
+        .global_supply()
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(
+        new_supply <= attestation.reserve_amount,
+        SssError::ReservesExceeded
+    );
+    Ok(())
+}
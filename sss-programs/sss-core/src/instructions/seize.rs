@@ -1,23 +1,36 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
+use crate::constants::capability;
 use crate::error::SssError;
 use crate::events::TokensSeized;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::instructions::event_queue::record_event;
+use crate::instructions::freeze_account::freeze_as_authority;
+use crate::instructions::seize_approval::consume_seize_approval;
+use crate::instructions::thaw_account::thaw_as_authority;
+use crate::state::{
+    EventQueue, QueuedEventKind, Role, RoleAccount, SeizeApproval, SeizureRecord, StablecoinConfig,
+};
 
 #[derive(Accounts)]
 pub struct Seize<'info> {
+    #[account(mut)]
     pub seizer: Signer<'info>,
 
-    /// NO pause check — seizure works during emergencies.
+    /// NO pause check — seizure works during emergencies. Mutable to
+    /// advance `seizure_count`.
     #[account(
+        mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
         bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
     /// Seizer role PDA — its existence proves seizure authorization.
     #[account(
+        mut,
         seeds = [
             RoleAccount::SSS_ROLE_SEED,
             config.key().as_ref(),
@@ -25,6 +38,7 @@ pub struct Seize<'info> {
             &[Role::Seizer.as_u8()],
         ],
         bump = seizer_role.bump,
+        constraint = seizer_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
     )]
     pub seizer_role: Account<'info, RoleAccount>,
 
@@ -39,40 +53,220 @@ pub struct Seize<'info> {
     )]
     pub from: InterfaceAccount<'info, TokenAccount>,
 
+    /// The treasury's associated token account while `config.treasury` is
+    /// set, so a Seizer cannot redirect seized funds.
     #[account(
         mut,
         token::mint = mint,
+        constraint = is_seize_destination(&config, &mint.key(), &to.to_account_info())
+            @ SssError::SeizeDestinationNotTreasury,
     )]
     pub to: InterfaceAccount<'info, TokenAccount>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Required while `config.event_queue_enabled`; see `EventQueue`.
+    #[account(
+        mut,
+        seeds = [EventQueue::SSS_EVENT_QUEUE_SEED, config.key().as_ref()],
+        bump = event_queue.bump,
+    )]
+    pub event_queue: Option<Account<'info, EventQueue>>,
+
+    /// A second key's approval of exactly this seizure. Required above
+    /// `config.large_seize_threshold`; closed to the seizer on success.
+    #[account(mut, close = seizer)]
+    pub seize_approval: Option<Account<'info, SeizeApproval>>,
+
+    #[account(
+        init,
+        payer = seizer,
+        space = SeizureRecord::SPACE,
+        seeds = [
+            SeizureRecord::SSS_SEIZURE_RECORD_SEED,
+            config.key().as_ref(),
+            &config.seizure_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub seizure_record: Account<'info, SeizureRecord>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler_seize<'info>(
     ctx: Context<'_, '_, '_, 'info, Seize<'info>>,
     amount: u64,
+    reason: String,
 ) -> Result<()> {
-    require!(amount > 0, SssError::ZeroAmount);
+    let record_bump = ctx.bumps.seizure_record;
+    ctx.accounts
+        .seize(amount, reason, record_bump, ctx.remaining_accounts)
+}
+
+impl<'info> Seize<'info> {
+    /// Move `amount` from `from` to `to` as permanent delegate, forwarding
+    /// `hook_accounts` for the transfer hook, and record it with `reason`.
+    /// Shared with `seize_idempotent`.
+    pub(crate) fn seize(
+        &mut self,
+        amount: u64,
+        reason: String,
+        record_bump: u8,
+        hook_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(amount > 0, SssError::ZeroAmount);
+        require!(
+            SeizureRecord::is_valid_reason(&reason),
+            SssError::InvalidSeizureReason
+        );
+        require!(
+            self.config.is_enabled(capability::SEIZE),
+            SssError::InstructionDisabled
+        );
+        let frozen = self.from.is_frozen();
+        require!(
+            frozen || !self.config.seize_requires_frozen,
+            SssError::SeizeRequiresFrozenAccount
+        );
+        let approver = consume_seize_approval(
+            &self.config,
+            self.seize_approval.as_ref(),
+            &self.seizer.key(),
+            &self.from.key(),
+            &self.to.key(),
+            amount,
+        )?;
+        require!(
+            self.seizer_role.record_action(Clock::get()?.unix_timestamp),
+            SssError::ActionQuotaExceeded
+        );
 
-    let mint_key = ctx.accounts.mint.key();
-    let decimals = ctx.accounts.mint.decimals;
+        // Token-2022 refuses transfers out of a frozen account, even by the
+        // permanent delegate, so the freeze is lifted around the transfer.
+        if frozen {
+            thaw_as_authority(&self.token_program, &self.config, &self.mint, &self.from)?;
+        }
+        transfer_as_delegate(
+            &self.token_program.to_account_info(),
+            &self.config,
+            &self.mint,
+            &self.from.to_account_info(),
+            &self.to.to_account_info(),
+            hook_accounts,
+            amount,
+        )?;
+        if frozen {
+            freeze_as_authority(&self.token_program, &self.config, &self.mint, &self.from)?;
+        }
+
+        let seizer = self.seizer.key();
+        let index = self
+            .config
+            .next_seizure_index()
+            .ok_or(SssError::ArithmeticOverflow)?;
+        self.seizure_record.set_inner(SeizureRecord {
+            config: self.config.key(),
+            index,
+            seizer,
+            from: self.from.key(),
+            to: self.to.key(),
+            amount,
+            approver,
+            reason: reason.clone(),
+            seized_at: Clock::get()?.unix_timestamp,
+            bump: record_bump,
+        });
+
+        emit!(TokensSeized {
+            mint: self.mint.key(),
+            from: self.from.key(),
+            to: self.to.key(),
+            amount,
+            seizer,
+            reason,
+            config: self.config.key(),
+            mint_index: self.config.mint_index,
+        });
+
+        record_event(
+            &self.config,
+            self.event_queue.as_mut(),
+            QueuedEventKind::Seize,
+            self.from.key(),
+            self.seizer.key(),
+            amount,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Whether `to` may receive a seizure: any token account while
+/// `config.treasury` is unset, otherwise only the treasury's associated token
+/// account.
+fn is_seize_destination(config: &StablecoinConfig, mint: &Pubkey, to: &AccountInfo) -> bool {
+    config.treasury.is_none_or(|treasury| {
+        to.key() == get_associated_token_address_with_program_id(&treasury, mint, to.owner)
+    })
+}
+
+/// `transfer_checked` from `from` to `to`, signed by the config PDA as the
+/// mint's permanent delegate. `hook_accounts` are forwarded so Token-2022 can
+/// invoke the transfer hook on SSS-2 mints.
+pub(crate) fn transfer_as_delegate<'info>(
+    token_program: &AccountInfo<'info>,
+    config: &Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let mint_key = mint.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
         StablecoinConfig::SSS_CONFIG_SEED,
         mint_key.as_ref(),
-        &[ctx.accounts.config.bump],
+        &[config.bump],
     ]];
+    transfer_with_hook(
+        token_program,
+        mint,
+        from,
+        to,
+        &config.to_account_info(),
+        hook_accounts,
+        amount,
+        signer_seeds,
+    )
+}
 
+/// `transfer_checked` from `from` to `to` by `authority`, which either signs
+/// the outer transaction or is a PDA named by `signer_seeds`.
+/// `hook_accounts` are forwarded so Token-2022 can invoke the transfer hook
+/// on SSS-2 mints.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn transfer_with_hook<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
     // Manually build the TransferChecked instruction to ensure exact account forwarding
     // for Token-2022 transfer hooks.
     let mut account_metas = vec![
-        AccountMeta::new(ctx.accounts.from.key(), false),
-        AccountMeta::new_readonly(ctx.accounts.mint.key(), false),
-        AccountMeta::new(ctx.accounts.to.key(), false),
-        AccountMeta::new_readonly(ctx.accounts.config.key(), true), // Authority (is_signer = true for invoke_signed)
+        AccountMeta::new(from.key(), false),
+        AccountMeta::new_readonly(mint.key(), false),
+        AccountMeta::new(to.key(), false),
+        AccountMeta::new_readonly(authority.key(), true),
     ];
 
     // Append extra hook accounts
-    for acc in ctx.remaining_accounts.iter() {
+    for acc in hook_accounts.iter() {
         account_metas.push(AccountMeta {
             pubkey: acc.key(),
             is_signer: acc.is_signer,
@@ -83,31 +277,23 @@ pub fn handler_seize<'info>(
     let mut data = Vec::with_capacity(13);
     data.push(12); // TransferChecked discriminator for Token-2022
     data.extend_from_slice(&amount.to_le_bytes());
-    data.push(decimals);
+    data.push(mint.decimals);
 
     let ix = anchor_lang::solana_program::instruction::Instruction {
-        program_id: ctx.accounts.token_program.key(),
+        program_id: token_program.key(),
         accounts: account_metas,
         data,
     };
 
     let mut invoke_accounts = vec![
-        ctx.accounts.from.to_account_info(),
-        ctx.accounts.mint.to_account_info(),
-        ctx.accounts.to.to_account_info(),
-        ctx.accounts.config.to_account_info(),
+        from.clone(),
+        mint.to_account_info(),
+        to.clone(),
+        authority.clone(),
     ];
-    invoke_accounts.extend_from_slice(&ctx.remaining_accounts);
+    invoke_accounts.extend_from_slice(hook_accounts);
 
     anchor_lang::solana_program::program::invoke_signed(&ix, &invoke_accounts, signer_seeds)?;
 
-    emit!(TokensSeized {
-        mint: ctx.accounts.mint.key(),
-        from: ctx.accounts.from.key(),
-        to: ctx.accounts.to.key(),
-        amount,
-        seizer: ctx.accounts.seizer.key(),
-    });
-
     Ok(())
 }
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::error::SssError;
+use crate::events::{SeizeApprovalCancelled, SeizeApprovalExecuted, SeizeApproved};
+use crate::state::{Role, RoleAccount, SeizeApproval, StablecoinConfig};
+
+/// Gate for seizures above `config.large_seize_threshold`, which take
+/// `seize_approval` as an optional account closed on success. A passed
+/// approval is checked even when the amount does not need one. Returns the
+/// approver, if any.
+pub(crate) fn consume_seize_approval(
+    config: &Account<StablecoinConfig>,
+    seize_approval: Option<&Account<SeizeApproval>>,
+    seizer: &Pubkey,
+    from: &Pubkey,
+    to: &Pubkey,
+    amount: u64,
+) -> Result<Option<Pubkey>> {
+    let Some(approval) = seize_approval else {
+        require!(
+            !config.requires_seize_approval(amount),
+            SssError::LargeSeizeApprovalRequired
+        );
+        return Ok(None);
+    };
+    require!(
+        approval.config == config.key() && approval.authorizes(seizer, from, to, amount),
+        SssError::SeizeApprovalMismatch
+    );
+
+    emit!(SeizeApprovalExecuted {
+        config: config.key(),
+        seizer: approval.seizer,
+        approver: approval.approver,
+        id: approval.id,
+        amount,
+        mint_index: config.mint_index,
+    });
+
+    Ok(Some(approval.approver))
+}
+
+// Approve Seize
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct ApproveSeize<'info> {
+    #[account(mut)]
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Seizer or Admin role PDA of `approver`.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            approver.key().as_ref(),
+            &[approver_role.role.as_u8()],
+        ],
+        bump = approver_role.bump,
+        constraint = matches!(approver_role.role, Role::Seizer | Role::Admin) @ SssError::Unauthorized,
+        constraint = approver_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub approver_role: Account<'info, RoleAccount>,
+
+    #[account(constraint = from.mint == config.mint @ SssError::MintMismatch)]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = to.mint == config.mint @ SssError::MintMismatch)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = approver,
+        space = SeizeApproval::SPACE,
+        seeds = [
+            SeizeApproval::SSS_SEIZE_APPROVAL_SEED,
+            config.key().as_ref(),
+            approver.key().as_ref(),
+            &id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub seize_approval: Account<'info, SeizeApproval>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sign off on `seizer` moving `amount` from `from` to `to`. The approver
+/// must be a different key from the seizer. Nothing is checked against the
+/// balance here; the seizure itself applies the usual checks.
+pub fn handler_approve_seize(
+    ctx: Context<ApproveSeize>,
+    id: u64,
+    seizer: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    let approver = ctx.accounts.approver.key();
+    require!(approver != seizer, SssError::SeizeApprovalRejected);
+
+    let approval = &mut ctx.accounts.seize_approval;
+    approval.config = ctx.accounts.config.key();
+    approval.approver = approver;
+    approval.id = id;
+    approval.seizer = seizer;
+    approval.from = ctx.accounts.from.key();
+    approval.to = ctx.accounts.to.key();
+    approval.amount = amount;
+    approval.approved_at = Clock::get()?.unix_timestamp;
+    approval.bump = ctx.bumps.seize_approval;
+
+    emit!(SeizeApproved {
+        config: approval.config,
+        approver,
+        id,
+        seizer,
+        from: approval.from,
+        to: approval.to,
+        amount,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Cancel Seize Approval
+
+#[derive(Accounts)]
+pub struct CancelSeizeApproval<'info> {
+    #[account(mut)]
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        close = approver,
+        has_one = config @ SssError::SeizeApprovalMismatch,
+        has_one = approver @ SssError::Unauthorized,
+    )]
+    pub seize_approval: Account<'info, SeizeApproval>,
+}
+
+/// Withdraw an unused approval and reclaim its rent.
+pub fn handler_cancel_seize_approval(ctx: Context<CancelSeizeApproval>) -> Result<()> {
+    let approval = &ctx.accounts.seize_approval;
+
+    emit!(SeizeApprovalCancelled {
+        config: approval.config,
+        approver: approval.approver,
+        id: approval.id,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::error::SssError;
+use crate::events::SnapshotBalanceRecorded;
+use crate::state::{Snapshot, SnapshotBalance, StablecoinConfig};
+
+/// Permissionless: the holder or a crank records a token account's balance
+/// while the snapshot is open.
+#[derive(Accounts)]
+pub struct RecordSnapshotBalance<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            Snapshot::SSS_SNAPSHOT_SEED,
+            config.key().as_ref(),
+            &snapshot.slot.to_le_bytes(),
+        ],
+        bump = snapshot.bump,
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = SnapshotBalance::SPACE,
+        seeds = [
+            SnapshotBalance::SSS_SNAPSHOT_BALANCE_SEED,
+            snapshot.key().as_ref(),
+            token_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub snapshot_balance: Account<'info, SnapshotBalance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_record_snapshot_balance(ctx: Context<RecordSnapshotBalance>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    require!(
+        ctx.accounts.snapshot.is_open(slot),
+        SssError::SnapshotNotOpen
+    );
+
+    let amount = ctx.accounts.token_account.amount;
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.total_recorded = snapshot
+        .total_recorded
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    snapshot.record_count = snapshot
+        .record_count
+        .checked_add(1)
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    let record = &mut ctx.accounts.snapshot_balance;
+    record.snapshot = snapshot.key();
+    record.token_account = ctx.accounts.token_account.key();
+    record.owner = ctx.accounts.token_account.owner;
+    record.amount = amount;
+    record.recorded_slot = slot;
+    record.bump = ctx.bumps.snapshot_balance;
+
+    emit!(SnapshotBalanceRecorded {
+        snapshot: record.snapshot,
+        token_account: record.token_account,
+        owner: record.owner,
+        amount,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
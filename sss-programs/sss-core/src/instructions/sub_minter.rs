@@ -0,0 +1,329 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::pause;
+use crate::error::SssError;
+use crate::events::{QuotaDelegated, SubMinterRevoked};
+use crate::instructions::destination_throttle::record_destination_mint;
+use crate::instructions::fees::fee_due;
+use crate::instructions::kyc::require_kyc;
+use crate::instructions::mint_tokens::issue_less_fee;
+use crate::instructions::reserve_attestation::require_attested_reserves;
+use crate::state::{
+    DestinationThrottle, FeeKind, FeeSchedule, FeeTierAssignment, KycEntry, ReserveAttestation,
+    Role, RoleAccount, StablecoinConfig, SubMinter,
+};
+
+/// A Minter delegates (or re-sizes) a slice of its quota to `sub_minter`.
+/// Calling again for the same sub-minter replaces the slice and keeps the
+/// running `amount_minted`.
+#[derive(Accounts)]
+pub struct DelegateQuota<'info> {
+    #[account(mut)]
+    pub parent: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Parent's Minter role PDA — proves the signer may delegate.
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            parent.key().as_ref(),
+            &[Role::Minter.as_u8()],
+        ],
+        bump = parent_role.bump,
+        constraint = parent_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub parent_role: Account<'info, RoleAccount>,
+
+    /// CHECK: Any key may be a sub-minter; it only needs to sign when minting.
+    pub sub_minter: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = parent,
+        space = SubMinter::SPACE,
+        seeds = [
+            SubMinter::SSS_SUB_MINTER_SEED,
+            config.key().as_ref(),
+            parent.key().as_ref(),
+            sub_minter.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub sub_minter_account: Account<'info, SubMinter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_delegate_quota(ctx: Context<DelegateQuota>, quota: u64) -> Result<()> {
+    require!(quota > 0, SssError::ZeroAmount);
+
+    let sub = &mut ctx.accounts.sub_minter_account;
+    sub.config = ctx.accounts.config.key();
+    sub.parent = ctx.accounts.parent.key();
+    sub.address = ctx.accounts.sub_minter.key();
+    sub.quota = quota;
+    sub.delegated_at = Clock::get()?.unix_timestamp;
+    sub.bump = ctx.bumps.sub_minter_account;
+
+    emit!(QuotaDelegated {
+        config: sub.config,
+        parent: sub.parent,
+        sub_minter: sub.address,
+        quota,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// The parent closes a sub-minter's slice. Rent returns to the parent.
+/// Does not require the parent to still hold the Minter role, so a
+/// demoted minter can still clean up.
+#[derive(Accounts)]
+pub struct RevokeSubMinter<'info> {
+    #[account(mut)]
+    pub parent: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        close = parent,
+        seeds = [
+            SubMinter::SSS_SUB_MINTER_SEED,
+            config.key().as_ref(),
+            parent.key().as_ref(),
+            sub_minter_account.address.as_ref(),
+        ],
+        bump = sub_minter_account.bump,
+    )]
+    pub sub_minter_account: Account<'info, SubMinter>,
+}
+
+pub fn handler_revoke_sub_minter(ctx: Context<RevokeSubMinter>) -> Result<()> {
+    let sub = &ctx.accounts.sub_minter_account;
+
+    emit!(SubMinterRevoked {
+        config: sub.config,
+        parent: sub.parent,
+        sub_minter: sub.address,
+        amount_minted: sub.amount_minted,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// `mint_tokens` signed by a sub-minter. The amount is checked against both
+/// the delegated slice and the parent's quota, and recorded on both.
+#[derive(Accounts)]
+pub struct MintTokensAsSubMinter<'info> {
+    pub sub_minter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::MINT, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            SubMinter::SSS_SUB_MINTER_SEED,
+            config.key().as_ref(),
+            sub_minter_account.parent.as_ref(),
+            sub_minter.key().as_ref(),
+        ],
+        bump = sub_minter_account.bump,
+    )]
+    pub sub_minter_account: Account<'info, SubMinter>,
+
+    /// Parent's Minter role PDA. Must still exist: revoking the parent's
+    /// role disables every slice it delegated.
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            sub_minter_account.parent.as_ref(),
+            &[Role::Minter.as_u8()],
+        ],
+        bump = parent_role.bump,
+        constraint = parent_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub parent_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: optional oracle account; see `MintTokens::price_update`.
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// KYC approval for the owner of `to`; see `MintTokens::kyc_entry`.
+    pub kyc_entry: Option<Account<'info, KycEntry>>,
+
+    /// `to`'s throttle; see `MintTokens::destination_throttle`.
+    #[account(
+        mut,
+        seeds = [
+            DestinationThrottle::SSS_DESTINATION_THROTTLE_SEED,
+            config.key().as_ref(),
+            to.key().as_ref(),
+        ],
+        bump = destination_throttle.bump,
+    )]
+    pub destination_throttle: Option<Account<'info, DestinationThrottle>>,
+
+    /// See `MintTokens::reserve_attestation`.
+    #[account(
+        seeds = [ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED, config.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+
+    /// See `MintTokens::fee_schedule`.
+    #[account(
+        seeds = [FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.key().as_ref()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
+
+    /// CHECK: `FeeTierAssignment` address of the owner of `to`, who pays the mint
+    /// fee; see `MintTokens::fee_tier`.
+    #[account(
+        seeds = [
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.key().as_ref(),
+            to.owner.as_ref(),
+        ],
+        bump,
+    )]
+    pub fee_tier: Option<UncheckedAccount<'info>>,
+
+    /// See `MintTokens::fee_treasury`.
+    #[account(mut, token::mint = mint)]
+    pub fee_treasury: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+pub fn handler_mint_tokens_as_sub_minter(
+    ctx: Context<MintTokensAsSubMinter>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SssError::ZeroAmount);
+    // Sub-minters have no receipt variant or mint approvals, so large
+    // issuances must go through the parent.
+    if let Some(threshold) = ctx.accounts.config.receipt_threshold {
+        require!(amount < threshold, SssError::MintReceiptRequired);
+    }
+    require!(
+        !ctx.accounts.config.requires_mint_approval(amount),
+        SssError::LargeMintApprovalRequired
+    );
+
+    let within_slice = ctx
+        .accounts
+        .sub_minter_account
+        .quota_allows(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_slice, SssError::SubMinterQuotaExceeded);
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.parent_role.roll_quota_period(now);
+    let within_parent = ctx
+        .accounts
+        .parent_role
+        .quota_allows(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_parent, SssError::QuotaExceeded);
+    let within_rate = ctx
+        .accounts
+        .parent_role
+        .rate_allows(amount, now)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    require!(within_rate, SssError::MintRateLimitExceeded);
+    require!(
+        ctx.accounts.parent_role.hours_allow(now),
+        SssError::OutsideOperatingHours
+    );
+    require_kyc(
+        &ctx.accounts.config,
+        ctx.accounts.kyc_entry.as_ref(),
+        &ctx.accounts.to.owner,
+    )?;
+
+    let accounts = &mut *ctx.accounts;
+    record_destination_mint(
+        &accounts.config,
+        accounts.destination_throttle.as_mut(),
+        amount,
+        now,
+    )?;
+    require_attested_reserves(
+        &accounts.config,
+        accounts.reserve_attestation.as_ref(),
+        amount,
+        now,
+    )?;
+    let fee = fee_due(
+        &accounts.config,
+        accounts.fee_schedule.as_deref(),
+        accounts.fee_tier.as_deref(),
+        accounts.fee_treasury.as_deref(),
+        FeeKind::Mint,
+        amount,
+    )?;
+    issue_less_fee(
+        &mut accounts.config,
+        &accounts.mint,
+        &accounts.to,
+        &accounts.token_program,
+        accounts.price_update.as_ref(),
+        accounts.sub_minter.key(),
+        amount,
+        fee,
+    )?;
+
+    // Roll the amount up into the parent's counter as well as the slice.
+    accounts.sub_minter_account.amount_minted = accounts
+        .sub_minter_account
+        .amount_minted
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    accounts.parent_role.amount_minted = accounts
+        .parent_role
+        .amount_minted
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    accounts
+        .parent_role
+        .record_rate(amount, now)
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    Ok(())
+}
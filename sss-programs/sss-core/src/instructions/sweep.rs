@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::pause;
+use crate::error::SssError;
+use crate::events::{IntakeSwept, SweepRouteClosed, SweepRouteRegistered};
+use crate::instructions::seize::transfer_as_delegate;
+use crate::state::{Role, RoleAccount, StablecoinConfig, SweepRoute};
+
+// Register Sweep Route
+
+#[derive(Accounts)]
+pub struct RegisterSweepRoute<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    /// The intake account's owner co-signs, so an Admin cannot route a
+    /// holder's account to the treasury on its own.
+    pub intake_owner: Signer<'info>,
+
+    #[account(
+        constraint = intake.mint == config.mint @ SssError::MintMismatch,
+        constraint = intake.owner == intake_owner.key() @ SssError::Unauthorized,
+    )]
+    pub intake: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = treasury.mint == config.mint @ SssError::MintMismatch,
+        constraint = treasury.key() != intake.key() @ SssError::InvalidSweepRoute,
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = SweepRoute::SPACE,
+        seeds = [
+            SweepRoute::SSS_SWEEP_ROUTE_SEED,
+            config.key().as_ref(),
+            intake.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub sweep_route: Account<'info, SweepRoute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Route `intake` into `treasury`. The config PDA moves the funds, so the
+/// intake must either be on a mint with the config as permanent delegate
+/// (SSS-2) or have the config approved as its delegate.
+pub fn handler_register_sweep_route(
+    ctx: Context<RegisterSweepRoute>,
+    threshold: u64,
+    min_interval: i64,
+) -> Result<()> {
+    require!(min_interval >= 0, SssError::InvalidSweepRoute);
+
+    let route = &mut ctx.accounts.sweep_route;
+    route.config = ctx.accounts.config.key();
+    route.intake = ctx.accounts.intake.key();
+    route.intake_owner = ctx.accounts.intake_owner.key();
+    route.treasury = ctx.accounts.treasury.key();
+    route.threshold = threshold;
+    route.min_interval = min_interval;
+    route.last_swept_at = 0;
+    route.registered_by = ctx.accounts.admin.key();
+    route.bump = ctx.bumps.sweep_route;
+
+    emit!(SweepRouteRegistered {
+        config: route.config,
+        intake: route.intake,
+        treasury: route.treasury,
+        threshold,
+        min_interval,
+        registered_by: route.registered_by,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Close Sweep Route
+
+#[derive(Accounts)]
+pub struct CloseSweepRoute<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            admin.key().as_ref(),
+            &[Role::Admin.as_u8()],
+        ],
+        bump = admin_role.bump,
+    )]
+    pub admin_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            SweepRoute::SSS_SWEEP_ROUTE_SEED,
+            config.key().as_ref(),
+            sweep_route.intake.as_ref(),
+        ],
+        bump = sweep_route.bump,
+    )]
+    pub sweep_route: Account<'info, SweepRoute>,
+}
+
+pub fn handler_close_sweep_route(ctx: Context<CloseSweepRoute>) -> Result<()> {
+    emit!(SweepRouteClosed {
+        config: ctx.accounts.config.key(),
+        intake: ctx.accounts.sweep_route.intake,
+        closed_by: ctx.accounts.admin.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Sweep Intake
+
+/// Permissionless crank: anyone may sweep a due intake, and the funds can
+/// only go to the registered treasury. Remaining accounts are forwarded to
+/// the transfer hook on SSS-2 mints.
+#[derive(Accounts)]
+pub struct SweepIntake<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::TRANSFERS, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            SweepRoute::SSS_SWEEP_ROUTE_SEED,
+            config.key().as_ref(),
+            intake.key().as_ref(),
+        ],
+        bump = sweep_route.bump,
+        constraint = sweep_route.treasury == treasury.key() @ SssError::InvalidSweepRoute,
+    )]
+    pub sweep_route: Account<'info, SweepRoute>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = intake.owner == sweep_route.intake_owner @ SssError::InvalidSweepRoute,
+    )]
+    pub intake: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_sweep_intake<'info>(
+    ctx: Context<'_, '_, '_, 'info, SweepIntake<'info>>,
+) -> Result<()> {
+    let amount = ctx.accounts.intake.amount;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.sweep_route.is_due(amount, now),
+        SssError::SweepNotDue
+    );
+
+    transfer_as_delegate(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.config,
+        &ctx.accounts.mint,
+        &ctx.accounts.intake.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+    )?;
+
+    ctx.accounts.sweep_route.last_swept_at = now;
+
+    emit!(IntakeSwept {
+        config: ctx.accounts.config.key(),
+        intake: ctx.accounts.intake.key(),
+        treasury: ctx.accounts.treasury.key(),
+        amount,
+        cranker: ctx.accounts.cranker.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
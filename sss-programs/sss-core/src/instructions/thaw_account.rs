@@ -1,6 +1,7 @@
+use crate::constants::{capability, pause};
 use crate::error::SssError;
-use crate::events::AccountThawed;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::events::{AccountThawed, ExemptAccountThawed};
+use crate::state::{FreezeExemption, Role, RoleAccount, StablecoinConfig};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
     self, Mint, ThawAccount as ThawAccountCpi, TokenAccount, TokenInterface,
@@ -13,7 +14,8 @@ pub struct ThawTokenAccount<'info> {
     #[account(
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
         bump = config.bump,
-        constraint = !config.paused @ SssError::Paused,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::FREEZE_THAW, Clock::get()?.unix_timestamp) @ SssError::Paused,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
@@ -25,6 +27,7 @@ pub struct ThawTokenAccount<'info> {
             &[Role::Freezer.as_u8()],
         ],
         bump = freezer_role.bump,
+        constraint = freezer_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
     )]
     pub freezer_role: Account<'info, RoleAccount>,
 
@@ -43,28 +46,118 @@ pub struct ThawTokenAccount<'info> {
 }
 
 pub fn handler_thaw_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
-    let mint_key = ctx.accounts.mint.key();
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        StablecoinConfig::SSS_CONFIG_SEED,
-        mint_key.as_ref(),
-        &[ctx.accounts.config.bump],
-    ]];
-
-    let cpi_accounts = ThawAccountCpi {
-        account: ctx.accounts.token_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        authority: ctx.accounts.config.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
-        .with_signer(signer_seeds);
-
-    token_interface::thaw_account(cpi_ctx)?;
+    let accounts = &ctx.accounts;
+    thaw_as_config(
+        &accounts.token_program,
+        &accounts.config,
+        &accounts.mint,
+        &accounts.token_account,
+    )?;
 
     emit!(AccountThawed {
         mint: ctx.accounts.mint.key(),
         account: ctx.accounts.token_account.key(),
         freezer: ctx.accounts.freezer.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
     });
 
     Ok(())
 }
+
+/// Permissionless thaw of a token account whose owner holds a
+/// `FreezeExemption`, so treasury and market-maker accounts on
+/// default-frozen mints do not wait on a Freezer.
+#[derive(Accounts)]
+pub struct ThawExemptAccount<'info> {
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = !config.pauses(pause::FREEZE_THAW, Clock::get()?.unix_timestamp) @ SssError::Paused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            FreezeExemption::SSS_FREEZE_EXEMPTION_SEED,
+            config.key().as_ref(),
+            token_account.owner.as_ref(),
+        ],
+        bump = freeze_exemption.bump,
+    )]
+    pub freeze_exemption: Account<'info, FreezeExemption>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler_thaw_exempt_account(ctx: Context<ThawExemptAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    thaw_as_config(
+        &accounts.token_program,
+        &accounts.config,
+        &accounts.mint,
+        &accounts.token_account,
+    )?;
+
+    emit!(ExemptAccountThawed {
+        mint: ctx.accounts.mint.key(),
+        account: ctx.accounts.token_account.key(),
+        owner: ctx.accounts.token_account.owner,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Thaw `token_account` signed by the config PDA as freeze authority.
+fn thaw_as_config<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    config: &Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<()> {
+    require!(
+        config.is_enabled(capability::THAW),
+        SssError::InstructionDisabled
+    );
+    thaw_as_authority(token_program, config, mint, token_account)
+}
+
+/// `thaw_as_config` without the capability check, for `seize` lifting a
+/// freeze only for the length of its own transfer.
+pub(crate) fn thaw_as_authority<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    config: &Account<'info, StablecoinConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<()> {
+    let mint_key = mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        StablecoinConfig::SSS_CONFIG_SEED,
+        mint_key.as_ref(),
+        &[config.bump],
+    ]];
+
+    let cpi_accounts = ThawAccountCpi {
+        account: token_account.to_account_info(),
+        mint: mint.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx =
+        CpiContext::new(token_program.to_account_info(), cpi_accounts).with_signer(signer_seeds);
+
+    token_interface::thaw_account(cpi_ctx)
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::error::SssError;
-use crate::events::OperationsUnpaused;
-use crate::state::{Role, RoleAccount, StablecoinConfig};
+use crate::events::{OperationsUnpaused, ThawRampStarted};
+use crate::instructions::event_queue::record_event;
+use crate::state::{EventQueue, QueuedEventKind, RoleAccount, StablecoinConfig};
 
 #[derive(Accounts)]
 pub struct Unpause<'info> {
@@ -12,30 +13,67 @@ pub struct Unpause<'info> {
         mut,
         seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
         bump = config.bump,
-        constraint = config.paused @ SssError::NotPaused,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+        constraint = config.is_paused(Clock::get()?.unix_timestamp) @ SssError::NotPaused,
     )]
     pub config: Account<'info, StablecoinConfig>,
 
+    /// Pauser role PDA of `pauser`, or its Admin role PDA while
+    /// `config.unpause_requires_admin`.
     #[account(
         seeds = [
             RoleAccount::SSS_ROLE_SEED,
             config.key().as_ref(),
             pauser.key().as_ref(),
-            &[Role::Pauser.as_u8()],
+            &[pauser_role.role.as_u8()],
         ],
         bump = pauser_role.bump,
+        constraint = pauser_role.role == config.unpause_role() @ SssError::Unauthorized,
+        constraint = pauser_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
     )]
     pub pauser_role: Account<'info, RoleAccount>,
+
+    /// Required while `config.event_queue_enabled`; see `EventQueue`.
+    #[account(
+        mut,
+        seeds = [EventQueue::SSS_EVENT_QUEUE_SEED, config.key().as_ref()],
+        bump = event_queue.bump,
+    )]
+    pub event_queue: Option<Account<'info, EventQueue>>,
 }
 
 pub fn handler_unpause(ctx: Context<Unpause>) -> Result<()> {
     let config = &mut ctx.accounts.config;
-    config.paused = false;
+    let clock = Clock::get()?;
+    let slot = clock.slot;
+    if config.set_pause_flags(0, slot, clock.unix_timestamp) {
+        let ramp_slots = config.thaw_ramp.map_or(0, |ramp| ramp.ramp_slots);
+        emit!(ThawRampStarted {
+            mint: config.mint,
+            start_slot: slot,
+            end_slot: slot.saturating_add(ramp_slots),
+            base_supply: config.ramp_base_supply,
+            config: config.key(),
+            mint_index: config.mint_index,
+        });
+    }
+    config.refresh_digest();
 
     emit!(OperationsUnpaused {
         mint: config.mint,
         pauser: ctx.accounts.pauser.key(),
+        config: config.key(),
+        mint_index: config.mint_index,
     });
 
+    record_event(
+        &ctx.accounts.config,
+        ctx.accounts.event_queue.as_mut(),
+        QueuedEventKind::Unpause,
+        ctx.accounts.config.mint,
+        ctx.accounts.pauser.key(),
+        0,
+    )?;
+
     Ok(())
 }
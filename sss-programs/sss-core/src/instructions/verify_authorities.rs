@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    permanent_delegate::PermanentDelegate, transfer_hook::TransferHook,
+};
+use anchor_spl::token_interface::{get_mint_extension_data, Mint};
+
+use crate::constants::{capability, pause};
+use crate::error::SssError;
+use crate::events::{AuthorityAnomaly, OperationsPaused};
+use crate::instructions::event_queue::record_event;
+use crate::state::{EventQueue, MintAuthorities, QueuedEventKind, StablecoinConfig};
+
+/// Permissionless check that nobody has taken over the mint by a path this
+/// program does not control.
+#[derive(Accounts)]
+pub struct VerifyAuthorities<'info> {
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(constraint = config.mint == mint.key() @ SssError::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Required while `config.event_queue_enabled`, for the pause an
+    /// anomaly may trigger; see `EventQueue`.
+    #[account(
+        mut,
+        seeds = [EventQueue::SSS_EVENT_QUEUE_SEED, config.key().as_ref()],
+        bump = event_queue.bump,
+    )]
+    pub event_queue: Option<Account<'info, EventQueue>>,
+}
+
+/// Emit `AuthorityAnomaly` unless the mint still names the config PDA as
+/// its mint, freeze and (where enabled) permanent-delegate authority and
+/// the SSS transfer hook where enabled. With `pause_on_authority_anomaly`
+/// set, an anomaly also pauses the config, with the config PDA as pauser.
+pub fn handler_verify_authorities(ctx: Context<VerifyAuthorities>) -> Result<()> {
+    let observed = observe_mint_authorities(&ctx.accounts.mint);
+
+    let config_key = ctx.accounts.config.key();
+    let config = &mut ctx.accounts.config;
+    if config.authorities_intact(&config_key, &observed) {
+        return Ok(());
+    }
+
+    let clock = Clock::get()?;
+    let paused = config.pause_on_authority_anomaly
+        && !config.is_fully_paused(clock.unix_timestamp)
+        && config.is_enabled(capability::PAUSE);
+    if paused {
+        config.set_pause_flags(pause::ALL, clock.slot, clock.unix_timestamp);
+        config.refresh_digest();
+
+        emit!(OperationsPaused {
+            mint: config.mint,
+            pauser: config_key,
+            config: config_key,
+            mint_index: config.mint_index,
+        });
+        record_event(
+            &ctx.accounts.config,
+            ctx.accounts.event_queue.as_mut(),
+            QueuedEventKind::Pause,
+            ctx.accounts.config.mint,
+            config_key,
+            0,
+        )?;
+    }
+
+    emit!(AuthorityAnomaly {
+        mint: ctx.accounts.config.mint,
+        observed,
+        paused,
+        config: config_key,
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Read the authorities and hook program `mint` currently names.
+pub(crate) fn observe_mint_authorities(mint: &InterfaceAccount<Mint>) -> MintAuthorities {
+    let mint_info = mint.to_account_info();
+    MintAuthorities {
+        mint_authority: mint.mint_authority.into(),
+        freeze_authority: mint.freeze_authority.into(),
+        permanent_delegate: get_mint_extension_data::<PermanentDelegate>(&mint_info)
+            .ok()
+            .and_then(|ext| Option::<Pubkey>::from(ext.delegate)),
+        transfer_hook_program: get_mint_extension_data::<TransferHook>(&mint_info)
+            .ok()
+            .and_then(|ext| Option::<Pubkey>::from(ext.program_id)),
+    }
+}
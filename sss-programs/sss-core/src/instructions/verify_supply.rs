@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::capability;
+use crate::error::SssError;
+use crate::events::SupplyMismatch;
+use crate::state::StablecoinConfig;
+
+/// Permissionless cross-check of the config's counters against the
+/// Token-2022 mint. Works while paused.
+#[derive(Accounts)]
+pub struct VerifySupply<'info> {
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(constraint = config.mint == mint.key() @ SssError::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Emit `SupplyMismatch` if the mint's `supply` differs from
+/// `local_supply()`. When the mint holds tokens the counters do not account
+/// for and `halt_mint_on_supply_mismatch` is set, also disable the mint
+/// capability until an admin re-enables it with `update_capabilities`.
+pub fn handler_verify_supply(ctx: Context<VerifySupply>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let expected = config.local_supply();
+    let actual = ctx.accounts.mint.supply;
+    if actual == expected {
+        return Ok(());
+    }
+
+    let minting_disabled = config.unaccounted_supply(actual) > 0
+        && config.halt_mint_on_supply_mismatch
+        && config.is_enabled(capability::MINT);
+    if minting_disabled {
+        config.disabled_instructions |= capability::MINT;
+        config.refresh_digest();
+    }
+
+    emit!(SupplyMismatch {
+        mint: config.mint,
+        expected,
+        actual,
+        minting_disabled,
+        config: config.key(),
+        mint_index: config.mint_index,
+    });
+
+    Ok(())
+}
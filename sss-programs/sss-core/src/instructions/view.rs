@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SssError;
+use crate::state::{RoleAccount, StablecoinConfig};
+use crate::views::{ConfigView, RoleView};
+
+/// Read-only: no signer, nothing is written. Meant to be simulated or
+/// called by CPI; see `crate::views`.
+#[derive(Accounts)]
+pub struct ViewConfig<'info> {
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, config.mint.as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+}
+
+pub fn handler_view_config(ctx: Context<ViewConfig>) -> Result<ConfigView> {
+    Ok(ctx.accounts.config.view())
+}
+
+/// Read-only, like `ViewConfig`. Any `RoleAccount` owned by this program is
+/// a live grant, so no seeds are needed.
+#[derive(Accounts)]
+pub struct ViewRole<'info> {
+    pub role_account: Account<'info, RoleAccount>,
+}
+
+pub fn handler_view_role(ctx: Context<ViewRole>) -> Result<RoleView> {
+    Ok(ctx.accounts.role_account.view())
+}
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::capability;
+use crate::error::SssError;
+use crate::events::{AccountWiped, TokensBurned};
+use crate::instructions::burn_tokens::burn_as_delegate;
+use crate::instructions::event_queue::record_event;
+use crate::instructions::freeze_account::freeze_as_authority;
+use crate::instructions::thaw_account::thaw_as_authority;
+use crate::state::{EventQueue, QueuedEventKind, Role, RoleAccount, StablecoinConfig, WipeRecord};
+
+/// Destroy the whole balance of a frozen token account under the permanent
+/// delegate, for sanctions cases where funds must be destroyed rather than
+/// seized to a treasury. The account stays frozen, now empty.
+#[derive(Accounts)]
+pub struct WipeAccount<'info> {
+    #[account(mut)]
+    pub wiper: Signer<'info>,
+
+    /// NO pause check — like seizure, wiping works during emergencies.
+    /// Mutable for `total_burned` and `wipe_count`.
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.is_supported_version() @ SssError::UnsupportedConfigVersion,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// Seizer or Admin role PDA of `wiper`. Mutable for the Seizer's action
+    /// quota.
+    #[account(
+        mut,
+        seeds = [
+            RoleAccount::SSS_ROLE_SEED,
+            config.key().as_ref(),
+            wiper.key().as_ref(),
+            &[wiper_role.role.as_u8()],
+        ],
+        bump = wiper_role.bump,
+        constraint = matches!(wiper_role.role, Role::Seizer | Role::Admin) @ SssError::Unauthorized,
+        constraint = wiper_role.is_active(Clock::get()?.unix_timestamp) @ SssError::RoleExpired,
+    )]
+    pub wiper_role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        constraint = config.mint == mint.key() @ SssError::MintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = token_account.is_frozen() @ SssError::WipeRequiresFrozenAccount,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Required while `config.event_queue_enabled`; see `EventQueue`.
+    #[account(
+        mut,
+        seeds = [EventQueue::SSS_EVENT_QUEUE_SEED, config.key().as_ref()],
+        bump = event_queue.bump,
+    )]
+    pub event_queue: Option<Account<'info, EventQueue>>,
+
+    #[account(
+        init,
+        payer = wiper,
+        space = WipeRecord::SPACE,
+        seeds = [
+            WipeRecord::SSS_WIPE_RECORD_SEED,
+            config.key().as_ref(),
+            &config.wipe_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub wipe_record: Account<'info, WipeRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Burn the full balance of `token_account` and record it with `reason`.
+/// Gated by the seize capability, so an issuer that has locked seizure off
+/// cannot wipe either.
+pub fn handler_wipe_account(ctx: Context<WipeAccount>, reason: String) -> Result<()> {
+    require!(
+        WipeRecord::is_valid_reason(&reason),
+        SssError::InvalidWipeReason
+    );
+    require!(
+        ctx.accounts.config.is_enabled(capability::SEIZE),
+        SssError::InstructionDisabled
+    );
+    let record_bump = ctx.bumps.wipe_record;
+    let amount = ctx.accounts.token_account.amount;
+    require!(amount > 0, SssError::ZeroAmount);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.wiper_role.record_action(now),
+        SssError::ActionQuotaExceeded
+    );
+
+    let accounts = &mut *ctx.accounts;
+    // Token-2022 refuses to burn from a frozen account, even by the
+    // permanent delegate, so the freeze is lifted around the burn.
+    thaw_as_authority(
+        &accounts.token_program,
+        &accounts.config,
+        &accounts.mint,
+        &accounts.token_account,
+    )?;
+    burn_as_delegate(
+        &accounts.config,
+        &accounts.mint,
+        &accounts.token_account,
+        &accounts.token_program,
+        amount,
+    )?;
+    freeze_as_authority(
+        &accounts.token_program,
+        &accounts.config,
+        &accounts.mint,
+        &accounts.token_account,
+    )?;
+
+    let config = &mut accounts.config;
+    config.total_burned = config
+        .total_burned
+        .checked_add(amount)
+        .ok_or(SssError::ArithmeticOverflow)?;
+    let index = config
+        .next_wipe_index()
+        .ok_or(SssError::ArithmeticOverflow)?;
+
+    let wiper = accounts.wiper.key();
+    let token_account = accounts.token_account.key();
+    let owner = accounts.token_account.owner;
+    accounts.wipe_record.set_inner(WipeRecord {
+        config: accounts.config.key(),
+        index,
+        wiper,
+        token_account,
+        owner,
+        amount,
+        reason: reason.clone(),
+        wiped_at: now,
+        bump: record_bump,
+    });
+
+    emit!(TokensBurned {
+        mint: accounts.mint.key(),
+        from: token_account,
+        amount,
+        burner: wiper,
+        new_supply: accounts.config.current_supply(),
+        from_owner: owner,
+        config: accounts.config.key(),
+        mint_index: accounts.config.mint_index,
+    });
+    emit!(AccountWiped {
+        config: accounts.config.key(),
+        index,
+        token_account,
+        owner,
+        amount,
+        wiper,
+        reason,
+        mint_index: accounts.config.mint_index,
+    });
+
+    record_event(
+        &accounts.config,
+        accounts.event_queue.as_mut(),
+        QueuedEventKind::Wipe,
+        token_account,
+        wiper,
+        amount,
+    )
+}
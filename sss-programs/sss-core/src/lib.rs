@@ -1,14 +1,30 @@
 use anchor_lang::prelude::*;
 
+pub mod address_book;
+pub mod args;
+pub mod constants;
 pub mod error;
+pub mod error_codes;
 pub mod events;
+#[cfg(feature = "program")]
 pub mod instructions;
+pub mod math;
+pub mod merkle;
+pub mod pda;
+pub mod role_check;
+pub mod seeds;
+pub mod signing;
 pub mod state;
+pub mod version;
+pub mod views;
 
+pub use args::*;
+#[cfg(feature = "program")]
 use instructions::*;
 
 declare_id!("SSSCFmmtaU1oToJ9eMqzTtPbK9EAyoXdivUG4irBHVP");
 
+#[cfg(feature = "program")]
 #[program]
 pub mod sss_core {
     use super::*;
@@ -17,22 +33,213 @@ pub mod sss_core {
         instructions::initialize::handler_initialize(ctx, args)
     }
 
-    pub fn mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
+    pub fn initialize_with_mint(
+        ctx: Context<InitializeWithMint>,
+        args: InitializeArgs,
+    ) -> Result<()> {
+        instructions::initialize::handler_initialize_with_mint(ctx, args)
+    }
+
+    pub fn initialize_deployment_counter(ctx: Context<InitializeDeploymentCounter>) -> Result<()> {
+        instructions::initialize::handler_initialize_deployment_counter(ctx)
+    }
+
+    pub fn mint_tokens(ctx: Context<MintTokens>, amount: u64) -> Result<views::MintOutcome> {
         instructions::mint_tokens::handler_mint_tokens(ctx, amount)
     }
 
+    pub fn mint_tokens_with_receipt(
+        ctx: Context<MintTokensWithReceipt>,
+        amount: u64,
+        reference: [u8; 32],
+    ) -> Result<()> {
+        instructions::mint_with_receipt::handler_mint_tokens_with_receipt(ctx, amount, reference)
+    }
+
+    pub fn mint_tokens_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintTokensBatch<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::mint_batch::handler_mint_tokens_batch(ctx, amounts)
+    }
+
+    pub fn request_mint_approval(
+        ctx: Context<RequestMintApproval>,
+        id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::mint_approval::handler_request_mint_approval(ctx, id, amount)
+    }
+
+    pub fn approve_mint(ctx: Context<ApproveMint>) -> Result<()> {
+        instructions::mint_approval::handler_approve_mint(ctx)
+    }
+
+    pub fn cancel_mint_approval(ctx: Context<CancelMintApproval>) -> Result<()> {
+        instructions::mint_approval::handler_cancel_mint_approval(ctx)
+    }
+
+    pub fn request_mint(
+        ctx: Context<RequestMint>,
+        id: u64,
+        amount: u64,
+        reference: [u8; 32],
+    ) -> Result<()> {
+        instructions::mint_request::handler_request_mint(ctx, id, amount, reference)
+    }
+
+    pub fn fulfill_mint_request(ctx: Context<FulfillMintRequest>) -> Result<()> {
+        instructions::mint_request::handler_fulfill_mint_request(ctx)
+    }
+
+    pub fn cancel_mint_request(ctx: Context<CancelMintRequest>) -> Result<()> {
+        instructions::mint_request::handler_cancel_mint_request(ctx)
+    }
+
     pub fn burn_tokens(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
         instructions::burn_tokens::handler_burn_tokens(ctx, amount)
     }
 
+    pub fn redeem<'info>(
+        ctx: Context<'_, '_, '_, 'info, Redeem<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem::handler_redeem(ctx, amount)
+    }
+
+    pub fn request_redemption<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestRedemption<'info>>,
+        id: u64,
+        amount: u64,
+        bank_reference: [u8; 32],
+    ) -> Result<()> {
+        instructions::redemption::handler_request_redemption(ctx, id, amount, bank_reference)
+    }
+
+    pub fn settle_redemption(ctx: Context<SettleRedemption>) -> Result<()> {
+        instructions::redemption::handler_settle_redemption(ctx)
+    }
+
+    pub fn cancel_redemption<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelRedemption<'info>>,
+    ) -> Result<()> {
+        instructions::redemption::handler_cancel_redemption(ctx)
+    }
+
+    pub fn burn_tokens_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BurnTokensBatch<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::burn_batch::handler_burn_tokens_batch(ctx, amounts)
+    }
+
+    pub fn bridge_mint(ctx: Context<BridgeMint>, amount: u64) -> Result<()> {
+        instructions::bridge::handler_bridge_mint(ctx, amount)
+    }
+
+    pub fn bridge_burn(ctx: Context<BridgeBurn>, amount: u64) -> Result<()> {
+        instructions::bridge::handler_bridge_burn(ctx, amount)
+    }
+
+    pub fn mint_tokens_idempotent(
+        ctx: Context<MintTokensIdempotent>,
+        amount: u64,
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::idempotent::handler_mint_tokens_idempotent(ctx, amount, nonce)
+    }
+
+    pub fn burn_tokens_idempotent(
+        ctx: Context<BurnTokensIdempotent>,
+        amount: u64,
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::idempotent::handler_burn_tokens_idempotent(ctx, amount, nonce)
+    }
+
+    pub fn delegate_quota(ctx: Context<DelegateQuota>, quota: u64) -> Result<()> {
+        instructions::sub_minter::handler_delegate_quota(ctx, quota)
+    }
+
+    pub fn revoke_sub_minter(ctx: Context<RevokeSubMinter>) -> Result<()> {
+        instructions::sub_minter::handler_revoke_sub_minter(ctx)
+    }
+
+    pub fn mint_tokens_as_sub_minter(
+        ctx: Context<MintTokensAsSubMinter>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::sub_minter::handler_mint_tokens_as_sub_minter(ctx, amount)
+    }
+
+    pub fn set_credit_line(
+        ctx: Context<SetCreditLine>,
+        limit: u64,
+        repay_cutoff: u32,
+    ) -> Result<()> {
+        instructions::credit_line::handler_set_credit_line(ctx, limit, repay_cutoff)
+    }
+
+    pub fn draw_credit(ctx: Context<DrawCredit>, amount: u64) -> Result<()> {
+        instructions::credit_line::handler_draw_credit(ctx, amount)
+    }
+
+    pub fn repay_credit(ctx: Context<RepayCredit>, amount: u64) -> Result<()> {
+        instructions::credit_line::handler_repay_credit(ctx, amount)
+    }
+
+    pub fn freeze_defaulted_credit(ctx: Context<FreezeDefaultedCredit>) -> Result<()> {
+        instructions::credit_line::handler_freeze_defaulted_credit(ctx)
+    }
+
+    pub fn close_credit_line(ctx: Context<CloseCreditLine>) -> Result<()> {
+        instructions::credit_line::handler_close_credit_line(ctx)
+    }
+
+    pub fn approve_kyc(ctx: Context<ApproveKyc>, expires_at: Option<i64>) -> Result<()> {
+        instructions::kyc::handler_approve_kyc(ctx, expires_at)
+    }
+
+    pub fn revoke_kyc(ctx: Context<RevokeKyc>) -> Result<()> {
+        instructions::kyc::handler_revoke_kyc(ctx)
+    }
+
+    pub fn mint_tokens_hot(ctx: Context<MintTokensHot>, amount: u64) -> Result<views::MintOutcome> {
+        instructions::hot_minter::handler_mint_tokens_hot(ctx, amount)
+    }
+
+    pub fn open_destination_throttle(ctx: Context<OpenDestinationThrottle>) -> Result<()> {
+        instructions::destination_throttle::handler_open_destination_throttle(ctx)
+    }
+
     pub fn freeze_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
         instructions::freeze_account::handler_freeze_account(ctx)
     }
 
+    pub fn freeze_blacklisted(ctx: Context<FreezeBlacklisted>) -> Result<()> {
+        instructions::freeze_account::handler_freeze_blacklisted(ctx)
+    }
+
     pub fn thaw_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
         instructions::thaw_account::handler_thaw_account(ctx)
     }
 
+    pub fn thaw_exempt_account(ctx: Context<ThawExemptAccount>) -> Result<()> {
+        instructions::thaw_account::handler_thaw_exempt_account(ctx)
+    }
+
+    pub fn freeze_accounts_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FreezeTokenAccountsBatch<'info>>,
+    ) -> Result<()> {
+        instructions::freeze_batch::handler_freeze_accounts_batch(ctx)
+    }
+
+    pub fn thaw_accounts_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ThawTokenAccountsBatch<'info>>,
+    ) -> Result<()> {
+        instructions::freeze_batch::handler_thaw_accounts_batch(ctx)
+    }
+
     pub fn pause(ctx: Context<Pause>) -> Result<()> {
         instructions::pause::handler_pause(ctx)
     }
@@ -41,20 +248,165 @@ pub mod sss_core {
         instructions::unpause::handler_unpause(ctx)
     }
 
-    pub fn seize<'info>(ctx: Context<'_, '_, '_, 'info, Seize<'info>>, amount: u64) -> Result<()> {
-        instructions::seize::handler_seize(ctx, amount)
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, flags: u8) -> Result<()> {
+        instructions::pause::handler_set_pause_flags(ctx, flags)
+    }
+
+    pub fn renew_pause(ctx: Context<RenewPause>) -> Result<()> {
+        instructions::pause::handler_renew_pause(ctx)
+    }
+
+    pub fn set_incident_status(
+        ctx: Context<SetIncidentStatus>,
+        active: bool,
+        uri: String,
+    ) -> Result<()> {
+        instructions::incident::handler_set_incident_status(ctx, active, uri)
+    }
+
+    pub fn seize<'info>(
+        ctx: Context<'_, '_, '_, 'info, Seize<'info>>,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::seize::handler_seize(ctx, amount, reason)
+    }
+
+    pub fn wipe_account(ctx: Context<WipeAccount>, reason: String) -> Result<()> {
+        instructions::wipe::handler_wipe_account(ctx, reason)
+    }
+
+    pub fn approve_seize(
+        ctx: Context<ApproveSeize>,
+        id: u64,
+        seizer: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::seize_approval::handler_approve_seize(ctx, id, seizer, amount)
+    }
+
+    pub fn cancel_seize_approval(ctx: Context<CancelSeizeApproval>) -> Result<()> {
+        instructions::seize_approval::handler_cancel_seize_approval(ctx)
+    }
+
+    pub fn rescue_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, RescueTokens<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::rescue::handler_rescue_tokens(ctx, amount)
+    }
+
+    pub fn register_receiver(ctx: Context<RegisterReceiver>, quorum: u8) -> Result<()> {
+        instructions::receivership::handler_register_receiver(ctx, quorum)
+    }
+
+    pub fn approve_receivership(ctx: Context<ApproveReceivership>) -> Result<()> {
+        instructions::receivership::handler_approve_receivership(ctx)
+    }
+
+    pub fn deregister_receiver(ctx: Context<DeregisterReceiver>) -> Result<()> {
+        instructions::receivership::handler_deregister_receiver(ctx)
+    }
+
+    pub fn discharge_receivership(ctx: Context<DischargeReceivership>) -> Result<()> {
+        instructions::receivership::handler_discharge_receivership(ctx)
+    }
+
+    pub fn receivership_pause(ctx: Context<ReceivershipPause>) -> Result<()> {
+        instructions::receivership::handler_receivership_pause(ctx)
+    }
+
+    pub fn receivership_freeze(ctx: Context<ReceivershipFreeze>) -> Result<()> {
+        instructions::receivership::handler_receivership_freeze(ctx)
+    }
+
+    pub fn receivership_seize<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReceivershipSeize<'info>>,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::receivership::handler_receivership_seize(ctx, amount, reason)
+    }
+
+    pub fn create_standby_config(ctx: Context<CreateStandbyConfig>) -> Result<()> {
+        instructions::standby_config::handler_create_standby_config(ctx)
+    }
+
+    pub fn request_failover(ctx: Context<ManageFailover>) -> Result<()> {
+        instructions::standby_config::handler_request_failover(ctx)
+    }
+
+    pub fn cancel_failover(ctx: Context<ManageFailover>) -> Result<()> {
+        instructions::standby_config::handler_cancel_failover(ctx)
+    }
+
+    pub fn failover(ctx: Context<Failover>) -> Result<()> {
+        instructions::standby_config::handler_failover(ctx)
+    }
+
+    pub fn seize_idempotent<'info>(
+        ctx: Context<'_, '_, '_, 'info, SeizeIdempotent<'info>>,
+        amount: u64,
+        nonce: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::idempotent::handler_seize_idempotent(ctx, amount, nonce, reason)
     }
 
     pub fn grant_role(ctx: Context<GrantRole>, role: u8) -> Result<()> {
         instructions::manage_roles::handler_grant(ctx, role)
     }
 
+    pub fn accept_role(ctx: Context<AcceptRole>) -> Result<()> {
+        instructions::manage_roles::handler_accept(ctx)
+    }
+
+    pub fn cancel_role_grant(ctx: Context<CancelRoleGrant>) -> Result<()> {
+        instructions::manage_roles::handler_cancel_grant(ctx)
+    }
+
     pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
         instructions::manage_roles::handler_revoke(ctx)
     }
 
-    pub fn transfer_authority(ctx: Context<TransferAuthority>) -> Result<()> {
-        instructions::transfer_authority::handler_transfer_authority(ctx)
+    pub fn renounce_role(ctx: Context<RenounceRole>) -> Result<()> {
+        instructions::manage_roles::handler_renounce(ctx)
+    }
+
+    pub fn sync_role_directory(ctx: Context<SyncRoleDirectory>) -> Result<()> {
+        instructions::manage_roles::handler_sync_directory(ctx)
+    }
+
+    pub fn set_action_quota(ctx: Context<SetActionQuota>, quota: Option<u32>) -> Result<()> {
+        instructions::manage_roles::handler_set_action_quota(ctx, quota)
+    }
+
+    pub fn set_role_expiry(ctx: Context<SetRoleExpiry>, expires_at: Option<i64>) -> Result<()> {
+        instructions::manage_roles::handler_set_role_expiry(ctx, expires_at)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>) -> Result<()> {
+        instructions::transfer_authority::handler_propose_authority(ctx)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::transfer_authority::handler_accept_authority(ctx)
+    }
+
+    pub fn queue_action(
+        ctx: Context<QueueAction>,
+        id: u64,
+        action: state::TimelockedAction,
+    ) -> Result<()> {
+        instructions::timelock::handler_queue_action(ctx, id, action)
+    }
+
+    pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+        instructions::timelock::handler_cancel_action(ctx)
+    }
+
+    pub fn set_admin_timelock(ctx: Context<SetAdminTimelock>, delay_secs: u32) -> Result<()> {
+        instructions::timelock::handler_set_admin_timelock(ctx, delay_secs)
     }
 
     pub fn update_supply_cap(
@@ -64,14 +416,429 @@ pub mod sss_core {
         instructions::update_config::handler_update_supply_cap(ctx, new_supply_cap)
     }
 
+    pub fn update_capabilities(
+        ctx: Context<UpdateCapabilities>,
+        disabled: u32,
+        lock: u32,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_capabilities(ctx, disabled, lock)
+    }
+
+    pub fn update_thaw_ramp(
+        ctx: Context<UpdateThawRamp>,
+        thaw_ramp: Option<state::ThawRamp>,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_thaw_ramp(ctx, thaw_ramp)
+    }
+
+    pub fn update_destination_mint_limit(
+        ctx: Context<UpdateDestinationMintLimit>,
+        limit: Option<u64>,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_destination_mint_limit(ctx, limit)
+    }
+
+    pub fn update_supply_check(
+        ctx: Context<UpdateSupplyCheck>,
+        halt_mint_on_mismatch: bool,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_supply_check(ctx, halt_mint_on_mismatch)
+    }
+
+    pub fn verify_supply(ctx: Context<VerifySupply>) -> Result<()> {
+        instructions::verify_supply::handler_verify_supply(ctx)
+    }
+
+    pub fn reconcile_supply(ctx: Context<ReconcileSupply>, adjust: bool) -> Result<()> {
+        instructions::reconcile_supply::handler_reconcile_supply(ctx, adjust)
+    }
+
+    pub fn update_authority_check(
+        ctx: Context<UpdateSupplyCheck>,
+        pause_on_anomaly: bool,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_authority_check(ctx, pause_on_anomaly)
+    }
+
+    pub fn update_large_mint_policy(
+        ctx: Context<UpdateSupplyCheck>,
+        threshold: Option<u64>,
+        approvals: u8,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_large_mint_policy(ctx, threshold, approvals)
+    }
+
+    pub fn update_unpause_policy(
+        ctx: Context<UpdateSupplyCheck>,
+        requires_admin: bool,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_unpause_policy(ctx, requires_admin)
+    }
+
+    pub fn update_max_pause(ctx: Context<UpdateSupplyCheck>, max_pause_secs: u32) -> Result<()> {
+        instructions::update_config::handler_update_max_pause(ctx, max_pause_secs)
+    }
+
+    pub fn update_seize_policy(
+        ctx: Context<UpdateSupplyCheck>,
+        threshold: Option<u64>,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_seize_policy(ctx, threshold)
+    }
+
+    pub fn update_treasury(
+        ctx: Context<UpdateSupplyCheck>,
+        treasury: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_treasury(ctx, treasury)
+    }
+
+    pub fn update_seize_freeze_policy(
+        ctx: Context<UpdateSupplyCheck>,
+        requires_frozen: bool,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_seize_freeze_policy(ctx, requires_frozen)
+    }
+
+    pub fn update_reserve_gate(
+        ctx: Context<UpdateSupplyCheck>,
+        max_attestation_age_secs: Option<u32>,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_reserve_gate(ctx, max_attestation_age_secs)
+    }
+
+    pub fn update_fee_treasury(
+        ctx: Context<UpdateSupplyCheck>,
+        fee_treasury: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_config::handler_update_fee_treasury(ctx, fee_treasury)
+    }
+
+    pub fn verify_authorities(ctx: Context<VerifyAuthorities>) -> Result<()> {
+        instructions::verify_authorities::handler_verify_authorities(ctx)
+    }
+
+    pub fn update_metadata_uri(ctx: Context<UpdateMetadataUri>, uri: String) -> Result<()> {
+        instructions::update_metadata::handler_update_metadata_uri(ctx, uri)
+    }
+
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        instructions::update_metadata::handler_update_metadata(ctx, name, symbol, uri)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config::handler_migrate_config(ctx)
+    }
+
+    pub fn migrate_standby_config(ctx: Context<MigrateStandbyConfig>) -> Result<()> {
+        instructions::migrate_config::handler_migrate_standby_config(ctx)
+    }
+
+    pub fn migrate_role(ctx: Context<MigrateRole>) -> Result<()> {
+        instructions::migrate_config::handler_migrate_role(ctx)
+    }
+
+    pub fn sunset(ctx: Context<Sunset>) -> Result<()> {
+        instructions::sunset::handler_sunset(ctx)
+    }
+
+    pub fn close_config(ctx: Context<CloseConfig>) -> Result<()> {
+        instructions::sunset::handler_close_config(ctx)
+    }
+
     pub fn update_minter(ctx: Context<UpdateMinter>, new_quota: Option<u64>) -> Result<()> {
         instructions::update_minter::handler_update_minter(ctx, new_quota)
     }
 
+    pub fn set_minter_hours(
+        ctx: Context<UpdateMinter>,
+        hours: Option<state::OperatingHours>,
+    ) -> Result<()> {
+        instructions::update_minter::handler_set_minter_hours(ctx, hours)
+    }
+
+    pub fn set_minter_quota_period(
+        ctx: Context<UpdateMinter>,
+        period_secs: Option<u32>,
+    ) -> Result<()> {
+        instructions::update_minter::handler_set_minter_quota_period(ctx, period_secs)
+    }
+
+    pub fn set_minter_rate_limit(
+        ctx: Context<UpdateMinter>,
+        max_per_window: Option<u64>,
+        window_secs: u32,
+    ) -> Result<()> {
+        instructions::update_minter::handler_set_minter_rate_limit(ctx, max_per_window, window_secs)
+    }
+
+    pub fn update_burner(ctx: Context<UpdateBurner>, new_quota: Option<u64>) -> Result<()> {
+        instructions::update_burner::handler_update_burner(ctx, new_quota)
+    }
+
+    pub fn reset_minter_usage(ctx: Context<ResetMinterUsage>) -> Result<()> {
+        instructions::update_minter::handler_reset_minter_usage(ctx)
+    }
+
+    pub fn configure_minter_keys(
+        ctx: Context<ConfigureMinterKeys>,
+        hot_key: Pubkey,
+        hot_window_quota: u64,
+        window_secs: i64,
+    ) -> Result<()> {
+        instructions::minter_keys::handler_configure_minter_keys(
+            ctx,
+            hot_key,
+            hot_window_quota,
+            window_secs,
+        )
+    }
+
+    pub fn remove_minter_keys(ctx: Context<RemoveMinterKeys>) -> Result<()> {
+        instructions::minter_keys::handler_remove_minter_keys(ctx)
+    }
+
+    pub fn update_receipt_policy(
+        ctx: Context<UpdateReceiptPolicy>,
+        threshold: Option<u64>,
+        retention_secs: i64,
+    ) -> Result<()> {
+        instructions::receipts::handler_update_receipt_policy(ctx, threshold, retention_secs)
+    }
+
+    pub fn close_mint_receipt(ctx: Context<CloseMintReceipt>) -> Result<()> {
+        instructions::receipts::handler_close_mint_receipt(ctx)
+    }
+
+    pub fn configure_event_queue(ctx: Context<ConfigureEventQueue>, enabled: bool) -> Result<()> {
+        instructions::configure_event_queue::handler_configure_event_queue(ctx, enabled)
+    }
+
     pub fn update_oracle_feed(
         ctx: Context<UpdateOracleFeed>,
         oracle_feed_id: Option<[u8; 32]>,
     ) -> Result<()> {
         instructions::update_oracle::handler_update_oracle_feed(ctx, oracle_feed_id)
     }
+
+    pub fn update_oracle_confidence(
+        ctx: Context<UpdateOracleFeed>,
+        max_confidence_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::update_oracle::handler_update_oracle_confidence(ctx, max_confidence_bps)
+    }
+
+    pub fn update_oracle_price_source(
+        ctx: Context<UpdateOracleFeed>,
+        source: state::OraclePriceSource,
+    ) -> Result<()> {
+        instructions::update_oracle::handler_update_oracle_price_source(ctx, source)
+    }
+
+    pub fn update_oracle_kind(
+        ctx: Context<UpdateOracleFeed>,
+        kind: state::OracleKind,
+        oracle_feed_id: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::update_oracle::handler_update_oracle_kind(ctx, kind, oracle_feed_id)
+    }
+
+    pub fn grant_regulator_access(ctx: Context<GrantRegulatorAccess>, name: String) -> Result<()> {
+        instructions::regulator_access::handler_grant_regulator_access(ctx, name)
+    }
+
+    pub fn revoke_regulator_access(ctx: Context<RevokeRegulatorAccess>) -> Result<()> {
+        instructions::regulator_access::handler_revoke_regulator_access(ctx)
+    }
+
+    pub fn request_report(ctx: Context<RequestReport>, period: u64) -> Result<()> {
+        instructions::compliance_report::handler_request_report(ctx, period)
+    }
+
+    pub fn publish_report(ctx: Context<PublishReport>, report_hash: [u8; 32]) -> Result<()> {
+        instructions::compliance_report::handler_publish_report(ctx, report_hash)
+    }
+
+    pub fn acknowledge_report(ctx: Context<AcknowledgeReport>) -> Result<()> {
+        instructions::compliance_report::handler_acknowledge_report(ctx)
+    }
+
+    pub fn declare_snapshot(
+        ctx: Context<DeclareSnapshot>,
+        slot: u64,
+        record_window_slots: u64,
+    ) -> Result<()> {
+        instructions::declare_snapshot::handler_declare_snapshot(ctx, slot, record_window_slots)
+    }
+
+    pub fn record_snapshot_balance(ctx: Context<RecordSnapshotBalance>) -> Result<()> {
+        instructions::snapshot::handler_record_snapshot_balance(ctx)
+    }
+
+    pub fn create_distributor(
+        ctx: Context<CreateDistributor>,
+        id: u64,
+        merkle_root: [u8; 32],
+        max_total: u64,
+        funding: state::DistributionFunding,
+    ) -> Result<()> {
+        instructions::distributor::handler_create_distributor(
+            ctx,
+            id,
+            merkle_root,
+            max_total,
+            funding,
+        )
+    }
+
+    pub fn claim_distribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimDistribution<'info>>,
+        index: u64,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_distribution::handler_claim_distribution(ctx, index, amount, proof)
+    }
+
+    pub fn create_swap_route(ctx: Context<CreateSwapRoute>) -> Result<()> {
+        instructions::swap_route::handler_create_swap_route(ctx)
+    }
+
+    pub fn close_swap_route(ctx: Context<CloseSwapRoute>) -> Result<()> {
+        instructions::swap_route::handler_close_swap_route(ctx)
+    }
+
+    pub fn par_swap(ctx: Context<ParSwap>, amount: u64) -> Result<()> {
+        instructions::par_swap::handler_par_swap(ctx, amount)
+    }
+
+    pub fn emit_issuer_event(
+        ctx: Context<EmitIssuerEvent>,
+        tag: String,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::issuer_event::handler_emit_issuer_event(ctx, tag, data)
+    }
+
+    pub fn record_reserve_movement(
+        ctx: Context<RecordReserveMovement>,
+        kind: state::ReserveMovementKind,
+        amount: u64,
+        ref_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::reserve_journal::handler_record_reserve_movement(ctx, kind, amount, ref_hash)
+    }
+
+    pub fn publish_reserve_attestation(
+        ctx: Context<PublishReserveAttestation>,
+        reserve_amount: u64,
+        attested_at: i64,
+        report_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::reserve_attestation::handler_publish_reserve_attestation(
+            ctx,
+            reserve_amount,
+            attested_at,
+            report_hash,
+        )
+    }
+
+    pub fn export_period_summary(ctx: Context<ExportPeriodSummary>, period_id: u64) -> Result<()> {
+        instructions::period_summary::handler_export_period_summary(ctx, period_id)
+    }
+
+    pub fn create_collateral_vault(
+        ctx: Context<CreateCollateralVault>,
+        collateral_ratio_bps: u16,
+        collateral_feed_id: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::configure_collateral_vault::handler_create_collateral_vault(
+            ctx,
+            collateral_ratio_bps,
+            collateral_feed_id,
+        )
+    }
+
+    pub fn update_collateral_vault(
+        ctx: Context<UpdateCollateralVault>,
+        collateral_ratio_bps: u16,
+        collateral_feed_id: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::configure_collateral_vault::handler_update_collateral_vault(
+            ctx,
+            collateral_ratio_bps,
+            collateral_feed_id,
+        )
+    }
+
+    pub fn deposit_and_mint(
+        ctx: Context<DepositAndMint>,
+        collateral_amount: u64,
+        min_mint_amount: u64,
+    ) -> Result<()> {
+        instructions::collateral_vault::handler_deposit_and_mint(
+            ctx,
+            collateral_amount,
+            min_mint_amount,
+        )
+    }
+
+    pub fn burn_and_withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnAndWithdraw<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::collateral_vault::handler_burn_and_withdraw(ctx, amount)
+    }
+
+    pub fn add_freeze_exemption(ctx: Context<AddFreezeExemption>) -> Result<()> {
+        instructions::freeze_exemption::handler_add_freeze_exemption(ctx)
+    }
+
+    pub fn remove_freeze_exemption(ctx: Context<RemoveFreezeExemption>) -> Result<()> {
+        instructions::freeze_exemption::handler_remove_freeze_exemption(ctx)
+    }
+
+    pub fn set_fee_schedule(
+        ctx: Context<SetFeeSchedule>,
+        tiers: Vec<state::FeeRates>,
+    ) -> Result<()> {
+        instructions::fee_schedule::handler_set_fee_schedule(ctx, tiers)
+    }
+
+    pub fn assign_fee_tier(ctx: Context<AssignFeeTier>, tier: u8) -> Result<()> {
+        instructions::fee_schedule::handler_assign_fee_tier(ctx, tier)
+    }
+
+    pub fn clear_fee_tier(ctx: Context<ClearFeeTier>) -> Result<()> {
+        instructions::fee_schedule::handler_clear_fee_tier(ctx)
+    }
+
+    pub fn register_sweep_route(
+        ctx: Context<RegisterSweepRoute>,
+        threshold: u64,
+        min_interval: i64,
+    ) -> Result<()> {
+        instructions::sweep::handler_register_sweep_route(ctx, threshold, min_interval)
+    }
+
+    pub fn close_sweep_route(ctx: Context<CloseSweepRoute>) -> Result<()> {
+        instructions::sweep::handler_close_sweep_route(ctx)
+    }
+
+    pub fn sweep_intake<'info>(ctx: Context<'_, '_, '_, 'info, SweepIntake<'info>>) -> Result<()> {
+        instructions::sweep::handler_sweep_intake(ctx)
+    }
+
+    pub fn view_config(ctx: Context<ViewConfig>) -> Result<views::ConfigView> {
+        instructions::view::handler_view_config(ctx)
+    }
+
+    pub fn view_role(ctx: Context<ViewRole>) -> Result<views::RoleView> {
+        instructions::view::handler_view_role(ctx)
+    }
 }
@@ -0,0 +1,135 @@
+//! Pure supply arithmetic shared by the on-chain handlers, the fuzz suite and
+//! the Kani proof harnesses. Nothing here touches accounts or sysvars.
+
+/// Convert a USD-denominated supply cap to token base units given a Pyth
+/// price (`price × 10^exponent` USD per token).
+///
+///   token_cap = usd_cap × 10^mint_decimals / (price × 10^exponent)
+///
+/// Returns `None` when `price` is not positive or any intermediate value
+/// overflows `u128`. Results larger than `u64::MAX` are clamped, which is
+/// effectively unlimited.
+pub fn oracle_token_cap(usd_cap: u64, price: i64, exponent: i32, mint_decimals: u8) -> Option<u64> {
+    if price <= 0 {
+        return None;
+    }
+    let price = price as u128;
+    let numerator = (usd_cap as u128).checked_mul(10u128.checked_pow(mint_decimals as u32)?)?;
+
+    let token_cap = if exponent < 0 {
+        // token_cap = cap * 10^decimals * 10^|expo| / price
+        numerator
+            .checked_mul(10u128.checked_pow(exponent.unsigned_abs())?)?
+            .checked_div(price)?
+    } else {
+        // token_cap = cap * 10^decimals / (price * 10^expo)
+        let denominator = price.checked_mul(10u128.checked_pow(exponent as u32)?)?;
+        numerator.checked_div(denominator)?
+    };
+
+    Some(token_cap.min(u64::MAX as u128) as u64)
+}
+
+/// Whether a Pyth confidence interval is within `max_bps` basis points of
+/// `price`, i.e. `conf / price <= max_bps / 10_000`. Non-positive prices are
+/// never within bounds.
+pub fn confidence_within_bps(price: i64, conf: u64, max_bps: u16) -> bool {
+    if price <= 0 {
+        return false;
+    }
+    // Both sides fit in u128: u64::MAX × 10_000 and i64::MAX × u16::MAX.
+    (conf as u128) * 10_000 <= (price as u128) * (max_bps as u128)
+}
+
+/// Stablecoin base units issued for `collateral_amount` base units of
+/// collateral priced at `price × 10^exponent` USD per whole token, at a
+/// collateral ratio of `ratio_bps`, valuing the stablecoin at $1:
+///
+///   minted = collateral_amount × price × 10^exponent × 10^mint_decimals
+///            × 10_000 / (10^collateral_decimals × ratio_bps)
+///
+/// Rounds down. Returns `None` when `price` or `ratio_bps` is not positive,
+/// an intermediate value overflows `u128`, or the result exceeds `u64`.
+pub fn collateral_mint_amount(
+    collateral_amount: u64,
+    collateral_decimals: u8,
+    price: i64,
+    exponent: i32,
+    mint_decimals: u8,
+    ratio_bps: u16,
+) -> Option<u64> {
+    if price <= 0 || ratio_bps == 0 {
+        return None;
+    }
+    let mut numerator = (collateral_amount as u128)
+        .checked_mul(price as u128)?
+        .checked_mul(10u128.checked_pow(mint_decimals as u32)?)?
+        .checked_mul(10_000)?;
+    let mut denominator = 10u128
+        .checked_pow(collateral_decimals as u32)?
+        .checked_mul(ratio_bps as u128)?;
+    if exponent < 0 {
+        denominator = denominator.checked_mul(10u128.checked_pow(exponent.unsigned_abs())?)?;
+    } else {
+        numerator = numerator.checked_mul(10u128.checked_pow(exponent as u32)?)?;
+    }
+    u64::try_from(numerator / denominator).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle_token_cap_at_peg() {
+        // $1.00000000 with 6 decimals: 1_000 USD cap -> 1_000 tokens.
+        assert_eq!(
+            oracle_token_cap(1_000, 100_000_000, -8, 6),
+            Some(1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_oracle_token_cap_rejects_bad_input() {
+        assert_eq!(oracle_token_cap(1_000, 0, -8, 6), None);
+        assert_eq!(oracle_token_cap(1_000, -1, -8, 6), None);
+        // 10^255 does not fit in u128; this used to panic in `pow`.
+        assert_eq!(oracle_token_cap(1_000, 1, -8, u8::MAX), None);
+        assert_eq!(oracle_token_cap(1_000, 1, i32::MIN, 6), None);
+        assert_eq!(oracle_token_cap(1_000, 1, i32::MAX, 6), None);
+    }
+
+    #[test]
+    fn test_oracle_token_cap_clamps_to_u64() {
+        assert_eq!(oracle_token_cap(u64::MAX, 1, -8, 9), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_collateral_mint_amount() {
+        // 150 USDC (6 decimals) at par and 150% mints 100 tokens (6 decimals).
+        assert_eq!(
+            collateral_mint_amount(150_000_000, 6, 1, 0, 6, 15_000),
+            Some(100_000_000)
+        );
+        // 1 SOL (9 decimals) at $150.00000000 and 200% mints 75 tokens.
+        assert_eq!(
+            collateral_mint_amount(1_000_000_000, 9, 15_000_000_000, -8, 6, 20_000),
+            Some(75_000_000)
+        );
+        assert_eq!(collateral_mint_amount(1, 6, 1, 0, 6, 10_001), Some(0));
+        assert_eq!(collateral_mint_amount(1, 6, 0, 0, 6, 10_000), None);
+        assert_eq!(collateral_mint_amount(1, 6, 1, 0, 6, 0), None);
+        assert_eq!(collateral_mint_amount(u64::MAX, 0, 1, 0, 6, 10_000), None);
+    }
+
+    #[test]
+    fn test_confidence_within_bps() {
+        // 1.00000000 ± 0.00500000 is 50 bps.
+        assert!(confidence_within_bps(100_000_000, 500_000, 50));
+        assert!(!confidence_within_bps(100_000_000, 500_001, 50));
+        assert!(confidence_within_bps(100_000_000, 0, 0));
+        assert!(!confidence_within_bps(0, 0, 10_000));
+        assert!(!confidence_within_bps(-1, 0, 10_000));
+        assert!(!confidence_within_bps(1, u64::MAX, u16::MAX));
+    }
+}
@@ -0,0 +1,85 @@
+//! Merkle proofs for distributor claims.
+//!
+//! Leaves and internal nodes are keccak-256 with distinct one-byte prefixes,
+//! so a leaf can never be passed off as an internal node. Sibling pairs are
+//! sorted before hashing, so proofs carry no left/right flags.
+
+use anchor_lang::prelude::Pubkey;
+use solana_keccak_hasher::hashv;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf for a claim of `amount` by `claimant` at position `index`.
+pub fn claim_leaf(index: u64, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        &index.to_le_bytes(),
+        claimant.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Hash two sibling nodes in sorted order.
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, lo, hi]).to_bytes()
+}
+
+/// Whether `proof` connects `leaf` to `root`.
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling));
+    &computed == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_three_leaf_tree() {
+        let claimants = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let leaves: Vec<[u8; 32]> = claimants
+            .iter()
+            .enumerate()
+            .map(|(i, c)| claim_leaf(i as u64, c, 100 * (i as u64 + 1)))
+            .collect();
+        // Odd leaf is promoted unchanged.
+        let left = hash_pair(&leaves[0], &leaves[1]);
+        let root = hash_pair(&left, &leaves[2]);
+
+        assert!(verify(&[leaves[1], leaves[2]], &root, leaves[0]));
+        assert!(verify(&[leaves[0], leaves[2]], &root, leaves[1]));
+        assert!(verify(&[left], &root, leaves[2]));
+
+        // Wrong amount, wrong index and a truncated proof all fail.
+        assert!(!verify(
+            &[leaves[1], leaves[2]],
+            &root,
+            claim_leaf(0, &claimants[0], 1)
+        ));
+        assert!(!verify(
+            &[leaves[1], leaves[2]],
+            &root,
+            claim_leaf(1, &claimants[0], 100)
+        ));
+        assert!(!verify(&[leaves[1]], &root, leaves[0]));
+    }
+
+    #[test]
+    fn test_leaf_is_not_a_node() {
+        let a = claim_leaf(0, &Pubkey::new_unique(), 1);
+        let b = claim_leaf(1, &Pubkey::new_unique(), 1);
+        // The parent of two leaves cannot be claimed as a leaf with an empty proof.
+        let root = hash_pair(&a, &b);
+        assert!(!verify(&[], &root, a));
+        assert!(verify(&[], &root, root));
+    }
+}
@@ -0,0 +1,570 @@
+//! PDA derivation helpers shared by the on-chain handlers and off-chain clients.
+//!
+//! Also derives the transfer hook's PDAs (owned by
+//! [`TRANSFER_HOOK_PROGRAM_ID`]), which `sss_transfer_hook::pda` re-exports.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::TRANSFER_HOOK_PROGRAM_ID;
+use crate::seeds;
+
+use crate::state::{
+    ClaimStatus, CollateralPosition, CollateralVault, ComplianceReport, ConsumedNonce, CreditLine,
+    DeploymentCounter, DestinationThrottle, Distributor, EventQueue, FeeSchedule,
+    FeeTierAssignment, FreezeExemption, KycEntry, MintApproval, MintReceipt, MintRequest,
+    MinterKeys, PendingAction, PendingRole, PeriodSummary, Receivership, RedemptionRequest,
+    RegulatorAccess, ReserveAttestation, ReserveJournal, ReserveMovement, Role, RoleAccount,
+    RoleDirectory, SeizeApproval, SeizureRecord, Snapshot, SnapshotBalance, StablecoinConfig,
+    StandbyConfig, SubMinter, SwapRoute, SweepRoute, WipeRecord,
+};
+
+/// Derive the `StablecoinConfig` PDA for a mint.
+/// Seeds: `["sss-config", mint]`.
+pub fn find_config_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[StablecoinConfig::SSS_CONFIG_SEED, mint.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Derive the program-wide `DeploymentCounter` PDA.
+/// Seeds: `["sss-deployment-counter"]`.
+pub fn find_deployment_counter_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DeploymentCounter::SSS_DEPLOYMENT_COUNTER_SEED],
+        &crate::ID,
+    )
+}
+
+/// Derive the `RoleAccount` PDA for an address holding `role` under `config`.
+/// Seeds: `["sss-role", config, address, role_u8]`.
+pub fn find_role_address(config: &Pubkey, address: &Pubkey, role: Role) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            RoleAccount::SSS_ROLE_SEED,
+            config.as_ref(),
+            address.as_ref(),
+            &[role.as_u8()],
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `RoleDirectory` PDA listing every holder of `role` under `config`.
+/// Seeds: `["sss-role-dir", config, role_u8]`.
+pub fn find_role_directory_address(config: &Pubkey, role: Role) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            RoleDirectory::SSS_ROLE_DIRECTORY_SEED,
+            config.as_ref(),
+            &[role.as_u8()],
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `PendingRole` PDA for a grant of `role` to `address` awaiting
+/// acceptance. Seeds: `["sss-pending-role", config, address, role_u8]`.
+pub fn find_pending_role_address(config: &Pubkey, address: &Pubkey, role: Role) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PendingRole::SSS_PENDING_ROLE_SEED,
+            config.as_ref(),
+            address.as_ref(),
+            &[role.as_u8()],
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `MintReceipt` PDA for an issuance under `config`.
+/// Seeds: `["sss-mint-receipt", config, reference]`.
+pub fn find_mint_receipt_address(config: &Pubkey, reference: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MintReceipt::SSS_MINT_RECEIPT_SEED,
+            config.as_ref(),
+            reference.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `RegulatorAccess` PDA for a regulator key under `config`.
+/// Seeds: `["sss-regulator", config, regulator]`.
+pub fn find_regulator_access_address(config: &Pubkey, regulator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            RegulatorAccess::SSS_REGULATOR_SEED,
+            config.as_ref(),
+            regulator.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `ComplianceReport` PDA for a regulator and reporting period.
+/// Seeds: `["sss-report", config, regulator, period_le_bytes]`.
+pub fn find_report_address(config: &Pubkey, regulator: &Pubkey, period: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ComplianceReport::SSS_REPORT_SEED,
+            config.as_ref(),
+            regulator.as_ref(),
+            &period.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `ConsumedNonce` PDA for an operator's idempotency nonce.
+/// Seeds: `["sss-nonce", config, operator, nonce_le_bytes]`.
+pub fn find_consumed_nonce_address(config: &Pubkey, operator: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ConsumedNonce::SSS_NONCE_SEED,
+            config.as_ref(),
+            operator.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `Snapshot` PDA declared at `slot`.
+/// Seeds: `["sss-snapshot", config, slot_le_bytes]`.
+pub fn find_snapshot_address(config: &Pubkey, slot: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Snapshot::SSS_SNAPSHOT_SEED,
+            config.as_ref(),
+            &slot.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `SnapshotBalance` PDA for a token account under a snapshot.
+/// Seeds: `["sss-snapshot-bal", snapshot, token_account]`.
+pub fn find_snapshot_balance_address(snapshot: &Pubkey, token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SnapshotBalance::SSS_SNAPSHOT_BALANCE_SEED,
+            snapshot.as_ref(),
+            token_account.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `Distributor` PDA for a distribution id.
+/// Seeds: `["sss-distributor", config, id_le_bytes]`.
+pub fn find_distributor_address(config: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Distributor::SSS_DISTRIBUTOR_SEED,
+            config.as_ref(),
+            &id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `ClaimStatus` PDA for a distributor leaf.
+/// Seeds: `["sss-claim", distributor, index_le_bytes]`.
+pub fn find_claim_status_address(distributor: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ClaimStatus::SSS_CLAIM_SEED,
+            distributor.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `SwapRoute` PDA from one stablecoin config to another.
+/// Seeds: `["sss-swap-route", from_config, to_config]`.
+pub fn find_swap_route_address(from_config: &Pubkey, to_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SwapRoute::SSS_SWAP_ROUTE_SEED,
+            from_config.as_ref(),
+            to_config.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `SubMinter` PDA for a quota slice delegated by `parent`.
+/// Seeds: `["sss-sub-minter", config, parent, sub_minter]`.
+pub fn find_sub_minter_address(
+    config: &Pubkey,
+    parent: &Pubkey,
+    sub_minter: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SubMinter::SSS_SUB_MINTER_SEED,
+            config.as_ref(),
+            parent.as_ref(),
+            sub_minter.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `KycEntry` PDA for a wallet.
+/// Seeds: `["sss-kyc", config, owner]`.
+pub fn find_kyc_entry_address(config: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[KycEntry::SSS_KYC_SEED, config.as_ref(), owner.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Derive the `EventQueue` ring buffer PDA for a config.
+/// Seeds: `["sss-event-queue", config]`.
+pub fn find_event_queue_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[EventQueue::SSS_EVENT_QUEUE_SEED, config.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Derive the `MinterKeys` hot/cold extension of a Minter `RoleAccount`.
+/// Seeds: `["sss-minter-keys", minter_role]`.
+pub fn find_minter_keys_address(minter_role: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MinterKeys::SSS_MINTER_KEYS_SEED, minter_role.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Derive the `ReserveJournal` header PDA for a config.
+/// Seeds: `["sss-reserve-journal", config]`.
+pub fn find_reserve_journal_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ReserveJournal::SSS_RESERVE_JOURNAL_SEED, config.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Derive the `ReserveAttestation` PDA for a config.
+/// Seeds: `["sss-reserve-attestation", config]`.
+pub fn find_reserve_attestation_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED,
+            config.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `CollateralVault` PDA for a config.
+/// Seeds: `["sss-collateral-vault", config]`.
+pub fn find_collateral_vault_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[CollateralVault::SSS_COLLATERAL_VAULT_SEED, config.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Derive a depositor's `CollateralPosition` PDA in a vault.
+/// Seeds: `["sss-collateral-position", collateral_vault, owner]`.
+pub fn find_collateral_position_address(collateral_vault: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CollateralPosition::SSS_COLLATERAL_POSITION_SEED,
+            collateral_vault.as_ref(),
+            owner.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `ReserveMovement` journal entry PDA at `index`.
+/// Seeds: `["sss-reserve-movement", config, index_u64_le]`.
+pub fn find_reserve_movement_address(config: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ReserveMovement::SSS_RESERVE_MOVEMENT_SEED,
+            config.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `FreezeExemption` PDA for an owner wallet.
+/// Seeds: `["sss-freeze-exempt", config, owner]`.
+pub fn find_freeze_exemption_address(config: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FreezeExemption::SSS_FREEZE_EXEMPTION_SEED,
+            config.as_ref(),
+            owner.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `Receivership` PDA for a config.
+/// Seeds: `["sss-receivership", config]`.
+pub fn find_receivership_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Receivership::SSS_RECEIVERSHIP_SEED, config.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Derive the `DestinationThrottle` PDA for a token account.
+/// Seeds: `["sss-dest-throttle", config, token_account]`.
+pub fn find_destination_throttle_address(config: &Pubkey, token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            DestinationThrottle::SSS_DESTINATION_THROTTLE_SEED,
+            config.as_ref(),
+            token_account.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `FeeSchedule` PDA for a config.
+/// Seeds: `["sss-fee-schedule", config]`.
+pub fn find_fee_schedule_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FeeSchedule::SSS_FEE_SCHEDULE_SEED, config.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Derive the `FeeTierAssignment` PDA for a wallet.
+/// Seeds: `["sss-fee-tier", config, wallet]`.
+pub fn find_fee_tier_address(config: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FeeTierAssignment::SSS_FEE_TIER_SEED,
+            config.as_ref(),
+            wallet.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `SweepRoute` PDA for an intake token account.
+/// Seeds: `["sss-sweep-route", config, intake]`.
+pub fn find_sweep_route_address(config: &Pubkey, intake: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SweepRoute::SSS_SWEEP_ROUTE_SEED,
+            config.as_ref(),
+            intake.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `CreditLine` PDA for a market maker.
+/// Seeds: `["sss-credit-line", config, market_maker]`.
+pub fn find_credit_line_address(config: &Pubkey, market_maker: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CreditLine::SSS_CREDIT_LINE_SEED,
+            config.as_ref(),
+            market_maker.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `StandbyConfig` PDA for a config.
+/// Seeds: `["sss-config-standby", config]`.
+pub fn find_standby_config_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[StandbyConfig::SSS_STANDBY_CONFIG_SEED, config.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Derive the `MintApproval` PDA for a minter's request id.
+/// Seeds: `["sss-mint-approval", config, minter, id_le_bytes]`.
+pub fn find_mint_approval_address(config: &Pubkey, minter: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MintApproval::SSS_MINT_APPROVAL_SEED,
+            config.as_ref(),
+            minter.as_ref(),
+            &id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `MintRequest` PDA for a requester's request id.
+/// Seeds: `["sss-mint-request", config, requester, id_le_bytes]`.
+pub fn find_mint_request_address(config: &Pubkey, requester: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MintRequest::SSS_MINT_REQUEST_SEED,
+            config.as_ref(),
+            requester.as_ref(),
+            &id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `RedemptionRequest` PDA for a holder's request id.
+/// Seeds: `["sss-redemption", config, holder, id_le_bytes]`.
+pub fn find_redemption_request_address(config: &Pubkey, holder: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            RedemptionRequest::SSS_REDEMPTION_SEED,
+            config.as_ref(),
+            holder.as_ref(),
+            &id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `SeizeApproval` PDA for an approver's approval id.
+/// Seeds: `["sss-seize-approval", config, approver, id_le_bytes]`.
+pub fn find_seize_approval_address(config: &Pubkey, approver: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SeizeApproval::SSS_SEIZE_APPROVAL_SEED,
+            config.as_ref(),
+            approver.as_ref(),
+            &id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `SeizureRecord` PDA for a config's `index`th seizure.
+/// Seeds: `["sss-seizure", config, index_le_bytes]`.
+pub fn find_seizure_record_address(config: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SeizureRecord::SSS_SEIZURE_RECORD_SEED,
+            config.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `WipeRecord` PDA for a config's `index`th wipe.
+/// Seeds: `["sss-wipe", config, index_le_bytes]`.
+pub fn find_wipe_record_address(config: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            WipeRecord::SSS_WIPE_RECORD_SEED,
+            config.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `PeriodSummary` PDA for a config's reporting period.
+/// Seeds: `["sss-period-summary", config, period_id_le_bytes]`.
+pub fn find_period_summary_address(config: &Pubkey, period_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PeriodSummary::SSS_PERIOD_SUMMARY_SEED,
+            config.as_ref(),
+            &period_id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the `PendingAction` PDA for a queued action id.
+/// Seeds: `["sss-pending-action", config, id_le_bytes]`.
+pub fn find_pending_action_address(config: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PendingAction::SSS_PENDING_ACTION_SEED,
+            config.as_ref(),
+            &id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Derive the hook's `BlacklistEntry` PDA for a wallet under a mint.
+/// Seeds: `["blacklist", mint, address]`.
+pub fn find_blacklist_address(mint: &Pubkey, address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::BLACKLIST, mint.as_ref(), address.as_ref()],
+        &TRANSFER_HOOK_PROGRAM_ID,
+    )
+}
+
+/// Derive the `ExtraAccountMetaList` PDA Token-2022 reads during transfers.
+/// Seeds: `["extra-account-metas", mint]`.
+pub fn find_extra_account_metas_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::EXTRA_ACCOUNT_METAS, mint.as_ref()],
+        &TRANSFER_HOOK_PROGRAM_ID,
+    )
+}
+
+/// Derive the hook's `HookConfig` PDA for a mint.
+/// Seeds: `["hook-config", mint]`.
+pub fn find_hook_config_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::HOOK_CONFIG, mint.as_ref()],
+        &TRANSFER_HOOK_PROGRAM_ID,
+    )
+}
+
+/// Derive the hook's `RiskScore` PDA for a wallet under a mint.
+/// Seeds: `["risk-score", mint, wallet]`.
+pub fn find_risk_score_address(mint: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::RISK_SCORE, mint.as_ref(), wallet.as_ref()],
+        &TRANSFER_HOOK_PROGRAM_ID,
+    )
+}
+
+/// Derive the hook's `HookEventQueue` PDA for a mint.
+/// Seeds: `["hook-event-queue", mint]`.
+pub fn find_hook_event_queue_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::HOOK_EVENT_QUEUE, mint.as_ref()],
+        &TRANSFER_HOOK_PROGRAM_ID,
+    )
+}
+
+/// Derive the hook's `AccountHold` PDA for a token account under a mint.
+/// Seeds: `["account-hold", mint, token_account]`.
+pub fn find_account_hold_address(mint: &Pubkey, token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::ACCOUNT_HOLD, mint.as_ref(), token_account.as_ref()],
+        &TRANSFER_HOOK_PROGRAM_ID,
+    )
+}
+
+/// Derive the hook's `ActionCounter` PDA for a blacklister under a mint.
+/// Seeds: `["action-counter", mint, blacklister]`.
+pub fn find_action_counter_address(mint: &Pubkey, blacklister: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::ACTION_COUNTER, mint.as_ref(), blacklister.as_ref()],
+        &TRANSFER_HOOK_PROGRAM_ID,
+    )
+}
+
+/// Derive the hook's `EnforcementQueue` PDA for a mint.
+/// Seeds: `["enforcement-queue", mint]`.
+pub fn find_enforcement_queue_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::ENFORCEMENT_QUEUE, mint.as_ref()],
+        &TRANSFER_HOOK_PROGRAM_ID,
+    )
+}
@@ -0,0 +1,124 @@
+//! Verification of sss-core `RoleAccount`s passed to other programs.
+//!
+//! The transfer hook, and any other policy program that gates instructions
+//! on an sss-core role, receives the role account unchecked. Re-deriving the
+//! PDA only proves the address; [`verify_role_account`] also requires that
+//! the account is a live, sss-core-owned `RoleAccount` whose stored config,
+//! holder and role match and whose grant has not expired, so a closed
+//! account (or anything else that ends up at the address) does not pass.
+
+use anchor_lang::prelude::*;
+
+use crate::pda::{find_config_address, find_role_address};
+use crate::state::{Role, RoleAccount};
+
+/// Why a role account was rejected. Callers map it to their own error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoleCheckError {
+    /// Not owned by sss-core.
+    WrongOwner,
+    /// Not the role PDA for this mint, holder and role.
+    WrongAddress,
+    /// Empty, closed, or not a `RoleAccount`.
+    NotARoleAccount,
+    /// Stored config, holder or role differ from the expected ones.
+    ContentMismatch,
+    /// The grant's `expires_at` has passed.
+    Expired,
+}
+
+/// Check that `role_account` is the live sss-core `RoleAccount` granting
+/// `role` to `holder` for `mint` at Unix time `now`, and return its
+/// contents.
+pub fn verify_role_account(
+    role_account: &AccountInfo,
+    mint: &Pubkey,
+    holder: &Pubkey,
+    role: Role,
+    now: i64,
+) -> std::result::Result<RoleAccount, RoleCheckError> {
+    if role_account.owner != &crate::ID {
+        return Err(RoleCheckError::WrongOwner);
+    }
+    let (config, _) = find_config_address(mint);
+    if role_account.key() != find_role_address(&config, holder, role).0 {
+        return Err(RoleCheckError::WrongAddress);
+    }
+
+    let data = role_account
+        .try_borrow_data()
+        .map_err(|_| RoleCheckError::NotARoleAccount)?;
+    let account = RoleAccount::try_deserialize(&mut &data[..])
+        .map_err(|_| RoleCheckError::NotARoleAccount)?;
+    check_role_contents(&account, &config, holder, role)?;
+    if !account.is_active(now) {
+        return Err(RoleCheckError::Expired);
+    }
+    Ok(account)
+}
+
+/// The content half of [`verify_role_account`], for an already
+/// deserialized account.
+pub fn check_role_contents(
+    account: &RoleAccount,
+    config: &Pubkey,
+    holder: &Pubkey,
+    role: Role,
+) -> std::result::Result<(), RoleCheckError> {
+    if account.config != *config || account.address != *holder || account.role != role {
+        return Err(RoleCheckError::ContentMismatch);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn role_account(config: Pubkey, address: Pubkey, role: Role) -> RoleAccount {
+        RoleAccount {
+            config,
+            address,
+            role,
+            granted_by: Pubkey::default(),
+            granted_at: 0,
+            bump: 0,
+            mint_quota: None,
+            amount_minted: 0,
+            allowed_hours: None,
+            action_quota: None,
+            action_window: Default::default(),
+            expires_at: None,
+            quota_period_secs: None,
+            period_start: 0,
+            burn_quota: None,
+            amount_burned: 0,
+            max_per_window: None,
+            window_secs: 0,
+            window_start: 0,
+            window_minted: 0,
+            prev_window_minted: 0,
+        }
+    }
+
+    #[test]
+    fn test_check_role_contents() {
+        let (config, holder) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let account = role_account(config, holder, Role::Blacklister);
+
+        assert_eq!(
+            check_role_contents(&account, &config, &holder, Role::Blacklister),
+            Ok(())
+        );
+        for (config, holder, role) in [
+            (Pubkey::new_unique(), holder, Role::Blacklister),
+            (config, Pubkey::new_unique(), Role::Blacklister),
+            (config, holder, Role::Admin),
+        ] {
+            assert_eq!(
+                check_role_contents(&account, &config, &holder, role),
+                Err(RoleCheckError::ContentMismatch)
+            );
+        }
+    }
+}
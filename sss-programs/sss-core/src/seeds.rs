@@ -0,0 +1,94 @@
+//! Seed prefixes of every PDA in both SSS programs.
+//!
+//! sss-transfer-hook depends on this crate and takes its seeds from here
+//! instead of keeping its own copies, so the two programs (and clients built
+//! on either crate) cannot drift apart on how an account is derived. The
+//! sss-core prefixes are the `SSS_*_SEED` constants on the account types;
+//! the hook prefixes are defined here and re-exported by the hook's state.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    ClaimStatus, CollateralPosition, CollateralVault, ComplianceReport, ConsumedNonce, CreditLine,
+    DeploymentCounter, DestinationThrottle, Distributor, EventQueue, FeeSchedule,
+    FeeTierAssignment, FreezeExemption, KycEntry, MintApproval, MintReceipt, MintRequest,
+    MinterKeys, PendingAction, PendingRole, PeriodSummary, Receivership, RedemptionRequest,
+    RegulatorAccess, ReserveAttestation, ReserveJournal, ReserveMovement, Role, RoleAccount,
+    RoleDirectory, SeizeApproval, SeizureRecord, Snapshot, SnapshotBalance, StablecoinConfig,
+    StandbyConfig, SubMinter, SwapRoute, SweepRoute, WipeRecord,
+};
+
+// sss-core accounts.
+pub const CONFIG: &[u8] = StablecoinConfig::SSS_CONFIG_SEED;
+pub const ROLE: &[u8] = RoleAccount::SSS_ROLE_SEED;
+pub const ROLE_DIRECTORY: &[u8] = RoleDirectory::SSS_ROLE_DIRECTORY_SEED;
+pub const PENDING_ROLE: &[u8] = PendingRole::SSS_PENDING_ROLE_SEED;
+pub const MINT_RECEIPT: &[u8] = MintReceipt::SSS_MINT_RECEIPT_SEED;
+pub const REGULATOR: &[u8] = RegulatorAccess::SSS_REGULATOR_SEED;
+pub const REPORT: &[u8] = ComplianceReport::SSS_REPORT_SEED;
+pub const NONCE: &[u8] = ConsumedNonce::SSS_NONCE_SEED;
+pub const SNAPSHOT: &[u8] = Snapshot::SSS_SNAPSHOT_SEED;
+pub const SNAPSHOT_BALANCE: &[u8] = SnapshotBalance::SSS_SNAPSHOT_BALANCE_SEED;
+pub const DISTRIBUTOR: &[u8] = Distributor::SSS_DISTRIBUTOR_SEED;
+pub const CLAIM: &[u8] = ClaimStatus::SSS_CLAIM_SEED;
+pub const SWAP_ROUTE: &[u8] = SwapRoute::SSS_SWAP_ROUTE_SEED;
+pub const SUB_MINTER: &[u8] = SubMinter::SSS_SUB_MINTER_SEED;
+pub const KYC: &[u8] = KycEntry::SSS_KYC_SEED;
+pub const EVENT_QUEUE: &[u8] = EventQueue::SSS_EVENT_QUEUE_SEED;
+pub const MINTER_KEYS: &[u8] = MinterKeys::SSS_MINTER_KEYS_SEED;
+pub const RESERVE_JOURNAL: &[u8] = ReserveJournal::SSS_RESERVE_JOURNAL_SEED;
+pub const RESERVE_MOVEMENT: &[u8] = ReserveMovement::SSS_RESERVE_MOVEMENT_SEED;
+pub const RESERVE_ATTESTATION: &[u8] = ReserveAttestation::SSS_RESERVE_ATTESTATION_SEED;
+pub const COLLATERAL_VAULT: &[u8] = CollateralVault::SSS_COLLATERAL_VAULT_SEED;
+pub const COLLATERAL_POSITION: &[u8] = CollateralPosition::SSS_COLLATERAL_POSITION_SEED;
+pub const FREEZE_EXEMPTION: &[u8] = FreezeExemption::SSS_FREEZE_EXEMPTION_SEED;
+pub const RECEIVERSHIP: &[u8] = Receivership::SSS_RECEIVERSHIP_SEED;
+pub const DESTINATION_THROTTLE: &[u8] = DestinationThrottle::SSS_DESTINATION_THROTTLE_SEED;
+pub const FEE_SCHEDULE: &[u8] = FeeSchedule::SSS_FEE_SCHEDULE_SEED;
+pub const FEE_TIER: &[u8] = FeeTierAssignment::SSS_FEE_TIER_SEED;
+pub const SWEEP_ROUTE: &[u8] = SweepRoute::SSS_SWEEP_ROUTE_SEED;
+pub const DEPLOYMENT_COUNTER: &[u8] = DeploymentCounter::SSS_DEPLOYMENT_COUNTER_SEED;
+pub const CREDIT_LINE: &[u8] = CreditLine::SSS_CREDIT_LINE_SEED;
+pub const STANDBY_CONFIG: &[u8] = StandbyConfig::SSS_STANDBY_CONFIG_SEED;
+pub const PENDING_ACTION: &[u8] = PendingAction::SSS_PENDING_ACTION_SEED;
+pub const MINT_APPROVAL: &[u8] = MintApproval::SSS_MINT_APPROVAL_SEED;
+pub const MINT_REQUEST: &[u8] = MintRequest::SSS_MINT_REQUEST_SEED;
+pub const REDEMPTION: &[u8] = RedemptionRequest::SSS_REDEMPTION_SEED;
+pub const SEIZE_APPROVAL: &[u8] = SeizeApproval::SSS_SEIZE_APPROVAL_SEED;
+pub const SEIZURE_RECORD: &[u8] = SeizureRecord::SSS_SEIZURE_RECORD_SEED;
+pub const WIPE_RECORD: &[u8] = WipeRecord::SSS_WIPE_RECORD_SEED;
+pub const PERIOD_SUMMARY: &[u8] = PeriodSummary::SSS_PERIOD_SUMMARY_SEED;
+
+// sss-transfer-hook accounts.
+pub const BLACKLIST: &[u8] = b"blacklist";
+pub const EXTRA_ACCOUNT_METAS: &[u8] = b"extra-account-metas";
+pub const HOOK_CONFIG: &[u8] = b"hook-config";
+pub const RISK_SCORE: &[u8] = b"risk-score";
+pub const HOOK_EVENT_QUEUE: &[u8] = b"hook-event-queue";
+pub const ACCOUNT_HOLD: &[u8] = b"account-hold";
+pub const ACTION_COUNTER: &[u8] = b"action-counter";
+pub const ENFORCEMENT_QUEUE: &[u8] = b"enforcement-queue";
+
+/// The trailing role byte of `RoleAccount` and `RoleDirectory` seeds.
+pub const fn role_seed(role: Role) -> [u8; 1] {
+    [role.as_u8()]
+}
+
+/// A `u64` seed component (nonce, period, slot, index): little-endian bytes.
+pub const fn u64_seed(value: u64) -> [u8; 8] {
+    value.to_le_bytes()
+}
+
+/// `a == b`, usable in const context, e.g. to check at compile time that a
+/// mirrored program ID matches the program's own.
+pub const fn same_address(a: &Pubkey, b: &Pubkey) -> bool {
+    let (a, b) = (a.to_bytes(), b.to_bytes());
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,313 @@
+//! Versioned, domain-separated messages for off-chain signatures.
+//!
+//! Every SSS feature that accepts a signature made off-chain (permits,
+//! reserve attestations, guardian approvals) signs the bytes built by
+//! [`SigningDomain::message`]:
+//!
+//! ```text
+//! 0xff "sss-offchain" (13) | version u8 | kind u8 | cluster u8
+//!   | program_id (32) | mint (32) | nonce u64 LE | payload_len u32 LE | payload
+//! ```
+//!
+//! The leading `0xff` can never start a legacy or versioned transaction
+//! message, so a wallet signature over one of these can never be replayed as
+//! a transaction. Program id, mint and cluster pin the signature to one
+//! deployment of one stablecoin; `kind` keeps a permit from being read as an
+//! attestation with the same payload. The nonce is the caller's replay
+//! protection: the consuming instruction must mark it used (e.g. with a PDA
+//! keyed by signer and nonce).
+//!
+//! On-chain, signatures are checked by the Ed25519 precompile. The client
+//! places an Ed25519 instruction (web3.js `Ed25519Program`) before the SSS
+//! instruction; the SSS instruction loads it from the instructions sysvar
+//! (`load_instruction_at_checked`) and calls [`verify_ed25519_instruction`]
+//! to confirm who signed what.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use solana_sdk_ids::ed25519_program;
+
+/// Prefix of every SSS off-chain message.
+pub const MESSAGE_PREFIX: &[u8] = b"\xffsss-offchain";
+
+/// Current message format version.
+pub const MESSAGE_VERSION: u8 = 1;
+
+/// What a signed message authorises. Append new kinds; never renumber.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MessageKind {
+    Permit = 0,
+    Attestation = 1,
+    GuardianApproval = 2,
+}
+
+impl MessageKind {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(MessageKind::Permit),
+            1 => Some(MessageKind::Attestation),
+            2 => Some(MessageKind::GuardianApproval),
+            _ => None,
+        }
+    }
+}
+
+/// Solana cluster the signature is valid on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Cluster {
+    Mainnet = 0,
+    Devnet = 1,
+    Testnet = 2,
+    Localnet = 3,
+}
+
+impl Cluster {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Cluster::Mainnet),
+            1 => Some(Cluster::Devnet),
+            2 => Some(Cluster::Testnet),
+            3 => Some(Cluster::Localnet),
+            _ => None,
+        }
+    }
+}
+
+/// The deployment a message is bound to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SigningDomain {
+    pub program_id: Pubkey,
+    pub mint: Pubkey,
+    pub cluster: Cluster,
+}
+
+/// A message decoded by [`SignedMessage::parse`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignedMessage<'a> {
+    pub version: u8,
+    pub kind: MessageKind,
+    pub domain: SigningDomain,
+    pub nonce: u64,
+    pub payload: &'a [u8],
+}
+
+/// Bytes before the payload.
+const HEADER_LEN: usize = 13 + 1 + 1 + 1 + 32 + 32 + 8 + 4;
+
+impl SigningDomain {
+    pub fn new(program_id: Pubkey, mint: Pubkey, cluster: Cluster) -> Self {
+        Self {
+            program_id,
+            mint,
+            cluster,
+        }
+    }
+
+    /// The exact bytes to sign for `kind` with `nonce` and `payload`.
+    pub fn message(&self, kind: MessageKind, nonce: u64, payload: &[u8]) -> Vec<u8> {
+        let mut message = Vec::with_capacity(HEADER_LEN + payload.len());
+        message.extend_from_slice(MESSAGE_PREFIX);
+        message.push(MESSAGE_VERSION);
+        message.push(kind as u8);
+        message.push(self.cluster as u8);
+        message.extend_from_slice(self.program_id.as_ref());
+        message.extend_from_slice(self.mint.as_ref());
+        message.extend_from_slice(&nonce.to_le_bytes());
+        message.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        message.extend_from_slice(payload);
+        message
+    }
+}
+
+impl<'a> SignedMessage<'a> {
+    /// Decode `bytes`. Returns `None` unless they are a well-formed message
+    /// of the current version with nothing after the payload.
+    pub fn parse(bytes: &'a [u8]) -> Option<Self> {
+        let rest = bytes.strip_prefix(MESSAGE_PREFIX)?;
+        let (&[version, kind, cluster], rest) = rest.split_first_chunk::<3>()?;
+        if version != MESSAGE_VERSION {
+            return None;
+        }
+        let (program_id, rest) = rest.split_first_chunk::<32>()?;
+        let (mint, rest) = rest.split_first_chunk::<32>()?;
+        let (nonce, rest) = rest.split_first_chunk::<8>()?;
+        let (len, payload) = rest.split_first_chunk::<4>()?;
+        if payload.len() != u32::from_le_bytes(*len) as usize {
+            return None;
+        }
+        Some(Self {
+            version,
+            kind: MessageKind::from_u8(kind)?,
+            domain: SigningDomain::new(
+                Pubkey::new_from_array(*program_id),
+                Pubkey::new_from_array(*mint),
+                Cluster::from_u8(cluster)?,
+            ),
+            nonce: u64::from_le_bytes(*nonce),
+            payload,
+        })
+    }
+}
+
+/// Signer and message of a single-signature Ed25519 precompile instruction
+/// whose key, signature and message all live in its own data (the layout
+/// web3.js `Ed25519Program` produces). The precompile has already verified
+/// the signature by the time a later instruction can read it.
+pub fn ed25519_signer_and_message(ix: &Instruction) -> Option<(Pubkey, &[u8])> {
+    if ix.program_id != ed25519_program::ID {
+        return None;
+    }
+    let data = ix.data.as_slice();
+    // num_signatures(1) + padding(1), then seven u16 offsets.
+    if data.len() < 16 || data[0] != 1 {
+        return None;
+    }
+    let field = |i: usize| u16::from_le_bytes([data[2 + 2 * i], data[3 + 2 * i]]);
+    let (signature_ix, key_offset, key_ix) = (field(1), field(2) as usize, field(3));
+    let (message_offset, message_len, message_ix) =
+        (field(4) as usize, field(5) as usize, field(6));
+    // Indices other than u16::MAX point the precompile at other
+    // instructions' data, which this helper does not follow.
+    if [signature_ix, key_ix, message_ix] != [u16::MAX; 3] {
+        return None;
+    }
+    let key = data.get(key_offset..key_offset.checked_add(32)?)?;
+    let message = data.get(message_offset..message_offset.checked_add(message_len)?)?;
+    Some((Pubkey::try_from(key).ok()?, message))
+}
+
+/// Whether `ix` is an Ed25519 precompile instruction proving that `signer`
+/// signed exactly `expected_message`.
+pub fn verify_ed25519_instruction(
+    ix: &Instruction,
+    signer: &Pubkey,
+    expected_message: &[u8],
+) -> bool {
+    ed25519_signer_and_message(ix)
+        .is_some_and(|(key, message)| key == *signer && message == expected_message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shared with the SDK's `signing.test.ts`; both encoders must agree.
+    const PERMIT_VECTOR: &str = "ff7373732d6f6666636861696e010001\
+        0101010101010101010101010101010101010101010101010101010101010101\
+        0202020202020202020202020202020202020202020202020202020202020202\
+        0700000000000000\
+        05000000\
+        68656c6c6f";
+
+    fn domain() -> SigningDomain {
+        SigningDomain::new(
+            Pubkey::new_from_array([1; 32]),
+            Pubkey::new_from_array([2; 32]),
+            Cluster::Devnet,
+        )
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn test_permit_vector() {
+        let message = domain().message(MessageKind::Permit, 7, b"hello");
+        assert_eq!(hex(&message), PERMIT_VECTOR);
+
+        let parsed = SignedMessage::parse(&message).unwrap();
+        assert_eq!(parsed.kind, MessageKind::Permit);
+        assert_eq!(parsed.domain, domain());
+        assert_eq!(parsed.nonce, 7);
+        assert_eq!(parsed.payload, b"hello");
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        let message = domain().message(MessageKind::Attestation, 1, b"reserve");
+        assert!(SignedMessage::parse(&message[..message.len() - 1]).is_none());
+        assert!(SignedMessage::parse(&[&message[..], &[0]].concat()).is_none());
+
+        let mut wrong_version = message.clone();
+        wrong_version[MESSAGE_PREFIX.len()] = MESSAGE_VERSION + 1;
+        assert!(SignedMessage::parse(&wrong_version).is_none());
+
+        let mut unknown_kind = message.clone();
+        unknown_kind[MESSAGE_PREFIX.len() + 1] = 0xee;
+        assert!(SignedMessage::parse(&unknown_kind).is_none());
+    }
+
+    #[test]
+    fn test_domains_do_not_collide() {
+        let permit = domain().message(MessageKind::Permit, 7, b"hello");
+        let other_mint = SigningDomain {
+            mint: Pubkey::new_from_array([3; 32]),
+            ..domain()
+        };
+        let mainnet = SigningDomain {
+            cluster: Cluster::Mainnet,
+            ..domain()
+        };
+        assert_ne!(permit, other_mint.message(MessageKind::Permit, 7, b"hello"));
+        assert_ne!(permit, mainnet.message(MessageKind::Permit, 7, b"hello"));
+        assert_ne!(
+            permit,
+            domain().message(MessageKind::GuardianApproval, 7, b"hello")
+        );
+    }
+
+    /// Ed25519 instruction data in the layout web3.js `Ed25519Program` builds:
+    /// header at 0, public key at 16, signature at 48, message at 112.
+    fn ed25519_ix(signer: &Pubkey, message: &[u8]) -> Instruction {
+        let mut data = vec![1, 0];
+        for field in [
+            48,
+            u16::MAX,
+            16,
+            u16::MAX,
+            112,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        Instruction {
+            program_id: ed25519_program::ID,
+            accounts: vec![],
+            data,
+        }
+    }
+
+    #[test]
+    fn test_verify_ed25519_instruction() {
+        let signer = Pubkey::new_unique();
+        let message = domain().message(MessageKind::GuardianApproval, 3, &[9; 40]);
+        let ix = ed25519_ix(&signer, &message);
+        assert!(verify_ed25519_instruction(&ix, &signer, &message));
+        assert!(!verify_ed25519_instruction(
+            &ix,
+            &Pubkey::new_unique(),
+            &message
+        ));
+        assert!(!verify_ed25519_instruction(&ix, &signer, &message[1..]));
+
+        let mut other_program = ix.clone();
+        other_program.program_id = Pubkey::new_unique();
+        assert!(!verify_ed25519_instruction(
+            &other_program,
+            &signer,
+            &message
+        ));
+
+        // Message data borrowed from another instruction is not followed.
+        let mut external = ix;
+        external.data[14..16].copy_from_slice(&0u16.to_le_bytes());
+        assert!(!verify_ed25519_instruction(&external, &signer, &message));
+    }
+}
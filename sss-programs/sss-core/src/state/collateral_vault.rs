@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+
+/// On-chain collateral backing part of a config's supply. Anyone may
+/// `deposit_and_mint` the approved collateral for stablecoins at
+/// `collateral_ratio_bps`. Each depositor's collateral and issuance are kept
+/// in their `CollateralPosition`, and `burn_and_withdraw` only releases
+/// collateral from the burner's own position.
+#[account]
+pub struct CollateralVault {
+    pub config: Pubkey,
+    /// The approved collateral, e.g. USDC.
+    pub collateral_mint: Pubkey,
+    /// The vault PDA's associated token account for `collateral_mint`.
+    pub vault_account: Pubkey,
+    /// Collateral value required per unit issued, in basis points; at least
+    /// `MIN_RATIO_BPS`.
+    pub collateral_ratio_bps: u16,
+    /// Pyth feed pricing the collateral in USD. `None` values it at par,
+    /// one whole collateral token per whole stablecoin.
+    pub collateral_feed_id: Option<[u8; 32]>,
+    /// Collateral held, in collateral base units.
+    pub total_collateral: u64,
+    /// Stablecoins issued against the vault and not yet burned back into
+    /// it, in mint base units.
+    pub total_issued: u64,
+    pub bump: u8,
+}
+
+impl CollateralVault {
+    pub const SSS_COLLATERAL_VAULT_SEED: &'static [u8] = b"sss-collateral-vault";
+
+    /// 100%: every stablecoin fully backed.
+    pub const MIN_RATIO_BPS: u16 = 10_000;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // collateral_mint
+        32 + // vault_account
+        2 +  // collateral_ratio_bps
+        33 + // collateral_feed_id
+        8 +  // total_collateral
+        8 +  // total_issued
+        1; // bump
+
+    /// Record `collateral` deposited for `issued` stablecoins. `None` on
+    /// overflow, in which case nothing is changed.
+    pub fn record_deposit(&mut self, collateral: u64, issued: u64) -> Option<()> {
+        let total_collateral = self.total_collateral.checked_add(collateral)?;
+        self.total_issued = self.total_issued.checked_add(issued)?;
+        self.total_collateral = total_collateral;
+        Some(())
+    }
+
+    /// Record `burned` stablecoins redeemed for `collateral`. `None` on
+    /// underflow, in which case nothing is changed.
+    pub fn record_withdrawal(&mut self, collateral: u64, burned: u64) -> Option<()> {
+        let total_collateral = self.total_collateral.checked_sub(collateral)?;
+        self.total_issued = self.total_issued.checked_sub(burned)?;
+        self.total_collateral = total_collateral;
+        Some(())
+    }
+}
+
+/// One depositor's share of a `CollateralVault`: what they deposited and
+/// what was minted to them for it, net of withdrawals.
+#[account]
+pub struct CollateralPosition {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    /// Collateral deposited and not yet withdrawn, in collateral base units.
+    pub collateral: u64,
+    /// Stablecoins minted for `collateral` and not yet burned back, in mint
+    /// base units.
+    pub issued: u64,
+    pub bump: u8,
+}
+
+impl CollateralPosition {
+    pub const SSS_COLLATERAL_POSITION_SEED: &'static [u8] = b"sss-collateral-position";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // vault
+        32 + // owner
+        8 +  // collateral
+        8 +  // issued
+        1; // bump
+
+    /// Collateral released for burning `amount` of the position's issuance:
+    /// the same share of `collateral` as `amount` is of `issued`, rounded
+    /// down so the position's ratio never falls, and all of it for the last
+    /// burn. `None` if `amount` exceeds `issued`.
+    pub fn withdrawal_for(&self, amount: u64) -> Option<u64> {
+        if amount > self.issued {
+            return None;
+        }
+        if amount == self.issued {
+            return Some(self.collateral);
+        }
+        let share = u128::from(self.collateral) * u128::from(amount) / u128::from(self.issued);
+        u64::try_from(share).ok()
+    }
+
+    /// Whether the position backs its issuance at least as well as
+    /// `collateral_before` backed `issued_before`. Holds after every
+    /// `withdrawal_for`; checked again before collateral leaves the vault.
+    pub fn ratio_kept(&self, collateral_before: u64, issued_before: u64) -> bool {
+        u128::from(self.collateral) * u128::from(issued_before)
+            >= u128::from(collateral_before) * u128::from(self.issued)
+    }
+
+    /// Record `collateral` deposited for `issued` stablecoins. `None` on
+    /// overflow, in which case nothing is changed.
+    pub fn record_deposit(&mut self, collateral: u64, issued: u64) -> Option<()> {
+        let total_collateral = self.collateral.checked_add(collateral)?;
+        self.issued = self.issued.checked_add(issued)?;
+        self.collateral = total_collateral;
+        Some(())
+    }
+
+    /// Record `burned` stablecoins redeemed for `collateral`. `None` on
+    /// underflow, in which case nothing is changed.
+    pub fn record_withdrawal(&mut self, collateral: u64, burned: u64) -> Option<()> {
+        let remaining = self.collateral.checked_sub(collateral)?;
+        self.issued = self.issued.checked_sub(burned)?;
+        self.collateral = remaining;
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault() -> CollateralVault {
+        CollateralVault {
+            config: Pubkey::new_unique(),
+            collateral_mint: Pubkey::new_unique(),
+            vault_account: Pubkey::new_unique(),
+            collateral_ratio_bps: 15_000,
+            collateral_feed_id: Some([7; 32]),
+            total_collateral: 0,
+            total_issued: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_space_matches_serialized_size() {
+        let mut serialized = Vec::new();
+        vault().serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), CollateralVault::SPACE);
+    }
+
+    #[test]
+    fn test_position_space_matches_serialized_size() {
+        let position = CollateralPosition {
+            vault: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            collateral: 0,
+            issued: 0,
+            bump: 255,
+        };
+        let mut serialized = Vec::new();
+        position.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), CollateralPosition::SPACE);
+    }
+
+    #[test]
+    fn test_withdrawals_come_from_the_position() {
+        let mut position = CollateralPosition {
+            vault: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            collateral: 0,
+            issued: 0,
+            bump: 255,
+        };
+        position.record_deposit(1_500, 1_000).unwrap();
+        position.record_deposit(1_501, 1_000).unwrap();
+
+        // Burning more than the position was issued releases nothing, however
+        // much the vault holds for other depositors.
+        assert_eq!(position.withdrawal_for(2_001), None);
+        let share = position.withdrawal_for(1_000).unwrap();
+        assert_eq!(share, 1_500);
+        position.record_withdrawal(share, 1_000).unwrap();
+        assert!(position.ratio_kept(3_001, 2_000));
+        // The last burn takes the remainder, rounding dust included.
+        assert_eq!(position.withdrawal_for(1_000), Some(1_501));
+        position.record_withdrawal(1_501, 1_000).unwrap();
+        assert_eq!((position.collateral, position.issued), (0, 0));
+        assert_eq!(position.record_withdrawal(1, 0), None);
+
+        // Taking more than the pro-rata share would lower the ratio.
+        position.record_deposit(1_500, 1_000).unwrap();
+        position.record_withdrawal(751, 500).unwrap();
+        assert!(!position.ratio_kept(1_500, 1_000));
+    }
+}
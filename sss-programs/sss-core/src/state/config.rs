@@ -1,11 +1,18 @@
 use anchor_lang::prelude::*;
+use solana_keccak_hasher::hashv;
+
+use crate::constants::{capability, pause, TRANSFER_HOOK_PROGRAM_ID};
+use crate::state::Role;
 
 #[account]
 pub struct StablecoinConfig {
     pub authority: Pubkey,
     pub mint: Pubkey,
     pub preset: u8,
-    pub paused: bool,
+    /// Paused operation classes (see `constants::pause`). Takes the byte of
+    /// the former `paused` flag, so a config paused by an earlier build
+    /// reads as mint-only paused; unpause before upgrading.
+    pub pause_flags: u8,
     pub supply_cap: Option<u64>,
     pub total_minted: u64,
     pub total_burned: u64,
@@ -30,7 +37,176 @@ pub struct StablecoinConfig {
     /// `None` means oracle-adjusted minting is disabled for this stablecoin.
     /// Must be set via `update_oracle_feed` before passing a `price_update` account
     /// to `mint_tokens`. Using a wildcard (all-zeros) is explicitly rejected.
+    /// Under `OracleKind::Switchboard` it holds the pull feed account's address.
     pub oracle_feed_id: Option<[u8; 32]>,
+    /// Mints of at least this amount must go through `mint_tokens_with_receipt`.
+    /// `None` disables the requirement.
+    pub receipt_threshold: Option<u64>,
+    /// Seconds a `MintReceipt` must be kept before an admin can close it.
+    pub receipt_retention_secs: i64,
+    /// Maximum Pyth confidence interval, in basis points of the price, accepted
+    /// for oracle-adjusted minting. `None` skips the check.
+    pub max_confidence_bps: Option<u16>,
+    /// `[major, minor, patch]` of the program that created (or last migrated)
+    /// this config. See [`crate::version::is_compatible`].
+    pub program_version: [u8; 3],
+    /// Whether pause, unpause and seize must also be recorded in the
+    /// config's `EventQueue` ring buffer.
+    pub event_queue_enabled: bool,
+    /// Bitmask of `constants::capability` bits the issuer has switched off.
+    pub disabled_instructions: u32,
+    /// Subset of `disabled_instructions` that can never be re-enabled.
+    pub locked_instructions: u32,
+    /// Tokens escrowed by pending redemption requests. They are still part
+    /// of `current_supply()`, so their cap headroom stays reserved until the
+    /// request is settled (burned) or cancelled, but they are excluded from
+    /// `circulating_supply()`.
+    pub reserved_for_redemption: u64,
+    /// Rolling keccak-256 over every state change, see [`Self::refresh_digest`].
+    /// Lets a light client check policy and supply with one account proof.
+    pub state_digest: [u8; 32],
+    /// Number of state changes folded into `state_digest`.
+    pub state_nonce: u64,
+    /// Tokens minted by the Bridge role for transfers in from other chains.
+    /// Not part of `total_minted`, so they never count against the cap.
+    pub bridge_minted: u64,
+    /// Tokens burned by the Bridge role for transfers out to other chains.
+    /// Not part of `total_burned`.
+    pub bridge_burned: u64,
+    /// Reduced cap applied after a long pause. `None` disables the ramp.
+    pub thaw_ramp: Option<ThawRamp>,
+    /// Slot of the most recent `pause`.
+    pub paused_at_slot: u64,
+    /// Slot `unpause` started the current ramp, if one is running.
+    pub ramp_started_at: Option<u64>,
+    /// `current_supply()` when the current ramp started.
+    pub ramp_base_supply: u64,
+    /// Issuer-declared incident (exploit, depeg, outage) for wallets and
+    /// front-ends to display. Informational only: it blocks nothing.
+    pub incident_flag: bool,
+    /// Unix time `incident_flag` or `incident_uri` last changed.
+    pub incident_updated_at: i64,
+    /// Machine-readable incident status document (max 200 bytes, may be
+    /// empty). Space for the maximum is reserved at initialization.
+    pub incident_uri: String,
+    /// Most any one token account may receive from minters per
+    /// `DestinationThrottle::WINDOW_SECS`. `None` disables the throttle.
+    pub destination_mint_limit: Option<u64>,
+    /// Whether `verify_supply` disables the mint capability when the mint
+    /// holds more tokens than the counters account for.
+    pub halt_mint_on_supply_mismatch: bool,
+    /// Which Pyth price converts a USD `supply_cap` to token units.
+    pub oracle_price_source: OraclePriceSource,
+    /// Whether `verify_authorities` pauses when the mint's authorities or
+    /// transfer hook were changed out-of-band.
+    pub pause_on_authority_anomaly: bool,
+    /// Compact program-wide number assigned at `initialize` from the
+    /// `DeploymentCounter`, carried in every event so multi-tenant indexers
+    /// can partition by it. Zero when none was assigned.
+    pub mint_index: u64,
+    /// Set by `propose_authority`; the key that may `accept_authority`.
+    pub pending_authority: Option<Pubkey>,
+    /// Delay before a queued `PendingAction` may be executed. While non-zero,
+    /// supply cap changes, Admin grants, authority proposals and changes to
+    /// the delay itself must go through the queue. Zero disables the timelock.
+    pub admin_timelock_secs: u32,
+    /// Mints above this amount need a `MintApproval` co-signed by
+    /// `large_mint_approvals` other Minter or Admin holders. `None` disables
+    /// co-signing.
+    pub large_mint_threshold: Option<u64>,
+    pub large_mint_approvals: u8,
+    /// When set, `unpause` takes an Admin instead of a Pauser, so operations
+    /// staff can stop the token but not resume it.
+    pub unpause_requires_admin: bool,
+    /// Longest a pause lasts before lifting on its own unless an Admin
+    /// renews it, so a lost or compromised Pauser key cannot halt the token
+    /// indefinitely. Zero means pauses never expire.
+    pub max_pause_secs: u32,
+    /// Unix time the current pause lifts, if it expires.
+    pub pause_expires_at: Option<i64>,
+    /// Seizures above this amount need a `SeizeApproval` from a second
+    /// Seizer or Admin. `None` lets a Seizer act alone.
+    pub large_seize_threshold: Option<u64>,
+    /// Seizures recorded so far; the index of the next `SeizureRecord`.
+    pub seizure_count: u64,
+    /// Wallet whose associated token account must receive seized funds.
+    /// `None` lets a Seizer choose any destination.
+    pub treasury: Option<Pubkey>,
+    /// Only frozen accounts may be seized, enforcing freeze, investigate,
+    /// then seize.
+    pub seize_requires_frozen: bool,
+    /// Wipes recorded so far; the index of the next `WipeRecord`.
+    pub wipe_count: u64,
+    /// Account layout the config was last written with; see
+    /// `CURRENT_VERSION`. Zero for configs created before the field existed.
+    pub version: u8,
+    /// When an Admin called `sunset`, after which minting stays disabled
+    /// for good and `close_config` becomes available. Since version 2.
+    pub sunset_at: Option<i64>,
+    /// Which oracle network `oracle_feed_id` names and `price_update`
+    /// accounts come from. Since version 3.
+    pub oracle_kind: OracleKind,
+    /// Oldest `ReserveAttestation` that still backs issuance, in seconds.
+    /// While set, supply may only grow up to the attested reserves. `None`
+    /// disables the gate. Since version 4.
+    pub max_attestation_age_secs: Option<u32>,
+    /// Token account of this mint that `FeeSchedule` mint and burn fees are
+    /// paid into. `None` charges no fees. Since version 5.
+    pub fee_treasury: Option<Pubkey>,
+}
+
+/// After a pause of at least `min_pause_slots`, `unpause` starts a ramp:
+/// only `start_bps` of the supply-cap headroom is usable at first, and the
+/// rest opens linearly over `ramp_slots`. Configs without a supply cap are
+/// unaffected.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThawRamp {
+    /// Shortest pause, in slots, that starts a ramp on `unpause`.
+    pub min_pause_slots: u64,
+    /// Slots for the cap to climb back to its configured value.
+    pub ramp_slots: u64,
+    /// Share of the headroom available right after `unpause`, in basis points.
+    pub start_bps: u16,
+}
+
+/// The price read from a Pyth `PriceUpdateV2` for cap conversion.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OraclePriceSource {
+    /// The aggregate spot price.
+    #[default]
+    Spot,
+    /// Pyth's exponentially weighted moving average of the price, which
+    /// smooths momentary wicks so the mintable headroom does not swing
+    /// between transactions.
+    Ema,
+}
+
+/// The oracle network a USD `supply_cap` is converted with.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OracleKind {
+    /// A Pyth `PriceUpdateV2`, matched on its feed ID.
+    #[default]
+    Pyth,
+    /// A Switchboard On-Demand pull feed, matched on its account address.
+    /// Has no EMA, so `oracle_price_source` does not apply.
+    Switchboard,
+}
+
+/// The authorities and hook program a Token-2022 mint currently names, as
+/// read by `initialize` and `verify_authorities`. `None` where unset or the extension is
+/// absent.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintAuthorities {
+    pub mint_authority: Option<Pubkey>,
+    pub freeze_authority: Option<Pubkey>,
+    pub permanent_delegate: Option<Pubkey>,
+    pub transfer_hook_program: Option<Pubkey>,
+}
+
+impl ThawRamp {
+    pub fn is_valid(&self) -> bool {
+        self.ramp_slots > 0 && self.start_bps < 10_000
+    }
 }
 
 impl StablecoinConfig {
@@ -42,7 +218,7 @@ impl StablecoinConfig {
     ///   32  authority
     ///   32  mint
     ///   1   preset
-    ///   1   paused
+    ///   1   pause_flags
     ///   9   Option<u64> supply_cap  (1 flag + 8 value)
     ///   8   total_minted
     ///   8   total_burned
@@ -53,20 +229,539 @@ impl StablecoinConfig {
     ///   1   default_account_frozen
     ///   4   admin_count (u32)
     ///   33  Option<[u8;32]> oracle_feed_id (1 flag + 32 bytes)
-    pub const BASE_SIZE: usize = 8 + 32 + 32 + 1 + 1 + 9 + 8 + 8 + 1 + 1 + 1 + 1 + 1 + 4 + 33;
+    ///   9   Option<u64> receipt_threshold
+    ///   8   receipt_retention_secs (i64)
+    ///   3   Option<u16> max_confidence_bps
+    ///   3   program_version ([u8; 3])
+    ///   1   event_queue_enabled
+    ///   4   disabled_instructions (u32)
+    ///   4   locked_instructions (u32)
+    ///   8   reserved_for_redemption
+    ///   32  state_digest
+    ///   8   state_nonce
+    ///   8   bridge_minted
+    ///   8   bridge_burned
+    ///   19  Option<ThawRamp> thaw_ramp (1 flag + 8 + 8 + 2)
+    ///   8   paused_at_slot
+    ///   9   Option<u64> ramp_started_at
+    ///   8   ramp_base_supply
+    ///   1   incident_flag
+    ///   8   incident_updated_at (i64)
+    ///   9   Option<u64> destination_mint_limit
+    ///   1   halt_mint_on_supply_mismatch
+    ///   1   oracle_price_source (enum tag)
+    ///   1   pause_on_authority_anomaly
+    ///   8   mint_index
+    ///   33  Option<Pubkey> pending_authority
+    ///   4   admin_timelock_secs (u32)
+    ///   9   Option<u64> large_mint_threshold
+    ///   1   large_mint_approvals
+    ///   1   unpause_requires_admin
+    ///   4   max_pause_secs (u32)
+    ///   9   Option<i64> pause_expires_at
+    ///   9   Option<u64> large_seize_threshold
+    ///   8   seizure_count
+    ///   33  Option<Pubkey> treasury
+    ///   1   seize_requires_frozen
+    ///   8   wipe_count
+    ///   1   version
+    ///   9   Option<i64> sunset_at
+    ///   1   oracle_kind (enum tag)
+    ///   5   Option<u32> max_attestation_age_secs
+    ///   33  Option<Pubkey> fee_treasury
+    pub const BASE_SIZE: usize = 8
+        + 32
+        + 32
+        + 1
+        + 1
+        + 9
+        + 8
+        + 8
+        + 1
+        + 1
+        + 1
+        + 1
+        + 1
+        + 4
+        + 33
+        + 9
+        + 8
+        + 3
+        + 3
+        + 1
+        + 4
+        + 4
+        + 8
+        + 32
+        + 8
+        + 8
+        + 8
+        + 19
+        + 8
+        + 9
+        + 8
+        + 1
+        + 8
+        + 9
+        + 1
+        + 1
+        + 1
+        + 8
+        + 33
+        + 4
+        + 9
+        + 1
+        + 1
+        + 4
+        + 9
+        + 9
+        + 8
+        + 33
+        + 1
+        + 8
+        + 1
+        + 9
+        + 1
+        + 5
+        + 33;
+
+    /// Account layout written by this build. Bumped whenever a program
+    /// upgrade appends fields; older configs are brought up to it with
+    /// `migrate_config`.
+    pub const CURRENT_VERSION: u8 = 5;
+
+    /// Bytes of the fields appended after `version` itself, `sunset_at`
+    /// through `fee_treasury`. A config created before `version` existed was
+    /// sized without any of them, nor the `version` byte.
+    const SIZE_AFTER_VERSION: usize = 9 + 1 + 5 + 33;
+
+    /// Longest `name`, in bytes.
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Longest `symbol`, in bytes.
+    pub const MAX_SYMBOL_LEN: usize = 10;
+
+    /// Longest metadata `uri`, in bytes.
+    pub const MAX_URI_LEN: usize = 200;
+
+    /// Longest `incident_uri`, in bytes.
+    pub const MAX_INCIDENT_URI_LEN: usize = 200;
+
+    /// Domain tag hashed first into every `state_digest`.
+    pub const STATE_DIGEST_DOMAIN: &'static [u8] = b"sss-config-digest-v1";
 
     /// Compute the total account space needed for a specific set of string lengths.
     /// Borsh serialises `String` as a `u32` length prefix (4 bytes) followed by the
     /// UTF-8 content bytes, so each string field costs `4 + len` bytes.
+    /// `incident_uri` changes after initialization, so its maximum is reserved.
     pub fn compute_space(name: &str, symbol: &str, uri: &str) -> usize {
-        Self::BASE_SIZE + (4 + name.len()) + (4 + symbol.len()) + (4 + uri.len())
+        Self::BASE_SIZE
+            + (4 + name.len())
+            + (4 + symbol.len())
+            + (4 + uri.len())
+            + (4 + Self::MAX_INCIDENT_URI_LEN)
+    }
+
+    /// Fold the current state into `state_digest`. Call after every change
+    /// to the config, once per instruction:
+    ///
+    /// ```text
+    /// state_nonce += 1
+    /// state_digest = keccak256(STATE_DIGEST_DOMAIN || previous state_digest
+    ///     || state_nonce || mint || authority || preset || pause_flags || supply_cap
+    ///     || total_minted || total_burned || reserved_for_redemption
+    ///     || bridge_minted || bridge_burned
+    ///     || thaw_ramp || ramp_started_at || ramp_base_supply
+    ///     || admin_count || oracle_feed_id || receipt_threshold
+    ///     || receipt_retention_secs || max_confidence_bps || event_queue_enabled
+    ///     || disabled_instructions || locked_instructions || program_version
+    ///     || incident_flag || incident_uri || destination_mint_limit
+    ///     || halt_mint_on_supply_mismatch || oracle_price_source
+    ///     || pause_on_authority_anomaly || pending_authority
+    ///     || admin_timelock_secs || large_mint_threshold || large_mint_approvals
+    ///     || uri || unpause_requires_admin || max_pause_secs || pause_expires_at
+    ///     || large_seize_threshold || seizure_count || treasury
+    ///     || seize_requires_frozen || wipe_count || name || symbol || version
+    ///     || sunset_at || oracle_kind || max_attestation_age_secs
+    ///     || fee_treasury)
+    /// ```
+    ///
+    /// Integers are little-endian, and options and strings are Borsh-encoded
+    /// (a 0/1 tag or a `u32` length, then the value), matching the account
+    /// layout.
+    pub fn refresh_digest(&mut self) {
+        self.state_nonce = self.state_nonce.wrapping_add(1);
+        self.state_digest = self.next_digest();
+    }
+
+    fn next_digest(&self) -> [u8; 32] {
+        fn push_option<T: AsRef<[u8]>>(fields: &mut Vec<u8>, value: Option<T>) {
+            match value {
+                Some(value) => {
+                    fields.push(1);
+                    fields.extend_from_slice(value.as_ref());
+                }
+                None => fields.push(0),
+            }
+        }
+
+        let mut fields = Vec::with_capacity(200);
+        fields.extend_from_slice(self.mint.as_ref());
+        fields.extend_from_slice(self.authority.as_ref());
+        fields.push(self.preset);
+        fields.push(self.pause_flags);
+        push_option(&mut fields, self.supply_cap.map(u64::to_le_bytes));
+        fields.extend_from_slice(&self.total_minted.to_le_bytes());
+        fields.extend_from_slice(&self.total_burned.to_le_bytes());
+        fields.extend_from_slice(&self.reserved_for_redemption.to_le_bytes());
+        fields.extend_from_slice(&self.bridge_minted.to_le_bytes());
+        fields.extend_from_slice(&self.bridge_burned.to_le_bytes());
+        push_option(
+            &mut fields,
+            self.thaw_ramp.map(|ramp| {
+                [
+                    &ramp.min_pause_slots.to_le_bytes()[..],
+                    &ramp.ramp_slots.to_le_bytes(),
+                    &ramp.start_bps.to_le_bytes(),
+                ]
+                .concat()
+            }),
+        );
+        push_option(&mut fields, self.ramp_started_at.map(u64::to_le_bytes));
+        fields.extend_from_slice(&self.ramp_base_supply.to_le_bytes());
+        fields.extend_from_slice(&self.admin_count.to_le_bytes());
+        push_option(&mut fields, self.oracle_feed_id);
+        push_option(&mut fields, self.receipt_threshold.map(u64::to_le_bytes));
+        fields.extend_from_slice(&self.receipt_retention_secs.to_le_bytes());
+        push_option(&mut fields, self.max_confidence_bps.map(u16::to_le_bytes));
+        fields.push(self.event_queue_enabled as u8);
+        fields.extend_from_slice(&self.disabled_instructions.to_le_bytes());
+        fields.extend_from_slice(&self.locked_instructions.to_le_bytes());
+        fields.extend_from_slice(&self.program_version);
+        fields.push(self.incident_flag as u8);
+        fields.extend_from_slice(&(self.incident_uri.len() as u32).to_le_bytes());
+        fields.extend_from_slice(self.incident_uri.as_bytes());
+        push_option(
+            &mut fields,
+            self.destination_mint_limit.map(u64::to_le_bytes),
+        );
+        fields.push(self.halt_mint_on_supply_mismatch as u8);
+        fields.push(self.oracle_price_source as u8);
+        fields.push(self.pause_on_authority_anomaly as u8);
+        push_option(&mut fields, self.pending_authority);
+        fields.extend_from_slice(&self.admin_timelock_secs.to_le_bytes());
+        push_option(&mut fields, self.large_mint_threshold.map(u64::to_le_bytes));
+        fields.push(self.large_mint_approvals);
+        fields.extend_from_slice(&(self.uri.len() as u32).to_le_bytes());
+        fields.extend_from_slice(self.uri.as_bytes());
+        fields.push(self.unpause_requires_admin as u8);
+        fields.extend_from_slice(&self.max_pause_secs.to_le_bytes());
+        push_option(&mut fields, self.pause_expires_at.map(i64::to_le_bytes));
+        push_option(
+            &mut fields,
+            self.large_seize_threshold.map(u64::to_le_bytes),
+        );
+        fields.extend_from_slice(&self.seizure_count.to_le_bytes());
+        push_option(&mut fields, self.treasury);
+        fields.push(self.seize_requires_frozen as u8);
+        fields.extend_from_slice(&self.wipe_count.to_le_bytes());
+        fields.extend_from_slice(&(self.name.len() as u32).to_le_bytes());
+        fields.extend_from_slice(self.name.as_bytes());
+        fields.extend_from_slice(&(self.symbol.len() as u32).to_le_bytes());
+        fields.extend_from_slice(self.symbol.as_bytes());
+        fields.push(self.version);
+        push_option(&mut fields, self.sunset_at.map(i64::to_le_bytes));
+        fields.push(self.oracle_kind as u8);
+        push_option(
+            &mut fields,
+            self.max_attestation_age_secs.map(u32::to_le_bytes),
+        );
+        push_option(&mut fields, self.fee_treasury);
+
+        hashv(&[
+            Self::STATE_DIGEST_DOMAIN,
+            &self.state_digest,
+            &self.state_nonce.to_le_bytes(),
+            &fields,
+        ])
+        .to_bytes()
+    }
+
+    /// Layout version of this config as decoded from an account of
+    /// `data_len` bytes. Every layout was sized exactly by its
+    /// `compute_space`, so an account too short to have held the `version`
+    /// byte predates the field: what decoded there is slack and the layout
+    /// is version 0.
+    pub fn stored_version(&self, data_len: usize) -> u8 {
+        let versioned_len =
+            Self::compute_space(&self.name, &self.symbol, &self.uri) - Self::SIZE_AFTER_VERSION;
+        if data_len < versioned_len {
+            0
+        } else {
+            self.version.min(Self::CURRENT_VERSION)
+        }
+    }
+
+    /// Clear the fields appended after layout `version`. `migrate_config`
+    /// decodes them from whatever bytes followed the old layout, which may be
+    /// stale rather than zero.
+    pub fn reset_fields_after(&mut self, version: u8) {
+        if version < 2 {
+            self.sunset_at = None;
+        }
+        if version < 3 {
+            self.oracle_kind = OracleKind::Pyth;
+        }
+        if version < 4 {
+            self.max_attestation_age_secs = None;
+        }
+        if version < 5 {
+            self.fee_treasury = None;
+        }
+    }
+
+    /// Whether this program build may operate on the config. Configs written
+    /// by a newer or different-major build, or with an older account layout,
+    /// must be migrated first.
+    pub fn is_supported_version(&self) -> bool {
+        self.version == Self::CURRENT_VERSION && crate::version::is_compatible(self.program_version)
+    }
+
+    /// Whether minting `amount` needs a co-signed `MintApproval`.
+    pub fn requires_mint_approval(&self, amount: u64) -> bool {
+        self.large_mint_threshold
+            .is_some_and(|threshold| amount > threshold)
+    }
+
+    /// Whether seizing `amount` needs a `SeizeApproval`.
+    pub fn requires_seize_approval(&self, amount: u64) -> bool {
+        self.large_seize_threshold
+            .is_some_and(|threshold| amount > threshold)
+    }
+
+    /// Claim the index of the next `SeizureRecord`. Returns `None` once the
+    /// counter is exhausted.
+    pub fn next_seizure_index(&mut self) -> Option<u64> {
+        let index = self.seizure_count;
+        self.seizure_count = index.checked_add(1)?;
+        self.refresh_digest();
+        Some(index)
+    }
+
+    /// Claim the index of the next `WipeRecord`. Returns `None` once the
+    /// counter is exhausted.
+    pub fn next_wipe_index(&mut self) -> Option<u64> {
+        let index = self.wipe_count;
+        self.wipe_count = index.checked_add(1)?;
+        self.refresh_digest();
+        Some(index)
+    }
+
+    /// The role `unpause` requires.
+    pub fn unpause_role(&self) -> Role {
+        if self.unpause_requires_admin {
+            Role::Admin
+        } else {
+            Role::Pauser
+        }
+    }
+
+    /// Whether every bit of `capability` (see `constants::capability`) is
+    /// still enabled.
+    pub fn is_enabled(&self, capability: u32) -> bool {
+        self.disabled_instructions & capability == 0
+    }
+
+    /// The `(disabled, locked)` masks after an admin sets `disabled` and
+    /// locks `lock`. Returns `None` if either mask has unknown bits, `lock`
+    /// is not a subset of `disabled`, or `disabled` would re-enable a bit
+    /// that is already locked.
+    pub fn updated_capabilities(&self, disabled: u32, lock: u32) -> Option<(u32, u32)> {
+        let locked = self.locked_instructions | lock;
+        let known = disabled & !capability::ALL == 0;
+        (known && disabled & locked == locked).then_some((disabled, locked))
     }
 
-    /// Returns the current circulating supply (minted minus burned).
+    /// Whether the mint names the authorities this config (at `config_key`)
+    /// relies on: the config PDA as mint and freeze authority and, where
+    /// enabled, as permanent delegate, and the SSS transfer hook where
+    /// enabled.
+    pub fn authorities_intact(&self, config_key: &Pubkey, observed: &MintAuthorities) -> bool {
+        let expected = |enabled: bool, key: Pubkey| enabled.then_some(key);
+        observed.mint_authority == Some(*config_key)
+            && observed.freeze_authority == Some(*config_key)
+            && observed.permanent_delegate == expected(self.enable_permanent_delegate, *config_key)
+            && observed.transfer_hook_program
+                == expected(self.enable_transfer_hook, TRANSFER_HOOK_PROGRAM_ID)
+    }
+
+    /// Whether minting requires a `KycEntry` for the destination owner.
+    /// Only the compliant SSS-2 preset enforces this.
+    pub fn requires_kyc(&self) -> bool {
+        self.preset == 2
+    }
+
+    /// Returns the outstanding supply (minted minus burned), including tokens
+    /// escrowed for redemption. Supply-cap checks use this figure.
     pub fn current_supply(&self) -> u64 {
         self.total_minted.saturating_sub(self.total_burned)
     }
 
+    /// Supply held by the public: `current_supply()` minus tokens escrowed
+    /// for redemption. This is the figure to report against the peg.
+    pub fn circulating_supply(&self) -> u64 {
+        self.current_supply()
+            .saturating_sub(self.reserved_for_redemption)
+    }
+
+    /// Supply issued through this config across every chain: what reserves
+    /// must back and the supply cap bounds. Bridging moves tokens between
+    /// chains without changing it.
+    pub fn global_supply(&self) -> u64 {
+        self.current_supply()
+    }
+
+    /// Tokens that exist on this chain: the global supply minus whatever is
+    /// currently bridged out. Matches the Token-2022 mint's `supply`.
+    pub fn local_supply(&self) -> u64 {
+        self.global_supply().saturating_sub(self.bridged_out())
+    }
+
+    /// Tokens in a mint with `mint_supply` that the counters do not account
+    /// for, i.e. issued outside this program. The opposite drift is expected:
+    /// holders can burn their own tokens through Token-2022 directly.
+    pub fn unaccounted_supply(&self, mint_supply: u64) -> u64 {
+        mint_supply.saturating_sub(self.local_supply())
+    }
+
+    /// Move the mint or burn counter so `local_supply()` equals
+    /// `mint_supply`, e.g. after restoring counters from an older copy.
+    pub fn reconcile_supply(&mut self, mint_supply: u64) {
+        let local = self.local_supply();
+        if mint_supply > local {
+            self.total_minted = self.total_minted.saturating_add(mint_supply - local);
+        } else {
+            self.total_burned = self.total_burned.saturating_add(local - mint_supply);
+        }
+    }
+
+    /// Tokens currently held on other chains.
+    pub fn bridged_out(&self) -> u64 {
+        self.bridge_burned.saturating_sub(self.bridge_minted)
+    }
+
+    /// Whether the bridge may mint `amount` back in. It can only return
+    /// what it previously burned, so bridging never creates supply.
+    pub fn bridge_can_mint(&self, amount: u64) -> bool {
+        amount > 0 && amount <= self.bridged_out()
+    }
+
+    /// The pause flags in force at `now`: `pause_flags` until
+    /// `pause_expires_at`, none after it.
+    pub fn active_pause_flags(&self, now: i64) -> u8 {
+        match self.pause_expires_at {
+            Some(expires_at) if now >= expires_at => 0,
+            _ => self.pause_flags,
+        }
+    }
+
+    /// Whether any operation class is paused at `now`.
+    pub fn is_paused(&self, now: i64) -> bool {
+        self.active_pause_flags(now) != 0
+    }
+
+    /// Whether every operation class is paused at `now`, as after `pause`.
+    pub fn is_fully_paused(&self, now: i64) -> bool {
+        self.active_pause_flags(now) & pause::ALL == pause::ALL
+    }
+
+    /// Whether any class in `class` (see `constants::pause`) is paused at
+    /// `now`.
+    pub fn pauses(&self, class: u8, now: i64) -> bool {
+        self.active_pause_flags(now) & class != 0
+    }
+
+    /// When a pause starting or renewed at `now` lifts, or `None` when
+    /// pauses do not expire.
+    pub fn pause_deadline(&self, now: i64) -> Option<i64> {
+        (self.max_pause_secs > 0).then(|| now.saturating_add(i64::from(self.max_pause_secs)))
+    }
+
+    /// Replace `pause_flags` at `slot` and `now`. A pause starting from
+    /// nothing (or from an expired pause) gets a fresh `pause_expires_at`;
+    /// widening a running pause keeps its deadline. Pausing minting records
+    /// `paused_at_slot` and resuming it may start the thaw ramp. Returns
+    /// whether a ramp started.
+    pub fn set_pause_flags(&mut self, flags: u8, slot: u64, now: i64) -> bool {
+        let was_paused = self.is_paused(now);
+        let was_mint_paused = self.pauses(pause::MINT, now);
+        self.pause_flags = flags;
+        self.pause_expires_at = match (flags, was_paused) {
+            (0, _) => None,
+            (_, true) => self.pause_expires_at,
+            (_, false) => self.pause_deadline(now),
+        };
+        match (was_mint_paused, self.pauses(pause::MINT, now)) {
+            (false, true) => {
+                self.paused_at_slot = slot;
+                false
+            }
+            (true, false) => self.start_thaw_ramp(slot),
+            _ => false,
+        }
+    }
+
+    /// Start a thaw ramp if one is configured and the pause ending at `slot`
+    /// lasted at least `min_pause_slots`. Returns whether a ramp started.
+    pub fn start_thaw_ramp(&mut self, slot: u64) -> bool {
+        let Some(ramp) = self.thaw_ramp else {
+            return false;
+        };
+        if slot.saturating_sub(self.paused_at_slot) < ramp.min_pause_slots {
+            return false;
+        }
+        self.ramp_started_at = Some(slot);
+        self.ramp_base_supply = self.current_supply();
+        true
+    }
+
+    /// `cap` as lowered by a running thaw ramp at `slot`. The headroom above
+    /// `ramp_base_supply` opens from `start_bps` to all of it over
+    /// `ramp_slots`; outside a ramp `cap` is returned unchanged.
+    pub fn ramped_cap(&self, cap: u64, slot: u64) -> u64 {
+        let (Some(ramp), Some(started_at)) = (self.thaw_ramp, self.ramp_started_at) else {
+            return cap;
+        };
+        let elapsed = slot.saturating_sub(started_at);
+        if elapsed >= ramp.ramp_slots || cap <= self.ramp_base_supply {
+            return cap;
+        }
+        let start_bps = ramp.start_bps as u128;
+        let bps = start_bps + (10_000 - start_bps) * elapsed as u128 / ramp.ramp_slots as u128;
+        let headroom = (cap - self.ramp_base_supply) as u128 * bps / 10_000;
+        self.ramp_base_supply + headroom as u64
+    }
+
+    /// Record `amount` newly escrowed for redemption. Returns `None` on
+    /// overflow or if the reservation would exceed the supply held on this chain.
+    pub fn reserve_for_redemption(&mut self, amount: u64) -> Option<()> {
+        let reserved = self.reserved_for_redemption.checked_add(amount)?;
+        if reserved > self.local_supply() {
+            return None;
+        }
+        self.reserved_for_redemption = reserved;
+        self.refresh_digest();
+        Some(())
+    }
+
+    /// Release `amount` of the reservation once its request is settled or
+    /// cancelled. Returns `None` if more is released than is reserved.
+    pub fn release_redemption(&mut self, amount: u64) -> Option<()> {
+        self.reserved_for_redemption = self.reserved_for_redemption.checked_sub(amount)?;
+        self.refresh_digest();
+        Some(())
+    }
+
     /// Checks whether `amount` tokens can be minted without exceeding
     /// the supply cap or overflowing the total_minted counter.
     ///
@@ -93,15 +788,15 @@ impl StablecoinConfig {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
-    fn default_config() -> StablecoinConfig {
+    pub(crate) fn default_config() -> StablecoinConfig {
         StablecoinConfig {
             authority: Pubkey::default(),
             mint: Pubkey::default(),
             preset: 1,
-            paused: false,
+            pause_flags: 0,
             supply_cap: None,
             total_minted: 0,
             total_burned: 0,
@@ -115,9 +810,349 @@ mod tests {
             default_account_frozen: false,
             admin_count: 1,
             oracle_feed_id: None,
+            receipt_threshold: None,
+            receipt_retention_secs: 0,
+            max_confidence_bps: None,
+            program_version: crate::version::program_version(),
+            event_queue_enabled: false,
+            disabled_instructions: 0,
+            locked_instructions: 0,
+            reserved_for_redemption: 0,
+            state_digest: [0; 32],
+            state_nonce: 0,
+            bridge_minted: 0,
+            bridge_burned: 0,
+            thaw_ramp: None,
+            paused_at_slot: 0,
+            ramp_started_at: None,
+            ramp_base_supply: 0,
+            incident_flag: false,
+            incident_updated_at: 0,
+            incident_uri: String::new(),
+            destination_mint_limit: None,
+            halt_mint_on_supply_mismatch: false,
+            oracle_price_source: OraclePriceSource::Spot,
+            pause_on_authority_anomaly: false,
+            mint_index: 0,
+            pending_authority: None,
+            admin_timelock_secs: 0,
+            large_mint_threshold: None,
+            large_mint_approvals: 0,
+            unpause_requires_admin: false,
+            max_pause_secs: 0,
+            pause_expires_at: None,
+            large_seize_threshold: None,
+            seizure_count: 0,
+            treasury: None,
+            seize_requires_frozen: false,
+            wipe_count: 0,
+            version: StablecoinConfig::CURRENT_VERSION,
+            sunset_at: None,
+            oracle_kind: OracleKind::Pyth,
+            max_attestation_age_secs: None,
+            fee_treasury: None,
         }
     }
 
+    #[test]
+    fn test_updated_capabilities() {
+        let mut cfg = default_config();
+        assert!(cfg.is_enabled(capability::SEIZE));
+
+        let seize_blacklist = capability::SEIZE | capability::BLACKLIST;
+        assert_eq!(
+            cfg.updated_capabilities(seize_blacklist, capability::SEIZE),
+            Some((seize_blacklist, capability::SEIZE))
+        );
+        cfg.disabled_instructions = seize_blacklist;
+        cfg.locked_instructions = capability::SEIZE;
+        assert!(!cfg.is_enabled(capability::SEIZE));
+        assert!(cfg.is_enabled(capability::MINT));
+
+        // Unlocked bits can be re-enabled, locked ones cannot.
+        assert_eq!(
+            cfg.updated_capabilities(capability::SEIZE, 0),
+            Some((capability::SEIZE, capability::SEIZE))
+        );
+        assert_eq!(cfg.updated_capabilities(capability::BLACKLIST, 0), None);
+        // Locking requires disabling, and unknown bits are rejected.
+        assert_eq!(
+            cfg.updated_capabilities(capability::SEIZE, capability::MINT),
+            None
+        );
+        assert_eq!(
+            cfg.updated_capabilities(capability::SEIZE | 1 << 31, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_state_digest_chains_changes() {
+        let mut cfg = default_config();
+        cfg.refresh_digest();
+        let first = cfg.state_digest;
+        assert_eq!(cfg.state_nonce, 1);
+        assert_ne!(first, [0; 32]);
+
+        // The same change from the same state always gives the same digest.
+        let mut replay = default_config();
+        replay.refresh_digest();
+        assert_eq!(replay.state_digest, first);
+
+        // Any policy change, or merely another step, moves the digest.
+        let mut paused = cfg.clone();
+        paused.pause_flags = pause::ALL;
+        paused.refresh_digest();
+        cfg.refresh_digest();
+        assert_eq!(cfg.state_nonce, 2);
+        assert_ne!(cfg.state_digest, first);
+        assert_ne!(paused.state_digest, cfg.state_digest);
+    }
+
+    #[test]
+    fn test_incident_uri_space_is_reserved() {
+        let mut cfg = default_config();
+        let space = StablecoinConfig::compute_space(&cfg.name, &cfg.symbol, &cfg.uri);
+        cfg.incident_flag = true;
+        cfg.incident_uri = "x".repeat(StablecoinConfig::MAX_INCIDENT_URI_LEN);
+
+        // Space reserves every optional field's payload; fill them all.
+        cfg.supply_cap = Some(u64::MAX);
+        cfg.oracle_feed_id = Some([1; 32]);
+        cfg.receipt_threshold = Some(1);
+        cfg.max_confidence_bps = Some(1);
+        cfg.thaw_ramp = Some(ThawRamp {
+            min_pause_slots: 1,
+            ramp_slots: 1,
+            start_bps: 1,
+        });
+        cfg.ramp_started_at = Some(1);
+        cfg.destination_mint_limit = Some(1);
+        cfg.pending_authority = Some(Pubkey::new_unique());
+        cfg.large_mint_threshold = Some(1);
+        cfg.pause_expires_at = Some(1);
+        cfg.large_seize_threshold = Some(1);
+        cfg.treasury = Some(Pubkey::new_unique());
+        cfg.sunset_at = Some(1);
+        cfg.max_attestation_age_secs = Some(1);
+        cfg.fee_treasury = Some(Pubkey::new_unique());
+
+        let mut data = Vec::new();
+        cfg.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= space, "{} > {}", data.len(), space);
+    }
+
+    #[test]
+    fn test_bridge_accounting() {
+        let mut cfg = default_config();
+        cfg.supply_cap = Some(1_000);
+        cfg.total_minted = 1_000;
+        assert!(!cfg.bridge_can_mint(1));
+
+        // 400 bridged out, 150 of it back in.
+        cfg.bridge_burned = 400;
+        assert!(cfg.bridge_can_mint(400));
+        assert!(!cfg.bridge_can_mint(401));
+        cfg.bridge_minted = 150;
+        assert_eq!(cfg.bridged_out(), 250);
+        assert_eq!(cfg.local_supply(), 750);
+        assert_eq!(cfg.global_supply(), 1_000);
+
+        // The cap is still exhausted: bridging frees no issuer headroom.
+        assert!(!cfg.can_mint(1));
+    }
+
+    #[test]
+    fn test_authorities_intact() {
+        let mut cfg = default_config();
+        cfg.enable_permanent_delegate = true;
+        cfg.enable_transfer_hook = true;
+        let config_key = Pubkey::new_unique();
+        let intact = MintAuthorities {
+            mint_authority: Some(config_key),
+            freeze_authority: Some(config_key),
+            permanent_delegate: Some(config_key),
+            transfer_hook_program: Some(TRANSFER_HOOK_PROGRAM_ID),
+        };
+        assert!(cfg.authorities_intact(&config_key, &intact));
+
+        let attacker = Some(Pubkey::new_unique());
+        for hijacked in [
+            MintAuthorities {
+                mint_authority: attacker,
+                ..intact
+            },
+            MintAuthorities {
+                freeze_authority: None,
+                ..intact
+            },
+            MintAuthorities {
+                permanent_delegate: attacker,
+                ..intact
+            },
+            MintAuthorities {
+                transfer_hook_program: attacker,
+                ..intact
+            },
+        ] {
+            assert!(!cfg.authorities_intact(&config_key, &hijacked));
+        }
+
+        // SSS-1 without a hook: one appearing is an anomaly too.
+        cfg.enable_transfer_hook = false;
+        assert!(!cfg.authorities_intact(&config_key, &intact));
+        let no_hook = MintAuthorities {
+            transfer_hook_program: None,
+            ..intact
+        };
+        assert!(cfg.authorities_intact(&config_key, &no_hook));
+    }
+
+    #[test]
+    fn test_unaccounted_supply() {
+        let mut cfg = default_config();
+        cfg.total_minted = 1_000;
+        cfg.bridge_burned = 300;
+        assert_eq!(cfg.unaccounted_supply(700), 0);
+        // A holder burned 50 directly: expected, not flagged.
+        assert_eq!(cfg.unaccounted_supply(650), 0);
+        assert_eq!(cfg.unaccounted_supply(725), 25);
+    }
+
+    #[test]
+    fn test_reconcile_supply() {
+        let mut cfg = default_config();
+        cfg.total_minted = 1_000;
+        cfg.total_burned = 100;
+        cfg.bridge_burned = 300;
+        cfg.reconcile_supply(800);
+        assert_eq!((cfg.total_minted, cfg.total_burned), (1_200, 100));
+        assert_eq!(cfg.local_supply(), 800);
+        cfg.reconcile_supply(500);
+        assert_eq!((cfg.total_minted, cfg.total_burned), (1_200, 400));
+        assert_eq!(cfg.local_supply(), 500);
+    }
+
+    #[test]
+    fn test_thaw_ramp() {
+        let mut cfg = default_config();
+        cfg.total_minted = 1_000;
+        cfg.thaw_ramp = Some(ThawRamp {
+            min_pause_slots: 100,
+            ramp_slots: 1_000,
+            start_bps: 2_000,
+        });
+
+        // Short pauses do not start a ramp.
+        cfg.paused_at_slot = 50;
+        assert!(!cfg.start_thaw_ramp(149));
+        assert_eq!(cfg.ramped_cap(2_000, 149), 2_000);
+
+        assert!(cfg.start_thaw_ramp(150));
+        assert_eq!(cfg.ramp_base_supply, 1_000);
+        assert_eq!(cfg.ramped_cap(2_000, 150), 1_200);
+        assert_eq!(cfg.ramped_cap(2_000, 650), 1_600);
+        assert_eq!(cfg.ramped_cap(2_000, 1_150), 2_000);
+        // A cap already at or below the base supply is left alone.
+        assert_eq!(cfg.ramped_cap(900, 150), 900);
+
+        cfg.thaw_ramp = None;
+        assert_eq!(cfg.ramped_cap(2_000, 150), 2_000);
+    }
+
+    #[test]
+    fn test_pause_flags() {
+        let mut cfg = default_config();
+        cfg.thaw_ramp = Some(ThawRamp {
+            min_pause_slots: 100,
+            ramp_slots: 1_000,
+            start_bps: 2_000,
+        });
+        assert!(!cfg.is_paused(0));
+
+        // Halting minting alone leaves burns and transfers running.
+        assert!(!cfg.set_pause_flags(pause::MINT, 10, 0));
+        assert!(cfg.pauses(pause::MINT, 0));
+        assert!(!cfg.pauses(pause::BURN | pause::TRANSFERS, 0));
+        assert!(cfg.is_paused(0) && !cfg.is_fully_paused(0));
+        assert_eq!(cfg.paused_at_slot, 10);
+
+        // Widening the pause keeps the slot minting stopped at.
+        assert!(!cfg.set_pause_flags(pause::ALL, 50, 0));
+        assert!(cfg.is_fully_paused(0));
+        assert_eq!(cfg.paused_at_slot, 10);
+
+        // The ramp starts when minting resumes, not when other classes do.
+        assert!(!cfg.set_pause_flags(pause::MINT, 60, 0));
+        assert!(cfg.ramp_started_at.is_none());
+        assert!(cfg.set_pause_flags(0, 110, 0));
+        assert_eq!(cfg.ramp_started_at, Some(110));
+    }
+
+    #[test]
+    fn test_pause_expiry() {
+        let mut cfg = default_config();
+        cfg.set_pause_flags(pause::ALL, 1, 1_000);
+        assert_eq!(cfg.pause_expires_at, None);
+        assert!(cfg.is_fully_paused(i64::MAX));
+        cfg.set_pause_flags(0, 2, 1_000);
+
+        cfg.max_pause_secs = 3_600;
+        cfg.set_pause_flags(pause::MINT, 3, 1_000);
+        assert_eq!(cfg.pause_expires_at, Some(4_600));
+
+        // Widening a running pause keeps its deadline.
+        cfg.set_pause_flags(pause::ALL, 4, 2_000);
+        assert_eq!(cfg.pause_expires_at, Some(4_600));
+        assert!(cfg.is_fully_paused(4_599));
+
+        // At the deadline the pause lifts on its own.
+        assert!(!cfg.is_paused(4_600));
+        assert!(!cfg.pauses(pause::MINT, 4_600));
+
+        // Pausing again after expiry starts a fresh window.
+        cfg.set_pause_flags(pause::BURN, 5, 5_000);
+        assert_eq!(cfg.pause_expires_at, Some(8_600));
+        assert!(!cfg.pauses(pause::MINT, 5_000));
+        assert!(cfg.pauses(pause::BURN, 5_000));
+    }
+
+    #[test]
+    fn test_redemption_reservation() {
+        let mut cfg = default_config();
+        cfg.supply_cap = Some(1_000);
+        cfg.total_minted = 1_000;
+        assert_eq!(cfg.reserve_for_redemption(300), Some(()));
+        assert_eq!(cfg.circulating_supply(), 700);
+        // Escrowed tokens keep their cap headroom.
+        assert_eq!(cfg.current_supply(), 1_000);
+        assert!(!cfg.can_mint(1));
+        assert_eq!(cfg.reserve_for_redemption(701), None);
+
+        // Settling burns the escrow and releases the reservation.
+        cfg.total_burned = 300;
+        assert_eq!(cfg.release_redemption(300), Some(()));
+        assert_eq!(cfg.circulating_supply(), 700);
+        assert_eq!(cfg.release_redemption(1), None);
+        assert!(cfg.can_mint(300));
+    }
+
+    #[test]
+    fn test_view_derives_supply() {
+        let mut cfg = default_config();
+        cfg.total_minted = 1_000;
+        cfg.total_burned = 100;
+        cfg.reserved_for_redemption = 50;
+        cfg.bridge_burned = 200;
+
+        let view = cfg.view();
+        assert_eq!(view.layout_version, crate::views::VIEW_LAYOUT_VERSION);
+        assert_eq!(view.current_supply, 900);
+        assert_eq!(view.circulating_supply, 850);
+        assert_eq!(view.local_supply, 700);
+        assert_eq!(view.name, cfg.name);
+    }
+
     #[test]
     fn test_current_supply() {
         let mut cfg = default_config();
@@ -180,4 +1215,111 @@ mod tests {
         // At cap, zero is still rejected.
         assert!(!cfg.can_mint(0));
     }
+
+    #[test]
+    fn test_requires_mint_approval() {
+        let mut cfg = default_config();
+        assert!(!cfg.requires_mint_approval(u64::MAX));
+
+        cfg.large_mint_threshold = Some(1_000);
+        assert!(!cfg.requires_mint_approval(1_000));
+        assert!(cfg.requires_mint_approval(1_001));
+    }
+
+    #[test]
+    fn test_requires_seize_approval() {
+        let mut cfg = default_config();
+        assert!(!cfg.requires_seize_approval(u64::MAX));
+
+        cfg.large_seize_threshold = Some(1_000);
+        assert!(!cfg.requires_seize_approval(1_000));
+        assert!(cfg.requires_seize_approval(1_001));
+    }
+
+    #[test]
+    fn test_next_seizure_index() {
+        let mut cfg = default_config();
+        let digest = cfg.state_digest;
+        assert_eq!(cfg.next_seizure_index(), Some(0));
+        assert_eq!(cfg.next_seizure_index(), Some(1));
+        assert_eq!(cfg.seizure_count, 2);
+        assert_ne!(cfg.state_digest, digest);
+
+        cfg.seizure_count = u64::MAX;
+        assert_eq!(cfg.next_seizure_index(), None);
+    }
+
+    #[test]
+    fn test_next_wipe_index() {
+        let mut cfg = default_config();
+        let digest = cfg.state_digest;
+        assert_eq!(cfg.next_wipe_index(), Some(0));
+        assert_eq!(cfg.wipe_count, 1);
+        assert_eq!(cfg.seizure_count, 0);
+        assert_ne!(cfg.state_digest, digest);
+
+        cfg.wipe_count = u64::MAX;
+        assert_eq!(cfg.next_wipe_index(), None);
+    }
+
+    #[test]
+    fn test_supported_version() {
+        let mut cfg = default_config();
+        assert!(cfg.is_supported_version());
+
+        cfg.version = 0;
+        assert!(!cfg.is_supported_version());
+
+        cfg.version = StablecoinConfig::CURRENT_VERSION;
+        cfg.program_version[0] = cfg.program_version[0].wrapping_add(1);
+        assert!(!cfg.is_supported_version());
+    }
+
+    #[test]
+    fn test_reset_fields_after() {
+        let mut cfg = default_config();
+        cfg.sunset_at = Some(7);
+        cfg.oracle_kind = OracleKind::Switchboard;
+        cfg.max_attestation_age_secs = Some(86_400);
+        cfg.fee_treasury = Some(Pubkey::new_unique());
+        cfg.reset_fields_after(5);
+        assert!(cfg.fee_treasury.is_some());
+        cfg.reset_fields_after(4);
+        assert_eq!(cfg.fee_treasury, None);
+        assert_eq!(cfg.max_attestation_age_secs, Some(86_400));
+        cfg.reset_fields_after(3);
+        assert_eq!(cfg.max_attestation_age_secs, None);
+        assert_eq!(cfg.oracle_kind, OracleKind::Switchboard);
+        cfg.reset_fields_after(2);
+        assert_eq!(cfg.sunset_at, Some(7));
+        assert_eq!(cfg.oracle_kind, OracleKind::Pyth);
+        cfg.reset_fields_after(1);
+        assert_eq!(cfg.sunset_at, None);
+    }
+
+    #[test]
+    fn test_stored_version_ignores_slack_before_the_field() {
+        let mut cfg = default_config();
+        cfg.version = 4;
+        let space = StablecoinConfig::compute_space(&cfg.name, &cfg.symbol, &cfg.uri);
+        assert_eq!(cfg.stored_version(space), 4);
+
+        // Sized for version 1: `version` but none of the later fields.
+        let v1_len = space - (9 + 1 + 5 + 33);
+        assert_eq!(cfg.stored_version(v1_len), 4);
+        // One byte short: the config predates `version`.
+        assert_eq!(cfg.stored_version(v1_len - 1), 0);
+
+        cfg.version = u8::MAX;
+        assert_eq!(cfg.stored_version(space), StablecoinConfig::CURRENT_VERSION);
+    }
+
+    #[test]
+    fn test_unpause_role() {
+        let mut cfg = default_config();
+        assert_eq!(cfg.unpause_role(), Role::Pauser);
+
+        cfg.unpause_requires_admin = true;
+        assert_eq!(cfg.unpause_role(), Role::Admin);
+    }
 }
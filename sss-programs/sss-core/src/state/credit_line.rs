@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+/// An interest-free intraday credit line for an approved market maker. The
+/// market maker mints up to `limit` with `draw_credit` and must burn the same
+/// amount back with `repay_credit` by `repay_cutoff` (seconds after UTC
+/// midnight) of the day it first drew. Once that deadline passes with a
+/// balance outstanding, anyone can freeze the market maker's token accounts
+/// with `freeze_defaulted_credit`.
+#[account]
+pub struct CreditLine {
+    pub config: Pubkey,
+    /// The wallet allowed to draw; it signs draws and repayments.
+    pub market_maker: Pubkey,
+    /// Maximum amount outstanding at any time.
+    pub limit: u64,
+    /// Drawn and not yet repaid.
+    pub outstanding: u64,
+    /// Seconds after UTC midnight by which each day's draws must be repaid.
+    pub repay_cutoff: u32,
+    /// When `outstanding` is due; zero while nothing is outstanding.
+    pub repay_by: i64,
+    /// Set by the first `freeze_defaulted_credit`. Blocks further draws until
+    /// an Admin closes the line.
+    pub defaulted: bool,
+    /// The Admin that last set the limit.
+    pub set_by: Pubkey,
+    pub bump: u8,
+}
+
+impl CreditLine {
+    pub const SSS_CREDIT_LINE_SEED: &'static [u8] = b"sss-credit-line";
+
+    pub const DAY_SECS: i64 = 86_400;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // market_maker
+        8 +  // limit
+        8 +  // outstanding
+        4 +  // repay_cutoff
+        8 +  // repay_by
+        1 +  // defaulted
+        32 + // set_by
+        1; // bump
+
+    /// Whether `repay_cutoff` is a time of day: after midnight, up to and
+    /// including the next one.
+    pub fn cutoff_is_valid(repay_cutoff: u32) -> bool {
+        repay_cutoff > 0 && i64::from(repay_cutoff) <= Self::DAY_SECS
+    }
+
+    /// The deadline a draw at `now` must be repaid by: the current one while
+    /// a balance is outstanding, otherwise today's cutoff. `None` once it has
+    /// passed, when no more can be drawn.
+    pub fn draw_deadline(&self, now: i64) -> Option<i64> {
+        let deadline = if self.outstanding > 0 {
+            self.repay_by
+        } else {
+            now - now.rem_euclid(Self::DAY_SECS) + i64::from(self.repay_cutoff)
+        };
+        (now < deadline).then_some(deadline)
+    }
+
+    /// Whether the market maker has let a balance run past its deadline.
+    pub fn is_past_due(&self, now: i64) -> bool {
+        self.outstanding > 0 && now >= self.repay_by
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(outstanding: u64, repay_by: i64) -> CreditLine {
+        CreditLine {
+            config: Pubkey::default(),
+            market_maker: Pubkey::default(),
+            limit: 1_000,
+            outstanding,
+            repay_cutoff: 22 * 3_600,
+            repay_by,
+            defaulted: false,
+            set_by: Pubkey::default(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_draw_deadline_is_same_day_cutoff() {
+        let day = 19_000 * CreditLine::DAY_SECS;
+        let cutoff = day + 22 * 3_600;
+        assert_eq!(line(0, 0).draw_deadline(day + 9 * 3_600), Some(cutoff));
+        assert_eq!(line(0, 0).draw_deadline(cutoff), None);
+        // Later draws keep the deadline of the first.
+        assert_eq!(
+            line(500, cutoff).draw_deadline(day + 21 * 3_600),
+            Some(cutoff)
+        );
+        assert_eq!(
+            line(500, cutoff).draw_deadline(day + CreditLine::DAY_SECS + 60),
+            None
+        );
+    }
+
+    #[test]
+    fn test_past_due() {
+        let cutoff = 19_000 * CreditLine::DAY_SECS + 22 * 3_600;
+        assert!(!line(500, cutoff).is_past_due(cutoff - 1));
+        assert!(line(500, cutoff).is_past_due(cutoff));
+        assert!(!line(0, 0).is_past_due(cutoff));
+        assert!(CreditLine::cutoff_is_valid(86_400));
+        assert!(!CreditLine::cutoff_is_valid(0));
+        assert!(!CreditLine::cutoff_is_valid(86_401));
+    }
+}
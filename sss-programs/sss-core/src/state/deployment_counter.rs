@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide counter that hands out `StablecoinConfig::mint_index`.
+/// Created once, by anyone, with `initialize_deployment_counter`; every
+/// `initialize` that passes it takes the next index.
+#[account]
+pub struct DeploymentCounter {
+    /// Indexes assigned so far, which is also the last one assigned.
+    pub count: u64,
+    pub bump: u8,
+}
+
+impl DeploymentCounter {
+    pub const SSS_DEPLOYMENT_COUNTER_SEED: &'static [u8] = b"sss-deployment-counter";
+
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // count
+        1; // bump
+
+    /// Assign the next index. Indexes start at 1, so zero can mark a config
+    /// that never received one.
+    pub fn next_index(&mut self) -> Option<u64> {
+        self.count = self.count.checked_add(1)?;
+        Some(self.count)
+    }
+}
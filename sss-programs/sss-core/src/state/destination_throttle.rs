@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+/// Minter issuance into one token account over a rolling day. While
+/// `StablecoinConfig::destination_mint_limit` is set, every minter path
+/// (`mint_tokens` and its receipt, idempotent, batch, sub-minter and
+/// hot-key variants) needs the destination's throttle and fails once the account
+/// has received the limit within `WINDOW_SECS`, whichever minters sent it.
+#[account]
+pub struct DestinationThrottle {
+    pub config: Pubkey,
+    /// Token account this throttle tracks.
+    pub token_account: Pubkey,
+    /// Start of the current window, set by the first mint into it.
+    pub window_start: i64,
+    /// Amount minted into `token_account` since `window_start`.
+    pub window_minted: u64,
+    pub bump: u8,
+}
+
+impl DestinationThrottle {
+    pub const SSS_DESTINATION_THROTTLE_SEED: &'static [u8] = b"sss-dest-throttle";
+    pub const WINDOW_SECS: i64 = 86_400;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // token_account
+        8 +  // window_start
+        8 +  // window_minted
+        1; // bump
+
+    /// Whether a mint at `now` opens a new window: the current one has
+    /// elapsed, or nothing has been minted through the throttle yet.
+    fn window_elapsed(&self, now: i64) -> bool {
+        self.window_minted == 0 || now >= self.window_start.saturating_add(Self::WINDOW_SECS)
+    }
+
+    /// Amount minted into the account in the window containing `now`.
+    pub fn window_minted_at(&self, now: i64) -> u64 {
+        if self.window_elapsed(now) {
+            0
+        } else {
+            self.window_minted
+        }
+    }
+
+    /// Whether `amount` more fits under `limit` at `now`. Returns `None` on
+    /// overflow.
+    pub fn allows(&self, amount: u64, limit: u64, now: i64) -> Option<bool> {
+        let new_total = self.window_minted_at(now).checked_add(amount)?;
+        Some(new_total <= limit)
+    }
+
+    /// Record a mint of `amount` at `now`, starting a new window if the
+    /// current one has elapsed.
+    pub fn record(&mut self, amount: u64, now: i64) -> Option<()> {
+        if self.window_elapsed(now) {
+            self.window_start = now;
+            self.window_minted = 0;
+        }
+        self.window_minted = self.window_minted.checked_add(amount)?;
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_caps_any_sender() {
+        let mut throttle = DestinationThrottle {
+            config: Pubkey::default(),
+            token_account: Pubkey::default(),
+            window_start: 0,
+            window_minted: 0,
+            bump: 0,
+        };
+        throttle.record(600, 10).unwrap();
+        throttle.record(400, 20).unwrap();
+        assert_eq!(throttle.allows(1, 1_000, 30), Some(false));
+
+        let next_window = 10 + DestinationThrottle::WINDOW_SECS;
+        assert_eq!(throttle.allows(1, 1_000, next_window - 1), Some(false));
+        assert_eq!(throttle.allows(1_000, 1_000, next_window), Some(true));
+        throttle.record(1, next_window).unwrap();
+        assert_eq!(throttle.window_start, next_window);
+        assert_eq!(throttle.window_minted, 1);
+        assert_eq!(throttle.allows(u64::MAX, u64::MAX, next_window), None);
+    }
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use super::Role;
+
+/// Per-config, per-role list of current role holders.
+///
+/// Kept in sync by `grant_role`, `revoke_role` and `accept_authority` so that
+/// clients can answer "who can mint right now" with a single account fetch
+/// instead of a `getProgramAccounts` scan over every `RoleAccount`.
+#[account]
+pub struct RoleDirectory {
+    pub config: Pubkey,
+    pub role: Role,
+    pub bump: u8,
+    /// Current holders, in grant order. Bounded by `MAX_HOLDERS`.
+    pub holders: Vec<Pubkey>,
+}
+
+impl RoleDirectory {
+    pub const SSS_ROLE_DIRECTORY_SEED: &'static [u8] = b"sss-role-dir";
+
+    /// Maximum holders tracked per role. Granting beyond this fails with
+    /// `RoleDirectoryFull`; revoke an unused holder first.
+    pub const MAX_HOLDERS: usize = 32;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        1 +  // role
+        1 +  // bump
+        4 + 32 * Self::MAX_HOLDERS; // Vec<Pubkey> holders
+
+    /// Record `address` as a holder. Returns `false` when the directory is
+    /// full. Adding an existing holder is a no-op.
+    pub fn add(&mut self, address: Pubkey) -> bool {
+        if self.holders.contains(&address) {
+            return true;
+        }
+        if self.holders.len() >= Self::MAX_HOLDERS {
+            return false;
+        }
+        self.holders.push(address);
+        true
+    }
+
+    /// Drop `address` from the holders. Removing an absent holder is a no-op.
+    pub fn remove(&mut self, address: &Pubkey) {
+        self.holders.retain(|holder| holder != address);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_directory() -> RoleDirectory {
+        RoleDirectory {
+            config: Pubkey::default(),
+            role: Role::Minter,
+            bump: 0,
+            holders: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_add_is_idempotent() {
+        let mut dir = empty_directory();
+        let holder = Pubkey::new_unique();
+        assert!(dir.add(holder));
+        assert!(dir.add(holder));
+        assert_eq!(dir.holders, vec![holder]);
+    }
+
+    #[test]
+    fn test_add_rejects_when_full() {
+        let mut dir = empty_directory();
+        for _ in 0..RoleDirectory::MAX_HOLDERS {
+            assert!(dir.add(Pubkey::new_unique()));
+        }
+        assert!(!dir.add(Pubkey::new_unique()));
+
+        // Existing holders are still accepted as a no-op.
+        let existing = dir.holders[0];
+        assert!(dir.add(existing));
+    }
+
+    #[test]
+    fn test_remove_preserves_order() {
+        let mut dir = empty_directory();
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        dir.add(a);
+        dir.add(b);
+        dir.add(c);
+        dir.remove(&b);
+        dir.remove(&Pubkey::new_unique());
+        assert_eq!(dir.holders, vec![a, c]);
+    }
+
+    #[test]
+    fn test_space_fits_full_directory() {
+        let mut dir = empty_directory();
+        for _ in 0..RoleDirectory::MAX_HOLDERS {
+            dir.add(Pubkey::new_unique());
+        }
+        let mut serialized = Vec::new();
+        dir.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), RoleDirectory::SPACE);
+    }
+}
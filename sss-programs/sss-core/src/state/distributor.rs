@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+/// Where claimed tokens come from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistributionFunding {
+    /// Newly minted by the config PDA; counts toward `total_minted` and the
+    /// supply cap.
+    Mint,
+    /// Moved out of an issuer treasury token account by the config PDA as
+    /// permanent delegate.
+    Treasury { token_account: Pubkey },
+}
+
+/// A merkle-root distribution (e.g. incident remediation). Each leaf is
+/// `(index, claimant, amount)`; see [`crate::merkle`].
+#[account]
+pub struct Distributor {
+    pub config: Pubkey,
+    /// Issuer-chosen identifier, also a PDA seed.
+    pub id: u64,
+    pub merkle_root: [u8; 32],
+    pub funding: DistributionFunding,
+    /// Upper bound on the sum of all claims.
+    pub max_total: u64,
+    pub total_claimed: u64,
+    pub num_claimed: u64,
+    pub created_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Distributor {
+    pub const SSS_DISTRIBUTOR_SEED: &'static [u8] = b"sss-distributor";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        8 +  // id
+        32 + // merkle_root
+        33 + // funding (1 + 32)
+        8 +  // max_total
+        8 +  // total_claimed
+        8 +  // num_claimed
+        32 + // created_by
+        8 +  // created_at
+        1; // bump
+}
+
+/// Marks leaf `index` of a distributor as claimed.
+#[account]
+pub struct ClaimStatus {
+    pub distributor: Pubkey,
+    pub index: u64,
+    pub claimant: Pubkey,
+    pub amount: u64,
+    pub claimed_at: i64,
+    pub bump: u8,
+}
+
+impl ClaimStatus {
+    pub const SSS_CLAIM_SEED: &'static [u8] = b"sss-claim";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // distributor
+        8 +  // index
+        32 + // claimant
+        8 +  // amount
+        8 +  // claimed_at
+        1; // bump
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+/// Number of entries kept in an `EventRing` before the oldest is overwritten.
+pub const EVENT_RING_CAPACITY: usize = 16;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QueuedEventKind {
+    /// Marks a slot that has never been written.
+    #[default]
+    Empty,
+    Seize,
+    Pause,
+    Unpause,
+    BlacklistAdd,
+    Wipe,
+}
+
+/// One entry in an `EventRing`. `subject` is the seized or wiped token
+/// account or blacklisted wallet; for pause and unpause it is the mint, and
+/// entries from `set_pause_flags` carry the new flags as `amount`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QueuedEvent {
+    /// 1-based sequence number; `0` marks an empty slot.
+    pub seq: u64,
+    pub kind: QueuedEventKind,
+    pub subject: Pubkey,
+    pub actor: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+impl QueuedEvent {
+    pub const SIZE: usize = 8 + // seq
+        1 +  // kind
+        32 + // subject
+        32 + // actor
+        8 +  // amount
+        8; // slot
+}
+
+/// Fixed-size ring buffer of critical events, embedded in the sss-core
+/// `EventQueue` and the transfer hook's `HookEventQueue`.
+///
+/// Consumers subscribe to the account and read every entry whose `seq` is
+/// above the last one they processed. If `seq - last_seen` exceeds the
+/// capacity, entries were overwritten and the consumer must fall back to
+/// transaction logs for the gap.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EventRing {
+    /// Total number of events ever pushed; also the last assigned `seq`.
+    pub head: u64,
+    pub events: [QueuedEvent; EVENT_RING_CAPACITY],
+}
+
+impl EventRing {
+    pub const SIZE: usize = 8 + QueuedEvent::SIZE * EVENT_RING_CAPACITY;
+
+    /// Append an event, overwriting the oldest once the ring is full.
+    /// Returns the assigned sequence number.
+    pub fn push(
+        &mut self,
+        kind: QueuedEventKind,
+        subject: Pubkey,
+        actor: Pubkey,
+        amount: u64,
+        slot: u64,
+    ) -> u64 {
+        let index = (self.head % EVENT_RING_CAPACITY as u64) as usize;
+        self.head = self.head.wrapping_add(1);
+        self.events[index] = QueuedEvent {
+            seq: self.head,
+            kind,
+            subject,
+            actor,
+            amount,
+            slot,
+        };
+        self.head
+    }
+}
+
+/// Per-config ring buffer for seize, pause and unpause. Only written while
+/// `StablecoinConfig::event_queue_enabled` is set.
+#[account]
+pub struct EventQueue {
+    pub config: Pubkey,
+    pub ring: EventRing,
+    pub bump: u8,
+}
+
+impl EventQueue {
+    pub const SSS_EVENT_QUEUE_SEED: &'static [u8] = b"sss-event-queue";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        EventRing::SIZE + // ring
+        1; // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_overwrites_oldest() {
+        let mut ring = EventRing::default();
+        for i in 0..EVENT_RING_CAPACITY as u64 + 3 {
+            let seq = ring.push(
+                QueuedEventKind::Pause,
+                Pubkey::default(),
+                Pubkey::default(),
+                i,
+                i,
+            );
+            assert_eq!(seq, i + 1);
+        }
+        assert_eq!(ring.head, EVENT_RING_CAPACITY as u64 + 3);
+        // Slots 0..3 hold the three newest events, the rest the oldest survivors.
+        assert_eq!(ring.events[0].seq, EVENT_RING_CAPACITY as u64 + 1);
+        assert_eq!(ring.events[2].seq, EVENT_RING_CAPACITY as u64 + 3);
+        assert_eq!(ring.events[3].seq, 4);
+        assert!(ring.events.iter().all(|e| e.kind == QueuedEventKind::Pause));
+    }
+
+    #[test]
+    fn test_event_size_matches_borsh() {
+        let mut buf = Vec::new();
+        QueuedEvent::default().serialize(&mut buf).unwrap();
+        assert_eq!(buf.len(), QueuedEvent::SIZE);
+
+        buf.clear();
+        EventRing::default().serialize(&mut buf).unwrap();
+        assert_eq!(buf.len(), EventRing::SIZE);
+    }
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+
+/// Fee rates by counterparty tier, set once per config by an Admin. A
+/// wallet's tier comes from its `FeeTierAssignment`; wallets without one
+/// pay tier 0. Issuers typically keep tier 0 for retail, add cheaper tiers
+/// for institutions and an all-zero tier for exempt wallets. Fee-bearing
+/// paths read their rate from here rather than keeping their own bps fields.
+#[account]
+pub struct FeeSchedule {
+    pub config: Pubkey,
+    /// Rates indexed by tier (at most `MAX_TIERS`, at least one).
+    pub tiers: Vec<FeeRates>,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// One tier's rates, in basis points of the amount moved.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeRates {
+    pub mint_bps: u16,
+    pub burn_bps: u16,
+    /// `par_swap` and other PSM-style conversions.
+    pub swap_bps: u16,
+    /// Transfers screened by the transfer hook.
+    pub transfer_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeKind {
+    Mint,
+    Burn,
+    Swap,
+    Transfer,
+}
+
+/// Places `wallet` in a tier of the config's `FeeSchedule`.
+#[account]
+pub struct FeeTierAssignment {
+    pub config: Pubkey,
+    pub wallet: Pubkey,
+    pub tier: u8,
+    pub assigned_by: Pubkey,
+    pub assigned_at: i64,
+    pub bump: u8,
+}
+
+impl FeeRates {
+    pub fn is_valid(&self) -> bool {
+        [
+            self.mint_bps,
+            self.burn_bps,
+            self.swap_bps,
+            self.transfer_bps,
+        ]
+        .iter()
+        .all(|bps| *bps <= 10_000)
+    }
+
+    pub fn bps(&self, kind: FeeKind) -> u16 {
+        match kind {
+            FeeKind::Mint => self.mint_bps,
+            FeeKind::Burn => self.burn_bps,
+            FeeKind::Swap => self.swap_bps,
+            FeeKind::Transfer => self.transfer_bps,
+        }
+    }
+}
+
+impl FeeSchedule {
+    pub const SSS_FEE_SCHEDULE_SEED: &'static [u8] = b"sss-fee-schedule";
+    pub const MAX_TIERS: usize = 8;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        4 + 8 * Self::MAX_TIERS + // tiers
+        32 + // updated_by
+        8 +  // updated_at
+        1; // bump
+
+    /// Rates for a wallet with `assignment`. A tier that no longer exists
+    /// (the schedule was shortened) falls back to tier 0.
+    pub fn rates_for(&self, assignment: Option<&FeeTierAssignment>) -> FeeRates {
+        let tier = assignment.map_or(0, |a| a.tier as usize);
+        self.tiers
+            .get(tier)
+            .or_else(|| self.tiers.first())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The fee on `amount` for a wallet with `assignment`, rounded down.
+    /// Returns `None` on overflow.
+    pub fn fee(
+        &self,
+        assignment: Option<&FeeTierAssignment>,
+        kind: FeeKind,
+        amount: u64,
+    ) -> Option<u64> {
+        let bps = self.rates_for(assignment).bps(kind) as u128;
+        u64::try_from((amount as u128).checked_mul(bps)? / 10_000).ok()
+    }
+}
+
+impl FeeTierAssignment {
+    pub const SSS_FEE_TIER_SEED: &'static [u8] = b"sss-fee-tier";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // wallet
+        1 +  // tier
+        32 + // assigned_by
+        8 +  // assigned_at
+        1; // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assignment(tier: u8) -> FeeTierAssignment {
+        FeeTierAssignment {
+            config: Pubkey::default(),
+            wallet: Pubkey::default(),
+            tier,
+            assigned_by: Pubkey::default(),
+            assigned_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_fee_by_tier() {
+        let retail = FeeRates {
+            mint_bps: 30,
+            burn_bps: 30,
+            swap_bps: 10,
+            transfer_bps: 0,
+        };
+        let institution = FeeRates {
+            mint_bps: 5,
+            ..retail
+        };
+        let schedule = FeeSchedule {
+            config: Pubkey::default(),
+            tiers: vec![retail, institution, FeeRates::default()],
+            updated_by: Pubkey::default(),
+            updated_at: 0,
+            bump: 0,
+        };
+
+        assert_eq!(schedule.fee(None, FeeKind::Mint, 1_000_000), Some(3_000));
+        assert_eq!(
+            schedule.fee(Some(&assignment(1)), FeeKind::Mint, 1_000_000),
+            Some(500)
+        );
+        assert_eq!(
+            schedule.fee(Some(&assignment(2)), FeeKind::Burn, 999),
+            Some(0)
+        );
+        // A dropped tier falls back to tier 0.
+        assert_eq!(schedule.rates_for(Some(&assignment(7))), retail);
+        assert_eq!(
+            schedule.fee(None, FeeKind::Swap, u64::MAX),
+            Some(u64::MAX / 1_000)
+        );
+
+        assert!(retail.is_valid());
+        assert!(!FeeRates {
+            burn_bps: 10_001,
+            ..retail
+        }
+        .is_valid());
+    }
+}
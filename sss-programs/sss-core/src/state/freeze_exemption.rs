@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Marks a wallet (treasury, market maker) as exempt from default-frozen
+/// onboarding and bulk freeze actions. Its token accounts can be thawed by
+/// anyone with `thaw_exempt_account`. The exemption never applies to the
+/// transfer hook's blacklist, and a Freezer can still freeze an exempt
+/// account individually with `freeze_account`.
+#[account]
+pub struct FreezeExemption {
+    pub config: Pubkey,
+    /// Owner wallet whose token accounts are exempt.
+    pub owner: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+impl FreezeExemption {
+    pub const SSS_FREEZE_EXEMPTION_SEED: &'static [u8] = b"sss-freeze-exempt";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // owner
+        32 + // added_by
+        8 +  // added_at
+        1; // bump
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+/// Records that `owner` passed the issuer's KYC checks. On SSS-2 configs,
+/// minting into a token account requires an entry for the account's owner.
+#[account]
+pub struct KycEntry {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub approved_by: Pubkey,
+    pub approved_at: i64,
+    /// Unix timestamp after which the approval lapses. `None` never expires.
+    pub expires_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl KycEntry {
+    pub const SSS_KYC_SEED: &'static [u8] = b"sss-kyc";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // owner
+        32 + // approved_by
+        8 +  // approved_at
+        9 +  // Option<i64> expires_at (1 + 8)
+        1; // bump
+
+    /// Whether this entry approves `owner` on `config` at time `now`.
+    pub fn approves(&self, config: &Pubkey, owner: &Pubkey, now: i64) -> bool {
+        self.config == *config
+            && self.owner == *owner
+            && self.expires_at.is_none_or(|expiry| now < expiry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approves() {
+        let config = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut entry = KycEntry {
+            config,
+            owner,
+            approved_by: Pubkey::default(),
+            approved_at: 0,
+            expires_at: None,
+            bump: 0,
+        };
+        assert!(entry.approves(&config, &owner, i64::MAX));
+        assert!(!entry.approves(&config, &Pubkey::new_unique(), 0));
+        assert!(!entry.approves(&Pubkey::new_unique(), &owner, 0));
+
+        entry.expires_at = Some(100);
+        assert!(entry.approves(&config, &owner, 99));
+        assert!(!entry.approves(&config, &owner, 100));
+    }
+}
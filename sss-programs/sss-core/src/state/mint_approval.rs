@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+/// A minter's request to mint more than `config.large_mint_threshold`.
+/// Other Minter or Admin holders co-sign it with `approve_mint`; once it has
+/// `config.large_mint_approvals` approvals, the minter passes it to
+/// `mint_tokens` (or the receipt and idempotent variants) for exactly this
+/// destination and amount, which closes it.
+#[account]
+pub struct MintApproval {
+    pub config: Pubkey,
+    /// The minter that requested it and alone may use it.
+    pub minter: Pubkey,
+    /// Chosen by the minter; part of the seeds.
+    pub id: u64,
+    /// Destination token account.
+    pub to: Pubkey,
+    pub amount: u64,
+    /// Distinct co-signers so far; never includes `minter`.
+    pub approvers: Vec<Pubkey>,
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+impl MintApproval {
+    pub const SSS_MINT_APPROVAL_SEED: &'static [u8] = b"sss-mint-approval";
+
+    /// Most approvals a config can require, and a request can hold.
+    pub const MAX_APPROVERS: usize = 8;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // minter
+        8 +  // id
+        32 + // to
+        8 +  // amount
+        4 + 32 * Self::MAX_APPROVERS + // approvers
+        8 +  // requested_at
+        1; // bump
+
+    /// Record `approver`. Returns `false` if it is the minter itself, has
+    /// already approved, or the list is full.
+    pub fn approve(&mut self, approver: Pubkey) -> bool {
+        if approver == self.minter
+            || self.approvers.contains(&approver)
+            || self.approvers.len() >= Self::MAX_APPROVERS
+        {
+            return false;
+        }
+        self.approvers.push(approver);
+        true
+    }
+
+    /// Whether the request authorizes `minter` minting `amount` into `to`
+    /// with `required` approvals.
+    pub fn authorizes(&self, minter: &Pubkey, to: &Pubkey, amount: u64, required: u8) -> bool {
+        self.minter == *minter
+            && self.to == *to
+            && self.amount == amount
+            && self.approvers.len() >= usize::from(required)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(minter: Pubkey, to: Pubkey) -> MintApproval {
+        MintApproval {
+            config: Pubkey::default(),
+            minter,
+            id: 0,
+            to,
+            amount: 5_000,
+            approvers: Vec::new(),
+            requested_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_approvals_are_distinct_co_signers() {
+        let minter = Pubkey::new_unique();
+        let mut approval = request(minter, Pubkey::new_unique());
+        let cosigner = Pubkey::new_unique();
+        assert!(!approval.approve(minter));
+        assert!(approval.approve(cosigner));
+        assert!(!approval.approve(cosigner));
+        for _ in 1..MintApproval::MAX_APPROVERS {
+            assert!(approval.approve(Pubkey::new_unique()));
+        }
+        assert!(!approval.approve(Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_authorizes_exact_mint() {
+        let (minter, to) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut approval = request(minter, to);
+        approval.approve(Pubkey::new_unique());
+        approval.approve(Pubkey::new_unique());
+        assert!(approval.authorizes(&minter, &to, 5_000, 2));
+        assert!(!approval.authorizes(&minter, &to, 5_000, 3));
+        assert!(!approval.authorizes(&minter, &to, 4_999, 2));
+        assert!(!approval.authorizes(&minter, &Pubkey::new_unique(), 5_000, 2));
+        assert!(!approval.authorizes(&to, &to, 5_000, 2));
+    }
+
+    #[test]
+    fn test_space_fits_full_list() {
+        let mut approval = request(Pubkey::new_unique(), Pubkey::new_unique());
+        while approval.approve(Pubkey::new_unique()) {}
+        let mut serialized = Vec::new();
+        approval.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), MintApproval::SPACE);
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+/// A MintRequester's ask for tokens, opened with `request_mint` and
+/// executed by a Minter with `fulfill_mint_request`, which mints exactly
+/// this amount into this destination and closes it. Keeps who asked for an
+/// issuance apart from who carried it out.
+#[account]
+pub struct MintRequest {
+    pub config: Pubkey,
+    /// The MintRequester that opened it; receives the rent back.
+    pub requester: Pubkey,
+    /// Chosen by the requester; part of the seeds.
+    pub id: u64,
+    /// Destination token account.
+    pub to: Pubkey,
+    pub amount: u64,
+    /// Caller-supplied hash of the off-chain record behind the request
+    /// (wire, ticket, customer order).
+    pub reference: [u8; 32],
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+impl MintRequest {
+    pub const SSS_MINT_REQUEST_SEED: &'static [u8] = b"sss-mint-request";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // requester
+        8 +  // id
+        32 + // to
+        8 +  // amount
+        32 + // reference
+        8 +  // requested_at
+        1; // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_space_matches_serialized_size() {
+        let request = MintRequest {
+            config: Pubkey::new_unique(),
+            requester: Pubkey::new_unique(),
+            id: u64::MAX,
+            to: Pubkey::new_unique(),
+            amount: u64::MAX,
+            reference: [7; 32],
+            requested_at: i64::MAX,
+            bump: 255,
+        };
+        let mut serialized = Vec::new();
+        request.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), MintRequest::SPACE);
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+/// Hot/cold key split for a Minter role. The `RoleAccount` belongs to the
+/// cold key; the hot key can mint against it on its own only up to
+/// `hot_window_quota` per `window_secs`. Anything larger needs the cold key
+/// as co-signer. Both paths count against the role's `mint_quota`.
+#[account]
+pub struct MinterKeys {
+    pub config: Pubkey,
+    /// The Minter `RoleAccount` this extends.
+    pub minter_role: Pubkey,
+    /// `minter_role.address`, copied for clients.
+    pub cold_key: Pubkey,
+    pub hot_key: Pubkey,
+    /// Maximum the hot key may mint alone within one window.
+    pub hot_window_quota: u64,
+    pub window_secs: i64,
+    pub window_start: i64,
+    /// Amount minted by the hot key alone since `window_start`.
+    pub window_minted: u64,
+    pub bump: u8,
+}
+
+impl MinterKeys {
+    pub const SSS_MINTER_KEYS_SEED: &'static [u8] = b"sss-minter-keys";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // minter_role
+        32 + // cold_key
+        32 + // hot_key
+        8 +  // hot_window_quota
+        8 +  // window_secs
+        8 +  // window_start
+        8 +  // window_minted
+        1; // bump
+
+    /// Amount minted by the hot key in the window containing `now`.
+    pub fn window_minted_at(&self, now: i64) -> u64 {
+        if now >= self.window_start.saturating_add(self.window_secs) {
+            0
+        } else {
+            self.window_minted
+        }
+    }
+
+    /// What the hot key may still mint alone in the window containing `now`.
+    pub fn hot_remaining_at(&self, now: i64) -> u64 {
+        self.hot_window_quota
+            .saturating_sub(self.window_minted_at(now))
+    }
+
+    /// Whether the hot key may mint `amount` alone at `now`. Returns `None`
+    /// on overflow.
+    pub fn hot_allows(&self, amount: u64, now: i64) -> Option<bool> {
+        let new_total = self.window_minted_at(now).checked_add(amount)?;
+        Some(new_total <= self.hot_window_quota)
+    }
+
+    /// Record a hot-only mint of `amount` at `now`, starting a new window if
+    /// the current one has elapsed.
+    pub fn record_hot(&mut self, amount: u64, now: i64) -> Option<()> {
+        if now >= self.window_start.saturating_add(self.window_secs) {
+            self.window_start = now;
+            self.window_minted = 0;
+        }
+        self.window_minted = self.window_minted.checked_add(amount)?;
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys() -> MinterKeys {
+        MinterKeys {
+            config: Pubkey::default(),
+            minter_role: Pubkey::default(),
+            cold_key: Pubkey::default(),
+            hot_key: Pubkey::default(),
+            hot_window_quota: 1_000,
+            window_secs: 3_600,
+            window_start: 0,
+            window_minted: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_hot_window_rolls_over() {
+        let mut keys = keys();
+        assert_eq!(keys.hot_allows(1_000, 10), Some(true));
+        keys.record_hot(800, 10).unwrap();
+        assert_eq!(keys.hot_allows(201, 100), Some(false));
+        assert_eq!(keys.hot_allows(200, 100), Some(true));
+
+        // A new window starts at the first mint after the old one elapsed.
+        assert_eq!(keys.hot_allows(1_000, 3_600), Some(true));
+        keys.record_hot(1_000, 3_700).unwrap();
+        assert_eq!(keys.window_start, 3_700);
+        assert_eq!(keys.hot_allows(1, 7_299), Some(false));
+        assert_eq!(keys.hot_allows(1, 7_300), Some(true));
+    }
+}
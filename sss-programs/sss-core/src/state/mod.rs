@@ -1,5 +1,67 @@
+pub mod collateral_vault;
 pub mod config;
+pub mod credit_line;
+pub mod deployment_counter;
+pub mod destination_throttle;
+pub mod directory;
+pub mod distributor;
+pub mod event_queue;
+pub mod fee_schedule;
+pub mod freeze_exemption;
+pub mod kyc;
+pub mod mint_approval;
+pub mod mint_request;
+pub mod minter_keys;
+pub mod nonce;
+pub mod pending_action;
+pub mod pending_role;
+pub mod period_summary;
+pub mod receipt;
+pub mod receivership;
+pub mod redemption;
+pub mod regulator;
+pub mod reserve_attestation;
+pub mod reserve_journal;
 pub mod role;
+pub mod seize_approval;
+pub mod seizure_record;
+pub mod snapshot;
+pub mod standby_config;
+pub mod sub_minter;
+pub mod swap_route;
+pub mod sweep_route;
+pub mod wipe_record;
 
+pub use collateral_vault::*;
 pub use config::*;
+pub use credit_line::*;
+pub use deployment_counter::*;
+pub use destination_throttle::*;
+pub use directory::*;
+pub use distributor::*;
+pub use event_queue::*;
+pub use fee_schedule::*;
+pub use freeze_exemption::*;
+pub use kyc::*;
+pub use mint_approval::*;
+pub use mint_request::*;
+pub use minter_keys::*;
+pub use nonce::*;
+pub use pending_action::*;
+pub use pending_role::*;
+pub use period_summary::*;
+pub use receipt::*;
+pub use receivership::*;
+pub use redemption::*;
+pub use regulator::*;
+pub use reserve_attestation::*;
+pub use reserve_journal::*;
 pub use role::*;
+pub use seize_approval::*;
+pub use seizure_record::*;
+pub use snapshot::*;
+pub use standby_config::*;
+pub use sub_minter::*;
+pub use swap_route::*;
+pub use sweep_route::*;
+pub use wipe_record::*;
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// The operation an idempotency nonce was consumed by.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NonceOperation {
+    Mint,
+    Burn,
+    Seize,
+}
+
+/// Marks an operator's idempotency nonce as used. Created by the
+/// `*_idempotent` variants of mint, burn and seize; because creation fails
+/// when the PDA already exists, a retried submission with the same nonce
+/// cannot execute twice.
+///
+/// Nonces share one namespace per operator across all three operations.
+#[account]
+pub struct ConsumedNonce {
+    pub config: Pubkey,
+    /// Minter, burner or seizer that submitted the operation.
+    pub operator: Pubkey,
+    pub nonce: u64,
+    pub operation: NonceOperation,
+    pub amount: u64,
+    pub consumed_at: i64,
+    pub bump: u8,
+}
+
+impl ConsumedNonce {
+    pub const SSS_NONCE_SEED: &'static [u8] = b"sss-nonce";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // operator
+        8 +  // nonce
+        1 +  // operation
+        8 +  // amount
+        8 +  // consumed_at
+        1; // bump
+}
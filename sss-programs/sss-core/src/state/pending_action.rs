@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+/// An admin operation that must be queued while `admin_timelock_secs` is
+/// non-zero. Each variant carries the arguments the executing instruction
+/// must be called with.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum TimelockedAction {
+    /// `update_supply_cap(new_supply_cap)`.
+    UpdateSupplyCap { new_supply_cap: Option<u64> },
+    /// `grant_role(Admin)` to `grantee`.
+    GrantAdmin { grantee: Pubkey },
+    /// `propose_authority` naming `new_authority`.
+    ProposeAuthority { new_authority: Pubkey },
+    /// `set_admin_timelock(delay_secs)`.
+    SetAdminTimelock { delay_secs: u32 },
+    /// `sunset`.
+    Sunset,
+}
+
+/// An admin operation queued by `queue_action`. It is executed by calling
+/// the operation's own instruction with this account passed as its
+/// `pending_action`, which closes it; any Admin can `cancel_action` it
+/// before then.
+#[account]
+pub struct PendingAction {
+    pub config: Pubkey,
+    /// Chosen by the Admin that queued it; part of the seeds.
+    pub id: u64,
+    pub action: TimelockedAction,
+    pub queued_by: Pubkey,
+    pub queued_at: i64,
+    /// `queued_at` plus the delay in force when it was queued.
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl PendingAction {
+    pub const SSS_PENDING_ACTION_SEED: &'static [u8] = b"sss-pending-action";
+
+    /// Longest `admin_timelock_secs`.
+    pub const MAX_DELAY_SECS: u32 = 30 * 24 * 60 * 60;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        8 +  // id
+        33 + // action (1 tag + largest variant, a Pubkey)
+        32 + // queued_by
+        8 +  // queued_at
+        8 +  // executable_at
+        1; // bump
+
+    /// Whether the action may be executed at `now` under the config's
+    /// current `delay_secs`. Raising the delay also holds back actions that
+    /// were queued under the shorter one.
+    pub fn is_ready(&self, now: i64, delay_secs: u32) -> bool {
+        now >= self.executable_at && now >= self.queued_at.saturating_add(i64::from(delay_secs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(queued_at: i64, delay_secs: u32) -> PendingAction {
+        PendingAction {
+            config: Pubkey::default(),
+            id: 0,
+            action: TimelockedAction::UpdateSupplyCap {
+                new_supply_cap: None,
+            },
+            queued_by: Pubkey::default(),
+            queued_at,
+            executable_at: queued_at + i64::from(delay_secs),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_ready_after_delay() {
+        let action = pending(1_000, 3_600);
+        assert!(!action.is_ready(4_599, 3_600));
+        assert!(action.is_ready(4_600, 3_600));
+        // Lowering the delay does not shorten a queued action's wait.
+        assert!(!action.is_ready(2_000, 60));
+        // Raising it lengthens the wait.
+        assert!(!action.is_ready(4_600, 7_200));
+        assert!(action.is_ready(8_200, 7_200));
+    }
+
+    #[test]
+    fn test_space_fits_largest_action() {
+        let mut action = pending(0, 0);
+        action.action = TimelockedAction::GrantAdmin {
+            grantee: Pubkey::new_unique(),
+        };
+        let mut serialized = Vec::new();
+        action.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), PendingAction::SPACE);
+    }
+}
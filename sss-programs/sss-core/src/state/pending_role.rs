@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Role;
+
+/// A role granted by `grant_role` and not yet accepted. The grantee signs
+/// `accept_role` to turn it into its `RoleAccount`, so no key ends up
+/// holding a role it did not agree to; any Admin can `cancel_role_grant` it
+/// before then.
+#[account]
+pub struct PendingRole {
+    pub config: Pubkey,
+    /// The grantee.
+    pub address: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub bump: u8,
+}
+
+impl PendingRole {
+    pub const SSS_PENDING_ROLE_SEED: &'static [u8] = b"sss-pending-role";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // address
+        1 +  // role
+        32 + // granted_by
+        8 +  // granted_at
+        1; // bump
+}
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+
+use crate::state::StablecoinConfig;
+
+/// Sealed accounting close for one reporting period, written once by
+/// `export_period_summary` from the config's running counters and never
+/// closed. Periods are numbered per config from zero and each one starts
+/// where the previous summary ended, so consecutive summaries tile the
+/// config's history without gaps or overlaps.
+#[account]
+pub struct PeriodSummary {
+    pub config: Pubkey,
+    /// Position in the config's period history; part of the seeds.
+    pub period_id: u64,
+    /// `closed_at` of the previous period; zero for period 0, which covers
+    /// everything since `initialize`.
+    pub opened_at: i64,
+    pub closed_at: i64,
+    /// First `state_nonce` inside the period. Equals `last_nonce + 1` when
+    /// the config did not change during the period.
+    pub first_nonce: u64,
+    /// `config.state_nonce` at the close.
+    pub last_nonce: u64,
+    /// `config.state_digest` at the close, so the figures can be checked
+    /// against the digest chain.
+    pub state_digest: [u8; 32],
+    /// Growth of the config's counters over the period.
+    pub minted: u64,
+    pub burned: u64,
+    pub bridge_minted: u64,
+    pub bridge_burned: u64,
+    pub seizures: u64,
+    pub wipes: u64,
+    /// The config's cumulative counters at the close.
+    pub total_minted: u64,
+    pub total_burned: u64,
+    pub total_bridge_minted: u64,
+    pub total_bridge_burned: u64,
+    pub seizure_count: u64,
+    pub wipe_count: u64,
+    /// The Admin that exported the summary.
+    pub exported_by: Pubkey,
+    /// The Attestor that co-signed it.
+    pub auditor: Pubkey,
+    pub bump: u8,
+}
+
+impl PeriodSummary {
+    pub const SSS_PERIOD_SUMMARY_SEED: &'static [u8] = b"sss-period-summary";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        8 +  // period_id
+        8 +  // opened_at
+        8 +  // closed_at
+        8 +  // first_nonce
+        8 +  // last_nonce
+        32 + // state_digest
+        8 * 6 + // period totals
+        8 * 6 + // cumulative counters
+        32 + // exported_by
+        32 + // auditor
+        1; // bump
+
+    /// Seal the period at `now` from `config`'s counters. `previous` is the
+    /// summary of the period before, absent only for period 0.
+    pub fn seal(&mut self, config: &StablecoinConfig, previous: Option<&PeriodSummary>, now: i64) {
+        let since = |current: u64, field: fn(&PeriodSummary) -> u64| {
+            current.saturating_sub(previous.map_or(0, field))
+        };
+
+        self.opened_at = previous.map_or(0, |p| p.closed_at);
+        self.closed_at = now;
+        self.first_nonce = previous.map_or(1, |p| p.last_nonce.wrapping_add(1));
+        self.last_nonce = config.state_nonce;
+        self.state_digest = config.state_digest;
+        self.minted = since(config.total_minted, |p| p.total_minted);
+        self.burned = since(config.total_burned, |p| p.total_burned);
+        self.bridge_minted = since(config.bridge_minted, |p| p.total_bridge_minted);
+        self.bridge_burned = since(config.bridge_burned, |p| p.total_bridge_burned);
+        self.seizures = since(config.seizure_count, |p| p.seizure_count);
+        self.wipes = since(config.wipe_count, |p| p.wipe_count);
+        self.total_minted = config.total_minted;
+        self.total_burned = config.total_burned;
+        self.total_bridge_minted = config.bridge_minted;
+        self.total_bridge_burned = config.bridge_burned;
+        self.seizure_count = config.seizure_count;
+        self.wipe_count = config.wipe_count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary() -> PeriodSummary {
+        PeriodSummary {
+            config: Pubkey::default(),
+            period_id: 0,
+            opened_at: 0,
+            closed_at: 0,
+            first_nonce: 0,
+            last_nonce: 0,
+            state_digest: [0; 32],
+            minted: 0,
+            burned: 0,
+            bridge_minted: 0,
+            bridge_burned: 0,
+            seizures: 0,
+            wipes: 0,
+            total_minted: 0,
+            total_burned: 0,
+            total_bridge_minted: 0,
+            total_bridge_burned: 0,
+            seizure_count: 0,
+            wipe_count: 0,
+            exported_by: Pubkey::default(),
+            auditor: Pubkey::default(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_space_matches_serialized_size() {
+        let mut serialized = Vec::new();
+        summary().serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), PeriodSummary::SPACE);
+    }
+
+    #[test]
+    fn test_consecutive_periods_tile_the_counters() {
+        let mut config = crate::state::config::tests::default_config();
+        config.total_minted = 1_000;
+        config.total_burned = 100;
+        config.seizure_count = 1;
+        config.state_nonce = 7;
+
+        let mut first = summary();
+        first.seal(&config, None, 500);
+        assert_eq!((first.opened_at, first.closed_at), (0, 500));
+        assert_eq!((first.first_nonce, first.last_nonce), (1, 7));
+        assert_eq!(
+            (first.minted, first.burned, first.seizures),
+            (1_000, 100, 1)
+        );
+
+        config.total_minted = 1_500;
+        config.bridge_burned = 20;
+        config.wipe_count = 2;
+        config.state_nonce = 9;
+        let mut second = summary();
+        second.seal(&config, Some(&first), 900);
+        assert_eq!((second.opened_at, second.closed_at), (500, 900));
+        assert_eq!((second.first_nonce, second.last_nonce), (8, 9));
+        assert_eq!((second.minted, second.burned), (500, 0));
+        assert_eq!(
+            (second.bridge_burned, second.seizures, second.wipes),
+            (20, 0, 2)
+        );
+        assert_eq!(second.total_minted, first.total_minted + second.minted);
+
+        let mut idle = summary();
+        idle.seal(&config, Some(&second), 1_000);
+        assert_eq!(idle.first_nonce, idle.last_nonce + 1);
+        assert_eq!((idle.minted, idle.wipes), (0, 0));
+    }
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+/// Oracle price an issuance was sized against: `price × 10^exponent` USD.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub exponent: i32,
+}
+
+/// Durable record of a single high-value issuance, created by
+/// `mint_tokens_with_receipt` and closable by an admin once
+/// `config.receipt_retention_secs` have elapsed.
+#[account]
+pub struct MintReceipt {
+    pub config: Pubkey,
+    pub minter: Pubkey,
+    /// Token account that received the minted tokens.
+    pub destination: Pubkey,
+    pub amount: u64,
+    /// Price used to convert the USD cap, if the mint was oracle-adjusted.
+    pub oracle_price: Option<OraclePrice>,
+    /// Caller-supplied hash of the off-chain issuance record (wire, ticket,
+    /// attestation). Also a PDA seed, so each reference can be minted once.
+    pub reference: [u8; 32],
+    pub minted_at: i64,
+    pub bump: u8,
+}
+
+impl MintReceipt {
+    pub const SSS_MINT_RECEIPT_SEED: &'static [u8] = b"sss-mint-receipt";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // minter
+        32 + // destination
+        8 +  // amount
+        13 + // Option<OraclePrice> (1 + 8 + 4)
+        32 + // reference
+        8 +  // minted_at
+        1; // bump
+
+    /// Whether the retention window has passed at `now`.
+    pub fn is_expired(&self, retention_secs: i64, now: i64) -> bool {
+        self.minted_at.saturating_add(retention_secs) <= now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_expired() {
+        let receipt = MintReceipt {
+            config: Pubkey::default(),
+            minter: Pubkey::default(),
+            destination: Pubkey::default(),
+            amount: 1,
+            oracle_price: None,
+            reference: [0; 32],
+            minted_at: 1_000,
+            bump: 0,
+        };
+        assert!(!receipt.is_expired(100, 1_099));
+        assert!(receipt.is_expired(100, 1_100));
+        // Saturates instead of wrapping for very long retention.
+        assert!(!receipt.is_expired(i64::MAX, i64::MAX - 1));
+    }
+
+    #[test]
+    fn test_space_matches_serialized_size() {
+        let receipt = MintReceipt {
+            config: Pubkey::default(),
+            minter: Pubkey::default(),
+            destination: Pubkey::default(),
+            amount: u64::MAX,
+            oracle_price: Some(OraclePrice {
+                price: 100_000_000,
+                exponent: -8,
+            }),
+            reference: [7; 32],
+            minted_at: 0,
+            bump: 255,
+        };
+        let mut serialized = Vec::new();
+        receipt.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), MintReceipt::SPACE);
+    }
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+/// A court-appointed receiver registered ahead of time. Once `quorum`
+/// distinct Admins approve, the receiver may pause, freeze and seize into a
+/// token account owned by this PDA, and nothing else. Every receiver action
+/// also emits `ReceivershipAction`.
+#[account]
+pub struct Receivership {
+    pub config: Pubkey,
+    pub receiver: Pubkey,
+    /// Distinct Admin approvals needed to activate.
+    pub quorum: u8,
+    /// Admins that have approved activation so far (at most `MAX_APPROVALS`).
+    pub approvals: Vec<Pubkey>,
+    /// Unix time the quorum was reached; zero while inactive.
+    pub activated_at: i64,
+    pub registered_by: Pubkey,
+    pub bump: u8,
+}
+
+impl Receivership {
+    pub const SSS_RECEIVERSHIP_SEED: &'static [u8] = b"sss-receivership";
+    pub const MAX_APPROVALS: usize = 8;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // receiver
+        1 +  // quorum
+        4 + 32 * Self::MAX_APPROVALS + // approvals
+        8 +  // activated_at
+        32 + // registered_by
+        1; // bump
+
+    pub fn is_active(&self) -> bool {
+        self.activated_at != 0
+    }
+
+    /// Record `admin`'s approval. Returns `false` if it already approved.
+    pub fn approve(&mut self, admin: Pubkey) -> bool {
+        if self.approvals.contains(&admin) {
+            return false;
+        }
+        self.approvals.push(admin);
+        true
+    }
+
+    /// Whether enough distinct Admins have approved to activate.
+    pub fn has_quorum(&self) -> bool {
+        self.approvals.len() >= self.quorum as usize
+    }
+}
+
+/// The receiver's permitted actions, as tagged in `ReceivershipAction`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReceivershipActionKind {
+    Pause,
+    Freeze,
+    Seize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quorum_counts_distinct_admins() {
+        let mut receivership = Receivership {
+            config: Pubkey::new_unique(),
+            receiver: Pubkey::new_unique(),
+            quorum: 2,
+            approvals: Vec::new(),
+            activated_at: 0,
+            registered_by: Pubkey::new_unique(),
+            bump: 0,
+        };
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        assert!(receivership.approve(a));
+        assert!(!receivership.approve(a));
+        assert!(!receivership.has_quorum());
+        assert!(receivership.approve(b));
+        assert!(receivership.has_quorum());
+        assert!(!receivership.is_active());
+    }
+}
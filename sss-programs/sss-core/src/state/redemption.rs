@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+/// A holder's pending fiat redemption. `request_redemption` moves `amount`
+/// into `escrow`, the request PDA's associated token account, and reserves
+/// it on the config; a Burner then `settle_redemption`s it (burns the
+/// escrow once the bank payout is made) or `cancel_redemption`s it (returns
+/// the escrow to the holder). Either closes the request and escrow to the
+/// holder.
+#[account]
+pub struct RedemptionRequest {
+    pub config: Pubkey,
+    pub holder: Pubkey,
+    /// Chosen by the holder; part of the seeds.
+    pub id: u64,
+    /// Associated token account of this request holding the tokens.
+    pub escrow: Pubkey,
+    pub amount: u64,
+    /// Caller-supplied hash of the holder's payout instruction (bank account,
+    /// wire memo). The details themselves stay off-chain.
+    pub bank_reference: [u8; 32],
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+impl RedemptionRequest {
+    pub const SSS_REDEMPTION_SEED: &'static [u8] = b"sss-redemption";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // holder
+        8 +  // id
+        32 + // escrow
+        8 +  // amount
+        32 + // bank_reference
+        8 +  // requested_at
+        1; // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_space_matches_serialized_size() {
+        let request = RedemptionRequest {
+            config: Pubkey::new_unique(),
+            holder: Pubkey::new_unique(),
+            id: u64::MAX,
+            escrow: Pubkey::new_unique(),
+            amount: u64::MAX,
+            bank_reference: [7; 32],
+            requested_at: i64::MAX,
+            bump: 255,
+        };
+        let mut serialized = Vec::new();
+        request.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), RedemptionRequest::SPACE);
+    }
+}
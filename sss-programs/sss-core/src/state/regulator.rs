@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+/// Grants a regulator key the right to request compliance reports for a
+/// stablecoin and acknowledge their receipt on-chain.
+#[account]
+pub struct RegulatorAccess {
+    pub config: Pubkey,
+    pub regulator: Pubkey,
+    /// Regulator name for display (max 32 bytes).
+    pub name: String,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub bump: u8,
+}
+
+impl RegulatorAccess {
+    pub const SSS_REGULATOR_SEED: &'static [u8] = b"sss-regulator";
+    pub const MAX_NAME_LEN: usize = 32;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // regulator
+        4 + Self::MAX_NAME_LEN + // name
+        32 + // granted_by
+        8 +  // granted_at
+        1; // bump
+}
+
+/// One reporting obligation: requested by a regulator for `period`, answered
+/// by the issuer with a report hash, then acknowledged by the regulator.
+/// Every step is timestamped so the record is tamper-evident.
+#[account]
+pub struct ComplianceReport {
+    pub config: Pubkey,
+    pub regulator: Pubkey,
+    /// Issuer-defined reporting period identifier (e.g. `202610` for October 2026).
+    pub period: u64,
+    pub requested_at: i64,
+    /// Hash of the off-chain report, set by `publish_report`.
+    pub report_hash: Option<[u8; 32]>,
+    pub published_by: Pubkey,
+    /// Zero until published.
+    pub published_at: i64,
+    /// Zero until the regulator acknowledges receipt.
+    pub acknowledged_at: i64,
+    pub bump: u8,
+}
+
+impl ComplianceReport {
+    pub const SSS_REPORT_SEED: &'static [u8] = b"sss-report";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // regulator
+        8 +  // period
+        8 +  // requested_at
+        33 + // Option<[u8; 32]> report_hash
+        32 + // published_by
+        8 +  // published_at
+        8 +  // acknowledged_at
+        1; // bump
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+/// Latest proof-of-reserves attestation for a config, overwritten by each
+/// `publish_reserve_attestation`. While
+/// `StablecoinConfig::max_attestation_age_secs` is set, every path that adds
+/// to global supply needs it and fails once it is older than that age or the
+/// new supply would exceed `reserve_amount`.
+#[account]
+pub struct ReserveAttestation {
+    pub config: Pubkey,
+    /// Reserves backing the stablecoin, in mint base units.
+    pub reserve_amount: u64,
+    /// When the reserves were measured, as stated by the attestor; never
+    /// later than the publishing slot's clock.
+    pub attested_at: i64,
+    /// Hash of the off-chain report (audit letter, custodian statement)
+    /// the amount is taken from.
+    pub report_hash: [u8; 32],
+    /// Attestor that published the current values.
+    pub attestor: Pubkey,
+    /// Number of attestations published; 0 until the first one.
+    pub attestation_count: u64,
+    pub bump: u8,
+}
+
+impl ReserveAttestation {
+    pub const SSS_RESERVE_ATTESTATION_SEED: &'static [u8] = b"sss-reserve-attestation";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        8 +  // reserve_amount
+        8 +  // attested_at
+        32 + // report_hash
+        32 + // attestor
+        8 +  // attestation_count
+        1; // bump
+
+    /// Whether an attestation has been published and is at most
+    /// `max_age_secs` old at `now`.
+    pub fn is_fresh(&self, now: i64, max_age_secs: u32) -> bool {
+        self.attestation_count > 0
+            && now.saturating_sub(self.attested_at) <= i64::from(max_age_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attestation(attested_at: i64, attestation_count: u64) -> ReserveAttestation {
+        ReserveAttestation {
+            config: Pubkey::new_unique(),
+            reserve_amount: u64::MAX,
+            attested_at,
+            report_hash: [7; 32],
+            attestor: Pubkey::new_unique(),
+            attestation_count,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_space_matches_serialized_size() {
+        let mut serialized = Vec::new();
+        attestation(i64::MAX, u64::MAX)
+            .serialize(&mut serialized)
+            .unwrap();
+        assert_eq!(8 + serialized.len(), ReserveAttestation::SPACE);
+    }
+
+    #[test]
+    fn test_freshness() {
+        assert!(!attestation(0, 0).is_fresh(0, 3_600));
+        let published = attestation(1_000, 1);
+        assert!(published.is_fresh(1_000, 0));
+        assert!(published.is_fresh(4_600, 3_600));
+        assert!(!published.is_fresh(4_601, 3_600));
+    }
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+
+/// Off-chain reserve flow recorded in the journal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReserveMovementKind {
+    /// Fiat or collateral deposited with the custodian.
+    CustodyDeposit,
+    /// Fiat or collateral withdrawn from the custodian.
+    CustodyWithdrawal,
+    /// Interest or yield received on reserves.
+    InterestReceived,
+}
+
+/// Per-config header of the reserve journal: the next entry index and
+/// running totals per movement kind. Entries are separate `ReserveMovement`
+/// PDAs keyed by index and are never closed, so the journal is append-only.
+#[account]
+pub struct ReserveJournal {
+    pub config: Pubkey,
+    /// Number of entries recorded; also the index of the next entry.
+    pub entry_count: u64,
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    pub total_interest: u64,
+    pub bump: u8,
+}
+
+impl ReserveJournal {
+    pub const SSS_RESERVE_JOURNAL_SEED: &'static [u8] = b"sss-reserve-journal";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        8 +  // entry_count
+        8 +  // total_deposited
+        8 +  // total_withdrawn
+        8 +  // total_interest
+        1; // bump
+
+    /// Add `amount` to the running total for `kind` and advance the entry
+    /// count, returning the index of the new entry. `None` on overflow, in
+    /// which case nothing is changed.
+    pub fn record(&mut self, kind: ReserveMovementKind, amount: u64) -> Option<u64> {
+        let index = self.entry_count;
+        let entry_count = index.checked_add(1)?;
+        let total = match kind {
+            ReserveMovementKind::CustodyDeposit => &mut self.total_deposited,
+            ReserveMovementKind::CustodyWithdrawal => &mut self.total_withdrawn,
+            ReserveMovementKind::InterestReceived => &mut self.total_interest,
+        };
+        *total = total.checked_add(amount)?;
+        self.entry_count = entry_count;
+        Some(index)
+    }
+}
+
+/// One journal entry. Seeds: `["sss-reserve-movement", config, index_u64_le]`.
+#[account]
+pub struct ReserveMovement {
+    pub config: Pubkey,
+    pub index: u64,
+    pub kind: ReserveMovementKind,
+    pub amount: u64,
+    /// Hash of the off-chain record (bank statement, custodian report) the
+    /// auditor reconciles this entry against.
+    pub ref_hash: [u8; 32],
+    pub recorded_by: Pubkey,
+    pub recorded_at: i64,
+    pub bump: u8,
+}
+
+impl ReserveMovement {
+    pub const SSS_RESERVE_MOVEMENT_SEED: &'static [u8] = b"sss-reserve-movement";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        8 +  // index
+        1 +  // kind
+        8 +  // amount
+        32 + // ref_hash
+        32 + // recorded_by
+        8 +  // recorded_at
+        1; // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_tracks_totals_per_kind() {
+        let mut journal = ReserveJournal {
+            config: Pubkey::default(),
+            entry_count: 0,
+            total_deposited: 0,
+            total_withdrawn: 0,
+            total_interest: 0,
+            bump: 0,
+        };
+        assert_eq!(
+            journal.record(ReserveMovementKind::CustodyDeposit, 100),
+            Some(0)
+        );
+        assert_eq!(
+            journal.record(ReserveMovementKind::CustodyWithdrawal, 40),
+            Some(1)
+        );
+        assert_eq!(
+            journal.record(ReserveMovementKind::InterestReceived, 3),
+            Some(2)
+        );
+        assert_eq!(journal.entry_count, 3);
+        assert_eq!(
+            (
+                journal.total_deposited,
+                journal.total_withdrawn,
+                journal.total_interest
+            ),
+            (100, 40, 3)
+        );
+
+        journal.total_interest = u64::MAX;
+        assert_eq!(
+            journal.record(ReserveMovementKind::InterestReceived, 1),
+            None
+        );
+        assert_eq!(journal.entry_count, 3);
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 #[account]
 pub struct RoleAccount {
@@ -11,8 +12,49 @@ pub struct RoleAccount {
     /// Per-minter quota: maximum amount this minter is allowed to mint.
     /// None means unlimited. Only meaningful for Role::Minter.
     pub mint_quota: Option<u64>,
-    /// Cumulative amount minted by this minter. Only tracked for Role::Minter.
+    /// Amount minted by this minter, since `period_start` while
+    /// `quota_period_secs` is set and over its lifetime otherwise. Only
+    /// tracked for Role::Minter.
     pub amount_minted: u64,
+    /// Recurring UTC window outside which this minter cannot mint. None means
+    /// any time. Only meaningful for Role::Minter.
+    pub allowed_hours: Option<OperatingHours>,
+    /// Most freezes (Freezer), seizures (Seizer) or blacklist additions and
+    /// holds (Blacklister) this key may make per rolling day. None means
+    /// unlimited.
+    pub action_quota: Option<u32>,
+    /// This key's freezes or seizures in the current window. Blacklister
+    /// actions are counted by the transfer hook, which cannot write here.
+    pub action_window: ActionWindow,
+    /// Unix timestamp from which the grant no longer authorizes anything,
+    /// though it stays in place until revoked. None means it never expires.
+    /// Never set on Admin grants.
+    pub expires_at: Option<i64>,
+    /// Length of the period `mint_quota` applies to, e.g. a day or a week.
+    /// None makes the quota a lifetime cap. Only meaningful for
+    /// Role::Minter.
+    pub quota_period_secs: Option<u32>,
+    /// Start of the current quota period. A new period starts at the first
+    /// mint after the previous one elapsed.
+    pub period_start: i64,
+    /// Per-burner quota: maximum amount this burner may burn. None means
+    /// unlimited. Only meaningful for Role::Burner.
+    pub burn_quota: Option<u64>,
+    /// Cumulative amount burned by this burner. Only tracked for
+    /// Role::Burner.
+    pub amount_burned: u64,
+    /// Most this minter may mint in any `window_secs`, on top of
+    /// `mint_quota`, so a stolen key cannot issue its whole allowance at
+    /// once. None means no rate limit. Only meaningful for Role::Minter.
+    pub max_per_window: Option<u64>,
+    pub window_secs: u32,
+    /// Start of the current `window_secs` bucket. Buckets follow each other
+    /// back to back; the first starts at the first rate-limited mint.
+    pub window_start: i64,
+    /// Amount minted since `window_start`.
+    pub window_minted: u64,
+    /// Amount minted in the bucket before `window_start`.
+    pub prev_window_minted: u64,
 }
 
 impl RoleAccount {
@@ -26,7 +68,285 @@ impl RoleAccount {
         8 +  // granted_at
         1 +  // bump
         9 +  // Option<u64> mint_quota (1 + 8)
-        8; // amount_minted
+        8 +  // amount_minted
+        6 +  // Option<OperatingHours> allowed_hours (1 + 2 + 2 + 1)
+        5 +  // Option<u32> action_quota (1 + 4)
+        ActionWindow::SPACE + // action_window
+        9 +  // Option<i64> expires_at (1 + 8)
+        5 +  // Option<u32> quota_period_secs (1 + 4)
+        8 +  // period_start
+        9 +  // Option<u64> burn_quota (1 + 8)
+        8 +  // amount_burned
+        9 +  // Option<u64> max_per_window (1 + 8)
+        4 +  // window_secs
+        8 +  // window_start
+        8 +  // window_minted
+        8; // prev_window_minted
+
+    /// `ROLE_SPACE` of each earlier layout, oldest first. Every layout
+    /// appended fields to the one before and role accounts are never
+    /// resized, so an account's length tells which fields it has.
+    pub const LEGACY_SPACES: [usize; 7] = [131, 137, 154, 163, 176, 193, 222];
+
+    /// Decode a role account created with one of the `LEGACY_SPACES`
+    /// layouts, with the fields it lacks set to `None` or zero. Fields are
+    /// read one at a time rather than from a zero-padded copy because an
+    /// option cleared to `None` leaves stale bytes where its value was.
+    /// `None` if `data` is not a legacy role account.
+    pub fn decode_legacy(data: &[u8]) -> Option<Self> {
+        fn read<T: AnchorDeserialize>(buf: &mut &[u8]) -> Option<T> {
+            T::deserialize(buf).ok()
+        }
+
+        let layout = Self::LEGACY_SPACES
+            .iter()
+            .position(|&space| space == data.len())?;
+        let buf = &mut data.strip_prefix(Self::DISCRIMINATOR)?;
+        let mut role = RoleAccount {
+            config: read(buf)?,
+            address: read(buf)?,
+            role: read(buf)?,
+            granted_by: read(buf)?,
+            granted_at: read(buf)?,
+            bump: read(buf)?,
+            mint_quota: read(buf)?,
+            amount_minted: read(buf)?,
+            allowed_hours: None,
+            action_quota: None,
+            action_window: ActionWindow::default(),
+            expires_at: None,
+            quota_period_secs: None,
+            period_start: 0,
+            burn_quota: None,
+            amount_burned: 0,
+            max_per_window: None,
+            window_secs: 0,
+            window_start: 0,
+            window_minted: 0,
+            prev_window_minted: 0,
+        };
+        if layout >= 1 {
+            role.allowed_hours = read(buf)?;
+        }
+        if layout >= 2 {
+            role.action_quota = read(buf)?;
+            role.action_window = read(buf)?;
+        }
+        if layout >= 3 {
+            role.expires_at = read(buf)?;
+        }
+        if layout >= 4 {
+            role.quota_period_secs = read(buf)?;
+            role.period_start = read(buf)?;
+        }
+        if layout >= 5 {
+            role.burn_quota = read(buf)?;
+            role.amount_burned = read(buf)?;
+        }
+        if layout >= 6 {
+            role.max_per_window = read(buf)?;
+            role.window_secs = read(buf)?;
+            role.window_start = read(buf)?;
+            role.window_minted = read(buf)?;
+        }
+        Some(role)
+    }
+
+    /// Checks whether this minter may mint `amount` more tokens under its
+    /// quota. Returns `None` if the running `amount_minted` total would
+    /// overflow, regardless of whether a quota is set.
+    pub fn quota_allows(&self, amount: u64) -> Option<bool> {
+        let new_total = self.amount_minted.checked_add(amount)?;
+        Some(self.mint_quota.is_none_or(|quota| new_total <= quota))
+    }
+
+    /// Start a new quota period at `now`, clearing `amount_minted`, if the
+    /// current one has elapsed. Minter paths call this before
+    /// `quota_allows`; without a `quota_period_secs` it does nothing.
+    pub fn roll_quota_period(&mut self, now: i64) {
+        let Some(period) = self.quota_period_secs else {
+            return;
+        };
+        if now >= self.period_start.saturating_add(i64::from(period)) {
+            self.period_start = now;
+            self.amount_minted = 0;
+        }
+    }
+
+    /// What this minter may still mint under its quota in the current
+    /// period; `None` when unlimited.
+    pub fn remaining_quota(&self) -> Option<u64> {
+        self.mint_quota
+            .map(|quota| quota.saturating_sub(self.amount_minted))
+    }
+
+    /// The rate buckets as of `now`: the start of the bucket containing
+    /// `now`, what was minted in it and what was minted in the one before.
+    fn rate_buckets_at(&self, now: i64) -> (i64, u64, u64) {
+        let window = i64::from(self.window_secs);
+        let elapsed = now.saturating_sub(self.window_start);
+        if window == 0 || elapsed < window {
+            return (
+                self.window_start,
+                self.window_minted,
+                self.prev_window_minted,
+            );
+        }
+        let buckets = elapsed / window;
+        let previous = if buckets == 1 { self.window_minted } else { 0 };
+        (
+            self.window_start.saturating_add(buckets * window),
+            0,
+            previous,
+        )
+    }
+
+    /// Amount counted against `max_per_window` over the `window_secs` ending
+    /// at `now`: everything minted in the current bucket plus the part of the
+    /// previous bucket still inside that window, taking its mints as spread
+    /// evenly and rounding up.
+    pub fn window_minted_at(&self, now: i64) -> u64 {
+        let (start, current, previous) = self.rate_buckets_at(now);
+        let window = u128::from(self.window_secs);
+        if window == 0 {
+            return current;
+        }
+        let elapsed = u128::try_from(now.saturating_sub(start)).unwrap_or(0);
+        let overlap = window.saturating_sub(elapsed);
+        // At most `previous`, so it fits.
+        let carried = (u128::from(previous) * overlap).div_ceil(window) as u64;
+        current.saturating_add(carried)
+    }
+
+    /// Whether minting `amount` at `now` stays within `max_per_window`.
+    /// Returns `None` on overflow.
+    pub fn rate_allows(&self, amount: u64, now: i64) -> Option<bool> {
+        let Some(max) = self.max_per_window else {
+            return Some(true);
+        };
+        let new_total = self.window_minted_at(now).checked_add(amount)?;
+        Some(new_total <= max)
+    }
+
+    /// What this minter may still mint at `now` under `max_per_window`;
+    /// `None` without a rate limit.
+    pub fn remaining_window_quota(&self, now: i64) -> Option<u64> {
+        self.max_per_window
+            .map(|max| max.saturating_sub(self.window_minted_at(now)))
+    }
+
+    /// Record a mint of `amount` at `now`, moving to the bucket containing
+    /// `now` first. Without a rate limit nothing is recorded.
+    pub fn record_rate(&mut self, amount: u64, now: i64) -> Option<()> {
+        if self.max_per_window.is_none() {
+            return Some(());
+        }
+        let (start, current, previous) = self.rate_buckets_at(now);
+        self.window_start = start;
+        self.prev_window_minted = previous;
+        self.window_minted = current.checked_add(amount)?;
+        Some(())
+    }
+
+    /// Checks whether this burner may burn `amount` more tokens under its
+    /// quota. Returns `None` if the running `amount_burned` total would
+    /// overflow, regardless of whether a quota is set.
+    pub fn burn_quota_allows(&self, amount: u64) -> Option<bool> {
+        let new_total = self.amount_burned.checked_add(amount)?;
+        Some(self.burn_quota.is_none_or(|quota| new_total <= quota))
+    }
+
+    /// Whether the grant is still in force at `now`.
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expires_at.is_none_or(|expires_at| now < expires_at)
+    }
+
+    /// Whether this minter's operating hours (if any) are open at `now`.
+    pub fn hours_allow(&self, now: i64) -> bool {
+        self.allowed_hours.is_none_or(|hours| hours.is_open_at(now))
+    }
+
+    /// Count one action at `now` against `action_quota`. Returns `false`,
+    /// recording nothing, once the quota for the current window is used up.
+    pub fn record_action(&mut self, now: i64) -> bool {
+        self.action_window.record(self.action_quota, now)
+    }
+}
+
+/// Actions taken by one key in the current day-long window, for
+/// `RoleAccount::action_quota`. The window starts at the first action after
+/// the previous one elapsed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ActionWindow {
+    pub window_start: i64,
+    pub count: u32,
+}
+
+impl ActionWindow {
+    pub const WINDOW_SECS: i64 = 86_400;
+    pub const SPACE: usize = 8 + 4;
+
+    /// Count one action at `now` under `quota`, starting a new window if the
+    /// current one has elapsed. Returns `false`, recording nothing, if the
+    /// window already holds `quota` actions. Without a quota nothing is
+    /// counted.
+    pub fn record(&mut self, quota: Option<u32>, now: i64) -> bool {
+        let Some(quota) = quota else {
+            return true;
+        };
+        if now >= self.window_start.saturating_add(Self::WINDOW_SECS) {
+            self.window_start = now;
+            self.count = 0;
+        }
+        if self.count >= quota {
+            return false;
+        }
+        self.count += 1;
+        true
+    }
+}
+
+/// A daily UTC window, e.g. a custody desk's shift, restricted to some days
+/// of the week.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OperatingHours {
+    /// Minute of the UTC day the window opens, 0-1439.
+    pub start_minute: u16,
+    /// Minute of the UTC day the window closes (exclusive), 0-1439. Less than
+    /// `start_minute` for a window that runs past midnight.
+    pub end_minute: u16,
+    /// Days the window opens on: bit 0 = Monday ... bit 6 = Sunday. A window
+    /// that runs past midnight belongs to the day it opened.
+    pub weekdays: u8,
+}
+
+impl OperatingHours {
+    pub const MINUTES_PER_DAY: u16 = 24 * 60;
+    pub const ALL_DAYS: u8 = 0b0111_1111;
+
+    pub fn is_valid(&self) -> bool {
+        self.start_minute < Self::MINUTES_PER_DAY
+            && self.end_minute < Self::MINUTES_PER_DAY
+            && self.start_minute != self.end_minute
+            && self.weekdays != 0
+            && self.weekdays & !Self::ALL_DAYS == 0
+    }
+
+    /// Whether the window is open at Unix timestamp `now`.
+    pub fn is_open_at(&self, now: i64) -> bool {
+        let day = now.div_euclid(86_400);
+        let minute = (now.rem_euclid(86_400) / 60) as u16;
+        let (open, opened_on) = if self.start_minute < self.end_minute {
+            (minute >= self.start_minute && minute < self.end_minute, day)
+        } else if minute >= self.start_minute {
+            (true, day)
+        } else {
+            (minute < self.end_minute, day - 1)
+        };
+        // 1970-01-01 was a Thursday (weekday 3 counting from Monday = 0).
+        let weekday = (opened_on + 3).rem_euclid(7);
+        open && self.weekdays & (1 << weekday) != 0
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
@@ -38,10 +358,34 @@ pub enum Role {
     Burner,
     Blacklister,
     Seizer,
+    /// Cross-chain bridge: mints tokens bridged in and burns tokens bridged
+    /// out, tracked apart from issuer supply.
+    Bridge,
+    /// Updates the metadata URI stored in the config.
+    MetadataManager,
+    /// Sets minters' quotas and operating hours.
+    QuotaManager,
+    /// Moves this mint's tokens out of accounts the config PDA owns, i.e.
+    /// tokens sent to the config address by mistake.
+    Rescuer,
+    /// Pause-only kill switch for monitoring bots and partners: can pause
+    /// every class or add pause flags, but never unpause, clear a flag or
+    /// do anything else. Not counted in `admin_count`.
+    Guardian,
+    /// Opens `MintRequest`s for a Minter to fulfil; cannot mint itself.
+    MintRequester,
+    /// Publishes the `ReserveAttestation` that gates issuance while
+    /// `max_attestation_age_secs` is set.
+    Attestor,
 }
 
 impl Role {
-    pub fn as_u8(&self) -> u8 {
+    /// Roles whose actions `RoleAccount::action_quota` can limit.
+    pub const fn supports_action_quota(&self) -> bool {
+        matches!(self, Role::Freezer | Role::Blacklister | Role::Seizer)
+    }
+
+    pub const fn as_u8(&self) -> u8 {
         match self {
             Role::Admin => 0,
             Role::Minter => 1,
@@ -50,6 +394,417 @@ impl Role {
             Role::Burner => 4,
             Role::Blacklister => 5,
             Role::Seizer => 6,
+            Role::Bridge => 7,
+            Role::MetadataManager => 8,
+            Role::QuotaManager => 9,
+            Role::Rescuer => 10,
+            Role::Guardian => 11,
+            Role::MintRequester => 12,
+            Role::Attestor => 13,
+        }
+    }
+
+    /// Inverse of `as_u8`. Returns `None` for bytes outside 0-13 so that
+    /// caller-supplied role seeds can never map to an unknown role.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Role::Admin),
+            1 => Some(Role::Minter),
+            2 => Some(Role::Freezer),
+            3 => Some(Role::Pauser),
+            4 => Some(Role::Burner),
+            5 => Some(Role::Blacklister),
+            6 => Some(Role::Seizer),
+            7 => Some(Role::Bridge),
+            8 => Some(Role::MetadataManager),
+            9 => Some(Role::QuotaManager),
+            10 => Some(Role::Rescuer),
+            11 => Some(Role::Guardian),
+            12 => Some(Role::MintRequester),
+            13 => Some(Role::Attestor),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Monday 2024-01-01 00:00:00 UTC.
+    const MONDAY: i64 = 1_704_067_200;
+    const HOUR: i64 = 3_600;
+
+    #[test]
+    fn test_business_hours() {
+        let hours = OperatingHours {
+            start_minute: 9 * 60,
+            end_minute: 17 * 60,
+            weekdays: 0b0001_1111,
+        };
+        assert!(hours.is_valid());
+        assert!(!hours.is_open_at(MONDAY + 3 * HOUR));
+        assert!(hours.is_open_at(MONDAY + 9 * HOUR));
+        assert!(hours.is_open_at(MONDAY + 17 * HOUR - 1));
+        assert!(!hours.is_open_at(MONDAY + 17 * HOUR));
+        // Saturday 10:00.
+        assert!(!hours.is_open_at(MONDAY + 5 * 24 * HOUR + 10 * HOUR));
+    }
+
+    #[test]
+    fn test_overnight_shift_belongs_to_opening_day() {
+        // Friday-only shift, 22:00 to 06:00.
+        let hours = OperatingHours {
+            start_minute: 22 * 60,
+            end_minute: 6 * 60,
+            weekdays: 1 << 4,
+        };
+        let friday = MONDAY + 4 * 24 * HOUR;
+        assert!(hours.is_open_at(friday + 23 * HOUR));
+        assert!(hours.is_open_at(friday + 24 * HOUR + 5 * HOUR));
+        assert!(!hours.is_open_at(friday + 24 * HOUR + 23 * HOUR));
+        assert!(!hours.is_open_at(friday + 5 * HOUR));
+    }
+
+    #[test]
+    fn test_invalid_hours() {
+        let valid = OperatingHours {
+            start_minute: 0,
+            end_minute: 60,
+            weekdays: OperatingHours::ALL_DAYS,
+        };
+        assert!(valid.is_valid());
+        assert!(!OperatingHours {
+            end_minute: 0,
+            ..valid
+        }
+        .is_valid());
+        assert!(!OperatingHours {
+            end_minute: 1_440,
+            ..valid
+        }
+        .is_valid());
+        assert!(!OperatingHours {
+            weekdays: 0,
+            ..valid
+        }
+        .is_valid());
+        assert!(!OperatingHours {
+            weekdays: 0x80,
+            ..valid
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn test_decode_legacy_role_account() {
+        let config = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        // A 131-byte Minter from before `allowed_hours`, whose quota was
+        // lifted: the `None` left the old quota's last bytes behind.
+        let mut data = RoleAccount::DISCRIMINATOR.to_vec();
+        config.serialize(&mut data).unwrap();
+        holder.serialize(&mut data).unwrap();
+        Role::Minter.serialize(&mut data).unwrap();
+        config.serialize(&mut data).unwrap();
+        MONDAY.serialize(&mut data).unwrap();
+        254u8.serialize(&mut data).unwrap();
+        None::<u64>.serialize(&mut data).unwrap();
+        500u64.serialize(&mut data).unwrap();
+        data.extend_from_slice(&[0xff; 8]);
+        assert_eq!(data.len(), RoleAccount::LEGACY_SPACES[0]);
+        assert!(RoleAccount::try_deserialize(&mut data.as_slice()).is_err());
+
+        let role = RoleAccount::decode_legacy(&data).unwrap();
+        assert_eq!(
+            (role.config, role.address, role.role, role.bump),
+            (config, holder, Role::Minter, 254)
+        );
+        assert_eq!((role.mint_quota, role.amount_minted), (None, 500));
+        assert_eq!((role.allowed_hours, role.expires_at), (None, None));
+        assert_eq!((role.max_per_window, role.window_minted), (None, 0));
+
+        // Written back in the current layout, it loads as an account again.
+        let mut migrated = Vec::new();
+        role.try_serialize(&mut migrated).unwrap();
+        migrated.resize(RoleAccount::ROLE_SPACE, 0);
+        let loaded = RoleAccount::try_deserialize(&mut migrated.as_slice()).unwrap();
+        assert_eq!((loaded.address, loaded.amount_minted), (holder, 500));
+        assert!(RoleAccount::decode_legacy(&migrated).is_none());
+    }
+
+    #[test]
+    fn test_legacy_spaces_are_layout_prefixes() {
+        let role = RoleAccount {
+            config: Pubkey::new_unique(),
+            address: Pubkey::new_unique(),
+            role: Role::Minter,
+            granted_by: Pubkey::new_unique(),
+            granted_at: MONDAY,
+            bump: 255,
+            mint_quota: Some(1),
+            amount_minted: 2,
+            allowed_hours: Some(OperatingHours {
+                start_minute: 0,
+                end_minute: 60,
+                weekdays: OperatingHours::ALL_DAYS,
+            }),
+            action_quota: Some(3),
+            action_window: ActionWindow {
+                window_start: 4,
+                count: 5,
+            },
+            expires_at: Some(6),
+            quota_period_secs: Some(7),
+            period_start: 8,
+            burn_quota: Some(9),
+            amount_burned: 10,
+            max_per_window: Some(11),
+            window_secs: 12,
+            window_start: 13,
+            window_minted: 14,
+            prev_window_minted: 15,
+        };
+        let mut data = Vec::new();
+        role.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), RoleAccount::ROLE_SPACE);
+
+        // Each earlier layout is a prefix of the current one holding every
+        // field it had; the newest lacks only `prev_window_minted`.
+        for space in RoleAccount::LEGACY_SPACES {
+            assert!(RoleAccount::decode_legacy(&data[..space]).is_some());
         }
+        let newest = RoleAccount::decode_legacy(&data[..222]).unwrap();
+        assert_eq!(newest.window_minted, 14);
+        assert_eq!(newest.prev_window_minted, 0);
+    }
+
+    #[test]
+    fn test_role_expiry() {
+        let mut role = RoleAccount {
+            config: Pubkey::default(),
+            address: Pubkey::default(),
+            role: Role::Freezer,
+            granted_by: Pubkey::default(),
+            granted_at: MONDAY,
+            bump: 0,
+            mint_quota: None,
+            amount_minted: 0,
+            allowed_hours: None,
+            action_quota: None,
+            action_window: ActionWindow::default(),
+            expires_at: None,
+            quota_period_secs: None,
+            period_start: 0,
+            burn_quota: None,
+            amount_burned: 0,
+            max_per_window: None,
+            window_secs: 0,
+            window_start: 0,
+            window_minted: 0,
+            prev_window_minted: 0,
+        };
+        assert!(role.is_active(i64::MAX));
+
+        role.expires_at = Some(MONDAY + HOUR);
+        assert!(role.is_active(MONDAY + HOUR - 1));
+        assert!(!role.is_active(MONDAY + HOUR));
+    }
+
+    #[test]
+    fn test_quota_period_rollover() {
+        let mut role = RoleAccount {
+            config: Pubkey::default(),
+            address: Pubkey::default(),
+            role: Role::Minter,
+            granted_by: Pubkey::default(),
+            granted_at: MONDAY,
+            bump: 0,
+            mint_quota: Some(1_000),
+            amount_minted: 1_000,
+            allowed_hours: None,
+            action_quota: None,
+            action_window: ActionWindow::default(),
+            expires_at: None,
+            quota_period_secs: None,
+            period_start: 0,
+            burn_quota: None,
+            amount_burned: 0,
+            max_per_window: None,
+            window_secs: 0,
+            window_start: 0,
+            window_minted: 0,
+            prev_window_minted: 0,
+        };
+        // Lifetime quota: never resets.
+        role.roll_quota_period(MONDAY);
+        assert_eq!(role.quota_allows(1), Some(false));
+
+        role.quota_period_secs = Some(86_400);
+        role.roll_quota_period(MONDAY);
+        assert_eq!((role.period_start, role.amount_minted), (MONDAY, 0));
+
+        role.amount_minted = 1_000;
+        role.roll_quota_period(MONDAY + 86_399);
+        assert_eq!(role.remaining_quota(), Some(0));
+        role.roll_quota_period(MONDAY + 86_400);
+        assert_eq!(role.remaining_quota(), Some(1_000));
+        assert_eq!(role.period_start, MONDAY + 86_400);
+    }
+
+    #[test]
+    fn test_mint_rate_window() {
+        let mut role = RoleAccount {
+            config: Pubkey::default(),
+            address: Pubkey::default(),
+            role: Role::Minter,
+            granted_by: Pubkey::default(),
+            granted_at: MONDAY,
+            bump: 0,
+            mint_quota: None,
+            amount_minted: 0,
+            allowed_hours: None,
+            action_quota: None,
+            action_window: ActionWindow::default(),
+            expires_at: None,
+            quota_period_secs: None,
+            period_start: 0,
+            burn_quota: None,
+            amount_burned: 0,
+            max_per_window: None,
+            window_secs: 0,
+            window_start: 0,
+            window_minted: 0,
+            prev_window_minted: 0,
+        };
+        // No limit: nothing is counted.
+        assert_eq!(role.rate_allows(u64::MAX, MONDAY), Some(true));
+        role.record_rate(u64::MAX, MONDAY).unwrap();
+        assert_eq!(role.window_minted, 0);
+
+        role.max_per_window = Some(1_000);
+        role.window_secs = 3_600;
+        role.window_start = MONDAY;
+        role.record_rate(600, MONDAY).unwrap();
+        assert_eq!(role.rate_allows(400, MONDAY + 60), Some(true));
+        assert_eq!(role.rate_allows(401, MONDAY + 60), Some(false));
+        assert_eq!(role.remaining_window_quota(MONDAY + 60), Some(400));
+
+        // Two hours on, nothing minted earlier is in the window any more.
+        assert_eq!(role.rate_allows(1_000, MONDAY + 2 * HOUR), Some(true));
+        role.record_rate(1_000, MONDAY + 2 * HOUR).unwrap();
+        assert_eq!(
+            (
+                role.window_start,
+                role.window_minted,
+                role.prev_window_minted
+            ),
+            (MONDAY + 2 * HOUR, 1_000, 0)
+        );
+    }
+
+    #[test]
+    fn test_mint_rate_window_slides_across_buckets() {
+        let mut role = RoleAccount {
+            config: Pubkey::default(),
+            address: Pubkey::default(),
+            role: Role::Minter,
+            granted_by: Pubkey::default(),
+            granted_at: MONDAY,
+            bump: 0,
+            mint_quota: None,
+            amount_minted: 0,
+            allowed_hours: None,
+            action_quota: None,
+            action_window: ActionWindow::default(),
+            expires_at: None,
+            quota_period_secs: None,
+            period_start: 0,
+            burn_quota: None,
+            amount_burned: 0,
+            max_per_window: Some(1_000),
+            window_secs: 3_600,
+            window_start: MONDAY,
+            window_minted: 0,
+            prev_window_minted: 0,
+        };
+        // The whole limit just before the bucket ends...
+        role.record_rate(1_000, MONDAY + HOUR - 1).unwrap();
+        // ...still fills the window just after it, so the limit cannot be
+        // used twice across the boundary.
+        assert_eq!(role.rate_allows(1, MONDAY + HOUR), Some(false));
+        assert_eq!(role.window_minted_at(MONDAY + HOUR + 1), 1_000);
+
+        // Half a window on, half of the previous bucket still counts.
+        assert_eq!(
+            role.remaining_window_quota(MONDAY + 3 * HOUR / 2),
+            Some(500)
+        );
+        assert_eq!(role.rate_allows(501, MONDAY + 3 * HOUR / 2), Some(false));
+        role.record_rate(500, MONDAY + 3 * HOUR / 2).unwrap();
+        assert_eq!(
+            (
+                role.window_start,
+                role.window_minted,
+                role.prev_window_minted
+            ),
+            (MONDAY + HOUR, 500, 1_000)
+        );
+        assert_eq!(role.rate_allows(1, MONDAY + 3 * HOUR / 2), Some(false));
+
+        // A full bucket later only the 500 minted in it still counts.
+        assert_eq!(role.window_minted_at(MONDAY + 2 * HOUR), 500);
+        assert_eq!(role.window_minted_at(MONDAY + 3 * HOUR), 0);
+    }
+
+    #[test]
+    fn test_burn_quota() {
+        let mut role = RoleAccount {
+            config: Pubkey::default(),
+            address: Pubkey::default(),
+            role: Role::Burner,
+            granted_by: Pubkey::default(),
+            granted_at: MONDAY,
+            bump: 0,
+            mint_quota: None,
+            amount_minted: 0,
+            allowed_hours: None,
+            action_quota: None,
+            action_window: ActionWindow::default(),
+            expires_at: None,
+            quota_period_secs: None,
+            period_start: 0,
+            burn_quota: None,
+            amount_burned: 600,
+            max_per_window: None,
+            window_secs: 0,
+            window_start: 0,
+            window_minted: 0,
+            prev_window_minted: 0,
+        };
+        assert_eq!(role.burn_quota_allows(u64::MAX - 600), Some(true));
+        assert_eq!(role.burn_quota_allows(u64::MAX), None);
+
+        role.burn_quota = Some(1_000);
+        assert_eq!(role.burn_quota_allows(400), Some(true));
+        assert_eq!(role.burn_quota_allows(401), Some(false));
+    }
+
+    #[test]
+    fn test_action_quota_per_window() {
+        let mut window = ActionWindow::default();
+        assert!(window.record(None, MONDAY));
+        assert_eq!(window, ActionWindow::default());
+
+        assert!(window.record(Some(2), MONDAY));
+        assert!(window.record(Some(2), MONDAY + HOUR));
+        assert!(!window.record(Some(2), MONDAY + 2 * HOUR));
+        assert_eq!(window.count, 2);
+
+        let next_window = MONDAY + ActionWindow::WINDOW_SECS;
+        assert!(!window.record(Some(2), next_window - 1));
+        assert!(window.record(Some(2), next_window));
+        assert_eq!(window.window_start, next_window);
+        assert_eq!(window.count, 1);
     }
 }
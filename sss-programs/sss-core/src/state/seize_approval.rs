@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+/// A second key's sign-off on one seizure above
+/// `config.large_seize_threshold`. A Seizer or Admin other than `seizer`
+/// creates it with `approve_seize`; `seizer` then passes it to `seize` (or
+/// `seize_idempotent`) for exactly these accounts and amount, which closes
+/// it.
+#[account]
+pub struct SeizeApproval {
+    pub config: Pubkey,
+    /// The Seizer or Admin that created it; part of the seeds.
+    pub approver: Pubkey,
+    /// Chosen by the approver; part of the seeds.
+    pub id: u64,
+    /// The seizer that alone may use it.
+    pub seizer: Pubkey,
+    /// Source token account.
+    pub from: Pubkey,
+    /// Destination token account.
+    pub to: Pubkey,
+    pub amount: u64,
+    pub approved_at: i64,
+    pub bump: u8,
+}
+
+impl SeizeApproval {
+    pub const SSS_SEIZE_APPROVAL_SEED: &'static [u8] = b"sss-seize-approval";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // approver
+        8 +  // id
+        32 + // seizer
+        32 + // from
+        32 + // to
+        8 +  // amount
+        8 +  // approved_at
+        1; // bump
+
+    /// Whether the approval authorizes `seizer` moving `amount` from `from`
+    /// to `to`.
+    pub fn authorizes(&self, seizer: &Pubkey, from: &Pubkey, to: &Pubkey, amount: u64) -> bool {
+        self.seizer == *seizer
+            && self.approver != *seizer
+            && self.from == *from
+            && self.to == *to
+            && self.amount == amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorizes_exact_seizure() {
+        let (approver, seizer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (from, to) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let approval = SeizeApproval {
+            config: Pubkey::default(),
+            approver,
+            id: 0,
+            seizer,
+            from,
+            to,
+            amount: 5_000,
+            approved_at: 0,
+            bump: 0,
+        };
+        assert!(approval.authorizes(&seizer, &from, &to, 5_000));
+        assert!(!approval.authorizes(&seizer, &from, &to, 4_999));
+        assert!(!approval.authorizes(&seizer, &to, &from, 5_000));
+        assert!(!approval.authorizes(&approver, &from, &to, 5_000));
+
+        let self_approved = SeizeApproval {
+            approver: seizer,
+            ..approval
+        };
+        assert!(!self_approved.authorizes(&seizer, &from, &to, 5_000));
+    }
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+/// Durable record of one seizure and the justification given for it,
+/// created by `seize`, `seize_idempotent` and `receivership_seize`. Records
+/// are numbered per config from `config.seizure_count` and never closed.
+#[account]
+pub struct SeizureRecord {
+    pub config: Pubkey,
+    /// Position in the config's seizure history; part of the seeds.
+    pub index: u64,
+    /// The Seizer, or the receiver for a receivership seizure.
+    pub seizer: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    /// The co-signer, when the seizure consumed a `SeizeApproval`.
+    pub approver: Option<Pubkey>,
+    /// Compliance reason supplied by the seizer, e.g. a court order or case
+    /// reference.
+    pub reason: String,
+    pub seized_at: i64,
+    pub bump: u8,
+}
+
+impl SeizureRecord {
+    pub const SSS_SEIZURE_RECORD_SEED: &'static [u8] = b"sss-seizure";
+
+    /// Longest `reason`, in bytes.
+    pub const MAX_REASON_LEN: usize = 200;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        8 +  // index
+        32 + // seizer
+        32 + // from
+        32 + // to
+        8 +  // amount
+        33 + // Option<Pubkey> approver
+        4 + Self::MAX_REASON_LEN + // reason
+        8 +  // seized_at
+        1; // bump
+
+    /// Whether `reason` may be recorded: present and within
+    /// `MAX_REASON_LEN`.
+    pub fn is_valid_reason(reason: &str) -> bool {
+        !reason.trim().is_empty() && reason.len() <= Self::MAX_REASON_LEN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reason_bounds() {
+        assert!(SeizureRecord::is_valid_reason("Court order 2026-CV-1142"));
+        assert!(!SeizureRecord::is_valid_reason(""));
+        assert!(!SeizureRecord::is_valid_reason("   "));
+        let longest = "x".repeat(SeizureRecord::MAX_REASON_LEN);
+        assert!(SeizureRecord::is_valid_reason(&longest));
+        assert!(!SeizureRecord::is_valid_reason(&format!("{longest}x")));
+    }
+
+    #[test]
+    fn test_space_fits_longest_reason() {
+        let record = SeizureRecord {
+            config: Pubkey::default(),
+            index: 0,
+            seizer: Pubkey::default(),
+            from: Pubkey::default(),
+            to: Pubkey::default(),
+            amount: 1,
+            approver: Some(Pubkey::default()),
+            reason: "x".repeat(SeizureRecord::MAX_REASON_LEN),
+            seized_at: 0,
+            bump: 0,
+        };
+        let mut serialized = Vec::new();
+        record.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), SeizureRecord::SPACE);
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+/// A declared balance snapshot for pro-rata distributions.
+///
+/// Balances are read when they are recorded, so records are only accepted
+/// while the snapshot is open (`slot..=record_until_slot`). Issuers that need
+/// exact point-in-time balances pause the stablecoin for that window (on
+/// SSS-2 this halts transfers through the hook).
+#[account]
+pub struct Snapshot {
+    pub config: Pubkey,
+    /// First slot at which balances may be recorded.
+    pub slot: u64,
+    /// Last slot at which balances may be recorded.
+    pub record_until_slot: u64,
+    /// Circulating supply when the snapshot was declared.
+    pub supply_at_declaration: u64,
+    /// Sum of all recorded balances.
+    pub total_recorded: u64,
+    /// Number of token accounts recorded.
+    pub record_count: u64,
+    pub declared_by: Pubkey,
+    pub bump: u8,
+}
+
+impl Snapshot {
+    pub const SSS_SNAPSHOT_SEED: &'static [u8] = b"sss-snapshot";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        8 +  // slot
+        8 +  // record_until_slot
+        8 +  // supply_at_declaration
+        8 +  // total_recorded
+        8 +  // record_count
+        32 + // declared_by
+        1; // bump
+
+    /// Whether balances may be recorded at `slot`.
+    pub fn is_open(&self, slot: u64) -> bool {
+        self.slot <= slot && slot <= self.record_until_slot
+    }
+}
+
+/// One token account's balance under a `Snapshot`. Created at most once per
+/// token account, so a balance cannot be counted twice.
+#[account]
+pub struct SnapshotBalance {
+    pub snapshot: Pubkey,
+    pub token_account: Pubkey,
+    /// Token account owner at record time; the distribution payee.
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub recorded_slot: u64,
+    pub bump: u8,
+}
+
+impl SnapshotBalance {
+    pub const SSS_SNAPSHOT_BALANCE_SEED: &'static [u8] = b"sss-snapshot-bal";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // snapshot
+        32 + // token_account
+        32 + // owner
+        8 +  // amount
+        8 +  // recorded_slot
+        1; // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_open() {
+        let snapshot = Snapshot {
+            config: Pubkey::default(),
+            slot: 100,
+            record_until_slot: 200,
+            supply_at_declaration: 0,
+            total_recorded: 0,
+            record_count: 0,
+            declared_by: Pubkey::default(),
+            bump: 0,
+        };
+        assert!(!snapshot.is_open(99));
+        assert!(snapshot.is_open(100));
+        assert!(snapshot.is_open(200));
+        assert!(!snapshot.is_open(201));
+    }
+}
@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::pause;
+use crate::state::StablecoinConfig;
+
+/// A warm standby of a mint's `StablecoinConfig` for disaster recovery.
+///
+/// `create_standby_config` copies the live config into `image`, paused, and
+/// can be called again to re-sync it. No instruction accepts the standby as
+/// a config, so it stays inert until `failover`, which an Admin may only run
+/// `failover_delay_secs` after `request_failover`. Failover writes `image`
+/// back over the primary config account rather than moving the mint to a new
+/// address: the mint and freeze authorities, the permanent delegate, every
+/// role PDA and the hook's derivations are all keyed to that address, so the
+/// roles carry over unchanged.
+#[account]
+pub struct StandbyConfig {
+    /// The primary config this standby restores.
+    pub primary: Pubkey,
+    /// When `image` was last copied from the primary.
+    pub synced_at: i64,
+    pub synced_by: Pubkey,
+    /// When a failover was requested; zero while none is pending.
+    pub failover_requested_at: i64,
+    pub failover_requested_by: Pubkey,
+    pub bump: u8,
+    /// The primary as of `synced_at`, stored paused.
+    pub image: StablecoinConfig,
+}
+
+impl StandbyConfig {
+    pub const SSS_STANDBY_CONFIG_SEED: &'static [u8] = b"sss-config-standby";
+
+    /// Shortest time between `request_failover` and the earliest
+    /// `failover`, during which any Admin can cancel.
+    pub const FAILOVER_DELAY_SECS: i64 = 24 * 60 * 60;
+
+    /// Offset of `image` in the account: discriminator, primary, synced_at,
+    /// synced_by, failover_requested_at, failover_requested_by and bump.
+    pub const IMAGE_OFFSET: usize = 8 + 32 + 8 + 32 + 8 + 32 + 1;
+
+    /// Account size for a standby of `config`. The image has the same
+    /// strings, and so the same size, as the primary.
+    pub fn space(config: &StablecoinConfig) -> usize {
+        Self::IMAGE_OFFSET
+            + StablecoinConfig::compute_space(&config.name, &config.symbol, &config.uri)
+            - 8 // image, which has no discriminator of its own
+    }
+
+    /// Size of a config account holding the same bytes as the image of a
+    /// standby of `data_len` bytes, for `StablecoinConfig::stored_version`.
+    pub fn image_space(data_len: usize) -> usize {
+        data_len.saturating_sub(Self::IMAGE_OFFSET) + 8
+    }
+
+    pub fn failover_pending(&self) -> bool {
+        self.failover_requested_at != 0
+    }
+
+    /// Time a failover waits after its request: `FAILOVER_DELAY_SECS`, or
+    /// the admin timelock of the image or of `live` (the primary, if it
+    /// still loads) where that is longer, so a failover never rolls back
+    /// timelocked settings faster than the timelock allows.
+    pub fn failover_delay_secs(&self, live: Option<&StablecoinConfig>) -> i64 {
+        let timelock = live.map_or(0, |live| live.admin_timelock_secs);
+        Self::FAILOVER_DELAY_SECS
+            .max(i64::from(self.image.admin_timelock_secs))
+            .max(i64::from(timelock))
+    }
+
+    /// Whether a pending failover's delay has elapsed at `now`.
+    pub fn failover_ready(&self, now: i64, live: Option<&StablecoinConfig>) -> bool {
+        self.failover_pending()
+            && now
+                >= self
+                    .failover_requested_at
+                    .saturating_add(self.failover_delay_secs(live))
+    }
+
+    /// The config failover writes back: `image`, still paused, with its
+    /// supply counters reconciled to `mint_supply`. Mints and burns since the
+    /// last sync are otherwise lost, and the supply cap would be checked
+    /// against stale figures.
+    ///
+    /// `live` is the primary, if it still loads. Fields that track accounts
+    /// created or closed since the sync are taken from it rather than the
+    /// image: `seizure_count` and `wipe_count` name the next `SeizureRecord`
+    /// and `WipeRecord`, which already exist for every seizure and wipe since
+    /// the sync, and `admin_count` and
+    /// `reserved_for_redemption` count Admin role PDAs and escrowed
+    /// redemption requests that a failover does not touch. Capability locks
+    /// and a sunset are permanent, so the restored config has every lock of
+    /// either and a live `sunset_at`. Settings only changed through the
+    /// admin timelock (the supply cap, the timelock itself, the authority
+    /// and a proposed authority) are taken from the primary as well, since
+    /// restoring older values would skip the timelock.
+    pub fn restored_config(
+        &self,
+        mint_supply: u64,
+        live: Option<&StablecoinConfig>,
+    ) -> StablecoinConfig {
+        let mut config = self.image.clone();
+        config.pause_flags = pause::ALL;
+        config.reconcile_supply(mint_supply);
+        if let Some(live) = live {
+            config.seizure_count = config.seizure_count.max(live.seizure_count);
+            config.wipe_count = config.wipe_count.max(live.wipe_count);
+            config.admin_count = live.admin_count;
+            config.reserved_for_redemption = live.reserved_for_redemption;
+            config.locked_instructions |= live.locked_instructions;
+            config.disabled_instructions |= config.locked_instructions;
+            config.sunset_at = live.sunset_at.or(config.sunset_at);
+            config.supply_cap = live.supply_cap;
+            config.admin_timelock_secs = live.admin_timelock_secs;
+            config.authority = live.authority;
+            config.pending_authority = live.pending_authority;
+        }
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::capability;
+    use crate::state::config::tests::default_config;
+    use crate::state::PendingAction;
+
+    fn standby(image: StablecoinConfig) -> StandbyConfig {
+        StandbyConfig {
+            primary: Pubkey::default(),
+            synced_at: 0,
+            synced_by: Pubkey::default(),
+            failover_requested_at: 0,
+            failover_requested_by: Pubkey::default(),
+            bump: 0,
+            image,
+        }
+    }
+
+    #[test]
+    fn test_restored_config_keeps_live_record_counters() {
+        let mut image = default_config();
+        image.total_minted = 1_000;
+        image.seizure_count = 2;
+        image.wipe_count = 1;
+        image.admin_count = 1;
+        let standby = standby(image.clone());
+
+        // Since the sync: two more seizures, a wipe, a second Admin and a
+        // pending redemption.
+        let mut live = image;
+        live.seizure_count = 4;
+        live.wipe_count = 2;
+        live.admin_count = 2;
+        live.reserved_for_redemption = 300;
+
+        let restored = standby.restored_config(900, Some(&live));
+        assert_eq!(restored.pause_flags, pause::ALL);
+        assert_eq!(restored.local_supply(), 900);
+        assert_eq!(restored.seizure_count, 4);
+        assert_eq!(restored.wipe_count, 2);
+        assert_eq!(restored.admin_count, 2);
+        assert_eq!(restored.reserved_for_redemption, 300);
+
+        // A primary that no longer loads leaves the image's values.
+        let restored = standby.restored_config(900, None);
+        assert_eq!(restored.seizure_count, 2);
+        assert_eq!(restored.admin_count, 1);
+    }
+
+    #[test]
+    fn test_restored_config_stays_sunset() {
+        let standby = standby(default_config());
+
+        let mut live = default_config();
+        live.sunset_at = Some(1_700_000_000);
+
+        let restored = standby.restored_config(0, Some(&live));
+        assert_eq!(restored.sunset_at, Some(1_700_000_000));
+    }
+
+    #[test]
+    fn test_restored_config_keeps_locks() {
+        let mut image = default_config();
+        image.disabled_instructions = capability::SEIZE;
+        image.locked_instructions = capability::SEIZE;
+        let standby = standby(image);
+
+        // Since the sync: minting locked off for good.
+        let mut live = default_config();
+        live.disabled_instructions = capability::MINT;
+        live.locked_instructions = capability::MINT;
+
+        let restored = standby.restored_config(0, Some(&live));
+        assert_eq!(
+            restored.locked_instructions,
+            capability::MINT | capability::SEIZE
+        );
+        assert!(!restored.is_enabled(capability::MINT));
+        assert!(!restored.is_enabled(capability::SEIZE));
+    }
+
+    #[test]
+    fn test_restored_config_keeps_timelocked_settings() {
+        let mut image = default_config();
+        image.supply_cap = Some(1_000_000);
+        image.admin_timelock_secs = 0;
+        image.pending_authority = Some(Pubkey::new_unique());
+        let standby = standby(image);
+
+        let mut live = default_config();
+        live.supply_cap = Some(10);
+        live.admin_timelock_secs = 7 * 24 * 60 * 60;
+        live.authority = Pubkey::new_unique();
+        live.pending_authority = None;
+
+        let restored = standby.restored_config(0, Some(&live));
+        assert_eq!(restored.supply_cap, Some(10));
+        assert_eq!(restored.admin_timelock_secs, 7 * 24 * 60 * 60);
+        assert_eq!(restored.authority, live.authority);
+        assert_eq!(restored.pending_authority, None);
+    }
+
+    #[test]
+    fn test_failover_waits_for_the_admin_timelock() {
+        let mut image = default_config();
+        image.admin_timelock_secs = 0;
+        let mut standby = standby(image);
+        standby.failover_requested_at = 1_000;
+        let day = StandbyConfig::FAILOVER_DELAY_SECS;
+
+        assert!(!standby.failover_ready(1_000 + day - 1, None));
+        assert!(standby.failover_ready(1_000 + day, None));
+
+        // A primary with a 30-day timelock holds the failover for 30 days.
+        let mut live = default_config();
+        live.admin_timelock_secs = PendingAction::MAX_DELAY_SECS;
+        let delay = i64::from(PendingAction::MAX_DELAY_SECS);
+        assert_eq!(standby.failover_delay_secs(Some(&live)), delay);
+        assert!(!standby.failover_ready(1_000 + delay - 1, Some(&live)));
+        assert!(standby.failover_ready(1_000 + delay, Some(&live)));
+
+        // So does one whose image has it, when the primary no longer loads.
+        standby.image.admin_timelock_secs = PendingAction::MAX_DELAY_SECS;
+        assert!(!standby.failover_ready(1_000 + day, None));
+    }
+
+    #[test]
+    fn test_image_sits_at_image_offset() {
+        let standby = standby(default_config());
+        let mut data = Vec::new();
+        standby.try_serialize(&mut data).unwrap();
+        let mut image = Vec::new();
+        standby.image.serialize(&mut image).unwrap();
+        assert_eq!(&data[StandbyConfig::IMAGE_OFFSET..], &image[..]);
+
+        let image_space = StablecoinConfig::compute_space(
+            &standby.image.name,
+            &standby.image.symbol,
+            &standby.image.uri,
+        );
+        assert_eq!(
+            StandbyConfig::image_space(StandbyConfig::space(&standby.image)),
+            image_space
+        );
+    }
+}
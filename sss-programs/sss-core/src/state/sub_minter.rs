@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+/// A slice of a Minter's quota delegated to another signer. Everything a
+/// sub-minter mints also counts against the parent's `RoleAccount`, so the
+/// parent's quota bounds the whole tree. The parent can revoke at any time,
+/// and revoking the parent's Minter role disables every sub-minter with it.
+#[account]
+pub struct SubMinter {
+    pub config: Pubkey,
+    /// The Minter that delegated this slice.
+    pub parent: Pubkey,
+    /// The signer allowed to mint against this slice.
+    pub address: Pubkey,
+    /// Maximum cumulative amount this sub-minter may mint.
+    pub quota: u64,
+    /// Cumulative amount minted by this sub-minter.
+    pub amount_minted: u64,
+    pub delegated_at: i64,
+    pub bump: u8,
+}
+
+impl SubMinter {
+    pub const SSS_SUB_MINTER_SEED: &'static [u8] = b"sss-sub-minter";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // parent
+        32 + // address
+        8 +  // quota
+        8 +  // amount_minted
+        8 +  // delegated_at
+        1; // bump
+
+    /// Checks whether this sub-minter may mint `amount` more tokens under
+    /// its delegated slice. Returns `None` if the running total would
+    /// overflow.
+    pub fn quota_allows(&self, amount: u64) -> Option<bool> {
+        let new_total = self.amount_minted.checked_add(amount)?;
+        Some(new_total <= self.quota)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quota_allows() {
+        let sub = SubMinter {
+            config: Pubkey::default(),
+            parent: Pubkey::default(),
+            address: Pubkey::default(),
+            quota: 1_000,
+            amount_minted: 400,
+            delegated_at: 0,
+            bump: 0,
+        };
+        assert_eq!(sub.quota_allows(600), Some(true));
+        assert_eq!(sub.quota_allows(601), Some(false));
+        assert_eq!(sub.quota_allows(u64::MAX), None);
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Authorizes 1:1 `par_swap`s from one SSS stablecoin into another run by
+/// the same issuer. Created only by a key holding Admin on both configs.
+#[account]
+pub struct SwapRoute {
+    pub from_config: Pubkey,
+    pub to_config: Pubkey,
+    pub from_mint: Pubkey,
+    pub to_mint: Pubkey,
+    pub created_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl SwapRoute {
+    pub const SSS_SWAP_ROUTE_SEED: &'static [u8] = b"sss-swap-route";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // from_config
+        32 + // to_config
+        32 + // from_mint
+        32 + // to_mint
+        32 + // created_by
+        8 +  // created_at
+        1; // bump
+}
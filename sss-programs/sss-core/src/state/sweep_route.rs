@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+/// A standing instruction to sweep an issuer intake account (fee receiver,
+/// par-swap income, ...) into the treasury. Registered by an Admin with the
+/// intake owner's consent; anyone may then crank `sweep_intake`, which moves
+/// the whole balance once it reaches `threshold` and `min_interval` has
+/// passed since the last sweep.
+#[account]
+pub struct SweepRoute {
+    pub config: Pubkey,
+    /// Token account swept.
+    pub intake: Pubkey,
+    /// Owner of `intake` who approved the route. Sweeps stop if ownership
+    /// of the token account changes.
+    pub intake_owner: Pubkey,
+    /// Token account the balance is swept into.
+    pub treasury: Pubkey,
+    /// Smallest balance worth sweeping.
+    pub threshold: u64,
+    /// Seconds that must pass between two sweeps.
+    pub min_interval: i64,
+    /// Unix time of the last sweep; zero before the first.
+    pub last_swept_at: i64,
+    pub registered_by: Pubkey,
+    pub bump: u8,
+}
+
+impl SweepRoute {
+    pub const SSS_SWEEP_ROUTE_SEED: &'static [u8] = b"sss-sweep-route";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        32 + // intake
+        32 + // intake_owner
+        32 + // treasury
+        8 +  // threshold
+        8 +  // min_interval
+        8 +  // last_swept_at
+        32 + // registered_by
+        1; // bump
+
+    /// Whether an intake holding `balance` is due for a sweep at `now`.
+    pub fn is_due(&self, balance: u64, now: i64) -> bool {
+        balance > 0
+            && balance >= self.threshold
+            && now >= self.last_swept_at.saturating_add(self.min_interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep_waits_for_threshold_and_interval() {
+        let mut route = SweepRoute {
+            config: Pubkey::default(),
+            intake: Pubkey::default(),
+            intake_owner: Pubkey::default(),
+            treasury: Pubkey::default(),
+            threshold: 1_000,
+            min_interval: 3_600,
+            last_swept_at: 0,
+            registered_by: Pubkey::default(),
+            bump: 0,
+        };
+        assert!(!route.is_due(999, 10_000));
+        assert!(route.is_due(1_000, 10_000));
+
+        route.last_swept_at = 10_000;
+        assert!(!route.is_due(5_000, 13_599));
+        assert!(route.is_due(5_000, 13_600));
+
+        route.threshold = 0;
+        assert!(!route.is_due(0, 13_600));
+    }
+}
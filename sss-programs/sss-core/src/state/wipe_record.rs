@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+/// Durable record of one `wipe_account`: the frozen token account whose
+/// whole balance was burned, and why. Records are numbered per config from
+/// `config.wipe_count` and never closed.
+#[account]
+pub struct WipeRecord {
+    pub config: Pubkey,
+    /// Position in the config's wipe history; part of the seeds.
+    pub index: u64,
+    /// The Seizer or Admin that wiped the account.
+    pub wiper: Pubkey,
+    pub token_account: Pubkey,
+    /// Owner of `token_account` at wipe time.
+    pub owner: Pubkey,
+    pub amount: u64,
+    /// Compliance reason supplied by the wiper, e.g. a sanctions designation
+    /// or court order.
+    pub reason: String,
+    pub wiped_at: i64,
+    pub bump: u8,
+}
+
+impl WipeRecord {
+    pub const SSS_WIPE_RECORD_SEED: &'static [u8] = b"sss-wipe";
+
+    /// Longest `reason`, in bytes.
+    pub const MAX_REASON_LEN: usize = 200;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // config
+        8 +  // index
+        32 + // wiper
+        32 + // token_account
+        32 + // owner
+        8 +  // amount
+        4 + Self::MAX_REASON_LEN + // reason
+        8 +  // wiped_at
+        1; // bump
+
+    /// Whether `reason` may be recorded: present and within
+    /// `MAX_REASON_LEN`.
+    pub fn is_valid_reason(reason: &str) -> bool {
+        !reason.trim().is_empty() && reason.len() <= Self::MAX_REASON_LEN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_space_fits_longest_reason() {
+        let record = WipeRecord {
+            config: Pubkey::default(),
+            index: 0,
+            wiper: Pubkey::default(),
+            token_account: Pubkey::default(),
+            owner: Pubkey::default(),
+            amount: 1,
+            reason: "x".repeat(WipeRecord::MAX_REASON_LEN),
+            wiped_at: 0,
+            bump: 0,
+        };
+        let mut serialized = Vec::new();
+        record.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), WipeRecord::SPACE);
+        assert!(!WipeRecord::is_valid_reason(&format!("{}x", record.reason)));
+    }
+}
@@ -0,0 +1,62 @@
+//! Program version stamped into every `StablecoinConfig` at `initialize`, so
+//! an upgraded program can tell which layout a config was written with.
+
+/// Semantic version of this build as `[major, minor, patch]`, taken from the
+/// crate version in `Cargo.toml`.
+pub const fn program_version() -> [u8; 3] {
+    [
+        parse_component(env!("CARGO_PKG_VERSION_MAJOR")),
+        parse_component(env!("CARGO_PKG_VERSION_MINOR")),
+        parse_component(env!("CARGO_PKG_VERSION_PATCH")),
+    ]
+}
+
+/// Whether this build may operate on a config stamped with `version`.
+///
+/// The major version must match (a major bump means the layout changed and
+/// the config has to be migrated first), and the config must not have been
+/// written by a newer build whose fields this one would silently ignore.
+pub fn is_compatible(version: [u8; 3]) -> bool {
+    let current = program_version();
+    version[0] == current[0] && version <= current
+}
+
+/// Parse a decimal version component. Fails the build if it does not fit a `u8`.
+const fn parse_component(s: &str) -> u8 {
+    let bytes = s.as_bytes();
+    let mut value: u8 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        value = value * 10 + (bytes[i] - b'0');
+        i += 1;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program_version_matches_cargo() {
+        let [major, minor, patch] = program_version();
+        assert_eq!(
+            format!("{major}.{minor}.{patch}"),
+            env!("CARGO_PKG_VERSION")
+        );
+    }
+
+    #[test]
+    fn test_is_compatible() {
+        let [major, minor, patch] = program_version();
+        assert!(is_compatible([major, minor, patch]));
+        assert!(!is_compatible([major, minor, patch.wrapping_add(1)]));
+        assert!(!is_compatible([major.wrapping_add(1), 0, 0]));
+        if minor > 0 {
+            assert!(is_compatible([major, minor - 1, u8::MAX]));
+        }
+        if major > 0 {
+            assert!(!is_compatible([major - 1, minor, patch]));
+        }
+    }
+}
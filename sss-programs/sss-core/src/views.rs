@@ -0,0 +1,208 @@
+//! Return types of the read-only `view_*` instructions, and of the mint
+//! instructions that report remaining headroom.
+//!
+//! `view_config` and `view_role` return these as Borsh-encoded return data,
+//! so a client can read state by simulating a transaction (no account-data
+//! decoding, no RPC data slicing) and another program can read it by CPI.
+//! `mint_tokens` and `mint_tokens_hot` return a [`MintOutcome`] the same way.
+//! Each view starts with `layout_version`; fields are only ever appended, and
+//! the version is bumped when they are, so readers can decode older views.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{OperatingHours, OracleKind, Role, RoleAccount, StablecoinConfig};
+
+/// `layout_version` of the views returned by this build.
+pub const VIEW_LAYOUT_VERSION: u8 = 23;
+
+/// A `StablecoinConfig` with the derived supply figures filled in.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ConfigView {
+    pub layout_version: u8,
+    pub program_version: [u8; 3],
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub preset: u8,
+    pub decimals: u8,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub paused: bool,
+    pub supply_cap: Option<u64>,
+    pub total_minted: u64,
+    pub total_burned: u64,
+    /// `StablecoinConfig::current_supply()`.
+    pub current_supply: u64,
+    /// `StablecoinConfig::circulating_supply()`.
+    pub circulating_supply: u64,
+    /// `StablecoinConfig::local_supply()`.
+    pub local_supply: u64,
+    pub reserved_for_redemption: u64,
+    pub admin_count: u32,
+    pub disabled_instructions: u32,
+    pub locked_instructions: u32,
+    pub incident_flag: bool,
+    pub incident_uri: String,
+    pub state_digest: [u8; 32],
+    pub state_nonce: u64,
+    /// Since layout version 3.
+    pub mint_index: u64,
+    /// Since layout version 4.
+    pub pending_authority: Option<Pubkey>,
+    /// Since layout version 5.
+    pub admin_timelock_secs: u32,
+    /// Since layout version 6.
+    pub large_mint_threshold: Option<u64>,
+    pub large_mint_approvals: u8,
+    /// Since layout version 8.
+    pub unpause_requires_admin: bool,
+    /// Since layout version 9. `paused` is set while any class is.
+    pub pause_flags: u8,
+    /// Since layout version 10. The pause lifts at `pause_expires_at`
+    /// even though `pause_flags` still holds its classes.
+    pub max_pause_secs: u32,
+    pub pause_expires_at: Option<i64>,
+    /// Since layout version 11.
+    pub large_seize_threshold: Option<u64>,
+    /// Since layout version 12.
+    pub seizure_count: u64,
+    /// Since layout version 13.
+    pub treasury: Option<Pubkey>,
+    /// Since layout version 14.
+    pub seize_requires_frozen: bool,
+    /// Since layout version 18.
+    pub wipe_count: u64,
+    /// Since layout version 19.
+    pub version: u8,
+    /// Since layout version 20.
+    pub sunset_at: Option<i64>,
+    /// Since layout version 21.
+    pub oracle_kind: OracleKind,
+    /// Since layout version 22.
+    pub max_attestation_age_secs: Option<u32>,
+    /// Since layout version 23.
+    pub fee_treasury: Option<Pubkey>,
+}
+
+/// A `RoleAccount` grant.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RoleView {
+    pub layout_version: u8,
+    pub config: Pubkey,
+    pub address: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub mint_quota: Option<u64>,
+    pub amount_minted: u64,
+    pub allowed_hours: Option<OperatingHours>,
+    /// Since layout version 2.
+    pub action_quota: Option<u32>,
+    /// Since layout version 7.
+    pub expires_at: Option<i64>,
+    /// Since layout version 15. `amount_minted` counts from `period_start`
+    /// while `quota_period_secs` is set.
+    pub quota_period_secs: Option<u32>,
+    pub period_start: i64,
+    /// Since layout version 16.
+    pub burn_quota: Option<u64>,
+    pub amount_burned: u64,
+    /// Since layout version 17.
+    pub max_per_window: Option<u64>,
+    pub window_secs: u32,
+}
+
+/// What the signer of `mint_tokens` or `mint_tokens_hot` can still mint
+/// after the mint, read in the same transaction, so automated issuance can
+/// throttle itself without fetching accounts between mints.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MintOutcome {
+    pub layout_version: u8,
+    /// Left of the minter role's `mint_quota` in the current period;
+    /// `None` when unlimited.
+    pub remaining_quota: Option<u64>,
+    /// Left of the minter role's `max_per_window` rate limit and, for
+    /// `mint_tokens_hot`, of the hot key's window quota, whichever is less;
+    /// `None` when neither is set.
+    pub remaining_window_quota: Option<u64>,
+    /// Left of `to`'s daily `destination_mint_limit`; `None` while no limit
+    /// is set.
+    pub remaining_destination_limit: Option<u64>,
+    /// Room under the supply cap, as converted and ramped for this mint;
+    /// `None` when uncapped.
+    pub remaining_cap: Option<u64>,
+}
+
+impl StablecoinConfig {
+    pub fn view(&self) -> ConfigView {
+        ConfigView {
+            layout_version: VIEW_LAYOUT_VERSION,
+            program_version: self.program_version,
+            mint: self.mint,
+            authority: self.authority,
+            preset: self.preset,
+            decimals: self.decimals,
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+            paused: self.pause_flags != 0,
+            supply_cap: self.supply_cap,
+            total_minted: self.total_minted,
+            total_burned: self.total_burned,
+            current_supply: self.current_supply(),
+            circulating_supply: self.circulating_supply(),
+            local_supply: self.local_supply(),
+            reserved_for_redemption: self.reserved_for_redemption,
+            admin_count: self.admin_count,
+            disabled_instructions: self.disabled_instructions,
+            locked_instructions: self.locked_instructions,
+            incident_flag: self.incident_flag,
+            incident_uri: self.incident_uri.clone(),
+            state_digest: self.state_digest,
+            state_nonce: self.state_nonce,
+            mint_index: self.mint_index,
+            pending_authority: self.pending_authority,
+            admin_timelock_secs: self.admin_timelock_secs,
+            large_mint_threshold: self.large_mint_threshold,
+            large_mint_approvals: self.large_mint_approvals,
+            unpause_requires_admin: self.unpause_requires_admin,
+            pause_flags: self.pause_flags,
+            max_pause_secs: self.max_pause_secs,
+            pause_expires_at: self.pause_expires_at,
+            large_seize_threshold: self.large_seize_threshold,
+            seizure_count: self.seizure_count,
+            treasury: self.treasury,
+            seize_requires_frozen: self.seize_requires_frozen,
+            wipe_count: self.wipe_count,
+            version: self.version,
+            sunset_at: self.sunset_at,
+            oracle_kind: self.oracle_kind,
+            max_attestation_age_secs: self.max_attestation_age_secs,
+            fee_treasury: self.fee_treasury,
+        }
+    }
+}
+
+impl RoleAccount {
+    pub fn view(&self) -> RoleView {
+        RoleView {
+            layout_version: VIEW_LAYOUT_VERSION,
+            config: self.config,
+            address: self.address,
+            role: self.role,
+            granted_by: self.granted_by,
+            granted_at: self.granted_at,
+            mint_quota: self.mint_quota,
+            amount_minted: self.amount_minted,
+            allowed_hours: self.allowed_hours,
+            action_quota: self.action_quota,
+            expires_at: self.expires_at,
+            quota_period_secs: self.quota_period_secs,
+            period_start: self.period_start,
+            burn_quota: self.burn_quota,
+            amount_burned: self.amount_burned,
+            max_per_window: self.max_per_window,
+            window_secs: self.window_secs,
+        }
+    }
+}
@@ -0,0 +1,318 @@
+//! Example payments escrow built on an SSS stablecoin.
+//!
+//! This program is integration documentation that the workspace compiles and
+//! the integration suite runs (`tests/escrow-example.test.ts`). It shows what a
+//! third-party protocol has to do to hold SSS tokens:
+//!
+//! - Read `StablecoinConfig` from sss-core (the `client` feature gives the
+//!   account type and seeds without the on-chain handlers) and stop moving
+//!   funds while the issuer has paused transfers (`pause::TRANSFERS`, which a
+//!   full `pause` includes). SSS-1 mints have no
+//!   hook, so nothing else enforces the pause on them.
+//! - Move tokens with Token-2022 `transfer_checked` and forward the transfer
+//!   hook's extra accounts from `remaining_accounts`. On SSS-2 mints
+//!   Token-2022 CPIs into sss-transfer-hook, which rejects blacklisted
+//!   senders/receivers and paused mints; without the extra accounts every
+//!   transfer fails.
+//! - SSS-2 token accounts, including the escrow vault, are created frozen.
+//!   The issuer's Freezer has to thaw the vault (`sss_core::thaw_account`)
+//!   between `create_escrow` and `deposit`.
+//!
+//! Flow: `create_escrow` → `deposit` → `release` to the payee, or `refund` to
+//! the payer once the escrow has expired.
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface};
+use sss_core::constants::pause;
+use sss_core::state::StablecoinConfig;
+
+declare_id!("83kzournm8VjtN2qbhD4suC1xPjS5UA23AZAsMofBSnQ");
+
+#[program]
+pub mod sss_escrow_example {
+    use super::*;
+
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        id: u64,
+        amount: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        require!(amount > 0, EscrowError::InvalidAmount);
+        require!(
+            expires_at > Clock::get()?.unix_timestamp,
+            EscrowError::InvalidExpiry
+        );
+
+        let escrow = &mut ctx.accounts.escrow;
+        escrow.payer = ctx.accounts.payer.key();
+        escrow.payee = ctx.accounts.payee.key();
+        escrow.mint = ctx.accounts.mint.key();
+        escrow.id = id;
+        escrow.amount = amount;
+        escrow.expires_at = expires_at;
+        escrow.funded = false;
+        escrow.bump = ctx.bumps.escrow;
+        Ok(())
+    }
+
+    pub fn deposit<'info>(ctx: Context<'_, '_, '_, 'info, Deposit<'info>>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        invoke_transfer_checked(
+            &accounts.token_program.key(),
+            accounts.payer_token.to_account_info(),
+            accounts.mint.to_account_info(),
+            accounts.vault.to_account_info(),
+            accounts.payer.to_account_info(),
+            ctx.remaining_accounts,
+            accounts.escrow.amount,
+            accounts.mint.decimals,
+            &[],
+        )?;
+
+        ctx.accounts.escrow.funded = true;
+        Ok(())
+    }
+
+    pub fn release<'info>(ctx: Context<'_, '_, '_, 'info, Settle<'info>>) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.recipient.owner,
+            ctx.accounts.escrow.payee,
+            EscrowError::WrongRecipient
+        );
+        ctx.accounts.settle(ctx.remaining_accounts)
+    }
+
+    pub fn refund<'info>(ctx: Context<'_, '_, '_, 'info, Settle<'info>>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        require!(
+            !escrow.funded || Clock::get()?.unix_timestamp >= escrow.expires_at,
+            EscrowError::NotExpired
+        );
+        require_keys_eq!(
+            ctx.accounts.recipient.owner,
+            escrow.payer,
+            EscrowError::WrongRecipient
+        );
+        ctx.accounts.settle(ctx.remaining_accounts)
+    }
+}
+
+// ── State ───────────────────────────────────────────────────────────
+
+#[account]
+pub struct Escrow {
+    pub payer: Pubkey,
+    pub payee: Pubkey,
+    pub mint: Pubkey,
+    pub id: u64,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub funded: bool,
+    pub bump: u8,
+}
+
+impl Escrow {
+    pub const SEED: &'static [u8] = b"escrow";
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // payer
+        32 + // payee
+        32 + // mint
+        8 +  // id
+        8 +  // amount
+        8 +  // expires_at
+        1 +  // funded
+        1; // bump
+}
+
+// ── Accounts ────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateEscrow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Receives the funds on `release`; it does not sign.
+    pub payee: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Only SSS-managed mints are accepted: the config PDA must exist.
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Escrow::SPACE,
+        seeds = [Escrow::SEED, payer.key().as_ref(), &id.to_le_bytes()],
+        bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    /// The escrow PDA's associated token account. Frozen on SSS-2 mints until
+    /// the issuer thaws it.
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts: the transfer hook's extra accounts for a transfer from
+/// `payer_token` to `vault` (empty for mints without a hook).
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = payer,
+        has_one = mint,
+        constraint = !escrow.funded @ EscrowError::AlreadyFunded,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = config.bump,
+        constraint = !config.pauses(pause::TRANSFERS, Clock::get()?.unix_timestamp) @ EscrowError::StablecoinPaused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = payer,
+        token::token_program = token_program,
+    )]
+    pub payer_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Shared by `release` (recipient owned by the payee) and `refund` (recipient
+/// owned by the payer). Both are authorised by the payer, who receives the
+/// escrow and vault rent.
+///
+/// Remaining accounts: the transfer hook's extra accounts for a transfer from
+/// `vault` to `recipient` (empty for mints without a hook).
+#[derive(Accounts)]
+pub struct Settle<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        has_one = mint,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [StablecoinConfig::SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = config.bump,
+        constraint = !config.pauses(pause::TRANSFERS, Clock::get()?.unix_timestamp) @ EscrowError::StablecoinPaused,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub recipient: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Settle<'info> {
+    /// Pay the escrowed amount out to `recipient` (if it was deposited) and
+    /// close the vault, both signed by the escrow PDA.
+    fn settle(&self, hook_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let escrow = &self.escrow;
+        let id = escrow.id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[Escrow::SEED, escrow.payer.as_ref(), &id, &[escrow.bump]]];
+
+        if escrow.funded {
+            invoke_transfer_checked(
+                &self.token_program.key(),
+                self.vault.to_account_info(),
+                self.mint.to_account_info(),
+                self.recipient.to_account_info(),
+                escrow.to_account_info(),
+                hook_accounts,
+                escrow.amount,
+                self.mint.decimals,
+                signer_seeds,
+            )?;
+        }
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.vault.to_account_info(),
+                destination: self.payer.to_account_info(),
+                authority: escrow.to_account_info(),
+            },
+            signer_seeds,
+        ))
+    }
+}
+
+// ── Errors ──────────────────────────────────────────────────────────
+
+#[error_code]
+pub enum EscrowError {
+    #[msg("Escrow amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Escrow expiry must be in the future")]
+    InvalidExpiry,
+    #[msg("Escrow is already funded")]
+    AlreadyFunded,
+    #[msg("Escrow has not expired yet")]
+    NotExpired,
+    #[msg("Recipient token account is not owned by the expected party")]
+    WrongRecipient,
+    #[msg("The stablecoin is paused by its issuer")]
+    StablecoinPaused,
+}
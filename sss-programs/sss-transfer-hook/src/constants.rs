@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use sss_core::seeds;
 
 pub const MAX_REASON_LEN: usize = 512;
-pub const SSS_CORE_PROGRAM_ID: Pubkey = pubkey!("SSSCFmmtaU1oToJ9eMqzTtPbK9EAyoXdivUG4irBHVP");
-pub const SSS_CONFIG_SEED: &[u8] = b"sss-config";
-pub const SSS_ROLE_SEED: &[u8] = b"sss-role";
+pub const SSS_CORE_PROGRAM_ID: Pubkey = sss_core::ID;
+pub const SSS_CONFIG_SEED: &[u8] = seeds::CONFIG;
+pub const SSS_ROLE_SEED: &[u8] = seeds::ROLE;
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = seeds::EXTRA_ACCOUNT_METAS;
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::events::{BlockedParty, BlockedPolicy};
+
 #[error_code]
 pub enum TransferHookError {
     #[msg("Sender is blacklisted")]
@@ -12,4 +14,47 @@ pub enum TransferHookError {
     Unauthorized,
     #[msg("Protocol is paused")]
     ProtocolPaused,
+    #[msg("Sender risk score exceeds the screening threshold")]
+    SenderRiskTooHigh,
+    #[msg("Receiver risk score exceeds the screening threshold")]
+    ReceiverRiskTooHigh,
+    #[msg("Blacklisting has been disabled by the issuer")]
+    BlacklistDisabled,
+    #[msg("Scheduled blacklist effective time must be in the future")]
+    InvalidEffectiveTime,
+    #[msg("Account is not a blacklist entry in the pre-scheduling layout")]
+    NotLegacyBlacklistEntry,
+    #[msg("Source token account is under a compliance hold")]
+    SourceOnHold,
+    #[msg("Hold expiry must be in the future")]
+    InvalidHoldExpiry,
+    #[msg("Blacklister has an action quota but its action counter is missing")]
+    ActionCounterRequired,
+    #[msg("Blacklister has used its action quota for the current window")]
+    ActionQuotaExceeded,
+    #[msg("Enforcement actions must be a non-empty set of known flags")]
+    InvalidEnforcementActions,
+    #[msg("Enforcement entry was overwritten or never existed")]
+    EnforcementEntryNotFound,
+    #[msg("Enforcement action is not pending on this entry")]
+    EnforcementActionNotPending,
+    #[msg("Token account is not a frozen account of the blacklisted address")]
+    AccountNotFrozen,
+    #[msg("Signer is not the enforcement queue's notifier")]
+    NotEnforcementNotifier,
+}
+
+impl TransferHookError {
+    /// The error a transfer fails with when `policy` rejects `party`. Every
+    /// policy/party combination has its own code.
+    pub fn for_block(policy: BlockedPolicy, party: BlockedParty) -> Self {
+        match (policy, party) {
+            (BlockedPolicy::Blacklist, BlockedParty::Receiver) => Self::ReceiverBlacklisted,
+            (BlockedPolicy::Blacklist, _) => Self::SenderBlacklisted,
+            (BlockedPolicy::RiskScore, BlockedParty::Receiver) => Self::ReceiverRiskTooHigh,
+            (BlockedPolicy::RiskScore, _) => Self::SenderRiskTooHigh,
+            (BlockedPolicy::Pause, _) => Self::ProtocolPaused,
+            (BlockedPolicy::AccountHold, _) => Self::SourceOnHold,
+        }
+    }
 }
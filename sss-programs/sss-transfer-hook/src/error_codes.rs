@@ -0,0 +1,124 @@
+//! Stable numeric error codes for integrators.
+//!
+//! `TransferHookError` is numbered by Anchor from its declaration order,
+//! starting at `ERROR_CODE_OFFSET` (6000, `0x1770`). Services that see only the
+//! number in a failed transaction's logs or `InstructionError::Custom` convert
+//! it with `TransferHookErrorCode::from(number)` and match on the result. Every
+//! number below is fixed: new errors are only ever appended to
+//! `TransferHookError`, and a code this build does not know converts to
+//! `Unknown` rather than to a wrong variant.
+
+use crate::error::TransferHookError;
+
+/// Anchor's offset for program-defined error numbers.
+pub const ERROR_CODE_OFFSET: u32 = anchor_lang::error::ERROR_CODE_OFFSET;
+
+/// A sss-transfer-hook error number as a typed value. Matches against it need
+/// a wildcard arm, so errors added in later versions do not break callers.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransferHookErrorCode {
+    SenderBlacklisted,
+    ReceiverBlacklisted,
+    ReasonTooLong,
+    Unauthorized,
+    ProtocolPaused,
+    SenderRiskTooHigh,
+    ReceiverRiskTooHigh,
+    BlacklistDisabled,
+    InvalidEffectiveTime,
+    NotLegacyBlacklistEntry,
+    SourceOnHold,
+    InvalidHoldExpiry,
+    ActionCounterRequired,
+    ActionQuotaExceeded,
+    InvalidEnforcementActions,
+    EnforcementEntryNotFound,
+    EnforcementActionNotPending,
+    AccountNotFrozen,
+    NotEnforcementNotifier,
+    /// A number outside sss-transfer-hook's errors, e.g. an Anchor framework
+    /// error or one added after this build.
+    Unknown(u32),
+}
+
+impl TransferHookErrorCode {
+    /// The on-chain error number.
+    pub fn code(self) -> u32 {
+        match self {
+            Self::SenderBlacklisted => 6000,
+            Self::ReceiverBlacklisted => 6001,
+            Self::ReasonTooLong => 6002,
+            Self::Unauthorized => 6003,
+            Self::ProtocolPaused => 6004,
+            Self::SenderRiskTooHigh => 6005,
+            Self::ReceiverRiskTooHigh => 6006,
+            Self::BlacklistDisabled => 6007,
+            Self::InvalidEffectiveTime => 6008,
+            Self::NotLegacyBlacklistEntry => 6009,
+            Self::SourceOnHold => 6010,
+            Self::InvalidHoldExpiry => 6011,
+            Self::ActionCounterRequired => 6012,
+            Self::ActionQuotaExceeded => 6013,
+            Self::InvalidEnforcementActions => 6014,
+            Self::EnforcementEntryNotFound => 6015,
+            Self::EnforcementActionNotPending => 6016,
+            Self::AccountNotFrozen => 6017,
+            Self::NotEnforcementNotifier => 6018,
+            Self::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<u32> for TransferHookErrorCode {
+    fn from(code: u32) -> Self {
+        match code {
+            6000 => Self::SenderBlacklisted,
+            6001 => Self::ReceiverBlacklisted,
+            6002 => Self::ReasonTooLong,
+            6003 => Self::Unauthorized,
+            6004 => Self::ProtocolPaused,
+            6005 => Self::SenderRiskTooHigh,
+            6006 => Self::ReceiverRiskTooHigh,
+            6007 => Self::BlacklistDisabled,
+            6008 => Self::InvalidEffectiveTime,
+            6009 => Self::NotLegacyBlacklistEntry,
+            6010 => Self::SourceOnHold,
+            6011 => Self::InvalidHoldExpiry,
+            6012 => Self::ActionCounterRequired,
+            6013 => Self::ActionQuotaExceeded,
+            6014 => Self::InvalidEnforcementActions,
+            6015 => Self::EnforcementEntryNotFound,
+            6016 => Self::EnforcementActionNotPending,
+            6017 => Self::AccountNotFrozen,
+            6018 => Self::NotEnforcementNotifier,
+            _ => Self::Unknown(code),
+        }
+    }
+}
+
+impl From<TransferHookError> for TransferHookErrorCode {
+    fn from(error: TransferHookError) -> Self {
+        match error {
+            TransferHookError::SenderBlacklisted => Self::SenderBlacklisted,
+            TransferHookError::ReceiverBlacklisted => Self::ReceiverBlacklisted,
+            TransferHookError::ReasonTooLong => Self::ReasonTooLong,
+            TransferHookError::Unauthorized => Self::Unauthorized,
+            TransferHookError::ProtocolPaused => Self::ProtocolPaused,
+            TransferHookError::SenderRiskTooHigh => Self::SenderRiskTooHigh,
+            TransferHookError::ReceiverRiskTooHigh => Self::ReceiverRiskTooHigh,
+            TransferHookError::BlacklistDisabled => Self::BlacklistDisabled,
+            TransferHookError::InvalidEffectiveTime => Self::InvalidEffectiveTime,
+            TransferHookError::NotLegacyBlacklistEntry => Self::NotLegacyBlacklistEntry,
+            TransferHookError::SourceOnHold => Self::SourceOnHold,
+            TransferHookError::InvalidHoldExpiry => Self::InvalidHoldExpiry,
+            TransferHookError::ActionCounterRequired => Self::ActionCounterRequired,
+            TransferHookError::ActionQuotaExceeded => Self::ActionQuotaExceeded,
+            TransferHookError::InvalidEnforcementActions => Self::InvalidEnforcementActions,
+            TransferHookError::EnforcementEntryNotFound => Self::EnforcementEntryNotFound,
+            TransferHookError::EnforcementActionNotPending => Self::EnforcementActionNotPending,
+            TransferHookError::AccountNotFrozen => Self::AccountNotFrozen,
+            TransferHookError::NotEnforcementNotifier => Self::NotEnforcementNotifier,
+        }
+    }
+}
@@ -1,4 +1,10 @@
+//! Events emitted by the transfer hook. Like sss-core's, each carries the
+//! sss-core config PDA and its `mint_index` for multi-tenant indexers.
+
 use anchor_lang::prelude::*;
+use sss_core::events::ConfigValue;
+
+use crate::state::RulePack;
 
 /// Emitted when an address is added to the blacklist.
 ///
@@ -18,6 +24,26 @@ pub struct BlacklistAdded {
     pub added_at: i64,
     /// Compliance reason (reference code, not PII).
     pub reason: String,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted alongside `BlacklistAdded` when the entry only takes effect at a
+/// later time. Transfers to and from `address` are allowed until then.
+#[event]
+pub struct BlacklistScheduled {
+    /// The stablecoin mint this entry applies to.
+    pub mint: Pubkey,
+    /// The wallet address that will be blacklisted.
+    pub address: Pubkey,
+    /// Unix timestamp from which the hook blocks the address.
+    pub effective_at: i64,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
 }
 
 /// Emitted when an address is removed from the blacklist.
@@ -29,4 +55,266 @@ pub struct BlacklistRemoved {
     pub address: Pubkey,
     /// The blacklister who removed this entry.
     pub removed_by: Pubkey,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted when an admin changes the wallet-screening policy for a mint.
+#[event]
+pub struct ScreeningConfigured {
+    /// The stablecoin mint the policy applies to.
+    pub mint: Pubkey,
+    /// The registered screening provider, if any.
+    pub screening_provider: Option<Pubkey>,
+    /// Highest passing risk score.
+    pub max_risk_score: u8,
+    /// Whether high-risk transfers are rejected rather than flagged.
+    pub block_high_risk: bool,
+    /// The admin who made the change.
+    pub updated_by: Pubkey,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted when an admin applies a regional rule pack to a mint.
+#[event]
+pub struct RulePackApplied {
+    /// The stablecoin mint the policy applies to.
+    pub mint: Pubkey,
+    /// The applied pack.
+    pub pack: RulePack,
+    /// The registered screening provider, if any.
+    pub screening_provider: Option<Pubkey>,
+    /// Highest passing risk score, as set by the pack.
+    pub max_risk_score: u8,
+    /// Whether high-risk transfers are rejected, as set by the pack.
+    pub block_high_risk: bool,
+    /// The admin who applied it.
+    pub updated_by: Pubkey,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// A `HookConfig` field written by an admin instruction, identified in
+/// `HookConfigFieldChanged`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HookConfigField {
+    ScreeningProvider,
+    MaxRiskScore,
+    BlockHighRisk,
+}
+
+/// The hook's counterpart of sss-core's `ConfigFieldChanged`: emitted next to
+/// `ScreeningConfigured` or `RulePackApplied` for every `HookConfig` field
+/// whose value actually changed.
+#[event]
+pub struct HookConfigFieldChanged {
+    /// The stablecoin mint the policy applies to.
+    pub mint: Pubkey,
+    /// The field that changed.
+    pub field: HookConfigField,
+    /// Value before the change.
+    pub old: ConfigValue,
+    /// Value after the change.
+    pub new: ConfigValue,
+    /// The admin who made the change.
+    pub updated_by: Pubkey,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted when the screening provider writes a wallet's risk score.
+#[event]
+pub struct RiskScoreUpdated {
+    /// The stablecoin mint the score applies to.
+    pub mint: Pubkey,
+    /// The scored wallet.
+    pub wallet: Pubkey,
+    /// The provider that wrote the score.
+    pub provider: Pubkey,
+    /// The new score.
+    pub score: u8,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted by the hook when a transfer party is over the screening threshold
+/// and the mint is in flag-only mode. The transfer itself succeeds.
+#[event]
+pub struct TransferFlagged {
+    /// The stablecoin mint being transferred.
+    pub mint: Pubkey,
+    /// The owner of the high-risk token account.
+    pub wallet: Pubkey,
+    /// True if `wallet` is the sender, false if it is the receiver.
+    pub is_sender: bool,
+    /// The wallet's risk score.
+    pub score: u8,
+    /// Transfer amount in base units.
+    pub amount: u64,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted when an admin creates the mint's `HookEventQueue`.
+#[event]
+pub struct EventQueueInitialized {
+    /// The stablecoin mint the queue belongs to.
+    pub mint: Pubkey,
+    /// The admin that created it.
+    pub initialized_by: Pubkey,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted when an admin creates a mint's `EnforcementQueue`.
+#[event]
+pub struct EnforcementQueueInitialized {
+    /// The stablecoin mint the queue belongs to.
+    pub mint: Pubkey,
+    /// `enforcement_action` bits each blacklist addition will queue.
+    pub actions: u8,
+    /// The signer that completes partner notifications.
+    pub notifier: Pubkey,
+    /// The admin that created it.
+    pub initialized_by: Pubkey,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted when a blacklist addition queues follow-up actions for keepers.
+#[event]
+pub struct EnforcementEnqueued {
+    /// The stablecoin mint the entry applies to.
+    pub mint: Pubkey,
+    /// The entry's sequence number in the queue.
+    pub seq: u64,
+    /// The blacklisted wallet.
+    pub address: Pubkey,
+    /// `enforcement_action` bits left to do.
+    pub pending: u8,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted when a keeper marks an enforcement action done.
+#[event]
+pub struct EnforcementActionCompleted {
+    /// The stablecoin mint the entry applies to.
+    pub mint: Pubkey,
+    /// The entry's sequence number in the queue.
+    pub seq: u64,
+    /// The blacklisted wallet.
+    pub address: Pubkey,
+    /// The `enforcement_action` bit completed.
+    pub action: u8,
+    /// `enforcement_action` bits still pending afterwards.
+    pub pending: u8,
+    /// The keeper that completed it.
+    pub completed_by: Pubkey,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted when a blacklister places a compliance hold on a token account.
+#[event]
+pub struct AccountHoldPlaced {
+    /// The stablecoin mint the hold applies to.
+    pub mint: Pubkey,
+    /// The held token account.
+    pub token_account: Pubkey,
+    /// The blacklister who placed the hold.
+    pub placed_by: Pubkey,
+    /// Unix timestamp from which outbound transfers are allowed again.
+    pub expires_at: i64,
+    /// Compliance reason code (reference, not PII).
+    pub reason_code: u32,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Emitted when a blacklister lifts a compliance hold before it expires, or
+/// closes one that has.
+#[event]
+pub struct AccountHoldLifted {
+    /// The stablecoin mint the hold applied to.
+    pub mint: Pubkey,
+    /// The token account that was held.
+    pub token_account: Pubkey,
+    /// The blacklister who lifted the hold.
+    pub lifted_by: Pubkey,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
+}
+
+/// Which hook policy rejected a transfer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockedPolicy {
+    /// A `BlacklistEntry` exists for the party.
+    Blacklist,
+    /// The stablecoin is paused in sss-core.
+    Pause,
+    /// The party's `RiskScore` is over the mint's screening threshold.
+    RiskScore,
+    /// The source token account is under an `AccountHold`.
+    AccountHold,
+}
+
+/// Which side of a transfer a policy rejected.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockedParty {
+    Sender,
+    Receiver,
+    /// Mint-wide policies such as the pause apply to both sides.
+    Mint,
+}
+
+/// Emitted by the hook immediately before it rejects a transfer. The
+/// transaction fails, but the event is still in its logs, so wallets that
+/// simulate a transfer can show which policy and which party stopped it
+/// rather than a bare custom error number.
+#[event]
+pub struct TransferBlocked {
+    /// The stablecoin mint being transferred.
+    pub mint: Pubkey,
+    /// Source token account.
+    pub source: Pubkey,
+    /// Destination token account.
+    pub destination: Pubkey,
+    /// The policy that rejected the transfer.
+    pub policy: BlockedPolicy,
+    /// The party the policy rejected.
+    pub party: BlockedParty,
+    /// Transfer amount in base units.
+    pub amount: u64,
+    /// The custom error code the transfer fails with.
+    pub error_code: u32,
+    /// The mint's sss-core config PDA.
+    pub config: Pubkey,
+    /// The config's `mint_index`; see `sss_core::events`.
+    pub mint_index: u64,
 }
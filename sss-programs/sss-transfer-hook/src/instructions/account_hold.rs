@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use sss_core::state::StablecoinConfig;
+
+use crate::constants::{SSS_CONFIG_SEED, SSS_CORE_PROGRAM_ID};
+use crate::error::TransferHookError;
+use crate::events::{AccountHoldLifted, AccountHoldPlaced};
+use crate::state::{AccountHold, ActionCounter};
+
+use super::admin_verify::{record_blacklister_action, verify_blacklister_for_mint};
+
+#[derive(Accounts)]
+pub struct PlaceAccountHold<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    /// CHECK: The sss-core RoleAccount PDA proving the authority has Blacklister role.
+    /// Verified by checking owner == sss-core program ID and re-deriving the
+    /// expected PDA address from known seeds using the mint key.
+    pub blacklister_role: UncheckedAccount<'info>,
+
+    /// CHECK: The stablecoin mint the held token account belongs to.
+    pub mint: UncheckedAccount<'info>,
+
+    /// The mint's sss-core config, for the event topic.
+    #[account(
+        seeds = [SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = SSS_CORE_PROGRAM_ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(token::mint = mint)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = blacklister,
+        space = AccountHold::SPACE,
+        seeds = [AccountHold::ACCOUNT_HOLD_SEED, mint.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub account_hold: Account<'info, AccountHold>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Required while the blacklister's role has an `action_quota`.
+    #[account(
+        mut,
+        seeds = [ActionCounter::ACTION_COUNTER_SEED, mint.key().as_ref(), blacklister.key().as_ref()],
+        bump = action_counter.bump,
+    )]
+    pub action_counter: Option<Account<'info, ActionCounter>>,
+}
+
+/// Block transfers out of `token_account` until `expires_at` without freezing
+/// it, so the holder can still receive.
+pub fn handler_place_account_hold(
+    ctx: Context<PlaceAccountHold>,
+    expires_at: i64,
+    reason_code: u32,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at > now, TransferHookError::InvalidHoldExpiry);
+
+    let role = verify_blacklister_for_mint(
+        &ctx.accounts.blacklister_role.to_account_info(),
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.blacklister.key(),
+    )?;
+    record_blacklister_action(&role, ctx.accounts.action_counter.as_mut(), now)?;
+
+    let hold = &mut ctx.accounts.account_hold;
+    hold.mint = ctx.accounts.mint.key();
+    hold.token_account = ctx.accounts.token_account.key();
+    hold.placed_by = ctx.accounts.blacklister.key();
+    hold.placed_at = now;
+    hold.expires_at = expires_at;
+    hold.reason_code = reason_code;
+    hold.bump = ctx.bumps.account_hold;
+
+    emit!(AccountHoldPlaced {
+        mint: hold.mint,
+        token_account: hold.token_account,
+        placed_by: hold.placed_by,
+        expires_at,
+        reason_code,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LiftAccountHold<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    /// CHECK: The sss-core RoleAccount PDA proving the authority has Blacklister role.
+    /// Verified by checking owner == sss-core program ID and re-deriving the
+    /// expected PDA address from known seeds using the mint key.
+    pub blacklister_role: UncheckedAccount<'info>,
+
+    /// The mint's sss-core config, for the event topic.
+    #[account(
+        seeds = [SSS_CONFIG_SEED, account_hold.mint.as_ref()],
+        seeds::program = SSS_CORE_PROGRAM_ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        close = blacklister,
+        seeds = [AccountHold::ACCOUNT_HOLD_SEED, account_hold.mint.as_ref(), account_hold.token_account.as_ref()],
+        bump = account_hold.bump,
+    )]
+    pub account_hold: Account<'info, AccountHold>,
+}
+
+pub fn handler_lift_account_hold(ctx: Context<LiftAccountHold>) -> Result<()> {
+    let hold = &ctx.accounts.account_hold;
+
+    verify_blacklister_for_mint(
+        &ctx.accounts.blacklister_role.to_account_info(),
+        &hold.mint,
+        &ctx.accounts.blacklister.key(),
+    )?;
+
+    emit!(AccountHoldLifted {
+        mint: hold.mint,
+        token_account: hold.token_account,
+        lifted_by: ctx.accounts.blacklister.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    // Account closure handled by Anchor via `close = blacklister`.
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ActionCounter;
+
+/// Create a blacklister's action counter so it can keep blacklisting and
+/// placing holds once an admin sets an `action_quota` on its role.
+/// Permissionless: the counter only ever restricts.
+#[derive(Accounts)]
+pub struct OpenActionCounter<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The stablecoin mint the count applies to.
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: The blacklister being counted. Any valid public key.
+    pub blacklister: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ActionCounter::SPACE,
+        seeds = [ActionCounter::ACTION_COUNTER_SEED, mint.key().as_ref(), blacklister.key().as_ref()],
+        bump,
+    )]
+    pub action_counter: Account<'info, ActionCounter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_open_action_counter(ctx: Context<OpenActionCounter>) -> Result<()> {
+    let counter = &mut ctx.accounts.action_counter;
+    counter.mint = ctx.accounts.mint.key();
+    counter.blacklister = ctx.accounts.blacklister.key();
+    counter.window = Default::default();
+    counter.bump = ctx.bumps.action_counter;
+    Ok(())
+}
@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
+use sss_core::constants::capability;
+use sss_core::state::{QueuedEventKind, StablecoinConfig};
 
-use crate::constants::MAX_REASON_LEN;
+use crate::constants::{MAX_REASON_LEN, SSS_CONFIG_SEED, SSS_CORE_PROGRAM_ID};
 use crate::error::TransferHookError;
-use crate::events::BlacklistAdded;
-use crate::state::BlacklistEntry;
+use crate::events::{BlacklistAdded, BlacklistScheduled, EnforcementEnqueued};
+use crate::state::{ActionCounter, BlacklistEntry, EnforcementQueue, HookEventQueue};
 
-use super::admin_verify::verify_blacklister_for_mint;
+use super::admin_verify::{record_blacklister_action, verify_blacklister_for_mint};
 
 #[derive(Accounts)]
 #[instruction(reason: String)]
@@ -21,6 +23,14 @@ pub struct AddToBlacklist<'info> {
     /// CHECK: The stablecoin mint this blacklist entry applies to.
     pub mint: UncheckedAccount<'info>,
 
+    /// The mint's sss-core config, checked for a disabled blacklist capability.
+    #[account(
+        seeds = [SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = SSS_CORE_PROGRAM_ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
     /// CHECK: The wallet address to blacklist. Any valid public key.
     pub address: UncheckedAccount<'info>,
 
@@ -34,21 +44,79 @@ pub struct AddToBlacklist<'info> {
     pub blacklist_entry: Account<'info, BlacklistEntry>,
 
     pub system_program: Program<'info, System>,
+
+    /// The mint's event queue, if one was initialized. When passed, the
+    /// addition is also recorded there for keepers.
+    #[account(
+        mut,
+        seeds = [HookEventQueue::HOOK_EVENT_QUEUE_SEED, mint.key().as_ref()],
+        bump = event_queue.bump,
+    )]
+    pub event_queue: Option<Account<'info, HookEventQueue>>,
+
+    /// Required while the blacklister's role has an `action_quota`.
+    #[account(
+        mut,
+        seeds = [ActionCounter::ACTION_COUNTER_SEED, mint.key().as_ref(), blacklister.key().as_ref()],
+        bump = action_counter.bump,
+    )]
+    pub action_counter: Option<Account<'info, ActionCounter>>,
+
+    /// The mint's enforcement queue, if one was initialized. When passed, the
+    /// addition leaves the queue's follow-up actions for keepers.
+    #[account(
+        mut,
+        seeds = [EnforcementQueue::ENFORCEMENT_QUEUE_SEED, mint.key().as_ref()],
+        bump = enforcement_queue.bump,
+    )]
+    pub enforcement_queue: Option<Account<'info, EnforcementQueue>>,
 }
 
 pub fn handler_add_to_blacklist(ctx: Context<AddToBlacklist>, reason: String) -> Result<()> {
+    add_entry(ctx, reason, None)
+}
+
+/// Like `add_to_blacklist`, but the hook keeps allowing transfers until
+/// `effective_at`, e.g. to give a partner exchange notice to halt deposits.
+pub fn handler_schedule_blacklist(
+    ctx: Context<AddToBlacklist>,
+    reason: String,
+    effective_at: i64,
+) -> Result<()> {
+    require!(
+        effective_at > Clock::get()?.unix_timestamp,
+        TransferHookError::InvalidEffectiveTime
+    );
+    add_entry(ctx, reason, Some(effective_at))
+}
+
+fn add_entry(
+    ctx: Context<AddToBlacklist>,
+    reason: String,
+    effective_at: Option<i64>,
+) -> Result<()> {
     // Validate reason length.
     require!(
         reason.len() <= MAX_REASON_LEN,
         TransferHookError::ReasonTooLong
     );
 
+    require!(
+        ctx.accounts.config.is_enabled(capability::BLACKLIST),
+        TransferHookError::BlacklistDisabled
+    );
+
     // Verify the caller has Blacklister role in sss-core for this mint.
-    verify_blacklister_for_mint(
+    let role = verify_blacklister_for_mint(
         &ctx.accounts.blacklister_role.to_account_info(),
         &ctx.accounts.mint.key(),
         &ctx.accounts.blacklister.key(),
     )?;
+    record_blacklister_action(
+        &role,
+        ctx.accounts.action_counter.as_mut(),
+        Clock::get()?.unix_timestamp,
+    )?;
 
     let entry = &mut ctx.accounts.blacklist_entry;
     entry.mint = ctx.accounts.mint.key();
@@ -57,6 +125,7 @@ pub fn handler_add_to_blacklist(ctx: Context<AddToBlacklist>, reason: String) ->
     entry.added_at = Clock::get()?.unix_timestamp;
     entry.reason = reason.clone();
     entry.bump = ctx.bumps.blacklist_entry;
+    entry.effective_at = effective_at;
 
     emit!(BlacklistAdded {
         mint: entry.mint,
@@ -64,7 +133,41 @@ pub fn handler_add_to_blacklist(ctx: Context<AddToBlacklist>, reason: String) ->
         added_by: entry.added_by,
         added_at: entry.added_at,
         reason,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
     });
+    if let Some(effective_at) = effective_at {
+        emit!(BlacklistScheduled {
+            mint: entry.mint,
+            address: entry.address,
+            effective_at,
+            config: ctx.accounts.config.key(),
+            mint_index: ctx.accounts.config.mint_index,
+        });
+    }
+
+    if let Some(queue) = ctx.accounts.event_queue.as_mut() {
+        queue.ring.push(
+            QueuedEventKind::BlacklistAdd,
+            ctx.accounts.address.key(),
+            ctx.accounts.blacklister.key(),
+            0,
+            Clock::get()?.slot,
+        );
+    }
+
+    if let Some(queue) = ctx.accounts.enforcement_queue.as_mut() {
+        let address = ctx.accounts.address.key();
+        let seq = queue.push(address, Clock::get()?.unix_timestamp);
+        emit!(EnforcementEnqueued {
+            mint: queue.mint,
+            seq,
+            address,
+            pending: queue.actions,
+            config: ctx.accounts.config.key(),
+            mint_index: ctx.accounts.config.mint_index,
+        });
+    }
 
     Ok(())
 }
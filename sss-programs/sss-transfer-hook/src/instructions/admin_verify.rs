@@ -1,84 +1,77 @@
 use anchor_lang::prelude::*;
+use sss_core::role_check::verify_role_account;
+use sss_core::state::{Role, RoleAccount};
 
-use crate::constants::{SSS_CONFIG_SEED, SSS_CORE_PROGRAM_ID, SSS_ROLE_SEED};
 use crate::error::TransferHookError;
+use crate::state::ActionCounter;
 
 /// Verifies that the provided admin_role account is a valid sss-core Admin
 /// RoleAccount PDA for the given mint and authority.
 ///
-/// First re-derives the sss-core config PDA from the mint, then verifies
-/// the admin_role PDA matches the expected derivation for that config.
-/// This ensures the admin_role is actually tied to the correct stablecoin.
+/// The config PDA is re-derived from the mint, so the role is tied to the
+/// correct stablecoin; see `sss_core::role_check`.
 pub fn verify_admin_for_mint(
     admin_role: &AccountInfo,
     mint_key: &Pubkey,
     authority_key: &Pubkey,
 ) -> Result<()> {
-    // The account must be owned by the sss-core program.
-    require!(
-        admin_role.owner == &SSS_CORE_PROGRAM_ID,
-        TransferHookError::Unauthorized
-    );
-
-    // Re-derive the sss-core config PDA from the mint.
-    let (sss_config_pda, _config_bump) =
-        Pubkey::find_program_address(&[SSS_CONFIG_SEED, mint_key.as_ref()], &SSS_CORE_PROGRAM_ID);
-
-    // Re-derive the expected admin role PDA and verify it matches.
-    // Seeds: [b"sss-role", config_key, authority_key, &[Role::Admin = 0]]
-    let (expected_pda, _bump) = Pubkey::find_program_address(
-        &[
-            SSS_ROLE_SEED,
-            sss_config_pda.as_ref(),
-            authority_key.as_ref(),
-            &[0u8], // Role::Admin = 0
-        ],
-        &SSS_CORE_PROGRAM_ID,
-    );
-
-    require!(
-        admin_role.key() == expected_pda,
-        TransferHookError::Unauthorized
-    );
-
-    Ok(())
+    verify_role_for_mint(admin_role, mint_key, authority_key, Role::Admin)
 }
 
 /// Verifies that the provided blacklister_role account is a valid sss-core
 /// Blacklister RoleAccount PDA for the given mint and authority.
 ///
-/// Same logic as verify_admin_for_mint but checks role byte = 5 (Blacklister).
+/// Same logic as verify_admin_for_mint but for `Role::Blacklister`. Returns
+/// the role account so callers can read its `action_quota`.
 pub fn verify_blacklister_for_mint(
     blacklister_role: &AccountInfo,
     mint_key: &Pubkey,
     authority_key: &Pubkey,
-) -> Result<()> {
-    // The account must be owned by the sss-core program.
-    require!(
-        blacklister_role.owner == &SSS_CORE_PROGRAM_ID,
-        TransferHookError::Unauthorized
-    );
-
-    // Re-derive the sss-core config PDA from the mint.
-    let (sss_config_pda, _config_bump) =
-        Pubkey::find_program_address(&[SSS_CONFIG_SEED, mint_key.as_ref()], &SSS_CORE_PROGRAM_ID);
-
-    // Re-derive the expected blacklister role PDA and verify it matches.
-    // Seeds: [b"sss-role", config_key, authority_key, &[Role::Blacklister = 5]]
-    let (expected_pda, _bump) = Pubkey::find_program_address(
-        &[
-            SSS_ROLE_SEED,
-            sss_config_pda.as_ref(),
-            authority_key.as_ref(),
-            &[5u8], // Role::Blacklister = 5
-        ],
-        &SSS_CORE_PROGRAM_ID,
-    );
+) -> Result<RoleAccount> {
+    let now = Clock::get()?.unix_timestamp;
+    verify_role_account(
+        blacklister_role,
+        mint_key,
+        authority_key,
+        Role::Blacklister,
+        now,
+    )
+    .map_err(|_| error!(TransferHookError::Unauthorized))
+}
 
+/// Count a blacklist addition or hold against the blacklister's
+/// `action_quota`. While a quota is set the blacklister's `ActionCounter`
+/// (already checked to be its PDA) must be passed; without one this is a
+/// no-op.
+pub fn record_blacklister_action(
+    role: &RoleAccount,
+    counter: Option<&mut Account<ActionCounter>>,
+    now: i64,
+) -> Result<()> {
+    if role.action_quota.is_none() {
+        return Ok(());
+    }
+    let counter = counter.ok_or(TransferHookError::ActionCounterRequired)?;
     require!(
-        blacklister_role.key() == expected_pda,
-        TransferHookError::Unauthorized
+        counter.window.record(role.action_quota, now),
+        TransferHookError::ActionQuotaExceeded
     );
+    Ok(())
+}
 
+/// `role_account` must be the live sss-core `RoleAccount` granting `role` to
+/// `authority_key` under the mint's config: owned by sss-core, at the role
+/// PDA, with matching stored config, holder and role, and not expired.
+/// Checking the contents as well as the address means a closed role account
+/// cannot pass.
+fn verify_role_for_mint(
+    role_account: &AccountInfo,
+    mint_key: &Pubkey,
+    authority_key: &Pubkey,
+    role: Role,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    verify_role_account(role_account, mint_key, authority_key, role, now)
+        .map_err(|_| TransferHookError::Unauthorized)?;
     Ok(())
 }
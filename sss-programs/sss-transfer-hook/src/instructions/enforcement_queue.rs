@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use sss_core::state::StablecoinConfig;
+
+use crate::constants::{SSS_CONFIG_SEED, SSS_CORE_PROGRAM_ID};
+use crate::error::TransferHookError;
+use crate::events::{EnforcementActionCompleted, EnforcementQueueInitialized};
+use crate::state::{enforcement_action, EnforcementQueue};
+
+use super::admin_verify::verify_admin_for_mint;
+
+// Initialize Enforcement Queue
+
+#[derive(Accounts)]
+pub struct InitializeEnforcementQueue<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: The sss-core RoleAccount PDA proving the authority has Admin role.
+    /// Verified by checking owner == sss-core program ID and re-deriving the
+    /// expected PDA address from known seeds using the mint key.
+    pub admin_role: UncheckedAccount<'info>,
+
+    /// CHECK: The stablecoin mint this queue belongs to.
+    pub mint: UncheckedAccount<'info>,
+
+    /// The mint's sss-core config, for the event topic.
+    #[account(
+        seeds = [SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = SSS_CORE_PROGRAM_ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = EnforcementQueue::SPACE,
+        seeds = [EnforcementQueue::ENFORCEMENT_QUEUE_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub enforcement_queue: Account<'info, EnforcementQueue>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the mint's enforcement queue. From then on, every blacklist
+/// addition that passes the queue leaves `actions` for keepers to carry out.
+pub fn handler_initialize_enforcement_queue(
+    ctx: Context<InitializeEnforcementQueue>,
+    actions: u8,
+    notifier: Pubkey,
+) -> Result<()> {
+    verify_admin_for_mint(
+        &ctx.accounts.admin_role.to_account_info(),
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.admin.key(),
+    )?;
+    require!(
+        actions != 0 && actions & !enforcement_action::ALL == 0,
+        TransferHookError::InvalidEnforcementActions
+    );
+
+    let queue = &mut ctx.accounts.enforcement_queue;
+    queue.mint = ctx.accounts.mint.key();
+    queue.actions = actions;
+    queue.notifier = notifier;
+    queue.bump = ctx.bumps.enforcement_queue;
+
+    emit!(EnforcementQueueInitialized {
+        mint: queue.mint,
+        actions,
+        notifier,
+        initialized_by: ctx.accounts.admin.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+// Complete Enforcement Action
+
+#[derive(Accounts)]
+pub struct CompleteEnforcementAction<'info> {
+    pub keeper: Signer<'info>,
+
+    /// CHECK: The stablecoin mint the queue belongs to.
+    pub mint: UncheckedAccount<'info>,
+
+    /// The mint's sss-core config, for the event topic.
+    #[account(
+        seeds = [SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = SSS_CORE_PROGRAM_ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [EnforcementQueue::ENFORCEMENT_QUEUE_SEED, mint.key().as_ref()],
+        bump = enforcement_queue.bump,
+    )]
+    pub enforcement_queue: Account<'info, EnforcementQueue>,
+
+    /// Required for `FREEZE_ACCOUNTS`: a token account of the blacklisted
+    /// address, already frozen.
+    #[account(token::mint = mint)]
+    pub token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Mark one `enforcement_action` bit of entry `seq` done. Freezing is
+/// permissionless and proven by the frozen token account; partner
+/// notification happens off-chain, so only the queue's notifier may attest it.
+pub fn handler_complete_enforcement_action(
+    ctx: Context<CompleteEnforcementAction>,
+    seq: u64,
+    action: u8,
+) -> Result<()> {
+    let keeper = ctx.accounts.keeper.key();
+    let queue = &mut ctx.accounts.enforcement_queue;
+    let notifier = queue.notifier;
+    let entry = queue
+        .entry_mut(seq)
+        .ok_or(TransferHookError::EnforcementEntryNotFound)?;
+    require!(
+        action.count_ones() == 1 && entry.pending & action != 0,
+        TransferHookError::EnforcementActionNotPending
+    );
+
+    match action {
+        enforcement_action::FREEZE_ACCOUNTS => {
+            let frozen = ctx
+                .accounts
+                .token_account
+                .as_ref()
+                .is_some_and(|account| account.owner == entry.address && account.is_frozen());
+            require!(frozen, TransferHookError::AccountNotFrozen);
+        }
+        enforcement_action::NOTIFY_PARTNERS => {
+            require_keys_eq!(keeper, notifier, TransferHookError::NotEnforcementNotifier);
+        }
+        _ => unreachable!("pending bits are a subset of enforcement_action::ALL"),
+    }
+
+    entry.pending &= !action;
+
+    emit!(EnforcementActionCompleted {
+        mint: ctx.accounts.mint.key(),
+        seq,
+        address: entry.address,
+        action,
+        pending: entry.pending,
+        completed_by: keeper,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
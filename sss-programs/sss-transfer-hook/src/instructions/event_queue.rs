@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use sss_core::state::StablecoinConfig;
+
+use crate::constants::{SSS_CONFIG_SEED, SSS_CORE_PROGRAM_ID};
+use crate::events::EventQueueInitialized;
+use crate::state::HookEventQueue;
+
+use super::admin_verify::verify_admin_for_mint;
+
+#[derive(Accounts)]
+pub struct InitializeEventQueue<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: The sss-core RoleAccount PDA proving the authority has Admin role.
+    /// Verified by checking owner == sss-core program ID and re-deriving the
+    /// expected PDA address from known seeds using the mint key.
+    pub admin_role: UncheckedAccount<'info>,
+
+    /// CHECK: The stablecoin mint this queue belongs to.
+    pub mint: UncheckedAccount<'info>,
+
+    /// The mint's sss-core config, for the event topic.
+    #[account(
+        seeds = [SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = SSS_CORE_PROGRAM_ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = HookEventQueue::SPACE,
+        seeds = [HookEventQueue::HOOK_EVENT_QUEUE_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub event_queue: Account<'info, HookEventQueue>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_initialize_event_queue(ctx: Context<InitializeEventQueue>) -> Result<()> {
+    verify_admin_for_mint(
+        &ctx.accounts.admin_role.to_account_info(),
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.admin.key(),
+    )?;
+
+    let queue = &mut ctx.accounts.event_queue;
+    queue.mint = ctx.accounts.mint.key();
+    queue.bump = ctx.bumps.event_queue;
+
+    emit!(EventQueueInitialized {
+        mint: queue.mint,
+        initialized_by: ctx.accounts.admin.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
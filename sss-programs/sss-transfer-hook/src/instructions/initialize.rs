@@ -1,4 +1,6 @@
-use crate::state::BlacklistEntry;
+use super::admin_verify::verify_admin_for_mint;
+use crate::constants::EXTRA_ACCOUNT_METAS_SEED;
+use crate::state::{AccountHold, BlacklistEntry, HookConfig, RiskScore};
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::token_interface::Mint;
@@ -16,7 +18,7 @@ pub struct InitializeExtraAccountMetas<'info> {
     /// for this mint. Created and initialized in this instruction.
     #[account(
     mut,
-    seeds = [b"extra-account-metas", mint.key().as_ref()],
+    seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
     bump,
   )]
     pub extra_account_metas: UncheckedAccount<'info>,
@@ -28,70 +30,7 @@ pub struct InitializeExtraAccountMetas<'info> {
 pub fn handler_initialize(ctx: Context<InitializeExtraAccountMetas>) -> Result<()> {
     let extra_account_metas = ctx.accounts.extra_account_metas.to_account_info();
     let mint = ctx.accounts.mint.to_account_info();
-
-    // Define the extra account metas that Token-2022 must resolve during transfers.
-    //
-    // Transfer hook execute account ordering:
-    //   0 = source token account
-    //   1 = mint
-    //   2 = destination token account
-    //   3 = source authority (owner/delegate)
-    //   4 = extra_account_metas PDA (validation state)
-    //
-    // We need three additional accounts (resolved by Token-2022):
-    //   5 = sender blacklist PDA  (seeds: [b"blacklist", mint, source_owner])
-    //   6 = receiver blacklist PDA (seeds: [b"blacklist", mint, dest_owner])
-    //   7 = protocol config PDA (seeds: [b"sss-config", mint])
-    //
-    // SECURITY — both PDAs use the token account's stored `owner` field
-    // (at byte offset 32), NOT the transfer authority (index 3). This prevents
-    // a blacklisted user from bypassing the denylist by authorizing a clean
-    // delegate to transfer on their behalf.
-    let account_metas = vec![
-        // Sender blacklist: PDA derived from [b"blacklist", mint, source_token_account.owner]
-        // Reading source owner from account data (offset 32, 32 bytes) prevents
-        // bypass via delegated transfers.
-        ExtraAccountMeta::new_with_seeds(
-            &[
-                Seed::Literal {
-                    bytes: BlacklistEntry::BLACKLIST_SEED.to_vec(),
-                },
-                Seed::AccountKey { index: 1 }, // mint
-                Seed::AccountData {
-                    account_index: 0, // source token account
-                    data_index: 32,   // offset of `owner` field in token account layout
-                    length: 32,       // Pubkey is 32 bytes
-                },
-            ],
-            false, // is_signer
-            false, // is_writable
-        )?,
-        // Receiver blacklist: PDA derived from [b"blacklist", mint, destination_owner]
-        // The destination owner is extracted from the destination token account
-        // data at offset 32, length 32 (the `owner` field in token account layout).
-        ExtraAccountMeta::new_with_seeds(
-            &[
-                Seed::Literal {
-                    bytes: BlacklistEntry::BLACKLIST_SEED.to_vec(),
-                },
-                Seed::AccountKey { index: 1 }, // mint
-                Seed::AccountData {
-                    account_index: 2, // destination token account
-                    data_index: 32,   // offset of `owner` field in token account
-                    length: 32,       // Pubkey is 32 bytes
-                },
-            ],
-            false,
-            false,
-        )?,
-        // Protocol config: Pre-calculated PDA owned by sss-core.
-        // This allows the hook to check the protocol's "paused" state.
-        ExtraAccountMeta::new_with_pubkey(
-            &Pubkey::find_program_address(&[b"sss-config", mint.key.as_ref()], &sss_core::ID).0,
-            false, // is_signer
-            false, // is_writable
-        )?,
-    ];
+    let account_metas = extra_account_metas_for(mint.key)?;
 
     // Calculate required account size for the ExtraAccountMetaList.
     let account_size = ExtraAccountMetaList::size_of(account_metas.len())?;
@@ -99,7 +38,7 @@ pub fn handler_initialize(ctx: Context<InitializeExtraAccountMetas>) -> Result<(
     // Allocate the ExtraAccountMetaList PDA via SystemProgram.
     let lamports = Rent::get()?.minimum_balance(account_size);
     let signer_seeds: &[&[u8]] = &[
-        b"extra-account-metas",
+        EXTRA_ACCOUNT_METAS_SEED,
         mint.key.as_ref(),
         &[ctx.bumps.extra_account_metas],
     ];
@@ -126,3 +65,155 @@ pub fn handler_initialize(ctx: Context<InitializeExtraAccountMetas>) -> Result<(
 
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetas<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: The sss-core RoleAccount PDA proving the authority has Admin role.
+    /// Verified by checking owner == sss-core program ID and re-deriving the
+    /// expected PDA address from known seeds using the mint key.
+    pub admin_role: UncheckedAccount<'info>,
+
+    /// CHECK: Validated via seeds constraint and ownership — the existing
+    /// ExtraAccountMetaList PDA for this mint.
+    #[account(
+    mut,
+    seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+    bump,
+    owner = crate::ID,
+  )]
+    pub extra_account_metas: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrite an existing ExtraAccountMetaList with the current account set, so
+/// mints initialized before an extra account was added resolve it too.
+pub fn handler_update_extra_account_metas(ctx: Context<UpdateExtraAccountMetas>) -> Result<()> {
+    verify_admin_for_mint(
+        &ctx.accounts.admin_role.to_account_info(),
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.admin.key(),
+    )?;
+
+    let extra_account_metas = ctx.accounts.extra_account_metas.to_account_info();
+    let account_metas = extra_account_metas_for(&ctx.accounts.mint.key())?;
+    let account_size = ExtraAccountMetaList::size_of(account_metas.len())?;
+
+    // Top up rent before growing the account; it never shrinks.
+    let required = Rent::get()?.minimum_balance(account_size);
+    let shortfall = required.saturating_sub(extra_account_metas.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: extra_account_metas.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    if account_size > extra_account_metas.data_len() {
+        extra_account_metas.resize(account_size)?;
+    }
+
+    ExtraAccountMetaList::update::<ExecuteInstruction>(
+        &mut extra_account_metas.try_borrow_mut_data()?,
+        &account_metas,
+    )?;
+
+    Ok(())
+}
+
+/// The extra accounts Token-2022 must resolve during transfers of `mint`.
+///
+/// Transfer hook execute account ordering:
+///   0 = source token account
+///   1 = mint
+///   2 = destination token account
+///   3 = source authority (owner/delegate)
+///   4 = extra_account_metas PDA (validation state)
+///
+/// Followed by these extra accounts (resolved by Token-2022):
+///   5  = sender blacklist PDA  (seeds: [b"blacklist", mint, source_owner])
+///   6  = receiver blacklist PDA (seeds: [b"blacklist", mint, dest_owner])
+///   7  = protocol config PDA (seeds: [b"sss-config", mint])
+///   8  = hook config PDA (seeds: [b"hook-config", mint])
+///   9  = sender risk score PDA (seeds: [b"risk-score", mint, source_owner])
+///   10 = receiver risk score PDA (seeds: [b"risk-score", mint, dest_owner])
+///   11 = source account hold PDA (seeds: [b"account-hold", mint, source])
+///
+/// SECURITY — the per-wallet PDAs use the token account's stored `owner`
+/// field (at byte offset 32), NOT the transfer authority (index 3). This
+/// prevents a blacklisted or high-risk user from bypassing the checks by
+/// authorizing a clean delegate to transfer on their behalf.
+fn extra_account_metas_for(mint: &Pubkey) -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        // Sender blacklist: PDA derived from [b"blacklist", mint, source_token_account.owner]
+        // Reading source owner from account data (offset 32, 32 bytes) prevents
+        // bypass via delegated transfers.
+        owner_keyed_meta(BlacklistEntry::BLACKLIST_SEED, 0)?,
+        // Receiver blacklist: PDA derived from [b"blacklist", mint, destination_owner]
+        // The destination owner is extracted from the destination token account
+        // data at offset 32, length 32 (the `owner` field in token account layout).
+        owner_keyed_meta(BlacklistEntry::BLACKLIST_SEED, 2)?,
+        // Protocol config: Pre-calculated PDA owned by sss-core.
+        // This allows the hook to check the protocol's "paused" state.
+        ExtraAccountMeta::new_with_pubkey(
+            &sss_core::pda::find_config_address(mint).0,
+            false, // is_signer
+            false, // is_writable
+        )?,
+        // Hook config: this program's per-mint policy. May not exist.
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: HookConfig::HOOK_CONFIG_SEED.to_vec(),
+                },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // Sender and receiver risk scores, keyed by token account owner.
+        owner_keyed_meta(RiskScore::RISK_SCORE_SEED, 0)?,
+        owner_keyed_meta(RiskScore::RISK_SCORE_SEED, 2)?,
+        // Compliance hold on the source token account itself, not its owner,
+        // so a hold on one account leaves the owner's other accounts usable.
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: AccountHold::ACCOUNT_HOLD_SEED.to_vec(),
+                },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountKey { index: 0 }, // source token account
+            ],
+            false,
+            false,
+        )?,
+    ])
+}
+
+/// A read-only PDA meta with seeds `[prefix, mint, token_account.owner]`.
+fn owner_keyed_meta(prefix: &[u8], token_account_index: u8) -> Result<ExtraAccountMeta> {
+    Ok(ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal {
+                bytes: prefix.to_vec(),
+            },
+            Seed::AccountKey { index: 1 }, // mint
+            Seed::AccountData {
+                account_index: token_account_index,
+                data_index: 32, // offset of `owner` field in token account layout
+                length: 32,     // Pubkey is 32 bytes
+            },
+        ],
+        false, // is_signer
+        false, // is_writable
+    )?)
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::error::TransferHookError;
+use crate::state::BlacklistEntry;
+
+#[derive(Accounts)]
+pub struct MigrateBlacklistEntry<'info> {
+    /// Pays the rent for the extra bytes. Anyone may migrate an entry; the
+    /// contents are unchanged and the new field is written as `None`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: A `BlacklistEntry` in the layout before `effective_at` existed,
+    /// which no longer deserializes as `Account<BlacklistEntry>`. Ownership is
+    /// checked here and the discriminator and length in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub blacklist_entry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a legacy blacklist entry to the current layout with
+/// `effective_at = None`, so `remove_from_blacklist` can load it again. The
+/// transfer hook already treats legacy entries as in effect without this.
+pub fn handler_migrate_blacklist_entry(ctx: Context<MigrateBlacklistEntry>) -> Result<()> {
+    let entry = ctx.accounts.blacklist_entry.to_account_info();
+
+    let (legacy_len, new_len) = {
+        let data = entry.try_borrow_data()?;
+        require!(
+            data.starts_with(BlacklistEntry::DISCRIMINATOR),
+            TransferHookError::NotLegacyBlacklistEntry
+        );
+        let offset = BlacklistEntry::REASON_OFFSET;
+        let reason_len = data
+            .get(offset..offset + 4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or(TransferHookError::NotLegacyBlacklistEntry)? as usize;
+        let legacy_len = BlacklistEntry::LEGACY_BASE_SIZE + 4 + reason_len;
+        require!(
+            data.len() == legacy_len,
+            TransferHookError::NotLegacyBlacklistEntry
+        );
+        (legacy_len, BlacklistEntry::BASE_SIZE + 4 + reason_len)
+    };
+
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(entry.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: entry.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    entry.resize(new_len)?;
+    // `effective_at = None` and padding.
+    entry.try_borrow_mut_data()?[legacy_len..].fill(0);
+
+    Ok(())
+}
@@ -1,10 +1,24 @@
+pub mod account_hold;
+pub mod action_counter;
 pub mod add_to_blacklist;
 pub mod admin_verify;
+pub mod enforcement_queue;
+pub mod event_queue;
 pub mod initialize;
+pub mod migrate_blacklist;
 pub mod remove_from_blacklist;
+pub mod screening;
 pub mod transfer_hook;
+pub mod view;
 
+pub use account_hold::*;
+pub use action_counter::*;
 pub use add_to_blacklist::*;
+pub use enforcement_queue::*;
+pub use event_queue::*;
 pub use initialize::*;
+pub use migrate_blacklist::*;
 pub use remove_from_blacklist::*;
+pub use screening::*;
 pub use transfer_hook::*;
+pub use view::*;
@@ -1,7 +1,9 @@
 use super::admin_verify::verify_blacklister_for_mint;
+use crate::constants::{SSS_CONFIG_SEED, SSS_CORE_PROGRAM_ID};
 use crate::events::BlacklistRemoved;
 use crate::state::BlacklistEntry;
 use anchor_lang::prelude::*;
+use sss_core::state::StablecoinConfig;
 
 #[derive(Accounts)]
 pub struct RemoveFromBlacklist<'info> {
@@ -16,6 +18,14 @@ pub struct RemoveFromBlacklist<'info> {
     /// CHECK: The stablecoin mint this blacklist entry applies to.
     pub mint: UncheckedAccount<'info>,
 
+    /// The mint's sss-core config, for the event topic.
+    #[account(
+        seeds = [SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = SSS_CORE_PROGRAM_ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
     #[account(
     mut,
     close = blacklister,
@@ -40,6 +50,8 @@ pub fn handler_remove_from_blacklist(ctx: Context<RemoveFromBlacklist>) -> Resul
         mint: mint_key,
         address: address_key,
         removed_by: ctx.accounts.blacklister.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
     });
 
     // Account closure handled by Anchor via `close = blacklister`.
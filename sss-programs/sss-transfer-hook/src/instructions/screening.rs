@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use sss_core::events::ConfigValue;
+use sss_core::state::StablecoinConfig;
+
+use crate::constants::{SSS_CONFIG_SEED, SSS_CORE_PROGRAM_ID};
+use crate::error::TransferHookError;
+use crate::events::{
+    HookConfigField, HookConfigFieldChanged, RiskScoreUpdated, RulePackApplied, ScreeningConfigured,
+};
+use crate::state::{HookConfig, RiskScore, RulePack};
+
+use super::admin_verify::verify_admin_for_mint;
+
+#[derive(Accounts)]
+pub struct ConfigureScreening<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: The sss-core RoleAccount PDA proving the authority has Admin role.
+    /// Verified by checking owner == sss-core program ID and re-deriving the
+    /// expected PDA address from known seeds using the mint key.
+    pub admin_role: UncheckedAccount<'info>,
+
+    /// CHECK: The stablecoin mint this policy applies to.
+    pub mint: UncheckedAccount<'info>,
+
+    /// The mint's sss-core config, for the event topic.
+    #[account(
+        seeds = [SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = SSS_CORE_PROGRAM_ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = HookConfig::SPACE,
+        seeds = [HookConfig::HOOK_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_configure_screening(
+    mut ctx: Context<ConfigureScreening>,
+    screening_provider: Option<Pubkey>,
+    max_risk_score: u8,
+    block_high_risk: bool,
+) -> Result<()> {
+    verify_admin_for_mint(
+        &ctx.accounts.admin_role.to_account_info(),
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.admin.key(),
+    )?;
+
+    write_screening(
+        &mut ctx,
+        screening_provider,
+        max_risk_score,
+        block_high_risk,
+    );
+
+    emit!(ScreeningConfigured {
+        mint: ctx.accounts.mint.key(),
+        screening_provider,
+        max_risk_score,
+        block_high_risk,
+        updated_by: ctx.accounts.admin.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+/// Apply a predefined rule pack: the same as `configure_screening` with the
+/// pack's threshold and block mode.
+pub fn handler_apply_rule_pack(
+    mut ctx: Context<ConfigureScreening>,
+    pack: RulePack,
+    screening_provider: Option<Pubkey>,
+) -> Result<()> {
+    verify_admin_for_mint(
+        &ctx.accounts.admin_role.to_account_info(),
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.admin.key(),
+    )?;
+
+    let (max_risk_score, block_high_risk) = pack.screening_policy();
+    write_screening(
+        &mut ctx,
+        screening_provider,
+        max_risk_score,
+        block_high_risk,
+    );
+
+    emit!(RulePackApplied {
+        mint: ctx.accounts.mint.key(),
+        pack,
+        screening_provider,
+        max_risk_score,
+        block_high_risk,
+        updated_by: ctx.accounts.admin.key(),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
+
+fn write_screening(
+    ctx: &mut Context<ConfigureScreening>,
+    screening_provider: Option<Pubkey>,
+    max_risk_score: u8,
+    block_high_risk: bool,
+) {
+    let hook_config = &mut ctx.accounts.hook_config;
+    let changes = [
+        (
+            HookConfigField::ScreeningProvider,
+            hook_config
+                .screening_provider
+                .map_or(ConfigValue::None, ConfigValue::Pubkey),
+            screening_provider.map_or(ConfigValue::None, ConfigValue::Pubkey),
+        ),
+        (
+            HookConfigField::MaxRiskScore,
+            ConfigValue::U8(hook_config.max_risk_score),
+            ConfigValue::U8(max_risk_score),
+        ),
+        (
+            HookConfigField::BlockHighRisk,
+            ConfigValue::Bool(hook_config.block_high_risk),
+            ConfigValue::Bool(block_high_risk),
+        ),
+    ];
+    hook_config.mint = ctx.accounts.mint.key();
+    hook_config.screening_provider = screening_provider;
+    hook_config.max_risk_score = max_risk_score;
+    hook_config.block_high_risk = block_high_risk;
+    hook_config.bump = ctx.bumps.hook_config;
+
+    for (field, old, new) in changes {
+        if old != new {
+            emit!(HookConfigFieldChanged {
+                mint: ctx.accounts.mint.key(),
+                field,
+                old,
+                new,
+                updated_by: ctx.accounts.admin.key(),
+                config: ctx.accounts.config.key(),
+                mint_index: ctx.accounts.config.mint_index,
+            });
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetRiskScore<'info> {
+    /// The screening provider registered on `hook_config`. Pays for new entries.
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    /// CHECK: The stablecoin mint the score applies to.
+    pub mint: UncheckedAccount<'info>,
+
+    /// The mint's sss-core config, for the event topic.
+    #[account(
+        seeds = [SSS_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = SSS_CORE_PROGRAM_ID,
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [HookConfig::HOOK_CONFIG_SEED, mint.key().as_ref()],
+        bump = hook_config.bump,
+        constraint = hook_config.screening_provider == Some(provider.key())
+            @ TransferHookError::Unauthorized,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+
+    /// CHECK: The wallet being scored. Any valid public key.
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = RiskScore::SPACE,
+        seeds = [RiskScore::RISK_SCORE_SEED, mint.key().as_ref(), wallet.key().as_ref()],
+        bump,
+    )]
+    pub risk_score: Account<'info, RiskScore>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_set_risk_score(ctx: Context<SetRiskScore>, score: u8) -> Result<()> {
+    let entry = &mut ctx.accounts.risk_score;
+    entry.mint = ctx.accounts.mint.key();
+    entry.wallet = ctx.accounts.wallet.key();
+    entry.provider = ctx.accounts.provider.key();
+    entry.score = score;
+    entry.updated_at = Clock::get()?.unix_timestamp;
+    entry.bump = ctx.bumps.risk_score;
+
+    emit!(RiskScoreUpdated {
+        mint: entry.mint,
+        wallet: entry.wallet,
+        provider: entry.provider,
+        score,
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+
+    Ok(())
+}
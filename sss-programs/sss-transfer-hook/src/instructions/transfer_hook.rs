@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::error::TransferHookError;
+use crate::events::{BlockedParty, BlockedPolicy, TransferBlocked, TransferFlagged};
+use crate::state::{AccountHold, BlacklistEntry, HookConfig, RiskScore};
+use sss_core::constants::pause;
 use sss_core::state::StablecoinConfig;
 
 /// Transfer hook validation accounts.
@@ -8,6 +11,16 @@ use sss_core::state::StablecoinConfig;
 /// Token-2022 calls this instruction during every transfer on a mint
 /// configured with this transfer hook. Account ordering is fixed by the
 /// transfer hook interface specification.
+///
+/// Mints whose ExtraAccountMetaList includes the screening accounts also pass
+/// `[hook_config, sender_risk_score, receiver_risk_score]` as remaining
+/// accounts, followed by the source `AccountHold` on lists created after
+/// holds existed. Older lists omit them, so they are read from
+/// `remaining_accounts` rather than declared here.
+///
+/// Every policy account is optional: one that was never created counts as
+/// passing, so no transfer depends on a policy account having been
+/// initialized first. New policies must keep it that way.
 #[derive(Accounts)]
 pub struct TransferHook<'info> {
     /// CHECK: Source token account — validated by Token-2022 before hook invocation.
@@ -42,25 +55,204 @@ pub struct TransferHook<'info> {
     pub config: Account<'info, StablecoinConfig>,
 }
 
-pub fn handler_transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+pub fn handler_transfer_hook<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+) -> Result<()> {
     let sender_bl = &ctx.accounts.sender_blacklist;
     let receiver_bl = &ctx.accounts.receiver_blacklist;
 
     // Blacklist check: if the PDA account exists (has data and is owned by
     // this program), the address is blacklisted. We use PDA existence as a
     // boolean flag — creating the account blacklists, closing it un-blacklists.
-    if !sender_bl.data_is_empty() && sender_bl.owner == ctx.program_id {
-        return Err(TransferHookError::SenderBlacklisted.into());
+    // Scheduled entries only count once their `effective_at` has passed.
+    if is_blacklisted(sender_bl, ctx.program_id)? {
+        return Err(blocked(
+            &ctx,
+            BlockedPolicy::Blacklist,
+            BlockedParty::Sender,
+            amount,
+        ));
+    }
+
+    if is_blacklisted(receiver_bl, ctx.program_id)? {
+        return Err(blocked(
+            &ctx,
+            BlockedPolicy::Blacklist,
+            BlockedParty::Receiver,
+            amount,
+        ));
+    }
+
+    // Emergency pause check: transfers are blocked while the issuer has
+    // paused them, alone or as part of a full pause.
+    if ctx
+        .accounts
+        .config
+        .pauses(pause::TRANSFERS, Clock::get()?.unix_timestamp)
+    {
+        return Err(blocked(
+            &ctx,
+            BlockedPolicy::Pause,
+            BlockedParty::Mint,
+            amount,
+        ));
+    }
+
+    if let Some(source_hold) = ctx.remaining_accounts.get(3) {
+        if is_on_hold(&ctx, source_hold)? {
+            return Err(blocked(
+                &ctx,
+                BlockedPolicy::AccountHold,
+                BlockedParty::Sender,
+                amount,
+            ));
+        }
     }
 
-    if !receiver_bl.data_is_empty() && receiver_bl.owner == ctx.program_id {
-        return Err(TransferHookError::ReceiverBlacklisted.into());
+    if let [hook_config, sender_risk, receiver_risk, ..] = ctx.remaining_accounts {
+        check_screening(&ctx, hook_config, sender_risk, receiver_risk, amount)?;
     }
 
-    // Emergency pause check: transfers are blocked if the protocol is paused.
-    if ctx.accounts.config.paused {
-        return Err(TransferHookError::ProtocolPaused.into());
+    Ok(())
+}
+
+/// Wallet screening: block or flag the transfer when either token account
+/// owner's provider-written risk score exceeds the mint's threshold.
+///
+/// A missing `HookConfig`, an unset provider, a missing `RiskScore` or a
+/// score written by a previously registered provider all count as passing.
+///
+/// A zero-amount transfer, which moves nothing, is only flagged for either
+/// party, so screening never locks a holder out of closing an empty token
+/// account. Emptying an account still moves its balance and is screened
+/// like any other transfer. The blacklist, pause and holds apply to both.
+fn check_screening<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    hook_config: &AccountInfo<'info>,
+    sender_risk: &AccountInfo<'info>,
+    receiver_risk: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    let Some(policy) = load_owned::<HookConfig>(hook_config, ctx.program_id)? else {
+        return Ok(());
+    };
+    require_keys_eq!(policy.mint, mint, TransferHookError::Unauthorized);
+    let Some(provider) = policy.screening_provider else {
+        return Ok(());
+    };
+
+    let sides = [
+        (&ctx.accounts.source, sender_risk, true),
+        (&ctx.accounts.destination, receiver_risk, false),
+    ];
+    for (token_account, risk_account, is_sender) in sides {
+        let Some(entry) = load_owned::<RiskScore>(risk_account, ctx.program_id)? else {
+            continue;
+        };
+        let wallet = token_account_owner(token_account)?;
+        require_keys_eq!(entry.mint, mint, TransferHookError::Unauthorized);
+        require_keys_eq!(entry.wallet, wallet, TransferHookError::Unauthorized);
+        if entry.provider != provider || entry.score <= policy.max_risk_score {
+            continue;
+        }
+
+        if policy.block_high_risk && amount != 0 {
+            let party = if is_sender {
+                BlockedParty::Sender
+            } else {
+                BlockedParty::Receiver
+            };
+            return Err(blocked(ctx, BlockedPolicy::RiskScore, party, amount));
+        }
+        emit!(TransferFlagged {
+            mint,
+            wallet,
+            is_sender,
+            score: entry.score,
+            amount,
+            config: ctx.accounts.config.key(),
+            mint_index: ctx.accounts.config.mint_index,
+        });
     }
 
     Ok(())
 }
+
+/// Whether `hold` is an unexpired `AccountHold` on the source token account.
+/// Only outbound transfers are checked; a held account can still receive.
+fn is_on_hold(ctx: &Context<TransferHook>, hold: &AccountInfo) -> Result<bool> {
+    let Some(hold) = load_owned::<AccountHold>(hold, ctx.program_id)? else {
+        return Ok(false);
+    };
+    require_keys_eq!(
+        hold.mint,
+        ctx.accounts.mint.key(),
+        TransferHookError::Unauthorized
+    );
+    require_keys_eq!(
+        hold.token_account,
+        ctx.accounts.source.key(),
+        TransferHookError::Unauthorized
+    );
+    Ok(hold.in_effect_at(Clock::get()?.unix_timestamp))
+}
+
+/// Whether `entry` is a blacklist PDA of this program that is in effect now.
+/// Entries written before scheduling existed lack `effective_at` and fail to
+/// deserialize; they are in effect immediately.
+fn is_blacklisted(entry: &AccountInfo, program_id: &Pubkey) -> Result<bool> {
+    if entry.data_is_empty() || entry.owner != program_id {
+        return Ok(false);
+    }
+    let data = entry.try_borrow_data()?;
+    match BlacklistEntry::try_deserialize(&mut &data[..]) {
+        Ok(entry) if entry.effective_at.is_some() => {
+            Ok(entry.in_effect_at(Clock::get()?.unix_timestamp))
+        }
+        _ => Ok(true),
+    }
+}
+
+/// Emit `TransferBlocked` for `policy`/`party` and return the matching error.
+fn blocked(
+    ctx: &Context<TransferHook>,
+    policy: BlockedPolicy,
+    party: BlockedParty,
+    amount: u64,
+) -> Error {
+    let error = TransferHookError::for_block(policy, party);
+    emit!(TransferBlocked {
+        mint: ctx.accounts.mint.key(),
+        source: ctx.accounts.source.key(),
+        destination: ctx.accounts.destination.key(),
+        policy,
+        party,
+        amount,
+        error_code: u32::from(error),
+        config: ctx.accounts.config.key(),
+        mint_index: ctx.accounts.config.mint_index,
+    });
+    error.into()
+}
+
+/// Deserialize `info` as `T` if it is an initialized account owned by this
+/// program; `None` if it does not exist.
+fn load_owned<T: AccountDeserialize>(info: &AccountInfo, program_id: &Pubkey) -> Result<Option<T>> {
+    if info.data_is_empty() || info.owner != program_id {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+/// The `owner` field (offset 32) of an SPL token account.
+fn token_account_owner(token_account: &AccountInfo) -> Result<Pubkey> {
+    let data = token_account.try_borrow_data()?;
+    let owner: [u8; 32] = data
+        .get(32..64)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    Ok(Pubkey::new_from_array(owner))
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::state::BlacklistEntry;
+use crate::views::BlacklistEntryView;
+
+/// Read-only: no signer, nothing is written. Entries written before
+/// scheduling existed must be migrated with `migrate_blacklist_entry` first.
+#[derive(Accounts)]
+pub struct ViewBlacklistEntry<'info> {
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+}
+
+pub fn handler_view_blacklist_entry(
+    ctx: Context<ViewBlacklistEntry>,
+) -> Result<BlacklistEntryView> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(ctx.accounts.blacklist_entry.view(now))
+}
@@ -2,14 +2,26 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod error;
+pub mod error_codes;
 pub mod events;
+#[cfg(feature = "program")]
 pub mod instructions;
+pub mod pda;
 pub mod state;
+pub mod views;
 
+#[cfg(feature = "program")]
 use instructions::*;
 
 declare_id!("HookFvKFaoF9KL8TUXUnQK5r2mJoMYdBENu549seRyXW");
 
+// sss-core derives this program's PDAs with its mirrored copy of the ID.
+const _: () = assert!(
+    sss_core::seeds::same_address(&ID, &sss_core::constants::TRANSFER_HOOK_PROGRAM_ID),
+    "sss_core::constants::TRANSFER_HOOK_PROGRAM_ID does not match declare_id!"
+);
+
+#[cfg(feature = "program")]
 #[program]
 pub mod sss_transfer_hook {
     use super::*;
@@ -18,7 +30,14 @@ pub mod sss_transfer_hook {
         instructions::initialize::handler_initialize(ctx)
     }
 
-    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+    pub fn update_extra_account_metas(ctx: Context<UpdateExtraAccountMetas>) -> Result<()> {
+        instructions::initialize::handler_update_extra_account_metas(ctx)
+    }
+
+    pub fn transfer_hook<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferHook<'info>>,
+        amount: u64,
+    ) -> Result<()> {
         instructions::transfer_hook::handler_transfer_hook(ctx, amount)
     }
 
@@ -26,10 +45,92 @@ pub mod sss_transfer_hook {
         instructions::add_to_blacklist::handler_add_to_blacklist(ctx, reason)
     }
 
+    pub fn schedule_blacklist(
+        ctx: Context<AddToBlacklist>,
+        reason: String,
+        effective_at: i64,
+    ) -> Result<()> {
+        instructions::add_to_blacklist::handler_schedule_blacklist(ctx, reason, effective_at)
+    }
+
+    pub fn migrate_blacklist_entry(ctx: Context<MigrateBlacklistEntry>) -> Result<()> {
+        instructions::migrate_blacklist::handler_migrate_blacklist_entry(ctx)
+    }
+
+    pub fn place_account_hold(
+        ctx: Context<PlaceAccountHold>,
+        expires_at: i64,
+        reason_code: u32,
+    ) -> Result<()> {
+        instructions::account_hold::handler_place_account_hold(ctx, expires_at, reason_code)
+    }
+
+    pub fn lift_account_hold(ctx: Context<LiftAccountHold>) -> Result<()> {
+        instructions::account_hold::handler_lift_account_hold(ctx)
+    }
+
+    pub fn open_action_counter(ctx: Context<OpenActionCounter>) -> Result<()> {
+        instructions::action_counter::handler_open_action_counter(ctx)
+    }
+
+    pub fn initialize_event_queue(ctx: Context<InitializeEventQueue>) -> Result<()> {
+        instructions::event_queue::handler_initialize_event_queue(ctx)
+    }
+
+    pub fn initialize_enforcement_queue(
+        ctx: Context<InitializeEnforcementQueue>,
+        actions: u8,
+        notifier: Pubkey,
+    ) -> Result<()> {
+        instructions::enforcement_queue::handler_initialize_enforcement_queue(
+            ctx, actions, notifier,
+        )
+    }
+
+    pub fn complete_enforcement_action(
+        ctx: Context<CompleteEnforcementAction>,
+        seq: u64,
+        action: u8,
+    ) -> Result<()> {
+        instructions::enforcement_queue::handler_complete_enforcement_action(ctx, seq, action)
+    }
+
     pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>) -> Result<()> {
         instructions::remove_from_blacklist::handler_remove_from_blacklist(ctx)
     }
 
+    pub fn configure_screening(
+        ctx: Context<ConfigureScreening>,
+        screening_provider: Option<Pubkey>,
+        max_risk_score: u8,
+        block_high_risk: bool,
+    ) -> Result<()> {
+        instructions::screening::handler_configure_screening(
+            ctx,
+            screening_provider,
+            max_risk_score,
+            block_high_risk,
+        )
+    }
+
+    pub fn apply_rule_pack(
+        ctx: Context<ConfigureScreening>,
+        pack: state::RulePack,
+        screening_provider: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::screening::handler_apply_rule_pack(ctx, pack, screening_provider)
+    }
+
+    pub fn set_risk_score(ctx: Context<SetRiskScore>, score: u8) -> Result<()> {
+        instructions::screening::handler_set_risk_score(ctx, score)
+    }
+
+    pub fn view_blacklist_entry(
+        ctx: Context<ViewBlacklistEntry>,
+    ) -> Result<views::BlacklistEntryView> {
+        instructions::view::handler_view_blacklist_entry(ctx)
+    }
+
     /// Fallback entrypoint for the transfer hook interface.
     ///
     /// Token-2022 invokes the hook using the SPL transfer hook interface
@@ -0,0 +1,11 @@
+//! PDA derivation helpers shared by the on-chain handlers and off-chain clients.
+//!
+//! The derivations live in `sss_core::pda` next to sss-core's own, so both
+//! programs and their clients use one set of seeds; they are re-exported here
+//! under the hook crate for convenience.
+
+pub use sss_core::pda::{
+    find_account_hold_address, find_action_counter_address, find_blacklist_address,
+    find_enforcement_queue_address, find_extra_account_metas_address, find_hook_config_address,
+    find_hook_event_queue_address, find_risk_score_address,
+};
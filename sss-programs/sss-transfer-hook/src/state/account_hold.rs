@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// A compliance hold on one token account. Until `expires_at` the hook
+/// rejects transfers out of `token_account`; transfers in still succeed and
+/// the account is not frozen at the token level.
+#[account]
+pub struct AccountHold {
+    /// The stablecoin mint this hold applies to.
+    pub mint: Pubkey,
+    /// The held token account.
+    pub token_account: Pubkey,
+    /// The blacklister who placed the hold.
+    pub placed_by: Pubkey,
+    /// Unix timestamp when the hold was placed.
+    pub placed_at: i64,
+    /// Unix timestamp from which outbound transfers are allowed again.
+    pub expires_at: i64,
+    /// Issuer-defined compliance reason code (reference, not PII).
+    pub reason_code: u32,
+    /// PDA bump seed.
+    pub bump: u8,
+}
+
+impl AccountHold {
+    pub const ACCOUNT_HOLD_SEED: &[u8] = sss_core::seeds::ACCOUNT_HOLD;
+    /// discriminator(8)
+    /// + mint(32)
+    /// + token_account(32)
+    /// + placed_by(32)
+    /// + placed_at(8)
+    /// + expires_at(8)
+    /// + reason_code(4)
+    /// + bump(1)
+    pub const SPACE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 4 + 1;
+
+    /// Whether the hold blocks outbound transfers at `now`.
+    pub fn in_effect_at(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use sss_core::state::ActionWindow;
+
+/// A blacklister's blacklist additions and holds under a mint in the current
+/// window, checked against the `action_quota` on its sss-core `RoleAccount`.
+/// The hook cannot write to sss-core accounts, so it keeps the count here.
+#[account]
+pub struct ActionCounter {
+    /// The stablecoin mint the count applies to.
+    pub mint: Pubkey,
+    /// The counted blacklister.
+    pub blacklister: Pubkey,
+    /// Actions in the current window.
+    pub window: ActionWindow,
+    /// PDA bump seed.
+    pub bump: u8,
+}
+
+impl ActionCounter {
+    pub const ACTION_COUNTER_SEED: &[u8] = sss_core::seeds::ACTION_COUNTER;
+    /// discriminator(8)
+    /// + mint(32)
+    /// + blacklister(32)
+    /// + window(8 + 4)
+    /// + bump(1)
+    pub const SPACE: usize = 8 + 32 + 32 + ActionWindow::SPACE + 1;
+}
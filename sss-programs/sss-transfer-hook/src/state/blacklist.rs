@@ -14,10 +14,14 @@ pub struct BlacklistEntry {
     pub reason: String,
     /// PDA bump seed.
     pub bump: u8,
+    /// When set, the entry is scheduled: transfers are allowed until this
+    /// Unix timestamp. Kept last so entries written before scheduling
+    /// existed still deserialize up to `bump`.
+    pub effective_at: Option<i64>,
 }
 
 impl BlacklistEntry {
-    pub const BLACKLIST_SEED: &[u8] = b"blacklist";
+    pub const BLACKLIST_SEED: &[u8] = sss_core::seeds::BLACKLIST;
     /// Fixed account space breakdown:
     /// discriminator(8)
     /// + mint(32)
@@ -25,10 +29,22 @@ impl BlacklistEntry {
     /// + added_by(32)
     /// + added_at(8)
     /// + bump(1)
-    pub const BASE_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1;
+    /// + effective_at(9)
+    pub const BASE_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1 + 9;
+
+    /// Offset of the reason's `u32` length prefix.
+    pub const REASON_OFFSET: usize = 8 + 32 + 32 + 32 + 8;
+
+    /// `BASE_SIZE` of entries written before `effective_at` existed.
+    pub const LEGACY_BASE_SIZE: usize = Self::BASE_SIZE - 9;
 
     /// Compute the dynamic account space required for a given reason string.
     pub fn compute_space(reason: &str) -> usize {
         Self::BASE_SIZE + 4 + reason.len()
     }
+
+    /// Whether the entry blocks transfers at `now`.
+    pub fn in_effect_at(&self, now: i64) -> bool {
+        self.effective_at.is_none_or(|at| now >= at)
+    }
 }
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+/// Number of entries kept in an `EnforcementQueue` before the oldest is
+/// overwritten.
+pub const ENFORCEMENT_QUEUE_CAPACITY: usize = 16;
+
+/// Follow-up actions a blacklist addition leaves for keepers, as bit flags.
+pub mod enforcement_action {
+    /// Freeze the blacklisted wallet's token account via sss-core's
+    /// `freeze_blacklisted`. Completed by anyone who shows the account frozen.
+    pub const FREEZE_ACCOUNTS: u8 = 1 << 0;
+    /// Tell partner venues about the addition. Completed by the queue's
+    /// `notifier`.
+    pub const NOTIFY_PARTNERS: u8 = 1 << 1;
+
+    pub const ALL: u8 = FREEZE_ACCOUNTS | NOTIFY_PARTNERS;
+}
+
+/// One blacklisted wallet awaiting follow-up. `pending` holds the
+/// `enforcement_action` bits not yet marked done.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EnforcementEntry {
+    /// 1-based sequence number; `0` marks an empty slot.
+    pub seq: u64,
+    pub address: Pubkey,
+    pub pending: u8,
+    pub enqueued_at: i64,
+}
+
+impl EnforcementEntry {
+    pub const SIZE: usize = 8 + // seq
+        32 + // address
+        1 +  // pending
+        8; // enqueued_at
+}
+
+/// Per-mint work list of blacklist follow-ups. `add_to_blacklist` pushes an
+/// entry with the queue's `actions`; permissionless keepers drain it by
+/// carrying out each action and calling `complete_enforcement_action`.
+///
+/// Like `EventRing`, the oldest entry is overwritten once the queue is full,
+/// pending or not. Keepers that fall more than the capacity behind must
+/// recover the gap from `BlacklistAdded` logs.
+#[account]
+pub struct EnforcementQueue {
+    /// The stablecoin mint this queue belongs to.
+    pub mint: Pubkey,
+    /// `enforcement_action` bits each new entry starts with.
+    pub actions: u8,
+    /// The only signer allowed to complete `NOTIFY_PARTNERS`.
+    pub notifier: Pubkey,
+    /// Total number of entries ever pushed; also the last assigned `seq`.
+    pub head: u64,
+    pub entries: [EnforcementEntry; ENFORCEMENT_QUEUE_CAPACITY],
+    /// PDA bump seed.
+    pub bump: u8,
+}
+
+impl EnforcementQueue {
+    pub const ENFORCEMENT_QUEUE_SEED: &[u8] = sss_core::seeds::ENFORCEMENT_QUEUE;
+    /// discriminator(8)
+    /// + mint(32)
+    /// + actions(1)
+    /// + notifier(32)
+    /// + head(8)
+    /// + entries(EnforcementEntry::SIZE * ENFORCEMENT_QUEUE_CAPACITY)
+    /// + bump(1)
+    pub const SPACE: usize =
+        8 + 32 + 1 + 32 + 8 + EnforcementEntry::SIZE * ENFORCEMENT_QUEUE_CAPACITY + 1;
+
+    /// Append an entry for `address` with the queue's `actions` pending,
+    /// overwriting the oldest once full. Returns the assigned sequence number.
+    pub fn push(&mut self, address: Pubkey, now: i64) -> u64 {
+        let index = (self.head % ENFORCEMENT_QUEUE_CAPACITY as u64) as usize;
+        self.head = self.head.wrapping_add(1);
+        self.entries[index] = EnforcementEntry {
+            seq: self.head,
+            address,
+            pending: self.actions,
+            enqueued_at: now,
+        };
+        self.head
+    }
+
+    /// The entry with sequence number `seq`, if it has not been overwritten.
+    pub fn entry_mut(&mut self, seq: u64) -> Option<&mut EnforcementEntry> {
+        if seq == 0 {
+            return None;
+        }
+        let index = ((seq - 1) % ENFORCEMENT_QUEUE_CAPACITY as u64) as usize;
+        self.entries.get_mut(index).filter(|entry| entry.seq == seq)
+    }
+}
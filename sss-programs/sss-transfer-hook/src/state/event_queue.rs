@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use sss_core::state::EventRing;
+
+/// Per-mint ring buffer of blacklist additions, the hook-side counterpart of
+/// sss-core's `EventQueue`. Optional: mints without one only log the event.
+#[account]
+pub struct HookEventQueue {
+    /// The stablecoin mint this queue belongs to.
+    pub mint: Pubkey,
+    pub ring: EventRing,
+    /// PDA bump seed.
+    pub bump: u8,
+}
+
+impl HookEventQueue {
+    pub const HOOK_EVENT_QUEUE_SEED: &[u8] = sss_core::seeds::HOOK_EVENT_QUEUE;
+    /// discriminator(8)
+    /// + mint(32)
+    /// + ring(EventRing::SIZE)
+    /// + bump(1)
+    pub const SPACE: usize = 8 + 32 + EventRing::SIZE + 1;
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+/// Per-mint hook policy. Optional: mints without a `HookConfig` only get the
+/// blacklist and pause checks.
+#[account]
+pub struct HookConfig {
+    /// The stablecoin mint this policy applies to.
+    pub mint: Pubkey,
+    /// Key allowed to write `RiskScore` entries for this mint. `None`
+    /// disables wallet screening.
+    pub screening_provider: Option<Pubkey>,
+    /// Highest risk score that passes screening. Scores above it are blocked
+    /// or flagged depending on `block_high_risk`.
+    pub max_risk_score: u8,
+    /// Reject high-risk transfers when true; otherwise allow them and emit
+    /// `TransferFlagged`.
+    pub block_high_risk: bool,
+    /// PDA bump seed.
+    pub bump: u8,
+}
+
+impl HookConfig {
+    pub const HOOK_CONFIG_SEED: &[u8] = sss_core::seeds::HOOK_CONFIG;
+    /// discriminator(8)
+    /// + mint(32)
+    /// + screening_provider(1 + 32)
+    /// + max_risk_score(1)
+    /// + block_high_risk(1)
+    /// + bump(1)
+    pub const SPACE: usize = 8 + 32 + 33 + 1 + 1 + 1;
+}
+
+/// Predefined screening policies for common regulatory regimes, applied with
+/// `apply_rule_pack`. A pack only writes the concrete `HookConfig` settings
+/// below; the hook enforces those, not the pack, and a later
+/// `configure_screening` call overrides them. Thresholds assume the provider
+/// scores on a 0-100 scale.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RulePack {
+    /// EU MiCA e-money token: reject transfers with a high-risk party.
+    EuMica,
+    /// US money transmitter: allow high-risk transfers but flag them for
+    /// suspicious-activity review. Sanctioned wallets are blacklisted.
+    UsMoneyTransmitter,
+    /// Closed-loop institutional network: reject anything but low-risk
+    /// counterparties.
+    ClosedLoopInstitutional,
+}
+
+impl RulePack {
+    /// `(max_risk_score, block_high_risk)` written by this pack.
+    pub const fn screening_policy(self) -> (u8, bool) {
+        match self {
+            RulePack::EuMica => (70, true),
+            RulePack::UsMoneyTransmitter => (60, false),
+            RulePack::ClosedLoopInstitutional => (25, true),
+        }
+    }
+}
@@ -1,3 +1,15 @@
+pub mod account_hold;
+pub mod action_counter;
 pub mod blacklist;
+pub mod enforcement_queue;
+pub mod event_queue;
+pub mod hook_config;
+pub mod risk_score;
 
+pub use account_hold::*;
+pub use action_counter::*;
 pub use blacklist::*;
+pub use enforcement_queue::*;
+pub use event_queue::*;
+pub use hook_config::*;
+pub use risk_score::*;
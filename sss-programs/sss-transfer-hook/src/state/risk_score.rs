@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Screening provider's risk score for a wallet under a mint. Written only by
+/// the provider registered on the mint's `HookConfig`.
+#[account]
+pub struct RiskScore {
+    /// The stablecoin mint this score applies to.
+    pub mint: Pubkey,
+    /// The wallet being scored.
+    pub wallet: Pubkey,
+    /// The provider that wrote this score. Scores from a provider that is no
+    /// longer registered are ignored by the hook.
+    pub provider: Pubkey,
+    /// Provider-defined risk score; higher is riskier.
+    pub score: u8,
+    /// Unix timestamp of the last update.
+    pub updated_at: i64,
+    /// PDA bump seed.
+    pub bump: u8,
+}
+
+impl RiskScore {
+    pub const RISK_SCORE_SEED: &[u8] = sss_core::seeds::RISK_SCORE;
+    /// discriminator(8)
+    /// + mint(32)
+    /// + wallet(32)
+    /// + provider(32)
+    /// + score(1)
+    /// + updated_at(8)
+    /// + bump(1)
+    pub const SPACE: usize = 8 + 32 + 32 + 32 + 1 + 8 + 1;
+}
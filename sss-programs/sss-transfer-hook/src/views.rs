@@ -0,0 +1,38 @@
+//! Return type of `view_blacklist_entry`, following the conventions of
+//! `sss_core::views`: Borsh return data, led by `layout_version`, fields only
+//! ever appended.
+
+use anchor_lang::prelude::*;
+
+use crate::state::BlacklistEntry;
+
+pub use sss_core::views::VIEW_LAYOUT_VERSION;
+
+/// A `BlacklistEntry`, with whether it blocks transfers right now.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BlacklistEntryView {
+    pub layout_version: u8,
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub reason: String,
+    pub effective_at: Option<i64>,
+    /// `BlacklistEntry::in_effect_at` the current clock.
+    pub in_effect: bool,
+}
+
+impl BlacklistEntry {
+    pub fn view(&self, now: i64) -> BlacklistEntryView {
+        BlacklistEntryView {
+            layout_version: VIEW_LAYOUT_VERSION,
+            mint: self.mint,
+            address: self.address,
+            added_by: self.added_by,
+            added_at: self.added_at,
+            reason: self.reason.clone(),
+            effective_at: self.effective_at,
+            in_effect: self.in_effect_at(now),
+        }
+    }
+}
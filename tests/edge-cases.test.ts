@@ -8,8 +8,10 @@ import {
   createSss1Mint,
   createTokenAccount,
   deriveRolePda,
+  derivePendingRolePda,
   grantRole,
   fetchConfig,
+  nextSeizureRecordPda,
   getTokenBalance,
   airdropSol,
   ROLE_ADMIN,
@@ -58,21 +60,21 @@ describe('Edge Cases', () => {
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      minter.publicKey,
+      minter,
       ROLE_MINTER,
     );
     burnerRolePda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      minter.publicKey,
+      minter,
       ROLE_BURNER,
     );
     pauserRolePda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      pauser.publicKey,
+      pauser,
       ROLE_PAUSER,
     );
     seizerRolePda = await grantRole(
@@ -154,7 +156,7 @@ describe('Edge Cases', () => {
   it('rejects zero amount seize', async () => {
     try {
       await coreProgram.methods
-        .seize(new BN(0))
+        .seize(new BN(0), 'Court order 2026-CV-1142')
         .accountsPartial({
           seizer: provider.wallet.publicKey,
           config: mintResult.configPda,
@@ -163,6 +165,7 @@ describe('Edge Cases', () => {
           from: recipientAta,
           to: treasuryAta,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
+          seizureRecord: await nextSeizureRecordPda(coreProgram, mintResult.configPda),
         })
         .rpc();
       expect.fail('Should have thrown ZeroAmount');
@@ -171,6 +174,27 @@ describe('Edge Cases', () => {
     }
   });
 
+  it('rejects seize without a compliance reason', async () => {
+    try {
+      await coreProgram.methods
+        .seize(new BN(1), '   ')
+        .accountsPartial({
+          seizer: provider.wallet.publicKey,
+          config: mintResult.configPda,
+          seizerRole: seizerRolePda,
+          mint: mintResult.mint.publicKey,
+          from: recipientAta,
+          to: treasuryAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          seizureRecord: await nextSeizureRecordPda(coreProgram, mintResult.configPda),
+        })
+        .rpc();
+      expect.fail('Should have thrown InvalidSeizureReason');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('InvalidSeizureReason');
+    }
+  });
+
   it('allows mint at exactly supply cap', async () => {
     // Create a new mint with known supply cap for clean testing
     const cappedMint = await createSss1Mint(provider, coreProgram, {
@@ -181,23 +205,13 @@ describe('Edge Cases', () => {
       supplyCap: new BN(1_000_000),
     });
 
-    const [cappedMinterRole] = deriveRolePda(
+    const cappedMinterRole = await grantRole(
+      coreProgram,
       cappedMint.configPda,
-      minter.publicKey,
+      cappedMint.adminRolePda,
+      minter,
       ROLE_MINTER,
-      coreProgram.programId,
     );
-    await coreProgram.methods
-      .grantRole(ROLE_MINTER)
-      .accountsPartial({
-        admin: provider.wallet.publicKey,
-        config: cappedMint.configPda,
-        adminRole: cappedMint.adminRolePda,
-        grantee: minter.publicKey,
-        roleAccount: cappedMinterRole,
-        systemProgram: SystemProgram.programId,
-      })
-      .rpc();
 
     const ata = await createTokenAccount(provider, cappedMint.mint.publicKey, recipient.publicKey);
 
@@ -233,23 +247,13 @@ describe('Edge Cases', () => {
       supplyCap: new BN(500_000),
     });
 
-    const [cappedMinterRole] = deriveRolePda(
+    const cappedMinterRole = await grantRole(
+      coreProgram,
       cappedMint.configPda,
-      minter.publicKey,
+      cappedMint.adminRolePda,
+      minter,
       ROLE_MINTER,
-      coreProgram.programId,
     );
-    await coreProgram.methods
-      .grantRole(ROLE_MINTER)
-      .accountsPartial({
-        admin: provider.wallet.publicKey,
-        config: cappedMint.configPda,
-        adminRole: cappedMint.adminRolePda,
-        grantee: minter.publicKey,
-        roleAccount: cappedMinterRole,
-        systemProgram: SystemProgram.programId,
-      })
-      .rpc();
 
     const ata = await createTokenAccount(provider, cappedMint.mint.publicKey, recipient.publicKey);
 
@@ -388,17 +392,13 @@ describe('Edge Cases', () => {
       coreProgram.programId,
     );
 
-    await coreProgram.methods
-      .grantRole(ROLE_ADMIN)
-      .accountsPartial({
-        admin: provider.wallet.publicKey,
-        config: mintResult.configPda,
-        adminRole: mintResult.adminRolePda,
-        grantee: recipient.publicKey,
-        roleAccount: recipientAdminRolePda,
-        systemProgram: SystemProgram.programId,
-      })
-      .rpc();
+    await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      recipient,
+      ROLE_ADMIN,
+    );
 
     // Verify admin_count is 2
     let config = await fetchConfig(coreProgram, mintResult.configPda);
@@ -438,6 +438,12 @@ describe('Edge Cases', () => {
     }
 
     // 4. Grant back the original admin so other tests pass downstream if they rely on it
+    const [pendingAdminRolePda] = derivePendingRolePda(
+      mintResult.configPda,
+      provider.wallet.publicKey,
+      ROLE_ADMIN,
+      coreProgram.programId,
+    );
     await coreProgram.methods
       .grantRole(ROLE_ADMIN)
       .accountsPartial({
@@ -445,10 +451,20 @@ describe('Edge Cases', () => {
         config: mintResult.configPda,
         adminRole: recipientAdminRolePda,
         grantee: provider.wallet.publicKey,
-        roleAccount: mintResult.adminRolePda,
+        pendingRole: pendingAdminRolePda,
         systemProgram: SystemProgram.programId,
       })
       .signers([recipient])
       .rpc();
+    await coreProgram.methods
+      .acceptRole()
+      .accountsPartial({
+        grantee: provider.wallet.publicKey,
+        config: mintResult.configPda,
+        pendingRole: pendingAdminRolePda,
+        roleAccount: mintResult.adminRolePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
   });
 });
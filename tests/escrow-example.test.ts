@@ -0,0 +1,386 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program, BN } from '@coral-xyz/anchor';
+import { AccountMeta, Keypair, PublicKey } from '@solana/web3.js';
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createTransferCheckedWithTransferHookInstruction,
+} from '@solana/spl-token';
+import { expect } from 'chai';
+import { SssCore } from '../target/types/sss_core';
+import { SssTransferHook } from '../target/types/sss_transfer_hook';
+import { SssEscrowExample } from '../target/types/sss_escrow_example';
+import {
+  createSss1Mint,
+  createSss2Mint,
+  createTokenAccount,
+  grantRole,
+  approveKyc,
+  getTokenBalance,
+  airdropSol,
+  ROLE_MINTER,
+  ROLE_FREEZER,
+  ROLE_PAUSER,
+  ROLE_BLACKLISTER,
+  CreateSss1MintResult,
+  CreateSss2MintResult,
+} from './helpers';
+
+const DECIMALS = 6;
+const ESCROW_AMOUNT = 2_000_000;
+
+describe('Escrow example (third-party integration)', () => {
+  const provider = anchor.AnchorProvider.env();
+  provider.opts.commitment = 'confirmed';
+  anchor.setProvider(provider);
+
+  const coreProgram = anchor.workspace.SssCore as Program<SssCore>;
+  const hookProgram = anchor.workspace.SssTransferHook as Program<SssTransferHook>;
+  const escrowProgram = anchor.workspace.SssEscrowExample as Program<SssEscrowExample>;
+
+  const minter = Keypair.generate();
+  const freezer = Keypair.generate();
+  const pauser = Keypair.generate();
+  const payer = Keypair.generate();
+  const payee = Keypair.generate();
+
+  function deriveEscrowPda(id: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('escrow'), payer.publicKey.toBuffer(), new BN(id).toArrayLike(Buffer, 'le', 8)],
+      escrowProgram.programId,
+    )[0];
+  }
+
+  function vaultFor(mint: PublicKey, escrow: PublicKey): PublicKey {
+    return getAssociatedTokenAddressSync(
+      mint,
+      escrow,
+      true,
+      TOKEN_2022_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+  }
+
+  /**
+   * The transfer hook's extra accounts for `source → destination`, resolved
+   * the same way a wallet would for a direct transfer. Empty for mints
+   * without a hook.
+   */
+  async function hookAccounts(
+    source: PublicKey,
+    mint: PublicKey,
+    destination: PublicKey,
+    authority: PublicKey,
+  ): Promise<AccountMeta[]> {
+    const ix = await createTransferCheckedWithTransferHookInstruction(
+      provider.connection,
+      source,
+      mint,
+      destination,
+      authority,
+      BigInt(ESCROW_AMOUNT),
+      DECIMALS,
+      undefined,
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID,
+    );
+    // [source, mint, destination, authority, ...extra]
+    return ix.keys.slice(4).map((meta) => ({ ...meta, isSigner: false }));
+  }
+
+  async function createEscrow(mint: PublicKey, configPda: PublicKey, id: number, expiresAt: BN) {
+    const escrow = deriveEscrowPda(id);
+    await escrowProgram.methods
+      .createEscrow(new BN(id), new BN(ESCROW_AMOUNT), expiresAt)
+      .accountsPartial({
+        payer: payer.publicKey,
+        payee: payee.publicKey,
+        mint,
+        config: configPda,
+        escrow,
+        vault: vaultFor(mint, escrow),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([payer])
+      .rpc();
+    return escrow;
+  }
+
+  async function deposit(
+    mint: PublicKey,
+    configPda: PublicKey,
+    escrow: PublicKey,
+    from: PublicKey,
+  ) {
+    const vault = vaultFor(mint, escrow);
+    await escrowProgram.methods
+      .deposit()
+      .accountsPartial({
+        payer: payer.publicKey,
+        escrow,
+        mint,
+        config: configPda,
+        payerToken: from,
+        vault,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await hookAccounts(from, mint, vault, payer.publicKey))
+      .signers([payer])
+      .rpc();
+  }
+
+  async function release(mint: PublicKey, configPda: PublicKey, escrow: PublicKey, to: PublicKey) {
+    const vault = vaultFor(mint, escrow);
+    await escrowProgram.methods
+      .release()
+      .accountsPartial({
+        payer: payer.publicKey,
+        escrow,
+        mint,
+        config: configPda,
+        recipient: to,
+        vault,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await hookAccounts(vault, mint, to, escrow))
+      .signers([payer])
+      .rpc();
+  }
+
+  function farFuture(): BN {
+    return new BN(Math.floor(Date.now() / 1000) + 86_400);
+  }
+
+  before(async () => {
+    await airdropSol(provider.connection, minter.publicKey, 5);
+    await airdropSol(provider.connection, freezer.publicKey, 5);
+    await airdropSol(provider.connection, pauser.publicKey, 5);
+    await airdropSol(provider.connection, payer.publicKey, 5);
+  });
+
+  // ─────────────────────────────────────────────────────────────
+  // SSS-1: no hook, the escrow enforces the pause itself
+  // ─────────────────────────────────────────────────────────────
+
+  describe('SSS-1 mint', () => {
+    let mintResult: CreateSss1MintResult;
+    let mint: PublicKey;
+    let payerAta: PublicKey;
+    let payeeAta: PublicKey;
+    let pauserRolePda: PublicKey;
+
+    before(async () => {
+      mintResult = await createSss1Mint(provider, coreProgram, {
+        name: 'Escrow USD',
+        symbol: 'eUSD',
+        uri: 'https://example.com/eusd.json',
+        decimals: DECIMALS,
+        supplyCap: null,
+      });
+      mint = mintResult.mint.publicKey;
+
+      const minterRolePda = await grantRole(
+        coreProgram,
+        mintResult.configPda,
+        mintResult.adminRolePda,
+        minter,
+        ROLE_MINTER,
+      );
+      pauserRolePda = await grantRole(
+        coreProgram,
+        mintResult.configPda,
+        mintResult.adminRolePda,
+        pauser,
+        ROLE_PAUSER,
+      );
+
+      payerAta = await createTokenAccount(provider, mint, payer.publicKey);
+      payeeAta = await createTokenAccount(provider, mint, payee.publicKey);
+
+      await coreProgram.methods
+        .mintTokens(new BN(ESCROW_AMOUNT * 3))
+        .accountsPartial({
+          minter: minter.publicKey,
+          config: mintResult.configPda,
+          minterRole: minterRolePda,
+          mint,
+          to: payerAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          priceUpdate: null,
+        })
+        .signers([minter])
+        .rpc();
+    });
+
+    it('deposits and releases to the payee', async () => {
+      const escrow = await createEscrow(mint, mintResult.configPda, 1, farFuture());
+      await deposit(mint, mintResult.configPda, escrow, payerAta);
+
+      const vaultBalance = await getTokenBalance(provider.connection, vaultFor(mint, escrow));
+      expect(vaultBalance.toString()).to.equal(ESCROW_AMOUNT.toString());
+
+      await release(mint, mintResult.configPda, escrow, payeeAta);
+
+      const payeeBalance = await getTokenBalance(provider.connection, payeeAta);
+      expect(payeeBalance.toString()).to.equal(ESCROW_AMOUNT.toString());
+      expect(await provider.connection.getAccountInfo(escrow)).to.be.null;
+      expect(await provider.connection.getAccountInfo(vaultFor(mint, escrow))).to.be.null;
+    });
+
+    it('rejects a refund before expiry', async () => {
+      const escrow = await createEscrow(mint, mintResult.configPda, 2, farFuture());
+      await deposit(mint, mintResult.configPda, escrow, payerAta);
+
+      try {
+        await escrowProgram.methods
+          .refund()
+          .accountsPartial({
+            payer: payer.publicKey,
+            escrow,
+            mint,
+            config: mintResult.configPda,
+            recipient: payerAta,
+            vault: vaultFor(mint, escrow),
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([payer])
+          .rpc();
+        expect.fail('Should have thrown NotExpired');
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('NotExpired');
+      }
+    });
+
+    it('refuses deposits while the stablecoin is paused', async () => {
+      const escrow = await createEscrow(mint, mintResult.configPda, 3, farFuture());
+
+      await coreProgram.methods
+        .pause()
+        .accountsPartial({
+          pauser: pauser.publicKey,
+          config: mintResult.configPda,
+          pauserRole: pauserRolePda,
+        })
+        .signers([pauser])
+        .rpc();
+
+      try {
+        await deposit(mint, mintResult.configPda, escrow, payerAta);
+        expect.fail('Should have thrown StablecoinPaused');
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal('StablecoinPaused');
+      } finally {
+        await coreProgram.methods
+          .unpause()
+          .accountsPartial({
+            pauser: pauser.publicKey,
+            config: mintResult.configPda,
+            pauserRole: pauserRolePda,
+          })
+          .signers([pauser])
+          .rpc();
+      }
+    });
+  });
+
+  // ─────────────────────────────────────────────────────────────
+  // SSS-2: transfers run through sss-transfer-hook via Token-2022
+  // ─────────────────────────────────────────────────────────────
+
+  describe('SSS-2 mint', () => {
+    let mintResult: CreateSss2MintResult;
+    let mint: PublicKey;
+    let freezerRolePda: PublicKey;
+    let payerAta: PublicKey;
+    let payeeAta: PublicKey;
+
+    async function thaw(tokenAccount: PublicKey) {
+      await coreProgram.methods
+        .thawAccount()
+        .accountsPartial({
+          freezer: freezer.publicKey,
+          config: mintResult.configPda,
+          freezerRole: freezerRolePda,
+          mint,
+          tokenAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([freezer])
+        .rpc();
+    }
+
+    before(async () => {
+      mintResult = await createSss2Mint(provider, coreProgram, hookProgram, {
+        name: 'Compliant Escrow USD',
+        symbol: 'ceUSD',
+        uri: 'https://example.com/ceusd.json',
+        decimals: DECIMALS,
+        supplyCap: null,
+      });
+      mint = mintResult.mint.publicKey;
+
+      const minterRolePda = await grantRole(
+        coreProgram,
+        mintResult.configPda,
+        mintResult.adminRolePda,
+        minter,
+        ROLE_MINTER,
+      );
+      freezerRolePda = await grantRole(
+        coreProgram,
+        mintResult.configPda,
+        mintResult.adminRolePda,
+        freezer,
+        ROLE_FREEZER,
+      );
+      const blacklisterRolePda = await grantRole(
+        coreProgram,
+        mintResult.configPda,
+        mintResult.adminRolePda,
+        provider.wallet.publicKey,
+        ROLE_BLACKLISTER,
+      );
+
+      payerAta = await createTokenAccount(provider, mint, payer.publicKey);
+      payeeAta = await createTokenAccount(provider, mint, payee.publicKey);
+      await thaw(payerAta);
+      await thaw(payeeAta);
+
+      const payerKyc = await approveKyc(
+        coreProgram,
+        mintResult.configPda,
+        blacklisterRolePda,
+        payer.publicKey,
+      );
+      await coreProgram.methods
+        .mintTokens(new BN(ESCROW_AMOUNT))
+        .accountsPartial({
+          minter: minter.publicKey,
+          config: mintResult.configPda,
+          minterRole: minterRolePda,
+          mint,
+          to: payerAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          priceUpdate: null,
+          kycEntry: payerKyc,
+        })
+        .signers([minter])
+        .rpc();
+    });
+
+    it('deposits into a thawed vault and releases through the hook', async () => {
+      const escrow = await createEscrow(mint, mintResult.configPda, 10, farFuture());
+
+      // The vault is created frozen like every SSS-2 account.
+      await thaw(vaultFor(mint, escrow));
+
+      await deposit(mint, mintResult.configPda, escrow, payerAta);
+      await release(mint, mintResult.configPda, escrow, payeeAta);
+
+      const payeeBalance = await getTokenBalance(provider.connection, payeeAta);
+      expect(payeeBalance.toString()).to.equal(ESCROW_AMOUNT.toString());
+      expect(await provider.connection.getAccountInfo(escrow)).to.be.null;
+    });
+  });
+});
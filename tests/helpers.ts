@@ -49,6 +49,29 @@ export function deriveRolePda(
   );
 }
 
+export function deriveRoleDirectoryPda(
+  config: PublicKey,
+  role: number,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-role-dir'), config.toBuffer(), Buffer.from([role])],
+    programId,
+  );
+}
+
+export function derivePendingRolePda(
+  config: PublicKey,
+  address: PublicKey,
+  role: number,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-pending-role'), config.toBuffer(), address.toBuffer(), Buffer.from([role])],
+    programId,
+  );
+}
+
 export function deriveBlacklistPda(
   mint: PublicKey,
   address: PublicKey,
@@ -70,6 +93,136 @@ export function deriveExtraAccountMetasPda(
   );
 }
 
+export function deriveKycPda(
+  config: PublicKey,
+  owner: PublicKey,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-kyc'), config.toBuffer(), owner.toBuffer()],
+    programId,
+  );
+}
+
+export function deriveFreezeExemptionPda(
+  config: PublicKey,
+  owner: PublicKey,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-freeze-exempt'), config.toBuffer(), owner.toBuffer()],
+    programId,
+  );
+}
+
+export function deriveReserveAttestationPda(
+  config: PublicKey,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-reserve-attestation'), config.toBuffer()],
+    programId,
+  );
+}
+
+export function deriveCollateralVaultPda(
+  config: PublicKey,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-collateral-vault'), config.toBuffer()],
+    programId,
+  );
+}
+
+export function deriveCollateralPositionPda(
+  collateralVault: PublicKey,
+  owner: PublicKey,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-collateral-position'), collateralVault.toBuffer(), owner.toBuffer()],
+    programId,
+  );
+}
+
+export function deriveFeeSchedulePda(
+  config: PublicKey,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-fee-schedule'), config.toBuffer()],
+    programId,
+  );
+}
+
+export function deriveFeeTierPda(
+  config: PublicKey,
+  wallet: PublicKey,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-fee-tier'), config.toBuffer(), wallet.toBuffer()],
+    programId,
+  );
+}
+
+export function deriveMintRequestPda(
+  config: PublicKey,
+  requester: PublicKey,
+  id: BN,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('sss-mint-request'),
+      config.toBuffer(),
+      requester.toBuffer(),
+      id.toArrayLike(Buffer, 'le', 8),
+    ],
+    programId,
+  );
+}
+
+export function deriveRedemptionRequestPda(
+  config: PublicKey,
+  holder: PublicKey,
+  id: BN,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('sss-redemption'),
+      config.toBuffer(),
+      holder.toBuffer(),
+      id.toArrayLike(Buffer, 'le', 8),
+    ],
+    programId,
+  );
+}
+
+export function deriveSeizureRecordPda(
+  config: PublicKey,
+  index: BN,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-seizure'), config.toBuffer(), index.toArrayLike(Buffer, 'le', 8)],
+    programId,
+  );
+}
+
+export function deriveWipeRecordPda(
+  config: PublicKey,
+  index: BN,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('sss-wipe'), config.toBuffer(), index.toArrayLike(Buffer, 'le', 8)],
+    programId,
+  );
+}
+
 // Role constants
 export const ROLE_ADMIN = 0;
 export const ROLE_MINTER = 1;
@@ -78,6 +231,20 @@ export const ROLE_PAUSER = 3;
 export const ROLE_BURNER = 4;
 export const ROLE_BLACKLISTER = 5;
 export const ROLE_SEIZER = 6;
+export const ROLE_BRIDGE = 7;
+export const ROLE_METADATA_MANAGER = 8;
+export const ROLE_QUOTA_MANAGER = 9;
+export const ROLE_RESCUER = 10;
+export const ROLE_GUARDIAN = 11;
+export const ROLE_MINT_REQUESTER = 12;
+export const ROLE_ATTESTOR = 13;
+
+// Pause flag bits (`sss_core::constants::pause`)
+export const PAUSE_MINT = 1 << 0;
+export const PAUSE_BURN = 1 << 1;
+export const PAUSE_FREEZE_THAW = 1 << 2;
+export const PAUSE_TRANSFERS = 1 << 3;
+export const PAUSE_ALL = PAUSE_MINT | PAUSE_BURN | PAUSE_FREEZE_THAW | PAUSE_TRANSFERS;
 
 // ─────────────────────────────────────────────────────────────
 // Airdrop
@@ -582,14 +749,27 @@ export async function createSss3Mint(
 // Grant Role Helper
 // ─────────────────────────────────────────────────────────────
 
+/**
+ * Grant a role and accept it on the grantee's behalf.
+ *
+ * A `PublicKey` grantee must be the provider wallet, which signs the accept;
+ * any other grantee is passed as its `Keypair`.
+ */
 export async function grantRole(
   coreProgram: Program<SssCore>,
   configPda: PublicKey,
   adminRolePda: PublicKey,
-  grantee: PublicKey,
+  grantee: PublicKey | Keypair,
   role: number,
 ): Promise<PublicKey> {
-  const [rolePda] = deriveRolePda(configPda, grantee, role, coreProgram.programId);
+  const granteeKey = grantee instanceof Keypair ? grantee.publicKey : grantee;
+  const [rolePda] = deriveRolePda(configPda, granteeKey, role, coreProgram.programId);
+  const [pendingRolePda] = derivePendingRolePda(
+    configPda,
+    granteeKey,
+    role,
+    coreProgram.programId,
+  );
 
   await coreProgram.methods
     .grantRole(role)
@@ -597,15 +777,59 @@ export async function grantRole(
       admin: coreProgram.provider.publicKey!,
       config: configPda,
       adminRole: adminRolePda,
-      grantee,
+      grantee: granteeKey,
+      pendingRole: pendingRolePda,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  await coreProgram.methods
+    .acceptRole()
+    .accountsPartial({
+      grantee: granteeKey,
+      config: configPda,
+      pendingRole: pendingRolePda,
       roleAccount: rolePda,
       systemProgram: SystemProgram.programId,
     })
+    .signers(grantee instanceof Keypair ? [grantee] : [])
     .rpc();
 
   return rolePda;
 }
 
+// ─────────────────────────────────────────────────────────────
+// KYC Approval Helper
+// ─────────────────────────────────────────────────────────────
+
+/**
+ * Approves `owner` for minting on an SSS-2 config. The provider wallet must
+ * hold the Blacklister role. Returns the KYC entry PDA to pass as `kycEntry`
+ * to `mintTokens`.
+ */
+export async function approveKyc(
+  coreProgram: Program<SssCore>,
+  configPda: PublicKey,
+  blacklisterRolePda: PublicKey,
+  owner: PublicKey,
+): Promise<PublicKey> {
+  const [kycPda] = deriveKycPda(configPda, owner, coreProgram.programId);
+
+  await coreProgram.methods
+    .approveKyc(null)
+    .accountsPartial({
+      blacklister: coreProgram.provider.publicKey!,
+      config: configPda,
+      blacklisterRole: blacklisterRolePda,
+      owner,
+      kycEntry: kycPda,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return kycPda;
+}
+
 // ─────────────────────────────────────────────────────────────
 // Fetch Config Helper
 // ─────────────────────────────────────────────────────────────
@@ -614,6 +838,23 @@ export async function fetchConfig(coreProgram: Program<SssCore>, configPda: Publ
   return coreProgram.account.stablecoinConfig.fetch(configPda);
 }
 
+/** The `SeizureRecord` the config's next seizure will create. */
+export async function nextSeizureRecordPda(
+  coreProgram: Program<SssCore>,
+  configPda: PublicKey,
+): Promise<PublicKey> {
+  const config = await fetchConfig(coreProgram, configPda);
+  return deriveSeizureRecordPda(configPda, config.seizureCount, coreProgram.programId)[0];
+}
+
+export async function nextWipeRecordPda(
+  coreProgram: Program<SssCore>,
+  configPda: PublicKey,
+): Promise<PublicKey> {
+  const config = await fetchConfig(coreProgram, configPda);
+  return deriveWipeRecordPda(configPda, config.wipeCount, coreProgram.programId)[0];
+}
+
 // ─────────────────────────────────────────────────────────────
 // Token Balance Helper
 // ─────────────────────────────────────────────────────────────
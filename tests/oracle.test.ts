@@ -30,6 +30,16 @@ const PYTH_RECEIVER_PROGRAM = new PublicKey('rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5
 // Obtained from: sha256("account:PriceUpdateV2")[0..8]
 const PRICE_UPDATE_V2_DISCRIMINATOR = Buffer.from([34, 241, 35, 99, 157, 126, 244, 205]);
 
+// Switchboard On-Demand program (mainnet), the owner of pull feed accounts.
+const SWITCHBOARD_ON_DEMAND_PROGRAM = new PublicKey('SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv');
+
+// Anchor discriminator for PullFeedAccountData
+const PULL_FEED_DISCRIMINATOR = Buffer.from([196, 27, 108, 196, 10, 215, 219, 40]);
+
+// Feed ID of every mock price update. Any non-zero value works: the program
+// rejects the all-zero ID.
+const MOCK_FEED_ID = Buffer.alloc(32, 7);
+
 // ─────────────────────────────────────────────────────────────
 // Mock Oracle Helpers
 // ─────────────────────────────────────────────────────────────
@@ -55,8 +65,14 @@ const PRICE_UPDATE_V2_DISCRIMINATOR = Buffer.from([34, 241, 35, 99, 157, 126, 24
  * @param price - Raw price i64 (e.g. 100_000_000 for $1.00 with exponent -8)
  * @param exponent - Price exponent i32 (typically -8)
  * @param publishTime - Unix timestamp (seconds). Defaults to now if omitted.
+ * @param conf - Confidence interval for both the spot and EMA price. Defaults to 0.
  */
-function buildPriceUpdateV2Data(price: bigint, exponent: number, publishTime?: bigint): Buffer {
+function buildPriceUpdateV2Data(
+  price: bigint,
+  exponent: number,
+  publishTime?: bigint,
+  conf = 0n,
+): Buffer {
   const buf = Buffer.alloc(133);
   let offset = 0;
 
@@ -71,15 +87,16 @@ function buildPriceUpdateV2Data(price: bigint, exponent: number, publishTime?: b
   buf.writeUInt8(1, offset);
   offset += 1;
 
-  // [41..73] feed_id: all zeros (wildcard — matches [0u8;32] in the program)
+  // [41..73] feed_id: the ID the tests pin in the config
+  MOCK_FEED_ID.copy(buf, offset);
   offset += 32;
 
   // [73..81] price (i64 LE)
   buf.writeBigInt64LE(price, offset);
   offset += 8;
 
-  // [81..89] conf (u64 LE) — confidence interval, use 0
-  buf.writeBigUInt64LE(0n, offset);
+  // [81..89] conf (u64 LE) — confidence interval
+  buf.writeBigUInt64LE(conf, offset);
   offset += 8;
 
   // [89..93] exponent (i32 LE)
@@ -100,7 +117,7 @@ function buildPriceUpdateV2Data(price: bigint, exponent: number, publishTime?: b
   offset += 8;
 
   // [117..125] ema_conf (u64 LE)
-  buf.writeBigUInt64LE(0n, offset);
+  buf.writeBigUInt64LE(conf, offset);
   offset += 8;
 
   // [125..133] posted_slot (u64 LE) — use slot 1
@@ -109,10 +126,32 @@ function buildPriceUpdateV2Data(price: bigint, exponent: number, publishTime?: b
   return buf;
 }
 
+/**
+ * Builds a mock Switchboard On-Demand PullFeedAccountData buffer. The account
+ * is zero-copy (repr(C)); only the current result is filled in:
+ *   [0..8]       discriminator
+ *   [2264..2280] result.value (i128, 18 decimals)
+ *   [2280..2296] result.std_dev (i128, 18 decimals)
+ *   [2368..2376] result.slot (u64)
+ * Total size is 8 + 3200 bytes.
+ *
+ * @param value - Price with 18 decimals (e.g. 10n ** 18n for $1.00)
+ * @param slot - Slot the result was signed at
+ */
+function buildPullFeedData(value: bigint, slot: bigint): Buffer {
+  const buf = Buffer.alloc(8 + 3200);
+  PULL_FEED_DISCRIMINATOR.copy(buf, 0);
+  // i128 LE as low and high 64-bit halves
+  buf.writeBigUInt64LE(value & 0xffff_ffff_ffff_ffffn, 2264);
+  buf.writeBigInt64LE(value >> 64n, 2272);
+  buf.writeBigUInt64LE(slot, 2368);
+  return buf;
+}
+
 // ─────────────────────────────────────────────────────────────
 // Part 1: Standard validator tests — no-oracle path
 //
-// With the typed PriceUpdateV2 account, Anchor enforces ownership.
+// The program verifies the owner of whatever oracle account is passed.
 // These tests verify that minting WITHOUT an oracle still works correctly
 // (raw token-unit supply cap).
 // ─────────────────────────────────────────────────────────────
@@ -147,7 +186,7 @@ describe('Oracle — No-Oracle Path (anchor test validator)', () => {
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      minter.publicKey,
+      minter,
       ROLE_MINTER,
     );
 
@@ -204,7 +243,7 @@ describe('Oracle — No-Oracle Path (anchor test validator)', () => {
 //
 // Uses bankrun's setAccount() to inject PriceUpdateV2 accounts with
 // arbitrary data, owned by the Pyth Receiver program.
-// Anchor's Account<'info, PriceUpdateV2> verifies the owner automatically.
+// The program rejects oracle accounts owned by any other program.
 // ─────────────────────────────────────────────────────────────
 
 describe('Oracle — PriceUpdateV2 (bankrun)', () => {
@@ -217,12 +256,17 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
 
   /**
    * Injects a mock PriceUpdateV2 account into the bankrun context.
-   * Owned by the Pyth Receiver program so Anchor's Account<PriceUpdateV2>
-   * can deserialize it.
+   * Owned by the Pyth Receiver program so the program accepts it as a
+   * PriceUpdateV2.
    */
-  function injectMockPriceUpdate(price: bigint, exponent: number, publishTime?: bigint): PublicKey {
+  function injectMockPriceUpdate(
+    price: bigint,
+    exponent: number,
+    publishTime?: bigint,
+    conf?: bigint,
+  ): PublicKey {
     const oracle = Keypair.generate();
-    const data = buildPriceUpdateV2Data(price, exponent, publishTime);
+    const data = buildPriceUpdateV2Data(price, exponent, publishTime, conf);
 
     context.setAccount(oracle.publicKey, {
       lamports: LAMPORTS_PER_SOL,
@@ -234,6 +278,25 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
     return oracle.publicKey;
   }
 
+  /**
+   * Injects a mock Switchboard pull feed account signed at the current slot.
+   * Advances one slot first, since a result signed at slot 0 reads as unset.
+   */
+  async function injectMockPullFeed(value: bigint): Promise<PublicKey> {
+    const feed = Keypair.generate();
+    const slot = (await context.banksClient.getSlot()) + 1n;
+    context.warpToSlot(slot);
+
+    context.setAccount(feed.publicKey, {
+      lamports: LAMPORTS_PER_SOL,
+      data: buildPullFeedData(value, slot),
+      owner: SWITCHBOARD_ON_DEMAND_PROGRAM,
+      executable: false,
+    });
+
+    return feed.publicKey;
+  }
+
   before(async () => {
     context = await startAnchor(
       '',
@@ -287,7 +350,7 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         coreProgram,
         mintResult.configPda,
         mintResult.adminRolePda,
-        minter.publicKey,
+        minter,
         ROLE_MINTER,
       );
 
@@ -297,9 +360,9 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         recipient.publicKey,
       );
 
-      // Pin the oracle feed ID (all-zeros matches mock PriceUpdateV2 feed_id)
+      // Pin the oracle feed ID the mock PriceUpdateV2 accounts carry
       await coreProgram.methods
-        .updateOracleFeed(Array(32).fill(0))
+        .updateOracleFeed(Array.from(MOCK_FEED_ID))
         .accountsPartial({
           admin: provider.wallet.publicKey,
           config: mintResult.configPda,
@@ -308,6 +371,22 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         .rpc();
     });
 
+    it('rejects pinning the all-zero feed ID', async () => {
+      try {
+        await coreProgram.methods
+          .updateOracleFeed(Array(32).fill(0))
+          .accountsPartial({
+            admin: provider.wallet.publicKey,
+            config: mintResult.configPda,
+            adminRole: mintResult.adminRolePda,
+          })
+          .rpc();
+        expect.fail('Should have thrown InvalidOracleFeedId');
+      } catch (err: any) {
+        expect(err.toString()).to.include('InvalidOracleFeedId');
+      }
+    });
+
     it('succeeds minting under oracle-adjusted cap', async () => {
       // Price = $1.00, cap = 1000 USD => token_cap = 1_000_000_000
       const oracleKey = injectMockPriceUpdate(BigInt(100_000_000), -8);
@@ -392,7 +471,7 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         coreProgram,
         capMint.configPda,
         capMint.adminRolePda,
-        minter.publicKey,
+        minter,
         ROLE_MINTER,
       );
 
@@ -402,9 +481,9 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         recipient.publicKey,
       );
 
-      // Pin the oracle feed ID (all-zeros matches mock PriceUpdateV2 feed_id)
+      // Pin the oracle feed ID the mock PriceUpdateV2 accounts carry
       await coreProgram.methods
-        .updateOracleFeed(Array(32).fill(0))
+        .updateOracleFeed(Array.from(MOCK_FEED_ID))
         .accountsPartial({
           admin: provider.wallet.publicKey,
           config: capMint.configPda,
@@ -466,7 +545,7 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         coreProgram,
         capMint.configPda,
         capMint.adminRolePda,
-        minter.publicKey,
+        minter,
         ROLE_MINTER,
       );
 
@@ -476,9 +555,9 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         recipient.publicKey,
       );
 
-      // Pin the oracle feed ID (all-zeros matches mock PriceUpdateV2 feed_id)
+      // Pin the oracle feed ID the mock PriceUpdateV2 accounts carry
       await coreProgram.methods
-        .updateOracleFeed(Array(32).fill(0))
+        .updateOracleFeed(Array.from(MOCK_FEED_ID))
         .accountsPartial({
           admin: provider.wallet.publicKey,
           config: capMint.configPda,
@@ -542,7 +621,7 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         coreProgram,
         mint.configPda,
         mint.adminRolePda,
-        minter.publicKey,
+        minter,
         ROLE_MINTER,
       );
 
@@ -552,9 +631,9 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         recipient.publicKey,
       );
 
-      // Pin the oracle feed ID (all-zeros matches mock PriceUpdateV2 feed_id)
+      // Pin the oracle feed ID the mock PriceUpdateV2 accounts carry
       await coreProgram.methods
-        .updateOracleFeed(Array(32).fill(0))
+        .updateOracleFeed(Array.from(MOCK_FEED_ID))
         .accountsPartial({
           admin: provider.wallet.publicKey,
           config: mint.configPda,
@@ -597,7 +676,7 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         coreProgram,
         mint.configPda,
         mint.adminRolePda,
-        minter.publicKey,
+        minter,
         ROLE_MINTER,
       );
 
@@ -607,9 +686,9 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         recipient.publicKey,
       );
 
-      // Pin the oracle feed ID (all-zeros matches mock PriceUpdateV2 feed_id)
+      // Pin the oracle feed ID the mock PriceUpdateV2 accounts carry
       await coreProgram.methods
-        .updateOracleFeed(Array(32).fill(0))
+        .updateOracleFeed(Array.from(MOCK_FEED_ID))
         .accountsPartial({
           admin: provider.wallet.publicKey,
           config: mint.configPda,
@@ -642,6 +721,142 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
     });
   });
 
+  describe('oracle confidence check', () => {
+    it('rejects a price whose confidence interval is wider than max_confidence_bps', async () => {
+      const mint = await createSss1Mint(provider as any, coreProgram, {
+        name: 'Wide Conf USD',
+        symbol: 'WCUSD',
+        uri: 'https://example.com/wcusd.json',
+        decimals: 6,
+        supplyCap: new BN(1_000),
+      });
+
+      const minterRole = await grantRole(
+        coreProgram,
+        mint.configPda,
+        mint.adminRolePda,
+        minter,
+        ROLE_MINTER,
+      );
+
+      const ata = await createTokenAccount(
+        provider as any,
+        mint.mint.publicKey,
+        recipient.publicKey,
+      );
+
+      const adminAccounts = {
+        admin: provider.wallet.publicKey,
+        config: mint.configPda,
+        adminRole: mint.adminRolePda,
+      };
+      await coreProgram.methods
+        .updateOracleFeed(Array.from(MOCK_FEED_ID))
+        .accountsPartial(adminAccounts)
+        .rpc();
+      // Accept at most 0.5% of the price
+      await coreProgram.methods.updateOracleConfidence(50).accountsPartial(adminAccounts).rpc();
+
+      const mintWith = (priceUpdate: PublicKey) =>
+        coreProgram.methods
+          .mintTokens(new BN(1_000_000))
+          .accountsPartial({
+            minter: minter.publicKey,
+            config: mint.configPda,
+            minterRole: minterRole,
+            mint: mint.mint.publicKey,
+            to: ata,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            priceUpdate,
+          })
+          .signers([minter])
+          .rpc();
+
+      // $1.00 ± $0.005 sits exactly on the limit
+      await mintWith(injectMockPriceUpdate(BigInt(100_000_000), -8, undefined, 500_000n));
+
+      try {
+        await mintWith(injectMockPriceUpdate(BigInt(100_000_000), -8, undefined, 500_001n));
+        expect.fail('Should have thrown OracleConfidenceTooWide');
+      } catch (err: any) {
+        expect(err.toString()).to.include('OracleConfidenceTooWide');
+      }
+    });
+  });
+
+  describe('switchboard pull feed', () => {
+    it('converts the cap with the pinned Switchboard feed', async () => {
+      const mint = await createSss1Mint(provider as any, coreProgram, {
+        name: 'Switchboard USD',
+        symbol: 'SBUSD',
+        uri: 'https://example.com/sbusd.json',
+        decimals: 6,
+        supplyCap: new BN(100),
+      });
+
+      const minterRole = await grantRole(
+        coreProgram,
+        mint.configPda,
+        mint.adminRolePda,
+        minter,
+        ROLE_MINTER,
+      );
+
+      const ata = await createTokenAccount(
+        provider as any,
+        mint.mint.publicKey,
+        recipient.publicKey,
+      );
+
+      // cap = 100 USD, $2.00 => token_cap = 50_000_000 (50 tokens)
+      const feed = await injectMockPullFeed(2n * 10n ** 18n);
+      await coreProgram.methods
+        .updateOracleKind({ switchboard: {} }, Array.from(feed.toBytes()))
+        .accountsPartial({
+          admin: provider.wallet.publicKey,
+          config: mint.configPda,
+          adminRole: mint.adminRolePda,
+        })
+        .rpc();
+
+      const mintWith = (priceUpdate: PublicKey, amount: number) =>
+        coreProgram.methods
+          .mintTokens(new BN(amount))
+          .accountsPartial({
+            minter: minter.publicKey,
+            config: mint.configPda,
+            minterRole: minterRole,
+            mint: mint.mint.publicKey,
+            to: ata,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            priceUpdate,
+          })
+          .signers([minter])
+          .rpc();
+
+      await mintWith(feed, 50_000_000);
+      const config = await fetchConfig(coreProgram, mint.configPda);
+      expect(config.totalMinted.toNumber()).to.equal(50_000_000);
+      expect(config.oracleKind).to.deep.equal({ switchboard: {} });
+
+      try {
+        await mintWith(feed, 1);
+        expect.fail('Should have thrown SupplyCapExceeded');
+      } catch (err: any) {
+        expect(err.toString()).to.include('SupplyCapExceeded');
+      }
+
+      // A feed other than the pinned one is refused, whatever its price
+      const unpinned = await injectMockPullFeed(10n ** 16n);
+      try {
+        await mintWith(unpinned, 1);
+        expect.fail('Should have thrown InvalidOracleData');
+      } catch (err: any) {
+        expect(err.toString()).to.include('InvalidOracleData');
+      }
+    });
+  });
+
   describe('oracle with negative price', () => {
     it('rejects oracle with negative price', async () => {
       const mint = await createSss1Mint(provider as any, coreProgram, {
@@ -656,7 +871,7 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         coreProgram,
         mint.configPda,
         mint.adminRolePda,
-        minter.publicKey,
+        minter,
         ROLE_MINTER,
       );
 
@@ -666,9 +881,9 @@ describe('Oracle — PriceUpdateV2 (bankrun)', () => {
         recipient.publicKey,
       );
 
-      // Pin the oracle feed ID (all-zeros matches mock PriceUpdateV2 feed_id)
+      // Pin the oracle feed ID the mock PriceUpdateV2 accounts carry
       await coreProgram.methods
-        .updateOracleFeed(Array(32).fill(0))
+        .updateOracleFeed(Array.from(MOCK_FEED_ID))
         .accountsPartial({
           admin: provider.wallet.publicKey,
           config: mint.configPda,
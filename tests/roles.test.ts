@@ -1,15 +1,25 @@
 import * as anchor from '@coral-xyz/anchor';
 import { Program, BN } from '@coral-xyz/anchor';
-import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
-import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey, SystemProgram, Transaction } from '@solana/web3.js';
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAssociatedTokenAddressSync,
+} from '@solana/spl-token';
 import { expect } from 'chai';
 import { SssCore } from '../target/types/sss_core';
 import {
   createSss1Mint,
   createTokenAccount,
   deriveRolePda,
+  derivePendingRolePda,
+  deriveRoleDirectoryPda,
+  deriveMintRequestPda,
+  deriveReserveAttestationPda,
   grantRole,
   airdropSol,
+  getTokenBalance,
   ROLE_ADMIN,
   ROLE_MINTER,
   ROLE_FREEZER,
@@ -17,6 +27,11 @@ import {
   ROLE_BURNER,
   ROLE_BLACKLISTER,
   ROLE_SEIZER,
+  ROLE_QUOTA_MANAGER,
+  ROLE_RESCUER,
+  ROLE_GUARDIAN,
+  ROLE_MINT_REQUESTER,
+  ROLE_ATTESTOR,
   CreateSss1MintResult,
 } from './helpers';
 
@@ -65,21 +80,21 @@ describe('Role Management', () => {
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      minter.publicKey,
+      minter,
       ROLE_MINTER,
     );
     const freezerPda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      freezer.publicKey,
+      freezer,
       ROLE_FREEZER,
     );
     const pauserPda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      pauser.publicKey,
+      pauser,
       ROLE_PAUSER,
     );
 
@@ -97,21 +112,21 @@ describe('Role Management', () => {
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      minter.publicKey,
+      minter,
       ROLE_BURNER,
     );
     const blacklisterPda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      freezer.publicKey,
+      freezer,
       ROLE_BLACKLISTER,
     );
     const seizerPda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      pauser.publicKey,
+      pauser,
       ROLE_SEIZER,
     );
 
@@ -129,7 +144,7 @@ describe('Role Management', () => {
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      admin2.publicKey,
+      admin2,
       ROLE_ADMIN,
     );
 
@@ -149,7 +164,7 @@ describe('Role Management', () => {
       ROLE_ADMIN,
       coreProgram.programId,
     );
-    const [targetRolePda] = deriveRolePda(
+    const [targetPendingRolePda] = derivePendingRolePda(
       mintResult.configPda,
       someUser.publicKey,
       ROLE_MINTER,
@@ -164,7 +179,7 @@ describe('Role Management', () => {
           config: mintResult.configPda,
           adminRole: fakeAdminRole,
           grantee: someUser.publicKey,
-          roleAccount: targetRolePda,
+          pendingRole: targetPendingRolePda,
           systemProgram: SystemProgram.programId,
         })
         .signers([nonAdmin])
@@ -425,4 +440,779 @@ describe('Role Management', () => {
       expect(err.error.errorCode.code).to.equal('AccountNotInitialized');
     }
   });
+
+  it('pauser cannot unpause once unpause requires an admin', async () => {
+    const [pauserRolePda] = deriveRolePda(
+      mintResult.configPda,
+      pauser.publicKey,
+      ROLE_PAUSER,
+      coreProgram.programId,
+    );
+    const admin = provider.wallet.publicKey;
+
+    await coreProgram.methods
+      .updateUnpausePolicy(true)
+      .accountsPartial({
+        admin,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+      })
+      .rpc();
+
+    await coreProgram.methods
+      .pause()
+      .accountsPartial({
+        pauser: pauser.publicKey,
+        config: mintResult.configPda,
+        pauserRole: pauserRolePda,
+      })
+      .signers([pauser])
+      .rpc();
+
+    try {
+      await coreProgram.methods
+        .unpause()
+        .accountsPartial({
+          pauser: pauser.publicKey,
+          config: mintResult.configPda,
+          pauserRole: pauserRolePda,
+        })
+        .signers([pauser])
+        .rpc();
+      expect.fail('Pauser should not be able to unpause');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('Unauthorized');
+    }
+
+    await coreProgram.methods
+      .unpause()
+      .accountsPartial({
+        pauser: admin,
+        config: mintResult.configPda,
+        pauserRole: mintResult.adminRolePda,
+      })
+      .rpc();
+
+    const config = await coreProgram.account.stablecoinConfig.fetch(mintResult.configPda);
+    expect(config.pauseFlags).to.equal(0);
+
+    await coreProgram.methods
+      .updateUnpausePolicy(false)
+      .accountsPartial({
+        admin,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+      })
+      .rpc();
+  });
+
+  it('pause lifts on its own once max_pause_secs elapses', async () => {
+    const [pauserRolePda] = deriveRolePda(
+      mintResult.configPda,
+      pauser.publicKey,
+      ROLE_PAUSER,
+      coreProgram.programId,
+    );
+    const admin = provider.wallet.publicKey;
+    const pauseAccounts = {
+      pauser: pauser.publicKey,
+      config: mintResult.configPda,
+      pauserRole: pauserRolePda,
+    };
+
+    await coreProgram.methods
+      .updateMaxPause(2)
+      .accountsPartial({
+        admin,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+      })
+      .rpc();
+
+    await coreProgram.methods.pause().accountsPartial(pauseAccounts).signers([pauser]).rpc();
+    let config = await coreProgram.account.stablecoinConfig.fetch(mintResult.configPda);
+    expect(config.pauseExpiresAt).to.not.equal(null);
+
+    await coreProgram.methods
+      .renewPause()
+      .accountsPartial({
+        admin,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+      })
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 3_000));
+
+    // The expired pause no longer counts, so pausing again is not a double pause.
+    await coreProgram.methods.pause().accountsPartial(pauseAccounts).signers([pauser]).rpc();
+    await coreProgram.methods.unpause().accountsPartial(pauseAccounts).signers([pauser]).rpc();
+    config = await coreProgram.account.stablecoinConfig.fetch(mintResult.configPda);
+    expect(config.pauseFlags).to.equal(0);
+    expect(config.pauseExpiresAt).to.equal(null);
+
+    await coreProgram.methods
+      .updateMaxPause(0)
+      .accountsPartial({
+        admin,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+      })
+      .rpc();
+  });
+
+  it('guardian can pause but never unpause', async () => {
+    const guardian = Keypair.generate();
+    await airdropSol(provider.connection, guardian.publicKey, 1);
+    const { adminCount: adminCountBefore } = await coreProgram.account.stablecoinConfig.fetch(
+      mintResult.configPda,
+    );
+    const guardianRole = await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      guardian,
+      ROLE_GUARDIAN,
+    );
+    const guardianAccounts = {
+      pauser: guardian.publicKey,
+      config: mintResult.configPda,
+      pauserRole: guardianRole,
+    };
+
+    await coreProgram.methods.pause().accountsPartial(guardianAccounts).signers([guardian]).rpc();
+    let config = await coreProgram.account.stablecoinConfig.fetch(mintResult.configPda);
+    expect(config.pauseFlags).to.not.equal(0);
+    expect(config.adminCount).to.equal(adminCountBefore);
+
+    try {
+      await coreProgram.methods
+        .unpause()
+        .accountsPartial(guardianAccounts)
+        .signers([guardian])
+        .rpc();
+      expect.fail('Guardian should not be able to unpause');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('Unauthorized');
+    }
+
+    try {
+      await coreProgram.methods
+        .setPauseFlags(0)
+        .accountsPartial(guardianAccounts)
+        .signers([guardian])
+        .rpc();
+      expect.fail('Guardian should not be able to clear pause flags');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('Unauthorized');
+    }
+
+    const [pauserRolePda] = deriveRolePda(
+      mintResult.configPda,
+      pauser.publicKey,
+      ROLE_PAUSER,
+      coreProgram.programId,
+    );
+    await coreProgram.methods
+      .unpause()
+      .accountsPartial({
+        pauser: pauser.publicKey,
+        config: mintResult.configPda,
+        pauserRole: pauserRolePda,
+      })
+      .signers([pauser])
+      .rpc();
+    config = await coreProgram.account.stablecoinConfig.fetch(mintResult.configPda);
+    expect(config.pauseFlags).to.equal(0);
+  });
+
+  it('holder can renounce their own role', async () => {
+    const departing = Keypair.generate();
+    await airdropSol(provider.connection, departing.publicKey, 1);
+    const rolePda = await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      departing,
+      ROLE_FREEZER,
+    );
+
+    await coreProgram.methods
+      .renounceRole()
+      .accountsPartial({
+        holder: departing.publicKey,
+        config: mintResult.configPda,
+        roleAccount: rolePda,
+      })
+      .signers([departing])
+      .rpc();
+
+    const roleInfo = await provider.connection.getAccountInfo(rolePda);
+    expect(roleInfo).to.be.null;
+  });
+
+  it('authority cannot renounce its admin role', async () => {
+    try {
+      await coreProgram.methods
+        .renounceRole()
+        .accountsPartial({
+          holder: provider.wallet.publicKey,
+          config: mintResult.configPda,
+          roleAccount: mintResult.adminRolePda,
+        })
+        .rpc();
+      expect.fail('Should have thrown AuthorityCannotRenounce');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('AuthorityCannotRenounce');
+    }
+  });
+
+  it('role directory lists every current holder', async () => {
+    const [minterDirPda] = deriveRoleDirectoryPda(
+      mintResult.configPda,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
+    const directory = await coreProgram.account.roleDirectory.fetch(minterDirPda);
+    const holders = directory.holders.map((h) => h.toBase58());
+    expect(holders).to.include(minter.publicKey.toBase58());
+
+    // admin2 was revoked above, so only the original admin remains listed
+    const [adminDirPda] = deriveRoleDirectoryPda(
+      mintResult.configPda,
+      ROLE_ADMIN,
+      coreProgram.programId,
+    );
+    const admins = await coreProgram.account.roleDirectory.fetch(adminDirPda);
+    expect(admins.holders.map((h) => h.toBase58())).to.deep.equal([
+      provider.wallet.publicKey.toBase58(),
+    ]);
+  });
+
+  it('granted role stays pending until the grantee accepts', async () => {
+    const invitee = Keypair.generate();
+    const [pendingPda] = derivePendingRolePda(
+      mintResult.configPda,
+      invitee.publicKey,
+      ROLE_PAUSER,
+      coreProgram.programId,
+    );
+    const [rolePda] = deriveRolePda(
+      mintResult.configPda,
+      invitee.publicKey,
+      ROLE_PAUSER,
+      coreProgram.programId,
+    );
+
+    await coreProgram.methods
+      .grantRole(ROLE_PAUSER)
+      .accountsPartial({
+        admin: provider.wallet.publicKey,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+        grantee: invitee.publicKey,
+        pendingRole: pendingPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const pending = await coreProgram.account.pendingRole.fetch(pendingPda);
+    expect(pending.address.toBase58()).to.equal(invitee.publicKey.toBase58());
+    expect(await provider.connection.getAccountInfo(rolePda)).to.be.null;
+  });
+
+  it('admin can cancel a pending grant', async () => {
+    const invitee = Keypair.generate();
+    const [pendingPda] = derivePendingRolePda(
+      mintResult.configPda,
+      invitee.publicKey,
+      ROLE_FREEZER,
+      coreProgram.programId,
+    );
+
+    await coreProgram.methods
+      .grantRole(ROLE_FREEZER)
+      .accountsPartial({
+        admin: provider.wallet.publicKey,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+        grantee: invitee.publicKey,
+        pendingRole: pendingPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await coreProgram.methods
+      .cancelRoleGrant()
+      .accountsPartial({
+        admin: provider.wallet.publicKey,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+        pendingRole: pendingPda,
+      })
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(pendingPda)).to.be.null;
+  });
+
+  it('quota manager can set a minter quota but not grant roles', async () => {
+    const quotaManager = Keypair.generate();
+    await airdropSol(provider.connection, quotaManager.publicKey, 1);
+    const quotaManagerRole = await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      quotaManager,
+      ROLE_QUOTA_MANAGER,
+    );
+    const [minterRolePda] = deriveRolePda(
+      mintResult.configPda,
+      minter.publicKey,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
+
+    await coreProgram.methods
+      .updateMinter(new BN(5_000))
+      .accountsPartial({
+        admin: quotaManager.publicKey,
+        config: mintResult.configPda,
+        adminRole: quotaManagerRole,
+        minterRole: minterRolePda,
+      })
+      .signers([quotaManager])
+      .rpc();
+
+    const minterRole = await coreProgram.account.roleAccount.fetch(minterRolePda);
+    expect(minterRole.mintQuota!.toNumber()).to.equal(5_000);
+
+    const someone = Keypair.generate();
+    try {
+      await coreProgram.methods
+        .grantRole(ROLE_MINTER)
+        .accountsPartial({
+          admin: quotaManager.publicKey,
+          config: mintResult.configPda,
+          adminRole: quotaManagerRole,
+          grantee: someone.publicKey,
+          pendingRole: derivePendingRolePda(
+            mintResult.configPda,
+            someone.publicKey,
+            ROLE_MINTER,
+            coreProgram.programId,
+          )[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([quotaManager])
+        .rpc();
+      expect.fail('Quota manager should not be able to grant roles');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('ConstraintSeeds');
+    }
+
+    // Lift the quota again for later tests.
+    await coreProgram.methods
+      .updateMinter(null)
+      .accountsPartial({
+        admin: quotaManager.publicKey,
+        config: mintResult.configPda,
+        adminRole: quotaManagerRole,
+        minterRole: minterRolePda,
+      })
+      .signers([quotaManager])
+      .rpc();
+  });
+
+  it('periodic minter quota resets once the period elapses', async () => {
+    const [minterRolePda] = deriveRolePda(
+      mintResult.configPda,
+      minter.publicKey,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
+    const updateAccounts = {
+      admin: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      adminRole: mintResult.adminRolePda,
+      minterRole: minterRolePda,
+    };
+    const mint = (amount: number) =>
+      coreProgram.methods
+        .mintTokens(new BN(amount))
+        .accountsPartial({
+          minter: minter.publicKey,
+          config: mintResult.configPda,
+          minterRole: minterRolePda,
+          mint: mintResult.mint.publicKey,
+          to: recipientAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          priceUpdate: null,
+        })
+        .signers([minter])
+        .rpc();
+
+    await coreProgram.methods.updateMinter(new BN(1_000)).accountsPartial(updateAccounts).rpc();
+    await coreProgram.methods.setMinterQuotaPeriod(2).accountsPartial(updateAccounts).rpc();
+
+    // The first mint under a period starts it, clearing the earlier total.
+    await mint(1_000);
+    try {
+      await mint(1);
+      expect.fail('Quota for this period is used up');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('QuotaExceeded');
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 3_000));
+    await mint(1);
+    const minterRole = await coreProgram.account.roleAccount.fetch(minterRolePda);
+    expect(minterRole.amountMinted.toNumber()).to.equal(1);
+
+    await coreProgram.methods.setMinterQuotaPeriod(null).accountsPartial(updateAccounts).rpc();
+    await coreProgram.methods.updateMinter(null).accountsPartial(updateAccounts).rpc();
+  });
+
+  it('admin can reset an exhausted minter without re-granting', async () => {
+    const [minterRolePda] = deriveRolePda(
+      mintResult.configPda,
+      minter.publicKey,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
+    const updateAccounts = {
+      admin: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      adminRole: mintResult.adminRolePda,
+      minterRole: minterRolePda,
+    };
+    const before = await coreProgram.account.roleAccount.fetch(minterRolePda);
+    await coreProgram.methods
+      .updateMinter(before.amountMinted)
+      .accountsPartial(updateAccounts)
+      .rpc();
+
+    await coreProgram.methods.resetMinterUsage().accountsPartial(updateAccounts).rpc();
+
+    const after = await coreProgram.account.roleAccount.fetch(minterRolePda);
+    expect(after.amountMinted.toNumber()).to.equal(0);
+    expect(after.mintQuota!.toString()).to.equal(before.amountMinted.toString());
+    expect(after.grantedAt.toString()).to.equal(before.grantedAt.toString());
+
+    await coreProgram.methods.updateMinter(null).accountsPartial(updateAccounts).rpc();
+  });
+
+  it('rate limit caps how fast a minter can mint', async () => {
+    const [minterRolePda] = deriveRolePda(
+      mintResult.configPda,
+      minter.publicKey,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
+    const updateAccounts = {
+      admin: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      adminRole: mintResult.adminRolePda,
+      minterRole: minterRolePda,
+    };
+    const mint = (amount: number) =>
+      coreProgram.methods
+        .mintTokens(new BN(amount))
+        .accountsPartial({
+          minter: minter.publicKey,
+          config: mintResult.configPda,
+          minterRole: minterRolePda,
+          mint: mintResult.mint.publicKey,
+          to: recipientAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          priceUpdate: null,
+        })
+        .signers([minter])
+        .rpc();
+
+    await coreProgram.methods
+      .setMinterRateLimit(new BN(1_000), 2)
+      .accountsPartial(updateAccounts)
+      .rpc();
+
+    await mint(1_000);
+    try {
+      await mint(1);
+      expect.fail('Window limit is used up');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('MintRateLimitExceeded');
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 3_000));
+    await mint(1);
+    const minterRole = await coreProgram.account.roleAccount.fetch(minterRolePda);
+    expect(minterRole.windowMinted.toNumber()).to.equal(1);
+
+    await coreProgram.methods.setMinterRateLimit(null, 0).accountsPartial(updateAccounts).rpc();
+  });
+
+  it('burner quota caps what a burner can burn', async () => {
+    const burner = Keypair.generate();
+    await airdropSol(provider.connection, burner.publicKey, 1);
+    const burnerRolePda = await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      burner,
+      ROLE_BURNER,
+    );
+    const [minterRolePda] = deriveRolePda(
+      mintResult.configPda,
+      minter.publicKey,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
+    await coreProgram.methods
+      .mintTokens(new BN(1_000))
+      .accountsPartial({
+        minter: minter.publicKey,
+        config: mintResult.configPda,
+        minterRole: minterRolePda,
+        mint: mintResult.mint.publicKey,
+        to: recipientAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        priceUpdate: null,
+      })
+      .signers([minter])
+      .rpc();
+
+    await coreProgram.methods
+      .updateBurner(new BN(1_000))
+      .accountsPartial({
+        admin: provider.wallet.publicKey,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+        burnerRole: burnerRolePda,
+      })
+      .rpc();
+    const burn = (amount: number) =>
+      coreProgram.methods
+        .burnTokens(new BN(amount))
+        .accountsPartial({
+          burner: burner.publicKey,
+          config: mintResult.configPda,
+          burnerRole: burnerRolePda,
+          mint: mintResult.mint.publicKey,
+          from: recipientAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([burner])
+        .rpc();
+
+    await burn(600);
+    try {
+      await burn(401);
+      expect.fail('Burn beyond the quota should be rejected');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('BurnQuotaExceeded');
+    }
+
+    const burnerRole = await coreProgram.account.roleAccount.fetch(burnerRolePda);
+    expect(burnerRole.amountBurned.toNumber()).to.equal(600);
+    expect(burnerRole.burnQuota!.toNumber()).to.equal(1_000);
+  });
+
+  it('mint requester asks and a minter fulfils', async () => {
+    const requester = Keypair.generate();
+    await airdropSol(provider.connection, requester.publicKey, 1);
+    const requesterRole = await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      requester,
+      ROLE_MINT_REQUESTER,
+    );
+    const [minterRolePda] = deriveRolePda(
+      mintResult.configPda,
+      minter.publicKey,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
+    const [mintRequest] = deriveMintRequestPda(
+      mintResult.configPda,
+      requester.publicKey,
+      new BN(0),
+      coreProgram.programId,
+    );
+    const reference = Array.from(Buffer.alloc(32, 7));
+
+    await coreProgram.methods
+      .requestMint(new BN(0), new BN(250), reference)
+      .accountsPartial({
+        requester: requester.publicKey,
+        config: mintResult.configPda,
+        requesterRole,
+        to: recipientAta,
+        mintRequest,
+      })
+      .signers([requester])
+      .rpc();
+
+    const before = await getTokenBalance(provider.connection, recipientAta);
+    await coreProgram.methods
+      .fulfillMintRequest()
+      .accountsPartial({
+        issuance: {
+          minter: minter.publicKey,
+          config: mintResult.configPda,
+          minterRole: minterRolePda,
+          mint: mintResult.mint.publicKey,
+          to: recipientAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          priceUpdate: null,
+        },
+        mintRequest,
+        requester: requester.publicKey,
+      })
+      .signers([minter])
+      .rpc();
+
+    expect(await getTokenBalance(provider.connection, recipientAta)).to.equal(before + 250n);
+    expect(await provider.connection.getAccountInfo(mintRequest)).to.be.null;
+  });
+
+  it('attestor publishes reserves that gate minting', async () => {
+    const attestor = Keypair.generate();
+    await airdropSol(provider.connection, attestor.publicKey, 1);
+    const attestorRole = await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      attestor,
+      ROLE_ATTESTOR,
+    );
+    const [reserveAttestation] = deriveReserveAttestationPda(
+      mintResult.configPda,
+      coreProgram.programId,
+    );
+    const [minterRolePda] = deriveRolePda(
+      mintResult.configPda,
+      minter.publicKey,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
+    const gateAccounts = {
+      admin: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      adminRole: mintResult.adminRolePda,
+    };
+    const mintWithAttestation = (amount: number) =>
+      coreProgram.methods
+        .mintTokens(new BN(amount))
+        .accountsPartial({
+          minter: minter.publicKey,
+          config: mintResult.configPda,
+          minterRole: minterRolePda,
+          mint: mintResult.mint.publicKey,
+          to: recipientAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          priceUpdate: null,
+          reserveAttestation,
+        })
+        .signers([minter])
+        .rpc();
+
+    const config = await coreProgram.account.stablecoinConfig.fetch(mintResult.configPda);
+    const supply = config.totalMinted.sub(config.totalBurned);
+    const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+    await coreProgram.methods
+      .publishReserveAttestation(supply.addn(100), new BN(now!), Array.from(Buffer.alloc(32, 9)))
+      .accountsPartial({
+        attestor: attestor.publicKey,
+        config: mintResult.configPda,
+        attestorRole,
+        reserveAttestation,
+      })
+      .signers([attestor])
+      .rpc();
+    await coreProgram.methods.updateReserveGate(3_600).accountsPartial(gateAccounts).rpc();
+
+    try {
+      await mintWithAttestation(101);
+      expect.fail('Minting past the attested reserves should be rejected');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('ReservesExceeded');
+    }
+    await mintWithAttestation(100);
+
+    const attestation = await coreProgram.account.reserveAttestation.fetch(reserveAttestation);
+    expect(attestation.attestationCount.toNumber()).to.equal(1);
+    expect(attestation.attestor.toBase58()).to.equal(attestor.publicKey.toBase58());
+
+    await coreProgram.methods.updateReserveGate(null).accountsPartial(gateAccounts).rpc();
+  });
+
+  it('rescuer can recover tokens sent to the config address', async () => {
+    const rescuer = Keypair.generate();
+    await airdropSol(provider.connection, rescuer.publicKey, 1);
+    const rescuerRole = await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      rescuer,
+      ROLE_RESCUER,
+    );
+    const [minterRolePda] = deriveRolePda(
+      mintResult.configPda,
+      minter.publicKey,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
+
+    const configAta = getAssociatedTokenAddressSync(
+      mintResult.mint.publicKey,
+      mintResult.configPda,
+      true,
+      TOKEN_2022_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        createAssociatedTokenAccountInstruction(
+          provider.wallet.publicKey,
+          configAta,
+          mintResult.configPda,
+          mintResult.mint.publicKey,
+          TOKEN_2022_PROGRAM_ID,
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+        ),
+      ),
+    );
+    await coreProgram.methods
+      .mintTokens(new BN(250))
+      .accountsPartial({
+        minter: minter.publicKey,
+        config: mintResult.configPda,
+        minterRole: minterRolePda,
+        mint: mintResult.mint.publicKey,
+        to: configAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        priceUpdate: null,
+      })
+      .signers([minter])
+      .rpc();
+
+    const before = await getTokenBalance(provider.connection, recipientAta);
+    await coreProgram.methods
+      .rescueTokens(new BN(250))
+      .accountsPartial({
+        rescuer: rescuer.publicKey,
+        config: mintResult.configPda,
+        rescuerRole,
+        mint: mintResult.mint.publicKey,
+        from: configAta,
+        to: recipientAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([rescuer])
+      .rpc();
+
+    expect((await getTokenBalance(provider.connection, configAta)).toString()).to.equal('0');
+    const after = await getTokenBalance(provider.connection, recipientAta);
+    expect((after - before).toString()).to.equal('250');
+  });
 });
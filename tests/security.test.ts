@@ -8,8 +8,10 @@ import {
   createSss1Mint,
   createTokenAccount,
   deriveRolePda,
+  derivePendingRolePda,
   grantRole,
   airdropSol,
+  nextSeizureRecordPda,
   ROLE_ADMIN,
   ROLE_MINTER,
   ROLE_FREEZER,
@@ -54,7 +56,7 @@ describe('Security', () => {
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      minter.publicKey,
+      minter,
       ROLE_MINTER,
     );
 
@@ -249,7 +251,7 @@ describe('Security', () => {
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      pauser.publicKey,
+      pauser,
       ROLE_PAUSER,
     );
 
@@ -311,7 +313,7 @@ describe('Security', () => {
       ROLE_ADMIN,
       coreProgram.programId,
     );
-    const [targetRolePda] = deriveRolePda(
+    const [targetPendingRolePda] = derivePendingRolePda(
       mintResult.configPda,
       someUser.publicKey,
       ROLE_MINTER,
@@ -326,7 +328,7 @@ describe('Security', () => {
           config: mintResult.configPda,
           adminRole: attackerAdminRole,
           grantee: someUser.publicKey,
-          roleAccount: targetRolePda,
+          pendingRole: targetPendingRolePda,
           systemProgram: SystemProgram.programId,
         })
         .signers([attacker])
@@ -387,7 +389,7 @@ describe('Security', () => {
 
     try {
       await coreProgram.methods
-        .seize(new BN(100))
+        .seize(new BN(100), 'No authority')
         .accountsPartial({
           seizer: attacker.publicKey,
           config: mintResult.configPda,
@@ -396,6 +398,7 @@ describe('Security', () => {
           from: recipientAta,
           to: attackerAta,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
+          seizureRecord: await nextSeizureRecordPda(coreProgram, mintResult.configPda),
           priceUpdate: null,
         })
         .signers([attacker])
@@ -1,15 +1,35 @@
 import * as anchor from '@coral-xyz/anchor';
 import { Program, BN } from '@coral-xyz/anchor';
-import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
-import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  burn,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+  getOrCreateAssociatedTokenAccount,
+  getTokenMetadata,
+  mintTo,
+} from '@solana/spl-token';
 import { expect } from 'chai';
 import { SssCore } from '../target/types/sss_core';
 import {
   createSss1Mint,
   createTokenAccount,
+  deriveCollateralPositionPda,
+  deriveCollateralVaultPda,
+  deriveConfigPda,
+  deriveFeeSchedulePda,
+  deriveFeeTierPda,
+  deriveFreezeExemptionPda,
+  deriveRedemptionRequestPda,
   deriveRolePda,
   grantRole,
   fetchConfig,
+  nextSeizureRecordPda,
+  nextWipeRecordPda,
   getTokenBalance,
   airdropSol,
   ROLE_MINTER,
@@ -17,6 +37,8 @@ import {
   ROLE_PAUSER,
   ROLE_BURNER,
   ROLE_SEIZER,
+  PAUSE_ALL,
+  PAUSE_MINT,
   CreateSss1MintResult,
 } from './helpers';
 
@@ -34,6 +56,7 @@ describe('SSS-1: Minimal Stablecoin', () => {
   let freezerRolePda: PublicKey;
   let pauserRolePda: PublicKey;
   let seizerRolePda: PublicKey;
+  let treasuryAta: PublicKey;
 
   const minter = Keypair.generate();
   const freezer = Keypair.generate();
@@ -62,18 +85,65 @@ describe('SSS-1: Minimal Stablecoin', () => {
     expect(config.authority.toBase58()).to.equal(provider.wallet.publicKey.toBase58());
     expect(config.mint.toBase58()).to.equal(mintResult.mint.publicKey.toBase58());
     expect(config.preset).to.equal(1);
-    expect(config.paused).to.equal(false);
+    expect(config.pauseFlags).to.equal(0);
     expect(config.supplyCap).to.be.null;
     expect(config.totalMinted.toNumber()).to.equal(0);
     expect(config.totalBurned.toNumber()).to.equal(0);
   });
 
+  it('creates the mint and config in one instruction', async () => {
+    const mint = Keypair.generate();
+    const [configPda] = deriveConfigPda(mint.publicKey, coreProgram.programId);
+
+    await coreProgram.methods
+      .initializeWithMint({
+        preset: 1,
+        name: 'Atomic USD',
+        symbol: 'AUSD',
+        uri: 'https://example.com/ausd.json',
+        decimals: 6,
+        supplyCap: null,
+        enablePermanentDelegate: null,
+        enableTransferHook: null,
+        defaultAccountFrozen: null,
+        oracleFeedId: null,
+      })
+      .accountsPartial({
+        authority: provider.wallet.publicKey,
+        config: configPda,
+        mint: mint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+
+    const config = await fetchConfig(coreProgram, configPda);
+    expect(config.mint.toBase58()).to.equal(mint.publicKey.toBase58());
+    expect(config.name).to.equal('Atomic USD');
+
+    const mintInfo = await getMint(
+      provider.connection,
+      mint.publicKey,
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(mintInfo.mintAuthority?.toBase58()).to.equal(configPda.toBase58());
+    expect(mintInfo.freezeAuthority?.toBase58()).to.equal(configPda.toBase58());
+    const metadata = await getTokenMetadata(
+      provider.connection,
+      mint.publicKey,
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(metadata?.symbol).to.equal('AUSD');
+  });
+
   it('grants minter role', async () => {
     minterRolePda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      minter.publicKey,
+      minter,
       ROLE_MINTER,
     );
 
@@ -128,23 +198,13 @@ describe('SSS-1: Minimal Stablecoin', () => {
     expect(config.supplyCap!.toNumber()).to.equal(500_000);
 
     // Grant minter role
-    const [cappedMinterRole] = deriveRolePda(
+    const cappedMinterRole = await grantRole(
+      coreProgram,
       cappedMint.configPda,
-      minter.publicKey,
+      cappedMint.adminRolePda,
+      minter,
       ROLE_MINTER,
-      coreProgram.programId,
     );
-    await coreProgram.methods
-      .grantRole(ROLE_MINTER)
-      .accountsPartial({
-        admin: provider.wallet.publicKey,
-        config: cappedMint.configPda,
-        adminRole: cappedMint.adminRolePda,
-        grantee: minter.publicKey,
-        roleAccount: cappedMinterRole,
-        systemProgram: SystemProgram.programId,
-      })
-      .rpc();
 
     // Create ATA for recipient
     const ata = await createTokenAccount(provider, cappedMint.mint.publicKey, recipient.publicKey);
@@ -188,13 +248,69 @@ describe('SSS-1: Minimal Stablecoin', () => {
     expect(balance.toString()).to.equal('500000');
   });
 
+  it('mints to several recipients in one batch', async () => {
+    const batchMint = await createSss1Mint(provider, coreProgram, {
+      name: 'Batch USD',
+      symbol: 'BUSD',
+      uri: 'https://example.com/busd.json',
+      decimals: 6,
+      supplyCap: new BN(300_000),
+    });
+    const batchMinterRole = await grantRole(
+      coreProgram,
+      batchMint.configPda,
+      batchMint.adminRolePda,
+      minter,
+      ROLE_MINTER,
+    );
+    const recipients = [
+      await createTokenAccount(provider, batchMint.mint.publicKey, recipient.publicKey),
+      await createTokenAccount(provider, batchMint.mint.publicKey, Keypair.generate().publicKey),
+    ];
+    const mintBatch = (amounts: number[]) =>
+      coreProgram.methods
+        .mintTokensBatch(amounts.map((amount) => new BN(amount)))
+        .accountsPartial({
+          minter: minter.publicKey,
+          config: batchMint.configPda,
+          minterRole: batchMinterRole,
+          mint: batchMint.mint.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          priceUpdate: null,
+        })
+        .remainingAccounts(
+          recipients.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+        )
+        .signers([minter])
+        .rpc();
+
+    // The cap is checked against the batch total, not each amount.
+    try {
+      await mintBatch([200_000, 100_001]);
+      expect.fail('Should have thrown SupplyCapExceeded');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('SupplyCapExceeded');
+    }
+
+    await mintBatch([200_000, 100_000]);
+
+    expect((await getTokenBalance(provider.connection, recipients[0])).toString()).to.equal(
+      '200000',
+    );
+    expect((await getTokenBalance(provider.connection, recipients[1])).toString()).to.equal(
+      '100000',
+    );
+    const config = await fetchConfig(coreProgram, batchMint.configPda);
+    expect(config.totalMinted.toNumber()).to.equal(300_000);
+  });
+
   it('burns tokens', async () => {
     // Grant burner role to the minter keypair (separate from minter role)
     burnerRolePda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      minter.publicKey,
+      minter,
       ROLE_BURNER,
     );
 
@@ -227,13 +343,478 @@ describe('SSS-1: Minimal Stablecoin', () => {
     );
   });
 
+  it('burns from several accounts in one batch', async () => {
+    const sources = [
+      await createTokenAccount(provider, mintResult.mint.publicKey, Keypair.generate().publicKey),
+      await createTokenAccount(provider, mintResult.mint.publicKey, Keypair.generate().publicKey),
+    ];
+    const remainingAccounts = sources.map((pubkey) => ({
+      pubkey,
+      isSigner: false,
+      isWritable: true,
+    }));
+    await coreProgram.methods
+      .mintTokensBatch([new BN(50_000), new BN(50_000)])
+      .accountsPartial({
+        minter: minter.publicKey,
+        config: mintResult.configPda,
+        minterRole: minterRolePda,
+        mint: mintResult.mint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        priceUpdate: null,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([minter])
+      .rpc();
+    const configBefore = await fetchConfig(coreProgram, mintResult.configPda);
+
+    await coreProgram.methods
+      .burnTokensBatch([new BN(20_000), new BN(50_000)])
+      .accountsPartial({
+        burner: minter.publicKey,
+        config: mintResult.configPda,
+        burnerRole: burnerRolePda,
+        mint: mintResult.mint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([minter])
+      .rpc();
+
+    expect((await getTokenBalance(provider.connection, sources[0])).toString()).to.equal('30000');
+    expect((await getTokenBalance(provider.connection, sources[1])).toString()).to.equal('0');
+    const configAfter = await fetchConfig(coreProgram, mintResult.configPda);
+    expect(configAfter.totalBurned.toNumber()).to.equal(
+      configBefore.totalBurned.toNumber() + 70_000,
+    );
+  });
+
+  it('lets a holder redeem their own tokens without a role', async () => {
+    const configBefore = await fetchConfig(coreProgram, mintResult.configPda);
+    const balanceBefore = await getTokenBalance(provider.connection, recipientAta);
+
+    await coreProgram.methods
+      .redeem(new BN(1_000))
+      .accountsPartial({
+        holder: recipient.publicKey,
+        config: mintResult.configPda,
+        mint: mintResult.mint.publicKey,
+        from: recipientAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([recipient])
+      .rpc();
+
+    const balanceAfter = await getTokenBalance(provider.connection, recipientAta);
+    expect(
+      (BigInt(balanceBefore.toString()) - BigInt(balanceAfter.toString())).toString(),
+    ).to.equal('1000');
+    const configAfter = await fetchConfig(coreProgram, mintResult.configPda);
+    expect(configAfter.totalBurned.toNumber()).to.equal(
+      configBefore.totalBurned.toNumber() + 1_000,
+    );
+  });
+
+  it('mints against a collateral vault and withdraws from the depositor's position', async () => {
+    // A plain SPL token stands in for USDC, valued at par.
+    const collateralMint = await createMint(
+      provider.connection,
+      recipient,
+      recipient.publicKey,
+      null,
+      6,
+    );
+    const holderCollateral = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      recipient,
+      collateralMint,
+      recipient.publicKey,
+    );
+    await mintTo(
+      provider.connection,
+      recipient,
+      collateralMint,
+      holderCollateral.address,
+      recipient,
+      3_000,
+    );
+    const [collateralVault] = deriveCollateralVaultPda(
+      mintResult.configPda,
+      coreProgram.programId,
+    );
+    const vaultAccount = getAssociatedTokenAddressSync(
+      collateralMint,
+      collateralVault,
+      true,
+      TOKEN_PROGRAM_ID,
+    );
+    const [position] = deriveCollateralPositionPda(
+      collateralVault,
+      recipient.publicKey,
+      coreProgram.programId,
+    );
+
+    await coreProgram.methods
+      .createCollateralVault(15_000, null)
+      .accountsPartial({
+        admin: provider.wallet.publicKey,
+        config: mintResult.configPda,
+        adminRole: mintResult.adminRolePda,
+        collateralMint,
+        collateralVault,
+        vaultAccount,
+        collateralTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    // SSS-1 has no transfer hook, so the blacklist account is not read.
+    const vaultAccounts = {
+      config: mintResult.configPda,
+      mint: mintResult.mint.publicKey,
+      blacklist: PublicKey.default,
+      collateralVault,
+      collateralMint,
+      vaultAccount,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      collateralTokenProgram: TOKEN_PROGRAM_ID,
+    };
+    try {
+      await coreProgram.methods
+        .depositAndMint(new BN(3_000), new BN(2_001))
+        .accountsPartial({
+          ...vaultAccounts,
+          depositor: recipient.publicKey,
+          position,
+          to: recipientAta,
+          depositorCollateral: holderCollateral.address,
+          priceUpdate: null,
+          kycEntry: null,
+        })
+        .signers([recipient])
+        .rpc();
+      expect.fail('deposit should fall short of the minimum');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('CollateralMintBelowMinimum');
+    }
+
+    const balanceBefore = await getTokenBalance(provider.connection, recipientAta);
+    await coreProgram.methods
+      .depositAndMint(new BN(3_000), new BN(2_000))
+      .accountsPartial({
+        ...vaultAccounts,
+        depositor: recipient.publicKey,
+        position,
+        to: recipientAta,
+        depositorCollateral: holderCollateral.address,
+        priceUpdate: null,
+        kycEntry: null,
+      })
+      .signers([recipient])
+      .rpc();
+    const balanceAfter = await getTokenBalance(provider.connection, recipientAta);
+    expect(
+      (BigInt(balanceAfter.toString()) - BigInt(balanceBefore.toString())).toString(),
+    ).to.equal('2000');
+
+    // Holding the stablecoin is not enough: collateral only comes back out
+    // of the burner's own position.
+    const [strangerPosition] = deriveCollateralPositionPda(
+      collateralVault,
+      minter.publicKey,
+      coreProgram.programId,
+    );
+    try {
+      await coreProgram.methods
+        .burnAndWithdraw(new BN(500))
+        .accountsPartial({
+          ...vaultAccounts,
+          holder: minter.publicKey,
+          position: strangerPosition,
+          from: recipientAta,
+          holderCollateral: holderCollateral.address,
+        })
+        .signers([minter])
+        .rpc();
+      expect.fail('a holder without a position should not withdraw');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('AccountNotInitialized');
+    }
+
+    await coreProgram.methods
+      .burnAndWithdraw(new BN(500))
+      .accountsPartial({
+        ...vaultAccounts,
+        holder: recipient.publicKey,
+        position,
+        from: recipientAta,
+        holderCollateral: holderCollateral.address,
+      })
+      .signers([recipient])
+      .rpc();
+
+    const withdrawn = await getAccount(
+      provider.connection,
+      holderCollateral.address,
+      'confirmed',
+      TOKEN_PROGRAM_ID,
+    );
+    expect(withdrawn.amount.toString()).to.equal('750');
+    const vault = await coreProgram.account.collateralVault.fetch(collateralVault);
+    expect(vault.totalCollateral.toNumber()).to.equal(2_250);
+    expect(vault.totalIssued.toNumber()).to.equal(1_500);
+    const held = await coreProgram.account.collateralPosition.fetch(position);
+    expect(held.collateral.toNumber()).to.equal(2_250);
+    expect(held.issued.toNumber()).to.equal(1_500);
+  });
+
+  it('charges mint and burn fees into the fee treasury', async () => {
+    const adminAccounts = {
+      admin: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      adminRole: mintResult.adminRolePda,
+    };
+    const [feeSchedule] = deriveFeeSchedulePda(mintResult.configPda, coreProgram.programId);
+    const feeTreasury = await createTokenAccount(
+      provider,
+      mintResult.mint.publicKey,
+      Keypair.generate().publicKey,
+    );
+    await coreProgram.methods
+      .setFeeSchedule([{ mintBps: 100, burnBps: 100, swapBps: 0, transferBps: 0 }])
+      .accountsPartial({ ...adminAccounts, feeSchedule })
+      .rpc();
+    await coreProgram.methods.updateFeeTreasury(feeTreasury).accountsPartial(adminAccounts).rpc();
+
+    const mintAccounts = {
+      minter: minter.publicKey,
+      config: mintResult.configPda,
+      minterRole: minterRolePda,
+      mint: mintResult.mint.publicKey,
+      to: recipientAta,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      priceUpdate: null,
+    };
+    try {
+      await coreProgram.methods
+        .mintTokens(new BN(10_000))
+        .accountsPartial(mintAccounts)
+        .signers([minter])
+        .rpc();
+      expect.fail('Minting without the fee schedule should be rejected');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('FeeScheduleRequired');
+    }
+
+    // Leaving out the fee tier account would let an assigned wallet pick
+    // tier 0; the recipient has no assignment, so its empty PDA is passed.
+    const [feeTier] = deriveFeeTierPda(
+      mintResult.configPda,
+      recipient.publicKey,
+      coreProgram.programId,
+    );
+    try {
+      await coreProgram.methods
+        .mintTokens(new BN(10_000))
+        .accountsPartial({ ...mintAccounts, feeSchedule, feeTier: null, feeTreasury })
+        .signers([minter])
+        .rpc();
+      expect.fail('Minting without the fee tier account should be rejected');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('FeeTierRequired');
+    }
+
+    const balanceBefore = await getTokenBalance(provider.connection, recipientAta);
+    await coreProgram.methods
+      .mintTokens(new BN(10_000))
+      .accountsPartial({ ...mintAccounts, feeSchedule, feeTier, feeTreasury })
+      .signers([minter])
+      .rpc();
+    await coreProgram.methods
+      .redeem(new BN(1_000))
+      .accountsPartial({
+        holder: recipient.publicKey,
+        config: mintResult.configPda,
+        mint: mintResult.mint.publicKey,
+        from: recipientAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        feeSchedule,
+        feeTier,
+        feeTreasury,
+      })
+      .signers([recipient])
+      .rpc();
+
+    const balanceAfter = await getTokenBalance(provider.connection, recipientAta);
+    expect(
+      (BigInt(balanceAfter.toString()) - BigInt(balanceBefore.toString())).toString(),
+    ).to.equal('8900');
+    expect((await getTokenBalance(provider.connection, feeTreasury)).toString()).to.equal('110');
+
+    // Exempt: a Burner's burn retires supply for the issuer rather than a
+    // holder redeeming, so the whole amount is burned.
+    await coreProgram.methods
+      .burnTokens(new BN(100))
+      .accountsPartial({
+        burner: minter.publicKey,
+        config: mintResult.configPda,
+        burnerRole: burnerRolePda,
+        mint: mintResult.mint.publicKey,
+        from: recipientAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([minter])
+      .rpc();
+    expect((await getTokenBalance(provider.connection, feeTreasury)).toString()).to.equal('110');
+
+    // Batches cannot charge each recipient's tier, so they are refused.
+    try {
+      await coreProgram.methods
+        .mintTokensBatch([new BN(1_000)])
+        .accountsPartial({
+          minter: minter.publicKey,
+          config: mintResult.configPda,
+          minterRole: minterRolePda,
+          mint: mintResult.mint.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          priceUpdate: null,
+        })
+        .remainingAccounts([{ pubkey: recipientAta, isSigner: false, isWritable: true }])
+        .signers([minter])
+        .rpc();
+      expect.fail('A batch mint should be refused while fees are on');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('FeesRequireSingleMint');
+    }
+
+    await coreProgram.methods.updateFeeTreasury(null).accountsPartial(adminAccounts).rpc();
+  });
+
+  it('counts a direct Token-2022 burn once supply is reconciled', async () => {
+    const configBefore = await fetchConfig(coreProgram, mintResult.configPda);
+
+    await burn(
+      provider.connection,
+      recipient,
+      recipientAta,
+      mintResult.mint.publicKey,
+      recipient,
+      250,
+      [],
+      { commitment: 'confirmed' },
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    await coreProgram.methods
+      .reconcileSupply(true)
+      .accountsPartial({
+        reconciler: provider.wallet.publicKey,
+        config: mintResult.configPda,
+        mint: mintResult.mint.publicKey,
+      })
+      .rpc();
+
+    const configAfter = await fetchConfig(coreProgram, mintResult.configPda);
+    expect(configAfter.totalBurned.toNumber()).to.equal(
+      configBefore.totalBurned.toNumber() + 250,
+    );
+  });
+
+  it('escrows a redemption until a burner settles or it is cancelled', async () => {
+    const requestRedemption = async (id: BN, amount: BN) => {
+      const [redemptionRequest] = deriveRedemptionRequestPda(
+        mintResult.configPda,
+        recipient.publicKey,
+        id,
+        coreProgram.programId,
+      );
+      const escrow = getAssociatedTokenAddressSync(
+        mintResult.mint.publicKey,
+        redemptionRequest,
+        true,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      await coreProgram.methods
+        .requestRedemption(id, amount, Array(32).fill(7))
+        .accountsPartial({
+          holder: recipient.publicKey,
+          config: mintResult.configPda,
+          mint: mintResult.mint.publicKey,
+          from: recipientAta,
+          redemptionRequest,
+          escrow,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([recipient])
+        .rpc();
+      return { redemptionRequest, escrow };
+    };
+    const configBefore = await fetchConfig(coreProgram, mintResult.configPda);
+    const balanceBefore = await getTokenBalance(provider.connection, recipientAta);
+
+    const settled = await requestRedemption(new BN(0), new BN(10_000));
+    expect((await getTokenBalance(provider.connection, settled.escrow)).toString()).to.equal(
+      '10000',
+    );
+    let config = await fetchConfig(coreProgram, mintResult.configPda);
+    expect(config.reservedForRedemption.toNumber()).to.equal(
+      configBefore.reservedForRedemption.toNumber() + 10_000,
+    );
+
+    await coreProgram.methods
+      .settleRedemption()
+      .accountsPartial({
+        burner: minter.publicKey,
+        config: mintResult.configPda,
+        burnerRole: burnerRolePda,
+        mint: mintResult.mint.publicKey,
+        redemptionRequest: settled.redemptionRequest,
+        holder: recipient.publicKey,
+        escrow: settled.escrow,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([minter])
+      .rpc();
+    config = await fetchConfig(coreProgram, mintResult.configPda);
+    expect(config.totalBurned.toNumber()).to.equal(configBefore.totalBurned.toNumber() + 10_000);
+    expect(config.reservedForRedemption.toNumber()).to.equal(
+      configBefore.reservedForRedemption.toNumber(),
+    );
+    expect(await provider.connection.getAccountInfo(settled.escrow)).to.be.null;
+
+    const cancelled = await requestRedemption(new BN(1), new BN(5_000));
+    await coreProgram.methods
+      .cancelRedemption()
+      .accountsPartial({
+        authority: recipient.publicKey,
+        config: mintResult.configPda,
+        burnerRole: null,
+        mint: mintResult.mint.publicKey,
+        redemptionRequest: cancelled.redemptionRequest,
+        holder: recipient.publicKey,
+        escrow: cancelled.escrow,
+        to: recipientAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([recipient])
+      .rpc();
+    const balanceAfter = await getTokenBalance(provider.connection, recipientAta);
+    expect(
+      (BigInt(balanceBefore.toString()) - BigInt(balanceAfter.toString())).toString(),
+    ).to.equal('10000');
+    config = await fetchConfig(coreProgram, mintResult.configPda);
+    expect(config.reservedForRedemption.toNumber()).to.equal(
+      configBefore.reservedForRedemption.toNumber(),
+    );
+    expect(await provider.connection.getAccountInfo(cancelled.redemptionRequest)).to.be.null;
+  });
+
   it('freezes token account', async () => {
     // Grant freezer role
     freezerRolePda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      freezer.publicKey,
+      freezer,
       ROLE_FREEZER,
     );
 
@@ -306,13 +887,78 @@ describe('SSS-1: Minimal Stablecoin', () => {
     expect(balance.toString()).to.equal('800100');
   });
 
+  it('freezes and thaws accounts in a batch', async () => {
+    const accounts = {
+      freezer: freezer.publicKey,
+      config: mintResult.configPda,
+      freezerRole: freezerRolePda,
+      mint: mintResult.mint.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+    };
+    const owners = [recipient.publicKey, Keypair.generate().publicKey];
+    const tokenAccounts = [
+      recipientAta,
+      await createTokenAccount(provider, mintResult.mint.publicKey, owners[1]),
+    ];
+    const isFrozen = async (tokenAccount: PublicKey) =>
+      (await getAccount(provider.connection, tokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID))
+        .isFrozen;
+
+    await coreProgram.methods
+      .freezeAccountsBatch()
+      .accountsPartial(accounts)
+      .remainingAccounts(
+        tokenAccounts.flatMap((tokenAccount, i) => [
+          { pubkey: tokenAccount, isSigner: false, isWritable: true },
+          {
+            pubkey: deriveFreezeExemptionPda(
+              mintResult.configPda,
+              owners[i],
+              coreProgram.programId,
+            )[0],
+            isSigner: false,
+            isWritable: false,
+          },
+        ]),
+      )
+      .signers([freezer])
+      .rpc();
+    for (const tokenAccount of tokenAccounts) {
+      expect(await isFrozen(tokenAccount)).to.equal(true);
+    }
+
+    try {
+      await coreProgram.methods
+        .freezeAccountsBatch()
+        .accountsPartial(accounts)
+        .remainingAccounts([{ pubkey: recipientAta, isSigner: false, isWritable: true }])
+        .signers([freezer])
+        .rpc();
+      expect.fail('A token account without its exemption PDA should be rejected');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('InvalidFreezeBatch');
+    }
+
+    await coreProgram.methods
+      .thawAccountsBatch()
+      .accountsPartial(accounts)
+      .remainingAccounts(
+        tokenAccounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+      )
+      .signers([freezer])
+      .rpc();
+    for (const tokenAccount of tokenAccounts) {
+      expect(await isFrozen(tokenAccount)).to.equal(false);
+    }
+  });
+
   it('pauses all operations', async () => {
     // Grant pauser role
     pauserRolePda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      pauser.publicKey,
+      pauser,
       ROLE_PAUSER,
     );
 
@@ -327,7 +973,7 @@ describe('SSS-1: Minimal Stablecoin', () => {
       .rpc();
 
     const config = await fetchConfig(coreProgram, mintResult.configPda);
-    expect(config.paused).to.equal(true);
+    expect(config.pauseFlags).to.equal(PAUSE_ALL);
   });
 
   it('rejects mint when paused', async () => {
@@ -363,7 +1009,7 @@ describe('SSS-1: Minimal Stablecoin', () => {
       .rpc();
 
     const config = await fetchConfig(coreProgram, mintResult.configPda);
-    expect(config.paused).to.equal(false);
+    expect(config.pauseFlags).to.equal(0);
 
     // Mint should work again
     await coreProgram.methods
@@ -381,6 +1027,67 @@ describe('SSS-1: Minimal Stablecoin', () => {
       .rpc();
   });
 
+  it('halts minting alone while burns keep working', async () => {
+    await coreProgram.methods
+      .setPauseFlags(PAUSE_MINT)
+      .accountsPartial({
+        pauser: pauser.publicKey,
+        config: mintResult.configPda,
+        pauserRole: pauserRolePda,
+      })
+      .signers([pauser])
+      .rpc();
+
+    try {
+      await coreProgram.methods
+        .mintTokens(new BN(100))
+        .accountsPartial({
+          minter: minter.publicKey,
+          config: mintResult.configPda,
+          minterRole: minterRolePda,
+          mint: mintResult.mint.publicKey,
+          to: recipientAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          priceUpdate: null,
+        })
+        .signers([minter])
+        .rpc();
+      expect.fail('Should have thrown Paused');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('Paused');
+    }
+
+    const balanceBefore = await getTokenBalance(provider.connection, recipientAta);
+    await coreProgram.methods
+      .burnTokens(new BN(50))
+      .accountsPartial({
+        burner: minter.publicKey,
+        config: mintResult.configPda,
+        burnerRole: burnerRolePda,
+        mint: mintResult.mint.publicKey,
+        from: recipientAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([minter])
+      .rpc();
+    const balanceAfter = await getTokenBalance(provider.connection, recipientAta);
+    expect(
+      (BigInt(balanceBefore.toString()) - BigInt(balanceAfter.toString())).toString(),
+    ).to.equal('50');
+
+    await coreProgram.methods
+      .setPauseFlags(0)
+      .accountsPartial({
+        pauser: pauser.publicKey,
+        config: mintResult.configPda,
+        pauserRole: pauserRolePda,
+      })
+      .signers([pauser])
+      .rpc();
+    const config = await fetchConfig(coreProgram, mintResult.configPda);
+    expect(config.pauseFlags).to.equal(0);
+  });
+
   it('rejects mint from unauthorized address', async () => {
     const unauthorized = Keypair.generate();
     await airdropSol(provider.connection, unauthorized.publicKey, 2);
@@ -424,7 +1131,7 @@ describe('SSS-1: Minimal Stablecoin', () => {
       ROLE_SEIZER,
     );
 
-    const treasuryAta = await createTokenAccount(
+    treasuryAta = await createTokenAccount(
       provider,
       mintResult.mint.publicKey,
       provider.wallet.publicKey,
@@ -433,8 +1140,9 @@ describe('SSS-1: Minimal Stablecoin', () => {
     const recipientBalBefore = await getTokenBalance(provider.connection, recipientAta);
 
     const seizeAmount = new BN(100_000);
+    const seizureRecord = await nextSeizureRecordPda(coreProgram, mintResult.configPda);
     await coreProgram.methods
-      .seize(seizeAmount)
+      .seize(seizeAmount, 'Court order 2026-CV-1142')
       .accountsPartial({
         seizer: provider.wallet.publicKey,
         config: mintResult.configPda,
@@ -443,9 +1151,15 @@ describe('SSS-1: Minimal Stablecoin', () => {
         from: recipientAta,
         to: treasuryAta,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
+        seizureRecord,
       })
       .rpc();
 
+    const record = await coreProgram.account.seizureRecord.fetch(seizureRecord);
+    expect(record.reason).to.equal('Court order 2026-CV-1142');
+    expect(record.amount.toString()).to.equal('100000');
+    expect(record.approver).to.equal(null);
+
     const recipientBalAfter = await getTokenBalance(provider.connection, recipientAta);
     const treasuryBal = await getTokenBalance(provider.connection, treasuryAta);
 
@@ -455,6 +1169,278 @@ describe('SSS-1: Minimal Stablecoin', () => {
     expect(treasuryBal.toString()).to.equal('100000');
   });
 
+  it('requires a second approval above the large seize threshold', async () => {
+    const admin = provider.wallet.publicKey;
+    const approver = Keypair.generate();
+    await airdropSol(provider.connection, approver.publicKey, 1);
+    const approverRole = await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      approver,
+      ROLE_SEIZER,
+    );
+    const policyAccounts = {
+      admin,
+      config: mintResult.configPda,
+      adminRole: mintResult.adminRolePda,
+    };
+    const seizeAccounts = {
+      seizer: admin,
+      config: mintResult.configPda,
+      seizerRole: seizerRolePda,
+      mint: mintResult.mint.publicKey,
+      from: recipientAta,
+      to: treasuryAta,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      seizureRecord: await nextSeizureRecordPda(coreProgram, mintResult.configPda),
+    };
+    const seizeAmount = new BN(100_000);
+    const reason = 'Court order 2026-CV-1142';
+
+    await coreProgram.methods
+      .updateSeizePolicy(new BN(50_000))
+      .accountsPartial(policyAccounts)
+      .rpc();
+
+    try {
+      await coreProgram.methods.seize(seizeAmount, reason).accountsPartial(seizeAccounts).rpc();
+      expect.fail('Seizure above the threshold should need an approval');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('LargeSeizeApprovalRequired');
+    }
+
+    const [seizeApproval] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('sss-seize-approval'),
+        mintResult.configPda.toBuffer(),
+        approver.publicKey.toBuffer(),
+        new BN(0).toArrayLike(Buffer, 'le', 8),
+      ],
+      coreProgram.programId,
+    );
+    await coreProgram.methods
+      .approveSeize(new BN(0), admin, seizeAmount)
+      .accountsPartial({
+        approver: approver.publicKey,
+        config: mintResult.configPda,
+        approverRole,
+        from: recipientAta,
+        to: treasuryAta,
+        seizeApproval,
+      })
+      .signers([approver])
+      .rpc();
+
+    const treasuryBalBefore = await getTokenBalance(provider.connection, treasuryAta);
+    await coreProgram.methods
+      .seize(seizeAmount, reason)
+      .accountsPartial({ ...seizeAccounts, seizeApproval })
+      .rpc();
+    const treasuryBalAfter = await getTokenBalance(provider.connection, treasuryAta);
+    expect(
+      (BigInt(treasuryBalAfter.toString()) - BigInt(treasuryBalBefore.toString())).toString(),
+    ).to.equal('100000');
+    expect(await provider.connection.getAccountInfo(seizeApproval)).to.equal(null);
+    const record = await coreProgram.account.seizureRecord.fetch(seizeAccounts.seizureRecord);
+    expect(record.approver?.toBase58()).to.equal(approver.publicKey.toBase58());
+
+    await coreProgram.methods.updateSeizePolicy(null).accountsPartial(policyAccounts).rpc();
+  });
+
+  it('only seizes into the configured treasury', async () => {
+    const policyAccounts = {
+      admin: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      adminRole: mintResult.adminRolePda,
+    };
+    const seizeAccounts = {
+      seizer: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      seizerRole: seizerRolePda,
+      mint: mintResult.mint.publicKey,
+      from: recipientAta,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      seizureRecord: await nextSeizureRecordPda(coreProgram, mintResult.configPda),
+    };
+    const reason = 'Court order 2026-CV-1142';
+    const elsewhere = await createTokenAccount(
+      provider,
+      mintResult.mint.publicKey,
+      Keypair.generate().publicKey,
+    );
+
+    await coreProgram.methods
+      .updateTreasury(provider.wallet.publicKey)
+      .accountsPartial(policyAccounts)
+      .rpc();
+
+    try {
+      await coreProgram.methods
+        .seize(new BN(1_000), reason)
+        .accountsPartial({ ...seizeAccounts, to: elsewhere })
+        .rpc();
+      expect.fail('Seizure outside the treasury should be rejected');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('SeizeDestinationNotTreasury');
+    }
+
+    await coreProgram.methods
+      .seize(new BN(1_000), reason)
+      .accountsPartial({ ...seizeAccounts, to: treasuryAta })
+      .rpc();
+
+    await coreProgram.methods.updateTreasury(null).accountsPartial(policyAccounts).rpc();
+  });
+
+  it('seizes only frozen accounts when the freeze policy is on', async () => {
+    const policyAccounts = {
+      admin: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      adminRole: mintResult.adminRolePda,
+    };
+    const freezeAccounts = {
+      freezer: freezer.publicKey,
+      config: mintResult.configPda,
+      freezerRole: freezerRolePda,
+      mint: mintResult.mint.publicKey,
+      tokenAccount: recipientAta,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+    };
+    const seizeAccounts = {
+      seizer: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      seizerRole: seizerRolePda,
+      mint: mintResult.mint.publicKey,
+      from: recipientAta,
+      to: treasuryAta,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      seizureRecord: await nextSeizureRecordPda(coreProgram, mintResult.configPda),
+    };
+    const reason = 'Court order 2026-CV-1142';
+
+    await coreProgram.methods.updateSeizeFreezePolicy(true).accountsPartial(policyAccounts).rpc();
+
+    try {
+      await coreProgram.methods.seize(new BN(1_000), reason).accountsPartial(seizeAccounts).rpc();
+      expect.fail('Seizing an unfrozen account should be rejected');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('SeizeRequiresFrozenAccount');
+    }
+
+    await coreProgram.methods
+      .freezeAccount()
+      .accountsPartial(freezeAccounts)
+      .signers([freezer])
+      .rpc();
+    await coreProgram.methods.seize(new BN(1_000), reason).accountsPartial(seizeAccounts).rpc();
+
+    const source = await getAccount(
+      provider.connection,
+      recipientAta,
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(source.isFrozen).to.equal(true);
+
+    await coreProgram.methods
+      .thawAccount()
+      .accountsPartial(freezeAccounts)
+      .signers([freezer])
+      .rpc();
+    await coreProgram.methods.updateSeizeFreezePolicy(false).accountsPartial(policyAccounts).rpc();
+  });
+
+  it('wipes the whole balance of a frozen account', async () => {
+    const sanctioned = await createTokenAccount(
+      provider,
+      mintResult.mint.publicKey,
+      Keypair.generate().publicKey,
+    );
+    await coreProgram.methods
+      .mintTokens(new BN(40_000))
+      .accountsPartial({
+        minter: minter.publicKey,
+        config: mintResult.configPda,
+        minterRole: minterRolePda,
+        mint: mintResult.mint.publicKey,
+        to: sanctioned,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        priceUpdate: null,
+      })
+      .signers([minter])
+      .rpc();
+    const wipeAccounts = {
+      wiper: provider.wallet.publicKey,
+      config: mintResult.configPda,
+      wiperRole: mintResult.adminRolePda,
+      mint: mintResult.mint.publicKey,
+      tokenAccount: sanctioned,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      eventQueue: null,
+      wipeRecord: await nextWipeRecordPda(coreProgram, mintResult.configPda),
+    };
+    const reason = 'OFAC SDN designation';
+
+    try {
+      await coreProgram.methods.wipeAccount(reason).accountsPartial(wipeAccounts).rpc();
+      expect.fail('Wiping an unfrozen account should be rejected');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('WipeRequiresFrozenAccount');
+    }
+
+    await coreProgram.methods
+      .freezeAccount()
+      .accountsPartial({
+        freezer: freezer.publicKey,
+        config: mintResult.configPda,
+        freezerRole: freezerRolePda,
+        mint: mintResult.mint.publicKey,
+        tokenAccount: sanctioned,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([freezer])
+      .rpc();
+    const configBefore = await fetchConfig(coreProgram, mintResult.configPda);
+    await coreProgram.methods.wipeAccount(reason).accountsPartial(wipeAccounts).rpc();
+
+    const account = await getAccount(
+      provider.connection,
+      sanctioned,
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(account.amount.toString()).to.equal('0');
+    expect(account.isFrozen).to.equal(true);
+    const configAfter = await fetchConfig(coreProgram, mintResult.configPda);
+    expect(configAfter.totalBurned.toNumber()).to.equal(
+      configBefore.totalBurned.toNumber() + 40_000,
+    );
+    const record = await coreProgram.account.wipeRecord.fetch(wipeAccounts.wipeRecord);
+    expect(record.amount.toNumber()).to.equal(40_000);
+    expect(record.reason).to.equal(reason);
+  });
+
+  it('renames the stablecoin while keeping its URI', async () => {
+    const before = await fetchConfig(coreProgram, mintResult.configPda);
+
+    await coreProgram.methods
+      .updateMetadata('Renamed Dollar', 'RUSD', null)
+      .accountsPartial({
+        authority: provider.wallet.publicKey,
+        config: mintResult.configPda,
+        authorityRole: mintResult.adminRolePda,
+        mint: mintResult.mint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const after = await fetchConfig(coreProgram, mintResult.configPda);
+    expect(after.name).to.equal('Renamed Dollar');
+    expect(after.symbol).to.equal('RUSD');
+    expect(after.uri).to.equal(before.uri);
+  });
+
   it('revokes minter role', async () => {
     await coreProgram.methods
       .revokeRole()
@@ -512,4 +1498,62 @@ describe('SSS-1: Minimal Stablecoin', () => {
       expect(err.error.errorCode.code).to.equal('AccountNotInitialized');
     }
   });
+
+  it('sunsets and closes a stablecoin with no supply', async () => {
+    const retired = await createSss1Mint(provider, coreProgram, {
+      name: 'Retired USD',
+      symbol: 'RUSD',
+      uri: '',
+      decimals: 6,
+      supplyCap: null,
+    });
+
+    await coreProgram.methods
+      .sunset()
+      .accountsPartial({
+        admin: provider.wallet.publicKey,
+        config: retired.configPda,
+        adminRole: retired.adminRolePda,
+        pendingAction: null,
+      })
+      .rpc();
+    const config = await fetchConfig(coreProgram, retired.configPda);
+    expect(config.sunsetAt).to.not.be.null;
+
+    try {
+      await coreProgram.methods
+        .sunset()
+        .accountsPartial({
+          admin: provider.wallet.publicKey,
+          config: retired.configPda,
+          adminRole: retired.adminRolePda,
+          pendingAction: null,
+        })
+        .rpc();
+      expect.fail('Should not sunset twice');
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal('AlreadySunset');
+    }
+
+    await coreProgram.methods
+      .closeConfig()
+      .accountsPartial({
+        admin: provider.wallet.publicKey,
+        config: retired.configPda,
+        adminRole: retired.adminRolePda,
+        mint: retired.mint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(retired.configPda)).to.be.null;
+    const mintInfo = await getMint(
+      provider.connection,
+      retired.mint.publicKey,
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(mintInfo.mintAuthority).to.be.null;
+    expect(mintInfo.freezeAuthority).to.be.null;
+  });
 });
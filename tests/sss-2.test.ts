@@ -15,7 +15,9 @@ import {
   createTokenAccount,
   deriveBlacklistPda,
   grantRole,
+  approveKyc,
   fetchConfig,
+  nextSeizureRecordPda,
   getTokenBalance,
   airdropSol,
   ROLE_MINTER,
@@ -69,7 +71,7 @@ describe('SSS-2: Compliant Stablecoin', () => {
     const config = await fetchConfig(coreProgram, mintResult.configPda);
 
     expect(config.preset).to.equal(2);
-    expect(config.paused).to.equal(false);
+    expect(config.pauseFlags).to.equal(0);
     expect(config.mint.toBase58()).to.equal(mintResult.mint.publicKey.toBase58());
 
     // Verify extra account metas was initialized
@@ -80,21 +82,30 @@ describe('SSS-2: Compliant Stablecoin', () => {
   });
 
   it('transfers tokens between non-blacklisted accounts', async () => {
-    // Grant minter and freezer roles
+    // Grant minter, freezer and blacklister roles
     minterRolePda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      minter.publicKey,
+      minter,
       ROLE_MINTER,
     );
     freezerRolePda = await grantRole(
       coreProgram,
       mintResult.configPda,
       mintResult.adminRolePda,
-      freezer.publicKey,
+      freezer,
       ROLE_FREEZER,
     );
+    // The provider wallet is the compliance officer: it approves KYC for
+    // mint destinations and manages the blacklist.
+    blacklisterRolePda = await grantRole(
+      coreProgram,
+      mintResult.configPda,
+      mintResult.adminRolePda,
+      provider.wallet.publicKey,
+      ROLE_BLACKLISTER,
+    );
 
     // Create token accounts (they start frozen due to DefaultAccountState)
     senderAta = await createTokenAccount(provider, mintResult.mint.publicKey, sender.publicKey);
@@ -127,7 +138,13 @@ describe('SSS-2: Compliant Stablecoin', () => {
       .signers([freezer])
       .rpc();
 
-    // Mint tokens to sender
+    // Mint tokens to sender (SSS-2 requires a KYC approval for the owner)
+    const senderKyc = await approveKyc(
+      coreProgram,
+      mintResult.configPda,
+      blacklisterRolePda,
+      sender.publicKey,
+    );
     await coreProgram.methods
       .mintTokens(new BN(10_000_000))
       .accountsPartial({
@@ -138,6 +155,7 @@ describe('SSS-2: Compliant Stablecoin', () => {
         to: senderAta,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         priceUpdate: null,
+        kycEntry: senderKyc,
       })
       .signers([minter])
       .rpc();
@@ -167,15 +185,6 @@ describe('SSS-2: Compliant Stablecoin', () => {
   });
 
   it('blacklists an address', async () => {
-    // Grant blacklister role to the provider wallet
-    blacklisterRolePda = await grantRole(
-      coreProgram,
-      mintResult.configPda,
-      mintResult.adminRolePda,
-      provider.wallet.publicKey,
-      ROLE_BLACKLISTER,
-    );
-
     // Create blacklisted user's token account and thaw it
     const blacklistedAta = await createTokenAccount(
       provider,
@@ -196,6 +205,12 @@ describe('SSS-2: Compliant Stablecoin', () => {
       .rpc();
 
     // Mint some tokens to the blacklisted account for testing
+    const blacklistedKyc = await approveKyc(
+      coreProgram,
+      mintResult.configPda,
+      blacklisterRolePda,
+      blacklisted.publicKey,
+    );
     await coreProgram.methods
       .mintTokens(new BN(500_000))
       .accountsPartial({
@@ -206,6 +221,7 @@ describe('SSS-2: Compliant Stablecoin', () => {
         to: blacklistedAta,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         priceUpdate: null,
+        kycEntry: blacklistedKyc,
       })
       .signers([minter])
       .rpc();
@@ -390,7 +406,7 @@ describe('SSS-2: Compliant Stablecoin', () => {
 
     try {
       await coreProgram.methods
-        .seize(new BN(500_000))
+        .seize(new BN(500_000), 'Court order 2026-CV-1142')
         .accountsPartial({
           seizer: provider.wallet.publicKey,
           config: mintResult.configPda,
@@ -399,6 +415,7 @@ describe('SSS-2: Compliant Stablecoin', () => {
           from: senderAta,
           to: treasuryAta,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
+          seizureRecord: await nextSeizureRecordPda(coreProgram, mintResult.configPda),
           priceUpdate: null,
         })
         .rpc();
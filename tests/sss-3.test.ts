@@ -29,7 +29,7 @@ describe('SSS-3: Confidential Stablecoin', () => {
     expect(config.authority.toBase58()).to.equal(provider.wallet.publicKey.toBase58());
     expect(config.mint.toBase58()).to.equal(mintResult.mint.publicKey.toBase58());
     expect(config.preset).to.equal(3);
-    expect(config.paused).to.equal(false);
+    expect(config.pauseFlags).to.equal(0);
     expect(config.supplyCap).to.be.null;
     expect(config.totalMinted.toNumber()).to.equal(0);
     expect(config.totalBurned.toNumber()).to.equal(0);
@@ -6,6 +6,7 @@ import { SssCore } from '../target/types/sss_core';
 import {
   createSss1Mint,
   deriveRolePda,
+  derivePendingRolePda,
   fetchConfig,
   airdropSol,
   ROLE_ADMIN,
@@ -37,8 +38,47 @@ describe('Transfer Authority', () => {
     });
   });
 
-  // Test 1: Happy path - admin transfers authority to newAdmin
-  it('transfers authority to a new admin', async () => {
+  // Test 1: Admin proposes newAdmin; nothing moves until it accepts
+  it('records the proposed authority', async () => {
+    const { configPda, adminRolePda } = mintResult;
+
+    await coreProgram.methods
+      .proposeAuthority()
+      .accountsPartial({
+        admin: provider.publicKey,
+        config: configPda,
+        adminRole: adminRolePda,
+        newAuthority: newAdmin.publicKey,
+      })
+      .rpc();
+
+    const config = await fetchConfig(coreProgram, configPda);
+    expect(config.authority.toBase58()).to.equal(provider.publicKey.toBase58());
+    expect(config.pendingAuthority.toBase58()).to.equal(newAdmin.publicKey.toBase58());
+  });
+
+  // Test 2: Only the proposed authority can accept
+  it('rejects accept from a non-pending signer', async () => {
+    const { configPda } = mintResult;
+
+    try {
+      await coreProgram.methods
+        .acceptAuthority()
+        .accountsPartial({
+          newAuthority: nonAdmin.publicKey,
+          config: configPda,
+          oldAuthority: provider.publicKey,
+        })
+        .signers([nonAdmin])
+        .rpc();
+      expect.fail('Should have thrown');
+    } catch (err: any) {
+      expect(err.toString()).to.include('NotPendingAuthority');
+    }
+  });
+
+  // Test 3: Happy path - newAdmin accepts
+  it('transfers authority once the new admin accepts', async () => {
     const { configPda, adminRolePda } = mintResult;
 
     const [newAdminRolePda] = deriveRolePda(
@@ -49,20 +89,22 @@ describe('Transfer Authority', () => {
     );
 
     await coreProgram.methods
-      .transferAuthority()
+      .acceptAuthority()
       .accountsPartial({
-        admin: provider.publicKey,
-        config: configPda,
-        adminRole: adminRolePda,
         newAuthority: newAdmin.publicKey,
+        config: configPda,
+        oldAuthority: provider.publicKey,
+        oldAdminRole: adminRolePda,
         newAdminRole: newAdminRolePda,
         systemProgram: SystemProgram.programId,
       })
+      .signers([newAdmin])
       .rpc();
 
-    // Verify config.authority updated
+    // Verify config.authority updated and the proposal cleared
     const config = await fetchConfig(coreProgram, configPda);
     expect(config.authority.toBase58()).to.equal(newAdmin.publicKey.toBase58());
+    expect(config.pendingAuthority).to.be.null;
 
     // Verify new admin role PDA exists
     const newRole = await coreProgram.account.roleAccount.fetch(newAdminRolePda);
@@ -74,7 +116,7 @@ describe('Transfer Authority', () => {
     expect(oldRole).to.be.null;
   });
 
-  // Test 2: New admin can perform admin actions (grant roles)
+  // Test 4: New admin can perform admin actions (grant roles)
   it('new admin can grant roles', async () => {
     const { configPda } = mintResult;
 
@@ -92,6 +134,12 @@ describe('Transfer Authority', () => {
       ROLE_MINTER,
       coreProgram.programId,
     );
+    const [pendingMinterRolePda] = derivePendingRolePda(
+      configPda,
+      minter.publicKey,
+      ROLE_MINTER,
+      coreProgram.programId,
+    );
 
     // Use newAdmin as signer
     await coreProgram.methods
@@ -101,24 +149,38 @@ describe('Transfer Authority', () => {
         config: configPda,
         adminRole: newAdminRolePda,
         grantee: minter.publicKey,
-        roleAccount: minterRolePda,
+        pendingRole: pendingMinterRolePda,
         systemProgram: SystemProgram.programId,
       })
       .signers([newAdmin])
       .rpc();
 
+    // The grantee pays for its own role account, so it needs lamports
+    await airdropSol(provider.connection, minter.publicKey, 1);
+    await coreProgram.methods
+      .acceptRole()
+      .accountsPartial({
+        grantee: minter.publicKey,
+        config: configPda,
+        pendingRole: pendingMinterRolePda,
+        roleAccount: minterRolePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([minter])
+      .rpc();
+
     const role = await coreProgram.account.roleAccount.fetch(minterRolePda);
     expect(role.address.toBase58()).to.equal(minter.publicKey.toBase58());
   });
 
-  // Test 3: Old admin can no longer perform admin actions
+  // Test 5: Old admin can no longer perform admin actions
   it('old admin cannot perform admin actions', async () => {
     const { configPda, adminRolePda } = mintResult;
 
-    // Old admin's role PDA was closed in test 1
+    // Old admin's role PDA was closed in test 3
     // Trying to grant a role with old admin should fail
     const randomUser = Keypair.generate();
-    const [randomRolePda] = deriveRolePda(
+    const [randomPendingRolePda] = derivePendingRolePda(
       configPda,
       randomUser.publicKey,
       ROLE_MINTER,
@@ -133,7 +195,7 @@ describe('Transfer Authority', () => {
           config: configPda,
           adminRole: adminRolePda,
           grantee: randomUser.publicKey,
-          roleAccount: randomRolePda,
+          pendingRole: randomPendingRolePda,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -144,8 +206,8 @@ describe('Transfer Authority', () => {
     }
   });
 
-  // Test 4: Non-admin cannot transfer authority
-  it('rejects transfer from non-admin', async () => {
+  // Test 6: Non-admin cannot propose a new authority
+  it('rejects a proposal from non-admin', async () => {
     // Create a fresh stablecoin for this test
     const freshMint = await createSss1Mint(provider, coreProgram, {
       name: 'Fresh Auth Test',
@@ -162,31 +224,21 @@ describe('Transfer Authority', () => {
       ROLE_ADMIN,
       coreProgram.programId,
     );
-    const target = Keypair.generate();
-    const [targetAdminRolePda] = deriveRolePda(
-      freshMint.configPda,
-      target.publicKey,
-      ROLE_ADMIN,
-      coreProgram.programId,
-    );
-
     try {
       await coreProgram.methods
-        .transferAuthority()
+        .proposeAuthority()
         .accountsPartial({
           admin: attacker.publicKey,
           config: freshMint.configPda,
           adminRole: attackerAdminRolePda,
-          newAuthority: target.publicKey,
-          newAdminRole: targetAdminRolePda,
-          systemProgram: SystemProgram.programId,
+          newAuthority: attacker.publicKey,
         })
         .signers([attacker])
         .rpc();
       expect.fail('Should have thrown');
     } catch (err: any) {
-      // Attacker has no admin role PDA — seed validation fails
-      expect(err.toString()).to.include('AccountNotInitialized');
+      // Attacker is not config.authority
+      expect(err.toString()).to.include('Unauthorized');
     }
   });
 });
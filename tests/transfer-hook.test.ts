@@ -21,6 +21,7 @@ import {
   deriveBlacklistPda,
   deriveExtraAccountMetasPda,
   grantRole,
+  approveKyc,
   fetchConfig,
   getTokenBalance,
   airdropSol,
@@ -145,14 +146,14 @@ describe('Transfer Hook', () => {
         coreProgram,
         mintResult.configPda,
         mintResult.adminRolePda,
-        minter.publicKey,
+        minter,
         ROLE_MINTER,
       );
       freezerRolePda = await grantRole(
         coreProgram,
         mintResult.configPda,
         mintResult.adminRolePda,
-        freezer.publicKey,
+        freezer,
         ROLE_FREEZER,
       );
       blacklisterRolePda = await grantRole(
@@ -187,6 +188,20 @@ describe('Transfer Hook', () => {
           .rpc();
       }
 
+      // SSS-2 mints require a KYC approval for the destination owner
+      const aliceKyc = await approveKyc(
+        coreProgram,
+        mintResult.configPda,
+        blacklisterRolePda,
+        alice.publicKey,
+      );
+      const charlieKyc = await approveKyc(
+        coreProgram,
+        mintResult.configPda,
+        blacklisterRolePda,
+        charlie.publicKey,
+      );
+
       // Mint tokens to alice and charlie for transfer tests
       await coreProgram.methods
         .mintTokens(new BN(MINT_AMOUNT))
@@ -198,6 +213,7 @@ describe('Transfer Hook', () => {
           to: aliceAta,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           priceUpdate: null,
+          kycEntry: aliceKyc,
         })
         .signers([minter])
         .rpc();
@@ -212,6 +228,7 @@ describe('Transfer Hook', () => {
           to: charlieAta,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           priceUpdate: null,
+          kycEntry: charlieKyc,
         })
         .signers([minter])
         .rpc();
@@ -3,6 +3,7 @@
 
 use proptest::prelude::*;
 use solana_sdk::pubkey::Pubkey;
+use sss_core::math::oracle_token_cap;
 use sss_core::state::config::StablecoinConfig;
 
 fn default_config() -> StablecoinConfig {
@@ -10,7 +11,7 @@ fn default_config() -> StablecoinConfig {
         authority: Pubkey::default(),
         mint: Pubkey::default(),
         preset: 1,
-        paused: false,
+        pause_flags: 0,
         supply_cap: None,
         total_minted: 0,
         total_burned: 0,
@@ -24,6 +25,47 @@ fn default_config() -> StablecoinConfig {
         default_account_frozen: false,
         admin_count: 1,
         oracle_feed_id: None,
+        receipt_threshold: None,
+        receipt_retention_secs: 0,
+        max_confidence_bps: None,
+        program_version: sss_core::version::program_version(),
+        event_queue_enabled: false,
+        disabled_instructions: 0,
+        locked_instructions: 0,
+        reserved_for_redemption: 0,
+        state_digest: [0; 32],
+        state_nonce: 0,
+        bridge_minted: 0,
+        bridge_burned: 0,
+        thaw_ramp: None,
+        paused_at_slot: 0,
+        ramp_started_at: None,
+        ramp_base_supply: 0,
+        incident_flag: false,
+        incident_updated_at: 0,
+        incident_uri: String::new(),
+        destination_mint_limit: None,
+        halt_mint_on_supply_mismatch: false,
+        oracle_price_source: Default::default(),
+        pause_on_authority_anomaly: false,
+        mint_index: 0,
+        pending_authority: None,
+        admin_timelock_secs: 0,
+        large_mint_threshold: None,
+        large_mint_approvals: 0,
+        unpause_requires_admin: false,
+        max_pause_secs: 0,
+        pause_expires_at: None,
+        large_seize_threshold: None,
+        seizure_count: 0,
+        treasury: None,
+        seize_requires_frozen: false,
+        wipe_count: 0,
+        version: StablecoinConfig::CURRENT_VERSION,
+        sunset_at: None,
+        oracle_kind: Default::default(),
+        max_attestation_age_secs: None,
+        fee_treasury: None,
     }
 }
 
@@ -71,11 +113,12 @@ proptest! {
         let mut success_count = 0u32;
 
         for amount in &amounts {
+            let before = config.total_minted;
             if checked_mint(&mut config, *amount) {
                 success_count += 1;
             }
             // total_minted must never wrap
-            prop_assert!(config.total_minted <= u64::MAX);
+            prop_assert!(config.total_minted >= before);
         }
 
         // At most one very large mint can succeed
@@ -125,20 +168,8 @@ proptest! {
         price in 1i64..=i64::MAX,
         abs_expo in 0u32..=12,
     ) {
-        // Mirrors adjust_cap_with_oracle in mint_tokens.rs
-        let numerator = (usd_cap as u128)
-            .checked_mul(10u128.pow(mint_decimals as u32))
-            .and_then(|v| v.checked_mul(10u128.pow(abs_expo)));
-
-        if let Some(num) = numerator {
-            let token_cap = num.checked_div(price as u128);
-            if let Some(cap) = token_cap {
-                // Must fit in u64 or be clamped to u64::MAX
-                let _final_cap = cap.min(u64::MAX as u128) as u64;
-                // Just verifying no panic occurs
-            }
-        }
-        // If any checked_* returns None, the on-chain code returns ArithmeticOverflow.
-        // That's the correct behavior.
+        // Exercises the same conversion adjust_cap_with_oracle uses on-chain.
+        // `None` maps to ArithmeticOverflow; the only requirement is no panic.
+        let _token_cap = oracle_token_cap(usd_cap, price, -(abs_expo as i32), mint_decimals);
     }
 }
@@ -0,0 +1,250 @@
+//! Fuzz: Authority transfer sequences — random interleavings of
+//! `propose_authority`, `accept_authority`, `grant_role(Admin)` and
+//! `revoke_role(Admin)` keep `config.admin_count` equal to the number of live
+//! Admin PDAs.
+//!
+//! `accept_authority` closes the old authority's Admin PDA and creates one for
+//! the new authority without touching `admin_count`. That is only sound
+//! because the two operations are atomic and the `init` on the new PDA fails
+//! when the new authority is already an admin (including a self-transfer).
+//!
+//! Properties:
+//!
+//! 1. `admin_count` always equals the number of live Admin PDAs.
+//! 2. At least one Admin PDA always exists, and the sole remaining admin can
+//!    never be revoked.
+//! 3. Accepting onto an existing admin (or onto itself) fails.
+//! 4. `config.authority` only ever changes to a key that signed
+//!    `accept_authority` while it was the pending authority.
+
+use std::collections::BTreeSet;
+
+use proptest::prelude::*;
+use solana_sdk::pubkey::Pubkey;
+
+/// Simulated admin state: the set of live Admin PDAs plus the counters
+/// stored on `StablecoinConfig`.
+#[derive(Debug, Clone)]
+struct AdminModel {
+    /// Addresses whose Admin role PDA currently exists.
+    live_admins: BTreeSet<Pubkey>,
+    /// Mirrors `config.admin_count`.
+    admin_count: u32,
+    /// Mirrors `config.authority`.
+    authority: Pubkey,
+    /// Mirrors `config.pending_authority`.
+    pending: Option<Pubkey>,
+}
+
+impl AdminModel {
+    fn new(initial: Pubkey) -> Self {
+        let mut live_admins = BTreeSet::new();
+        live_admins.insert(initial);
+        Self {
+            live_admins,
+            admin_count: 1,
+            authority: initial,
+            pending: None,
+        }
+    }
+
+    /// Mirrors `handler_grant` for `Role::Admin`.
+    fn grant_admin(&mut self, admin: &Pubkey, grantee: &Pubkey) -> bool {
+        // admin_role seeds constraint
+        if !self.live_admins.contains(admin) {
+            return false;
+        }
+        // `init` fails if the PDA already exists
+        if self.live_admins.contains(grantee) {
+            return false;
+        }
+        self.admin_count = match self.admin_count.checked_add(1) {
+            Some(v) => v,
+            None => return false,
+        };
+        self.live_admins.insert(*grantee);
+        true
+    }
+
+    /// Mirrors `handler_revoke` for an Admin role account.
+    fn revoke_admin(&mut self, admin: &Pubkey, target: &Pubkey) -> bool {
+        if !self.live_admins.contains(admin) || !self.live_admins.contains(target) {
+            return false;
+        }
+        if self.admin_count <= 1 {
+            return false; // LastAdmin
+        }
+        self.admin_count -= 1;
+        self.live_admins.remove(target);
+        true
+    }
+
+    /// Mirrors `handler_propose_authority`: only the current authority,
+    /// holding its Admin PDA, may propose; a new proposal replaces the last.
+    fn propose_authority(&mut self, admin: &Pubkey, new_authority: &Pubkey) -> bool {
+        if *admin != self.authority || !self.live_admins.contains(admin) {
+            return false;
+        }
+        self.pending = Some(*new_authority);
+        true
+    }
+
+    /// Mirrors `handler_accept_authority`: close the old authority's Admin
+    /// PDA, `init` the signer's, leave `admin_count` untouched.
+    fn accept_authority(&mut self, signer: &Pubkey) -> bool {
+        if self.pending != Some(*signer) {
+            return false; // NotPendingAuthority
+        }
+        // old_admin_role seeds constraint
+        if !self.live_admins.contains(&self.authority) {
+            return false;
+        }
+        // `init` on new_admin_role runs during account validation, before the
+        // `close` on old_admin_role executes at instruction exit. An existing
+        // PDA (including a self-transfer) aborts the whole transaction.
+        if self.live_admins.contains(signer) {
+            return false;
+        }
+        let old = self.authority;
+        self.live_admins.insert(*signer);
+        self.live_admins.remove(&old);
+        self.authority = *signer;
+        self.pending = None;
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+enum AuthorityOp {
+    Grant {
+        admin_idx: usize,
+        grantee_idx: usize,
+    },
+    Revoke {
+        admin_idx: usize,
+        target_idx: usize,
+    },
+    Propose {
+        admin_idx: usize,
+        new_idx: usize,
+    },
+    Accept {
+        signer_idx: usize,
+    },
+}
+
+fn authority_op_strategy() -> impl Strategy<Value = AuthorityOp> {
+    prop_oneof![
+        (0usize..6, 0usize..6).prop_map(|(a, g)| AuthorityOp::Grant {
+            admin_idx: a,
+            grantee_idx: g,
+        }),
+        (0usize..6, 0usize..6).prop_map(|(a, t)| AuthorityOp::Revoke {
+            admin_idx: a,
+            target_idx: t,
+        }),
+        (0usize..6, 0usize..6).prop_map(|(a, n)| AuthorityOp::Propose {
+            admin_idx: a,
+            new_idx: n,
+        }),
+        (0usize..6).prop_map(|s| AuthorityOp::Accept { signer_idx: s }),
+    ]
+}
+
+proptest! {
+    /// admin_count tracks live Admin PDAs across any interleaving.
+    #[test]
+    fn admin_count_matches_live_admins(
+        ops in proptest::collection::vec(authority_op_strategy(), 1..200),
+    ) {
+        let addresses: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let mut model = AdminModel::new(addresses[0]);
+
+        for op in ops {
+            match op {
+                AuthorityOp::Grant { admin_idx, grantee_idx } => {
+                    model.grant_admin(&addresses[admin_idx], &addresses[grantee_idx]);
+                }
+                AuthorityOp::Revoke { admin_idx, target_idx } => {
+                    model.revoke_admin(&addresses[admin_idx], &addresses[target_idx]);
+                }
+                AuthorityOp::Propose { admin_idx, new_idx } => {
+                    model.propose_authority(&addresses[admin_idx], &addresses[new_idx]);
+                }
+                AuthorityOp::Accept { signer_idx } => {
+                    let before = model.authority;
+                    let pending = model.pending;
+                    if model.accept_authority(&addresses[signer_idx]) {
+                        prop_assert_eq!(pending, Some(addresses[signer_idx]));
+                    } else {
+                        prop_assert_eq!(model.authority, before);
+                    }
+                }
+            }
+
+            prop_assert_eq!(model.admin_count as usize, model.live_admins.len(),
+                "admin_count drifted from live Admin PDAs"
+            );
+            prop_assert!(!model.live_admins.is_empty(), "No admin left");
+
+            if model.live_admins.len() == 1 {
+                // Any revoke of the sole admin is blocked by LastAdmin.
+                let sole = *model.live_admins.iter().next().unwrap();
+                prop_assert!(!model.revoke_admin(&sole, &sole));
+            }
+        }
+    }
+
+    /// A sole admin cannot hand authority to itself to drop to zero admins,
+    /// and handing it to a fresh key keeps exactly one admin.
+    #[test]
+    fn transfer_cannot_bypass_last_admin(new_idx in 0usize..6) {
+        let addresses: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let mut model = AdminModel::new(addresses[0]);
+
+        prop_assert!(model.propose_authority(&addresses[0], &addresses[new_idx]));
+        let ok = model.accept_authority(&addresses[new_idx]);
+
+        if new_idx == 0 {
+            prop_assert!(!ok, "Self-transfer must fail on the existing PDA");
+        } else {
+            prop_assert!(ok);
+            prop_assert_eq!(model.authority, addresses[new_idx]);
+        }
+        prop_assert_eq!(model.admin_count, 1);
+        prop_assert_eq!(model.live_admins.len(), 1);
+    }
+
+    /// Transferring onto an address that is already an admin is rejected, so
+    /// two admins can never collapse into one without decrementing the count.
+    #[test]
+    fn transfer_onto_existing_admin_rejected(a in 0usize..3, b in 3usize..6) {
+        let addresses: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let mut model = AdminModel::new(addresses[a]);
+        prop_assert!(model.grant_admin(&addresses[a], &addresses[b]));
+
+        prop_assert!(model.propose_authority(&addresses[a], &addresses[b]));
+        prop_assert!(!model.accept_authority(&addresses[b]));
+        prop_assert_eq!(model.admin_count, 2);
+        prop_assert_eq!(model.live_admins.len(), 2);
+    }
+
+    /// Only the proposed key can accept, so a mistyped proposal moves nothing
+    /// and can be replaced.
+    #[test]
+    fn only_pending_authority_accepts(typo in 1usize..6, intended in 1usize..6) {
+        let addresses: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let mut model = AdminModel::new(addresses[0]);
+
+        prop_assert!(model.propose_authority(&addresses[0], &addresses[typo]));
+        if typo != intended {
+            prop_assert!(!model.accept_authority(&addresses[intended]));
+            prop_assert_eq!(model.authority, addresses[0]);
+            prop_assert!(model.propose_authority(&addresses[0], &addresses[intended]));
+            prop_assert!(!model.accept_authority(&addresses[typo]));
+        }
+        prop_assert!(model.accept_authority(&addresses[intended]));
+        prop_assert_eq!(model.authority, addresses[intended]);
+        prop_assert_eq!(model.pending, None);
+    }
+}
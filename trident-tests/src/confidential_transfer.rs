@@ -46,7 +46,7 @@ proptest! {
     fn confidential_state_transitions(
         ops in proptest::collection::vec(confidential_op_strategy(), 1..100),
     ) {
-        let mut accounts = vec![
+        let mut accounts = [
             ConfidentialAccount { pub_balance: 1_000_000, available: 0, pending: 0, configured: false },
             ConfidentialAccount { pub_balance: 1_000_000, available: 0, pending: 0, configured: false },
         ];
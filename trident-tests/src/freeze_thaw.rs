@@ -0,0 +1,331 @@
+//! Fuzz: Freeze/thaw interleavings on an SSS-2 default-frozen deployment.
+//!
+//! Models the combined surface of `sss-core` (freeze, thaw, mint, burn, seize,
+//! pause) and `sss-transfer-hook` (blacklist, transfer checks) on a single
+//! mint, with Token-2022's own rules applied first:
+//!
+//! - Every balance movement out of or into a frozen account fails, including
+//!   burns and seizes made by the config PDA as permanent delegate.
+//! - Transfers and seizes both run through the hook, so the sender/receiver
+//!   blacklist and the pause flag apply to them. Mints and burns do not.
+//!
+//! Invariants checked after every operation:
+//!
+//! 1. A blacklisted owner never receives tokens through a hook-checked
+//!    transfer or seize. (`mint_tokens` does not invoke the hook.)
+//! 2. A frozen account's balance only changes via seize or burn.
+//! 3. The sum of all balances equals `current_supply()`.
+
+use proptest::prelude::*;
+use solana_sdk::pubkey::Pubkey;
+use sss_core::constants::pause;
+use sss_core::state::config::StablecoinConfig;
+
+use crate::invariants::check_all_invariants;
+
+/// Index 0 is the issuer treasury, thawed at setup like a real deployment.
+const TREASURY: usize = 0;
+const HOLDERS: usize = 5;
+
+/// Model clock; `max_pause_secs` is zero here, so pauses never expire.
+const NOW: i64 = 0;
+
+fn sss2_config() -> StablecoinConfig {
+    StablecoinConfig {
+        authority: Pubkey::default(),
+        mint: Pubkey::default(),
+        preset: 2,
+        pause_flags: 0,
+        supply_cap: Some(1_000_000_000),
+        total_minted: 0,
+        total_burned: 0,
+        bump: 0,
+        name: String::new(),
+        symbol: String::new(),
+        uri: String::new(),
+        decimals: 6,
+        enable_permanent_delegate: true,
+        enable_transfer_hook: true,
+        default_account_frozen: true,
+        admin_count: 1,
+        oracle_feed_id: None,
+        receipt_threshold: None,
+        receipt_retention_secs: 0,
+        max_confidence_bps: None,
+        program_version: sss_core::version::program_version(),
+        event_queue_enabled: false,
+        disabled_instructions: 0,
+        locked_instructions: 0,
+        reserved_for_redemption: 0,
+        state_digest: [0; 32],
+        state_nonce: 0,
+        bridge_minted: 0,
+        bridge_burned: 0,
+        thaw_ramp: None,
+        paused_at_slot: 0,
+        ramp_started_at: None,
+        ramp_base_supply: 0,
+        incident_flag: false,
+        incident_updated_at: 0,
+        incident_uri: String::new(),
+        destination_mint_limit: None,
+        halt_mint_on_supply_mismatch: false,
+        oracle_price_source: Default::default(),
+        pause_on_authority_anomaly: false,
+        mint_index: 0,
+        pending_authority: None,
+        admin_timelock_secs: 0,
+        large_mint_threshold: None,
+        large_mint_approvals: 0,
+        unpause_requires_admin: false,
+        max_pause_secs: 0,
+        pause_expires_at: None,
+        large_seize_threshold: None,
+        seizure_count: 0,
+        treasury: None,
+        seize_requires_frozen: false,
+        wipe_count: 0,
+        version: StablecoinConfig::CURRENT_VERSION,
+        sunset_at: None,
+        oracle_kind: Default::default(),
+        max_attestation_age_secs: None,
+        fee_treasury: None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Holder {
+    balance: u64,
+    frozen: bool,
+    blacklisted: bool,
+}
+
+struct Deployment {
+    config: StablecoinConfig,
+    holders: [Holder; HOLDERS],
+}
+
+impl Deployment {
+    fn new() -> Self {
+        let mut config = sss2_config();
+        let mut holders = [Holder {
+            frozen: config.default_account_frozen,
+            ..Holder::default()
+        }; HOLDERS];
+        holders[TREASURY].frozen = false;
+        // Seed the treasury so transfers have something to move.
+        config.total_minted = 1_000_000;
+        holders[TREASURY].balance = 1_000_000;
+        Self { config, holders }
+    }
+
+    /// Token-2022 `transfer_checked` followed by the hook's checks.
+    fn hooked_transfer(&mut self, from: usize, to: usize, amount: u64) -> bool {
+        let (src, dst) = (self.holders[from], self.holders[to]);
+        if src.frozen || dst.frozen || src.balance < amount {
+            return false;
+        }
+        if src.blacklisted || dst.blacklisted || self.config.pauses(pause::TRANSFERS, NOW) {
+            return false;
+        }
+        self.holders[from].balance -= amount;
+        self.holders[to].balance += amount;
+        true
+    }
+
+    /// Apply `op`, returning whether it succeeded on-chain.
+    fn apply(&mut self, op: FreezeOp) -> bool {
+        match op {
+            FreezeOp::Freeze(i) => {
+                if self.config.pauses(pause::FREEZE_THAW, NOW) || self.holders[i].frozen {
+                    return false;
+                }
+                self.holders[i].frozen = true;
+                true
+            }
+            FreezeOp::Thaw(i) => {
+                if self.config.pauses(pause::FREEZE_THAW, NOW) || !self.holders[i].frozen {
+                    return false;
+                }
+                self.holders[i].frozen = false;
+                true
+            }
+            FreezeOp::Blacklist(i) => {
+                // `add_to_blacklist` inits the PDA; a second add fails.
+                if self.holders[i].blacklisted {
+                    return false;
+                }
+                self.holders[i].blacklisted = true;
+                true
+            }
+            FreezeOp::Unblacklist(i) => {
+                if !self.holders[i].blacklisted {
+                    return false;
+                }
+                self.holders[i].blacklisted = false;
+                true
+            }
+            FreezeOp::Pause => {
+                if self.config.is_fully_paused(NOW) {
+                    return false;
+                }
+                self.config.pause_flags = pause::ALL;
+                true
+            }
+            FreezeOp::Unpause => {
+                if !self.config.is_paused(NOW) {
+                    return false;
+                }
+                self.config.pause_flags = 0;
+                true
+            }
+            FreezeOp::Mint(i, amount) => {
+                if self.config.pauses(pause::MINT, NOW)
+                    || self.holders[i].frozen
+                    || !self.config.can_mint(amount)
+                {
+                    return false;
+                }
+                self.config.total_minted += amount;
+                self.holders[i].balance += amount;
+                true
+            }
+            FreezeOp::Burn(i, amount) => {
+                let holder = self.holders[i];
+                if self.config.pauses(pause::BURN, NOW) || holder.frozen || holder.balance < amount
+                {
+                    return false;
+                }
+                self.config.total_burned += amount;
+                self.holders[i].balance -= amount;
+                true
+            }
+            FreezeOp::Transfer(from, to, amount) => {
+                from != to && self.hooked_transfer(from, to, amount)
+            }
+            FreezeOp::Seize(from, amount) => {
+                // Seize is not pause-gated in sss-core, but the hook still runs.
+                from != TREASURY && self.hooked_transfer(from, TREASURY, amount)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FreezeOp {
+    Freeze(usize),
+    Thaw(usize),
+    Blacklist(usize),
+    Unblacklist(usize),
+    Pause,
+    Unpause,
+    Mint(usize, u64),
+    Burn(usize, u64),
+    Transfer(usize, usize, u64),
+    Seize(usize, u64),
+}
+
+impl FreezeOp {
+    fn receiver(self) -> Option<usize> {
+        match self {
+            FreezeOp::Transfer(_, to, _) => Some(to),
+            FreezeOp::Seize(..) => Some(TREASURY),
+            _ => None,
+        }
+    }
+
+    fn may_move_frozen_balance(self) -> bool {
+        matches!(self, FreezeOp::Seize(..) | FreezeOp::Burn(..))
+    }
+}
+
+fn freeze_op_strategy() -> impl Strategy<Value = FreezeOp> {
+    let holder = 0..HOLDERS;
+    let amount = 1u64..=500_000;
+    prop_oneof![
+        holder.clone().prop_map(FreezeOp::Freeze),
+        holder.clone().prop_map(FreezeOp::Thaw),
+        holder.clone().prop_map(FreezeOp::Blacklist),
+        holder.clone().prop_map(FreezeOp::Unblacklist),
+        Just(FreezeOp::Pause),
+        Just(FreezeOp::Unpause),
+        (holder.clone(), amount.clone()).prop_map(|(i, a)| FreezeOp::Mint(i, a)),
+        (holder.clone(), amount.clone()).prop_map(|(i, a)| FreezeOp::Burn(i, a)),
+        (holder.clone(), holder.clone(), amount.clone())
+            .prop_map(|(f, t, a)| FreezeOp::Transfer(f, t, a)),
+        (holder, amount).prop_map(|(f, a)| FreezeOp::Seize(f, a)),
+    ]
+}
+
+proptest! {
+    /// Random freeze/thaw/blacklist/transfer/seize interleavings never let a
+    /// blacklisted owner receive, never move a frozen balance outside
+    /// seize/burn, and keep balances in sync with the supply counters.
+    #[test]
+    fn freeze_thaw_interleavings_hold_invariants(
+        ops in proptest::collection::vec(freeze_op_strategy(), 1..300),
+    ) {
+        let mut deployment = Deployment::new();
+
+        for op in ops {
+            let before = deployment.holders;
+            let succeeded = deployment.apply(op);
+
+            if succeeded {
+                if let Some(to) = op.receiver() {
+                    prop_assert!(
+                        !before[to].blacklisted,
+                        "Blacklisted holder {} received via {:?}", to, op
+                    );
+                }
+            }
+
+            for (i, (old, new)) in before.iter().zip(deployment.holders.iter()).enumerate() {
+                if old.frozen && new.frozen && old.balance != new.balance {
+                    prop_assert!(
+                        op.may_move_frozen_balance(),
+                        "Frozen holder {} balance changed via {:?}", i, op
+                    );
+                }
+            }
+
+            let total: u64 = deployment.holders.iter().map(|h| h.balance).sum();
+            prop_assert_eq!(total, deployment.config.current_supply());
+            check_all_invariants(&deployment.config);
+        }
+    }
+
+    /// Default-frozen accounts cannot receive anything until thawed.
+    #[test]
+    fn default_frozen_accounts_reject_until_thawed(
+        to in 1..HOLDERS,
+        amount in 1u64..=500_000,
+    ) {
+        let mut deployment = Deployment::new();
+
+        prop_assert!(!deployment.apply(FreezeOp::Mint(to, amount)));
+        prop_assert!(!deployment.apply(FreezeOp::Transfer(TREASURY, to, amount)));
+
+        prop_assert!(deployment.apply(FreezeOp::Thaw(to)));
+        prop_assert!(deployment.apply(FreezeOp::Transfer(TREASURY, to, amount)));
+        prop_assert_eq!(deployment.holders[to].balance, amount);
+    }
+
+    /// Blacklisting a thawed holder blocks inbound transfers without touching
+    /// the freeze state; delisting restores them.
+    #[test]
+    fn blacklist_is_independent_of_freeze(
+        to in 1..HOLDERS,
+        amount in 1u64..=500_000,
+    ) {
+        let mut deployment = Deployment::new();
+        prop_assert!(deployment.apply(FreezeOp::Thaw(to)));
+        prop_assert!(deployment.apply(FreezeOp::Blacklist(to)));
+
+        prop_assert!(!deployment.apply(FreezeOp::Transfer(TREASURY, to, amount)));
+        prop_assert!(!deployment.holders[to].frozen);
+
+        prop_assert!(deployment.apply(FreezeOp::Unblacklist(to)));
+        prop_assert!(deployment.apply(FreezeOp::Transfer(TREASURY, to, amount)));
+    }
+}
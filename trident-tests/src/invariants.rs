@@ -37,9 +37,21 @@ pub fn check_burn_invariant(config: &StablecoinConfig) {
     );
 }
 
+/// Invariant: tokens escrowed for redemption are part of the outstanding
+/// supply, so the reservation can never exceed it.
+pub fn check_redemption_invariant(config: &StablecoinConfig) {
+    assert!(
+        config.reserved_for_redemption <= config.current_supply(),
+        "Redemption invariant violated: reserved ({}) > current_supply ({})",
+        config.reserved_for_redemption,
+        config.current_supply(),
+    );
+}
+
 /// Run all invariant checks.
 pub fn check_all_invariants(config: &StablecoinConfig) {
     check_supply_invariant(config);
     check_cap_invariant(config);
     check_burn_invariant(config);
+    check_redemption_invariant(config);
 }
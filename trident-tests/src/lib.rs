@@ -22,15 +22,30 @@
 //!    while the token is paused; Admin ops always remain available.
 //! 8. **Burn audit trail (C-2)**: `TokensBurned` events always carry the
 //!    token account owner (`from_owner`) so privileged burns are detectable.
+//! 9. **Role PDA seeds**: Distinct (config, address, role) tuples never derive
+//!    the same PDA, and role bytes outside 0-13 are never accepted.
+//! 10. **Authority transfer**: Interleaved transfer/grant/revoke sequences keep
+//!     `admin_count` equal to the number of live Admin PDAs.
+//! 11. **Freeze/thaw interleavings**: On an SSS-2 default-frozen deployment,
+//!     blacklisted owners never receive via the hook and frozen balances only
+//!     move through seize/burn.
+//!
+//! Supply, quota and oracle-cap arithmetic are additionally proven for all
+//! inputs with Kani (`cargo kani -p sss-fuzz-tests`, see `proofs.rs`).
 //!
 //! For on-chain fuzz testing with Trident (honggfuzz), see `trident-tests/fuzz_0/`.
 
 mod arithmetic;
+mod authority_transfer;
 mod burn_audit;
 mod confidential_transfer;
+mod freeze_thaw;
 mod invariants;
 mod oracle_feed;
 mod pause_bypass;
 mod pause_roles;
+#[cfg(kani)]
+mod proofs;
 mod role_escalation;
+mod role_pda;
 mod supply_cap;
@@ -18,7 +18,7 @@ fn config_with_feed(feed_id: Option<[u8; 32]>, cap: Option<u64>) -> StablecoinCo
         authority: Pubkey::default(),
         mint: Pubkey::default(),
         preset: 1,
-        paused: false,
+        pause_flags: 0,
         supply_cap: cap,
         total_minted: 0,
         total_burned: 0,
@@ -32,6 +32,47 @@ fn config_with_feed(feed_id: Option<[u8; 32]>, cap: Option<u64>) -> StablecoinCo
         default_account_frozen: false,
         admin_count: 1,
         oracle_feed_id: feed_id,
+        receipt_threshold: None,
+        receipt_retention_secs: 0,
+        max_confidence_bps: None,
+        program_version: sss_core::version::program_version(),
+        event_queue_enabled: false,
+        disabled_instructions: 0,
+        locked_instructions: 0,
+        reserved_for_redemption: 0,
+        state_digest: [0; 32],
+        state_nonce: 0,
+        bridge_minted: 0,
+        bridge_burned: 0,
+        thaw_ramp: None,
+        paused_at_slot: 0,
+        ramp_started_at: None,
+        ramp_base_supply: 0,
+        incident_flag: false,
+        incident_updated_at: 0,
+        incident_uri: String::new(),
+        destination_mint_limit: None,
+        halt_mint_on_supply_mismatch: false,
+        oracle_price_source: Default::default(),
+        pause_on_authority_anomaly: false,
+        mint_index: 0,
+        pending_authority: None,
+        admin_timelock_secs: 0,
+        large_mint_threshold: None,
+        large_mint_approvals: 0,
+        unpause_requires_admin: false,
+        max_pause_secs: 0,
+        pause_expires_at: None,
+        large_seize_threshold: None,
+        seizure_count: 0,
+        treasury: None,
+        seize_requires_frozen: false,
+        wipe_count: 0,
+        version: StablecoinConfig::CURRENT_VERSION,
+        sunset_at: None,
+        oracle_kind: Default::default(),
+        max_attestation_age_secs: None,
+        fee_treasury: None,
     }
 }
 
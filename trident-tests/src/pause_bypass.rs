@@ -1,15 +1,20 @@
-//! Fuzz: Pause bypass — operations always fail when the protocol is paused.
+//! Fuzz: Pause bypass — operations always fail while their class is paused.
 
 use proptest::prelude::*;
 use solana_sdk::pubkey::Pubkey;
+use sss_core::constants::pause;
 use sss_core::state::config::StablecoinConfig;
+use sss_core::state::role::Role;
 
-fn default_config(paused: bool) -> StablecoinConfig {
+/// Model clock; `max_pause_secs` is zero here, so pauses never expire.
+const NOW: i64 = 0;
+
+fn default_config(pause_flags: u8) -> StablecoinConfig {
     StablecoinConfig {
         authority: Pubkey::default(),
         mint: Pubkey::default(),
         preset: 1,
-        paused,
+        pause_flags,
         supply_cap: Some(1_000_000_000),
         total_minted: 100_000,
         total_burned: 0,
@@ -23,33 +28,74 @@ fn default_config(paused: bool) -> StablecoinConfig {
         default_account_frozen: false,
         admin_count: 1,
         oracle_feed_id: None,
+        receipt_threshold: None,
+        receipt_retention_secs: 0,
+        max_confidence_bps: None,
+        program_version: sss_core::version::program_version(),
+        event_queue_enabled: false,
+        disabled_instructions: 0,
+        locked_instructions: 0,
+        reserved_for_redemption: 0,
+        state_digest: [0; 32],
+        state_nonce: 0,
+        bridge_minted: 0,
+        bridge_burned: 0,
+        thaw_ramp: None,
+        paused_at_slot: 0,
+        ramp_started_at: None,
+        ramp_base_supply: 0,
+        incident_flag: false,
+        incident_updated_at: 0,
+        incident_uri: String::new(),
+        destination_mint_limit: None,
+        halt_mint_on_supply_mismatch: false,
+        oracle_price_source: Default::default(),
+        pause_on_authority_anomaly: false,
+        mint_index: 0,
+        pending_authority: None,
+        admin_timelock_secs: 0,
+        large_mint_threshold: None,
+        large_mint_approvals: 0,
+        unpause_requires_admin: false,
+        max_pause_secs: 0,
+        pause_expires_at: None,
+        large_seize_threshold: None,
+        seizure_count: 0,
+        treasury: None,
+        seize_requires_frozen: false,
+        wipe_count: 0,
+        version: StablecoinConfig::CURRENT_VERSION,
+        sunset_at: None,
+        oracle_kind: Default::default(),
+        max_attestation_age_secs: None,
+        fee_treasury: None,
     }
 }
 
-/// Simulate mint — must fail when paused.
+/// Simulate mint — must fail while minting is paused.
 fn sim_mint(config: &StablecoinConfig, amount: u64) -> bool {
-    if config.paused || amount == 0 {
+    if config.pauses(pause::MINT, NOW) || amount == 0 {
         return false;
     }
     config.can_mint(amount)
 }
 
-/// Simulate burn — must fail when paused.
+/// Simulate burn — must fail while burning is paused.
 fn sim_burn(config: &StablecoinConfig, amount: u64) -> bool {
-    if config.paused || amount == 0 {
+    if config.pauses(pause::BURN, NOW) || amount == 0 {
         return false;
     }
     config.current_supply() >= amount
 }
 
-/// Simulate freeze — must fail when paused.
+/// Simulate freeze — must fail while freeze/thaw is paused.
 fn sim_freeze(config: &StablecoinConfig) -> bool {
-    !config.paused
+    !config.pauses(pause::FREEZE_THAW, NOW)
 }
 
-/// Simulate thaw — must fail when paused.
+/// Simulate thaw — must fail while freeze/thaw is paused.
 fn sim_thaw(config: &StablecoinConfig) -> bool {
-    !config.paused
+    !config.pauses(pause::FREEZE_THAW, NOW)
 }
 
 /// Simulate seize — must succeed even when paused (Seize works during emergencies).
@@ -57,15 +103,27 @@ fn sim_seize(_config: &StablecoinConfig, _amount: u64) -> bool {
     true
 }
 
-/// Simulate transfer — must fail when paused (enforced by transfer hook).
+/// Simulate transfer — must fail while transfers are paused (enforced by
+/// transfer hook).
 fn sim_transfer(config: &StablecoinConfig, _amount: u64) -> bool {
-    !config.paused
+    !config.pauses(pause::TRANSFERS, NOW)
+}
+
+/// Mirrors the role checks in `set_pause_flags`: adding a class takes a
+/// Pauser or Guardian, clearing one takes the role `unpause` requires.
+fn sim_set_flags_authorized(config: &StablecoinConfig, role: Role, flags: u8) -> bool {
+    let old_flags = config.active_pause_flags(NOW);
+    let added = flags & !old_flags;
+    let removed = old_flags & !flags;
+    (added == 0 || matches!(role, Role::Pauser | Role::Guardian))
+        && (removed == 0 || role == config.unpause_role())
 }
 
 #[derive(Debug, Clone)]
 enum PauseOp {
     Pause,
     Unpause,
+    SetFlags(u8),
     Mint(u64),
     Burn(u64),
     Freeze,
@@ -78,6 +136,7 @@ fn pause_op_strategy() -> impl Strategy<Value = PauseOp> {
     prop_oneof![
         Just(PauseOp::Pause),
         Just(PauseOp::Unpause),
+        (0u8..=pause::ALL).prop_map(PauseOp::SetFlags),
         (1u64..=1_000_000u64).prop_map(PauseOp::Mint),
         (1u64..=100_000u64).prop_map(PauseOp::Burn),
         Just(PauseOp::Freeze),
@@ -88,28 +147,31 @@ fn pause_op_strategy() -> impl Strategy<Value = PauseOp> {
 }
 
 proptest! {
-    /// No operation succeeds when the protocol is in a paused state.
+    /// No operation succeeds while its class is paused.
     #[test]
     fn no_ops_when_paused(
         ops in proptest::collection::vec(pause_op_strategy(), 1..200),
     ) {
-        let mut config = default_config(false);
+        let mut config = default_config(0);
 
-        for op in ops {
+        for (slot, op) in ops.into_iter().enumerate() {
             match op {
                 PauseOp::Pause => {
-                    if !config.paused {
-                        config.paused = true;
+                    if !config.is_fully_paused(NOW) {
+                        config.set_pause_flags(pause::ALL, slot as u64, NOW);
                     }
                 }
                 PauseOp::Unpause => {
-                    if config.paused {
-                        config.paused = false;
+                    if config.is_paused(NOW) {
+                        config.set_pause_flags(0, slot as u64, NOW);
                     }
                 }
+                PauseOp::SetFlags(flags) => {
+                    config.set_pause_flags(flags, slot as u64, NOW);
+                }
                 PauseOp::Mint(amount) => {
                     let result = sim_mint(&config, amount);
-                    if config.paused {
+                    if config.pauses(pause::MINT, NOW) {
                         prop_assert!(!result,
                             "Mint succeeded while paused (amount={})", amount
                         );
@@ -117,7 +179,7 @@ proptest! {
                 }
                 PauseOp::Burn(amount) => {
                     let result = sim_burn(&config, amount);
-                    if config.paused {
+                    if config.pauses(pause::BURN, NOW) {
                         prop_assert!(!result,
                             "Burn succeeded while paused (amount={})", amount
                         );
@@ -125,7 +187,7 @@ proptest! {
                 }
                 PauseOp::Freeze => {
                     let result = sim_freeze(&config);
-                    if config.paused {
+                    if config.pauses(pause::FREEZE_THAW, NOW) {
                         prop_assert!(!result,
                             "Freeze succeeded while paused"
                         );
@@ -133,7 +195,7 @@ proptest! {
                 }
                 PauseOp::Thaw => {
                     let result = sim_thaw(&config);
-                    if config.paused {
+                    if config.pauses(pause::FREEZE_THAW, NOW) {
                         prop_assert!(!result,
                             "Thaw succeeded while paused"
                         );
@@ -141,7 +203,7 @@ proptest! {
                 }
                 PauseOp::Seize(amount) => {
                     let result = sim_seize(&config, amount);
-                    if config.paused {
+                    if config.is_paused(NOW) {
                         prop_assert!(result,
                             "Seize failed while paused (amount={})", amount
                         );
@@ -149,7 +211,7 @@ proptest! {
                 }
                 PauseOp::Transfer(amount) => {
                     let result = sim_transfer(&config, amount);
-                    if config.paused {
+                    if config.pauses(pause::TRANSFERS, NOW) {
                         prop_assert!(!result,
                             "Transfer succeeded while paused (amount={})", amount
                         );
@@ -159,6 +221,54 @@ proptest! {
         }
     }
 
+    /// Pausing one class never blocks another: with only minting paused,
+    /// holders can still burn and transfer.
+    #[test]
+    fn classes_pause_independently(flags in 0u8..=pause::ALL, amount in 1u64..=100_000u64) {
+        let config = default_config(flags);
+        prop_assert_eq!(sim_mint(&config, amount), flags & pause::MINT == 0);
+        prop_assert_eq!(sim_burn(&config, amount), flags & pause::BURN == 0);
+        prop_assert_eq!(sim_freeze(&config), flags & pause::FREEZE_THAW == 0);
+        prop_assert_eq!(sim_transfer(&config, amount), flags & pause::TRANSFERS == 0);
+    }
+
+    /// A Guardian can add pause classes but never lift one, whichever role
+    /// the unpause policy names.
+    #[test]
+    fn guardian_never_lifts_a_pause(
+        old_flags in 0u8..=pause::ALL,
+        flags in 0u8..=pause::ALL,
+        unpause_requires_admin in any::<bool>(),
+    ) {
+        let mut config = default_config(old_flags);
+        config.unpause_requires_admin = unpause_requires_admin;
+        if sim_set_flags_authorized(&config, Role::Guardian, flags) {
+            prop_assert_eq!(flags & old_flags, old_flags);
+        }
+        prop_assert!(sim_set_flags_authorized(&config, Role::Guardian, old_flags | flags));
+    }
+
+    /// A pause with `max_pause_secs` set lifts on its own at the deadline,
+    /// and an admin renewal pushes that deadline out by a full window.
+    #[test]
+    fn pause_expires_unless_renewed(
+        max_pause_secs in 1u32..=86_400,
+        paused_at in 0i64..=1_000_000,
+        renew_after in 0u32..=86_400,
+    ) {
+        let mut config = default_config(0);
+        config.max_pause_secs = max_pause_secs;
+        config.set_pause_flags(pause::ALL, 0, paused_at);
+        let deadline = paused_at + i64::from(max_pause_secs);
+        prop_assert!(config.is_fully_paused(deadline - 1));
+        prop_assert!(!config.is_paused(deadline));
+
+        let renewed_at = paused_at + i64::from(renew_after.min(max_pause_secs - 1));
+        config.pause_expires_at = config.pause_deadline(renewed_at);
+        prop_assert!(config.is_fully_paused(renewed_at + i64::from(max_pause_secs) - 1));
+        prop_assert!(!config.is_paused(renewed_at + i64::from(max_pause_secs)));
+    }
+
     /// Double-pause and double-unpause are always rejected.
     #[test]
     fn no_double_pause(
@@ -12,8 +12,12 @@
 
 use proptest::prelude::*;
 use solana_sdk::pubkey::Pubkey;
+use sss_core::constants::pause;
 use sss_core::state::{config::StablecoinConfig, role::Role};
 
+/// Model clock; `max_pause_secs` is zero here, so pauses never expire.
+const NOW: i64 = 0;
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -23,7 +27,7 @@ fn make_config(paused: bool) -> StablecoinConfig {
         authority: Pubkey::default(),
         mint: Pubkey::default(),
         preset: 1,
-        paused,
+        pause_flags: if paused { pause::ALL } else { 0 },
         supply_cap: None,
         total_minted: 0,
         total_burned: 0,
@@ -37,19 +41,67 @@ fn make_config(paused: bool) -> StablecoinConfig {
         default_account_frozen: false,
         admin_count: 1,
         oracle_feed_id: None,
+        receipt_threshold: None,
+        receipt_retention_secs: 0,
+        max_confidence_bps: None,
+        program_version: sss_core::version::program_version(),
+        event_queue_enabled: false,
+        disabled_instructions: 0,
+        locked_instructions: 0,
+        reserved_for_redemption: 0,
+        state_digest: [0; 32],
+        state_nonce: 0,
+        bridge_minted: 0,
+        bridge_burned: 0,
+        thaw_ramp: None,
+        paused_at_slot: 0,
+        ramp_started_at: None,
+        ramp_base_supply: 0,
+        incident_flag: false,
+        incident_updated_at: 0,
+        incident_uri: String::new(),
+        destination_mint_limit: None,
+        halt_mint_on_supply_mismatch: false,
+        oracle_price_source: Default::default(),
+        pause_on_authority_anomaly: false,
+        mint_index: 0,
+        pending_authority: None,
+        admin_timelock_secs: 0,
+        large_mint_threshold: None,
+        large_mint_approvals: 0,
+        unpause_requires_admin: false,
+        max_pause_secs: 0,
+        pause_expires_at: None,
+        large_seize_threshold: None,
+        seizure_count: 0,
+        treasury: None,
+        seize_requires_frozen: false,
+        wipe_count: 0,
+        version: StablecoinConfig::CURRENT_VERSION,
+        sunset_at: None,
+        oracle_kind: Default::default(),
+        max_attestation_age_secs: None,
+        fee_treasury: None,
     }
 }
 
 /// Role indices as u8 to derive `Role` from fuzzer-generated integers.
 fn role_from_u8(n: u8) -> Role {
-    match n % 7 {
+    match n % 14 {
         0 => Role::Admin,
         1 => Role::Minter,
         2 => Role::Freezer,
         3 => Role::Pauser,
         4 => Role::Burner,
         5 => Role::Blacklister,
-        _ => Role::Seizer,
+        6 => Role::Seizer,
+        7 => Role::Bridge,
+        8 => Role::MetadataManager,
+        9 => Role::QuotaManager,
+        10 => Role::Rescuer,
+        11 => Role::Guardian,
+        12 => Role::MintRequester,
+        _ => Role::Attestor,
     }
 }
 
@@ -57,7 +109,7 @@ fn role_from_u8(n: u8) -> Role {
 ///
 /// Returns true when the operation would be allowed on-chain.
 fn simulated_grant(config: &StablecoinConfig, role: Role) -> bool {
-    if config.paused && !matches!(role, Role::Admin) {
+    if config.is_paused(NOW) && !matches!(role, Role::Admin) {
         return false; // Blocked — H-2 guard
     }
     true // All other pre-conditions assumed satisfied for this simulation
@@ -65,7 +117,7 @@ fn simulated_grant(config: &StablecoinConfig, role: Role) -> bool {
 
 /// Simulates the pause guard from `manage_roles::handler_revoke` (H-2 fix).
 fn simulated_revoke(config: &StablecoinConfig, role: Role) -> bool {
-    if config.paused && !matches!(role, Role::Admin) {
+    if config.is_paused(NOW) && !matches!(role, Role::Admin) {
         return false; // Blocked — H-2 guard
     }
     true
@@ -75,9 +127,9 @@ fn simulated_revoke(config: &StablecoinConfig, role: Role) -> bool {
 // Proptest strategies
 // ---------------------------------------------------------------------------
 
-/// Non-admin role (indices 1–6).
+/// Non-admin role (indices 1–13).
 fn arb_non_admin_role() -> impl Strategy<Value = Role> {
-    (1u8..7u8).prop_map(role_from_u8)
+    (1u8..14u8).prop_map(role_from_u8)
 }
 
 // ---------------------------------------------------------------------------
@@ -89,7 +141,7 @@ proptest! {
     /// paused.
     #[test]
     fn non_admin_grant_blocked_when_paused(
-        role_idx in 1u8..7u8,
+        role_idx in 1u8..14u8,
     ) {
         let paused_config = make_config(true);
         let role = role_from_u8(role_idx);
@@ -103,7 +155,7 @@ proptest! {
     /// **H-2 / property 2**: Any non-Admin revoke is blocked when paused.
     #[test]
     fn non_admin_revoke_blocked_when_paused(
-        role_idx in 1u8..7u8,
+        role_idx in 1u8..14u8,
     ) {
         let paused_config = make_config(true);
         let role = role_from_u8(role_idx);
@@ -136,11 +188,11 @@ proptest! {
     }
 
     /// **H-2 / property 5**: Unpausing re-enables all role operations.
-    /// Confirms that the guard is purely a `config.paused` check and does not
+    /// Confirms that the guard is purely a `config.is_paused()` check and does not
     /// permanently disable operations.
     #[test]
     fn non_admin_ops_re_enabled_after_unpause(
-        role_idx in 1u8..7u8,
+        role_idx in 1u8..14u8,
     ) {
         let mut config = make_config(true);
         let role = role_from_u8(role_idx);
@@ -150,7 +202,7 @@ proptest! {
         prop_assert!(!simulated_revoke(&config, role));
 
         // Unpause.
-        config.paused = false;
+        config.pause_flags = 0;
 
         // Now allowed.
         prop_assert!(
@@ -170,7 +222,7 @@ proptest! {
     /// non-admin ops fail; if unpaused, they succeed.
     #[test]
     fn pause_state_at_call_time_governs_result(
-        ops in prop::collection::vec((any::<bool>(), 1u8..7u8), 1..50),
+        ops in prop::collection::vec((any::<bool>(), 1u8..14u8), 1..50),
     ) {
         for (paused, role_idx) in ops {
             let config = make_config(paused);
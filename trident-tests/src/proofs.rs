@@ -0,0 +1,226 @@
+//! Kani proof harnesses for supply arithmetic.
+//!
+//! The proptest suites sample inputs; these harnesses check the same
+//! properties for *every* input. Run with:
+//!
+//! ```text
+//! cargo kani -p sss-fuzz-tests
+//! ```
+//!
+//! Each harness starts from a symbolic config that already satisfies
+//! `check_all_invariants`, applies one operation, and asserts the invariants
+//! still hold. Panics, overflows and failed asserts are all reported by Kani.
+
+use anchor_lang::prelude::Pubkey;
+use sss_core::math::oracle_token_cap;
+use sss_core::state::{Role, RoleAccount, StablecoinConfig};
+
+use crate::invariants::{check_all_invariants, check_supply_invariant};
+
+fn symbolic_config() -> StablecoinConfig {
+    StablecoinConfig {
+        authority: Pubkey::default(),
+        mint: Pubkey::default(),
+        preset: 1,
+        pause_flags: 0,
+        supply_cap: kani::any(),
+        total_minted: kani::any(),
+        total_burned: kani::any(),
+        bump: 0,
+        name: String::new(),
+        symbol: String::new(),
+        uri: String::new(),
+        decimals: 6,
+        enable_permanent_delegate: true,
+        enable_transfer_hook: false,
+        default_account_frozen: false,
+        admin_count: 1,
+        oracle_feed_id: None,
+        receipt_threshold: None,
+        receipt_retention_secs: 0,
+        max_confidence_bps: None,
+        program_version: sss_core::version::program_version(),
+        event_queue_enabled: false,
+        disabled_instructions: 0,
+        locked_instructions: 0,
+        reserved_for_redemption: 0,
+        state_digest: [0; 32],
+        state_nonce: 0,
+        bridge_minted: 0,
+        bridge_burned: 0,
+        thaw_ramp: None,
+        paused_at_slot: 0,
+        ramp_started_at: None,
+        ramp_base_supply: 0,
+        incident_flag: false,
+        incident_updated_at: 0,
+        incident_uri: String::new(),
+        destination_mint_limit: None,
+        halt_mint_on_supply_mismatch: false,
+        oracle_price_source: Default::default(),
+        pause_on_authority_anomaly: false,
+        mint_index: 0,
+        pending_authority: None,
+        admin_timelock_secs: 0,
+        large_mint_threshold: None,
+        large_mint_approvals: 0,
+        unpause_requires_admin: false,
+        max_pause_secs: 0,
+        pause_expires_at: None,
+        large_seize_threshold: None,
+        seizure_count: 0,
+        treasury: None,
+        seize_requires_frozen: false,
+        wipe_count: 0,
+        version: StablecoinConfig::CURRENT_VERSION,
+        sunset_at: None,
+        oracle_kind: Default::default(),
+        max_attestation_age_secs: None,
+        fee_treasury: None,
+    }
+}
+
+/// A symbolic config restricted to states reachable through the program.
+fn valid_config() -> StablecoinConfig {
+    let config = symbolic_config();
+    kani::assume(config.total_burned <= config.total_minted);
+    if let Some(cap) = config.supply_cap {
+        kani::assume(config.current_supply() <= cap);
+    }
+    config
+}
+
+fn symbolic_minter() -> RoleAccount {
+    RoleAccount {
+        config: Pubkey::default(),
+        address: Pubkey::default(),
+        role: Role::Minter,
+        granted_by: Pubkey::default(),
+        granted_at: 0,
+        bump: 0,
+        mint_quota: kani::any(),
+        amount_minted: kani::any(),
+        allowed_hours: None,
+        action_quota: None,
+        action_window: Default::default(),
+        expires_at: None,
+        quota_period_secs: None,
+        period_start: 0,
+        burn_quota: None,
+        amount_burned: 0,
+        max_per_window: None,
+        window_secs: 0,
+        window_start: 0,
+        window_minted: 0,
+        prev_window_minted: 0,
+    }
+}
+
+/// `current_supply` never underflows and matches the saturating definition
+/// for arbitrary (even unreachable) counter values.
+#[kani::proof]
+fn current_supply_is_saturating_difference() {
+    let config = symbolic_config();
+    check_supply_invariant(&config);
+    assert!(config.current_supply() <= config.total_minted);
+}
+
+/// Whenever `can_mint` approves an amount, applying it cannot overflow and
+/// leaves every invariant intact, including the supply cap.
+#[kani::proof]
+fn can_mint_preserves_invariants() {
+    let mut config = valid_config();
+    let amount: u64 = kani::any();
+
+    if config.can_mint(amount) {
+        assert!(amount > 0);
+        config.total_minted = config.total_minted.checked_add(amount).unwrap();
+        check_all_invariants(&config);
+    }
+}
+
+/// `can_mint` only rejects an amount for a reason: zero, counter overflow,
+/// or the cap. Without a cap, every non-overflowing amount is accepted.
+#[kani::proof]
+fn can_mint_rejections_are_justified() {
+    let config = valid_config();
+    let amount: u64 = kani::any();
+
+    if !config.can_mint(amount) {
+        let overflows = config.total_minted.checked_add(amount).is_none();
+        let over_cap = match config.supply_cap {
+            Some(cap) => config
+                .current_supply()
+                .checked_add(amount)
+                .map_or(true, |supply| supply > cap),
+            None => false,
+        };
+        assert!(amount == 0 || overflows || over_cap);
+    }
+}
+
+/// Burning at most the circulating supply keeps every invariant intact.
+#[kani::proof]
+fn burn_within_supply_preserves_invariants() {
+    let mut config = valid_config();
+    let amount: u64 = kani::any();
+    kani::assume(amount <= config.current_supply());
+
+    config.total_burned = config.total_burned.checked_add(amount).unwrap();
+    check_all_invariants(&config);
+}
+
+/// An approved quota check never lets `amount_minted` exceed the quota or
+/// overflow; an unlimited minter is only rejected on overflow.
+#[kani::proof]
+fn quota_allows_is_sound() {
+    let mut minter = symbolic_minter();
+    let amount: u64 = kani::any();
+
+    match minter.quota_allows(amount) {
+        Some(true) => {
+            minter.amount_minted = minter.amount_minted.checked_add(amount).unwrap();
+            if let Some(quota) = minter.mint_quota {
+                assert!(minter.amount_minted <= quota);
+            }
+        }
+        Some(false) => assert!(minter.mint_quota.is_some()),
+        None => assert!(minter.amount_minted.checked_add(amount).is_none()),
+    }
+}
+
+/// The oracle cap conversion never panics for any price, exponent or
+/// decimals, and rejects non-positive prices.
+#[kani::proof]
+#[kani::unwind(34)]
+fn oracle_token_cap_never_panics() {
+    let usd_cap: u64 = kani::any();
+    let price: i64 = kani::any();
+    let exponent: i32 = kani::any();
+    let decimals: u8 = kani::any();
+
+    let token_cap = oracle_token_cap(usd_cap, price, exponent, decimals);
+    if price <= 0 {
+        assert!(token_cap.is_none());
+    }
+}
+
+/// At a $1.00 peg the oracle-adjusted cap equals the raw cap scaled to base
+/// units, so the oracle path can never widen the cap for a pegged price.
+#[kani::proof]
+#[kani::unwind(34)]
+fn oracle_token_cap_at_peg_matches_raw_cap() {
+    let usd_cap: u64 = kani::any();
+    let decimals: u8 = kani::any();
+    let abs_expo: u32 = kani::any();
+    kani::assume(decimals <= 9);
+    // u64::MAX × 10^9 × 10^10 is the largest numerator that fits in u128.
+    kani::assume(abs_expo <= 10);
+
+    let price = 10i64.pow(abs_expo);
+    let expected = (usd_cap as u128 * 10u128.pow(decimals as u32)).min(u64::MAX as u128) as u64;
+    assert_eq!(
+        oracle_token_cap(usd_cap, price, -(abs_expo as i32), decimals),
+        Some(expected)
+    );
+}
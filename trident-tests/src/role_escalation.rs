@@ -96,6 +96,13 @@ fn role_strategy() -> impl Strategy<Value = Role> {
         Just(Role::Burner),
         Just(Role::Blacklister),
         Just(Role::Seizer),
+        Just(Role::Bridge),
+        Just(Role::MetadataManager),
+        Just(Role::QuotaManager),
+        Just(Role::Rescuer),
+        Just(Role::Guardian),
+        Just(Role::MintRequester),
+        Just(Role::Attestor),
     ]
 }
 
@@ -0,0 +1,152 @@
+//! Fuzz: Role PDA seed collisions — the `["sss-role", config, address, role_u8]`
+//! derivation must be injective, and caller-supplied role bytes outside 0-13
+//! must never be accepted by `grant_role`.
+//!
+//! Properties:
+//!
+//! 1. Distinct (config, address, role) tuples never derive the same PDA.
+//! 2. `Role::from_u8` round-trips every valid role and rejects bytes > 13.
+//! 3. A PDA derived from an invalid role byte never coincides with the PDA of
+//!    any valid role, so it cannot stand in for a real role account.
+
+use proptest::prelude::*;
+use solana_sdk::pubkey::Pubkey;
+use sss_core::pda::find_role_address;
+use sss_core::state::{Role, RoleAccount};
+
+const ALL_ROLES: [Role; 14] = [
+    Role::Admin,
+    Role::Minter,
+    Role::Freezer,
+    Role::Pauser,
+    Role::Burner,
+    Role::Blacklister,
+    Role::Seizer,
+    Role::Bridge,
+    Role::MetadataManager,
+    Role::QuotaManager,
+    Role::Rescuer,
+    Role::Guardian,
+    Role::MintRequester,
+    Role::Attestor,
+];
+
+/// Derive a role PDA from a raw role byte, exactly as `grant_role` does with
+/// its `role: u8` instruction argument.
+fn derive_raw(config: &Pubkey, address: &Pubkey, role_byte: u8) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            RoleAccount::SSS_ROLE_SEED,
+            config.as_ref(),
+            address.as_ref(),
+            &[role_byte],
+        ],
+        &sss_core::ID,
+    )
+    .0
+}
+
+/// Simulated `grant_role` role-byte validation (mirrors `handler_grant`).
+fn sim_grant_role_byte(role_byte: u8) -> Option<Role> {
+    Role::from_u8(role_byte)
+}
+
+fn pubkey_strategy() -> impl Strategy<Value = Pubkey> {
+    any::<[u8; 32]>().prop_map(Pubkey::new_from_array)
+}
+
+fn role_strategy() -> impl Strategy<Value = Role> {
+    (0u8..14).prop_map(|b| Role::from_u8(b).unwrap())
+}
+
+proptest! {
+    /// Two tuples that differ in any component derive different PDAs.
+    #[test]
+    fn distinct_tuples_never_collide(
+        config_a in pubkey_strategy(),
+        config_b in pubkey_strategy(),
+        address_a in pubkey_strategy(),
+        address_b in pubkey_strategy(),
+        role_a in role_strategy(),
+        role_b in role_strategy(),
+    ) {
+        let same_tuple = config_a == config_b && address_a == address_b && role_a == role_b;
+        let pda_a = find_role_address(&config_a, &address_a, role_a).0;
+        let pda_b = find_role_address(&config_b, &address_b, role_b).0;
+
+        if same_tuple {
+            prop_assert_eq!(pda_a, pda_b);
+        } else {
+            prop_assert_ne!(pda_a, pda_b,
+                "PDA collision between ({}, {}, {:?}) and ({}, {}, {:?})",
+                config_a, address_a, role_a, config_b, address_b, role_b
+            );
+        }
+    }
+
+    /// All seven roles held by one address under one config live at seven
+    /// distinct PDAs.
+    #[test]
+    fn roles_of_one_holder_are_distinct(
+        config in pubkey_strategy(),
+        address in pubkey_strategy(),
+    ) {
+        let pdas: Vec<Pubkey> = ALL_ROLES
+            .iter()
+            .map(|r| find_role_address(&config, &address, *r).0)
+            .collect();
+
+        for i in 0..pdas.len() {
+            for j in (i + 1)..pdas.len() {
+                prop_assert_ne!(pdas[i], pdas[j],
+                    "Roles {:?} and {:?} share a PDA", ALL_ROLES[i], ALL_ROLES[j]
+                );
+            }
+        }
+    }
+
+    /// The typed helper and the raw-byte derivation used by `grant_role`
+    /// agree for every valid role.
+    #[test]
+    fn helper_matches_raw_derivation(
+        config in pubkey_strategy(),
+        address in pubkey_strategy(),
+        role in role_strategy(),
+    ) {
+        prop_assert_eq!(
+            find_role_address(&config, &address, role).0,
+            derive_raw(&config, &address, role.as_u8())
+        );
+    }
+
+    /// Role bytes > 13 are rejected by the grant validation, and bytes 0-13
+    /// round-trip through `as_u8`.
+    #[test]
+    fn invalid_role_bytes_rejected(role_byte in any::<u8>()) {
+        match sim_grant_role_byte(role_byte) {
+            Some(role) => {
+                prop_assert!(role_byte <= 13);
+                prop_assert_eq!(role.as_u8(), role_byte);
+            }
+            None => prop_assert!(role_byte > 13,
+                "Valid role byte {} was rejected", role_byte
+            ),
+        }
+    }
+
+    /// A PDA derived from an invalid role byte never aliases a valid role PDA
+    /// for any holder under the same config.
+    #[test]
+    fn invalid_role_pda_never_aliases_valid_role(
+        config in pubkey_strategy(),
+        address in pubkey_strategy(),
+        other in pubkey_strategy(),
+        role_byte in 14u8..=u8::MAX,
+    ) {
+        let invalid = derive_raw(&config, &address, role_byte);
+        for role in ALL_ROLES {
+            prop_assert_ne!(invalid, find_role_address(&config, &address, role).0);
+            prop_assert_ne!(invalid, find_role_address(&config, &other, role).0);
+        }
+    }
+}
@@ -2,13 +2,17 @@
 
 use proptest::prelude::*;
 use solana_sdk::pubkey::Pubkey;
+use sss_core::constants::pause;
 use sss_core::state::config::StablecoinConfig;
 
 use crate::invariants::check_all_invariants;
 
+/// Model clock; `max_pause_secs` is zero here, so pauses never expire.
+const NOW: i64 = 0;
+
 /// Simulated mint operation on the config (mirrors on-chain logic).
 fn sim_mint(config: &mut StablecoinConfig, amount: u64) -> bool {
-    if config.paused || amount == 0 {
+    if config.pauses(pause::MINT, NOW) || amount == 0 {
         return false;
     }
 
@@ -30,7 +34,7 @@ fn sim_mint(config: &mut StablecoinConfig, amount: u64) -> bool {
 
 /// Simulated burn operation on the config.
 fn sim_burn(config: &mut StablecoinConfig, amount: u64) -> bool {
-    if config.paused || amount == 0 {
+    if config.pauses(pause::BURN, NOW) || amount == 0 {
         return false;
     }
 
@@ -54,7 +58,7 @@ fn default_config(cap: Option<u64>) -> StablecoinConfig {
         authority: Pubkey::default(),
         mint: Pubkey::default(),
         preset: 1,
-        paused: false,
+        pause_flags: 0,
         supply_cap: cap,
         total_minted: 0,
         total_burned: 0,
@@ -68,6 +72,47 @@ fn default_config(cap: Option<u64>) -> StablecoinConfig {
         default_account_frozen: false,
         admin_count: 1,
         oracle_feed_id: None,
+        receipt_threshold: None,
+        receipt_retention_secs: 0,
+        max_confidence_bps: None,
+        program_version: sss_core::version::program_version(),
+        event_queue_enabled: false,
+        disabled_instructions: 0,
+        locked_instructions: 0,
+        reserved_for_redemption: 0,
+        state_digest: [0; 32],
+        state_nonce: 0,
+        bridge_minted: 0,
+        bridge_burned: 0,
+        thaw_ramp: None,
+        paused_at_slot: 0,
+        ramp_started_at: None,
+        ramp_base_supply: 0,
+        incident_flag: false,
+        incident_updated_at: 0,
+        incident_uri: String::new(),
+        destination_mint_limit: None,
+        halt_mint_on_supply_mismatch: false,
+        oracle_price_source: Default::default(),
+        pause_on_authority_anomaly: false,
+        mint_index: 0,
+        pending_authority: None,
+        admin_timelock_secs: 0,
+        large_mint_threshold: None,
+        large_mint_approvals: 0,
+        unpause_requires_admin: false,
+        max_pause_secs: 0,
+        pause_expires_at: None,
+        large_seize_threshold: None,
+        seizure_count: 0,
+        treasury: None,
+        seize_requires_frozen: false,
+        wipe_count: 0,
+        version: StablecoinConfig::CURRENT_VERSION,
+        sunset_at: None,
+        oracle_kind: Default::default(),
+        max_attestation_age_secs: None,
+        fee_treasury: None,
     }
 }
 